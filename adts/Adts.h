@@ -0,0 +1,230 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <queue>
+#include <utility>
+#include <vector>
+
+// Abstract data types built out of other ones: a queue out of two stacks, a
+// stack out of a queue, and stacks and queues that also know their minimum.
+// The interesting ones are cheap only on average, so they count their work
+// for the tests (and for anyone wanting to watch amortization happen).
+
+// Work done so far: operations are the calls made on the outside, steps
+// the pushes and pops made on the inside
+struct CostCounter {
+  size_t operations = 0;
+  size_t steps = 0;
+
+  double steps_per_operation() const {
+    return operations == 0 ? 0.0
+                           : static_cast<double>(steps) /
+                                 static_cast<double>(operations);
+  }
+};
+
+// A queue out of two stacks: push onto `in`, pop off `out`, and when `out`
+// runs dry, pour all of `in` into it, which reverses it into queue order. A
+// single pop can take O(n), but every element is pushed and popped at most
+// twice on its way through, so n operations take O(n): at most four steps
+// per operation, amortized.
+template <typename T>
+class TwoStackQueue {
+ public:
+  bool empty() const { return in_.empty() && out_.empty(); }
+
+  size_t size() const { return in_.size() + out_.size(); }
+
+  void push(T x) {
+    ++cost_.operations;
+    ++cost_.steps;
+    in_.push_back(std::move(x));
+  }
+
+  const T& front() {
+    assert(!empty() && "front of an empty queue");
+    ++cost_.operations;
+    refill();
+    return out_.back();
+  }
+
+  T pop() {
+    assert(!empty() && "pop from an empty queue");
+    ++cost_.operations;
+    refill();
+    T x = std::move(out_.back());
+    out_.pop_back();
+    ++cost_.steps;
+    return x;
+  }
+
+  const CostCounter& cost() const { return cost_; }
+
+ private:
+  void refill() {
+    if (!out_.empty()) {
+      return;
+    }
+    while (!in_.empty()) {
+      out_.push_back(std::move(in_.back()));
+      in_.pop_back();
+      cost_.steps += 2;
+    }
+  }
+
+  std::vector<T> in_;   // Newest on top
+  std::vector<T> out_;  // Oldest on top
+  CostCounter cost_;
+};
+
+// The other way around, a stack out of one queue, shows that not every
+// construction amortizes: after each push, rotate the queue until the new
+// element is at the front. Pops are one step, but the k-th push takes k, so
+// there's no sequence of n pushes that costs less than O(n^2).
+template <typename T>
+class QueueStack {
+ public:
+  bool empty() const { return queue_.empty(); }
+
+  size_t size() const { return queue_.size(); }
+
+  void push(T x) {
+    ++cost_.operations;
+    queue_.push(std::move(x));
+    ++cost_.steps;
+    for (size_t i = 1; i < queue_.size(); ++i) {
+      queue_.push(std::move(queue_.front()));
+      queue_.pop();
+      cost_.steps += 2;
+    }
+  }
+
+  const T& top() const {
+    assert(!empty() && "top of an empty stack");
+    return queue_.front();
+  }
+
+  T pop() {
+    assert(!empty() && "pop from an empty stack");
+    ++cost_.operations;
+    ++cost_.steps;
+    T x = std::move(queue_.front());
+    queue_.pop();
+    return x;
+  }
+
+  const CostCounter& cost() const { return cost_; }
+
+ private:
+  std::queue<T> queue_;  // Top of the stack at the front
+  CostCounter cost_;
+};
+
+// A stack that knows its minimum in O(1), worst case: every entry also
+// records the minimum of itself and everything below it, which is all a pop
+// can ever uncover. With Compare = std::greater<T>, the maximum.
+template <typename T, typename Compare = std::less<T>>
+class MinStack {
+ public:
+  explicit MinStack(Compare less = Compare()) : less_(less) {}
+
+  bool empty() const { return entries_.empty(); }
+
+  size_t size() const { return entries_.size(); }
+
+  void push(T x) {
+    size_t min = entries_.size();
+    if (!entries_.empty() && !less_(x, entries_[entries_.back().min].value)) {
+      min = entries_.back().min;  // Ties keep the one lower down
+    }
+    entries_.push_back({std::move(x), min});
+  }
+
+  const T& top() const {
+    assert(!empty() && "top of an empty stack");
+    return entries_.back().value;
+  }
+
+  const T& min() const {
+    assert(!empty() && "min of an empty stack");
+    return entries_[entries_.back().min].value;
+  }
+
+  T pop() {
+    assert(!empty() && "pop from an empty stack");
+    T x = std::move(entries_.back().value);
+    entries_.pop_back();
+    return x;
+  }
+
+ private:
+  // The minimum as an index rather than a copy, so T needn't be copyable
+  struct Entry {
+    T value;
+    size_t min;
+  };
+
+  std::vector<Entry> entries_;
+  Compare less_;
+};
+
+// A queue that knows its maximum in amortized O(1): the two-stack queue,
+// with both stacks keeping their maximum, since the queue's maximum is the
+// larger of theirs. Unlike sliding-window/'s MonotonicQueue it keeps every
+// element, so pop() returns what left rather than needing to be told.
+template <typename T, typename Compare = std::less<T>>
+class MaxQueue {
+ public:
+  explicit MaxQueue(Compare less = Compare())
+      : less_(less), in_(Greater{less}), out_(Greater{less}) {}
+
+  bool empty() const { return in_.empty() && out_.empty(); }
+
+  size_t size() const { return in_.size() + out_.size(); }
+
+  void push(T x) {
+    ++cost_.operations;
+    ++cost_.steps;
+    in_.push(std::move(x));
+  }
+
+  T pop() {
+    assert(!empty() && "pop from an empty queue");
+    ++cost_.operations;
+    if (out_.empty()) {
+      while (!in_.empty()) {
+        out_.push(in_.pop());
+        cost_.steps += 2;
+      }
+    }
+    ++cost_.steps;
+    return out_.pop();
+  }
+
+  const T& max() const {
+    assert(!empty() && "max of an empty queue");
+    if (in_.empty()) {
+      return out_.min();
+    }
+    if (out_.empty()) {
+      return in_.min();
+    }
+    return less_(out_.min(), in_.min()) ? in_.min() : out_.min();
+  }
+
+  const CostCounter& cost() const { return cost_; }
+
+ private:
+  // The stacks' minimum is the queue's maximum
+  struct Greater {
+    Compare less;
+    bool operator()(const T& a, const T& b) const { return less(b, a); }
+  };
+
+  Compare less_;
+  MinStack<T, Greater> in_;
+  MinStack<T, Greater> out_;
+  CostCounter cost_;
+};
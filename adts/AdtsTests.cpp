@@ -0,0 +1,125 @@
+#include <algorithm>
+#include <cstddef>
+#include <deque>
+#include <functional>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Adts.h"
+#include "gtest/gtest.h"
+
+// Random pushes and pops, checked against std::deque after every one
+TEST(Adts, TwoStackQueueMatchesDeque) {
+  Rng gen(187);
+  TwoStackQueue<int> queue;
+  std::deque<int> expected;
+  for (int i = 0; i < 10000; ++i) {
+    if (expected.empty() || uniform_below(gen, 3) != 0) {
+      queue.push(i);
+      expected.push_back(i);
+    } else {
+      ASSERT_EQ(queue.front(), expected.front());
+      ASSERT_EQ(queue.pop(), expected.front());
+      expected.pop_front();
+    }
+    ASSERT_EQ(queue.size(), expected.size());
+  }
+  EXPECT_LE(queue.cost().steps_per_operation(), 4.0);
+}
+
+TEST(Adts, TwoStackQueueAmortizes) {
+  TwoStackQueue<std::unique_ptr<int>> queue;
+  const size_t n = 1000;
+  for (size_t i = 0; i < n; ++i) {
+    queue.push(std::make_unique<int>(static_cast<int>(i)));
+  }
+  EXPECT_EQ(queue.cost().steps, n);
+
+  // The first pop pours everything over; the rest are one step each
+  EXPECT_EQ(*queue.pop(), 0);
+  EXPECT_EQ(queue.cost().steps, n + 2 * n + 1);
+  while (!queue.empty()) {
+    queue.pop();
+  }
+  EXPECT_EQ(queue.cost().operations, 2 * n);
+  EXPECT_EQ(queue.cost().steps, 4 * n);
+}
+
+TEST(Adts, QueueStackDoesNot) {
+  QueueStack<std::string> stack;
+  std::vector<std::string> expected;
+  const size_t n = 100;
+  for (size_t i = 0; i < n; ++i) {
+    stack.push(std::to_string(i));
+    expected.push_back(std::to_string(i));
+    ASSERT_EQ(stack.top(), expected.back());
+  }
+  // The k-th push takes 2k - 1 steps, n^2 in all
+  EXPECT_EQ(stack.cost().steps, n * n);
+  while (!expected.empty()) {
+    ASSERT_EQ(stack.pop(), expected.back());
+    expected.pop_back();
+  }
+  EXPECT_TRUE(stack.empty());
+}
+
+TEST(Adts, MinStack) {
+  Rng gen(187);
+  MinStack<int> min_stack;
+  MinStack<int, std::greater<int>> max_stack;
+  std::vector<int> expected;
+  for (int i = 0; i < 5000; ++i) {
+    if (expected.empty() || uniform_below(gen, 2) == 0) {
+      const int x = uniform_int(gen, -50, 50);
+      min_stack.push(x);
+      max_stack.push(x);
+      expected.push_back(x);
+    } else {
+      ASSERT_EQ(min_stack.pop(), expected.back());
+      ASSERT_EQ(max_stack.pop(), expected.back());
+      expected.pop_back();
+    }
+    if (!expected.empty()) {
+      ASSERT_EQ(min_stack.top(), expected.back());
+      ASSERT_EQ(min_stack.min(),
+                *std::min_element(expected.begin(), expected.end()));
+      ASSERT_EQ(max_stack.min(),
+                *std::max_element(expected.begin(), expected.end()));
+    }
+    ASSERT_EQ(min_stack.size(), expected.size());
+  }
+}
+
+TEST(Adts, MaxQueueMatchesDeque) {
+  Rng gen(187);
+  MaxQueue<int> queue;
+  MaxQueue<int, std::greater<int>> min_queue;
+  std::deque<int> expected;
+  for (int i = 0; i < 10000; ++i) {
+    if (expected.empty() || uniform_below(gen, 2) == 0) {
+      const int x = uniform_int(gen, -50, 50);
+      queue.push(x);
+      min_queue.push(x);
+      expected.push_back(x);
+    } else {
+      ASSERT_EQ(queue.pop(), expected.front());
+      ASSERT_EQ(min_queue.pop(), expected.front());
+      expected.pop_front();
+    }
+    if (!expected.empty()) {
+      ASSERT_EQ(queue.max(),
+                *std::max_element(expected.begin(), expected.end()));
+      ASSERT_EQ(min_queue.max(),
+                *std::min_element(expected.begin(), expected.end()));
+    }
+    ASSERT_EQ(queue.size(), expected.size());
+  }
+  EXPECT_LE(queue.cost().steps_per_operation(), 4.0);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,179 @@
+#pragma once
+
+#include <cassert>
+#include <concepts>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <type_traits>
+#include <utility>
+#include <vector>
+
+// Backtracking: build a solution one choice at a time, and when a partial
+// solution can't be finished, undo the last choice and try the next one.
+// Every backtracking search has the same scaffolding, so it lives here
+// once, and a problem only describes its partial solutions. A problem is
+// Backtracking if it has:
+//
+// - a Choice type;
+// - is_complete(), whether the current partial solution is a solution;
+// - choices(), the choices to try next, in order;
+// - accepts(c), the constraint check, whether c is allowed next; and
+// - choose(c) and unchoose(c), to make a choice and to take it back.
+//
+// It can also have promising(), a bound: false when the partial solution
+// can't lead to any solution, however it goes on, which prunes everything
+// below it. accepts prunes one choice before it's made; promising prunes
+// after, using what the choice changed.
+template <typename P>
+concept Backtracking =
+    requires(P& problem, const P& current, const typename P::Choice& c) {
+      { current.is_complete() } -> std::convertible_to<bool>;
+      {
+        current.choices()
+      } -> std::convertible_to<std::vector<typename P::Choice>>;
+      { current.accepts(c) } -> std::convertible_to<bool>;
+      problem.choose(c);
+      problem.unchoose(c);
+    };
+
+template <typename P>
+concept Bounded = requires(const P& current) {
+  { current.promising() } -> std::convertible_to<bool>;
+};
+
+struct BacktrackStats {
+  size_t nodes{0};  // partial solutions reached, the empty one included
+  size_t solutions{0};
+  bool stopped{false};  // visit asked to stop before the search was done
+};
+
+template <Backtracking P, typename Visit>
+bool backtrack_from(P& problem, Visit& visit, BacktrackStats& stats) {
+  ++stats.nodes;
+  if constexpr (Bounded<P>) {
+    if (!problem.promising()) {
+      return true;
+    }
+  }
+  if (problem.is_complete()) {
+    ++stats.solutions;
+    if (!visit(std::as_const(problem))) {
+      stats.stopped = true;
+      return false;
+    }
+    return true;
+  }
+  for (const auto& choice : problem.choices()) {
+    if (!problem.accepts(choice)) {
+      continue;
+    }
+    problem.choose(choice);
+    const bool keep_going = backtrack_from(problem, visit, stats);
+    problem.unchoose(choice);
+    if (!keep_going) {
+      return false;
+    }
+  }
+  return true;
+}
+
+// Calls visit(problem) at every solution, in the order the choices give,
+// for as long as it returns true. The problem is back where it started
+// afterwards. A solution is a leaf: the search doesn't go on past one.
+template <Backtracking P, typename Visit>
+BacktrackStats backtrack(P& problem, Visit visit) {
+  BacktrackStats stats;
+  backtrack_from(problem, visit, stats);
+  return stats;
+}
+
+template <Backtracking P>
+BacktrackStats count_solutions(P& problem) {
+  return backtrack(problem, [](const P&) { return true; });
+}
+
+// Up to `limit` solutions, as the problem's solution() reads them off
+template <Backtracking P>
+auto collect_solutions(P& problem,
+                       size_t limit = std::numeric_limits<size_t>::max()) {
+  std::vector<std::remove_cvref_t<decltype(problem.solution())>> solutions;
+  if (limit > 0) {
+    backtrack(problem, [&](const P& current) {
+      solutions.push_back(current.solution());
+      return solutions.size() < limit;
+    });
+  }
+  return solutions;
+}
+
+// The subsets of some values, none negative, that add up to target,
+// deciding the values in order, each taken or left. The bound abandons a
+// partial subset once it's over the target, or once everything still
+// undecided couldn't bring it up to the target.
+class SubsetSumSearch {
+ public:
+  using Choice = bool;  // whether the next value is in
+
+  SubsetSumSearch(std::vector<int64_t> values, int64_t target)
+      : values_(std::move(values)), target_(target) {
+    for (int64_t v : values_) {
+      assert(v >= 0 && "the bound needs values that aren't negative");
+      remaining_ += v;
+    }
+  }
+
+  bool is_complete() const {
+    return next_ == values_.size() && sum_ == target_;
+  }
+
+  std::vector<Choice> choices() const {
+    if (next_ == values_.size()) {
+      return {};
+    }
+    return {true, false};
+  }
+
+  bool accepts(Choice) const { return true; }
+
+  bool promising() const {
+    return sum_ <= target_ && sum_ + remaining_ >= target_;
+  }
+
+  void choose(Choice in) {
+    remaining_ -= values_[next_];
+    if (in) {
+      sum_ += values_[next_];
+      taken_.push_back(next_);
+    }
+    ++next_;
+  }
+
+  void unchoose(Choice in) {
+    --next_;
+    remaining_ += values_[next_];
+    if (in) {
+      sum_ -= values_[next_];
+      taken_.pop_back();
+    }
+  }
+
+  // The indices of the values in the subset
+  const std::vector<size_t>& solution() const { return taken_; }
+
+ private:
+  std::vector<int64_t> values_;
+  int64_t target_;
+  size_t next_{0};
+  int64_t sum_{0};
+  int64_t remaining_{0};  // the sum of the undecided values
+  std::vector<size_t> taken_;
+};
+
+// Every subset as its indices in increasing order, those taking earlier
+// values first.
+inline std::vector<std::vector<size_t>> subset_sums(
+    const std::vector<int64_t>& values, int64_t target) {
+  SubsetSumSearch search(values, target);
+  return collect_solutions(search);
+}
@@ -0,0 +1,101 @@
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../local-search/NQueens.h"
+#include "../rng/Rng.h"
+#include "Backtracking.h"
+#include "Sudoku.h"
+#include "gtest/gtest.h"
+
+// Against every subset, and the bound cuts the search well short of the
+// whole tree of 2^(n + 1) - 1 partial subsets
+TEST(Backtracking, SubsetSums) {
+  EXPECT_EQ(subset_sums({3, 1, 2}, 3),
+            (std::vector<std::vector<size_t>>{{0}, {1, 2}}));
+  EXPECT_TRUE(subset_sums({2, 4}, 5).empty());
+  EXPECT_EQ(subset_sums({}, 0), (std::vector<std::vector<size_t>>{{}}));
+
+  Rng gen(222);
+  for (int trial = 0; trial < 200; ++trial) {
+    std::vector<int64_t> values(uniform_below(gen, 13));
+    for (auto& v : values) {
+      v = uniform_int<int64_t>(gen, 0, 20);
+    }
+    const int64_t target = uniform_int<int64_t>(gen, 0, 60);
+    std::vector<std::vector<size_t>> expected;
+    // Taking a value comes before leaving it, so the subsets come in
+    // decreasing order of their masks read with value 0 highest
+    const size_t n = values.size();
+    for (size_t mask = size_t{1} << n; mask-- > 0;) {
+      std::vector<size_t> subset;
+      int64_t sum = 0;
+      for (size_t i = 0; i < n; ++i) {
+        if (mask >> (n - 1 - i) & 1) {
+          subset.push_back(i);
+          sum += values[i];
+        }
+      }
+      if (sum == target) {
+        expected.push_back(subset);
+      }
+    }
+    ASSERT_EQ(subset_sums(values, target), expected);
+  }
+
+  SubsetSumSearch search(std::vector<int64_t>(20, 5), 10);
+  const BacktrackStats stats = count_solutions(search);
+  EXPECT_EQ(stats.solutions, 190u);
+  EXPECT_LT(stats.nodes, size_t{1} << 14);
+}
+
+TEST(Backtracking, Sudoku) {
+  const SudokuGrid puzzle = sudoku_from_string(
+      "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3"
+      "..9..5.1.3..");
+  const auto solution = solve_sudoku(puzzle);
+  ASSERT_TRUE(solution.has_value());
+  EXPECT_EQ(sudoku_to_string(*solution),
+            "4839216579673458212518764935481329767295641381367982453726895148"
+            "14253769695417382");
+  EXPECT_TRUE(is_sudoku_solution(*solution, puzzle));
+  EXPECT_EQ(count_sudoku_solutions(puzzle), 1u);
+
+  // The empty grid has lots of solutions, and clashing givens have none
+  const SudokuGrid empty{};
+  EXPECT_EQ(count_sudoku_solutions(empty), 2u);
+  EXPECT_TRUE(is_sudoku_solution(*solve_sudoku(empty), empty));
+  SudokuGrid clash{};
+  clash[0] = clash[8] = 5;
+  EXPECT_FALSE(solve_sudoku(clash).has_value());
+
+  EXPECT_THROW(sudoku_from_string("123"), std::invalid_argument);
+  EXPECT_THROW(sudoku_from_string(std::string(80, '.') + "x"),
+               std::invalid_argument);
+}
+
+// Stopping at the first solution, and the problem is left as it started
+TEST(Backtracking, StopsEarly) {
+  QueensSearch search(6);
+  size_t seen = 0;
+  const BacktrackStats first = backtrack(search, [&](const QueensSearch& q) {
+    EXPECT_EQ(q.solution(), (std::vector<int>{1, 3, 5, 0, 2, 4}));
+    ++seen;
+    return false;
+  });
+  EXPECT_EQ(seen, 1u);
+  EXPECT_TRUE(first.stopped);
+  EXPECT_TRUE(search.solution().empty());
+  const BacktrackStats all = count_solutions(search);
+  EXPECT_EQ(all.solutions, 4u);
+  EXPECT_FALSE(all.stopped);
+  EXPECT_GT(all.nodes, first.nodes);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
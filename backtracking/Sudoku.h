@@ -0,0 +1,179 @@
+#pragma once
+
+#include <array>
+#include <bit>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <vector>
+
+#include "Backtracking.h"
+
+// Sudoku: fill a 9x9 grid with 1 to 9 so that every row, column and 3x3
+// box has each digit once. Cells are numbered 0 to 80 row by row, and 0 is
+// an empty cell.
+using SudokuGrid = std::array<int, 81>;
+
+inline size_t sudoku_box(size_t cell) {
+  return (cell / 27) * 3 + (cell % 9) / 3;
+}
+
+// 81 digits or '.'s, ignoring whitespace, with '.' or '0' for empty.
+// Throws std::invalid_argument otherwise.
+inline SudokuGrid sudoku_from_string(std::string_view text) {
+  SudokuGrid grid{};
+  size_t cell = 0;
+  for (char c : text) {
+    if (c == ' ' || c == '\n' || c == '\t' || c == '\r') {
+      continue;
+    }
+    if (cell == grid.size() || (c != '.' && (c < '0' || c > '9'))) {
+      throw std::invalid_argument("a sudoku is 81 digits or '.'s");
+    }
+    grid[cell++] = c == '.' ? 0 : c - '0';
+  }
+  if (cell != grid.size()) {
+    throw std::invalid_argument("a sudoku is 81 digits or '.'s");
+  }
+  return grid;
+}
+
+inline std::string sudoku_to_string(const SudokuGrid& grid) {
+  std::string text;
+  for (int digit : grid) {
+    text.push_back(digit == 0 ? '.' : static_cast<char>('0' + digit));
+  }
+  return text;
+}
+
+// Whether solution is complete and valid, and agrees with every given of
+// the puzzle
+inline bool is_sudoku_solution(const SudokuGrid& solution,
+                               const SudokuGrid& puzzle) {
+  std::array<uint16_t, 27> seen{};  // rows, then columns, then boxes
+  for (size_t cell = 0; cell < solution.size(); ++cell) {
+    const int digit = solution[cell];
+    if (digit < 1 || digit > 9 || (puzzle[cell] && puzzle[cell] != digit)) {
+      return false;
+    }
+    const auto bit = static_cast<uint16_t>(1u << digit);
+    for (size_t unit : {cell / 9, 9 + cell % 9, 18 + sudoku_box(cell)}) {
+      if (seen[unit] & bit) {
+        return false;
+      }
+      seen[unit] |= bit;
+    }
+  }
+  return true;
+}
+
+// A cell at a time, always the empty cell with the fewest digits left,
+// which finds contradictions early and leaves most easy puzzles without a
+// single wrong guess. The digits used in each row, column and box are bit
+// masks, so the constraint check is three ands.
+class SudokuSearch {
+ public:
+  struct Choice {
+    size_t cell;
+    int digit;
+  };
+
+  explicit SudokuSearch(const SudokuGrid& puzzle) : grid_(puzzle) {
+    for (size_t cell = 0; cell < grid_.size(); ++cell) {
+      const int digit = grid_[cell];
+      if (digit < 0 || digit > 9) {
+        throw std::invalid_argument("sudoku cells are 0 to 9");
+      }
+      if (digit == 0) {
+        ++empty_;
+      } else if (!accepts({cell, digit})) {
+        contradiction_ = true;
+      } else {
+        mark(cell, digit);
+      }
+    }
+  }
+
+  bool is_complete() const { return empty_ == 0; }
+
+  bool promising() const { return !contradiction_; }
+
+  std::vector<Choice> choices() const {
+    size_t best = grid_.size();
+    int fewest = 10;
+    for (size_t cell = 0; cell < grid_.size() && fewest > 0; ++cell) {
+      if (grid_[cell] == 0) {
+        const int left = std::popcount(candidates(cell));
+        if (left < fewest) {
+          best = cell;
+          fewest = left;
+        }
+      }
+    }
+    std::vector<Choice> choices;
+    for (int digit = 1; best < grid_.size() && digit <= 9; ++digit) {
+      choices.push_back({best, digit});
+    }
+    return choices;
+  }
+
+  bool accepts(const Choice& c) const {
+    return candidates(c.cell) & (1u << c.digit);
+  }
+
+  void choose(const Choice& c) {
+    grid_[c.cell] = c.digit;
+    mark(c.cell, c.digit);
+    --empty_;
+  }
+
+  void unchoose(const Choice& c) {
+    grid_[c.cell] = 0;
+    mark(c.cell, c.digit);
+    ++empty_;
+  }
+
+  const SudokuGrid& solution() const { return grid_; }
+
+ private:
+  // The digits still allowed in cell, as bits 1 to 9
+  unsigned candidates(size_t cell) const {
+    return ~(rows_[cell / 9] | cols_[cell % 9] | boxes_[sudoku_box(cell)]) &
+           0x3FEu;
+  }
+
+  // Toggles digit in the cell's row, column and box
+  void mark(size_t cell, int digit) {
+    const auto bit = static_cast<uint16_t>(1u << digit);
+    rows_[cell / 9] ^= bit;
+    cols_[cell % 9] ^= bit;
+    boxes_[sudoku_box(cell)] ^= bit;
+  }
+
+  SudokuGrid grid_;
+  std::array<uint16_t, 9> rows_{};
+  std::array<uint16_t, 9> cols_{};
+  std::array<uint16_t, 9> boxes_{};
+  size_t empty_{0};
+  bool contradiction_{false};  // two givens clash
+};
+
+inline std::optional<SudokuGrid> solve_sudoku(const SudokuGrid& puzzle) {
+  SudokuSearch search(puzzle);
+  const auto solutions = collect_solutions(search, 1);
+  if (solutions.empty()) {
+    return std::nullopt;
+  }
+  return solutions[0];
+}
+
+// Up to limit solutions: a proper puzzle has exactly one, so a limit of 2
+// tells whether it's proper
+inline size_t count_sudoku_solutions(const SudokuGrid& puzzle,
+                                     size_t limit = 2) {
+  SudokuSearch search(puzzle);
+  return collect_solutions(search, limit).size();
+}
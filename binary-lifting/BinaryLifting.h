@@ -0,0 +1,160 @@
+#pragma once
+
+#include <algorithm>
+#include <bit>
+#include <cassert>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../binary-tree/ArenaTree.h"
+
+// Binary lifting over an arena tree: for every node, its 2^j-th ancestor
+// for each j, so that climbing k levels takes one jump per set bit of k.
+// Then the k-th ancestor is O(log n), and so is the lowest common
+// ancestor: lift the deeper node to the other's depth, then lift both
+// together by the largest jumps that keep them apart.
+//
+// The arena only links parents to children, so the parents come first,
+// from a preorder walk, which reaches every parent before its children.
+// The table is O(n log n), and nothing recurses, so deep trees are fine.
+// Nodes outside the root's subtree have no ancestors and can't be asked
+// about.
+
+// Each node's parent in tree, kNoNode for the root and for nodes the root
+// doesn't reach
+template <typename T>
+std::vector<NodeIndex> arena_parents(const ArenaTree<T>& tree) {
+  std::vector<NodeIndex> parent(tree.size(), kNoNode);
+  for (NodeIndex i : arena_preorder_indices(tree)) {
+    for (NodeIndex child : {tree[i].left, tree[i].right}) {
+      if (child != kNoNode) {
+        parent[child] = i;
+      }
+    }
+  }
+  return parent;
+}
+
+class BinaryLifting {
+ public:
+  template <typename T>
+  explicit BinaryLifting(const ArenaTree<T>& tree)
+      : depth_(tree.size(), -1) {
+    const std::vector<NodeIndex> parent = arena_parents(tree);
+    const std::vector<NodeIndex> order = arena_preorder_indices(tree);
+    for (NodeIndex i : order) {
+      depth_[i] = parent[i] == kNoNode ? 0 : depth_[parent[i]] + 1;
+    }
+    // up_[j][v] is the 2^j-th ancestor of v, or kNoNode past the root
+    const size_t levels = std::max<size_t>(1, std::bit_width(order.size()));
+    up_.assign(levels, std::vector<NodeIndex>(tree.size(), kNoNode));
+    up_[0] = parent;
+    for (size_t j = 1; j < levels; ++j) {
+      for (NodeIndex v : order) {
+        const NodeIndex half = up_[j - 1][v];
+        up_[j][v] = half == kNoNode ? kNoNode : up_[j - 1][half];
+      }
+    }
+  }
+
+  size_t size() const { return depth_.size(); }
+
+  // Edges from the root down to v
+  int depth(NodeIndex v) const {
+    assert(reached(v));
+    return depth_[v];
+  }
+
+  NodeIndex parent(NodeIndex v) const {
+    assert(reached(v));
+    return up_[0][v];
+  }
+
+  // The k-th ancestor of v (v itself for k = 0), or kNoNode if v has fewer
+  // than k ancestors
+  NodeIndex ancestor(NodeIndex v, int k) const {
+    assert(reached(v) && k >= 0);
+    if (k > depth_[v]) {
+      return kNoNode;
+    }
+    for (size_t j = 0; k > 0; ++j, k >>= 1) {
+      if (k & 1) {
+        v = up_[j][v];
+      }
+    }
+    return v;
+  }
+
+  NodeIndex lca(NodeIndex u, NodeIndex v) const {
+    assert(reached(u) && reached(v));
+    if (depth_[u] < depth_[v]) {
+      std::swap(u, v);
+    }
+    u = ancestor(u, depth_[u] - depth_[v]);
+    if (u == v) {
+      return u;
+    }
+    // Take every jump that keeps u and v apart; afterwards they're children
+    // of the LCA. Jumps past the root land both on kNoNode, so they're
+    // never taken.
+    for (size_t j = up_.size(); j-- > 0;) {
+      if (up_[j][u] != up_[j][v]) {
+        u = up_[j][u];
+        v = up_[j][v];
+      }
+    }
+    return up_[0][u];
+  }
+
+  // Whether u is v or above it
+  bool is_ancestor(NodeIndex u, NodeIndex v) const {
+    return depth(u) <= depth(v) && ancestor(v, depth_[v] - depth_[u]) == u;
+  }
+
+  // Edges on the path between u and v
+  int distance(NodeIndex u, NodeIndex v) const {
+    return depth(u) + depth(v) - 2 * depth_[lca(u, v)];
+  }
+
+  // The k-th node on the path from u to v (u itself for k = 0), or kNoNode
+  // if the path is shorter than that. The path climbs from u to the LCA,
+  // then goes down to v, and the way down is v's ancestors read backwards.
+  NodeIndex kth_on_path(NodeIndex u, NodeIndex v, int k) const {
+    const NodeIndex top = lca(u, v);
+    const int up = depth_[u] - depth_[top];
+    const int down = depth_[v] - depth_[top];
+    if (k < 0 || k > up + down) {
+      return kNoNode;
+    }
+    return k <= up ? ancestor(u, k) : ancestor(v, up + down - k);
+  }
+
+ private:
+  bool reached(NodeIndex v) const { return v < size() && depth_[v] >= 0; }
+
+  std::vector<int> depth_;
+  std::vector<std::vector<NodeIndex>> up_;
+};
+
+// O(k): climb one parent at a time
+inline NodeIndex arena_ancestor_naive(const std::vector<NodeIndex>& parent,
+                                      NodeIndex v, int k) {
+  for (; k > 0 && v != kNoNode; --k) {
+    v = parent[v];
+  }
+  return v;
+}
+
+// O(n): mark u's ancestors, then climb from v to the first marked one
+inline NodeIndex arena_lca_naive(const std::vector<NodeIndex>& parent,
+                                 NodeIndex u, NodeIndex v) {
+  std::vector<bool> above_u(parent.size(), false);
+  for (NodeIndex x = u; x != kNoNode; x = parent[x]) {
+    above_u[x] = true;
+  }
+  while (!above_u[v]) {
+    v = parent[v];
+  }
+  return v;
+}
@@ -0,0 +1,136 @@
+#include <algorithm>
+#include <cstddef>
+#include <vector>
+
+#include "../binary-tree/ArenaTree.h"
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "BinaryLifting.h"
+#include "gtest/gtest.h"
+
+/*
+ * The example tree, with each node's value its index:
+ *
+ *          0
+ *        /   \
+ *       1     2
+ *      / \     \
+ *     3   4     5
+ *        / \
+ *       6   7
+ */
+ArenaTree<int> example_tree() {
+  using Node = ArenaTree<int>::Node;
+  return ArenaTree<int>({Node{0, 1, 2}, Node{1, 3, 4}, Node{2, kNoNode, 5},
+                         Node{3}, Node{4, 6, 7}, Node{5}, Node{6}, Node{7}},
+                        0);
+}
+
+TEST(BinaryLifting, Example) {
+  const BinaryLifting lifting(example_tree());
+
+  EXPECT_EQ(arena_parents(example_tree()),
+            (std::vector<NodeIndex>{kNoNode, 0, 0, 1, 1, 2, 4, 4}));
+  EXPECT_EQ(lifting.depth(0), 0);
+  EXPECT_EQ(lifting.depth(7), 3);
+
+  EXPECT_EQ(lifting.ancestor(7, 0), 7u);
+  EXPECT_EQ(lifting.ancestor(7, 1), 4u);
+  EXPECT_EQ(lifting.ancestor(7, 3), 0u);
+  EXPECT_EQ(lifting.ancestor(7, 4), kNoNode);
+
+  EXPECT_EQ(lifting.lca(6, 7), 4u);
+  EXPECT_EQ(lifting.lca(6, 3), 1u);
+  EXPECT_EQ(lifting.lca(7, 5), 0u);
+  EXPECT_EQ(lifting.lca(1, 6), 1u);
+  EXPECT_EQ(lifting.distance(6, 5), 5);
+
+  EXPECT_TRUE(lifting.is_ancestor(1, 7));
+  EXPECT_TRUE(lifting.is_ancestor(7, 7));
+  EXPECT_FALSE(lifting.is_ancestor(7, 1));
+  EXPECT_FALSE(lifting.is_ancestor(2, 4));
+
+  // 6 4 1 0 2 5
+  std::vector<NodeIndex> path;
+  for (int k = 0; k <= lifting.distance(6, 5); ++k) {
+    path.push_back(lifting.kth_on_path(6, 5, k));
+  }
+  EXPECT_EQ(path, (std::vector<NodeIndex>{6, 4, 1, 0, 2, 5}));
+  EXPECT_EQ(lifting.kth_on_path(6, 5, 6), kNoNode);
+  EXPECT_EQ(lifting.kth_on_path(3, 3, 0), 3u);
+}
+
+TEST(BinaryLifting, SingleNode) {
+  ArenaTree<int> tree;
+  tree.add(0);
+  const BinaryLifting lifting(tree);
+  EXPECT_EQ(lifting.ancestor(0, 0), 0u);
+  EXPECT_EQ(lifting.ancestor(0, 1), kNoNode);
+  EXPECT_EQ(lifting.lca(0, 0), 0u);
+}
+
+// A path a million nodes deep: every query is a handful of jumps
+TEST(BinaryLifting, DeepTree) {
+  const int n = 1000000;
+  ArenaTree<int> tree;
+  tree.reserve(n);
+  NodeIndex below = kNoNode;
+  for (int i = 0; i < n; ++i) {
+    below = tree.add(i, below);
+  }
+  // Node 0 is the bottom, and node n - 1 the root
+  const BinaryLifting lifting(tree);
+  EXPECT_EQ(lifting.depth(0), n - 1);
+  EXPECT_EQ(lifting.ancestor(0, n - 1), static_cast<NodeIndex>(n - 1));
+  EXPECT_EQ(lifting.ancestor(0, 123456), 123456u);
+  EXPECT_EQ(lifting.lca(10, 999), 999u);
+  EXPECT_EQ(lifting.kth_on_path(5, 15, 4), 9u);
+}
+
+// Every node, every k, and every pair, on trees small enough to check them
+// all: uniformly random shapes, and BSTs of random insertions
+TEST(BinaryLifting, Exhaustively) {
+  Rng gen(213);
+  for (int trial = 0; trial < 200; ++trial) {
+    const int n = 1 + static_cast<int>(uniform_below(gen, 30));
+    const auto tree = from_tree((trial % 2 == 0 ? random_binary_tree(n, gen)
+                                                : random_bst(n, gen))
+                                    .get());
+    const std::vector<NodeIndex> parent = arena_parents(tree);
+    const BinaryLifting lifting(tree);
+
+    for (NodeIndex v = 0; v < tree.size(); ++v) {
+      for (int k = 0; k <= n; ++k) {
+        ASSERT_EQ(lifting.ancestor(v, k), arena_ancestor_naive(parent, v, k));
+      }
+    }
+    for (NodeIndex u = 0; u < tree.size(); ++u) {
+      for (NodeIndex v = 0; v < tree.size(); ++v) {
+        const NodeIndex top = arena_lca_naive(parent, u, v);
+        ASSERT_EQ(lifting.lca(u, v), top);
+        ASSERT_EQ(lifting.is_ancestor(u, v), top == u);
+
+        // The path is u's ancestors up to the LCA, then v's back down
+        std::vector<NodeIndex> path;
+        for (NodeIndex x = u; x != top; x = parent[x]) {
+          path.push_back(x);
+        }
+        const size_t turn = path.size();
+        for (NodeIndex x = v; x != top; x = parent[x]) {
+          path.push_back(x);
+        }
+        path.push_back(top);
+        std::reverse(path.begin() + turn, path.end());
+        ASSERT_EQ(lifting.distance(u, v), static_cast<int>(path.size()) - 1);
+        for (size_t k = 0; k < path.size(); ++k) {
+          ASSERT_EQ(lifting.kth_on_path(u, v, static_cast<int>(k)), path[k]);
+        }
+      }
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,254 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <utility>
+#include <vector>
+
+#include "TreeNode.h"
+
+// A binary tree stored as one vector of nodes, with children as indices
+// into it instead of owning pointers. One allocation for the whole tree
+// instead of one per node, nodes next to each other in memory, and 4-byte
+// links instead of 8-byte pointers. Nothing here recurses, so trees of
+// millions of nodes, however deep, are fine: every traversal is a loop,
+// running over an explicit stack or straight down the vector.
+
+using NodeIndex = uint32_t;
+constexpr NodeIndex kNoNode = UINT32_MAX;
+
+template <typename T>
+class ArenaTree {
+ public:
+  struct Node {
+    T val;
+    NodeIndex left = kNoNode;
+    NodeIndex right = kNoNode;
+  };
+
+  ArenaTree() = default;
+
+  // The nodes as they are, links and all
+  ArenaTree(std::vector<Node> nodes, NodeIndex root)
+      : nodes_(std::move(nodes)), root_(root) {
+    assert(nodes_.size() <= kNoNode && "too many nodes");
+    assert((root_ == kNoNode || root_ < nodes_.size()) && "no such root");
+  }
+
+  // Adds a node, with children already in the tree, and returns its index.
+  // The last node added becomes the root unless set_root says otherwise.
+  NodeIndex add(T val, NodeIndex left = kNoNode, NodeIndex right = kNoNode) {
+    assert(nodes_.size() < kNoNode && "too many nodes");
+    assert((left == kNoNode || left < nodes_.size()) && "no such left child");
+    assert((right == kNoNode || right < nodes_.size()) &&
+           "no such right child");
+    nodes_.push_back({std::move(val), left, right});
+    root_ = static_cast<NodeIndex>(nodes_.size() - 1);
+    return root_;
+  }
+
+  void set_root(NodeIndex root) { root_ = root; }
+  void reserve(size_t n) { nodes_.reserve(n); }
+
+  NodeIndex root() const { return root_; }
+  size_t size() const { return nodes_.size(); }
+  bool empty() const { return root_ == kNoNode; }
+
+  const Node& operator[](NodeIndex i) const { return nodes_[i]; }
+  Node& operator[](NodeIndex i) { return nodes_[i]; }
+
+ private:
+  std::vector<Node> nodes_;
+  NodeIndex root_ = kNoNode;
+};
+
+// The indices of root's subtree in preorder, the order from_tree lays
+// nodes out in. A stack of the right subtrees still to visit.
+template <typename T>
+std::vector<NodeIndex> arena_preorder_indices(const ArenaTree<T>& tree) {
+  std::vector<NodeIndex> order;
+  std::vector<NodeIndex> stack;
+  if (!tree.empty()) {
+    stack.push_back(tree.root());
+  }
+  while (!stack.empty()) {
+    const NodeIndex i = stack.back();
+    stack.pop_back();
+    order.push_back(i);
+    if (tree[i].right != kNoNode) {
+      stack.push_back(tree[i].right);
+    }
+    if (tree[i].left != kNoNode) {
+      stack.push_back(tree[i].left);
+    }
+  }
+  return order;
+}
+
+// Postorder is preorder with the children swapped, backwards
+template <typename T>
+std::vector<NodeIndex> arena_postorder_indices(const ArenaTree<T>& tree) {
+  std::vector<NodeIndex> order;
+  std::vector<NodeIndex> stack;
+  if (!tree.empty()) {
+    stack.push_back(tree.root());
+  }
+  while (!stack.empty()) {
+    const NodeIndex i = stack.back();
+    stack.pop_back();
+    order.push_back(i);
+    if (tree[i].left != kNoNode) {
+      stack.push_back(tree[i].left);
+    }
+    if (tree[i].right != kNoNode) {
+      stack.push_back(tree[i].right);
+    }
+  }
+  std::reverse(order.begin(), order.end());
+  return order;
+}
+
+// Walk left as far as possible, stacking the nodes passed; visit the top
+// of the stack and go right once
+template <typename T>
+std::vector<NodeIndex> arena_inorder_indices(const ArenaTree<T>& tree) {
+  std::vector<NodeIndex> order;
+  std::vector<NodeIndex> stack;
+  for (NodeIndex i = tree.root(); i != kNoNode || !stack.empty();) {
+    if (i != kNoNode) {
+      stack.push_back(i);
+      i = tree[i].left;
+      continue;
+    }
+    i = stack.back();
+    stack.pop_back();
+    order.push_back(i);
+    i = tree[i].right;
+  }
+  return order;
+}
+
+template <typename T>
+std::vector<T> values_at(const ArenaTree<T>& tree,
+                         const std::vector<NodeIndex>& order) {
+  std::vector<T> values;
+  values.reserve(order.size());
+  for (NodeIndex i : order) {
+    values.push_back(tree[i].val);
+  }
+  return values;
+}
+
+template <typename T>
+std::vector<T> preorder(const ArenaTree<T>& tree) {
+  return values_at(tree, arena_preorder_indices(tree));
+}
+
+template <typename T>
+std::vector<T> inorder(const ArenaTree<T>& tree) {
+  return values_at(tree, arena_inorder_indices(tree));
+}
+
+template <typename T>
+std::vector<T> postorder(const ArenaTree<T>& tree) {
+  return values_at(tree, arena_postorder_indices(tree));
+}
+
+// Balance factors in postorder, as compute_balance_factors gives them. In
+// postorder, both children come before their parent, so one pass down the
+// order has every height ready when it's needed.
+template <typename T>
+std::vector<int> compute_balance_factors(const ArenaTree<T>& tree) {
+  std::vector<int> heights(tree.size(), 0);
+  auto height_of = [&](NodeIndex i) { return i == kNoNode ? 0 : heights[i]; };
+  std::vector<int> factors;
+  for (NodeIndex i : arena_postorder_indices(tree)) {
+    const int left = height_of(tree[i].left);
+    const int right = height_of(tree[i].right);
+    heights[i] = 1 + std::max(left, right);
+    factors.push_back(left - right);
+  }
+  return factors;
+}
+
+template <typename T>
+int height(const ArenaTree<T>& tree) {
+  std::vector<int> heights(tree.size(), 0);
+  auto height_of = [&](NodeIndex i) { return i == kNoNode ? 0 : heights[i]; };
+  for (NodeIndex i : arena_postorder_indices(tree)) {
+    heights[i] =
+        1 + std::max(height_of(tree[i].left), height_of(tree[i].right));
+  }
+  return height_of(tree.root());
+}
+
+// Copies a TreeNode tree into an arena, in preorder, so that every subtree
+// is one contiguous run of nodes, starting with its root. A node's parent
+// is always copied first, so each stacked node remembers the link in its
+// parent that should point to it.
+template <typename T>
+ArenaTree<T> from_tree(const TreeNode<T>* root) {
+  if (root == nullptr) {
+    return ArenaTree<T>();
+  }
+  struct Pending {
+    const TreeNode<T>* node;
+    NodeIndex parent;
+    bool is_left;
+  };
+  std::vector<typename ArenaTree<T>::Node> nodes;
+  std::vector<Pending> stack = {{root, kNoNode, false}};
+  while (!stack.empty()) {
+    const Pending p = stack.back();
+    stack.pop_back();
+    const auto i = static_cast<NodeIndex>(nodes.size());
+    nodes.push_back({p.node->val, kNoNode, kNoNode});
+    if (p.parent != kNoNode) {
+      (p.is_left ? nodes[p.parent].left : nodes[p.parent].right) = i;
+    }
+    if (p.node->right) {
+      stack.push_back({p.node->right.get(), i, false});
+    }
+    if (p.node->left) {
+      stack.push_back({p.node->left.get(), i, true});
+    }
+  }
+  return ArenaTree<T>(std::move(nodes), 0);
+}
+
+// And back: children before parents, so postorder, with each node's
+// subtrees waiting in `built` until their parent takes them
+template <typename T>
+std::unique_ptr<TreeNode<T>> to_tree(const ArenaTree<T>& tree) {
+  if (tree.empty()) {
+    return nullptr;
+  }
+  std::vector<std::unique_ptr<TreeNode<T>>> built(tree.size());
+  auto take = [&](NodeIndex i) {
+    return i == kNoNode ? nullptr : std::move(built[i]);
+  };
+  for (NodeIndex i : arena_postorder_indices(tree)) {
+    built[i] = make_node(tree[i].val, take(tree[i].left), take(tree[i].right));
+  }
+  return std::move(built[tree.root()]);
+}
+
+// Frees a TreeNode tree without recursing: unique_ptr's destructor
+// recurses once per level, which overflows the stack on a deep enough
+// tree. Each step detaches the root's children and lets the root go.
+template <typename T>
+void destroy_tree(std::unique_ptr<TreeNode<T>> root) {
+  std::vector<std::unique_ptr<TreeNode<T>>> stack;
+  stack.push_back(std::move(root));
+  while (!stack.empty()) {
+    auto node = std::move(stack.back());
+    stack.pop_back();
+    if (node != nullptr) {
+      stack.push_back(std::move(node->left));
+      stack.push_back(std::move(node->right));
+    }
+  }
+}
@@ -0,0 +1,51 @@
+#pragma once
+
+#include <algorithm>
+#include <vector>
+
+#include "TreeNode.h"
+
+// Balance factors (height of the left subtree minus height of the right
+// subtree) of every node, in postorder. The same induction as
+// manber/balance_factors.cpp, for any TreeNode<T>: computing a node's
+// balance factor needs its children's heights, so return the height from
+// the recursion too.
+
+template <typename T>
+int compute_heights_and_balance_factors(const TreeNode<T>* node,
+                                        std::vector<int>& result) {
+  if (node == nullptr) {
+    return 0;
+  }
+
+  int left_height =
+      compute_heights_and_balance_factors(node->left.get(), result);
+  int right_height =
+      compute_heights_and_balance_factors(node->right.get(), result);
+
+  result.push_back(left_height - right_height);
+
+  return 1 + std::max(left_height, right_height);
+}
+
+template <typename T>
+std::vector<int> compute_balance_factors(const TreeNode<T>* root) {
+  std::vector<int> result;
+  compute_heights_and_balance_factors(root, result);
+  return result;
+}
+
+// Number of nodes on the longest root-to-leaf path; 0 for the empty tree
+template <typename T>
+int height(const TreeNode<T>* root) {
+  std::vector<int> unused;
+  return compute_heights_and_balance_factors(root, unused);
+}
+
+// Every node's balance factor is -1, 0, or 1, as in an AVL tree
+template <typename T>
+bool is_height_balanced(const TreeNode<T>* root) {
+  auto factors = compute_balance_factors(root);
+  return std::all_of(factors.begin(), factors.end(),
+                     [](int factor) { return factor >= -1 && factor <= 1; });
+}
@@ -0,0 +1,568 @@
+#include <algorithm>
+#include <climits>
+#include <functional>
+#include <memory>
+#include <random>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "ArenaTree.h"
+#include "BalanceFactors.h"
+#include "Bst.h"
+#include "Morris.h"
+#include "OptimalBst.h"
+#include "Reconstruct.h"
+#include "Serialize.h"
+#include "Traversal.h"
+#include "TreeNode.h"
+#include "gtest/gtest.h"
+
+/*
+ *       1
+ *      / \
+ *     2   3
+ *        / \
+ *       4   5
+ */
+std::unique_ptr<TreeNode<int>> example_tree() {
+  return make_node(1, make_node(2),
+                   make_node(3, make_node(4), make_node(5)));
+}
+
+// Random shape: each new node takes a random free child slot reached by a
+// random walk down from the root
+std::unique_ptr<TreeNode<int>> random_tree(std::mt19937& gen, size_t n) {
+  std::unique_ptr<TreeNode<int>> root;
+  std::uniform_int_distribution<int> value(-100, 100);
+  std::bernoulli_distribution go_left(0.5);
+  for (size_t i = 0; i < n; ++i) {
+    auto* slot = &root;
+    while (*slot != nullptr) {
+      slot = go_left(gen) ? &(*slot)->left : &(*slot)->right;
+    }
+    *slot = make_node(value(gen));
+  }
+  return root;
+}
+
+// Random shape whose values are a permutation of 0..n-1
+std::unique_ptr<TreeNode<int>> random_distinct_tree(std::mt19937& gen,
+                                                    size_t n) {
+  auto root = random_tree(gen, n);
+  std::vector<int> values(n);
+  for (size_t i = 0; i < n; ++i) {
+    values[i] = static_cast<int>(i);
+  }
+  std::shuffle(values.begin(), values.end(), gen);
+  size_t next = 0;
+  std::function<void(TreeNode<int>*)> relabel = [&](TreeNode<int>* node) {
+    if (node != nullptr) {
+      node->val = values[next++];
+      relabel(node->left.get());
+      relabel(node->right.get());
+    }
+  };
+  relabel(root.get());
+  return root;
+}
+
+TEST(TreeNode, SameTree) {
+  auto a = example_tree();
+  auto b = example_tree();
+  auto mirrored = make_node(1, make_node(3, make_node(4), make_node(5)),
+                            make_node(2));
+
+  EXPECT_TRUE(same_tree(a.get(), b.get()));
+  EXPECT_FALSE(same_tree(a.get(), mirrored.get()));
+  b->right->left->val = 6;
+  EXPECT_FALSE(same_tree(a.get(), b.get()));
+  EXPECT_TRUE(same_tree<int>(nullptr, nullptr));
+}
+
+TEST(Serialize, Example) {
+  EXPECT_EQ(serialize(example_tree()), "1,2,3,#,#,4,5");
+  EXPECT_EQ(serialize<int>(nullptr), "");
+  EXPECT_EQ(serialize(make_node(7)), "7");
+  EXPECT_EQ(serialize(make_node(1, nullptr, make_node(2))), "1,#,2");
+}
+
+TEST(Serialize, Deserialize) {
+  auto tree = deserialize<int>("1,2,3,#,#,4,5");
+
+  EXPECT_TRUE(same_tree(tree.get(), example_tree().get()));
+  EXPECT_EQ(deserialize<int>(""), nullptr);
+  // Trailing '#'s are optional
+  EXPECT_TRUE(same_tree(deserialize<int>("1,2,3,#,#,4,5,#,#,#,#").get(),
+                        tree.get()));
+}
+
+TEST(Serialize, OtherValueTypes) {
+  auto words = make_node(std::string("root"), make_node(std::string("left")),
+                         nullptr);
+  auto reals = make_node(-1.5, nullptr, make_node(0.25));
+
+  EXPECT_EQ(serialize(words), "root,left");
+  EXPECT_TRUE(same_tree(deserialize<std::string>("root,left").get(),
+                        words.get()));
+  EXPECT_TRUE(same_tree(deserialize<double>(serialize(reals)).get(),
+                        reals.get()));
+}
+
+TEST(Serialize, MalformedInputThrows) {
+  EXPECT_THROW(deserialize<int>("#"), std::invalid_argument);
+  EXPECT_THROW(deserialize<int>("1,x"), std::invalid_argument);
+  EXPECT_THROW(deserialize<int>("1,2,"), std::invalid_argument);
+  EXPECT_THROW(deserialize<int>("1,,2"), std::invalid_argument);
+  EXPECT_THROW(deserialize<int>("1,2z"), std::invalid_argument);
+  // The root has two child slots, and '#' children have none
+  EXPECT_THROW(deserialize<int>("1,#,#,2"), std::invalid_argument);
+}
+
+TEST(Serialize, RoundTripsRandomTrees) {
+  std::mt19937 gen(129);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(0, 50)(gen);
+    auto tree = random_tree(gen, n);
+    std::string data = serialize(tree);
+    auto copy = deserialize<int>(data);
+
+    ASSERT_TRUE(same_tree(tree.get(), copy.get())) << data;
+    ASSERT_EQ(serialize(copy), data);
+  }
+}
+
+TEST(Traversal, Example) {
+  auto tree = example_tree();
+
+  EXPECT_EQ(preorder(tree.get()), (std::vector<int>{1, 2, 3, 4, 5}));
+  EXPECT_EQ(inorder(tree.get()), (std::vector<int>{2, 1, 4, 3, 5}));
+  EXPECT_EQ(postorder(tree.get()), (std::vector<int>{2, 4, 5, 3, 1}));
+  EXPECT_TRUE(inorder<int>(nullptr).empty());
+}
+
+TEST(Traversal, IterativeMatchesRecursive) {
+  auto tree = example_tree();
+  EXPECT_EQ(preorder_iterative(tree.get()), preorder(tree.get()));
+  EXPECT_EQ(inorder_iterative(tree.get()), inorder(tree.get()));
+  EXPECT_EQ(postorder_iterative(tree.get()), postorder(tree.get()));
+  EXPECT_TRUE(postorder_iterative<int>(nullptr).empty());
+
+  std::mt19937 gen(179);
+  for (int trial = 0; trial < 200; ++trial) {
+    auto random = random_tree(gen, gen() % 50);
+    ASSERT_EQ(preorder_iterative(random.get()), preorder(random.get()));
+    ASSERT_EQ(inorder_iterative(random.get()), inorder(random.get()));
+    ASSERT_EQ(postorder_iterative(random.get()), postorder(random.get()));
+  }
+}
+
+TEST(Traversal, IterativeDeepZigzag) {
+  // Deep enough to overflow the call stack if traversed recursively
+  const int n = 1000000;
+  auto tree = make_node(0);
+  TreeNode<int>* bottom = tree.get();
+  for (int i = 1; i < n; ++i) {
+    auto& child = i % 2 == 0 ? bottom->left : bottom->right;
+    child = make_node(i);
+    bottom = child.get();
+  }
+  const auto values = preorder_iterative(tree.get());
+  ASSERT_EQ(values.size(), static_cast<size_t>(n));
+  EXPECT_EQ(values.back(), n - 1);
+  EXPECT_EQ(postorder_iterative(tree.get()).front(), n - 1);
+
+  // So is destroying it, which unique_ptr does recursively
+  destroy_tree(std::move(tree));
+}
+
+TEST(Reconstruct, FromPreorderInorder) {
+  auto tree = build_tree_from_preorder_inorder<int>({1, 2, 3, 4, 5},
+                                                    {2, 1, 4, 3, 5});
+
+  EXPECT_TRUE(same_tree(tree.get(), example_tree().get()));
+  EXPECT_EQ(build_tree_from_preorder_inorder<int>({}, {}), nullptr);
+}
+
+TEST(Reconstruct, FromPostorderInorder) {
+  auto tree = build_tree_from_postorder_inorder<int>({2, 4, 5, 3, 1},
+                                                     {2, 1, 4, 3, 5});
+
+  EXPECT_TRUE(same_tree(tree.get(), example_tree().get()));
+  EXPECT_EQ(build_tree_from_postorder_inorder<int>({}, {}), nullptr);
+}
+
+TEST(Reconstruct, InorderFixesTheSideOfAnOnlyChild) {
+  auto left = build_tree_from_preorder_inorder<int>({1, 2}, {2, 1});
+  auto right = build_tree_from_preorder_inorder<int>({1, 2}, {1, 2});
+
+  EXPECT_EQ(serialize(left), "1,2");
+  EXPECT_EQ(serialize(right), "1,#,2");
+}
+
+TEST(Reconstruct, InconsistentInputThrows) {
+  using Values = std::vector<int>;
+
+  // Different lengths, repeated values, and values missing from inorder
+  EXPECT_THROW(build_tree_from_preorder_inorder<int>({1, 2}, {1}),
+               std::invalid_argument);
+  EXPECT_THROW(build_tree_from_preorder_inorder<int>({1, 1}, {1, 1}),
+               std::invalid_argument);
+  EXPECT_THROW(build_tree_from_preorder_inorder<int>({1, 3}, {1, 2}),
+               std::invalid_argument);
+  EXPECT_THROW(build_tree_from_postorder_inorder<int>({3, 1}, {1, 2}),
+               std::invalid_argument);
+  // Same values, but no tree has both traversals. With root 2, the inorder
+  // puts 1 in the left subtree, so the preorder must visit 1 before 3.
+  EXPECT_THROW(
+      build_tree_from_preorder_inorder(Values{2, 3, 1}, Values{1, 2, 3}),
+      std::invalid_argument);
+  EXPECT_THROW(
+      build_tree_from_postorder_inorder(Values{3, 1, 2}, Values{1, 2, 3}),
+      std::invalid_argument);
+}
+
+TEST(Reconstruct, RoundTripsRandomTrees) {
+  std::mt19937 gen(130);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(0, 60)(gen);
+    auto tree = random_distinct_tree(gen, n);
+    auto pre = preorder(tree.get());
+    auto in = inorder(tree.get());
+    auto post = postorder(tree.get());
+
+    auto from_pre = build_tree_from_preorder_inorder(pre, in);
+    auto from_post = build_tree_from_postorder_inorder(post, in);
+
+    ASSERT_TRUE(same_tree(from_pre.get(), tree.get()));
+    ASSERT_TRUE(same_tree(from_post.get(), tree.get()));
+    ASSERT_EQ(postorder(from_pre.get()), post);
+    ASSERT_EQ(preorder(from_post.get()), pre);
+  }
+}
+
+TEST(Reconstruct, RejectsShuffledTraversals) {
+  // Permuting a preorder usually breaks it. Whatever is accepted must really
+  // have those traversals.
+  std::mt19937 gen(131);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(1, 8)(gen);
+    auto tree = random_distinct_tree(gen, n);
+    auto pre = preorder(tree.get());
+    auto in = inorder(tree.get());
+    std::shuffle(pre.begin(), pre.end(), gen);
+
+    try {
+      auto rebuilt = build_tree_from_preorder_inorder(pre, in);
+      ASSERT_EQ(preorder(rebuilt.get()), pre);
+      ASSERT_EQ(inorder(rebuilt.get()), in);
+    } catch (const std::invalid_argument&) {
+    }
+  }
+}
+
+TEST(BalanceFactors, Example) {
+  auto tree = example_tree();
+
+  // Postorder: 2, 4, 5, 3, 1
+  EXPECT_EQ(compute_balance_factors(tree.get()),
+            (std::vector<int>{0, 0, 0, 0, -1}));
+  EXPECT_EQ(height(tree.get()), 3);
+  EXPECT_EQ(height<int>(nullptr), 0);
+  EXPECT_TRUE(is_height_balanced(tree.get()));
+
+  tree->right->right->right = make_node(6);
+  EXPECT_FALSE(is_height_balanced(tree.get()));
+}
+
+TEST(Bst, IsValidBst) {
+  auto bst = make_node(4, make_node(2, make_node(1), make_node(3)),
+                       make_node(6, nullptr, make_node(7)));
+
+  EXPECT_TRUE(is_valid_bst(bst.get()));
+  EXPECT_TRUE(is_valid_bst<int>(nullptr));
+  EXPECT_FALSE(is_valid_bst(example_tree().get()));
+}
+
+TEST(Bst, ChecksWholeSubtreesNotJustChildren) {
+  // Every parent-child pair is in order, but 4 is in the left subtree of 3
+  auto tree = make_node(3, make_node(1, nullptr, make_node(4)), nullptr);
+
+  EXPECT_FALSE(is_valid_bst(tree.get()));
+}
+
+TEST(Bst, RejectsDuplicates) {
+  EXPECT_FALSE(is_valid_bst(make_node(2, make_node(2), nullptr).get()));
+  EXPECT_FALSE(is_valid_bst(make_node(2, nullptr, make_node(2)).get()));
+}
+
+TEST(Bst, ExtremeValues) {
+  auto tree = make_node(0, make_node(INT_MIN), make_node(INT_MAX));
+  auto only_min = make_node(INT_MIN);
+  auto bad = make_node(INT_MIN, make_node(INT_MIN), nullptr);
+
+  EXPECT_TRUE(is_valid_bst(tree.get()));
+  EXPECT_TRUE(is_valid_bst(only_min.get()));
+  EXPECT_FALSE(is_valid_bst(bad.get()));
+}
+
+TEST(Bst, CustomComparator) {
+  auto descending = make_node(2, make_node(3), make_node(1));
+
+  EXPECT_FALSE(is_valid_bst(descending.get()));
+  EXPECT_TRUE((is_valid_bst<int, std::greater<int>>(descending.get())));
+}
+
+TEST(Bst, BalancedFromSorted) {
+  auto tree = balanced_bst_from_sorted(std::vector<int>{1, 2, 3, 4, 5, 6, 7});
+
+  EXPECT_EQ(serialize(tree), "4,2,6,1,3,5,7");
+  EXPECT_EQ(balanced_bst_from_sorted(std::vector<int>{}), nullptr);
+}
+
+TEST(Bst, BalancedFromSortedIsBalancedForEverySize) {
+  for (int n = 0; n <= 300; ++n) {
+    std::vector<int> sorted(n);
+    for (int i = 0; i < n; ++i) {
+      sorted[i] = 3 * i - 100;
+    }
+    auto tree = balanced_bst_from_sorted(sorted);
+    auto factors = compute_balance_factors(tree.get());
+
+    ASSERT_TRUE(is_valid_bst(tree.get()));
+    ASSERT_EQ(inorder(tree.get()), sorted);
+    ASSERT_EQ(factors.size(), sorted.size());
+    for (int factor : factors) {
+      ASSERT_GE(factor, -1);
+      ASSERT_LE(factor, 1);
+    }
+    // Minimum possible height: ceil(log2(n + 1))
+    int expected_height = 0;
+    while ((1 << expected_height) < n + 1) {
+      ++expected_height;
+    }
+    ASSERT_EQ(height(tree.get()), expected_height);
+  }
+}
+
+TEST(Bst, RandomTreesMatchInorderCheck) {
+  // A tree is a BST exactly when its inorder traversal is strictly increasing
+  std::mt19937 gen(131);
+  for (int trial = 0; trial < 500; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(0, 6)(gen);
+    auto tree = random_tree(gen, n);
+    auto values = inorder(tree.get());
+    bool increasing = std::adjacent_find(values.begin(), values.end(),
+                                         std::greater_equal<int>()) ==
+                      values.end();
+
+    ASSERT_EQ(is_valid_bst(tree.get()), increasing) << serialize(tree);
+  }
+}
+
+TEST(Morris, Example) {
+  auto tree = example_tree();
+
+  EXPECT_EQ(morris_inorder(tree.get()), (std::vector<int>{2, 1, 4, 3, 5}));
+  EXPECT_TRUE(same_tree(tree.get(), example_tree().get()));
+  EXPECT_TRUE(morris_inorder<int>(nullptr).empty());
+}
+
+TEST(Morris, MatchesRecursiveInorderAndRestoresTree) {
+  std::mt19937 gen(132);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(0, 100)(gen);
+    auto tree = random_tree(gen, n);
+    auto copy = deserialize<int>(serialize(tree));
+
+    ASSERT_EQ(morris_inorder(tree.get()), inorder(copy.get()));
+    ASSERT_TRUE(same_tree(tree.get(), copy.get()));
+  }
+}
+
+TEST(Morris, RestoresTreeWhenVisitThrows) {
+  auto tree = balanced_bst_from_sorted(std::vector<int>{1, 2, 3, 4, 5, 6, 7});
+  std::vector<int> seen;
+  auto visit = [&](int val) {
+    if (val == 3) {
+      throw std::runtime_error("stop");
+    }
+    seen.push_back(val);
+  };
+
+  EXPECT_THROW(morris_inorder(tree.get(), visit), std::runtime_error);
+  EXPECT_EQ(seen, (std::vector<int>{1, 2}));
+  EXPECT_EQ(serialize(tree), "4,2,6,1,3,5,7");
+}
+
+TEST(Morris, DeepLeftSpine) {
+  // Every node gets threaded before the first one is visited
+  const int n = 20000;
+  auto tree = make_node(n - 1);
+  TreeNode<int>* bottom = tree.get();
+  for (int i = n - 2; i >= 0; --i) {
+    bottom->left = make_node(i);
+    bottom = bottom->left.get();
+  }
+  auto values = morris_inorder(tree.get());
+
+  ASSERT_EQ(values.size(), static_cast<size_t>(n));
+  for (int i = 0; i < n; ++i) {
+    ASSERT_EQ(values[i], i);
+  }
+  EXPECT_EQ(height(tree.get()), n);
+}
+
+TEST(ArenaTree, BuildAndTraverse) {
+  // example_tree(), children first
+  ArenaTree<int> tree;
+  const NodeIndex two = tree.add(2);
+  const NodeIndex four = tree.add(4);
+  const NodeIndex five = tree.add(5);
+  const NodeIndex three = tree.add(3, four, five);
+  tree.add(1, two, three);
+
+  EXPECT_EQ(tree.size(), 5u);
+  EXPECT_EQ(tree[tree.root()].val, 1);
+  EXPECT_EQ(preorder(tree), (std::vector<int>{1, 2, 3, 4, 5}));
+  EXPECT_EQ(inorder(tree), (std::vector<int>{2, 1, 4, 3, 5}));
+  EXPECT_EQ(postorder(tree), (std::vector<int>{2, 4, 5, 3, 1}));
+  EXPECT_EQ(height(tree), 3);
+  EXPECT_TRUE(same_tree(to_tree(tree).get(), example_tree().get()));
+
+  // Laid out in preorder
+  const auto copy = from_tree(example_tree().get());
+  for (NodeIndex i = 0; i < copy.size(); ++i) {
+    EXPECT_EQ(copy[i].val, static_cast<int>(i) + 1);
+  }
+  EXPECT_TRUE(ArenaTree<int>().empty());
+  EXPECT_EQ(to_tree(ArenaTree<int>()), nullptr);
+  EXPECT_EQ(height(ArenaTree<int>()), 0);
+}
+
+TEST(ArenaTree, MatchesTreeNode) {
+  std::mt19937 gen(184);
+  for (int trial = 0; trial < 200; ++trial) {
+    const auto root = random_tree(gen, gen() % 60);
+    const auto tree = from_tree(root.get());
+    ASSERT_TRUE(same_tree(to_tree(tree).get(), root.get()));
+    ASSERT_EQ(preorder(tree), preorder(root.get()));
+    ASSERT_EQ(inorder(tree), inorder(root.get()));
+    ASSERT_EQ(postorder(tree), postorder(root.get()));
+    ASSERT_EQ(compute_balance_factors(tree),
+              compute_balance_factors(root.get()));
+    ASSERT_EQ(height(tree), height(root.get()));
+  }
+}
+
+TEST(ArenaTree, MillionsOfNodes) {
+  // A path a million deep: every recursive algorithm would overflow
+  const NodeIndex n = 1000000;
+  ArenaTree<NodeIndex> path;
+  path.reserve(n);
+  NodeIndex below = kNoNode;
+  for (NodeIndex i = n; i-- > 0;) {
+    below = i % 2 == 0 ? path.add(i, below, kNoNode)
+                       : path.add(i, kNoNode, below);
+  }
+  EXPECT_EQ(height(path), static_cast<int>(n));
+  const auto values = preorder(path);
+  ASSERT_EQ(values.size(), n);
+  EXPECT_EQ(values.front(), 0u);
+  EXPECT_EQ(values.back(), n - 1);
+
+  // And back and forth through TreeNode, which can't be freed recursively
+  auto root = to_tree(path);
+  const auto copy = from_tree(root.get());
+  destroy_tree(std::move(root));
+  EXPECT_EQ(inorder(copy), inorder(path));
+
+  // Two million nodes, complete, heap-numbered
+  const NodeIndex m = 2000000;
+  std::vector<ArenaTree<NodeIndex>::Node> nodes(m);
+  for (NodeIndex i = 0; i < m; ++i) {
+    nodes[i].val = i;
+    nodes[i].left = 2 * i + 1 < m ? 2 * i + 1 : kNoNode;
+    nodes[i].right = 2 * i + 2 < m ? 2 * i + 2 : kNoNode;
+  }
+  const ArenaTree<NodeIndex> complete(std::move(nodes), 0);
+  EXPECT_EQ(height(complete), 21);
+  const auto factors = compute_balance_factors(complete);
+  EXPECT_TRUE(std::all_of(factors.begin(), factors.end(),
+                          [](int f) { return f == 0 || f == 1; }));
+}
+
+// sum(freq[key] * depth) / sum(freq) for a tree whose keys index freq
+double expected_depth(const TreeNode<int>* root,
+                      const std::vector<double>& freq) {
+  std::function<double(const TreeNode<int>*, int)> weighted =
+      [&](const TreeNode<int>* node, int depth) -> double {
+    if (node == nullptr) {
+      return 0.0;
+    }
+    return freq[node->val] * depth + weighted(node->left.get(), depth + 1) +
+           weighted(node->right.get(), depth + 1);
+  };
+  double total = 0.0;
+  for (double f : freq) {
+    total += f;
+  }
+  return weighted(root, 1) / total;
+}
+
+TEST(OptimalBst, Example) {
+  // 20 is looked up most often, so it goes at the root even though it's the
+  // largest key
+  auto result = optimal_bst<int>({10, 12, 20}, {34, 8, 50});
+
+  EXPECT_NEAR(result.expected_cost, 142.0 / 92.0, 1e-12);
+  EXPECT_EQ(serialize(result.tree), "20,10,#,#,12");
+}
+
+TEST(OptimalBst, UniformFrequenciesGiveABalancedTree) {
+  std::vector<int> keys = {1, 2, 3, 4, 5, 6, 7};
+  auto result = optimal_bst(keys, std::vector<double>(7, 1.0));
+
+  EXPECT_EQ(serialize(result.tree), "4,2,6,1,3,5,7");
+  EXPECT_NEAR(result.expected_cost, (1 + 2 * 2 + 4 * 3) / 7.0, 1e-12);
+}
+
+TEST(OptimalBst, EdgeCases) {
+  auto empty = optimal_bst<int>({}, {});
+  auto single = optimal_bst<int>({7}, {0.3});
+  auto unused = optimal_bst<int>({1, 2}, {0.0, 0.0});
+
+  EXPECT_EQ(empty.tree, nullptr);
+  EXPECT_EQ(empty.expected_cost, 0.0);
+  EXPECT_EQ(serialize(single.tree), "7");
+  EXPECT_NEAR(single.expected_cost, 1.0, 1e-12);
+  EXPECT_EQ(unused.expected_cost, 0.0);
+  EXPECT_EQ(inorder(unused.tree.get()), (std::vector<int>{1, 2}));
+}
+
+TEST(OptimalBst, MatchesBruteForce) {
+  std::mt19937 gen(133);
+  for (int trial = 0; trial < 300; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(1, 8)(gen);
+    std::vector<int> keys(n);
+    std::vector<double> freq(n);
+    for (size_t i = 0; i < n; ++i) {
+      keys[i] = static_cast<int>(i);
+      // Small integers, so that ties between roots come up
+      freq[i] = std::uniform_int_distribution<int>(0, 5)(gen);
+    }
+    freq[0] += 1;  // Keep the total positive
+    auto result = optimal_bst(keys, freq);
+
+    ASSERT_NEAR(result.expected_cost, optimal_bst_cost_naive(freq), 1e-9);
+    ASSERT_NEAR(expected_depth(result.tree.get(), freq), result.expected_cost,
+                1e-9);
+    ASSERT_TRUE(is_valid_bst(result.tree.get()));
+    ASSERT_EQ(inorder(result.tree.get()), keys);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
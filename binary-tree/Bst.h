@@ -0,0 +1,57 @@
+#pragma once
+
+#include <cstddef>
+#include <functional>
+#include <memory>
+#include <optional>
+#include <vector>
+
+#include "TreeNode.h"
+
+// Binary search trees built from TreeNode: every value in a node's left
+// subtree is smaller than the node's value, and every value in its right
+// subtree is larger. Duplicates aren't allowed.
+
+// Checks the whole-subtree condition, not just each node against its
+// children: 3 with left child 1, whose right child is 4, is not a BST.
+//
+// The bounds are optional rather than sentinels like INT_MIN and INT_MAX,
+// because a sentinel is also a legal value. A tree holding INT_MIN would be
+// rejected by a check of val > INT_MIN.
+template <typename T, typename Compare = std::less<T>>
+bool is_valid_bst(const TreeNode<T>* node,
+                  const std::optional<T>& lower = std::nullopt,
+                  const std::optional<T>& upper = std::nullopt,
+                  Compare less = Compare()) {
+  if (node == nullptr) {
+    return true;
+  }
+  if ((lower && !less(*lower, node->val)) ||
+      (upper && !less(node->val, *upper))) {
+    return false;
+  }
+  return is_valid_bst(node->left.get(), lower, std::optional<T>(node->val),
+                      less) &&
+         is_valid_bst(node->right.get(), std::optional<T>(node->val), upper,
+                      less);
+}
+
+template <typename T>
+std::unique_ptr<TreeNode<T>> balanced_bst_from_range(
+    const std::vector<T>& sorted, size_t lo, size_t hi) {
+  if (lo == hi) {
+    return nullptr;
+  }
+  const size_t mid = lo + (hi - lo) / 2;
+  return make_node(sorted[mid], balanced_bst_from_range(sorted, lo, mid),
+                   balanced_bst_from_range(sorted, mid + 1, hi));
+}
+
+// Height-balanced BST holding the values of a sorted vector, or nullptr if
+// it's empty. The middle value becomes the root, so the two halves differ in
+// size by at most one, and by induction so do their heights. O(n).
+template <typename T>
+std::unique_ptr<TreeNode<T>> balanced_bst_from_sorted(
+    const std::vector<T>& sorted) {
+  return balanced_bst_from_range(sorted, 0, sorted.size());
+}
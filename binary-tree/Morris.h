@@ -0,0 +1,76 @@
+#pragma once
+
+#include <exception>
+#include <vector>
+
+#include "TreeNode.h"
+
+// Morris traversal: inorder in O(n) time and O(1) extra space, with no stack
+// and no recursion.
+//
+// A recursive inorder traversal needs its stack to find the way back up from
+// the end of a left subtree. The last node of that subtree is the inorder
+// predecessor of the subtree's parent, and its right pointer is empty. Morris
+// threads it: point it at the parent before descending, and when the
+// traversal later arrives at the parent through that pointer, remove the
+// thread again. Each edge is walked at most a constant number of times, so
+// the whole traversal is O(n).
+//
+// The tree is modified while the traversal runs, and is restored exactly by
+// the time it returns. A thread is a second owner of an already owned node,
+// so it is installed with reset() and removed with release(), never
+// destroyed. If visit throws, the traversal still runs to the end without
+// visiting anything, so that every thread is removed, and then rethrows.
+
+template <typename T, typename Visit>
+void morris_inorder(TreeNode<T>* root, Visit visit) {
+  std::exception_ptr error;
+  auto try_visit = [&](const T& val) {
+    if (!error) {
+      try {
+        visit(val);
+      } catch (...) {
+        error = std::current_exception();
+      }
+    }
+  };
+
+  TreeNode<T>* current = root;
+  while (current != nullptr) {
+    if (current->left == nullptr) {
+      try_visit(current->val);
+      current = current->right.get();  // Possibly along a thread
+      continue;
+    }
+
+    // The inorder predecessor: rightmost node of the left subtree, unless
+    // the walk runs into a thread we installed earlier
+    TreeNode<T>* predecessor = current->left.get();
+    while (predecessor->right != nullptr &&
+           predecessor->right.get() != current) {
+      predecessor = predecessor->right.get();
+    }
+
+    if (predecessor->right == nullptr) {
+      // First visit: thread the way back, then do the left subtree
+      predecessor->right.reset(current);
+      current = current->left.get();
+    } else {
+      // Back from the left subtree: unthread, visit, go right
+      static_cast<void>(predecessor->right.release());
+      try_visit(current->val);
+      current = current->right.get();
+    }
+  }
+
+  if (error) {
+    std::rethrow_exception(error);
+  }
+}
+
+template <typename T>
+std::vector<T> morris_inorder(TreeNode<T>* root) {
+  std::vector<T> out;
+  morris_inorder(root, [&](const T& val) { out.push_back(val); });
+  return out;
+}
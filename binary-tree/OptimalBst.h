@@ -0,0 +1,137 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <limits>
+#include <memory>
+#include <vector>
+
+#include "../prefix-sums/PrefixSums.h"
+#include "TreeNode.h"
+
+// Optimal binary search tree: given sorted keys and how often each one is
+// looked up, build the BST that minimizes the expected number of comparisons
+// per search, sum(p[i] * depth(i)) with the root at depth 1.
+//
+// Like a Huffman code, frequent keys should end up near the root. Unlike
+// Huffman, the tree must keep the keys in order, so greedily merging the two
+// rarest items doesn't work. Instead, dynamic programming over contiguous
+// ranges of keys: whichever key r is the root of keys [i, j), the two
+// subtrees are optimal trees for [i, r) and [r + 1, j), and hanging them one
+// level lower adds the total weight of [i, j) to the cost:
+//
+//   cost[i][j] = weight(i, j) + min over r of (cost[i][r] + cost[r + 1][j])
+//
+// Trying every r is O(n^3). Knuth's speedup: some optimal root for [i, j)
+// lies between the optimal roots for [i, j - 1) and [i + 1, j), and those
+// ranges telescope, so each diagonal of the table costs O(n) and the whole
+// table O(n^2).
+
+template <typename K>
+struct OptimalBst {
+  double expected_cost{0.0};  // Frequencies are normalized to probabilities
+  std::unique_ptr<TreeNode<K>> tree;
+};
+
+template <typename K>
+std::unique_ptr<TreeNode<K>> build_from_roots(
+    const std::vector<K>& keys, const std::vector<std::vector<size_t>>& root,
+    size_t i, size_t j) {
+  if (i == j) {
+    return nullptr;
+  }
+  const size_t r = root[i][j];
+  return make_node(keys[r], build_from_roots(keys, root, i, r),
+                   build_from_roots(keys, root, r + 1, j));
+}
+
+// keys must be strictly increasing and freq non-negative, one per key
+template <typename K>
+OptimalBst<K> optimal_bst(const std::vector<K>& keys,
+                          const std::vector<double>& freq) {
+  assert(keys.size() == freq.size() && "one frequency per key");
+  const size_t n = keys.size();
+  OptimalBst<K> result;
+  if (n == 0) {
+    return result;
+  }
+
+  for (size_t i = 0; i < n; ++i) {
+    assert(freq[i] >= 0.0 && "frequencies must be non-negative");
+  }
+  const PrefixSums<double> weight(freq);
+
+  // cost[i][j] and root[i][j] describe the keys [i, j)
+  std::vector<std::vector<double>> cost(n + 1, std::vector<double>(n + 1));
+  std::vector<std::vector<size_t>> root(n + 1, std::vector<size_t>(n + 1));
+  for (size_t i = 0; i < n; ++i) {
+    cost[i][i + 1] = freq[i];
+    root[i][i + 1] = i;
+  }
+  for (size_t length = 2; length <= n; ++length) {
+    for (size_t i = 0; i + length <= n; ++i) {
+      const size_t j = i + length;
+      double best = std::numeric_limits<double>::infinity();
+      // The max only matters if rounding ever breaks the monotonicity
+      const size_t last = std::max(root[i][j - 1], root[i + 1][j]);
+      for (size_t r = root[i][j - 1]; r <= last; ++r) {
+        const double candidate = cost[i][r] + cost[r + 1][j];
+        if (candidate < best) {
+          best = candidate;
+          root[i][j] = r;
+        }
+      }
+      cost[i][j] = best + weight.sum(i, j);
+    }
+  }
+
+  const double total = weight.total();
+  result.expected_cost = total > 0.0 ? cost[0][n] / total : 0.0;
+  result.tree = build_from_roots(keys, root, 0, n);
+  return result;
+}
+
+// Depth of every key (root at depth 1), for every BST on keys [lo, hi)
+inline std::vector<std::vector<int>> all_bst_depths(size_t lo, size_t hi) {
+  if (lo == hi) {
+    return {{}};
+  }
+  std::vector<std::vector<int>> result;
+  for (size_t r = lo; r < hi; ++r) {
+    for (const auto& left : all_bst_depths(lo, r)) {
+      for (const auto& right : all_bst_depths(r + 1, hi)) {
+        std::vector<int> depths;
+        for (int d : left) {
+          depths.push_back(d + 1);
+        }
+        depths.push_back(1);
+        for (int d : right) {
+          depths.push_back(d + 1);
+        }
+        result.push_back(std::move(depths));
+      }
+    }
+  }
+  return result;
+}
+
+// Tries all Catalan(n) BSTs, so only for small n
+inline double optimal_bst_cost_naive(const std::vector<double>& freq) {
+  double total = 0.0;
+  for (double f : freq) {
+    total += f;
+  }
+  if (freq.empty() || total == 0.0) {
+    return 0.0;
+  }
+  double best = std::numeric_limits<double>::infinity();
+  for (const auto& depths : all_bst_depths(0, freq.size())) {
+    double cost = 0.0;
+    for (size_t i = 0; i < freq.size(); ++i) {
+      cost += freq[i] * depths[i];
+    }
+    best = std::min(best, cost);
+  }
+  return best / total;
+}
@@ -0,0 +1,100 @@
+#pragma once
+
+#include <cstddef>
+#include <memory>
+#include <stdexcept>
+#include <unordered_map>
+#include <vector>
+
+#include "TreeNode.h"
+
+// Rebuilding a binary tree from two of its traversals.
+//
+// The inorder traversal alone doesn't fix the shape, but it does split the
+// tree: everything left of the root's position is in the left subtree, and
+// everything right of it is in the right subtree. The preorder traversal
+// names the root first (the postorder traversal names it last), so the two
+// together determine the tree, provided that the values are distinct.
+//
+// Preorder and postorder alone aren't enough: 1 with a single child 2 has the
+// same pre- and postorder whether 2 is the left or the right child.
+//
+// Both builders run in O(n) with a hash map from value to inorder position.
+// They throw std::invalid_argument if the traversals have different lengths,
+// have repeated values, or don't come from the same tree.
+
+template <typename T>
+std::unordered_map<T, size_t> index_inorder(const std::vector<T>& order,
+                                            const std::vector<T>& inorder) {
+  if (order.size() != inorder.size()) {
+    throw std::invalid_argument("traversals have different lengths");
+  }
+  std::unordered_map<T, size_t> position;
+  for (size_t i = 0; i < inorder.size(); ++i) {
+    if (!position.emplace(inorder[i], i).second) {
+      throw std::invalid_argument("values must be distinct");
+    }
+  }
+  return position;
+}
+
+// Where the root of inorder[lo, hi) sits, checking that it's in that range
+template <typename T>
+size_t inorder_root_position(const std::unordered_map<T, size_t>& position,
+                             const T& root, size_t lo, size_t hi) {
+  auto it = position.find(root);
+  if (it == position.end() || it->second < lo || it->second >= hi) {
+    throw std::invalid_argument("traversals don't describe the same tree");
+  }
+  return it->second;
+}
+
+// Builds the subtree whose inorder traversal is inorder[lo, hi). Its root is
+// preorder[next], and the rest of its preorder traversal follows.
+template <typename T>
+std::unique_ptr<TreeNode<T>> build_from_preorder(
+    const std::vector<T>& preorder,
+    const std::unordered_map<T, size_t>& position, size_t& next, size_t lo,
+    size_t hi) {
+  if (lo == hi) {
+    return nullptr;
+  }
+  const T& root = preorder[next++];
+  const size_t mid = inorder_root_position(position, root, lo, hi);
+  auto left = build_from_preorder(preorder, position, next, lo, mid);
+  auto right = build_from_preorder(preorder, position, next, mid + 1, hi);
+  return make_node(root, std::move(left), std::move(right));
+}
+
+// Mirror image: postorder read backwards is root, right subtree, left
+// subtree. next counts down from the end.
+template <typename T>
+std::unique_ptr<TreeNode<T>> build_from_postorder(
+    const std::vector<T>& postorder,
+    const std::unordered_map<T, size_t>& position, size_t& next, size_t lo,
+    size_t hi) {
+  if (lo == hi) {
+    return nullptr;
+  }
+  const T& root = postorder[--next];
+  const size_t mid = inorder_root_position(position, root, lo, hi);
+  auto right = build_from_postorder(postorder, position, next, mid + 1, hi);
+  auto left = build_from_postorder(postorder, position, next, lo, mid);
+  return make_node(root, std::move(left), std::move(right));
+}
+
+template <typename T>
+std::unique_ptr<TreeNode<T>> build_tree_from_preorder_inorder(
+    const std::vector<T>& preorder, const std::vector<T>& inorder) {
+  auto position = index_inorder(preorder, inorder);
+  size_t next = 0;
+  return build_from_preorder(preorder, position, next, 0, inorder.size());
+}
+
+template <typename T>
+std::unique_ptr<TreeNode<T>> build_tree_from_postorder_inorder(
+    const std::vector<T>& postorder, const std::vector<T>& inorder) {
+  auto position = index_inorder(postorder, inorder);
+  size_t next = postorder.size();
+  return build_from_postorder(postorder, position, next, 0, inorder.size());
+}
@@ -0,0 +1,126 @@
+#pragma once
+
+#include <cstddef>
+#include <memory>
+#include <optional>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "TreeNode.h"
+
+// Compact string form of a binary tree: its level order, with '#' for a
+// missing child and trailing '#'s dropped. The empty tree is "". Values are
+// written with operator<< and read back with operator>>, so they must not
+// contain ',' or whitespace.
+
+/*
+ *       1
+ *      / \
+ *     2   3       ->   "1,2,3,#,#,4,5"
+ *        / \
+ *       4   5
+ */
+
+template <typename T>
+std::string serialize(const TreeNode<T>* root) {
+  std::vector<std::string> fields;
+  std::vector<const TreeNode<T>*> queue = {root};
+  for (size_t i = 0; i < queue.size(); ++i) {
+    const TreeNode<T>* node = queue[i];
+    if (node == nullptr) {
+      fields.push_back("#");
+      continue;
+    }
+    std::ostringstream out;
+    out << node->val;
+    fields.push_back(out.str());
+    queue.push_back(node->left.get());
+    queue.push_back(node->right.get());
+  }
+  while (!fields.empty() && fields.back() == "#") {
+    fields.pop_back();
+  }
+
+  std::string result;
+  for (size_t i = 0; i < fields.size(); ++i) {
+    if (i > 0) {
+      result += ',';
+    }
+    result += fields[i];
+  }
+  return result;
+}
+
+template <typename T>
+std::string serialize(const std::unique_ptr<TreeNode<T>>& root) {
+  return serialize(root.get());
+}
+
+// The tree with the given level order, std::nullopt standing for a missing
+// child, as in serialize() and LeetCode's arrays. Throws
+// std::invalid_argument if the root is missing, or if there are more values
+// than the tree has room for.
+template <typename T>
+std::unique_ptr<TreeNode<T>> tree_from_level_order(
+    const std::vector<std::optional<T>>& values) {
+  if (values.empty()) {
+    return nullptr;
+  }
+  if (!values[0]) {
+    throw std::invalid_argument("missing root");
+  }
+  auto root = make_node(*values[0]);
+  // Nodes waiting for their children, in level order
+  std::vector<TreeNode<T>*> queue = {root.get()};
+  size_t next = 1;
+  for (size_t i = 0; i < queue.size() && next < values.size(); ++i) {
+    for (auto* child : {&queue[i]->left, &queue[i]->right}) {
+      if (next == values.size()) {
+        break;
+      }
+      if (const auto& value = values[next++]) {
+        *child = make_node(*value);
+        queue.push_back(child->get());
+      }
+    }
+  }
+  if (next < values.size()) {
+    throw std::invalid_argument("more values than the tree has room for");
+  }
+  return root;
+}
+
+// Inverse of serialize. Throws std::invalid_argument on a field that isn't a
+// T, and as tree_from_level_order() does.
+template <typename T>
+std::unique_ptr<TreeNode<T>> deserialize(const std::string& data) {
+  if (data.empty()) {
+    return nullptr;
+  }
+  std::vector<std::string> fields;
+  std::string field;
+  std::istringstream in(data);
+  while (std::getline(in, field, ',')) {
+    fields.push_back(field);
+  }
+  if (data.back() == ',') {
+    fields.push_back("");
+  }
+
+  std::vector<std::optional<T>> values;
+  for (const auto& text : fields) {
+    if (text == "#") {
+      values.push_back(std::nullopt);
+      continue;
+    }
+    std::istringstream value_in(text);
+    T value;
+    if (!(value_in >> value) || !(value_in >> std::ws).eof()) {
+      throw std::invalid_argument("bad field: '" + text + "'");
+    }
+    values.push_back(std::move(value));
+  }
+  return tree_from_level_order(values);
+}
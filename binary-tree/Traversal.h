@@ -0,0 +1,122 @@
+#pragma once
+
+#include <vector>
+
+#include "TreeNode.h"
+
+// Depth-first traversals, written recursively to mirror their definitions,
+// and then again with an explicit stack, for trees too deep for the call
+// stack
+
+template <typename T>
+void preorder(const TreeNode<T>* node, std::vector<T>& out) {
+  if (node == nullptr) {
+    return;
+  }
+  out.push_back(node->val);
+  preorder(node->left.get(), out);
+  preorder(node->right.get(), out);
+}
+
+template <typename T>
+void inorder(const TreeNode<T>* node, std::vector<T>& out) {
+  if (node == nullptr) {
+    return;
+  }
+  inorder(node->left.get(), out);
+  out.push_back(node->val);
+  inorder(node->right.get(), out);
+}
+
+template <typename T>
+void postorder(const TreeNode<T>* node, std::vector<T>& out) {
+  if (node == nullptr) {
+    return;
+  }
+  postorder(node->left.get(), out);
+  postorder(node->right.get(), out);
+  out.push_back(node->val);
+}
+
+template <typename T>
+std::vector<T> preorder(const TreeNode<T>* root) {
+  std::vector<T> out;
+  preorder(root, out);
+  return out;
+}
+
+template <typename T>
+std::vector<T> inorder(const TreeNode<T>* root) {
+  std::vector<T> out;
+  inorder(root, out);
+  return out;
+}
+
+template <typename T>
+std::vector<T> postorder(const TreeNode<T>* root) {
+  std::vector<T> out;
+  postorder(root, out);
+  return out;
+}
+
+// The recursive versions, turned inside out. A call's state is its node and
+// how far through the body it got: before the left subtree, between the
+// subtrees, or after the right one. Each frame on the stack holds exactly
+// that; a call becomes a push, a return becomes a pop, and the three orders
+// differ only in the stage at which they visit. O(height) frames.
+
+enum class DepthFirstOrder { kPreorder, kInorder, kPostorder };
+
+template <typename T>
+void depth_first_iterative(const TreeNode<T>* root, DepthFirstOrder order,
+                           std::vector<T>& out) {
+  struct Frame {
+    const TreeNode<T>* node;
+    int stage;  // 0: nothing done yet, 1: left done, 2: right done too
+  };
+  std::vector<Frame> stack;
+  if (root != nullptr) {
+    stack.push_back({root, 0});
+  }
+  while (!stack.empty()) {
+    Frame& frame = stack.back();
+    const TreeNode<T>* node = frame.node;
+    const int stage = frame.stage++;
+    if (stage == 0 && order == DepthFirstOrder::kPreorder) {
+      out.push_back(node->val);
+    } else if (stage == 1 && order == DepthFirstOrder::kInorder) {
+      out.push_back(node->val);
+    } else if (stage == 2 && order == DepthFirstOrder::kPostorder) {
+      out.push_back(node->val);
+    }
+    // A push may move the stack, so frame isn't used from here on
+    if (stage == 0 && node->left) {
+      stack.push_back({node->left.get(), 0});
+    } else if (stage == 1 && node->right) {
+      stack.push_back({node->right.get(), 0});
+    } else if (stage == 2) {
+      stack.pop_back();
+    }
+  }
+}
+
+template <typename T>
+std::vector<T> preorder_iterative(const TreeNode<T>* root) {
+  std::vector<T> out;
+  depth_first_iterative(root, DepthFirstOrder::kPreorder, out);
+  return out;
+}
+
+template <typename T>
+std::vector<T> inorder_iterative(const TreeNode<T>* root) {
+  std::vector<T> out;
+  depth_first_iterative(root, DepthFirstOrder::kInorder, out);
+  return out;
+}
+
+template <typename T>
+std::vector<T> postorder_iterative(const TreeNode<T>* root) {
+  std::vector<T> out;
+  depth_first_iterative(root, DepthFirstOrder::kPostorder, out);
+  return out;
+}
@@ -0,0 +1,46 @@
+#pragma once
+
+#include <memory>
+#include <type_traits>
+#include <utility>
+
+// Binary tree node that owns its children. Generalizes the int-valued
+// TreeNode in manber/balance_factors.cpp so that the tree etudes can share
+// one representation.
+template <typename T>
+struct TreeNode {
+  T val;
+  std::unique_ptr<TreeNode> left;
+  std::unique_ptr<TreeNode> right;
+
+  explicit TreeNode(T v) : val(std::move(v)), left(nullptr), right(nullptr) {}
+
+  TreeNode(T v, std::unique_ptr<TreeNode> l, std::unique_ptr<TreeNode> r)
+      : val(std::move(v)), left(std::move(l)), right(std::move(r)) {}
+};
+
+// Helper function to create a leaf node
+template <typename T>
+std::unique_ptr<TreeNode<T>> make_node(T val) {
+  return std::make_unique<TreeNode<T>>(std::move(val));
+}
+
+// Helper function to create a node with children. T is deduced from val
+// alone, so that nullptr can be passed for a missing child.
+template <typename T>
+std::unique_ptr<TreeNode<T>> make_node(
+    T val, std::unique_ptr<TreeNode<std::type_identity_t<T>>> left,
+    std::unique_ptr<TreeNode<std::type_identity_t<T>>> right) {
+  return std::make_unique<TreeNode<T>>(std::move(val), std::move(left),
+                                       std::move(right));
+}
+
+// Structural equality: same shape and same values
+template <typename T>
+bool same_tree(const TreeNode<T>* a, const TreeNode<T>* b) {
+  if (a == nullptr || b == nullptr) {
+    return a == b;
+  }
+  return a->val == b->val && same_tree(a->left.get(), b->left.get()) &&
+         same_tree(a->right.get(), b->right.get());
+}
@@ -0,0 +1,109 @@
+#pragma once
+
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+// Bit tricks on unsigned 64-bit words. Most come from one observation:
+// subtracting 1 turns the lowest set bit off and every bit below it on,
+// leaving the bits above alone, and -x is ~x + 1.
+
+// Sideways addition: count the bits of each pair, then add neighboring
+// pairs into 4-bit counts, then 8-bit counts, and let one multiplication
+// add up all eight bytes into the top byte. O(1), no loop.
+inline int popcount(uint64_t x) {
+  x = x - ((x >> 1) & 0x5555555555555555);
+  x = (x & 0x3333333333333333) + ((x >> 2) & 0x3333333333333333);
+  x = (x + (x >> 4)) & 0x0f0f0f0f0f0f0f0f;
+  return static_cast<int>((x * 0x0101010101010101) >> 56);
+}
+
+// Kernighan: clear the lowest set bit until there's none left. One
+// iteration per set bit, so fast for sparse words.
+inline int popcount_kernighan(uint64_t x) {
+  int count = 0;
+  for (; x != 0; x &= x - 1) {
+    ++count;
+  }
+  return count;
+}
+
+// 0b10110100 -> 0b00000100, or 0 for 0
+inline uint64_t lowest_set_bit(uint64_t x) { return x & (~x + 1); }
+
+// 0b10110100 -> 0b10110000
+inline uint64_t clear_lowest_set_bit(uint64_t x) { return x & (x - 1); }
+
+// 0b10110111 -> 0b00001000: the lowest set bit of ~x
+inline uint64_t lowest_clear_bit(uint64_t x) { return ~x & (x + 1); }
+
+// 0b10110100 -> 0b00000111: the bits below the lowest set bit
+inline uint64_t trailing_zeros_mask(uint64_t x) { return ~x & (x - 1); }
+
+inline bool is_power_of_two(uint64_t x) {
+  return x != 0 && clear_lowest_set_bit(x) == 0;
+}
+
+// Gosper's hack: the next larger number with as many bits set, e.g.
+// 0b0011100 -> 0b0100011. Move the lowest block of ones' top bit up one
+// place (adding the lowest bit carries it there), and push the rest of the
+// block down to the bottom. Starting from 2^k - 1, this walks through every
+// k-element subset of the bits in increasing order. std::nullopt for 0, or
+// if the block already reaches the top bit.
+inline std::optional<uint64_t> next_same_popcount(uint64_t x) {
+  if (x == 0) {
+    return std::nullopt;
+  }
+  const uint64_t low = lowest_set_bit(x);
+  const uint64_t ripple = x + low;
+  if (ripple == 0) {
+    return std::nullopt;
+  }
+  // ripple ^ x is the block plus the bit above it. Two of those bits are
+  // accounted for by the carry, so shift them out, and divide by low to move
+  // the rest of the block down to bit 0.
+  return ripple | (((ripple ^ x) >> 2) / low);
+}
+
+// Swaps the two halves, then the two halves of each half, and so on down
+// to neighboring bits: six steps
+inline uint64_t reverse_bits(uint64_t x) {
+  x = ((x >> 1) & 0x5555555555555555) | ((x & 0x5555555555555555) << 1);
+  x = ((x >> 2) & 0x3333333333333333) | ((x & 0x3333333333333333) << 2);
+  x = ((x >> 4) & 0x0f0f0f0f0f0f0f0f) | ((x & 0x0f0f0f0f0f0f0f0f) << 4);
+  x = ((x >> 8) & 0x00ff00ff00ff00ff) | ((x & 0x00ff00ff00ff00ff) << 8);
+  x = ((x >> 16) & 0x0000ffff0000ffff) | ((x & 0x0000ffff0000ffff) << 16);
+  return (x >> 32) | (x << 32);
+}
+
+// The low `width` bits, reversed among themselves
+inline uint64_t reverse_bits(uint64_t x, int width) {
+  return width == 0 ? 0 : reverse_bits(x) >> (64 - width);
+}
+
+inline uint64_t reverse_bits_naive(uint64_t x, int width) {
+  uint64_t reversed = 0;
+  for (int i = 0; i < width; ++i) {
+    reversed = (reversed << 1) | ((x >> i) & 1);
+  }
+  return reversed;
+}
+
+// Every subset of the set bits of mask, from mask itself down to 0.
+// Subtracting 1 and masking gives the next smaller subset: the borrow only
+// ever lands on bits of the mask. O(2^popcount(mask)).
+template <typename Visit>
+void for_each_subset(uint64_t mask, Visit visit) {
+  for (uint64_t s = mask;; s = (s - 1) & mask) {
+    visit(s);
+    if (s == 0) {
+      break;
+    }
+  }
+}
+
+inline std::vector<uint64_t> subsets(uint64_t mask) {
+  std::vector<uint64_t> result;
+  for_each_subset(mask, [&](uint64_t s) { result.push_back(s); });
+  return result;
+}
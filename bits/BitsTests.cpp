@@ -0,0 +1,119 @@
+#include <bit>
+#include <cstdint>
+#include <random>
+#include <vector>
+
+#include "Bits.h"
+#include "gtest/gtest.h"
+
+// Every 16-bit word, plus random 64-bit words with a few bits set and some
+// with many, so the high bytes get exercised too
+std::vector<uint64_t> test_words() {
+  std::vector<uint64_t> words;
+  for (uint64_t x = 0; x < (1 << 16); ++x) {
+    words.push_back(x);
+  }
+  std::mt19937_64 gen(165);
+  for (int trial = 0; trial < 10000; ++trial) {
+    words.push_back(gen() & gen() & gen());
+    words.push_back(gen() | gen());
+  }
+  words.push_back(UINT64_MAX);
+  words.push_back(uint64_t{1} << 63);
+  return words;
+}
+
+TEST(Bits, Popcount) {
+  for (uint64_t x : test_words()) {
+    ASSERT_EQ(popcount(x), std::popcount(x)) << x;
+    ASSERT_EQ(popcount_kernighan(x), std::popcount(x)) << x;
+  }
+}
+
+TEST(Bits, LowestBits) {
+  EXPECT_EQ(lowest_set_bit(0b10110100), 0b100u);
+  EXPECT_EQ(clear_lowest_set_bit(0b10110100), 0b10110000u);
+  EXPECT_EQ(lowest_clear_bit(0b10110111), 0b1000u);
+  EXPECT_EQ(trailing_zeros_mask(0b10110100), 0b11u);
+  EXPECT_EQ(lowest_set_bit(0), 0u);
+  EXPECT_EQ(lowest_clear_bit(UINT64_MAX), 0u);
+
+  for (uint64_t x : test_words()) {
+    const int zeros = std::countr_zero(x);
+    const uint64_t low = x == 0 ? 0 : uint64_t{1} << zeros;
+    ASSERT_EQ(lowest_set_bit(x), low);
+    ASSERT_EQ(clear_lowest_set_bit(x), x ^ low);
+    ASSERT_EQ(trailing_zeros_mask(x), x == 0 ? UINT64_MAX : low - 1);
+    ASSERT_EQ(lowest_clear_bit(x), lowest_set_bit(~x));
+    ASSERT_EQ(is_power_of_two(x), std::has_single_bit(x));
+  }
+}
+
+TEST(Bits, NextSamePopcount) {
+  EXPECT_EQ(next_same_popcount(0b0011100), 0b0100011u);
+  EXPECT_EQ(next_same_popcount(0), std::nullopt);
+  EXPECT_EQ(next_same_popcount(uint64_t{1} << 63), std::nullopt);
+  EXPECT_EQ(next_same_popcount(UINT64_MAX), std::nullopt);
+
+  // Exhaustive below 2^16: the next number with as many bits, by counting up
+  for (uint64_t x = 1; x < (1 << 16); ++x) {
+    uint64_t expected = x + 1;
+    while (std::popcount(expected) != std::popcount(x)) {
+      ++expected;
+    }
+    ASSERT_EQ(next_same_popcount(x), expected) << x;
+  }
+}
+
+TEST(Bits, NextSamePopcountWalksCombinations) {
+  // From 0b111, every 3-element subset of 10 bits: C(10, 3) = 120 of them
+  int count = 0;
+  uint64_t previous = 0;
+  for (std::optional<uint64_t> x = 0b111; *x < (1 << 10);
+       x = next_same_popcount(*x)) {
+    ASSERT_EQ(std::popcount(*x), 3);
+    ASSERT_GT(*x, previous);
+    previous = *x;
+    ++count;
+  }
+  EXPECT_EQ(count, 120);
+}
+
+TEST(Bits, ReverseBits) {
+  EXPECT_EQ(reverse_bits(1), uint64_t{1} << 63);
+  EXPECT_EQ(reverse_bits(0b1101, 4), 0b1011u);
+  EXPECT_EQ(reverse_bits(0b1101, 0), 0u);
+
+  for (uint64_t x : test_words()) {
+    ASSERT_EQ(reverse_bits(x), reverse_bits_naive(x, 64)) << x;
+    ASSERT_EQ(reverse_bits(reverse_bits(x)), x);
+  }
+  for (int width = 1; width <= 16; ++width) {
+    for (uint64_t x = 0; x < (uint64_t{1} << width); ++x) {
+      ASSERT_EQ(reverse_bits(x, width), reverse_bits_naive(x, width));
+    }
+  }
+}
+
+TEST(Bits, Subsets) {
+  EXPECT_EQ(subsets(0), std::vector<uint64_t>{0});
+  EXPECT_EQ(subsets(0b1010), (std::vector<uint64_t>{0b1010, 0b1000, 0b10, 0}));
+
+  // Exhaustive over 12-bit masks: exactly the x with x & ~mask == 0, each
+  // once, in decreasing order
+  for (uint64_t mask = 0; mask < (1 << 12); ++mask) {
+    std::vector<uint64_t> expected;
+    for (uint64_t x = mask + 1; x-- > 0;) {
+      if ((x & ~mask) == 0) {
+        expected.push_back(x);
+      }
+    }
+    ASSERT_EQ(subsets(mask), expected) << mask;
+  }
+  EXPECT_EQ(subsets(UINT64_MAX >> 44).size(), size_t{1} << 20);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,111 @@
+#pragma once
+
+#include <algorithm>
+#include <concepts>
+#include <cstddef>
+#include <limits>
+#include <optional>
+#include <utility>
+#include <vector>
+
+#include "../heap/BinaryHeap.h"
+
+// Branch and bound, for minimizing. Split the solutions into smaller and
+// smaller sets (branch) and skip any set whose best possible cost is no
+// better than a solution already found (bound). A problem describes
+// partial solutions as nodes; it's BranchAndBound if it has:
+//
+// - a Node type, the partial solutions, held by value in the queue;
+// - root(), the node for every solution at once;
+// - branch(n), the nodes that split n's solutions between them;
+// - is_complete(n), whether n is a single solution, and cost(n), its cost;
+//   and
+// - lower_bound(n), at most the cost of every solution below n, and the
+//   cost itself for a complete node.
+//
+// It can also have upper_bound(n), the cost of some solution below n, say a
+// greedy completion. That prunes before any solution has been reached.
+//
+// Best-first: the open nodes wait in a heap by lower bound, and the one
+// with the smallest goes next. When that bound is no better than the best
+// solution so far, neither is anything still open, so the search is done.
+// Tighter bounds prune more; the answer is exact with any valid bound.
+template <typename P>
+concept BranchAndBound =
+    requires(const P& problem, const typename P::Node& node) {
+      { problem.root() } -> std::convertible_to<typename P::Node>;
+      {
+        problem.branch(node)
+      } -> std::convertible_to<std::vector<typename P::Node>>;
+      { problem.is_complete(node) } -> std::convertible_to<bool>;
+      { problem.cost(node) } -> std::convertible_to<double>;
+      { problem.lower_bound(node) } -> std::convertible_to<double>;
+    };
+
+template <typename P>
+concept UpperBounded = requires(const P& problem, const typename P::Node& n) {
+  { problem.upper_bound(n) } -> std::convertible_to<double>;
+};
+
+template <typename Node>
+struct BranchAndBoundResult {
+  std::optional<Node> best;  // none if there are no solutions
+  double cost{std::numeric_limits<double>::infinity()};
+  size_t expanded{0};  // nodes branched on
+  size_t pruned{0};    // nodes dropped by their bounds
+};
+
+template <BranchAndBound P>
+BranchAndBoundResult<typename P::Node> branch_and_bound(const P& problem) {
+  using Node = typename P::Node;
+  struct Open {
+    double bound;
+    Node node;
+  };
+  struct LaterFirst {
+    bool operator()(const Open& a, const Open& b) const {
+      return a.bound > b.bound;
+    }
+  };
+
+  BranchAndBoundResult<Node> result;
+  // The cost of some solution: the best found, or better, an upper bound.
+  // Only found solutions prune nodes that tie, so that the best isn't
+  // dropped in favor of one that was never reached.
+  double upper = std::numeric_limits<double>::infinity();
+  BinaryHeap<Open, LaterFirst> open;
+  auto reach = [&](Node node) {
+    if (problem.is_complete(node)) {
+      const double cost = problem.cost(node);
+      if (cost < result.cost) {
+        result.cost = cost;
+        result.best = std::move(node);
+        upper = std::min(upper, cost);
+      }
+      return;
+    }
+    const double bound = problem.lower_bound(node);
+    if (bound >= result.cost || bound > upper) {
+      ++result.pruned;
+      return;
+    }
+    if constexpr (UpperBounded<P>) {
+      upper = std::min(upper, static_cast<double>(problem.upper_bound(node)));
+    }
+    open.push({bound, std::move(node)});
+  };
+
+  reach(problem.root());
+  while (!open.empty()) {
+    Open next = open.pop();
+    if (next.bound >= result.cost || next.bound > upper) {
+      result.pruned += 1 + open.size();
+      break;
+    }
+    ++result.expanded;
+    for (auto& child : problem.branch(next.node)) {
+      reach(std::move(child));
+    }
+  }
+  return result;
+}
@@ -0,0 +1,84 @@
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "../tsp/Tsp.h"
+#include "BranchAndBound.h"
+#include "Knapsack.h"
+#include "TspBranchAndBound.h"
+#include "gtest/gtest.h"
+
+TEST(BranchAndBound, KnapsackExample) {
+  // Greedy by density takes items 0 and 1, for 160, and then item 2 doesn't
+  // fit; items 1 and 2 are worth 220
+  const std::vector<KnapsackItem> items = {{10, 60}, {20, 100}, {30, 120}};
+  const KnapsackSolution solution = knapsack_by_branch_and_bound(items, 50);
+  EXPECT_EQ(solution.value, 220);
+  EXPECT_EQ(solution.items, (std::vector<size_t>{1, 2}));
+  EXPECT_EQ(knapsack_value_dp(items, 50), 220);
+
+  EXPECT_EQ(knapsack_by_branch_and_bound({}, 10).value, 0);
+  EXPECT_EQ(knapsack_by_branch_and_bound(items, 5).value, 0);
+}
+
+TEST(BranchAndBound, KnapsackMatchesDp) {
+  Rng gen(223);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<KnapsackItem> items(uniform_below(gen, 16));
+    int64_t total = 0;
+    for (auto& item : items) {
+      item = {uniform_int<int64_t>(gen, 1, 30),
+              uniform_int<int64_t>(gen, 0, 50)};
+      total += item.weight;
+    }
+    const int64_t capacity = uniform_int<int64_t>(gen, 0, total);
+    const KnapsackSolution solution =
+        knapsack_by_branch_and_bound(items, capacity);
+    ASSERT_EQ(solution.value, knapsack_value_dp(items, capacity));
+    int64_t weight = 0;
+    int64_t value = 0;
+    for (size_t i : solution.items) {
+      weight += items[i].weight;
+      value += items[i].value;
+    }
+    ASSERT_LE(weight, capacity);
+    ASSERT_EQ(value, solution.value);
+  }
+}
+
+TEST(BranchAndBound, TspMatchesHeldKarp) {
+  Rng gen(223);
+  for (int trial = 0; trial < 40; ++trial) {
+    std::vector<Point> points(1 + uniform_below(gen, 9));
+    for (auto& p : points) {
+      p = {uniform_unit(gen) * 100, uniform_unit(gen) * 100};
+    }
+    const DistanceMatrix dist = euclidean_distances(points);
+    const Tour tour = tsp_by_branch_and_bound(dist);
+    ASSERT_NEAR(tour.cost, held_karp(dist).cost, 1e-9);
+    ASSERT_EQ(tour.order.size(), points.size());
+    ASSERT_EQ(tour.order[0], 0);
+    ASSERT_NEAR(tour_cost(dist, tour.order), tour.cost, 1e-9);
+  }
+  EXPECT_TRUE(tsp_by_branch_and_bound({}).order.empty());
+}
+
+// The bounds cut the search well short of all (n - 1)! orders
+TEST(BranchAndBound, Prunes) {
+  Rng gen(223);
+  std::vector<Point> points(10);
+  for (auto& p : points) {
+    p = {uniform_unit(gen) * 100, uniform_unit(gen) * 100};
+  }
+  const DistanceMatrix dist = euclidean_distances(points);
+  const auto result = branch_and_bound(TspBranchAndBound(dist));
+  EXPECT_NEAR(result.cost, held_karp(dist).cost, 1e-9);
+  EXPECT_LT(result.expanded, 362'880u / 10);
+  EXPECT_GT(result.pruned, 0u);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,130 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <numeric>
+#include <vector>
+
+#include "BranchAndBound.h"
+
+// 0/1 knapsack: the most valuable set of items with total weight at most
+// the capacity, each item taken whole or not at all.
+
+struct KnapsackItem {
+  int64_t weight;
+  int64_t value;
+};
+
+struct KnapsackSolution {
+  int64_t value{0};
+  std::vector<size_t> items;  // indices, in increasing order
+};
+
+// Deciding the items in order of value per unit of weight, best first.
+// Maximizing value is minimizing its negative, so the bounds are negated
+// too. The lower bound comes from the fractional knapsack (see greedy/) on
+// what's left, which is worth at least as much as any whole items are; the
+// upper bound from the greedy that takes each remaining item that fits.
+class KnapsackBranchAndBound {
+ public:
+  struct Node {
+    size_t next;  // items before this, in density order, are decided
+    int64_t weight;
+    int64_t value;
+    std::vector<size_t> taken;
+  };
+
+  KnapsackBranchAndBound(const std::vector<KnapsackItem>& items,
+                         int64_t capacity)
+      : items_(items), capacity_(capacity), order_(items.size()) {
+    assert(capacity >= 0 && "negative capacity");
+    std::iota(order_.begin(), order_.end(), 0);
+    std::sort(order_.begin(), order_.end(), [&](size_t a, size_t b) {
+      return items_[a].value * items_[b].weight >
+             items_[b].value * items_[a].weight;
+    });
+  }
+
+  Node root() const { return {0, 0, 0, {}}; }
+
+  bool is_complete(const Node& node) const {
+    return node.next == order_.size();
+  }
+
+  double cost(const Node& node) const {
+    return -static_cast<double>(node.value);
+  }
+
+  std::vector<Node> branch(const Node& node) const {
+    const KnapsackItem& item = items_[order_[node.next]];
+    std::vector<Node> children;
+    if (node.weight + item.weight <= capacity_) {
+      Node in = {node.next + 1, node.weight + item.weight,
+                 node.value + item.value, node.taken};
+      in.taken.push_back(order_[node.next]);
+      children.push_back(std::move(in));
+    }
+    children.push_back({node.next + 1, node.weight, node.value, node.taken});
+    return children;
+  }
+
+  double lower_bound(const Node& node) const {
+    double value = static_cast<double>(node.value);
+    int64_t room = capacity_ - node.weight;
+    for (size_t i = node.next; i < order_.size() && room > 0; ++i) {
+      const KnapsackItem& item = items_[order_[i]];
+      if (item.weight <= room) {
+        value += static_cast<double>(item.value);
+        room -= item.weight;
+      } else {
+        value += static_cast<double>(item.value) * static_cast<double>(room) /
+                 static_cast<double>(item.weight);
+        room = 0;
+      }
+    }
+    return -value;
+  }
+
+  double upper_bound(const Node& node) const {
+    int64_t value = node.value;
+    int64_t room = capacity_ - node.weight;
+    for (size_t i = node.next; i < order_.size(); ++i) {
+      const KnapsackItem& item = items_[order_[i]];
+      if (item.weight <= room) {
+        value += item.value;
+        room -= item.weight;
+      }
+    }
+    return -static_cast<double>(value);
+  }
+
+ private:
+  const std::vector<KnapsackItem>& items_;
+  int64_t capacity_;
+  std::vector<size_t> order_;  // item indices by density, best first
+};
+
+inline KnapsackSolution knapsack_by_branch_and_bound(
+    const std::vector<KnapsackItem>& items, int64_t capacity) {
+  const auto result =
+      branch_and_bound(KnapsackBranchAndBound(items, capacity));
+  KnapsackSolution solution{result.best->value, result.best->taken};
+  std::sort(solution.items.begin(), solution.items.end());
+  return solution;
+}
+
+// The textbook DP over capacities: best[w] is the most value within weight
+// w using the items so far, and capacities go down so that each item is
+// used at most once. O(n W), for integer weights.
+inline int64_t knapsack_value_dp(const std::vector<KnapsackItem>& items,
+                                 int64_t capacity) {
+  std::vector<int64_t> best(capacity + 1, 0);
+  for (const auto& item : items) {
+    for (int64_t w = capacity; w >= item.weight; --w) {
+      best[w] = std::max(best[w], best[w - item.weight] + item.value);
+    }
+  }
+  return best[capacity];
+}
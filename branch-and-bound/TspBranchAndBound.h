@@ -0,0 +1,87 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <vector>
+
+#include "../tsp/Tsp.h"
+#include "BranchAndBound.h"
+
+// Traveling salesman by branch and bound: extend a path from city 0 a city
+// at a time. Every city still to be left, the path's end and each
+// unvisited one, will be left exactly once, for an unvisited city or,
+// last, for 0, so a tour through the path costs at least the path plus the
+// cheapest such edge out of each. Much weaker than a 1-tree bound, but
+// cheap, and enough to cut most of the (n - 1)! orders.
+class TspBranchAndBound {
+ public:
+  struct Node {
+    std::vector<int> path;
+    uint32_t visited;  // bit i for city i
+    double cost;       // along the path
+  };
+
+  explicit TspBranchAndBound(const DistanceMatrix& dist) : dist_(dist) {
+    assert(dist.size() <= 32 && "visited sets are 32 bits");
+  }
+
+  Node root() const { return {{0}, 1, 0.0}; }
+
+  bool is_complete(const Node& node) const {
+    return node.path.size() >= dist_.size();
+  }
+
+  double cost(const Node& node) const {
+    return node.cost + dist_[node.path.back()][0];
+  }
+
+  std::vector<Node> branch(const Node& node) const {
+    std::vector<Node> children;
+    for (size_t city = 0; city < dist_.size(); ++city) {
+      if (!(node.visited >> city & 1)) {
+        Node child = {node.path, node.visited | uint32_t{1} << city,
+                      node.cost + dist_[node.path.back()][city]};
+        child.path.push_back(static_cast<int>(city));
+        children.push_back(std::move(child));
+      }
+    }
+    return children;
+  }
+
+  double lower_bound(const Node& node) const {
+    if (is_complete(node)) {
+      return cost(node);
+    }
+    double bound = node.cost;
+    const auto cheapest_out = [&](size_t from) {
+      double cheapest = dist_[from][0];
+      for (size_t to = 1; to < dist_.size(); ++to) {
+        if (to != from && !(node.visited >> to & 1)) {
+          cheapest = std::min(cheapest, dist_[from][to]);
+        }
+      }
+      return cheapest;
+    };
+    bound += cheapest_out(node.path.back());
+    for (size_t city = 1; city < dist_.size(); ++city) {
+      if (!(node.visited >> city & 1)) {
+        bound += cheapest_out(city);
+      }
+    }
+    return bound;
+  }
+
+ private:
+  const DistanceMatrix& dist_;
+};
+
+inline Tour tsp_by_branch_and_bound(const DistanceMatrix& dist) {
+  if (dist.empty()) {
+    return {};
+  }
+  const auto result = branch_and_bound(TspBranchAndBound(dist));
+  return {result.cost, result.best->path};
+}
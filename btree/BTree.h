@@ -0,0 +1,238 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <memory>
+#include <optional>
+#include <utility>
+#include <vector>
+
+// B-tree of order m (Knuth's definition): every node has at most m children,
+// every internal node other than the root has at least ceil(m / 2) children,
+// and all leaves sit at the same depth.
+//
+// Binary search trees pay one pointer chase per comparison. A B-tree packs
+// up to m - 1 keys into each node, so a lookup touches only O(log_m n) nodes.
+// When a node is a disk block, that's the number of reads -- the reason
+// databases and file systems use B-trees instead of binary trees.
+//
+// Insertion goes down to a leaf and adds the key there. If the leaf now holds
+// m keys, it splits around its median: the median moves up into the parent
+// and the two halves become siblings. The parent may overflow in turn, and a
+// split of the root is the only way the tree grows taller.
+template <typename K>
+class BTree {
+ private:
+  struct Node {
+    std::vector<K> keys;
+    std::vector<std::unique_ptr<Node>> children;  // Empty for leaves
+
+    bool is_leaf() const { return children.empty(); }
+  };
+
+  // Result of splitting an overflowing node: the median key that moves up to
+  // the parent, and the new right sibling.
+  struct Split {
+    K median;
+    std::unique_ptr<Node> right;
+  };
+
+  const size_t order_;
+  std::unique_ptr<Node> root_;
+  size_t size_{0};
+
+  size_t max_keys() const { return order_ - 1; }
+
+  // ceil(m / 2) children means ceil(m / 2) - 1 keys
+  size_t min_keys() const { return (order_ + 1) / 2 - 1; }
+
+  // Index of the first key in node that is not less than key
+  static size_t lower_bound(const Node& node, const K& key) {
+    return std::lower_bound(node.keys.begin(), node.keys.end(), key) -
+           node.keys.begin();
+  }
+
+  Split split(Node& node) {
+    const size_t mid = node.keys.size() / 2;
+    auto right = std::make_unique<Node>();
+
+    right->keys.assign(std::make_move_iterator(node.keys.begin() + mid + 1),
+                       std::make_move_iterator(node.keys.end()));
+    if (!node.is_leaf()) {
+      right->children.assign(
+          std::make_move_iterator(node.children.begin() + mid + 1),
+          std::make_move_iterator(node.children.end()));
+      node.children.resize(mid + 1);
+    }
+    K median = std::move(node.keys[mid]);
+    node.keys.resize(mid);
+
+    return Split{std::move(median), std::move(right)};
+  }
+
+  // Returns {inserted, split}: whether the key was new, and the split that
+  // the caller must absorb if this node overflowed.
+  std::pair<bool, std::optional<Split>> insert(Node& node, const K& key) {
+    const size_t i = lower_bound(node, key);
+    if (i < node.keys.size() && !(key < node.keys[i])) {
+      return {false, std::nullopt};  // Already present
+    }
+
+    if (node.is_leaf()) {
+      node.keys.insert(node.keys.begin() + i, key);
+    } else {
+      auto [inserted, child_split] = insert(*node.children[i], key);
+      if (!inserted) {
+        return {false, std::nullopt};
+      }
+      if (child_split) {
+        node.keys.insert(node.keys.begin() + i, std::move(child_split->median));
+        node.children.insert(node.children.begin() + i + 1,
+                             std::move(child_split->right));
+      }
+    }
+
+    if (node.keys.size() > max_keys()) {
+      return {true, split(node)};
+    }
+    return {true, std::nullopt};
+  }
+
+  void range(const Node& node, const K& lo, const K& hi,
+             std::vector<K>& out) const {
+    // Skip every key (and the subtree to its left) that is below lo
+    size_t i = lower_bound(node, lo);
+    for (; i < node.keys.size(); ++i) {
+      if (!node.is_leaf()) {
+        range(*node.children[i], lo, hi, out);
+      }
+      if (hi < node.keys[i]) {
+        return;
+      }
+      out.push_back(node.keys[i]);
+    }
+    if (!node.is_leaf()) {
+      range(*node.children[i], lo, hi, out);
+    }
+  }
+
+  // Checks the subtree rooted at node. lo and hi are the separator keys
+  // inherited from the ancestors; every key in the subtree must lie strictly
+  // between them. leaf_depth records the depth of the first leaf found.
+  bool check(const Node& node, const K* lo, const K* hi, size_t depth,
+             std::optional<size_t>& leaf_depth) const {
+    const bool is_root = &node == root_.get();
+
+    // Key counts
+    if (node.keys.size() > max_keys()) {
+      return false;
+    }
+    if (!is_root && node.keys.size() < min_keys()) {
+      return false;
+    }
+    if (!node.is_leaf() && node.children.size() != node.keys.size() + 1) {
+      return false;
+    }
+
+    // Sortedness, within the node and against the ancestors' separators
+    for (size_t i = 0; i < node.keys.size(); ++i) {
+      if (i > 0 && !(node.keys[i - 1] < node.keys[i])) {
+        return false;
+      }
+      if ((lo && !(*lo < node.keys[i])) || (hi && !(node.keys[i] < *hi))) {
+        return false;
+      }
+    }
+
+    // Uniform leaf depth
+    if (node.is_leaf()) {
+      if (!leaf_depth) {
+        leaf_depth = depth;
+      }
+      return *leaf_depth == depth;
+    }
+
+    for (size_t i = 0; i < node.children.size(); ++i) {
+      const K* child_lo = i == 0 ? lo : &node.keys[i - 1];
+      const K* child_hi = i == node.keys.size() ? hi : &node.keys[i];
+      if (!check(*node.children[i], child_lo, child_hi, depth + 1,
+                 leaf_depth)) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+ public:
+  explicit BTree(size_t order)
+      : order_(order), root_(std::make_unique<Node>()) {
+    assert(order >= 3 && "B-tree order must be at least 3");
+  }
+
+  size_t order() const { return order_; }
+
+  size_t size() const { return size_; }
+
+  bool empty() const { return size_ == 0; }
+
+  // Number of levels. Every root-to-leaf path visits this many nodes.
+  size_t height() const {
+    size_t h = 1;
+    for (const Node* node = root_.get(); !node->is_leaf();
+         node = node->children[0].get()) {
+      ++h;
+    }
+    return h;
+  }
+
+  // Returns false if the key was already present
+  bool insert(const K& key) {
+    auto [inserted, root_split] = insert(*root_, key);
+    if (!inserted) {
+      return false;
+    }
+    if (root_split) {
+      // The root split: the median becomes the only key of a new root
+      auto new_root = std::make_unique<Node>();
+      new_root->keys.push_back(std::move(root_split->median));
+      new_root->children.push_back(std::move(root_));
+      new_root->children.push_back(std::move(root_split->right));
+      root_ = std::move(new_root);
+    }
+    ++size_;
+    return true;
+  }
+
+  bool contains(const K& key) const {
+    const Node* node = root_.get();
+    while (true) {
+      const size_t i = lower_bound(*node, key);
+      if (i < node->keys.size() && !(key < node->keys[i])) {
+        return true;
+      }
+      if (node->is_leaf()) {
+        return false;
+      }
+      node = node->children[i].get();
+    }
+  }
+
+  // All keys in [lo, hi], in sorted order
+  std::vector<K> range(const K& lo, const K& hi) const {
+    std::vector<K> out;
+    if (!(hi < lo)) {
+      range(*root_, lo, hi, out);
+    }
+    return out;
+  }
+
+  // Verifies the B-tree invariants: every node holds between
+  // ceil(m / 2) - 1 and m - 1 keys (the root may hold fewer), internal nodes
+  // have one more child than keys, keys are sorted and respect the
+  // separators above them, and every leaf is at the same depth.
+  bool check_invariants() const {
+    std::optional<size_t> leaf_depth;
+    return check(*root_, nullptr, nullptr, 0, leaf_depth);
+  }
+};
@@ -0,0 +1,173 @@
+#include <algorithm>
+#include <cmath>
+#include <numeric>
+#include <random>
+#include <set>
+#include <string>
+#include <vector>
+
+#include "BTree.h"
+#include "gtest/gtest.h"
+
+TEST(BTree, EmptyTree) {
+  BTree<int> tree(3);
+
+  EXPECT_TRUE(tree.empty());
+  EXPECT_EQ(tree.height(), 1u);
+  EXPECT_FALSE(tree.contains(1));
+  EXPECT_TRUE(tree.range(0, 100).empty());
+  EXPECT_TRUE(tree.check_invariants());
+}
+
+TEST(BTree, InsertAndContains) {
+  BTree<int> tree(4);
+
+  for (int x : {10, 20, 5, 6, 12, 30, 7, 17}) {
+    EXPECT_TRUE(tree.insert(x));
+    EXPECT_TRUE(tree.check_invariants());
+  }
+
+  EXPECT_EQ(tree.size(), 8u);
+  for (int x : {10, 20, 5, 6, 12, 30, 7, 17}) {
+    EXPECT_TRUE(tree.contains(x));
+  }
+  EXPECT_FALSE(tree.contains(11));
+  EXPECT_FALSE(tree.contains(0));
+  EXPECT_FALSE(tree.contains(31));
+}
+
+TEST(BTree, InsertDuplicates) {
+  BTree<int> tree(3);
+
+  EXPECT_TRUE(tree.insert(1));
+  EXPECT_TRUE(tree.insert(2));
+  EXPECT_TRUE(tree.insert(3));
+  EXPECT_FALSE(tree.insert(2));
+  EXPECT_EQ(tree.size(), 3u);
+  EXPECT_TRUE(tree.check_invariants());
+}
+
+TEST(BTree, RootSplitGrowsHeight) {
+  // With order 3 a node holds at most 2 keys, so the third insert splits the
+  // root.
+  BTree<int> tree(3);
+
+  tree.insert(1);
+  tree.insert(2);
+  EXPECT_EQ(tree.height(), 1u);
+
+  tree.insert(3);
+  EXPECT_EQ(tree.height(), 2u);
+  EXPECT_TRUE(tree.check_invariants());
+}
+
+TEST(BTree, InvariantsHoldForManyOrders) {
+  std::mt19937 gen(7);
+  for (size_t order : {3, 4, 5, 6, 7, 16, 64}) {
+    BTree<int> tree(order);
+    std::vector<int> keys(2000);
+    std::iota(keys.begin(), keys.end(), 0);
+    std::shuffle(keys.begin(), keys.end(), gen);
+
+    for (size_t i = 0; i < keys.size(); ++i) {
+      tree.insert(keys[i]);
+      if (i % 97 == 0) {
+        ASSERT_TRUE(tree.check_invariants()) << "order " << order;
+      }
+    }
+    EXPECT_TRUE(tree.check_invariants()) << "order " << order;
+    EXPECT_EQ(tree.size(), keys.size());
+  }
+}
+
+TEST(BTree, SequentialInsertsStayBalanced) {
+  // Sorted input is the worst case for a plain BST; the B-tree doesn't care.
+  for (size_t order : {3, 4, 10}) {
+    BTree<int> tree(order);
+    for (int i = 0; i < 5000; ++i) {
+      tree.insert(i);
+    }
+    EXPECT_TRUE(tree.check_invariants());
+    for (int i = 0; i < 5000; ++i) {
+      ASSERT_TRUE(tree.contains(i));
+    }
+  }
+}
+
+TEST(BTree, HeightIsLogarithmicInOrder) {
+  // Every non-root node has at least ceil(m / 2) children, so a tree of
+  // height h holds at least 2 * ceil(m / 2)^(h - 1) - 1 keys.
+  const int n = 100000;
+  for (size_t order : {3, 8, 32, 128}) {
+    BTree<int> tree(order);
+    for (int i = 0; i < n; ++i) {
+      tree.insert(i);
+    }
+    const double min_branching = static_cast<double>((order + 1) / 2);
+    const double bound =
+        1.0 + std::log((n + 1) / 2.0) / std::log(min_branching);
+    EXPECT_LE(static_cast<double>(tree.height()), bound) << "order " << order;
+  }
+
+  // Wider nodes mean a shallower tree
+  BTree<int> narrow(3);
+  BTree<int> wide(64);
+  for (int i = 0; i < n; ++i) {
+    narrow.insert(i);
+    wide.insert(i);
+  }
+  EXPECT_LT(wide.height(), narrow.height());
+}
+
+TEST(BTree, RangeScan) {
+  BTree<int> tree(4);
+  for (int i = 0; i < 100; i += 2) {
+    tree.insert(i);
+  }
+
+  EXPECT_EQ(tree.range(10, 20), (std::vector<int>{10, 12, 14, 16, 18, 20}));
+  EXPECT_EQ(tree.range(11, 19), (std::vector<int>{12, 14, 16, 18}));
+  EXPECT_EQ(tree.range(-5, 3), (std::vector<int>{0, 2}));
+  EXPECT_EQ(tree.range(95, 500), (std::vector<int>{96, 98}));
+  EXPECT_EQ(tree.range(7, 7), std::vector<int>{});
+  EXPECT_EQ(tree.range(8, 8), std::vector<int>{8});
+  EXPECT_EQ(tree.range(20, 10), std::vector<int>{});
+  EXPECT_EQ(tree.range(0, 98).size(), 50u);
+}
+
+TEST(BTree, RangeScanMatchesStdSet) {
+  std::mt19937 gen(2024);
+  std::uniform_int_distribution<int> key(0, 10000);
+
+  for (size_t order : {3, 5, 12}) {
+    BTree<int> tree(order);
+    std::set<int> expected;
+    for (int i = 0; i < 3000; ++i) {
+      int k = key(gen);
+      EXPECT_EQ(tree.insert(k), expected.insert(k).second);
+    }
+
+    for (int q = 0; q < 200; ++q) {
+      int lo = key(gen);
+      int hi = lo + key(gen) / 10;
+      std::vector<int> want(expected.lower_bound(lo), expected.upper_bound(hi));
+      ASSERT_EQ(tree.range(lo, hi), want);
+    }
+  }
+}
+
+TEST(BTree, StringKeys) {
+  BTree<std::string> tree(3);
+  for (const char* word : {"pear", "apple", "fig", "kiwi", "banana", "date"}) {
+    tree.insert(word);
+  }
+
+  EXPECT_TRUE(tree.check_invariants());
+  EXPECT_EQ(tree.range("b", "g"),
+            (std::vector<std::string>{"banana", "date", "fig"}));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
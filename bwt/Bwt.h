@@ -0,0 +1,192 @@
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <numeric>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <vector>
+
+#include "../encoding/Encoding.h"
+#include "../suffix-array/SuffixArray.h"
+
+// The Burrows-Wheeler transform, the heart of bzip2. Sort all rotations of
+// the text and keep the last column. Characters that come before similar
+// contexts end up together, so the last column has long runs, and it's
+// still enough to rebuild the text.
+//
+// With a sentinel $ appended that sorts before every character, sorting
+// the rotations is sorting the suffixes, so the suffix array gives the
+// transform: row i ends with the character just before suffix sa[i]. The
+// sentinel itself lands in one row, which is kept as an index instead.
+//
+// Move-to-front then turns the runs into runs of zeros, and the zeros
+// shrink to a count each with the varints from encoding/.
+
+struct Bwt {
+  std::string last;  // the last column, without the sentinel
+  size_t sentinel;   // the row whose last character is the sentinel
+};
+
+inline Bwt bwt(std::string_view text) {
+  std::vector<int> s;
+  s.reserve(text.size() + 1);
+  for (char c : text) {
+    s.push_back(static_cast<unsigned char>(c) + 1);
+  }
+  s.push_back(0);
+  const auto sa = suffix_array(s);
+  Bwt result{std::string(), 0};
+  result.last.reserve(text.size());
+  for (size_t i = 0; i < sa.size(); ++i) {
+    if (sa[i] == 0) {
+      result.sentinel = i;
+    } else {
+      result.last.push_back(text[sa[i] - 1]);
+    }
+  }
+  return result;
+}
+
+// Undoing it: the first column is the last one sorted, and the k-th
+// occurrence of a character in the last column is the same text position
+// as its k-th occurrence in the first column, since both orders sort by
+// what follows. That maps each row to the row of the rotation one to the
+// left (LF). Row 0 is the sentinel's own suffix, so its last character is
+// the text's last, and following the map reads the text backwards. O(n).
+inline std::string inverse_bwt(const Bwt& transformed) {
+  const size_t n = transformed.last.size();
+  if (transformed.sentinel > n) {
+    throw std::invalid_argument("sentinel row out of range");
+  }
+  // Row r's last character, with 0 for the sentinel and c + 1 otherwise
+  auto symbol = [&](size_t r) -> size_t {
+    if (r == transformed.sentinel) {
+      return 0;
+    }
+    const size_t i = r < transformed.sentinel ? r : r - 1;
+    return static_cast<unsigned char>(transformed.last[i]) + size_t{1};
+  };
+  // starts[c] is the first row of the first column starting with symbol c
+  std::array<size_t, 258> starts = {};
+  for (size_t r = 0; r <= n; ++r) {
+    ++starts[symbol(r) + 1];
+  }
+  std::partial_sum(starts.begin(), starts.end(), starts.begin());
+  std::vector<size_t> lf(n + 1);
+  for (size_t r = 0; r <= n; ++r) {
+    lf[r] = starts[symbol(r)]++;
+  }
+
+  std::string text(n, '\0');
+  size_t r = 0;
+  for (size_t k = n; k-- > 0;) {
+    text[k] = static_cast<char>(symbol(r) - 1);
+    r = lf[r];
+  }
+  return text;
+}
+
+// Each byte becomes its position in a list of all 256, which it then moves
+// to the front of: a run of one byte becomes a run of zeros after the
+// first, and recently seen bytes stay small.
+inline Bytes move_to_front_encode(std::string_view text) {
+  std::array<uint8_t, 256> list;
+  std::iota(list.begin(), list.end(), 0);
+  Bytes out;
+  out.reserve(text.size());
+  for (char c : text) {
+    const auto byte = static_cast<uint8_t>(c);
+    uint8_t i = 0;
+    while (list[i] != byte) {
+      ++i;
+    }
+    out.push_back(i);
+    for (; i > 0; --i) {
+      list[i] = list[i - 1];
+    }
+    list[0] = byte;
+  }
+  return out;
+}
+
+inline std::string move_to_front_decode(const Bytes& codes) {
+  std::array<uint8_t, 256> list;
+  std::iota(list.begin(), list.end(), 0);
+  std::string text;
+  text.reserve(codes.size());
+  for (uint8_t i : codes) {
+    const uint8_t byte = list[i];
+    text.push_back(static_cast<char>(byte));
+    for (; i > 0; --i) {
+      list[i] = list[i - 1];
+    }
+    list[0] = byte;
+  }
+  return text;
+}
+
+// The whole pipeline: the text's length and the sentinel row, then the
+// move-to-front codes, with each run of zeros written as a 0 and the
+// length of the run. All of them are varints, so codes below 128 take a
+// byte.
+inline Bytes bwt_compress(std::string_view text) {
+  const Bwt transformed = bwt(text);
+  const Bytes codes = move_to_front_encode(transformed.last);
+  Bytes out;
+  encode_varint(text.size(), out);
+  encode_varint(transformed.sentinel, out);
+  for (size_t i = 0; i < codes.size();) {
+    if (codes[i] != 0) {
+      encode_varint(codes[i++], out);
+      continue;
+    }
+    size_t run = 0;
+    while (i < codes.size() && codes[i] == 0) {
+      ++run;
+      ++i;
+    }
+    encode_varint(0, out);
+    encode_varint(run, out);
+  }
+  return out;
+}
+
+// Throws std::invalid_argument if the bytes aren't from bwt_compress. The
+// codes can't add up to more than the length up front, so a corrupt run
+// can't make it allocate without bound.
+inline std::string bwt_decompress(const Bytes& bytes) {
+  size_t pos = 0;
+  const uint64_t length = decode_varint(bytes, pos);
+  const uint64_t sentinel = decode_varint(bytes, pos);
+  if (sentinel > length) {
+    throw std::invalid_argument("sentinel row out of range");
+  }
+  Bytes codes;
+  while (pos < bytes.size()) {
+    const uint64_t code = decode_varint(bytes, pos);
+    if (code > 255) {
+      throw std::invalid_argument("move-to-front code out of range");
+    }
+    const uint64_t left = length - codes.size();
+    if (code != 0) {
+      if (left == 0) {
+        throw std::invalid_argument("more codes than the length");
+      }
+      codes.push_back(static_cast<uint8_t>(code));
+      continue;
+    }
+    const uint64_t run = decode_varint(bytes, pos);
+    if (run == 0 || run > left) {
+      throw std::invalid_argument("bad run of zeros");
+    }
+    codes.insert(codes.end(), static_cast<size_t>(run), 0);
+  }
+  if (codes.size() != length) {
+    throw std::invalid_argument("fewer codes than the length");
+  }
+  return inverse_bwt(
+      {move_to_front_decode(codes), static_cast<size_t>(sentinel)});
+}
@@ -0,0 +1,109 @@
+#include <algorithm>
+#include <cstddef>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Bwt.h"
+#include "gtest/gtest.h"
+
+// Sort the rotations of text + '\0' for real; '\0' stands in for the
+// sentinel, so the texts mustn't contain it
+Bwt bwt_naive(const std::string& text) {
+  const std::string s = text + '\0';
+  std::vector<std::string> rotations;
+  for (size_t i = 0; i < s.size(); ++i) {
+    rotations.push_back(s.substr(i) + s.substr(0, i));
+  }
+  std::sort(rotations.begin(), rotations.end());
+  Bwt result{std::string(), 0};
+  for (size_t r = 0; r < rotations.size(); ++r) {
+    if (rotations[r].back() == '\0') {
+      result.sentinel = r;
+    } else {
+      result.last.push_back(rotations[r].back());
+    }
+  }
+  return result;
+}
+
+const std::vector<std::string> kSamples = {
+    "",
+    "a",
+    "banana",
+    "abracadabra",
+    "mississippi",
+    "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+    "It was the best of times, it was the worst of times, it was the age "
+    "of wisdom, it was the age of foolishness, it was the epoch of belief, "
+    "it was the epoch of incredulity, it was the season of Light, it was "
+    "the season of Darkness",
+    std::string("bytes \xff\x80\x01 and more \xff\x80\x01", 24),
+};
+
+TEST(Bwt, Banana) {
+  // Rotations of banana$, sorted: $banana a$banan ana$ban anana$b banana$
+  // na$bana nana$ba
+  const Bwt transformed = bwt("banana");
+  EXPECT_EQ(transformed.last, "annbaa");  // annb$aa
+  EXPECT_EQ(transformed.sentinel, 4u);
+  EXPECT_EQ(inverse_bwt(transformed), "banana");
+
+  EXPECT_EQ(move_to_front_encode("aaabbbaaa"),
+            (Bytes{97, 0, 0, 98, 0, 0, 1, 0, 0}));
+  EXPECT_THROW(inverse_bwt({"abc", 4}), std::invalid_argument);
+}
+
+TEST(Bwt, RoundTrips) {
+  for (const auto& text : kSamples) {
+    const Bwt transformed = bwt(text);
+    if (text.find('\0') == std::string::npos) {
+      const Bwt naive = bwt_naive(text);
+      EXPECT_EQ(transformed.last, naive.last) << text;
+      EXPECT_EQ(transformed.sentinel, naive.sentinel) << text;
+    }
+    EXPECT_EQ(inverse_bwt(transformed), text);
+    EXPECT_EQ(move_to_front_decode(move_to_front_encode(text)), text);
+    EXPECT_EQ(bwt_decompress(bwt_compress(text)), text);
+  }
+
+  Rng gen(217);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::string text(uniform_below(gen, 300), '\0');
+    const int alphabet = 1 + static_cast<int>(uniform_below(gen, 256));
+    for (auto& c : text) {
+      c = static_cast<char>(uniform_below(gen, alphabet));
+    }
+    ASSERT_EQ(inverse_bwt(bwt(text)), text);
+    ASSERT_EQ(bwt_decompress(bwt_compress(text)), text);
+  }
+}
+
+TEST(Bwt, Compresses) {
+  // Repetitive text makes long runs, which shrink to a few bytes each
+  std::string text;
+  for (int i = 0; i < 200; ++i) {
+    text += "the quick brown fox jumps over the lazy dog. ";
+  }
+  const Bytes compressed = bwt_compress(text);
+  EXPECT_LT(compressed.size(), text.size() / 10);
+  EXPECT_EQ(bwt_decompress(compressed), text);
+
+  EXPECT_THROW(bwt_decompress(Bytes{0, 0, 0}), std::invalid_argument);
+  EXPECT_THROW(bwt_decompress(Bytes{1, 0, 0x80, 0x02}),
+               std::invalid_argument);
+  // The sentinel past the end, and codes past the length or short of it
+  EXPECT_THROW(bwt_decompress(Bytes{2, 3, 97, 0, 1}), std::invalid_argument);
+  EXPECT_THROW(bwt_decompress(Bytes{1, 0, 97, 98}), std::invalid_argument);
+  EXPECT_THROW(bwt_decompress(Bytes{3, 0, 97}), std::invalid_argument);
+  // A run of zeros far longer than the text is rejected, not allocated
+  EXPECT_THROW(bwt_decompress(Bytes{3, 0, 97, 0, 0xff, 0xff, 0xff, 0xff,
+                                    0xff, 0xff, 0xff, 0xff, 0x7f}),
+               std::invalid_argument);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,180 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <utility>
+#include <vector>
+
+#include "../graph/Graph.h"
+#include "../graph/Tree.h"
+
+// Centroid decomposition. A centroid of a tree is a vertex whose removal
+// leaves no piece with more than half the vertices; every tree has one.
+// Remove it, decompose each piece the same way, and hang the pieces'
+// centroids below it: the result is the centroid tree, of depth at most
+// log2 n, since every level at least halves the piece.
+//
+// It's a divide and conquer over paths. Every path in the tree either
+// passes through the top centroid or lies within one of the pieces, so a
+// question about all paths can be answered at each centroid for the paths
+// through it. Each vertex is in O(log n) pieces, so work linear in the
+// size of each piece adds up to O(n log n).
+
+// The vertices of the piece holding `start`, in BFS order from it. A piece
+// stops at removed vertices. Within it, up[i] is the index of order[i]'s
+// parent (0 for start itself) and depth[i] its distance from start.
+struct Piece {
+  std::vector<int> order;
+  std::vector<size_t> up;
+  std::vector<int> depth;
+};
+
+inline Piece piece_of(const AdjacencyList& tree,
+                      const std::vector<bool>& removed, int start) {
+  Piece piece{{start}, {0}, {0}};
+  for (size_t i = 0; i < piece.order.size(); ++i) {
+    const int u = piece.order[i];
+    const int from = i == 0 ? -1 : piece.order[piece.up[i]];
+    for (int v : tree[u]) {
+      if (!removed[v] && v != from) {
+        piece.order.push_back(v);
+        piece.up.push_back(i);
+        piece.depth.push_back(piece.depth[i] + 1);
+      }
+    }
+  }
+  return piece;
+}
+
+// A centroid of the piece: a vertex whose largest part once it's gone,
+// one of its children's subtrees or everything above it, has at most half
+// the vertices
+inline int centroid_of(const Piece& piece) {
+  const size_t total = piece.order.size();
+  std::vector<size_t> size(total, 1);
+  std::vector<size_t> largest(total, 0);
+  // Children come after their parents, so going backwards finishes each
+  // subtree before its parent needs it
+  for (size_t i = total; i-- > 1;) {
+    size[piece.up[i]] += size[i];
+    largest[piece.up[i]] = std::max(largest[piece.up[i]], size[i]);
+  }
+  for (size_t i = 0; i < total; ++i) {
+    if (2 * std::max(largest[i], total - size[i]) <= total) {
+      return piece.order[i];
+    }
+  }
+  return piece.order[0];  // not reached: every tree has a centroid
+}
+
+// The centroid tree: parent[v] is the centroid whose removal made the piece
+// that v is the centroid of (-1 for the top one, the root), and level[v]
+// is v's depth in the centroid tree.
+struct CentroidDecomposition {
+  int root = -1;
+  std::vector<int> parent;
+  std::vector<int> level;
+};
+
+// Splits pieces until none are left, calling visit(c, removed) at each
+// centroid c just after removing it: c's piece is then c and, for each
+// neighbor that isn't removed, the part of the piece through that
+// neighbor. Returns the centroid tree. O(n log n) plus the visits. Throws
+// std::invalid_argument if the graph isn't a tree.
+template <typename Visit>
+CentroidDecomposition decompose_by_centroids(const AdjacencyList& tree,
+                                             Visit visit) {
+  CentroidDecomposition result;
+  const size_t n = tree.size();
+  if (n == 0) {
+    return result;
+  }
+  RootedTree(tree, 0);  // checks that it's a tree
+  result.parent.assign(n, -1);
+  result.level.assign(n, 0);
+  std::vector<bool> removed(n, false);
+
+  // Pieces still to split: a vertex in the piece and the centroid above it
+  std::vector<std::pair<int, int>> pending = {{0, -1}};
+  while (!pending.empty()) {
+    const auto [start, above] = pending.back();
+    pending.pop_back();
+    const int c = centroid_of(piece_of(tree, removed, start));
+    result.parent[c] = above;
+    if (above == -1) {
+      result.root = c;
+    } else {
+      result.level[c] = result.level[above] + 1;
+    }
+    removed[c] = true;
+    visit(c, removed);
+    for (int v : tree[c]) {
+      if (!removed[v]) {
+        pending.push_back({v, c});
+      }
+    }
+  }
+  return result;
+}
+
+inline CentroidDecomposition centroid_decomposition(const AdjacencyList& tree) {
+  return decompose_by_centroids(tree, [](int, const std::vector<bool>&) {});
+}
+
+// The pairs of values in `sorted` at distinct indices that add up to at
+// most k, by two pointers. O(n).
+inline int64_t pairs_summing_at_most(const std::vector<int>& sorted, int k) {
+  int64_t pairs = 0;
+  size_t lo = 0;
+  size_t hi = sorted.size();
+  while (lo < hi) {
+    if (sorted[lo] + sorted[hi - 1] <= k) {
+      // sorted[lo] pairs with everything in (lo, hi)
+      pairs += static_cast<int64_t>(hi - lo - 1);
+      ++lo;
+    } else {
+      --hi;
+    }
+  }
+  return pairs;
+}
+
+// The number of paths with at most k edges, counting each pair of distinct
+// endpoints once. At each centroid, pair up the depths of everything in the
+// piece, then take back the pairs from the same child's part, whose paths
+// don't pass through the centroid; those are counted again, correctly, in
+// the child's own piece. O(n log^2 n).
+inline int64_t count_paths_at_most(const AdjacencyList& tree, int k) {
+  int64_t paths = 0;
+  decompose_by_centroids(tree, [&](int c, const std::vector<bool>& removed) {
+    std::vector<int> all = {0};
+    for (int child : tree[c]) {
+      if (removed[child]) {
+        continue;
+      }
+      std::vector<int> depths = piece_of(tree, removed, child).depth;
+      for (int& d : depths) {
+        ++d;
+      }
+      std::sort(depths.begin(), depths.end());
+      paths -= pairs_summing_at_most(depths, k);
+      all.insert(all.end(), depths.begin(), depths.end());
+    }
+    std::sort(all.begin(), all.end());
+    paths += pairs_summing_at_most(all, k);
+  });
+  return paths;
+}
+
+// O(n^2): a BFS from every vertex
+inline int64_t count_paths_at_most_naive(const AdjacencyList& tree, int k) {
+  int64_t paths = 0;
+  for (size_t u = 0; u < tree.size(); ++u) {
+    const auto distances = bfs_distances(tree, static_cast<int>(u));
+    for (size_t v = u + 1; v < tree.size(); ++v) {
+      paths += distances[v] <= k;
+    }
+  }
+  return paths;
+}
@@ -0,0 +1,82 @@
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "Centroid.h"
+#include "gtest/gtest.h"
+
+AdjacencyList path(int n) {
+  std::vector<Edge> edges;
+  for (int i = 1; i < n; ++i) {
+    edges.emplace_back(i - 1, i);
+  }
+  return undirected_from_edges(n, edges);
+}
+
+TEST(Centroid, Path) {
+  // The middle vertex, then the middles of the halves, and so on
+  const auto decomposition = centroid_decomposition(path(7));
+  EXPECT_EQ(decomposition.root, 3);
+  EXPECT_EQ(decomposition.parent, (std::vector<int>{1, 3, 1, -1, 5, 3, 5}));
+  EXPECT_EQ(decomposition.level, (std::vector<int>{2, 1, 2, 0, 2, 1, 2}));
+
+  // On a path of n vertices, n - d pairs are d apart
+  EXPECT_EQ(count_paths_at_most(path(7), 0), 0);
+  EXPECT_EQ(count_paths_at_most(path(7), 1), 6);
+  EXPECT_EQ(count_paths_at_most(path(7), 2), 6 + 5);
+  EXPECT_EQ(count_paths_at_most(path(7), 100), 21);
+  EXPECT_EQ(count_paths_at_most(AdjacencyList(1), 5), 0);
+  EXPECT_EQ(count_paths_at_most(AdjacencyList(), 5), 0);
+
+  auto cycle = undirected_from_edges(3, {{0, 1}, {1, 2}, {2, 0}});
+  EXPECT_THROW(centroid_decomposition(cycle), std::invalid_argument);
+}
+
+// Every piece is at most half its parent piece, so the levels go no deeper
+// than log2 n
+TEST(Centroid, PiecesHalve) {
+  Rng gen(215);
+  for (size_t n : {1, 2, 3, 100, 1000, 10000}) {
+    const auto tree = random_stretched_tree(static_cast<int>(n), 0.7, gen);
+    const auto decomposition = centroid_decomposition(tree);
+    // A piece is its centroid and everything below it in the centroid tree
+    std::vector<size_t> piece(n, 0);
+    for (size_t v = 0; v < n; ++v) {
+      for (int c = static_cast<int>(v); c != -1;
+           c = decomposition.parent[c]) {
+        ++piece[c];
+      }
+    }
+    ASSERT_EQ(piece[decomposition.root], n);
+    for (size_t v = 0; v < n; ++v) {
+      const int above = decomposition.parent[v];
+      if (above != -1) {
+        ASSERT_LE(2 * piece[v], piece[above]);
+        ASSERT_EQ(decomposition.level[v], decomposition.level[above] + 1);
+      }
+      ASSERT_LE(size_t{1} << decomposition.level[v], n);
+    }
+  }
+}
+
+TEST(Centroid, CountsMatchNaive) {
+  Rng gen(215);
+  for (int trial = 0; trial < 100; ++trial) {
+    const size_t n = 1 + uniform_below(gen, 300);
+    const auto tree =
+        random_stretched_tree(static_cast<int>(n), uniform_unit(gen), gen);
+    for (int k : {0, 1, 2, 3, 5, 10, 50, 300}) {
+      ASSERT_EQ(count_paths_at_most(tree, k),
+                count_paths_at_most_naive(tree, k))
+          << n << " vertices, k = " << k;
+    }
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,393 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <optional>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../memo/Memo.h"
+
+// Classic interview dynamic programs, each in three forms:
+//
+// - naive: the recurrence written as plain recursion. Exponential, because
+//   the same subproblems are solved over and over.
+// - memoized: the same recursion, caching every answer the first time it's
+//   computed. Top down; only reachable subproblems get solved. The later
+//   ones use the Memo cache from memo/, which can report its hits and
+//   misses.
+// - tabulated: fill a table bottom up in an order where every subproblem's
+//   dependencies come first. No recursion, and often the table shrinks to a
+//   few rows.
+
+// Egg drop: with `eggs` eggs and a building of `floors` floors, find the
+// fewest drops that always identify the highest safe floor. Dropping from
+// floor x either breaks the egg (search the x - 1 floors below with one egg
+// fewer) or doesn't (search the floors - x floors above). Plan for the
+// worse outcome and pick the best x:
+//
+//   drops(e, f) = 1 + min over x of max(drops(e - 1, x - 1), drops(e, f - x))
+
+inline int egg_drop_naive(int eggs, int floors) {
+  assert(eggs >= 1 && floors >= 0);
+  if (floors == 0 || eggs == 1) {
+    return floors;  // With a single egg, try every floor bottom up
+  }
+  int best = std::numeric_limits<int>::max();
+  for (int x = 1; x <= floors; ++x) {
+    best = std::min(best, 1 + std::max(egg_drop_naive(eggs - 1, x - 1),
+                                       egg_drop_naive(eggs, floors - x)));
+  }
+  return best;
+}
+
+inline int egg_drop_memoized(int eggs, int floors,
+                             std::vector<std::vector<int>>& memo) {
+  if (floors == 0 || eggs == 1) {
+    return floors;
+  }
+  int& result = memo[eggs][floors];
+  if (result == -1) {
+    result = std::numeric_limits<int>::max();
+    for (int x = 1; x <= floors; ++x) {
+      result = std::min(
+          result, 1 + std::max(egg_drop_memoized(eggs - 1, x - 1, memo),
+                               egg_drop_memoized(eggs, floors - x, memo)));
+    }
+  }
+  return result;
+}
+
+inline int egg_drop_memoized(int eggs, int floors) {
+  assert(eggs >= 1 && floors >= 0);
+  std::vector<std::vector<int>> memo(eggs + 1,
+                                     std::vector<int>(floors + 1, -1));
+  return egg_drop_memoized(eggs, floors, memo);
+}
+
+// O(eggs * floors^2), keeping only the rows for e - 1 and e eggs
+inline int egg_drop_tabulated(int eggs, int floors) {
+  assert(eggs >= 1 && floors >= 0);
+  std::vector<int> fewer(floors + 1);
+  for (int f = 0; f <= floors; ++f) {
+    fewer[f] = f;
+  }
+  for (int e = 2; e <= eggs; ++e) {
+    std::vector<int> current(floors + 1, 0);
+    for (int f = 1; f <= floors; ++f) {
+      current[f] = std::numeric_limits<int>::max();
+      for (int x = 1; x <= f; ++x) {
+        current[f] =
+            std::min(current[f], 1 + std::max(fewer[x - 1], current[f - x]));
+      }
+    }
+    fewer = std::move(current);
+  }
+  return fewer[floors];
+}
+
+// Turn the question around: with t drops and e eggs, how many floors can be
+// handled? The first drop splits the building into the part below (t - 1
+// drops, e - 1 eggs) and above (t - 1 drops, e eggs), plus the floor itself:
+//
+//   covered(t, e) = covered(t - 1, e - 1) + covered(t - 1, e) + 1
+//
+// which solves to covered(t, e) = C(t, 1) + C(t, 2) + ... + C(t, e). That's
+// increasing in t, so binary search for the smallest t with
+// covered(t, e) >= floors. O(eggs * log(floors)), fast for any building.
+inline bool covers(int64_t drops, int eggs, int64_t floors) {
+  // Binomials get huge, but once the sum reaches floors the answer is known.
+  // Until then the previous binomial is below floors, so the product fits.
+  unsigned __int128 binomial = 1;
+  unsigned __int128 sum = 0;
+  for (int i = 1; i <= eggs && i <= drops; ++i) {
+    binomial = binomial * (drops - i + 1) / i;
+    sum += binomial;
+    if (sum >= static_cast<unsigned __int128>(floors)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+inline int64_t egg_drop_binomial(int eggs, int64_t floors) {
+  assert(eggs >= 1 && floors >= 0);
+  int64_t lo = 0;  // covers(lo) is false, unless floors is 0
+  int64_t hi = floors;
+  while (lo < hi) {
+    const int64_t mid = lo + (hi - lo) / 2;
+    if (covers(mid, eggs, floors)) {
+      hi = mid;
+    } else {
+      lo = mid + 1;
+    }
+  }
+  return lo;
+}
+
+// Climbing stairs: the number of ways to climb n stairs when every move goes
+// up by one of the sizes in steps. Order matters, so 1 + 2 and 2 + 1 are
+// different ways. Counts wrap around modulo 2^64.
+//
+//   ways(n) = sum over s in steps with s <= n of ways(n - s), ways(0) = 1
+
+inline uint64_t climb_stairs_naive(int n, const std::vector<int>& steps) {
+  if (n == 0) {
+    return 1;
+  }
+  uint64_t ways = 0;
+  for (int s : steps) {
+    if (s <= n) {
+      ways += climb_stairs_naive(n - s, steps);
+    }
+  }
+  return ways;
+}
+
+inline uint64_t climb_stairs_memoized(
+    int n, const std::vector<int>& steps,
+    std::vector<std::optional<uint64_t>>& memo) {
+  if (n == 0) {
+    return 1;
+  }
+  if (!memo[n]) {
+    uint64_t ways = 0;
+    for (int s : steps) {
+      assert(s > 0 && "steps must be positive");
+      if (s <= n) {
+        ways += climb_stairs_memoized(n - s, steps, memo);
+      }
+    }
+    memo[n] = ways;
+  }
+  return *memo[n];
+}
+
+inline uint64_t climb_stairs_memoized(int n, const std::vector<int>& steps) {
+  assert(n >= 0);
+  std::vector<std::optional<uint64_t>> memo(n + 1);
+  return climb_stairs_memoized(n, steps, memo);
+}
+
+inline uint64_t climb_stairs_tabulated(int n, const std::vector<int>& steps) {
+  assert(n >= 0);
+  std::vector<uint64_t> ways(n + 1, 0);
+  ways[0] = 1;
+  for (int i = 1; i <= n; ++i) {
+    for (int s : steps) {
+      assert(s > 0 && "steps must be positive");
+      if (s <= i) {
+        ways[i] += ways[i - s];
+      }
+    }
+  }
+  return ways[n];
+}
+
+// Decode ways: letters are encoded as 'A' -> "1", ..., 'Z' -> "26". How many
+// letter strings encode to the given digits? At each position, either the
+// next digit is a letter on its own (1-9), or the next two are (10-26):
+//
+//   decode(i) = [s[i] != '0'] * decode(i + 1) +
+//               [10 <= s[i, i + 2) <= 26] * decode(i + 2)
+//
+// where decode(i) counts the decodings of the suffix starting at i.
+
+inline bool decodes_one(const std::string& s, size_t i) {
+  return s[i] >= '1' && s[i] <= '9';
+}
+
+inline bool decodes_two(const std::string& s, size_t i) {
+  return i + 1 < s.size() &&
+         (s[i] == '1' || (s[i] == '2' && s[i + 1] <= '6')) &&
+         s[i + 1] >= '0' && s[i + 1] <= '9';
+}
+
+inline uint64_t decode_ways_naive(const std::string& s, size_t i = 0) {
+  if (i == s.size()) {
+    return 1;
+  }
+  uint64_t ways = 0;
+  if (decodes_one(s, i)) {
+    ways += decode_ways_naive(s, i + 1);
+  }
+  if (decodes_two(s, i)) {
+    ways += decode_ways_naive(s, i + 2);
+  }
+  return ways;
+}
+
+inline uint64_t decode_ways_memoized(
+    const std::string& s, size_t i,
+    std::vector<std::optional<uint64_t>>& memo) {
+  if (i == s.size()) {
+    return 1;
+  }
+  if (!memo[i]) {
+    uint64_t ways = 0;
+    if (decodes_one(s, i)) {
+      ways += decode_ways_memoized(s, i + 1, memo);
+    }
+    if (decodes_two(s, i)) {
+      ways += decode_ways_memoized(s, i + 2, memo);
+    }
+    memo[i] = ways;
+  }
+  return *memo[i];
+}
+
+inline uint64_t decode_ways_memoized(const std::string& s) {
+  std::vector<std::optional<uint64_t>> memo(s.size());
+  return decode_ways_memoized(s, 0, memo);
+}
+
+// Right to left, keeping only decode(i + 1) and decode(i + 2)
+inline uint64_t decode_ways_tabulated(const std::string& s) {
+  uint64_t next = 1;        // decode(i + 1), starting with the empty suffix
+  uint64_t after_next = 0;  // decode(i + 2), unused until i + 2 <= size
+  for (size_t i = s.size(); i-- > 0;) {
+    uint64_t current = 0;
+    if (decodes_one(s, i)) {
+      current += next;
+    }
+    if (decodes_two(s, i)) {
+      current += after_next;
+    }
+    after_next = next;
+    next = current;
+  }
+  return next;
+}
+
+// Rod cutting: a rod of length n can be cut into pieces of whole lengths,
+// and a piece of length l sells for prices[l - 1]; lengths beyond the price
+// list don't sell. What's the most the rod can make? The first piece has
+// some length l, and the rest is a smaller rod:
+//
+//   best(n) = max over l <= n of prices[l - 1] + best(n - l), best(0) = 0
+
+inline int64_t rod_cutting_naive(int n, const std::vector<int64_t>& prices) {
+  int64_t best = 0;
+  for (int l = 1; l <= n && l <= static_cast<int>(prices.size()); ++l) {
+    best = std::max(best, prices[l - 1] + rod_cutting_naive(n - l, prices));
+  }
+  return best;
+}
+
+inline int64_t rod_cutting_memoized(int n, const std::vector<int64_t>& prices,
+                                    MemoStats* stats = nullptr) {
+  assert(n >= 0);
+  Memo<int, int64_t, DenseCache<int, int64_t>> memo;
+  const int64_t best =
+      memo.recursive(n, [&](int length, auto best_for) -> int64_t {
+        int64_t best = 0;
+        for (int l = 1; l <= length && l <= static_cast<int>(prices.size());
+             ++l) {
+          best = std::max(best, prices[l - 1] + best_for(length - l));
+        }
+        return best;
+      });
+  if (stats != nullptr) {
+    *stats = memo.stats();
+  }
+  return best;
+}
+
+// O(n * prices)
+inline int64_t rod_cutting_tabulated(int n,
+                                     const std::vector<int64_t>& prices) {
+  assert(n >= 0);
+  std::vector<int64_t> best(n + 1, 0);
+  for (int length = 1; length <= n; ++length) {
+    for (int l = 1; l <= length && l <= static_cast<int>(prices.size());
+         ++l) {
+      best[length] = std::max(best[length], prices[l - 1] + best[length - l]);
+    }
+  }
+  return best[n];
+}
+
+// Edit distance: the fewest insertions, deletions, and substitutions of
+// single characters that turn a into b. Working on the suffixes a[i..] and
+// b[j..]: matching first characters cost nothing, and otherwise the first
+// edit deletes a[i], inserts b[j], or substitutes one for the other:
+//
+//   edit(i, j) = edit(i + 1, j + 1)                       if a[i] == b[j]
+//              = 1 + min(edit(i + 1, j), edit(i, j + 1), edit(i + 1, j + 1))
+//
+// and once either suffix is empty, the rest of the other has to be
+// inserted or deleted.
+
+inline size_t edit_distance_naive(const std::string& a, const std::string& b,
+                                  size_t i = 0, size_t j = 0) {
+  if (i == a.size() || j == b.size()) {
+    return (a.size() - i) + (b.size() - j);
+  }
+  if (a[i] == b[j]) {
+    return edit_distance_naive(a, b, i + 1, j + 1);
+  }
+  return 1 + std::min({edit_distance_naive(a, b, i + 1, j),
+                       edit_distance_naive(a, b, i, j + 1),
+                       edit_distance_naive(a, b, i + 1, j + 1)});
+}
+
+// The recursion is a.size() + b.size() deep, so this drives the memo with
+// its explicit stack instead
+inline size_t edit_distance_memoized(const std::string& a,
+                                     const std::string& b,
+                                     MemoStats* stats = nullptr) {
+  using Cell = std::pair<size_t, size_t>;
+  auto dependencies = [&](const Cell& cell) -> std::vector<Cell> {
+    const auto [i, j] = cell;
+    if (i == a.size() || j == b.size()) {
+      return {};
+    }
+    if (a[i] == b[j]) {
+      return {{i + 1, j + 1}};
+    }
+    return {{i + 1, j}, {i, j + 1}, {i + 1, j + 1}};
+  };
+  auto compute = [&](const Cell& cell, auto edit) -> size_t {
+    const auto [i, j] = cell;
+    if (i == a.size() || j == b.size()) {
+      return (a.size() - i) + (b.size() - j);
+    }
+    if (a[i] == b[j]) {
+      return edit({i + 1, j + 1});
+    }
+    return 1 + std::min({edit({i + 1, j}), edit({i, j + 1}),
+                         edit({i + 1, j + 1})});
+  };
+
+  Memo<Cell, size_t, HashCache<Cell, size_t, PairHash>> memo;
+  const size_t distance = memo.iterative({0, 0}, dependencies, compute);
+  if (stats != nullptr) {
+    *stats = memo.stats();
+  }
+  return distance;
+}
+
+// Right to left, keeping only the rows for i + 1 and i. O(|a| |b|) time,
+// O(|b|) space.
+inline size_t edit_distance_tabulated(const std::string& a,
+                                      const std::string& b) {
+  std::vector<size_t> below(b.size() + 1);  // edit(i + 1, .)
+  for (size_t j = 0; j <= b.size(); ++j) {
+    below[j] = b.size() - j;
+  }
+  for (size_t i = a.size(); i-- > 0;) {
+    std::vector<size_t> current(b.size() + 1);
+    current[b.size()] = a.size() - i;
+    for (size_t j = b.size(); j-- > 0;) {
+      current[j] = a[i] == b[j] ? below[j + 1]
+                                : 1 + std::min({below[j], current[j + 1],
+                                                below[j + 1]});
+    }
+    below = std::move(current);
+  }
+  return below[0];
+}
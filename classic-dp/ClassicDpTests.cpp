@@ -0,0 +1,209 @@
+#include <cstdint>
+#include <random>
+#include <string>
+#include <vector>
+
+#include "ClassicDp.h"
+#include "gtest/gtest.h"
+
+TEST(EggDrop, KnownValues) {
+  EXPECT_EQ(egg_drop_tabulated(1, 10), 10);
+  EXPECT_EQ(egg_drop_tabulated(2, 10), 4);
+  EXPECT_EQ(egg_drop_tabulated(2, 36), 8);
+  EXPECT_EQ(egg_drop_tabulated(2, 100), 14);
+  EXPECT_EQ(egg_drop_tabulated(3, 100), 9);
+  EXPECT_EQ(egg_drop_memoized(2, 100), 14);
+  EXPECT_EQ(egg_drop_binomial(2, 100), 14);
+}
+
+TEST(EggDrop, NoFloors) {
+  EXPECT_EQ(egg_drop_naive(3, 0), 0);
+  EXPECT_EQ(egg_drop_memoized(3, 0), 0);
+  EXPECT_EQ(egg_drop_tabulated(3, 0), 0);
+  EXPECT_EQ(egg_drop_binomial(3, 0), 0);
+}
+
+TEST(EggDrop, AllFormsAgree) {
+  for (int eggs = 1; eggs <= 4; ++eggs) {
+    for (int floors = 0; floors <= 14; ++floors) {
+      int expected = egg_drop_naive(eggs, floors);
+
+      ASSERT_EQ(egg_drop_memoized(eggs, floors), expected);
+      ASSERT_EQ(egg_drop_tabulated(eggs, floors), expected);
+      ASSERT_EQ(egg_drop_binomial(eggs, floors), expected);
+    }
+  }
+  for (int eggs = 1; eggs <= 6; ++eggs) {
+    for (int floors = 0; floors <= 300; floors += 7) {
+      ASSERT_EQ(egg_drop_binomial(eggs, floors),
+                egg_drop_tabulated(eggs, floors));
+    }
+  }
+}
+
+TEST(EggDrop, BinomialHandlesHugeBuildings) {
+  // Plenty of eggs is binary search
+  EXPECT_EQ(egg_drop_binomial(64, (int64_t{1} << 40) - 1), 40);
+  EXPECT_EQ(egg_drop_binomial(64, int64_t{1} << 40), 41);
+  // Two eggs: t drops cover t(t + 1)/2 floors
+  EXPECT_EQ(egg_drop_binomial(2, 1000000LL * 1000001 / 2), 1000000);
+  EXPECT_EQ(egg_drop_binomial(1, 1000000000000LL), 1000000000000LL);
+  EXPECT_EQ(egg_drop_binomial(3, INT64_MAX), 3810779);
+}
+
+TEST(ClimbStairs, OneOrTwoStepsIsFibonacci) {
+  std::vector<int> steps = {1, 2};
+
+  EXPECT_EQ(climb_stairs_tabulated(0, steps), 1u);
+  EXPECT_EQ(climb_stairs_tabulated(1, steps), 1u);
+  EXPECT_EQ(climb_stairs_tabulated(10, steps), 89u);
+  EXPECT_EQ(climb_stairs_memoized(90, steps), 4660046610375530309u);
+  EXPECT_EQ(climb_stairs_tabulated(90, steps), 4660046610375530309u);
+}
+
+TEST(ClimbStairs, UnreachableHeights) {
+  std::vector<int> even = {2, 4};
+
+  EXPECT_EQ(climb_stairs_tabulated(7, even), 0u);
+  EXPECT_EQ(climb_stairs_tabulated(6, even), 3u);  // 2+2+2, 2+4, 4+2
+  EXPECT_EQ(climb_stairs_tabulated(5, {}), 0u);
+}
+
+TEST(ClimbStairs, AllFormsAgree) {
+  std::mt19937 gen(137);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<int> steps;
+    for (int s = 1; s <= 6; ++s) {
+      if (std::bernoulli_distribution(0.5)(gen)) {
+        steps.push_back(s);
+      }
+    }
+    int n = std::uniform_int_distribution<int>(0, 20)(gen);
+    uint64_t expected = climb_stairs_naive(n, steps);
+
+    ASSERT_EQ(climb_stairs_memoized(n, steps), expected);
+    ASSERT_EQ(climb_stairs_tabulated(n, steps), expected);
+  }
+}
+
+TEST(DecodeWays, Examples) {
+  EXPECT_EQ(decode_ways_tabulated("12"), 2u);      // AB, L
+  EXPECT_EQ(decode_ways_tabulated("226"), 3u);     // BBF, BZ, VF
+  EXPECT_EQ(decode_ways_tabulated("11106"), 2u);   // AAJF, KJF
+  EXPECT_EQ(decode_ways_tabulated("27"), 1u);
+  EXPECT_EQ(decode_ways_tabulated("10"), 1u);
+  EXPECT_EQ(decode_ways_tabulated(""), 1u);
+}
+
+TEST(DecodeWays, Zeros) {
+  EXPECT_EQ(decode_ways_tabulated("0"), 0u);
+  EXPECT_EQ(decode_ways_tabulated("06"), 0u);
+  EXPECT_EQ(decode_ways_tabulated("100"), 0u);
+  EXPECT_EQ(decode_ways_tabulated("301"), 0u);
+  EXPECT_EQ(decode_ways_memoized("100"), 0u);
+  EXPECT_EQ(decode_ways_naive("100"), 0u);
+}
+
+TEST(DecodeWays, AllFormsAgree) {
+  std::mt19937 gen(138);
+  // Mostly ones and twos, so that there are many decodings
+  const std::string digits = "0111222345678926";
+  std::uniform_int_distribution<size_t> digit(0, digits.size() - 1);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::string s(std::uniform_int_distribution<size_t>(0, 25)(gen), ' ');
+    for (auto& c : s) {
+      c = digits[digit(gen)];
+    }
+    uint64_t expected = decode_ways_naive(s);
+
+    ASSERT_EQ(decode_ways_memoized(s), expected) << s;
+    ASSERT_EQ(decode_ways_tabulated(s), expected) << s;
+  }
+}
+
+TEST(RodCutting, Example) {
+  // The textbook prices: a rod of 4 makes 10 as two pieces of 2
+  std::vector<int64_t> prices = {1, 5, 8, 9, 10, 17, 17, 20};
+
+  EXPECT_EQ(rod_cutting_tabulated(4, prices), 10);
+  EXPECT_EQ(rod_cutting_memoized(8, prices), 22);
+  EXPECT_EQ(rod_cutting_naive(8, prices), 22);
+  EXPECT_EQ(rod_cutting_tabulated(0, prices), 0);
+  // Lengths past the price list have to be cut
+  EXPECT_EQ(rod_cutting_tabulated(20, {0, 3}), 30);
+  EXPECT_EQ(rod_cutting_tabulated(5, {}), 0);
+
+  // Every length from 0 to 8 is computed once, and reused by the longer
+  // rods: length l is asked for by each of the 8 - l longer ones, and all
+  // but the first ask are hits
+  MemoStats stats;
+  rod_cutting_memoized(8, prices, &stats);
+  EXPECT_EQ(stats.misses, 9u);
+  EXPECT_EQ(stats.hits, 8u * 9 / 2 - 8);
+}
+
+TEST(RodCutting, AllFormsAgree) {
+  std::mt19937 gen(168);
+  std::uniform_int_distribution<int64_t> price(0, 30);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<int64_t> prices(gen() % 8);
+    for (auto& p : prices) {
+      p = price(gen);
+    }
+    const int n = static_cast<int>(gen() % 16);
+    const int64_t expected = rod_cutting_naive(n, prices);
+
+    ASSERT_EQ(rod_cutting_memoized(n, prices), expected);
+    ASSERT_EQ(rod_cutting_tabulated(n, prices), expected);
+  }
+}
+
+TEST(EditDistance, Examples) {
+  EXPECT_EQ(edit_distance_tabulated("kitten", "sitting"), 3u);
+  EXPECT_EQ(edit_distance_memoized("kitten", "sitting"), 3u);
+  EXPECT_EQ(edit_distance_naive("kitten", "sitting"), 3u);
+  EXPECT_EQ(edit_distance_tabulated("", "abc"), 3u);
+  EXPECT_EQ(edit_distance_memoized("abc", ""), 3u);
+  EXPECT_EQ(edit_distance_tabulated("flaw", "lawn"), 2u);
+  EXPECT_EQ(edit_distance_memoized("same", "same"), 0u);
+
+  // Identical strings go straight down the diagonal: nothing to reuse
+  MemoStats stats;
+  edit_distance_memoized("abcd", "abcd", &stats);
+  EXPECT_EQ(stats.misses, 5u);
+  EXPECT_EQ(stats.hits, 0u);
+}
+
+TEST(EditDistance, AllFormsAgree) {
+  std::mt19937 gen(169);
+  auto random_string = [&](size_t max_length) {
+    std::string s(gen() % (max_length + 1), ' ');
+    for (auto& c : s) {
+      c = static_cast<char>('a' + gen() % 3);
+    }
+    return s;
+  };
+  for (int trial = 0; trial < 200; ++trial) {
+    const std::string a = random_string(8);
+    const std::string b = random_string(8);
+    const size_t expected = edit_distance_naive(a, b);
+
+    ASSERT_EQ(edit_distance_memoized(a, b), expected) << a << " " << b;
+    ASSERT_EQ(edit_distance_tabulated(a, b), expected) << a << " " << b;
+  }
+}
+
+TEST(EditDistance, LongStringsDontOverflowTheStack) {
+  // 100,000 levels of recursion would be too deep; the memo's own stack
+  // is on the heap
+  const std::string a(100000, 'a');
+  const std::string b = a + "b";
+  EXPECT_EQ(edit_distance_memoized(a, b), 1u);
+  EXPECT_EQ(edit_distance_tabulated(a.substr(0, 1000), b.substr(0, 999)),
+            1u);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
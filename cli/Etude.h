@@ -0,0 +1,142 @@
+#pragma once
+
+#include <algorithm>
+#include <functional>
+#include <istream>
+#include <memory>
+#include <ostream>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+#include "../parse/Parse.h"
+
+// A uniform face for the etudes, so the command line tool and the tests can
+// list and run any of them without knowing which one it is. An Etude has a
+// name, a one-line description, and run(), which parses its input from a
+// stream and writes its answer to another, both as plain text. Each also
+// carries a worked example, an input and the output it must produce, which
+// doubles as documentation and as a test.
+//
+// run() throws std::invalid_argument if the input doesn't parse.
+
+struct Example {
+  std::string input;
+  std::string output;
+};
+
+class Etude {
+ public:
+  virtual ~Etude() = default;
+
+  virtual std::string name() const = 0;
+  virtual std::string description() const = 0;
+  virtual Example example() const = 0;
+  virtual void run(std::istream& in, std::ostream& out) const = 0;
+
+  std::string run(const std::string& input) const {
+    std::istringstream in(input);
+    std::ostringstream out;
+    run(in, out);
+    return out.str();
+  }
+};
+
+// Most etudes are a function wrapped in some parsing, with no state of
+// their own, so they don't need a class each
+class FunctionEtude : public Etude {
+ public:
+  using Run = std::function<void(std::istream&, std::ostream&)>;
+
+  FunctionEtude(std::string name, std::string description, Example example,
+                Run run)
+      : name_(std::move(name)),
+        description_(std::move(description)),
+        example_(std::move(example)),
+        run_(std::move(run)) {}
+
+  std::string name() const override { return name_; }
+  std::string description() const override { return description_; }
+  Example example() const override { return example_; }
+  void run(std::istream& in, std::ostream& out) const override {
+    run_(in, out);
+  }
+
+ private:
+  std::string name_;
+  std::string description_;
+  Example example_;
+  Run run_;
+};
+
+// The etudes by name. Names are unique.
+class Registry {
+ public:
+  void add(std::unique_ptr<Etude> etude) {
+    if (find(etude->name()) != nullptr) {
+      throw std::invalid_argument("duplicate etude: " + etude->name());
+    }
+    etudes_.push_back(std::move(etude));
+    std::sort(etudes_.begin(), etudes_.end(),
+              [](const auto& a, const auto& b) {
+                return a->name() < b->name();
+              });
+  }
+
+  // nullptr if there's no such etude
+  const Etude* find(std::string_view name) const {
+    for (const auto& etude : etudes_) {
+      if (etude->name() == name) {
+        return etude.get();
+      }
+    }
+    return nullptr;
+  }
+
+  // In order of name
+  std::vector<const Etude*> all() const {
+    std::vector<const Etude*> result;
+    for (const auto& etude : etudes_) {
+      result.push_back(etude.get());
+    }
+    return result;
+  }
+
+  size_t size() const { return etudes_.size(); }
+
+ private:
+  std::vector<std::unique_ptr<Etude>> etudes_;
+};
+
+// Parsing helpers for run(): whitespace-separated values, read with the
+// parsers from parse/
+
+template <typename T>
+T read_value(std::istream& in) {
+  std::string token;
+  if (!(in >> token)) {
+    throw ParseError("expected a value");
+  }
+  T value;
+  if (!parse_one(token, value)) {
+    throw ParseError("bad value '" + token + "'");
+  }
+  return value;
+}
+
+// Everything up to the end of the input
+template <typename T>
+std::vector<T> read_values(std::istream& in) {
+  return parse_values<T>(in);
+}
+
+template <typename T>
+void write_values(std::ostream& out, const std::vector<T>& values) {
+  for (size_t i = 0; i < values.size(); ++i) {
+    out << (i > 0 ? " " : "") << values[i];
+  }
+  out << '\n';
+}
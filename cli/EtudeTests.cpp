@@ -0,0 +1,85 @@
+#include <filesystem>
+#include <memory>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+
+#include "Etude.h"
+#include "Etudes.h"
+#include "Fixtures.h"
+#include "gtest/gtest.h"
+
+TEST(Etude, EveryExampleRuns) {
+  const Registry registry = all_etudes();
+  ASSERT_GT(registry.size(), 0u);
+  for (const Etude* etude : registry.all()) {
+    const Example example = etude->example();
+    EXPECT_EQ(etude->run(example.input), example.output) << etude->name();
+    EXPECT_FALSE(etude->description().empty()) << etude->name();
+  }
+}
+
+TEST(Etude, Registry) {
+  Registry registry;
+  auto echo = [](std::istream& in, std::ostream& out) {
+    out << read_value<std::string>(in) << '\n';
+  };
+  registry.add(std::make_unique<FunctionEtude>(
+      "zeta", "last", Example{"z\n", "z\n"}, echo));
+  registry.add(std::make_unique<FunctionEtude>(
+      "alpha", "first", Example{"a\n", "a\n"}, echo));
+
+  ASSERT_EQ(registry.size(), 2u);
+  EXPECT_EQ(registry.all()[0]->name(), "alpha");
+  EXPECT_EQ(registry.all()[1]->name(), "zeta");
+  EXPECT_EQ(registry.find("zeta")->run("hello"), "hello\n");
+  EXPECT_EQ(registry.find("beta"), nullptr);
+  EXPECT_THROW(registry.add(std::make_unique<FunctionEtude>(
+                   "alpha", "again", Example{}, echo)),
+               std::invalid_argument);
+}
+
+TEST(Etude, BadInput) {
+  const Registry registry = all_etudes();
+  EXPECT_THROW(registry.find("lis")->run("1 2 x"), std::invalid_argument);
+  EXPECT_THROW(registry.find("edit-distance")->run("lonely"),
+               std::invalid_argument);
+  EXPECT_THROW(registry.find("envelopes")->run("1 2 3"),
+               std::invalid_argument);
+  EXPECT_THROW(registry.find("permutation-rank")->run("0 0"),
+               std::invalid_argument);
+  EXPECT_THROW(registry.find("tsp")->run("2 0 1 1"), std::invalid_argument);
+  // Negative counts used to wrap around to huge unsigned ones
+  EXPECT_THROW(registry.find("popcount")->run("-1"), std::invalid_argument);
+  EXPECT_THROW(registry.find("rod-cutting")->run("8.5 1 5"),
+               std::invalid_argument);
+}
+
+// Every case under testdata/, next to this file
+TEST(Etude, GoldenFiles) {
+  const auto root = std::filesystem::path(__FILE__).parent_path() / "testdata";
+  const Registry registry = all_etudes();
+  const auto fixtures = load_fixtures(root);
+  ASSERT_FALSE(fixtures.empty());
+  for (const auto& fixture : fixtures) {
+    const auto failure = check_fixture(registry, fixture);
+    EXPECT_FALSE(failure) << *failure;
+  }
+}
+
+TEST(Etude, FixtureFailures) {
+  const Registry registry = all_etudes();
+  EXPECT_EQ(check_fixture(registry, {"lis", "ok", "1 3 2\n", "1 2\n"}),
+            std::nullopt);
+  EXPECT_EQ(check_fixture(registry, {"nope", "a", "", ""}),
+            "nope/a: no etude named nope");
+  EXPECT_EQ(check_fixture(registry, {"lis", "wrong", "2 1\n", "2\n"}),
+            "lis/wrong: expected\n2\nbut got\n1\n");
+  EXPECT_EQ(check_fixture(registry, {"lis", "bad", "x\n", ""}),
+            "lis/bad: threw line 1: bad value 'x'");
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,156 @@
+#pragma once
+
+#include <cstdint>
+#include <istream>
+#include <memory>
+#include <ostream>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../binary-tree/BalanceFactors.h"
+#include "../bits/Bits.h"
+#include "../classic-dp/ClassicDp.h"
+#include "../combinatorics/Permutation.h"
+#include "../envelopes/Envelopes.h"
+#include "../graph/TopologicalSort.h"
+#include "../heavy-hitters/HeavyHitters.h"
+#include "../parse/Parse.h"
+#include "../patience/Patience.h"
+#include "../tsp/Tsp.h"
+#include "Etude.h"
+
+// Every etude the command line tool knows about. Adding one is a single
+// add() here: the tool and the tests pick it up from the registry.
+inline Registry all_etudes() {
+  Registry registry;
+  auto add = [&](std::string name, std::string description, Example example,
+                 FunctionEtude::Run run) {
+    registry.add(std::make_unique<FunctionEtude>(
+        std::move(name), std::move(description), std::move(example),
+        std::move(run)));
+  };
+
+  add("balance-factors", "balance factors of a tree's nodes, in postorder",
+      {"[1,2,3,null,null,4,5,6]\n", "0 0 1 0 1 -2\n"},
+      [](std::istream& in, std::ostream& out) {
+        std::ostringstream text;
+        text << in.rdbuf();
+        const auto root = parse_tree<int>(text.str());
+        write_values(out, compute_balance_factors(root.get()));
+      });
+
+  add("decode-ways", "ways to decode digits, with A = 1, ..., Z = 26",
+      {"226\n", "3\n"}, [](std::istream& in, std::ostream& out) {
+        out << decode_ways_tabulated(read_value<std::string>(in)) << '\n';
+      });
+
+  add("edit-distance", "fewest single-character edits between two words",
+      {"kitten sitting\n", "3\n"}, [](std::istream& in, std::ostream& out) {
+        const auto a = read_value<std::string>(in);
+        const auto b = read_value<std::string>(in);
+        out << edit_distance_tabulated(a, b) << '\n';
+      });
+
+  add("egg-drop", "fewest drops to find the highest safe floor: eggs floors",
+      {"2 100\n", "14\n"}, [](std::istream& in, std::ostream& out) {
+        const int eggs = read_value<int>(in);
+        const auto floors = read_value<int64_t>(in);
+        if (eggs < 1 || floors < 0) {
+          throw std::invalid_argument("need eggs >= 1 and floors >= 0");
+        }
+        out << egg_drop_binomial(eggs, floors) << '\n';
+      });
+
+  add("envelopes", "longest chain of nesting envelopes: width height pairs",
+      {"5 4 6 4 6 7 2 3\n", "3\n"}, [](std::istream& in, std::ostream& out) {
+        const auto sizes = read_values<int>(in);
+        if (sizes.size() % 2 != 0) {
+          throw std::invalid_argument("expected width height pairs");
+        }
+        std::vector<Envelope> envelopes;
+        for (size_t i = 0; i < sizes.size(); i += 2) {
+          envelopes.push_back({sizes[i], sizes[i + 1]});
+        }
+        out << max_nesting(envelopes) << '\n';
+      });
+
+  add("lis", "a longest strictly increasing subsequence",
+      {"10 9 2 5 3 7 101 18\n", "2 3 7 18\n"},
+      [](std::istream& in, std::ostream& out) {
+        write_values(out, longest_increasing_subsequence(read_values<int>(in)));
+      });
+
+  add("permutation-rank", "lexicographic rank of a permutation of 0..n-1",
+      {"1 0 2\n", "2\n"}, [](std::istream& in, std::ostream& out) {
+        const auto perm = read_values<int>(in);
+        check_permutation(perm);
+        out << permutation_rank(perm) << '\n';
+      });
+
+  add("popcount", "number of set bits in each number",
+      {"0 7 255 1024\n", "0 3 8 1\n"}, [](std::istream& in, std::ostream& out) {
+        std::vector<int> counts;
+        for (uint64_t x : read_values<uint64_t>(in)) {
+          counts.push_back(popcount(x));
+        }
+        write_values(out, counts);
+      });
+
+  add("rod-cutting", "best price for a rod: length, then prices by length",
+      {"8 1 5 8 9 10 17 17 20\n", "22\n"},
+      [](std::istream& in, std::ostream& out) {
+        const int n = read_value<int>(in);
+        if (n < 0) {
+          throw std::invalid_argument("negative length");
+        }
+        out << rod_cutting_tabulated(n, read_values<int64_t>(in)) << '\n';
+      });
+
+  add("topological-sort", "an order with every edge u -> v going forward",
+      {"3\n2 0\n0 1\n", "2 0 1\n"}, [](std::istream& in, std::ostream& out) {
+        std::ostringstream text;
+        text << in.rdbuf();
+        const auto order = topological_sort(parse_directed_graph(text.str()));
+        if (order) {
+          write_values(out, *order);
+        } else {
+          out << "cycle\n";
+        }
+      });
+
+  add("top-k", "the k most frequent words: k, then the words",
+      {"2 a b a c b a\n", "a 3\nb 2\n"},
+      [](std::istream& in, std::ostream& out) {
+        const auto k = read_value<size_t>(in);
+        for (const auto& [word, count] :
+             top_k_frequent(read_values<std::string>(in), k)) {
+          out << word << ' ' << count << '\n';
+        }
+      });
+
+  add("tsp", "shortest round trip: n, then the n x n distance matrix",
+      {"4 0 10 15 20 10 0 35 25 15 35 0 30 20 25 30 0\n", "80: 0 2 3 1\n"},
+      [](std::istream& in, std::ostream& out) {
+        const auto n = read_value<size_t>(in);
+        const auto distances = read_values<double>(in);
+        if (distances.size() != n * n || n > 20) {
+          throw std::invalid_argument("expected n <= 20 and n * n distances");
+        }
+        DistanceMatrix dist(n, std::vector<double>(n));
+        for (size_t i = 0; i < n; ++i) {
+          for (size_t j = 0; j < n; ++j) {
+            dist[i][j] = distances[i * n + j];
+          }
+        }
+        const Tour tour = held_karp(dist);
+        out << tour.cost << ':';
+        for (int city : tour.order) {
+          out << ' ' << city;
+        }
+        out << '\n';
+      });
+
+  return registry;
+}
@@ -0,0 +1,91 @@
+#pragma once
+
+#include <algorithm>
+#include <filesystem>
+#include <fstream>
+#include <optional>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "Etude.h"
+
+// Golden files: regression cases for the etudes as plain files, so adding
+// one takes no code. Under a testdata directory, the cases for etude
+// `name` live in testdata/name/, each as a pair of files:
+//
+//   testdata/lis/duplicates.in    the input
+//   testdata/lis/duplicates.out   exactly what the etude must write
+//
+// The cases run through the registry, so every registered etude can have
+// them, and a directory that names no etude is an error rather than a set
+// of cases that silently never runs.
+
+struct Fixture {
+  std::string etude;
+  std::string name;
+  std::string input;
+  std::string expected;
+};
+
+inline std::string read_file(const std::filesystem::path& path) {
+  std::ifstream in(path, std::ios::binary);
+  if (!in) {
+    throw std::runtime_error("can't read " + path.string());
+  }
+  std::ostringstream text;
+  text << in.rdbuf();
+  return text.str();
+}
+
+// Every case under root, in order of etude and then case name. Throws if
+// an .in file has no .out next to it.
+inline std::vector<Fixture> load_fixtures(const std::filesystem::path& root) {
+  std::vector<Fixture> fixtures;
+  for (const auto& dir : std::filesystem::directory_iterator(root)) {
+    if (!dir.is_directory()) {
+      continue;
+    }
+    for (const auto& file : std::filesystem::directory_iterator(dir)) {
+      const auto& path = file.path();
+      if (path.extension() != ".in") {
+        continue;
+      }
+      auto out = path;
+      out.replace_extension(".out");
+      if (!std::filesystem::exists(out)) {
+        throw std::runtime_error(path.string() + " has no " +
+                                 out.filename().string());
+      }
+      fixtures.push_back({dir.path().filename().string(),
+                          path.stem().string(), read_file(path),
+                          read_file(out)});
+    }
+  }
+  std::sort(fixtures.begin(), fixtures.end(),
+            [](const Fixture& a, const Fixture& b) {
+              return a.etude != b.etude ? a.etude < b.etude : a.name < b.name;
+            });
+  return fixtures;
+}
+
+// What went wrong with one case, or std::nullopt if it passed
+inline std::optional<std::string> check_fixture(const Registry& registry,
+                                                const Fixture& fixture) {
+  const std::string where = fixture.etude + "/" + fixture.name;
+  const Etude* etude = registry.find(fixture.etude);
+  if (etude == nullptr) {
+    return where + ": no etude named " + fixture.etude;
+  }
+  std::string output;
+  try {
+    output = etude->run(fixture.input);
+  } catch (const std::exception& e) {
+    return where + ": threw " + e.what();
+  }
+  if (output != fixture.expected) {
+    return where + ": expected\n" + fixture.expected + "but got\n" + output;
+  }
+  return std::nullopt;
+}
@@ -0,0 +1,291 @@
+#include <algorithm>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <iostream>
+#include <iterator>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../compare/Comparisons.h"
+#include "../life/Life.h"
+#include "../maze/Maze.h"
+#include "../maze/Play.h"
+#include "../sat/Sat.h"
+#include "../parse/Parse.h"
+#include "../sorting/Animation.h"
+#include "../sorting/Sorting.h"
+#include "../viz/Viz.h"
+#include "Etudes.h"
+#include "Fixtures.h"
+
+// etudes list              names and descriptions
+// etudes example <name>    the worked example's input and output
+// etudes run <name>        runs on standard input
+// etudes check <testdata>  runs the golden files under a testdata directory
+// etudes viz <kind> <file>  draws standard input as SVG: tree, skyline,
+//                           hull, or sort
+// etudes sort <name> play   sorts standard input, drawing every step in the
+//                           terminal: insertion, quick, heap, or merge
+// etudes sort <name> json   the same steps as JSON, for an animator
+// etudes compare <name> [--n N] [--seed S] [--limit-ms T]
+//                           races the naive version against the optimized
+//                           one on a random input of size N
+// etudes maze <kind> [--rows R] [--cols C] [--seed S] [--solve|--play]
+//                           draws a random maze: backtracker, kruskal, or
+//                           prim; with its solution, or to walk through
+// etudes life <file> [--generations G] [--rows R] [--cols C] [--torus]
+//                           plays the Game of Life from a pattern in
+//                           plaintext or RLE, in an R × C window; with
+//                           --torus, on an R × C board whose edges wrap
+// etudes sat <file.cnf>    solves a DIMACS CNF formula, writing the result
+//                           the way SAT competition solvers do
+// The compare, maze, and life options are numbers, and may group digits with
+// underscores, as in --n 1_000_000.
+static bool parse_count(std::string text, uint64_t& value) {
+  text.erase(std::remove(text.begin(), text.end(), '_'), text.end());
+  return parse_one(text, value);
+}
+
+static int compare(const std::string& name, int argc, char* argv[]) {
+  const auto comparisons = all_comparisons();
+  const Comparison* comparison = find_comparison(comparisons, name);
+  if (comparison == nullptr) {
+    std::cerr << "no comparison named " << name << "; try";
+    for (const auto& c : comparisons) {
+      std::cerr << ' ' << c.name;
+    }
+    std::cerr << '\n';
+    return 1;
+  }
+  uint64_t n = 10000;
+  uint64_t seed = 185;
+  uint64_t limit_ms = 10000;
+  for (int i = 0; i < argc; i += 2) {
+    const std::string option = argv[i];
+    uint64_t* value = option == "--n"          ? &n
+                      : option == "--seed"     ? &seed
+                      : option == "--limit-ms" ? &limit_ms
+                                               : nullptr;
+    if (value == nullptr || i + 1 == argc ||
+        !parse_count(argv[i + 1], *value)) {
+      std::cerr << "usage: etudes compare <name> [--n N] [--seed S] "
+                   "[--limit-ms T]\n";
+      return 2;
+    }
+  }
+  const auto report = comparison->run(static_cast<size_t>(n), seed,
+                                      std::chrono::milliseconds(limit_ms));
+  std::cout << format_report(report);
+  return report.agree == false ? 1 : 0;
+}
+
+static int maze(const std::string& kind, int argc, char* argv[]) {
+  uint64_t rows = 10;
+  uint64_t cols = 20;
+  uint64_t seed = 196;
+  bool solve = false;
+  bool play = false;
+  auto usage = [] {
+    std::cerr << "usage: etudes maze <kind> [--rows R] [--cols C] "
+                 "[--seed S] [--solve|--play]\n";
+    return 2;
+  };
+  for (int i = 0; i < argc; ++i) {
+    const std::string option = argv[i];
+    if (option == "--solve" || option == "--play") {
+      (option == "--solve" ? solve : play) = true;
+      continue;
+    }
+    uint64_t* value = option == "--rows"   ? &rows
+                      : option == "--cols" ? &cols
+                      : option == "--seed" ? &seed
+                                           : nullptr;
+    if (value == nullptr || i + 1 == argc ||
+        !parse_count(argv[++i], *value) || *value == 0 ||
+        (value != &seed && *value > 1000)) {
+      return usage();
+    }
+  }
+  if (solve && play) {
+    return usage();
+  }
+  Rng gen(seed);
+  const auto maze = maze_by_name(kind, static_cast<int>(rows),
+                                 static_cast<int>(cols), gen);
+  if (!maze) {
+    std::cerr << "no maze generator named " << kind
+              << "; try backtracker, kruskal, or prim\n";
+    return 1;
+  }
+  if (play) {
+    return play_maze(*maze, std::cin, std::cout) ? 0 : 1;
+  }
+  std::vector<Cell> path;
+  if (solve) {
+    path = *solve_maze(*maze, {0, 0}, {maze->rows() - 1, maze->cols() - 1});
+  }
+  std::cout << render_maze(*maze, path);
+  return 0;
+}
+
+static int life(const std::string& file, int argc, char* argv[]) {
+  uint64_t generations = 100;
+  uint64_t rows = 24;
+  uint64_t cols = 60;
+  bool torus = false;
+  for (int i = 0; i < argc; ++i) {
+    const std::string option = argv[i];
+    if (option == "--torus") {
+      torus = true;
+      continue;
+    }
+    uint64_t* value = option == "--generations" ? &generations
+                      : option == "--rows"      ? &rows
+                      : option == "--cols"      ? &cols
+                                                : nullptr;
+    if (value == nullptr || i + 1 == argc ||
+        !parse_count(argv[++i], *value) ||
+        (value != &generations && (*value == 0 || *value > 1000))) {
+      std::cerr << "usage: etudes life <file> [--generations G] [--rows R] "
+                   "[--cols C] [--torus]\n";
+      return 2;
+    }
+  }
+  Pattern pattern;
+  try {
+    pattern = parse_pattern(read_file(file));
+  } catch (const std::exception& e) {
+    std::cerr << file << ": " << e.what() << '\n';
+    return 1;
+  }
+  // One dead row and column around the pattern, to give it room
+  const int r = static_cast<int>(rows);
+  const int c = static_cast<int>(cols);
+  const auto delay = std::chrono::milliseconds(100);
+  if (torus) {
+    Torus board(r, c, pattern, {1, 1});
+    play_life(board, generations, {0, 0}, r, c, std::cout, delay, true);
+  } else {
+    Life board(pattern, {1, 1});
+    play_life(board, generations, {0, 0}, r, c, std::cout, delay, true);
+  }
+  return 0;
+}
+
+int main(int argc, char* argv[]) {
+  const Registry registry = all_etudes();
+  const std::string command = argc > 1 ? argv[1] : "";
+
+  if (command == "list" && argc == 2) {
+    for (const Etude* etude : registry.all()) {
+      std::cout << etude->name() << ": " << etude->description() << '\n';
+    }
+    return 0;
+  }
+  if (command == "check" && argc == 3) {
+    try {
+      const auto fixtures = load_fixtures(argv[2]);
+      int failures = 0;
+      for (const auto& fixture : fixtures) {
+        if (auto failure = check_fixture(registry, fixture)) {
+          std::cerr << *failure << '\n';
+          ++failures;
+        }
+      }
+      std::cout << fixtures.size() - failures << " of " << fixtures.size()
+                << " cases passed\n";
+      return failures == 0 ? 0 : 1;
+    } catch (const std::exception& e) {
+      std::cerr << e.what() << '\n';
+      return 1;
+    }
+  }
+  if (command == "viz" && argc == 4) {
+    try {
+      const std::string input(std::istreambuf_iterator<char>(std::cin), {});
+      write_file(argv[3], render_svg(argv[2], input));
+    } catch (const std::exception& e) {
+      std::cerr << e.what() << '\n';
+      return 1;
+    }
+    return 0;
+  }
+  if (command == "sort" && argc == 4 &&
+      (std::string(argv[3]) == "play" || std::string(argv[3]) == "json")) {
+    FrameRecorder<int64_t> recorder;
+    try {
+      auto values = parse_values<int64_t>(std::cin);
+      if (!sort_by_name(argv[2], values, recorder)) {
+        std::cerr << "no sort named " << argv[2]
+                  << "; try insertion, quick, heap, or merge\n";
+        return 1;
+      }
+    } catch (const std::exception& e) {
+      std::cerr << e.what() << '\n';
+      return 1;
+    }
+    if (std::string(argv[3]) == "json") {
+      std::cout << to_json(recorder.frames()) << '\n';
+    } else {
+      play_frames(recorder.frames(), std::cout,
+                  std::chrono::milliseconds(100), true);
+    }
+    return 0;
+  }
+  if (command == "compare" && argc >= 3) {
+    // A version that timed out may still be running on its abandoned
+    // thread, so leave without running static destructors under it
+    const int status = compare(argv[2], argc - 3, argv + 3);
+    std::cout.flush();
+    std::_Exit(status);
+  }
+  if (command == "life" && argc >= 3) {
+    return life(argv[2], argc - 3, argv + 3);
+  }
+  if (command == "sat" && argc == 3) {
+    try {
+      const SatResult result = solve_sat(parse_dimacs(read_file(argv[2])));
+      std::cout << format_sat_result(result);
+      const SatStats& stats = result.stats;
+      std::cout << "c " << stats.decisions << " decisions, "
+                << stats.propagations << " propagations, "
+                << stats.pure_literals << " pure literals, "
+                << stats.conflicts << " conflicts\n";
+      return 0;
+    } catch (const std::exception& e) {
+      std::cerr << argv[2] << ": " << e.what() << '\n';
+      return 1;
+    }
+  }
+  if (command == "maze" && argc >= 3) {
+    return maze(argv[2], argc - 3, argv + 3);
+  }
+  if ((command == "run" || command == "example") && argc == 3) {
+    const Etude* etude = registry.find(argv[2]);
+    if (etude == nullptr) {
+      std::cerr << "no etude named " << argv[2] << "; try etudes list\n";
+      return 1;
+    }
+    if (command == "example") {
+      std::cout << "input:\n" << etude->example().input << "output:\n"
+                << etude->example().output;
+      return 0;
+    }
+    try {
+      etude->run(std::cin, std::cout);
+    } catch (const std::exception& e) {
+      std::cerr << etude->name() << ": " << e.what() << '\n';
+      return 1;
+    }
+    return 0;
+  }
+  std::cerr << "usage: etudes list | etudes example <name> | "
+               "etudes run <name> | etudes check <testdata> | "
+               "etudes viz <kind> <file> | etudes sort <name> play|json | "
+               "etudes compare <name> [--n N] | etudes maze <kind> | "
+               "etudes life <file> | etudes sat <file.cnf>\n";
+  return 2;
+}
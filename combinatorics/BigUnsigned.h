@@ -0,0 +1,145 @@
+#pragma once
+
+#include <cassert>
+#include <compare>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Just enough of an arbitrary-precision unsigned integer for counting
+// recurrences and exact decimals (see decimal/): addition, subtraction,
+// multiplication, division by a small number, comparison, and printing.
+// Little-endian limbs in base 10^9, so that printing is easy.
+class BigUnsigned {
+ public:
+  explicit BigUnsigned(uint64_t value = 0) {
+    for (; value > 0; value /= kBase) {
+      limbs_.push_back(value % kBase);
+    }
+  }
+
+  BigUnsigned operator+(const BigUnsigned& other) const {
+    BigUnsigned sum;
+    uint64_t carry = 0;
+    for (size_t i = 0; i < limbs_.size() || i < other.limbs_.size() || carry;
+         ++i) {
+      carry += limb(i) + other.limb(i);
+      sum.limbs_.push_back(static_cast<uint32_t>(carry % kBase));
+      carry /= kBase;
+    }
+    return sum;
+  }
+
+  BigUnsigned operator*(uint32_t factor) const {
+    BigUnsigned product;
+    if (factor == 0) {
+      return product;
+    }
+    uint64_t carry = 0;
+    for (size_t i = 0; i < limbs_.size() || carry; ++i) {
+      carry += static_cast<uint64_t>(limb(i)) * factor;
+      product.limbs_.push_back(static_cast<uint32_t>(carry % kBase));
+      carry /= kBase;
+    }
+    return product;
+  }
+
+  // Schoolbook, O(nm) in the limbs
+  BigUnsigned operator*(const BigUnsigned& other) const {
+    BigUnsigned product;
+    if (limbs_.empty() || other.limbs_.empty()) {
+      return product;
+    }
+    std::vector<uint64_t> sums(limbs_.size() + other.limbs_.size(), 0);
+    for (size_t i = 0; i < limbs_.size(); ++i) {
+      uint64_t carry = 0;
+      for (size_t j = 0; j < other.limbs_.size() || carry; ++j) {
+        carry += sums[i + j] +
+                 static_cast<uint64_t>(limbs_[i]) * other.limb(j);
+        sums[i + j] = carry % kBase;
+        carry /= kBase;
+      }
+    }
+    for (uint64_t sum : sums) {
+      product.limbs_.push_back(static_cast<uint32_t>(sum));
+    }
+    product.trim();
+    return product;
+  }
+
+  // this - other, which must not be negative
+  BigUnsigned operator-(const BigUnsigned& other) const {
+    assert(*this >= other && "BigUnsigned can't go negative");
+    BigUnsigned difference;
+    int64_t borrow = 0;
+    for (size_t i = 0; i < limbs_.size(); ++i) {
+      int64_t limb_difference =
+          static_cast<int64_t>(limbs_[i]) - other.limb(i) - borrow;
+      borrow = limb_difference < 0;
+      if (borrow) {
+        limb_difference += kBase;
+      }
+      difference.limbs_.push_back(static_cast<uint32_t>(limb_difference));
+    }
+    difference.trim();
+    return difference;
+  }
+
+  // The quotient and remainder of dividing by a nonzero divisor
+  std::pair<BigUnsigned, uint32_t> divide(uint32_t divisor) const {
+    assert(divisor > 0 && "division by zero");
+    BigUnsigned quotient;
+    quotient.limbs_.resize(limbs_.size());
+    uint64_t remainder = 0;
+    for (size_t i = limbs_.size(); i-- > 0;) {
+      remainder = remainder * kBase + limbs_[i];
+      quotient.limbs_[i] = static_cast<uint32_t>(remainder / divisor);
+      remainder %= divisor;
+    }
+    quotient.trim();
+    return {quotient, static_cast<uint32_t>(remainder)};
+  }
+
+  bool is_zero() const { return limbs_.empty(); }
+
+  bool operator==(const BigUnsigned&) const = default;
+
+  std::strong_ordering operator<=>(const BigUnsigned& other) const {
+    if (limbs_.size() != other.limbs_.size()) {
+      return limbs_.size() <=> other.limbs_.size();
+    }
+    for (size_t i = limbs_.size(); i-- > 0;) {
+      if (limbs_[i] != other.limbs_[i]) {
+        return limbs_[i] <=> other.limbs_[i];
+      }
+    }
+    return std::strong_ordering::equal;
+  }
+
+  std::string to_string() const {
+    if (limbs_.empty()) {
+      return "0";
+    }
+    std::string result = std::to_string(limbs_.back());
+    for (size_t i = limbs_.size() - 1; i-- > 0;) {
+      const std::string digits = std::to_string(limbs_[i]);
+      result += std::string(9 - digits.size(), '0') + digits;
+    }
+    return result;
+  }
+
+ private:
+  static constexpr uint32_t kBase = 1'000'000'000;
+
+  uint32_t limb(size_t i) const { return i < limbs_.size() ? limbs_[i] : 0; }
+
+  void trim() {
+    while (!limbs_.empty() && limbs_.back() == 0) {
+      limbs_.pop_back();
+    }
+  }
+
+  std::vector<uint32_t> limbs_;  // No leading zero limbs, so 0 is empty
+};
@@ -0,0 +1,255 @@
+#pragma once
+
+#include <algorithm>
+#include <array>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <map>
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <variant>
+#include <vector>
+
+#include "../binary-tree/TreeNode.h"
+#include "../errors/Error.h"
+
+// Catalan families: the Catalan number C(n) counts, among many others,
+//
+// - binary trees with n nodes,
+// - triangulations of a convex polygon with n + 2 vertices, and
+// - full bracketings of a product of n + 1 factors, e.g. "((ab)c)".
+//
+// They all decompose the same way. A tree is a root with a left subtree of
+// k nodes and a right subtree of n - 1 - k. A triangulation has one triangle
+// on the edge from the first vertex to the last, whose apex splits the rest
+// into two smaller polygons. A bracketing is the product of two smaller ones.
+// So every family satisfies
+//
+//   C(0) = 1,  C(n) = sum over k of C(k) * C(n - 1 - k)
+//
+// and following the decompositions side by side turns any member of one
+// family into a member of another.
+
+// C(n) = binomial(2n, n) / (n + 1), from C(n + 1) = C(n) * 2(2n + 1) / (n + 2).
+// C(35) is the largest that fits in 64 bits.
+inline Result<uint64_t> try_catalan(int n) {
+  if (n < 0) {
+    return Error{ErrorKind::kInvalidInput, "C(n) needs n >= 0"};
+  }
+  if (n > 35) {
+    return Error{ErrorKind::kOverflow, "C(n) overflows 64 bits"};
+  }
+  unsigned __int128 c = 1;
+  for (int i = 0; i < n; ++i) {
+    c = c * (2 * (2 * i + 1)) / (i + 2);
+  }
+  return static_cast<uint64_t>(c);
+}
+
+inline uint64_t catalan(int n) { return try_catalan(n).or_throw(); }
+
+// Binary trees where only the shape matters
+using Shape = TreeNode<std::monostate>;
+
+inline std::unique_ptr<Shape> clone_shape(const Shape* shape) {
+  if (shape == nullptr) {
+    return nullptr;
+  }
+  return make_node(std::monostate{}, clone_shape(shape->left.get()),
+                   clone_shape(shape->right.get()));
+}
+
+inline size_t shape_size(const Shape* shape) {
+  return shape == nullptr ? 0
+                          : 1 + shape_size(shape->left.get()) +
+                                shape_size(shape->right.get());
+}
+
+// Every shape with n nodes, smaller left subtrees first. Subtrees are copied
+// into each tree that uses them, so this is O(n * C(n)).
+inline std::vector<std::unique_ptr<Shape>> all_shapes(int n) {
+  std::vector<std::unique_ptr<Shape>> result;
+  if (n == 0) {
+    result.push_back(nullptr);
+    return result;
+  }
+  for (int k = 0; k < n; ++k) {
+    const auto lefts = all_shapes(k);
+    const auto rights = all_shapes(n - 1 - k);
+    for (const auto& l : lefts) {
+      for (const auto& r : rights) {
+        result.push_back(make_node(std::monostate{}, clone_shape(l.get()),
+                                   clone_shape(r.get())));
+      }
+    }
+  }
+  return result;
+}
+
+// Triangulations of the polygon with vertices 0, 1, ..., n + 1 in order,
+// as the sorted list of triangles, each with its vertices sorted
+using Triangle = std::array<int, 3>;
+using Triangulation = std::vector<Triangle>;
+
+// Triangulations of the sub-polygon i, i + 1, ..., j, closed by the edge i-j
+inline std::vector<Triangulation> triangulations(int i, int j) {
+  if (j - i < 2) {
+    return {Triangulation{}};  // Just an edge
+  }
+  std::vector<Triangulation> result;
+  for (int apex = i + 1; apex < j; ++apex) {
+    const auto lows = triangulations(i, apex);
+    const auto highs = triangulations(apex, j);
+    for (const auto& low : lows) {
+      for (const auto& high : highs) {
+        Triangulation t = low;
+        t.push_back({i, apex, j});
+        t.insert(t.end(), high.begin(), high.end());
+        std::sort(t.begin(), t.end());
+        result.push_back(std::move(t));
+      }
+    }
+  }
+  return result;
+}
+
+// Every triangulation of the polygon with n + 2 vertices
+inline std::vector<Triangulation> all_triangulations(int n) {
+  assert(n >= 0);
+  return triangulations(0, n + 1);
+}
+
+// Full bracketings of the product of the letters first, ..., last
+inline std::vector<std::string> bracketings(char first, char last) {
+  if (first == last) {
+    return {std::string(1, first)};
+  }
+  std::vector<std::string> result;
+  for (char split = first; split < last; ++split) {
+    const auto lefts = bracketings(first, split);
+    const auto rights = bracketings(split + 1, last);
+    for (const auto& l : lefts) {
+      for (const auto& r : rights) {
+        result.push_back("(" + l + r + ")");
+      }
+    }
+  }
+  return result;
+}
+
+// Every bracketing of the product of n + 1 factors a, b, c, ...
+inline std::vector<std::string> all_bracketings(int n) {
+  assert(n >= 0 && n < 26 && "one letter per factor");
+  return bracketings('a', static_cast<char>('a' + n));
+}
+
+// Bijections. Trees are the hub: a node is a triangle, or a product, whose
+// left and right subtrees are the two smaller pieces on either side.
+
+// A node of the shape for the sub-polygon i..j is the triangle on edge i-j.
+// Its apex comes after all the nodes of the left subtree, in inorder.
+inline void collect_triangles(const Shape* shape, int i, int j,
+                              Triangulation& out) {
+  if (shape == nullptr) {
+    return;
+  }
+  const int apex = i + 1 + static_cast<int>(shape_size(shape->left.get()));
+  collect_triangles(shape->left.get(), i, apex, out);
+  out.push_back({i, apex, j});
+  collect_triangles(shape->right.get(), apex, j, out);
+}
+
+inline Triangulation to_triangulation(const Shape* shape) {
+  Triangulation t;
+  collect_triangles(shape, 0, static_cast<int>(shape_size(shape)) + 1, t);
+  std::sort(t.begin(), t.end());
+  return t;
+}
+
+// The apex of the triangle on each edge i-j, with i < j
+using Apexes = std::map<std::pair<int, int>, int>;
+
+inline std::unique_ptr<Shape> shape_from_apexes(const Apexes& apexes, int i,
+                                                int j) {
+  if (j - i < 2) {
+    return nullptr;
+  }
+  auto it = apexes.find({i, j});
+  if (it == apexes.end()) {
+    throw std::invalid_argument("not a triangulation");
+  }
+  return make_node(std::monostate{}, shape_from_apexes(apexes, i, it->second),
+                   shape_from_apexes(apexes, it->second, j));
+}
+
+// Throws std::invalid_argument unless t triangulates the polygon on
+// 0, ..., t.size() + 1
+inline std::unique_ptr<Shape> from_triangulation(const Triangulation& t) {
+  Apexes apexes;
+  for (auto [a, b, c] : t) {
+    if (!(a < b && b < c) || !apexes.emplace(std::pair{a, c}, b).second) {
+      throw std::invalid_argument("not a triangulation");
+    }
+  }
+  auto shape = shape_from_apexes(apexes, 0, static_cast<int>(t.size()) + 1);
+  if (shape_size(shape.get()) != t.size()) {
+    throw std::invalid_argument("not a triangulation");
+  }
+  return shape;
+}
+
+// Missing children are the factors, lettered left to right
+inline void write_bracketing(const Shape* shape, char& next_letter,
+                             std::string& out) {
+  if (shape == nullptr) {
+    out += next_letter++;
+    return;
+  }
+  out += '(';
+  write_bracketing(shape->left.get(), next_letter, out);
+  write_bracketing(shape->right.get(), next_letter, out);
+  out += ')';
+}
+
+inline std::string to_bracketing(const Shape* shape) {
+  assert(shape_size(shape) < 26 && "one letter per factor");
+  char next_letter = 'a';
+  std::string result;
+  write_bracketing(shape, next_letter, result);
+  return result;
+}
+
+inline std::unique_ptr<Shape> parse_bracketing(const std::string& s,
+                                               size_t& pos, char& next_letter) {
+  if (pos < s.size() && s[pos] == next_letter) {
+    ++pos;
+    ++next_letter;
+    return nullptr;
+  }
+  if (pos >= s.size() || s[pos] != '(') {
+    throw std::invalid_argument("not a bracketing");
+  }
+  ++pos;
+  auto left = parse_bracketing(s, pos, next_letter);
+  auto right = parse_bracketing(s, pos, next_letter);
+  if (pos >= s.size() || s[pos] != ')') {
+    throw std::invalid_argument("not a bracketing");
+  }
+  ++pos;
+  return make_node(std::monostate{}, std::move(left), std::move(right));
+}
+
+// Throws std::invalid_argument unless s is a full bracketing of a, b, c, ...
+// in order, like the ones from all_bracketings
+inline std::unique_ptr<Shape> from_bracketing(const std::string& s) {
+  size_t pos = 0;
+  char next_letter = 'a';
+  auto shape = parse_bracketing(s, pos, next_letter);
+  if (pos != s.size()) {
+    throw std::invalid_argument("not a bracketing");
+  }
+  return shape;
+}
@@ -0,0 +1,431 @@
+#include <algorithm>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "Catalan.h"
+#include "Counting.h"
+#include "Derangements.h"
+#include "Permutation.h"
+#include "SetPartitions.h"
+#include "gtest/gtest.h"
+
+std::vector<int> identity(int n) {
+  std::vector<int> perm(n);
+  for (int i = 0; i < n; ++i) {
+    perm[i] = i;
+  }
+  return perm;
+}
+
+TEST(Permutation, RankExamples) {
+  EXPECT_EQ(permutation_rank({}), 0u);
+  EXPECT_EQ(permutation_rank({0, 1, 2}), 0u);
+  EXPECT_EQ(permutation_rank({1, 0, 2}), 2u);
+  EXPECT_EQ(permutation_rank({2, 1, 0}), 5u);
+  EXPECT_EQ(permutation_unrank(4, 0), (std::vector<int>{0, 1, 2, 3}));
+  EXPECT_EQ(permutation_unrank(4, 23), (std::vector<int>{3, 2, 1, 0}));
+  EXPECT_EQ(permutation_unrank(0, 0), std::vector<int>{});
+
+  // The last permutation of the largest size that fits
+  auto reversed = identity(20);
+  std::reverse(reversed.begin(), reversed.end());
+  EXPECT_EQ(permutation_rank(reversed), factorial(20) - 1);
+  EXPECT_EQ(permutation_unrank(20, factorial(20) - 1), reversed);
+}
+
+TEST(Permutation, RankFollowsLexicographicOrder) {
+  for (int n = 0; n <= 6; ++n) {
+    auto perm = identity(n);
+    uint64_t rank = 0;
+    do {
+      ASSERT_EQ(permutation_rank(perm), rank);
+      ASSERT_EQ(permutation_unrank(n, rank), perm);
+      ++rank;
+    } while (std::next_permutation(perm.begin(), perm.end()));
+    ASSERT_EQ(rank, factorial(n));
+  }
+}
+
+TEST(Permutation, RankRoundTrip) {
+  Rng gen(143);
+  for (int trial = 0; trial < 200; ++trial) {
+    const int n = uniform_int(gen, 0, 20);
+    auto perm = random_permutation(n, gen);
+    ASSERT_EQ(permutation_unrank(n, permutation_rank(perm)), perm);
+
+    const uint64_t rank = uniform_below(gen, factorial(n));
+    ASSERT_EQ(permutation_rank(permutation_unrank(n, rank)), rank);
+  }
+}
+
+TEST(Permutation, Inverse) {
+  EXPECT_EQ(inverse_permutation({2, 0, 1}), (std::vector<int>{1, 2, 0}));
+
+  Rng gen(144);
+  for (int trial = 0; trial < 100; ++trial) {
+    const int n = uniform_int(gen, 0, 30);
+    auto perm = random_permutation(n, gen);
+    auto inverse = inverse_permutation(perm);
+
+    ASSERT_EQ(compose(perm, inverse), identity(n));
+    ASSERT_EQ(compose(inverse, perm), identity(n));
+    ASSERT_EQ(inverse_permutation(inverse), perm);
+  }
+}
+
+TEST(Permutation, Cycles) {
+  EXPECT_EQ(cycles({1, 2, 0, 3, 5, 4}),
+            (std::vector<std::vector<int>>{{0, 1, 2}, {3}, {4, 5}}));
+  EXPECT_TRUE(cycles({}).empty());
+  EXPECT_EQ(from_cycles(5, {{1, 3}}), (std::vector<int>{0, 3, 2, 1, 4}));
+
+  Rng gen(145);
+  for (int trial = 0; trial < 100; ++trial) {
+    const int n = uniform_int(gen, 0, 30);
+    auto perm = random_permutation(n, gen);
+    auto decomposition = cycles(perm);
+
+    size_t total = 0;
+    for (const auto& cycle : decomposition) {
+      ASSERT_EQ(cycle[0], *std::min_element(cycle.begin(), cycle.end()));
+      total += cycle.size();
+    }
+    ASSERT_EQ(total, static_cast<size_t>(n));
+    ASSERT_EQ(from_cycles(n, decomposition), perm);
+  }
+}
+
+TEST(Permutation, RejectsNonPermutations) {
+  EXPECT_THROW(permutation_rank({0, 0}), std::invalid_argument);
+  EXPECT_THROW(inverse_permutation({1, 2}), std::invalid_argument);
+  EXPECT_THROW(cycles({-1, 0}), std::invalid_argument);
+  EXPECT_THROW(permutation_unrank(3, 6), std::invalid_argument);
+  EXPECT_THROW(from_cycles(3, {{0, 1}, {1, 2}}), std::invalid_argument);
+  EXPECT_THROW(from_cycles(3, {{0, 3}}), std::invalid_argument);
+}
+
+TEST(Permutation, TryVersionsReturnErrors) {
+  EXPECT_EQ(try_factorial(20), factorial(20));
+  EXPECT_EQ(try_factorial(21).error().kind, ErrorKind::kOverflow);
+  EXPECT_EQ(try_factorial(-1).error().kind, ErrorKind::kInvalidInput);
+  EXPECT_THROW(factorial(21), std::overflow_error);
+
+  EXPECT_EQ(try_permutation_rank({1, 0}), 1u);
+  EXPECT_EQ(try_permutation_rank({0, 0}).error(),
+            (Error{ErrorKind::kInvalidInput, "not a permutation of 0..n-1"}));
+  std::vector<int> big(21);
+  for (int i = 0; i < 21; ++i) {
+    big[i] = i;
+  }
+  EXPECT_EQ(try_permutation_rank(big).error().kind, ErrorKind::kOverflow);
+
+  EXPECT_EQ(try_permutation_unrank(2, 1), (std::vector<int>{1, 0}));
+  EXPECT_EQ(try_permutation_unrank(3, 6).error().kind,
+            ErrorKind::kInvalidInput);
+  EXPECT_EQ(try_permutation_unrank(21, 0).error().kind, ErrorKind::kOverflow);
+}
+
+TEST(Derangements, KnownCounts) {
+  const std::vector<uint64_t> expected = {1,     0,      1,       2,
+                                          9,     44,     265,     1854,
+                                          14833, 133496, 1334961};
+  for (int n = 0; n <= 10; ++n) {
+    EXPECT_EQ(count_derangements(n), expected[n]);
+    EXPECT_EQ(count_derangements_exact(n), std::to_string(expected[n]));
+  }
+}
+
+TEST(Derangements, TryCount) {
+  EXPECT_EQ(try_count_derangements(4), 9u);
+  EXPECT_EQ(try_count_derangements(21).error().kind, ErrorKind::kOverflow);
+  EXPECT_EQ(try_count_derangements(-1).error().kind, ErrorKind::kInvalidInput);
+  EXPECT_THROW(count_derangements(21), std::overflow_error);
+}
+
+TEST(Derangements, ExactCounts) {
+  EXPECT_EQ(count_derangements(20), 895014631192902121u);
+  EXPECT_EQ(count_derangements_exact(20), "895014631192902121");
+  // The first one past 64 bits
+  EXPECT_EQ(count_derangements_exact(21), "18795307255050944540");
+  EXPECT_EQ(count_derangements_exact(30), "97581073836835777732377428235481");
+  EXPECT_EQ(count_derangements_exact(50),
+            "1118871961078248050463025807075773432401135420886572159272033680"
+            "1");
+}
+
+TEST(Derangements, Generate) {
+  EXPECT_EQ(derangements(std::vector<char>{'a', 'b', 'c'}),
+            (std::vector<std::vector<char>>{{'b', 'c', 'a'}, {'c', 'a', 'b'}}));
+  EXPECT_TRUE(derangements(std::vector<int>{7}).empty());
+  EXPECT_EQ(derangements(std::vector<int>{}).size(), 1u);
+
+  for (int n = 0; n <= 8; ++n) {
+    const auto values = identity(n);
+    std::set<std::vector<int>> seen;
+    std::vector<int> previous;
+    for_each_derangement(values, [&](const std::vector<int>& d) {
+      for (int i = 0; i < n; ++i) {
+        ASSERT_NE(d[i], i);
+      }
+      ASSERT_TRUE(is_permutation(d));
+      ASSERT_TRUE(seen.empty() || previous < d);  // Lexicographic order
+      seen.insert(d);
+      previous = d;
+    });
+    ASSERT_EQ(seen.size(), count_derangements(n));
+  }
+}
+
+TEST(Catalan, Numbers) {
+  const std::vector<uint64_t> expected = {1, 1, 2, 5, 14, 42, 132, 429, 1430};
+  for (int n = 0; n < static_cast<int>(expected.size()); ++n) {
+    EXPECT_EQ(catalan(n), expected[n]);
+  }
+  EXPECT_EQ(catalan(35), 3116285494907301262u);
+  EXPECT_EQ(try_catalan(36).error().kind, ErrorKind::kOverflow);
+  EXPECT_EQ(try_catalan(-1).error().kind, ErrorKind::kInvalidInput);
+  EXPECT_THROW(catalan(36), std::overflow_error);
+}
+
+TEST(Catalan, SmallFamilies) {
+  EXPECT_EQ(all_bracketings(0), std::vector<std::string>{"a"});
+  EXPECT_EQ(all_bracketings(2),
+            (std::vector<std::string>{"(a(bc))", "((ab)c)"}));
+  // A square has two diagonals, each a triangulation
+  EXPECT_EQ(all_triangulations(2),
+            (std::vector<Triangulation>{{{0, 1, 3}, {1, 2, 3}},
+                                        {{0, 1, 2}, {0, 2, 3}}}));
+  EXPECT_EQ(all_triangulations(0), std::vector<Triangulation>{{}});
+  EXPECT_EQ(all_shapes(0).size(), 1u);
+  EXPECT_EQ(all_shapes(0)[0], nullptr);
+}
+
+TEST(Catalan, CountsMatch) {
+  for (int n = 0; n <= 9; ++n) {
+    ASSERT_EQ(all_shapes(n).size(), catalan(n));
+    ASSERT_EQ(all_triangulations(n).size(), catalan(n));
+    ASSERT_EQ(all_bracketings(n).size(), catalan(n));
+  }
+}
+
+TEST(Catalan, Bijections) {
+  for (int n = 0; n <= 8; ++n) {
+    auto shapes = all_shapes(n);
+    std::set<Triangulation> triangulations;
+    std::set<std::string> bracketings;
+    for (const auto& shape : shapes) {
+      auto t = to_triangulation(shape.get());
+      auto b = to_bracketing(shape.get());
+      ASSERT_EQ(t.size(), static_cast<size_t>(n));
+      ASSERT_TRUE(same_tree(from_triangulation(t).get(), shape.get()));
+      ASSERT_TRUE(same_tree(from_bracketing(b).get(), shape.get()));
+      triangulations.insert(t);
+      bracketings.insert(b);
+    }
+    // Every member of each family is reached exactly once
+    auto all_t = all_triangulations(n);
+    auto all_b = all_bracketings(n);
+    ASSERT_EQ(triangulations.size(), shapes.size());
+    ASSERT_EQ(bracketings.size(), shapes.size());
+    ASSERT_EQ(triangulations,
+              std::set<Triangulation>(all_t.begin(), all_t.end()));
+    ASSERT_EQ(bracketings, std::set<std::string>(all_b.begin(), all_b.end()));
+  }
+}
+
+TEST(Catalan, RejectsMalformed) {
+  EXPECT_THROW(from_bracketing("(ab"), std::invalid_argument);
+  EXPECT_THROW(from_bracketing("(ba)"), std::invalid_argument);
+  EXPECT_THROW(from_bracketing("(ab)c"), std::invalid_argument);
+  EXPECT_THROW(from_bracketing("(abc)"), std::invalid_argument);
+  EXPECT_THROW(from_triangulation({{0, 1, 3}, {0, 1, 3}}),
+               std::invalid_argument);
+  // Two triangles, but they overlap instead of covering the square
+  EXPECT_THROW(from_triangulation({{0, 1, 3}, {0, 2, 3}}),
+               std::invalid_argument);
+}
+
+std::string to_string(unsigned __int128 x) {
+  std::string digits;
+  do {
+    digits.insert(digits.begin(), static_cast<char>('0' + x % 10));
+    x /= 10;
+  } while (x > 0);
+  return digits;
+}
+
+TEST(Counting, Binomial) {
+  EXPECT_EQ(binomial(5, 2), 10u);
+  EXPECT_EQ(binomial(0, 0), 1u);
+  EXPECT_EQ(binomial(3, 4), 0u);
+  EXPECT_EQ(binomial(3, -1), 0u);
+  EXPECT_EQ(binomial(67, 33), 14226520737620288370u);
+  EXPECT_THROW(binomial(68, 34), std::overflow_error);
+  // Only the result has to fit, not n!
+  EXPECT_EQ(binomial(1'000'000'000, 2), 499999999500000000u);
+
+  auto triangle = pascal_triangle(67);
+  for (int n = 0; n <= 67; ++n) {
+    for (int k = 0; k <= n; ++k) {
+      ASSERT_EQ(triangle[n][k], binomial(n, k));
+    }
+  }
+  EXPECT_THROW(pascal_triangle(68), std::overflow_error);
+}
+
+TEST(Counting, Backends) {
+  // Row 100 is out of reach for 64 bits, but not for the others
+  const std::string middle = "100891344545564193334812497256";
+  EXPECT_EQ(to_string(pascal_triangle<unsigned __int128>(100)[100][50]),
+            middle);
+  EXPECT_EQ(pascal_triangle<BigUnsigned>(100)[100][50].to_string(), middle);
+
+  EXPECT_EQ(bell(25), 4638590332229999353u);
+  EXPECT_THROW(bell(26), std::overflow_error);
+  EXPECT_EQ(to_string(bell<unsigned __int128>(30)),
+            "846749014511809332450147");
+  EXPECT_EQ(bell<BigUnsigned>(50).to_string(),
+            "185724268771078270438257767181908917499221852770");
+
+  auto first = stirling_first_table<unsigned __int128>(30);
+  auto first_big = stirling_first_table<BigUnsigned>(30);
+  auto second = stirling_second_table<unsigned __int128>(30);
+  auto second_big = stirling_second_table<BigUnsigned>(30);
+  for (int n = 0; n <= 30; ++n) {
+    for (int k = 0; k <= n; ++k) {
+      ASSERT_EQ(to_string(first[n][k]), first_big[n][k].to_string());
+      ASSERT_EQ(to_string(second[n][k]), second_big[n][k].to_string());
+    }
+  }
+}
+
+TEST(Counting, Stirling) {
+  EXPECT_EQ(stirling_first(10, 3), 1172700u);
+  EXPECT_EQ(stirling_second(10, 3), 9330u);
+  EXPECT_EQ(stirling_first(0, 0), 1u);
+  EXPECT_EQ(stirling_second(5, 0), 0u);
+  EXPECT_EQ(stirling_second(3, 5), 0u);
+  // (n - 1)! cyclic orders of n elements
+  EXPECT_EQ(stirling_first(20, 1), factorial(19));
+
+  // Permutations split up by their cycles, and partitions by their blocks
+  auto first = stirling_first_table(20);
+  auto second = stirling_second_table(25);
+  for (int n = 0; n <= 20; ++n) {
+    uint64_t permutations = 0;
+    for (int k = 0; k <= n; ++k) {
+      permutations += first[n][k];
+    }
+    ASSERT_EQ(permutations, factorial(n));
+  }
+  for (int n = 0; n <= 25; ++n) {
+    uint64_t partitions = 0;
+    for (int k = 0; k <= n; ++k) {
+      partitions += second[n][k];
+    }
+    ASSERT_EQ(partitions, bell(n));
+  }
+}
+
+TEST(Counting, StirlingFirstCountsCycles) {
+  for (int n = 0; n <= 7; ++n) {
+    std::vector<uint64_t> by_cycles(n + 1, 0);
+    auto perm = identity(n);
+    do {
+      ++by_cycles[cycles(perm).size()];
+    } while (std::next_permutation(perm.begin(), perm.end()));
+    for (int k = 0; k <= n; ++k) {
+      ASSERT_EQ(by_cycles[k], stirling_first(n, k));
+    }
+  }
+}
+
+bool is_restricted_growth_string(const std::vector<int>& rgs) {
+  int max_so_far = -1;
+  for (int block : rgs) {
+    if (block < 0 || block > max_so_far + 1) {
+      return false;
+    }
+    max_so_far = std::max(max_so_far, block);
+  }
+  return true;
+}
+
+TEST(SetPartitions, Example) {
+  std::vector<std::vector<int>> strings;
+  for (const auto& rgs : SetPartitions(3)) {
+    strings.push_back(rgs);
+  }
+  EXPECT_EQ(strings, (std::vector<std::vector<int>>{
+                         {0, 0, 0}, {0, 0, 1}, {0, 1, 0}, {0, 1, 1},
+                         {0, 1, 2}}));
+
+  strings.clear();
+  for (const auto& rgs : SetPartitions(4, 3)) {
+    strings.push_back(rgs);
+  }
+  EXPECT_EQ(strings, (std::vector<std::vector<int>>{{0, 0, 1, 2},
+                                                    {0, 1, 0, 2},
+                                                    {0, 1, 1, 2},
+                                                    {0, 1, 2, 0},
+                                                    {0, 1, 2, 1},
+                                                    {0, 1, 2, 2}}));
+
+  EXPECT_EQ(to_blocks({0, 1, 0, 2}),
+            (std::vector<std::vector<int>>{{0, 2}, {1}, {3}}));
+}
+
+TEST(SetPartitions, EdgeCases) {
+  auto count = [](int n, std::optional<int> blocks) {
+    size_t total = 0;
+    for (const auto& rgs : SetPartitions(n, blocks)) {
+      EXPECT_EQ(rgs.size(), static_cast<size_t>(n));
+      ++total;
+    }
+    return total;
+  };
+  EXPECT_EQ(count(0, std::nullopt), 1u);  // The empty partition
+  EXPECT_EQ(count(0, 0), 1u);
+  EXPECT_EQ(count(0, 1), 0u);
+  EXPECT_EQ(count(3, 0), 0u);
+  EXPECT_EQ(count(3, 4), 0u);
+  EXPECT_EQ(count(1, 1), 1u);
+  EXPECT_EQ(count(5, 1), 1u);
+  EXPECT_EQ(count(5, 5), 1u);
+}
+
+TEST(SetPartitions, CountsMatchBellAndStirling) {
+  for (int n = 0; n <= 10; ++n) {
+    std::vector<uint64_t> by_blocks(n + 1, 0);
+    std::vector<int> previous;
+    uint64_t total = 0;
+    for (const auto& rgs : SetPartitions(n)) {
+      ASSERT_TRUE(is_restricted_growth_string(rgs));
+      ASSERT_TRUE(total == 0 || previous < rgs);
+      ++by_blocks[to_blocks(rgs).size()];
+      previous = rgs;
+      ++total;
+    }
+    ASSERT_EQ(total, bell(n));
+
+    for (int k = 0; k <= n; ++k) {
+      uint64_t with_k = 0;
+      for (const auto& rgs : SetPartitions(n, k)) {
+        ASSERT_TRUE(is_restricted_growth_string(rgs));
+        ASSERT_EQ(to_blocks(rgs).size(), static_cast<size_t>(k));
+        ++with_k;
+      }
+      ASSERT_EQ(with_k, stirling_second(n, k));
+      ASSERT_EQ(with_k, by_blocks[k]);
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,180 @@
+#pragma once
+
+#include <algorithm>
+#include <concepts>
+#include <cstdint>
+#include <stdexcept>
+#include <utility>
+#include <vector>
+
+#include "BigUnsigned.h"
+
+// Counting numbers from their recurrences. Each table is generic over the
+// number type, so the same code runs on:
+//
+// - uint64_t, the default, for small n;
+// - unsigned __int128, good for about twice as far; or
+// - BigUnsigned, exact for any n, at the cost of speed.
+//
+// The fixed-width types throw std::overflow_error instead of wrapping
+// around, so a table is either exact or not computed at all.
+
+template <std::unsigned_integral Number>
+Number checked_add(Number a, Number b) {
+  Number sum;
+  if (__builtin_add_overflow(a, b, &sum)) {
+    throw std::overflow_error("count overflows its number type");
+  }
+  return sum;
+}
+
+template <std::unsigned_integral Number>
+Number checked_mul(Number a, uint32_t factor) {
+  Number product;
+  if (__builtin_mul_overflow(a, static_cast<Number>(factor), &product)) {
+    throw std::overflow_error("count overflows its number type");
+  }
+  return product;
+}
+
+// std::unsigned_integral leaves out unsigned __int128 in strict mode
+inline unsigned __int128 checked_add(unsigned __int128 a, unsigned __int128 b) {
+  unsigned __int128 sum;
+  if (__builtin_add_overflow(a, b, &sum)) {
+    throw std::overflow_error("count overflows its number type");
+  }
+  return sum;
+}
+
+inline unsigned __int128 checked_mul(unsigned __int128 a, uint32_t factor) {
+  unsigned __int128 product;
+  if (__builtin_mul_overflow(a, static_cast<unsigned __int128>(factor),
+                             &product)) {
+    throw std::overflow_error("count overflows its number type");
+  }
+  return product;
+}
+
+inline BigUnsigned checked_add(const BigUnsigned& a, const BigUnsigned& b) {
+  return a + b;
+}
+
+inline BigUnsigned checked_mul(const BigUnsigned& a, uint32_t factor) {
+  return a * factor;
+}
+
+// Binomial coefficients, multiplicatively: after step i, c is
+// binomial(n - k + i, i), and c * (n - k + i + 1) is always divisible by
+// i + 1. The product is taken in 128 bits, so the only way to fail is a
+// result too big for 64 bits. O(k).
+inline uint64_t binomial(int64_t n, int64_t k) {
+  if (k < 0 || n < 0 || k > n) {
+    return 0;
+  }
+  k = std::min(k, n - k);
+  unsigned __int128 c = 1;
+  for (int64_t i = 0; i < k; ++i) {
+    c = c * static_cast<uint64_t>(n - k + i + 1) / static_cast<uint64_t>(i + 1);
+    if (c > UINT64_MAX) {
+      throw std::overflow_error("binomial overflows 64 bits");
+    }
+  }
+  return static_cast<uint64_t>(c);
+}
+
+// Pascal's triangle, rows 0 to n: binomial(i, j) = binomial(i - 1, j - 1) +
+// binomial(i - 1, j). Additions only, so it suits any number type.
+// O(n^2) entries.
+template <typename Number = uint64_t>
+std::vector<std::vector<Number>> pascal_triangle(int n) {
+  std::vector<std::vector<Number>> rows;
+  for (int i = 0; i <= n; ++i) {
+    auto& row = rows.emplace_back(i + 1, Number(1));
+    for (int j = 1; j < i; ++j) {
+      row[j] = checked_add(rows[i - 1][j - 1], rows[i - 1][j]);
+    }
+  }
+  return rows;
+}
+
+// Stirling numbers of the first kind (unsigned), c(n, k): permutations of n
+// elements with exactly k cycles. Element n either is a cycle of its own, or
+// follows one of the n - 1 others in their cycle:
+//
+//   c(n, k) = c(n - 1, k - 1) + (n - 1) * c(n - 1, k),  c(0, 0) = 1
+//
+// table[i][j] for 0 <= j <= i <= n.
+template <typename Number = uint64_t>
+std::vector<std::vector<Number>> stirling_first_table(int n) {
+  std::vector<std::vector<Number>> table;
+  for (int i = 0; i <= n; ++i) {
+    auto& row = table.emplace_back(i + 1, Number(0));
+    if (i == 0) {
+      row[0] = Number(1);
+      continue;
+    }
+    for (int j = 1; j <= i; ++j) {
+      const Number& stay = j < i ? table[i - 1][j] : Number(0);
+      row[j] = checked_add(table[i - 1][j - 1],
+                           checked_mul(stay, static_cast<uint32_t>(i - 1)));
+    }
+  }
+  return table;
+}
+
+// Stirling numbers of the second kind, S(n, k): partitions of n elements
+// into exactly k nonempty blocks. Element n either is a block of its own, or
+// joins one of the k blocks of the others:
+//
+//   S(n, k) = S(n - 1, k - 1) + k * S(n - 1, k),  S(0, 0) = 1
+template <typename Number = uint64_t>
+std::vector<std::vector<Number>> stirling_second_table(int n) {
+  std::vector<std::vector<Number>> table;
+  for (int i = 0; i <= n; ++i) {
+    auto& row = table.emplace_back(i + 1, Number(0));
+    if (i == 0) {
+      row[0] = Number(1);
+      continue;
+    }
+    for (int j = 1; j <= i; ++j) {
+      const Number& join = j < i ? table[i - 1][j] : Number(0);
+      row[j] = checked_add(table[i - 1][j - 1],
+                           checked_mul(join, static_cast<uint32_t>(j)));
+    }
+  }
+  return table;
+}
+
+template <typename Number = uint64_t>
+Number stirling_first(int n, int k) {
+  if (k < 0 || k > n) {
+    return Number(0);
+  }
+  return stirling_first_table<Number>(n)[n][k];
+}
+
+template <typename Number = uint64_t>
+Number stirling_second(int n, int k) {
+  if (k < 0 || k > n) {
+    return Number(0);
+  }
+  return stirling_second_table<Number>(n)[n][k];
+}
+
+// Bell numbers, B(n): all partitions of n elements, the sum of S(n, k) over
+// k. The Bell triangle gets them by additions alone: each row starts with
+// the last entry of the row before, and every other entry adds the entry to
+// its left and the one above that. Row i starts with B(i) and ends with
+// B(i + 1), so stop at row n - 1, before anything bigger than B(n). O(n^2).
+template <typename Number = uint64_t>
+Number bell(int n) {
+  std::vector<Number> row = {Number(1)};
+  for (int i = 1; i < n; ++i) {
+    std::vector<Number> next = {row.back()};
+    for (const auto& above : row) {
+      next.push_back(checked_add(next.back(), above));
+    }
+    row = std::move(next);
+  }
+  return row.back();
+}
@@ -0,0 +1,104 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../errors/Error.h"
+#include "BigUnsigned.h"
+
+// Derangements: permutations that leave nothing where it was. Where does the
+// first element go? To one of the n - 1 other positions, say k's. Then
+// either k goes to the first position, leaving a derangement of the other
+// n - 2, or it doesn't, which is a derangement of n - 1 elements, with the
+// first position standing in for k's:
+//
+//   D(n) = (n - 1) * (D(n - 1) + D(n - 2)),  D(0) = 1, D(1) = 0
+//
+// D(n) is the closest integer to n! / e, so it overflows 64 bits past 20.
+
+inline Result<uint64_t> try_count_derangements(int n) {
+  if (n < 0) {
+    return Error{ErrorKind::kInvalidInput, "D(n) needs n >= 0"};
+  }
+  if (n > 20) {
+    return Error{ErrorKind::kOverflow, "D(n) overflows 64 bits"};
+  }
+  if (n == 0) {
+    return 1;
+  }
+  uint64_t before = 1;  // D(i - 2)
+  uint64_t last = 0;    // D(i - 1)
+  for (int i = 2; i <= n; ++i) {
+    const uint64_t current = (i - 1) * (before + last);
+    before = last;
+    last = current;
+  }
+  return last;
+}
+
+inline uint64_t count_derangements(int n) {
+  return try_count_derangements(n).or_throw();
+}
+
+// The exact D(n) for any n, in decimal
+inline std::string count_derangements_exact(int n) {
+  assert(n >= 0);
+  if (n == 0) {
+    return "1";
+  }
+  BigUnsigned before(1);
+  BigUnsigned last(0);
+  for (int i = 2; i <= n; ++i) {
+    BigUnsigned current = (before + last) * static_cast<uint32_t>(i - 1);
+    before = std::move(last);
+    last = std::move(current);
+  }
+  return last.to_string();
+}
+
+// Fills positions left to right with values from positions not used yet,
+// never a position's own value. A position only runs out of choices when
+// it's the last one and its own value is all that's left, so the search
+// rarely backtracks: O(n * D(n)) in total.
+template <typename T, typename Visit>
+void extend_derangement(const std::vector<T>& values, std::vector<bool>& used,
+                        std::vector<T>& current, Visit& visit) {
+  const size_t i = current.size();
+  if (i == values.size()) {
+    visit(current);
+    return;
+  }
+  for (size_t j = 0; j < values.size(); ++j) {
+    if (j == i || used[j]) {
+      continue;
+    }
+    used[j] = true;
+    current.push_back(values[j]);
+    extend_derangement(values, used, current, visit);
+    current.pop_back();
+    used[j] = false;
+  }
+}
+
+// Calls visit on every derangement of values, as a const std::vector<T>&,
+// in lexicographic order of positions. Derangements are by position: with
+// repeated values, a derangement may put an equal value back in place.
+template <typename T, typename Visit>
+void for_each_derangement(const std::vector<T>& values, Visit visit) {
+  std::vector<bool> used(values.size(), false);
+  std::vector<T> current;
+  current.reserve(values.size());
+  extend_derangement(values, used, current, visit);
+}
+
+template <typename T>
+std::vector<std::vector<T>> derangements(const std::vector<T>& values) {
+  std::vector<std::vector<T>> result;
+  for_each_derangement(values,
+                       [&](const std::vector<T>& d) { result.push_back(d); });
+  return result;
+}
@@ -0,0 +1,168 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <vector>
+
+#include "../errors/Error.h"
+
+// Permutations of 0, 1, ..., n - 1, stored as the sequence perm[0], ...,
+// perm[n - 1].
+
+inline bool is_permutation(const std::vector<int>& perm) {
+  std::vector<bool> seen(perm.size(), false);
+  for (int x : perm) {
+    if (x < 0 || static_cast<size_t>(x) >= perm.size() || seen[x]) {
+      return false;
+    }
+    seen[x] = true;
+  }
+  return true;
+}
+
+inline void check_permutation(const std::vector<int>& perm) {
+  if (!is_permutation(perm)) {
+    throw std::invalid_argument("not a permutation of 0..n-1");
+  }
+}
+
+// 20! is the largest factorial that fits in 64 bits
+inline Result<uint64_t> try_factorial(int n) {
+  if (n < 0) {
+    return Error{ErrorKind::kInvalidInput, "n! needs n >= 0"};
+  }
+  if (n > 20) {
+    return Error{ErrorKind::kOverflow, "n! overflows 64 bits"};
+  }
+  uint64_t result = 1;
+  for (int i = 2; i <= n; ++i) {
+    result *= i;
+  }
+  return result;
+}
+
+inline uint64_t factorial(int n) { return try_factorial(n).or_throw(); }
+
+// Position of perm in the lexicographic order of all permutations of its
+// size, counting from 0. In the factorial number system, digit i (with place
+// value (n - 1 - i)!) is the number of elements after position i that are
+// smaller than perm[i]: that many choices for position i would have come
+// first. O(n^2), for n <= 20.
+inline Result<uint64_t> try_permutation_rank(const std::vector<int>& perm) {
+  if (!is_permutation(perm)) {
+    return Error{ErrorKind::kInvalidInput, "not a permutation of 0..n-1"};
+  }
+  if (perm.size() > 20) {
+    return Error{ErrorKind::kOverflow, "ranks past 20! overflow 64 bits"};
+  }
+  const size_t n = perm.size();
+  uint64_t rank = 0;
+  for (size_t i = 0; i < n; ++i) {
+    uint64_t smaller_after = 0;
+    for (size_t j = i + 1; j < n; ++j) {
+      smaller_after += perm[j] < perm[i];
+    }
+    rank = rank * (n - i) + smaller_after;
+  }
+  return rank;
+}
+
+inline uint64_t permutation_rank(const std::vector<int>& perm) {
+  return try_permutation_rank(perm).or_throw();
+}
+
+// The inverse of permutation_rank: read off the factorial digits of rank,
+// and let each pick among the elements not used yet. O(n^2), for n <= 20.
+inline Result<std::vector<int>> try_permutation_unrank(int n, uint64_t rank) {
+  const auto count = try_factorial(n);
+  if (!count) {
+    return count.error();
+  }
+  if (rank >= count.value()) {
+    return Error{ErrorKind::kInvalidInput, "rank must be less than n!"};
+  }
+  std::vector<int> digits(n);
+  for (int i = n - 1; i >= 0; --i) {
+    const int base = n - i;  // Digit i has place value (n - 1 - i)!
+    digits[i] = static_cast<int>(rank % base);
+    rank /= base;
+  }
+
+  std::vector<int> unused(n);
+  for (int i = 0; i < n; ++i) {
+    unused[i] = i;
+  }
+  std::vector<int> perm;
+  perm.reserve(n);
+  for (int d : digits) {
+    perm.push_back(unused[d]);
+    unused.erase(unused.begin() + d);
+  }
+  return perm;
+}
+
+inline std::vector<int> permutation_unrank(int n, uint64_t rank) {
+  return try_permutation_unrank(n, rank).or_throw();
+}
+
+// inverse[perm[i]] == i, so the two undo each other. O(n).
+inline std::vector<int> inverse_permutation(const std::vector<int>& perm) {
+  check_permutation(perm);
+  std::vector<int> inverse(perm.size());
+  for (size_t i = 0; i < perm.size(); ++i) {
+    inverse[perm[i]] = static_cast<int>(i);
+  }
+  return inverse;
+}
+
+// (a * b)[i] == a[b[i]]: apply b first, then a
+inline std::vector<int> compose(const std::vector<int>& a,
+                                const std::vector<int>& b) {
+  assert(a.size() == b.size());
+  std::vector<int> result(b.size());
+  for (size_t i = 0; i < b.size(); ++i) {
+    result[i] = a[b[i]];
+  }
+  return result;
+}
+
+// Cycle decomposition: following i -> perm[i] from any element eventually
+// comes back to it. Each cycle starts at its smallest element, and the
+// cycles are ordered by it; fixed points are cycles of length 1. O(n).
+inline std::vector<std::vector<int>> cycles(const std::vector<int>& perm) {
+  check_permutation(perm);
+  std::vector<std::vector<int>> result;
+  std::vector<bool> visited(perm.size(), false);
+  for (size_t start = 0; start < perm.size(); ++start) {
+    if (visited[start]) {
+      continue;
+    }
+    auto& cycle = result.emplace_back();
+    for (int i = static_cast<int>(start); !visited[i]; i = perm[i]) {
+      visited[i] = true;
+      cycle.push_back(i);
+    }
+  }
+  return result;
+}
+
+// The permutation with the given cycles. Elements not in any cycle are fixed.
+inline std::vector<int> from_cycles(
+    int n, const std::vector<std::vector<int>>& cycle_list) {
+  std::vector<int> perm(n);
+  for (int i = 0; i < n; ++i) {
+    perm[i] = i;
+  }
+  for (const auto& cycle : cycle_list) {
+    for (size_t i = 0; i < cycle.size(); ++i) {
+      if (cycle[i] < 0 || cycle[i] >= n) {
+        throw std::invalid_argument("cycle element out of range");
+      }
+      perm[cycle[i]] = cycle[(i + 1) % cycle.size()];
+    }
+  }
+  check_permutation(perm);
+  return perm;
+}
@@ -0,0 +1,112 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <optional>
+#include <vector>
+
+// Partitions of the set {0, 1, ..., n - 1} into nonempty blocks, as
+// restricted growth strings: a[i] is the block of element i, with blocks
+// numbered in order of their smallest element. So a[0] = 0, and each a[i]
+// is at most one more than the largest before it. Every partition has
+// exactly one such string, e.g. {0, 2}, {1}, {3} is 0, 1, 0, 2.
+//
+// Bell(n) strings in all, or S(n, k) with exactly k blocks: see Counting.h.
+
+// Splits elements into their blocks, in block order
+inline std::vector<std::vector<int>> to_blocks(const std::vector<int>& rgs) {
+  std::vector<std::vector<int>> blocks;
+  for (size_t i = 0; i < rgs.size(); ++i) {
+    if (static_cast<size_t>(rgs[i]) == blocks.size()) {
+      blocks.emplace_back();
+    }
+    assert(static_cast<size_t>(rgs[i]) < blocks.size() &&
+           "not a restricted growth string");
+    blocks[rgs[i]].push_back(static_cast<int>(i));
+  }
+  return blocks;
+}
+
+// The partitions in lexicographic order of their strings, one at a time:
+//
+//   for (const auto& rgs : SetPartitions(4, 2)) { ... }
+//
+// Each step is O(n), and nothing beyond the current string is stored.
+class SetPartitions {
+ public:
+  // All partitions of n elements, or only those with exactly `blocks` blocks
+  explicit SetPartitions(int n, std::optional<int> blocks = std::nullopt)
+      : blocks_(blocks), rgs_(n, 0), prefix_max_(n, 0) {
+    assert(n >= 0);
+    if (blocks_) {
+      const int k = *blocks_;
+      done_ = k > n || (k == 0 && n > 0) || k < 0;
+      if (!done_) {
+        fill_from(0, 0);
+      }
+    }
+  }
+
+  class Iterator {
+   public:
+    const std::vector<int>& operator*() const { return owner_->rgs_; }
+    Iterator& operator++() {
+      owner_->advance();
+      return *this;
+    }
+    bool operator==(const Iterator& other) const {
+      return done() == other.done();
+    }
+
+   private:
+    friend class SetPartitions;
+    explicit Iterator(SetPartitions* owner) : owner_(owner) {}
+    bool done() const { return owner_ == nullptr || owner_->done_; }
+
+    SetPartitions* owner_;
+  };
+
+  // Iterating consumes the sequence, so begin() can only be used once
+  Iterator begin() { return Iterator(this); }
+  Iterator end() { return Iterator(nullptr); }
+
+ private:
+  // Moves to the next string: the rightmost element that can move to a
+  // later block does, and everything after it goes back to the earliest
+  // blocks that still allow for the required number of blocks
+  void advance() {
+    const int n = static_cast<int>(rgs_.size());
+    for (int i = n - 1; i >= 1; --i) {
+      const int m = prefix_max_[i - 1];
+      const int limit = blocks_ ? std::min(m + 1, *blocks_ - 1) : m + 1;
+      for (int v = rgs_[i] + 1; v <= limit; ++v) {
+        const int max_so_far = std::max(m, v);
+        if (!blocks_ || n - 1 - i >= *blocks_ - 1 - max_so_far) {
+          rgs_[i] = v;
+          prefix_max_[i] = max_so_far;
+          fill_from(i + 1, max_so_far);
+          return;
+        }
+      }
+    }
+    done_ = true;
+  }
+
+  // Sets rgs_[i..] to the smallest completion given the largest block so
+  // far: all zeros, then just enough new blocks at the end to make k
+  void fill_from(int i, int max_so_far) {
+    const int n = static_cast<int>(rgs_.size());
+    const int new_blocks = blocks_ ? *blocks_ - 1 - max_so_far : 0;
+    for (int j = i; j < n; ++j) {
+      const int from_end = n - j;  // New block number k - from_end
+      rgs_[j] = from_end <= new_blocks ? *blocks_ - from_end : 0;
+      prefix_max_[j] = std::max(j > 0 ? prefix_max_[j - 1] : 0, rgs_[j]);
+    }
+  }
+
+  std::optional<int> blocks_;
+  std::vector<int> rgs_;
+  std::vector<int> prefix_max_;  // prefix_max_[i] = max of rgs_[0..i]
+  bool done_{false};
+};
@@ -0,0 +1,175 @@
+#pragma once
+
+#include <chrono>
+#include <condition_variable>
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <memory>
+#include <mutex>
+#include <optional>
+#include <sstream>
+#include <string>
+#include <thread>
+#include <utility>
+
+#include "../rng/Rng.h"
+
+// Racing an etude's naive version against its optimized one: both run on
+// the same generated input, each in a worker thread with a time limit,
+// and the report says how long each took, how many times faster the
+// optimized one was, and whether they agreed.
+//
+// A thread can't be stopped from outside, so a version that runs past its
+// limit is abandoned rather than killed: it's detached, keeps running in
+// the background until it finishes, and its result is thrown away. The
+// worker owns everything it touches (the input is shared, and its own
+// result), so abandoning it is safe; it just costs CPU until it's done,
+// which slows down whatever runs after it.
+
+using CompareClock = std::chrono::steady_clock;
+
+// How one version did: its output and time, or nothing if it timed out
+template <typename Output>
+struct Timed {
+  std::optional<Output> output;
+  CompareClock::duration elapsed{};
+
+  bool timed_out() const { return !output.has_value(); }
+};
+
+// Runs f() in a worker thread and waits up to limit for it. An exception
+// from f is rethrown here.
+template <typename Output, typename Function>
+Timed<Output> run_with_limit(Function f, std::chrono::milliseconds limit) {
+  struct State {
+    std::mutex mutex;
+    std::condition_variable finished;
+    bool done = false;
+    std::optional<Output> output;
+    std::exception_ptr error;
+    CompareClock::duration elapsed{};
+  };
+  auto state = std::make_shared<State>();
+  std::thread([state, f = std::move(f)]() mutable {
+    const auto start = CompareClock::now();
+    std::optional<Output> output;
+    std::exception_ptr error;
+    try {
+      output.emplace(f());
+    } catch (...) {
+      error = std::current_exception();
+    }
+    const auto elapsed = CompareClock::now() - start;
+    std::lock_guard<std::mutex> lock(state->mutex);
+    state->output = std::move(output);
+    state->error = error;
+    state->elapsed = elapsed;
+    state->done = true;
+    state->finished.notify_one();
+  }).detach();
+
+  std::unique_lock<std::mutex> lock(state->mutex);
+  if (!state->finished.wait_for(lock, limit, [&] { return state->done; })) {
+    return {std::nullopt, limit};
+  }
+  if (state->error) {
+    std::rethrow_exception(state->error);
+  }
+  return {std::move(state->output), state->elapsed};
+}
+
+struct CompareReport {
+  std::string name;
+  size_t n = 0;
+  uint64_t seed = 0;
+  std::chrono::milliseconds limit{0};
+  // nullopt for a version that timed out
+  std::optional<CompareClock::duration> naive;
+  std::optional<CompareClock::duration> optimized;
+  // nullopt unless both finished
+  std::optional<bool> agree;
+
+  // naive time / optimized time, if both finished
+  std::optional<double> speedup() const {
+    if (!naive || !optimized) {
+      return std::nullopt;
+    }
+    const double fast = std::chrono::duration<double>(*optimized).count();
+    const double slow = std::chrono::duration<double>(*naive).count();
+    return fast > 0 ? slow / fast : slow > 0 ? 1e300 : 1.0;
+  }
+};
+
+// Generates an input of size n from the seed, then runs the two versions
+// on it, one at a time so they don't compete for cores. The optimized one
+// goes first: the naive one is the one likely to time out, and once
+// abandoned it would compete with anything timed after it.
+// generate(n, gen) makes the input; naive and optimized take it by const
+// reference, and their outputs must be comparable with ==.
+template <typename Generate, typename Naive, typename Optimized>
+CompareReport compare_versions(const std::string& name, size_t n,
+                               uint64_t seed, std::chrono::milliseconds limit,
+                               Generate generate, Naive naive,
+                               Optimized optimized) {
+  Rng gen(seed);
+  const auto input = std::make_shared<const decltype(generate(n, gen))>(
+      generate(n, gen));
+  using Output = decltype(naive(*input));
+
+  CompareReport report{name, n, seed, limit, {}, {}, {}};
+  const auto fast = run_with_limit<Output>(
+      [input, optimized] { return Output(optimized(*input)); }, limit);
+  const auto slow = run_with_limit<Output>(
+      [input, naive] { return naive(*input); }, limit);
+  if (!slow.timed_out()) {
+    report.naive = slow.elapsed;
+  }
+  if (!fast.timed_out()) {
+    report.optimized = fast.elapsed;
+  }
+  if (!slow.timed_out() && !fast.timed_out()) {
+    report.agree = *slow.output == *fast.output;
+  }
+  return report;
+}
+
+inline std::string format_duration(CompareClock::duration d) {
+  std::ostringstream out;
+  out.precision(3);
+  const double ms = std::chrono::duration<double, std::milli>(d).count();
+  if (ms < 1) {
+    out << ms * 1000 << " us";
+  } else {
+    out << ms << " ms";
+  }
+  return out.str();
+}
+
+// For example:
+//
+//   kadane, n = 100000, seed 185, limit 10000 ms
+//     naive      4.62e+03 ms
+//     optimized  0.211 ms
+//     speedup    2.19e+04x
+//     results    agree
+inline std::string format_report(const CompareReport& report) {
+  std::ostringstream out;
+  out << report.name << ", n = " << report.n << ", seed " << report.seed
+      << ", limit " << report.limit.count() << " ms\n";
+  auto time = [&](const char* label,
+                  const std::optional<CompareClock::duration>& elapsed) {
+    out << "  " << label
+        << (elapsed ? format_duration(*elapsed) : "timed out") << '\n';
+  };
+  time("naive      ", report.naive);
+  time("optimized  ", report.optimized);
+  if (const auto speedup = report.speedup()) {
+    out.precision(3);
+    out << "  speedup    " << *speedup << "x\n";
+  }
+  out << "  results    "
+      << (!report.agree ? "unknown" : *report.agree ? "agree" : "MISMATCH")
+      << '\n';
+  return out.str();
+}
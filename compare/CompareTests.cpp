@@ -0,0 +1,94 @@
+#include <chrono>
+#include <stdexcept>
+#include <string>
+#include <thread>
+#include <vector>
+
+#include "Compare.h"
+#include "Comparisons.h"
+#include "gtest/gtest.h"
+
+using namespace std::chrono_literals;
+
+TEST(Compare, RunWithLimit) {
+  auto quick = run_with_limit<int>([] { return 42; }, 1000ms);
+  ASSERT_FALSE(quick.timed_out());
+  EXPECT_EQ(*quick.output, 42);
+  EXPECT_LT(quick.elapsed, 1000ms);
+
+  // The abandoned worker sleeps on in the background, on its own state
+  auto slow = run_with_limit<int>(
+      [] {
+        std::this_thread::sleep_for(300ms);
+        return 0;
+      },
+      20ms);
+  EXPECT_TRUE(slow.timed_out());
+
+  EXPECT_THROW(run_with_limit<int>(
+                   []() -> int { throw std::runtime_error("no"); }, 1000ms),
+               std::runtime_error);
+}
+
+TEST(Compare, Report) {
+  auto generate = [](size_t n, Rng&) { return n; };
+  auto report = compare_versions(
+      "double", 21, 185, 1000ms, generate, [](size_t n) { return n + n; },
+      [](size_t n) { return 2 * n; });
+  EXPECT_EQ(report.agree, true);
+  ASSERT_TRUE(report.speedup());
+
+  auto wrong = compare_versions(
+      "double", 21, 185, 1000ms, generate, [](size_t n) { return n + n; },
+      [](size_t n) { return 3 * n; });
+  EXPECT_EQ(wrong.agree, false);
+  EXPECT_NE(format_report(wrong).find("MISMATCH"), std::string::npos);
+
+  auto stuck = compare_versions(
+      "double", 21, 185, 20ms, generate,
+      [](size_t n) {
+        std::this_thread::sleep_for(300ms);
+        return n + n;
+      },
+      [](size_t n) { return 2 * n; });
+  EXPECT_FALSE(stuck.naive);
+  EXPECT_TRUE(stuck.optimized);
+  EXPECT_FALSE(stuck.agree);
+  EXPECT_FALSE(stuck.speedup());
+  EXPECT_NE(format_report(stuck).find("naive      timed out"),
+            std::string::npos);
+}
+
+// The naive version is the one that gets abandoned, so it goes last, where
+// it can't slow the optimized one down
+TEST(Compare, OptimizedRunsFirst) {
+  std::vector<std::string> order;
+  compare_versions(
+      "order", 1, 185, 1000ms, [](size_t n, Rng&) { return n; },
+      [&](size_t n) {
+        order.push_back("naive");
+        return n;
+      },
+      [&](size_t n) {
+        order.push_back("optimized");
+        return n;
+      });
+  EXPECT_EQ(order, (std::vector<std::string>{"optimized", "naive"}));
+}
+
+TEST(Compare, EveryComparisonAgrees) {
+  const auto comparisons = all_comparisons();
+  EXPECT_EQ(find_comparison(comparisons, "no such"), nullptr);
+  for (const auto& comparison : comparisons) {
+    ASSERT_EQ(find_comparison(comparisons, comparison.name), &comparison);
+    for (size_t n : {0, 1, 2, 50}) {
+      const auto report = comparison.run(n, 185, 10000ms);
+      ASSERT_EQ(report.agree, true) << comparison.name << ", n = " << n;
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,133 @@
+#pragma once
+
+#include <algorithm>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <string>
+#include <vector>
+
+#include "../geometry/ConvexHull.h"
+#include "../geometry/Skyline.h"
+#include "../order-statistics/OrderStatistics.h"
+#include "../patience/Patience.h"
+#include "../rng/Rng.h"
+#include "../sequences/Sequences.h"
+#include "Compare.h"
+
+// The etudes that come with both a naive version and an optimized one,
+// each with a generator for random inputs of a given size
+
+struct Comparison {
+  std::string name;
+  std::string description;
+  std::function<CompareReport(size_t n, uint64_t seed,
+                              std::chrono::milliseconds limit)>
+      run;
+};
+
+inline std::vector<int64_t> random_values(size_t n, Rng& gen,
+                                          int64_t low, int64_t high) {
+  std::vector<int64_t> values(n);
+  for (auto& v : values) {
+    v = uniform_int(gen, low, high);
+  }
+  return values;
+}
+
+inline std::vector<Comparison> all_comparisons() {
+  std::vector<Comparison> comparisons;
+  auto add = [&](std::string name, std::string description, auto generate,
+                 auto naive, auto optimized) {
+    const std::string label = name;
+    comparisons.push_back(
+        {std::move(name), std::move(description),
+         [=](size_t n, uint64_t seed, std::chrono::milliseconds limit) {
+           return compare_versions(label, n, seed, limit, generate, naive,
+                                   optimized);
+         }});
+  };
+
+  add(
+      "kadane", "maximum subarray sum: every start and end, O(n^2), vs O(n)",
+      [](size_t n, Rng& gen) {
+        return random_values(n, gen, -1000, 1000);
+      },
+      [](const std::vector<int64_t>& nums) {
+        return max_subarray_sum_naive(nums);
+      },
+      [](const std::vector<int64_t>& nums) { return max_subarray(nums).sum; });
+  add(
+      "lis", "longest increasing subsequence: O(n^2) DP vs patience piles",
+      [](size_t n, Rng& gen) {
+        return random_values(n, gen, 0, static_cast<int64_t>(n));
+      },
+      [](const std::vector<int64_t>& values) {
+        return lis_length_naive(values);
+      },
+      [](const std::vector<int64_t>& values) { return lis_length(values); });
+  add(
+      "count-smaller",
+      "smaller elements to the right: O(n^2) vs a Fenwick tree",
+      [](size_t n, Rng& gen) {
+        return random_values(n, gen, 0, static_cast<int64_t>(n));
+      },
+      [](const std::vector<int64_t>& nums) {
+        return count_smaller_to_the_right_naive(nums);
+      },
+      [](const std::vector<int64_t>& nums) {
+        return count_smaller_to_the_right_fenwick(nums);
+      });
+  add(
+      "skyline", "skyline: height at every wall vs a multiset sweep",
+      [](size_t n, Rng& gen) {
+        std::vector<Building> buildings(n);
+        const int64_t width = 10 * static_cast<int64_t>(n) + 10;
+        const auto lefts = random_values(n, gen, 0, width);
+        const auto widths = random_values(n, gen, 1, 100);
+        const auto heights = random_values(n, gen, 1, 1000);
+        for (size_t i = 0; i < n; ++i) {
+          buildings[i] = {lefts[i], lefts[i] + widths[i], heights[i]};
+        }
+        return buildings;
+      },
+      [](const std::vector<Building>& buildings) {
+        return skyline_naive(buildings);
+      },
+      [](const std::vector<Building>& buildings) {
+        return skyline(buildings);
+      });
+  add(
+      "hull",
+      "convex hull vertices: points in no triangle, O(n^4), vs monotone chain",
+      [](size_t n, Rng& gen) {
+        const auto xs = random_values(n, gen, -1000, 1000);
+        const auto ys = random_values(n, gen, -1000, 1000);
+        std::vector<LatticePoint> points(n);
+        for (size_t i = 0; i < n; ++i) {
+          points[i] = {xs[i], ys[i]};
+        }
+        return points;
+      },
+      [](const std::vector<LatticePoint>& points) {
+        return convex_hull_vertices_naive(points);
+      },
+      [](const std::vector<LatticePoint>& points) {
+        auto hull = convex_hull(points);
+        std::sort(hull.begin(), hull.end());
+        return hull;
+      });
+  return comparisons;
+}
+
+// nullptr if there's no comparison with that name
+inline const Comparison* find_comparison(
+    const std::vector<Comparison>& comparisons, const std::string& name) {
+  for (const auto& comparison : comparisons) {
+    if (comparison.name == name) {
+      return &comparison;
+    }
+  }
+  return nullptr;
+}
@@ -0,0 +1,177 @@
+#pragma once
+
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "Rational.h"
+
+// Continued fractions: x = a0 + 1 / (a1 + 1 / (a2 + ...)), written
+// [a0; a1, a2, ...]. a0 is floor(x), and the rest come from doing the same
+// to 1 / (x - a0), so a0 may be negative but the rest are positive. For a
+// rational p / q that's Euclid's algorithm, the terms being its quotients,
+// and it ends; for an irrational it goes on forever.
+//
+// Cutting the expansion short gives the convergents, which alternate
+// around x, each closer than the last, and are the best approximations
+// there are for the size of their denominators. Between two convergents,
+// the semiconvergents fill in the best approximations from one side.
+
+// The terms for p / q. The last term is at least 2 unless it's the only
+// one, since [..., a, 1] is the same number as [..., a + 1]; that makes the
+// expansion of a rational unique.
+inline std::vector<int64_t> continued_fraction(const Rational& x) {
+  std::vector<int64_t> terms;
+  int64_t p = x.numerator();
+  int64_t q = x.denominator();
+  terms.push_back(x.floor());
+  p -= terms.back() * q;  // Now 0 <= p < q
+  while (p != 0) {
+    // x - a is p / q, so the next x is q / p
+    terms.push_back(q / p);
+    const int64_t rest = q % p;
+    q = p;
+    p = rest;
+  }
+  return terms;
+}
+
+// The value of [a0; a1, ..., an], folded from the back
+inline Rational from_continued_fraction(const std::vector<int64_t>& terms) {
+  assert(!terms.empty() && "a continued fraction needs a term");
+  Rational x(terms.back());
+  for (size_t i = terms.size() - 1; i-- > 0;) {
+    x = Rational(terms[i]) + Rational(1) / x;
+  }
+  return x;
+}
+
+// Every convergent [a0; ..., ak], front to back, by the recurrence
+// p(k) = a(k) p(k - 1) + p(k - 2), and the same for q, starting from
+// p(-1) / q(-1) = 1 / 0 and p(-2) / q(-2) = 0 / 1
+inline std::vector<Rational> convergents(const std::vector<int64_t>& terms) {
+  std::vector<Rational> result;
+  int64_t p_before = 0;
+  int64_t q_before = 1;
+  int64_t p = 1;
+  int64_t q = 0;
+  for (int64_t a : terms) {
+    const int64_t p_next = a * p + p_before;
+    const int64_t q_next = a * q + q_before;
+    p_before = p;
+    q_before = q;
+    p = p_next;
+    q = q_next;
+    result.push_back(Rational(p, q));
+  }
+  return result;
+}
+
+// The terms of a double, as far as they make sense. The expansion is done
+// in floating point, and each step amplifies the error of the last, so it
+// stops at the first convergent that, as a double, is x, or after
+// max_terms; the later terms of a double's exact (rational) expansion
+// describe its rounding error rather than the number it approximates.
+inline std::vector<int64_t> continued_fraction(double x,
+                                               size_t max_terms = 64) {
+  assert(std::isfinite(x) && std::fabs(x) < 9e18 && "x must fit an int64_t");
+  std::vector<int64_t> terms;
+  double rest = x;
+  int64_t p_before = 0;
+  int64_t q_before = 1;
+  int64_t p = 1;
+  int64_t q = 0;
+  while (terms.size() < max_terms) {
+    const double a = std::floor(rest);
+    const auto term = static_cast<int64_t>(a);
+    int64_t p_next;
+    int64_t q_next;
+    if (__builtin_mul_overflow(term, p, &p_next) ||
+        __builtin_add_overflow(p_next, p_before, &p_next) ||
+        __builtin_mul_overflow(term, q, &q_next) ||
+        __builtin_add_overflow(q_next, q_before, &q_next)) {
+      break;
+    }
+    terms.push_back(term);
+    p_before = p;
+    q_before = q;
+    p = p_next;
+    q = q_next;
+    if (static_cast<double>(p) / static_cast<double>(q) == x || rest == a) {
+      break;
+    }
+    rest = 1 / (rest - a);
+  }
+  return terms;
+}
+
+// The closest fraction to x with a denominator at most max_denominator,
+// ties going to the smaller denominator. It's the last convergent that
+// fits or a semiconvergent between it and the one before: with
+// convergents p0 / q0 and p1 / q1, the next would be p0 + a p1 over
+// q0 + a q1, and the largest k that keeps q0 + k q1 in bounds makes the
+// other candidate.
+inline Rational best_approximation(const Rational& x,
+                                   int64_t max_denominator) {
+  assert(max_denominator >= 1);
+  if (x.denominator() <= max_denominator) {
+    return x;
+  }
+  int64_t p0 = 0;
+  int64_t q0 = 1;
+  int64_t p1 = 1;
+  int64_t q1 = 0;
+  for (int64_t a : continued_fraction(x)) {
+    // Whether q0 + a q1 > max_denominator, without computing it
+    if (q1 > 0 && a > (max_denominator - q0) / q1) {
+      break;
+    }
+    const int64_t p2 = p0 + a * p1;
+    const int64_t q2 = q0 + a * q1;
+    p0 = p1;
+    q0 = q1;
+    p1 = p2;
+    q1 = q2;
+  }
+  const int64_t k = (max_denominator - q0) / q1;
+  const Rational semiconvergent(p0 + k * p1, q0 + k * q1);
+  const Rational convergent(p1, q1);
+  auto distance = [&](const Rational& r) {
+    return r < x ? x - r : r - x;
+  };
+  const auto order = distance(convergent) <=> distance(semiconvergent);
+  if (order == 0) {
+    return convergent.denominator() < semiconvergent.denominator()
+               ? convergent
+               : semiconvergent;
+  }
+  return order < 0 ? convergent : semiconvergent;
+}
+
+// For doubles, through the double's own terms
+inline Rational best_approximation(double x, int64_t max_denominator) {
+  return best_approximation(from_continued_fraction(continued_fraction(x)),
+                            max_denominator);
+}
+
+// Every denominator in turn, for the tests: the numerator closest to
+// x * q, and the closest of those, the first on ties
+inline Rational best_approximation_naive(const Rational& x,
+                                         int64_t max_denominator) {
+  Rational best(x.floor());
+  Rational best_distance = x - best;
+  for (int64_t q = 1; q <= max_denominator; ++q) {
+    const Rational scaled = x * Rational(q);
+    for (int64_t p : {scaled.floor(), scaled.floor() + 1}) {
+      const Rational candidate(p, q);
+      const Rational d = candidate < x ? x - candidate : candidate - x;
+      if (d < best_distance) {
+        best = candidate;
+        best_distance = d;
+      }
+    }
+  }
+  return best;
+}
@@ -0,0 +1,112 @@
+#include <cmath>
+#include <cstdint>
+#include <limits>
+#include <sstream>
+#include <stdexcept>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "ContinuedFractions.h"
+#include "Rational.h"
+#include "gtest/gtest.h"
+
+using Terms = std::vector<int64_t>;
+
+TEST(Rational, Arithmetic) {
+  EXPECT_EQ(Rational(6, -4), Rational(-3, 2));
+  EXPECT_EQ(Rational(6, -4).denominator(), 2);
+  EXPECT_EQ(Rational(1, 2) + Rational(1, 3), Rational(5, 6));
+  EXPECT_EQ(Rational(1, 2) - 1, Rational(-1, 2));
+  EXPECT_EQ(Rational(2, 3) * Rational(9, 4), Rational(3, 2));
+  EXPECT_EQ(Rational(2, 3) / Rational(-4, 3), Rational(-1, 2));
+  EXPECT_LT(Rational(1, 3), Rational(1, 2));
+  EXPECT_GT(Rational(-1, 3), Rational(-1, 2));
+  EXPECT_EQ(Rational(-7, 2).floor(), -4);
+  EXPECT_EQ(Rational(7, 2).floor(), 3);
+  EXPECT_EQ(Rational(-3, 1).to_string(), "-3");
+  std::ostringstream out;
+  out << Rational(22, 7);
+  EXPECT_EQ(out.str(), "22/7");
+
+  // Reduced in 128 bits before it's narrowed, so this is fine...
+  const int64_t big = std::numeric_limits<int64_t>::max();
+  EXPECT_EQ(Rational(big, 3) * Rational(3, big), Rational(1));
+  // ...but this isn't
+  EXPECT_THROW(Rational(big) + Rational(1), std::overflow_error);
+  EXPECT_THROW(Rational(1, big) + Rational(1, big - 1), std::overflow_error);
+}
+
+TEST(ContinuedFractions, Rationals) {
+  EXPECT_EQ(continued_fraction(Rational(415, 93)), (Terms{4, 2, 6, 7}));
+  EXPECT_EQ(continued_fraction(Rational(-415, 93)), (Terms{-5, 1, 1, 6, 7}));
+  EXPECT_EQ(continued_fraction(Rational(5)), (Terms{5}));
+  EXPECT_EQ(continued_fraction(Rational(1, 3)), (Terms{0, 3}));
+  EXPECT_EQ(from_continued_fraction({4, 2, 6, 7}), Rational(415, 93));
+  EXPECT_EQ(from_continued_fraction({4, 2, 6, 6, 1}), Rational(415, 93));
+  EXPECT_EQ(convergents({4, 2, 6, 7}),
+            (std::vector<Rational>{4, {9, 2}, {58, 13}, {415, 93}}));
+
+  // Fibonacci ratios have the longest expansions: all ones
+  EXPECT_EQ(continued_fraction(Rational(89, 55)),
+            (Terms{1, 1, 1, 1, 1, 1, 1, 1, 2}));
+
+  Rng gen(201);
+  for (int t = 0; t < 2000; ++t) {
+    const Rational x(uniform_int<int64_t>(gen, -1'000'000, 1'000'000),
+                     uniform_int<int64_t>(gen, 1, 1'000'000));
+    const Terms terms = continued_fraction(x);
+    ASSERT_EQ(from_continued_fraction(terms), x);
+    ASSERT_EQ(convergents(terms).back(), x);
+    for (size_t i = 1; i < terms.size(); ++i) {
+      ASSERT_GE(terms[i], 1);
+    }
+    if (terms.size() > 1) {
+      ASSERT_GE(terms.back(), 2);
+    }
+  }
+}
+
+TEST(ContinuedFractions, Doubles) {
+  // Past here pi goes on 2, 1, 1, but the double ends with 3 instead
+  EXPECT_EQ(continued_fraction(M_PI, 13),
+            (Terms{3, 7, 15, 1, 292, 1, 1, 1, 2, 1, 3, 1, 14}));
+  EXPECT_EQ(continued_fraction(M_PI).size(), 14u);
+  const std::vector<Rational> pi = convergents({3, 7, 15, 1, 292});
+  EXPECT_EQ(pi, (std::vector<Rational>{
+                    3, {22, 7}, {333, 106}, {355, 113}, {103993, 33102}}));
+  EXPECT_EQ(continued_fraction(0.75), (Terms{0, 1, 3}));
+  EXPECT_EQ(continued_fraction(-2.5), (Terms{-3, 2}));
+  EXPECT_EQ(continued_fraction(std::sqrt(2.0), 8),
+            (Terms{1, 2, 2, 2, 2, 2, 2, 2}));
+  EXPECT_EQ(continued_fraction(std::exp(1.0), 10),
+            (Terms{2, 1, 2, 1, 1, 4, 1, 1, 6, 1}));
+  // The expansion stops once a convergent is the double
+  const Rational golden = from_continued_fraction(
+      continued_fraction((1 + std::sqrt(5.0)) / 2));
+  EXPECT_EQ(golden.to_double(), (1 + std::sqrt(5.0)) / 2);
+}
+
+TEST(ContinuedFractions, BestApproximation) {
+  EXPECT_EQ(best_approximation(M_PI, 100), Rational(311, 99));
+  EXPECT_EQ(best_approximation(M_PI, 1000), Rational(355, 113));
+  EXPECT_EQ(best_approximation(std::exp(1.0), 1000), Rational(1457, 536));
+  EXPECT_EQ(best_approximation(std::sqrt(2.0), 100), Rational(140, 99));
+  EXPECT_EQ(best_approximation(Rational(3, 7), 10), Rational(3, 7));
+  EXPECT_EQ(best_approximation(Rational(1, 1000), 10), Rational(0));
+  // Halfway between 0 and 1/5: the smaller denominator wins
+  EXPECT_EQ(best_approximation(Rational(1, 10), 5), Rational(0));
+
+  Rng gen(201);
+  for (int t = 0; t < 3000; ++t) {
+    const Rational x(uniform_int<int64_t>(gen, -100'000, 100'000),
+                     uniform_int<int64_t>(gen, 1, 100'000));
+    const auto limit = uniform_int<int64_t>(gen, 1, 200);
+    ASSERT_EQ(best_approximation(x, limit), best_approximation_naive(x, limit))
+        << x << " " << limit;
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
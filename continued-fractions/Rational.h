@@ -0,0 +1,122 @@
+#pragma once
+
+#include <cassert>
+#include <compare>
+#include <cstdint>
+#include <limits>
+#include <numeric>
+#include <ostream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+
+// Exact fractions p / q of 64-bit integers, always in lowest terms with a
+// positive denominator, so each value has one form and == is just ==.
+// Arithmetic is done in 128 bits and reduced before it's narrowed back, so
+// it only fails if the reduced answer itself doesn't fit, and then it
+// throws std::overflow_error rather than wrapping around.
+class Rational {
+ public:
+  // Not explicit, so that integers mix in, as in 1 + Rational(1, 2)
+  Rational(int64_t numerator = 0, int64_t denominator = 1)
+      : Rational(wide(numerator), wide(denominator), Reduce{}) {}
+
+  int64_t numerator() const { return numerator_; }
+  int64_t denominator() const { return denominator_; }
+
+  // The largest integer at most this, rounding toward minus infinity even
+  // for negative values, unlike /
+  int64_t floor() const {
+    const int64_t quotient = numerator_ / denominator_;
+    return quotient - (numerator_ % denominator_ < 0);
+  }
+
+  double to_double() const {
+    return static_cast<double>(numerator_) /
+           static_cast<double>(denominator_);
+  }
+
+  // "3/4", or just "3" for an integer
+  std::string to_string() const {
+    return std::to_string(numerator_) +
+           (denominator_ == 1 ? "" : "/" + std::to_string(denominator_));
+  }
+
+  Rational operator-() const {
+    return Rational(-wide(numerator_), wide(denominator_), Reduce{});
+  }
+
+  Rational operator+(const Rational& other) const {
+    return Rational(wide(numerator_) * other.denominator_ +
+                        wide(other.numerator_) * denominator_,
+                    wide(denominator_) * other.denominator_, Reduce{});
+  }
+  Rational operator-(const Rational& other) const { return *this + -other; }
+  Rational operator*(const Rational& other) const {
+    return Rational(wide(numerator_) * other.numerator_,
+                    wide(denominator_) * other.denominator_, Reduce{});
+  }
+  Rational operator/(const Rational& other) const {
+    assert(other.numerator_ != 0 && "division by zero");
+    return Rational(wide(numerator_) * other.denominator_,
+                    wide(denominator_) * other.numerator_, Reduce{});
+  }
+
+  bool operator==(const Rational&) const = default;
+
+  // Cross-multiplied in 128 bits, which can't overflow
+  std::strong_ordering operator<=>(const Rational& other) const {
+    return compare(wide(numerator_) * other.denominator_,
+                   wide(other.numerator_) * denominator_);
+  }
+
+ private:
+  using Wide = __int128;
+
+  // Tags the constructor that takes a fraction in 128 bits and reduces it
+  struct Reduce {};
+
+  static Wide wide(int64_t x) { return static_cast<Wide>(x); }
+
+  // <=> isn't defined for __int128 in strict mode
+  static std::strong_ordering compare(Wide a, Wide b) {
+    return a < b   ? std::strong_ordering::less
+           : a > b ? std::strong_ordering::greater
+                   : std::strong_ordering::equal;
+  }
+
+  static Wide gcd(Wide a, Wide b) {
+    a = a < 0 ? -a : a;
+    b = b < 0 ? -b : b;
+    while (b != 0) {
+      a %= b;
+      std::swap(a, b);
+    }
+    return a;
+  }
+
+  Rational(Wide numerator, Wide denominator, Reduce) {
+    assert(denominator != 0 && "a fraction needs a nonzero denominator");
+    if (denominator < 0) {
+      numerator = -numerator;
+      denominator = -denominator;
+    }
+    const Wide divisor = gcd(numerator, denominator);
+    numerator /= divisor;
+    denominator /= divisor;
+    constexpr Wide kLow = std::numeric_limits<int64_t>::min();
+    constexpr Wide kHigh = std::numeric_limits<int64_t>::max();
+    if (numerator < kLow || numerator > kHigh || denominator > kHigh) {
+      throw std::overflow_error("fraction doesn't fit 64 bits");
+    }
+    numerator_ = static_cast<int64_t>(numerator);
+    denominator_ = static_cast<int64_t>(denominator);
+  }
+
+  int64_t numerator_;
+  int64_t denominator_;
+};
+
+inline std::ostream& operator<<(std::ostream& out, const Rational& r) {
+  return out << r.to_string();
+}
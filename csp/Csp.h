@@ -0,0 +1,286 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <deque>
+#include <functional>
+#include <optional>
+#include <utility>
+#include <vector>
+
+#include "../backtracking/Backtracking.h"
+#include "../backtracking/Sudoku.h"
+#include "../graph/Graph.h"
+
+// Constraint satisfaction: variables, each with a finite domain of values,
+// and binary constraints, each saying which pairs of values two variables
+// may take together. A solution gives every variable a value from its
+// domain that satisfies every constraint.
+//
+// Arc consistency is the cheap half of solving one. An arc x -> y is
+// consistent if every value left for x has some value left for y that the
+// constraint allows; a value without one, its support, can't be in any
+// solution, and goes. AC-3 keeps a queue of arcs to check, and when x
+// loses a value, every arc z -> x goes back in, since z's values may have
+// lost their support. With d values a domain and c constraints that's
+// O(c d^3). An empty domain proves there's no solution at all, usually
+// long before a search would have found that out.
+//
+// Consistency alone isn't enough: with two colors, a triangle is arc
+// consistent and still can't be colored. So the search assigns a variable,
+// makes everything arc consistent again, and backtracks when a domain
+// empties, maintaining arc consistency (MAC) all the way down.
+
+using Domains = std::vector<std::vector<int>>;
+
+class Csp {
+ public:
+  // Returns the variable's index, counting from 0
+  int add_variable(std::vector<int> domain) {
+    domains_.push_back(std::move(domain));
+    arcs_.emplace_back();
+    return static_cast<int>(domains_.size()) - 1;
+  }
+
+  // allowed(a, b) says whether x = a and y = b go together. Constraints
+  // on the same pair of variables all have to hold.
+  void add_constraint(int x, int y, std::function<bool(int, int)> allowed) {
+    assert(x != y && "constraints are between two variables");
+    const size_t forward = arcs_[x].size();
+    const size_t backward = arcs_[y].size();
+    arcs_[y].push_back({x, forward, [allowed](int b, int a) {
+                          return allowed(a, b);
+                        }});
+    arcs_[x].push_back({y, backward, std::move(allowed)});
+  }
+
+  void add_different(int x, int y) {
+    add_constraint(x, y, [](int a, int b) { return a != b; });
+  }
+
+  size_t variables() const { return domains_.size(); }
+  const Domains& domains() const { return domains_; }
+
+  // AC-3 on domains, in place. Checks every arc, or with `changed`, only
+  // the arcs into it, for when everything else was consistent before it
+  // changed. False if a domain becomes empty.
+  bool make_arc_consistent(Domains& domains,
+                           std::optional<int> changed = std::nullopt) const {
+    std::deque<std::pair<int, size_t>> queue;  // x and the arc's index
+    std::vector<std::vector<bool>> queued(arcs_.size());
+    auto push_into = [&](int y) {
+      for (const auto& arc : arcs_[y]) {
+        if (!queued[arc.to][arc.reverse]) {
+          queued[arc.to][arc.reverse] = true;
+          queue.push_back({arc.to, arc.reverse});
+        }
+      }
+    };
+    for (size_t x = 0; x < arcs_.size(); ++x) {
+      queued[x].assign(arcs_[x].size(), false);
+    }
+    if (changed) {
+      push_into(*changed);
+    } else {
+      if (std::any_of(domains.begin(), domains.end(),
+                      [](const auto& domain) { return domain.empty(); })) {
+        return false;
+      }
+      for (size_t y = 0; y < arcs_.size(); ++y) {
+        push_into(static_cast<int>(y));
+      }
+    }
+    while (!queue.empty()) {
+      const auto [x, i] = queue.front();
+      queue.pop_front();
+      queued[x][i] = false;
+      if (revise(domains, x, arcs_[x][i])) {
+        if (domains[x].empty()) {
+          return false;
+        }
+        push_into(x);
+      }
+    }
+    return true;
+  }
+
+  // Whether a full assignment satisfies every constraint and domain
+  bool satisfies(const std::vector<int>& values) const {
+    for (size_t x = 0; x < arcs_.size(); ++x) {
+      const auto& domain = domains_[x];
+      if (std::find(domain.begin(), domain.end(), values[x]) == domain.end()) {
+        return false;
+      }
+      for (const auto& arc : arcs_[x]) {
+        if (!arc.allowed(values[x], values[arc.to])) {
+          return false;
+        }
+      }
+    }
+    return true;
+  }
+
+ private:
+  struct Arc {
+    int to;
+    size_t reverse;  // the index of to -> this in arcs_[to]
+    std::function<bool(int, int)> allowed;
+  };
+
+  // Drops x's values that have no support in arc.to's domain, and says
+  // whether any went
+  static bool revise(Domains& domains, int x, const Arc& arc) {
+    auto& domain = domains[x];
+    const auto& other = domains[arc.to];
+    const auto unsupported = [&](int a) {
+      return std::none_of(other.begin(), other.end(),
+                          [&](int b) { return arc.allowed(a, b); });
+    };
+    const size_t before = domain.size();
+    domain.erase(std::remove_if(domain.begin(), domain.end(), unsupported),
+                 domain.end());
+    return domain.size() != before;
+  }
+
+  Domains domains_;
+  std::vector<std::vector<Arc>> arcs_;  // arcs_[x] holds the arcs x -> y
+};
+
+// MAC on the engine in backtracking/: a choice gives a variable one value,
+// the one with the fewest values left and more than one, and choosing
+// pushes the domains that arc consistency leaves after it.
+class CspSearch {
+ public:
+  struct Choice {
+    int variable;
+    int value;
+  };
+
+  explicit CspSearch(const Csp& csp) : csp_(csp), domains_{csp.domains()} {
+    consistent_.push_back(csp_.make_arc_consistent(domains_.back()));
+  }
+
+  // Every domain down to one value, which arc consistency makes a solution
+  bool is_complete() const {
+    const Domains& current = domains_.back();
+    return std::all_of(current.begin(), current.end(),
+                       [](const auto& domain) { return domain.size() == 1; });
+  }
+
+  bool promising() const { return consistent_.back(); }
+
+  std::vector<Choice> choices() const {
+    const Domains& current = domains_.back();
+    int best = -1;
+    for (size_t x = 0; x < current.size(); ++x) {
+      if (current[x].size() > 1 &&
+          (best == -1 || current[x].size() < current[best].size())) {
+        best = static_cast<int>(x);
+      }
+    }
+    std::vector<Choice> choices;
+    if (best != -1) {
+      for (int value : current[best]) {
+        choices.push_back({best, value});
+      }
+    }
+    return choices;
+  }
+
+  bool accepts(const Choice&) const { return true; }
+
+  void choose(const Choice& c) {
+    Domains next = domains_.back();
+    next[c.variable] = {c.value};
+    consistent_.push_back(csp_.make_arc_consistent(next, c.variable));
+    domains_.push_back(std::move(next));
+  }
+
+  void unchoose(const Choice&) {
+    domains_.pop_back();
+    consistent_.pop_back();
+  }
+
+  std::vector<int> solution() const {
+    std::vector<int> values;
+    for (const auto& domain : domains_.back()) {
+      values.push_back(domain[0]);
+    }
+    return values;
+  }
+
+ private:
+  const Csp& csp_;
+  std::vector<Domains> domains_;  // after each choice, the initial first
+  std::vector<bool> consistent_;  // whether each of those has no empty domain
+};
+
+struct CspResult {
+  std::optional<std::vector<int>> solution;
+  BacktrackStats stats;
+};
+
+inline CspResult solve_csp(const Csp& csp) {
+  CspSearch search(csp);
+  CspResult result;
+  result.stats = backtrack(search, [&](const CspSearch& done) {
+    result.solution = done.solution();
+    return false;
+  });
+  return result;
+}
+
+// Map coloring: neighboring regions get different colors, 0 to colors - 1
+inline Csp map_coloring_csp(const AdjacencyList& graph, int colors) {
+  Csp csp;
+  std::vector<int> palette(colors);
+  for (int c = 0; c < colors; ++c) {
+    palette[c] = c;
+  }
+  for (size_t v = 0; v < graph.size(); ++v) {
+    csp.add_variable(palette);
+  }
+  for (size_t u = 0; u < graph.size(); ++u) {
+    for (int v : graph[u]) {
+      if (static_cast<int>(u) < v) {
+        csp.add_different(static_cast<int>(u), v);
+      }
+    }
+  }
+  return csp;
+}
+
+inline std::optional<std::vector<int>> color_map(const AdjacencyList& graph,
+                                                 int colors) {
+  return solve_csp(map_coloring_csp(graph, colors)).solution;
+}
+
+// Sudoku: a variable per cell, and a constraint that the two differ for
+// every pair of cells that share a row, a column or a box
+inline Csp sudoku_csp(const SudokuGrid& puzzle) {
+  Csp csp;
+  for (int given : puzzle) {
+    csp.add_variable(given == 0 ? std::vector<int>{1, 2, 3, 4, 5, 6, 7, 8, 9}
+                                : std::vector<int>{given});
+  }
+  for (size_t a = 0; a < puzzle.size(); ++a) {
+    for (size_t b = a + 1; b < puzzle.size(); ++b) {
+      if (a / 9 == b / 9 || a % 9 == b % 9 ||
+          sudoku_box(a) == sudoku_box(b)) {
+        csp.add_different(static_cast<int>(a), static_cast<int>(b));
+      }
+    }
+  }
+  return csp;
+}
+
+inline std::optional<SudokuGrid> solve_sudoku_by_csp(const SudokuGrid& puzzle) {
+  const auto solution = solve_csp(sudoku_csp(puzzle)).solution;
+  if (!solution) {
+    return std::nullopt;
+  }
+  SudokuGrid grid{};
+  std::copy(solution->begin(), solution->end(), grid.begin());
+  return grid;
+}
@@ -0,0 +1,118 @@
+#include <cstddef>
+#include <vector>
+
+#include "../backtracking/Sudoku.h"
+#include "../generators/Generators.h"
+#include "../graph/Graph.h"
+#include "../rng/Rng.h"
+#include "Csp.h"
+#include "gtest/gtest.h"
+
+// x < y < z < x can't hold, and arc consistency alone finds that out, so
+// the search stops at the root
+TEST(Csp, InconsistentEarly) {
+  Csp csp;
+  const int x = csp.add_variable({1, 2, 3});
+  const int y = csp.add_variable({1, 2, 3});
+  const int z = csp.add_variable({1, 2, 3});
+  auto less = [](int a, int b) { return a < b; };
+  csp.add_constraint(x, y, less);
+  csp.add_constraint(y, z, less);
+
+  Domains domains = csp.domains();
+  ASSERT_TRUE(csp.make_arc_consistent(domains));
+  EXPECT_EQ(domains, (Domains{{1}, {2}, {3}}));
+
+  csp.add_constraint(z, x, less);
+  domains = csp.domains();
+  EXPECT_FALSE(csp.make_arc_consistent(domains));
+  const CspResult result = solve_csp(csp);
+  EXPECT_FALSE(result.solution.has_value());
+  EXPECT_EQ(result.stats.nodes, 1u);
+
+  Csp empty_domain;
+  empty_domain.add_variable({});
+  EXPECT_EQ(solve_csp(empty_domain).stats.nodes, 1u);
+  EXPECT_EQ(solve_csp(Csp()).solution, std::vector<int>{});
+}
+
+TEST(Csp, MapColoring) {
+  // Australia: WA, NT, SA, Q, NSW, V and T
+  const AdjacencyList australia = undirected_from_edges(
+      7, {{0, 1}, {0, 2}, {1, 2}, {1, 3}, {2, 3}, {2, 4}, {2, 5}, {3, 4},
+          {4, 5}});
+  const auto colors = color_map(australia, 3);
+  ASSERT_TRUE(colors.has_value());
+  EXPECT_TRUE(map_coloring_csp(australia, 3).satisfies(*colors));
+  EXPECT_FALSE(color_map(australia, 2).has_value());
+
+  // A triangle is arc consistent with two colors, but can't be colored
+  const AdjacencyList triangle =
+      undirected_from_edges(3, {{0, 1}, {1, 2}, {0, 2}});
+  const Csp two_colors = map_coloring_csp(triangle, 2);
+  Domains domains = two_colors.domains();
+  EXPECT_TRUE(two_colors.make_arc_consistent(domains));
+  EXPECT_EQ(domains, two_colors.domains());
+  EXPECT_FALSE(solve_csp(two_colors).solution.has_value());
+}
+
+// Against trying every coloring, on random small graphs
+TEST(Csp, ColoringMatchesNaive) {
+  Rng gen(225);
+  for (int trial = 0; trial < 300; ++trial) {
+    const size_t n = 1 + uniform_below(gen, 7);
+    const AdjacencyList graph = erdos_renyi(n, 0.5, gen);
+    const int colors = 1 + static_cast<int>(uniform_below(gen, 3));
+    bool colorable = false;
+    std::vector<int> coloring(n, 0);
+    for (bool more = true; more && !colorable;) {
+      colorable = true;
+      for (size_t u = 0; u < n; ++u) {
+        for (int v : graph[u]) {
+          colorable = colorable && coloring[u] != coloring[v];
+        }
+      }
+      more = false;
+      for (size_t i = 0; i < n && !more; ++i) {
+        more = ++coloring[i] < colors;
+        if (!more) {
+          coloring[i] = 0;
+        }
+      }
+    }
+    const auto found = color_map(graph, colors);
+    ASSERT_EQ(found.has_value(), colorable);
+    if (found) {
+      ASSERT_TRUE(map_coloring_csp(graph, colors).satisfies(*found));
+    }
+  }
+}
+
+TEST(Csp, Sudoku) {
+  // Easy enough that arc consistency alone solves it
+  const SudokuGrid easy = sudoku_from_string(
+      "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3"
+      "..9..5.1.3..");
+  const CspResult result = solve_csp(sudoku_csp(easy));
+  ASSERT_TRUE(result.solution.has_value());
+  EXPECT_EQ(result.stats.nodes, 1u);
+  EXPECT_EQ(solve_sudoku_by_csp(easy), solve_sudoku(easy));
+
+  const SudokuGrid hard = sudoku_from_string(
+      "8..........36......7..9.2...5...7.......457.....1...3...1....68..85."
+      "..1..9....4..");
+  const auto solution = solve_sudoku_by_csp(hard);
+  ASSERT_TRUE(solution.has_value());
+  EXPECT_TRUE(is_sudoku_solution(*solution, hard));
+
+  SudokuGrid clash{};
+  clash[0] = clash[8] = 5;
+  const CspResult none = solve_csp(sudoku_csp(clash));
+  EXPECT_FALSE(none.solution.has_value());
+  EXPECT_EQ(none.stats.nodes, 1u);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
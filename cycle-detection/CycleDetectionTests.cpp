@@ -0,0 +1,87 @@
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "CycleDetection.h"
+#include "gtest/gtest.h"
+
+TEST(CycleDetection, Example) {
+  // 2 -> 0 -> 6 -> 3 -> 1 -> 6 -> ...: a tail of 2, then a cycle of 3
+  const std::vector<int> next = {6, 6, 0, 1, 4, 3, 3, 4, 0};
+  auto f = [&](int x) { return next[x]; };
+  const CycleInfo expected = {2, 3};
+  EXPECT_EQ(floyd(2, f), expected);
+  EXPECT_EQ(brent(2, f), expected);
+  EXPECT_EQ(cycle_naive(2, f), expected);
+  // A fixed point is a cycle of length 1
+  EXPECT_EQ(floyd(4, f), (CycleInfo{0, 1}));
+  EXPECT_EQ(brent(4, f), (CycleInfo{0, 1}));
+}
+
+// x^2 + c mod m, as in Pollard's rho, and random mappings of [0, m)
+TEST(CycleDetection, PseudoRandomFunctions) {
+  Rng gen(192);
+  size_t floyd_calls = 0;
+  size_t brent_calls = 0;
+  for (int trial = 0; trial < 200; ++trial) {
+    const uint64_t m = 1 + uniform_below(gen, 100000);
+    const uint64_t c = uniform_below(gen, m);
+    const uint64_t x0 = uniform_below(gen, m);
+    auto square = [&](uint64_t x) { return (x * x + c) % m; };
+    ASSERT_EQ(floyd(x0, square), cycle_naive(x0, square));
+    ASSERT_EQ(brent(x0, square), cycle_naive(x0, square));
+
+    std::vector<uint64_t> table(m);
+    for (auto& y : table) {
+      y = uniform_below(gen, m);
+    }
+    auto counted = [&](size_t& calls) {
+      return [&](uint64_t x) {
+        ++calls;
+        return table[x];
+      };
+    };
+    const CycleInfo expected =
+        cycle_naive(x0, [&](uint64_t x) { return table[x]; });
+    ASSERT_EQ(floyd(x0, counted(floyd_calls)), expected);
+    ASSERT_EQ(brent(x0, counted(brent_calls)), expected);
+  }
+  EXPECT_LT(brent_calls, floyd_calls);
+}
+
+struct ListNode {
+  int val;
+  ListNode* next = nullptr;
+};
+
+// n nodes in a row; the last one points back at node loop_to, if any
+std::vector<ListNode> make_list(size_t n, std::optional<size_t> loop_to) {
+  std::vector<ListNode> nodes(n);
+  for (size_t i = 0; i < n; ++i) {
+    nodes[i].val = static_cast<int>(i);
+    nodes[i].next = i + 1 < n ? &nodes[i + 1] : nullptr;
+  }
+  if (loop_to) {
+    nodes.back().next = &nodes[*loop_to];
+  }
+  return nodes;
+}
+
+TEST(CycleDetection, LinkedLists) {
+  EXPECT_EQ(list_cycle<ListNode>(nullptr), std::nullopt);
+  for (size_t n : {1, 2, 5, 100}) {
+    auto straight = make_list(n, std::nullopt);
+    EXPECT_EQ(list_cycle(&straight[0]), std::nullopt);
+    for (size_t k = 0; k < n; ++k) {
+      auto looped = make_list(n, k);
+      ASSERT_EQ(list_cycle(&looped[0]), (CycleInfo{k, n - k}));
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
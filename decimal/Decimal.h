@@ -0,0 +1,247 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../combinatorics/BigUnsigned.h"
+#include "../sequences/Sequences.h"
+
+// Reporting numeric results to a given number of decimal places, with the
+// last place rounded correctly, in exact integer arithmetic rather than
+// trusting floating point formatting.
+//
+// Two different questions hide in "print x to 30 places":
+//
+// - What is this double, to 30 places? A double is exactly m * 2^e, a
+//   terminating decimal, so the question has an exact answer, which
+//   format_fixed works out digit by digit.
+// - What is the root itself, to 30 places? A double holds about 16
+//   significant digits, so past those it answers the first question but
+//   not this one. polynomial_root_fixed answers it for roots of integer
+//   polynomials by bisecting on exact rationals, as far as asked.
+
+// The digits of magnitude with a decimal point `places` digits from the
+// right, and a '-' in front if negative
+inline std::string with_point(const BigUnsigned& magnitude, int places,
+                              bool negative) {
+  std::string digits = magnitude.to_string();
+  const auto point = static_cast<size_t>(places);
+  if (digits.size() <= point) {
+    digits.insert(0, point + 1 - digits.size(), '0');
+  }
+  if (places > 0) {
+    digits.insert(digits.size() - point, 1, '.');
+  }
+  return (negative ? "-" : "") + digits;
+}
+
+// 10^places
+inline BigUnsigned power_of_ten(int places) {
+  BigUnsigned power(1);
+  for (int i = 0; i < places; ++i) {
+    power = power * 10;
+  }
+  return power;
+}
+
+// The exact value of x rounded to `places` decimal places, half to even,
+// as printf("%.*f", places, x) writes it: "-" for anything with the sign
+// bit, even zero, and "nan", "inf", or "-inf" for the rest.
+//
+// x = m * 2^e with m a 53-bit integer. With e >= 0 x is an integer; with
+// e < 0 x * 10^places = m * 10^places / 2^-e, and halving one bit at a
+// time leaves the quotient plus the bits that fell off: the last one is
+// the half, and any one before it makes the remainder more than a half.
+inline std::string format_fixed(double x, int places) {
+  assert(places >= 0 && "places can't be negative");
+  const bool negative = std::signbit(x);
+  if (std::isnan(x)) {
+    return negative ? "-nan" : "nan";
+  }
+  if (std::isinf(x)) {
+    return negative ? "-inf" : "inf";
+  }
+  int exponent = 0;
+  const double fraction = std::frexp(std::fabs(x), &exponent);
+  BigUnsigned scaled(static_cast<uint64_t>(std::ldexp(fraction, 53)));
+  exponent -= 53;
+  scaled = scaled * power_of_ten(places);
+  for (; exponent > 0; --exponent) {
+    scaled = scaled * 2;
+  }
+  bool half = false;
+  bool beyond_half = false;
+  for (; exponent < 0; ++exponent) {
+    beyond_half = beyond_half || half;
+    auto [quotient, bit] = scaled.divide(2);
+    scaled = std::move(quotient);
+    half = bit == 1;
+  }
+  const bool odd = scaled.divide(2).second == 1;
+  if (half && (beyond_half || odd)) {
+    scaled = scaled + BigUnsigned(1);
+  }
+  return with_point(scaled, places, negative);
+}
+
+// Exact evaluation of an integer polynomial at a rational point, for its
+// sign. Coefficients go highest degree first, as in sequences/.
+
+// |value|. The magnitude of the most negative int64_t doesn't fit one, but
+// it does fit a uint64_t.
+inline uint64_t unsigned_abs(int64_t value) {
+  return value < 0 ? 0 - static_cast<uint64_t>(value)
+                   : static_cast<uint64_t>(value);
+}
+
+// An integer with a sign, as the sums and products evaluation needs
+struct SignedBig {
+  bool negative = false;
+  BigUnsigned magnitude;
+
+  SignedBig operator+(const SignedBig& other) const {
+    if (negative == other.negative) {
+      return {negative, magnitude + other.magnitude};
+    }
+    if (magnitude >= other.magnitude) {
+      return normalized({negative, magnitude - other.magnitude});
+    }
+    return {other.negative, other.magnitude - magnitude};
+  }
+
+  SignedBig operator*(const BigUnsigned& factor) const {
+    return normalized({negative, magnitude * factor});
+  }
+
+  SignedBig operator-() const { return normalized({!negative, magnitude}); }
+
+  static SignedBig from(int64_t value) {
+    return {value < 0, BigUnsigned(unsigned_abs(value))};
+  }
+
+  // The sign of 0 is +, so that zero has one form
+  static SignedBig normalized(SignedBig value) {
+    value.negative = value.negative && !value.magnitude.is_zero();
+    return value;
+  }
+
+  int sign() const { return magnitude.is_zero() ? 0 : negative ? -1 : 1; }
+};
+
+// The sign of p(a / b), for a, b >= 0 and b > 0: Horner's rule on
+// p(a / b) * b^n = (...((c0 a + c1 b) a + c2 b^2) a + ...) + cn b^n
+//
+// With reflected, the sign of p(-a / b): the odd degrees flip sign. They're
+// flipped once widened, since the most negative int64_t has no negation.
+inline int sign_at(const std::vector<int64_t>& coefficients,
+                   const BigUnsigned& a, const BigUnsigned& b,
+                   bool reflected = false) {
+  SignedBig value;
+  BigUnsigned b_power(1);
+  for (size_t i = 0; i < coefficients.size(); ++i) {
+    SignedBig term = SignedBig::from(coefficients[i]) * b_power;
+    if (reflected && (coefficients.size() - 1 - i) % 2 == 1) {
+      term = -term;
+    }
+    value = value * a + term;
+    b_power = b_power * b;
+  }
+  return value.sign();
+}
+
+// The root of p in [low, high], or of p(-x) with reflected, as the
+// numerator a of a / (2 * 10^places) just below it, and whether it's
+// exactly there
+inline std::pair<BigUnsigned, bool> bisect_root(
+    const std::vector<int64_t>& coefficients, uint64_t low, uint64_t high,
+    int places, bool reflected) {
+  const BigUnsigned scale = power_of_ten(places) * 2;
+  BigUnsigned lo = BigUnsigned(low) * scale;
+  BigUnsigned hi = BigUnsigned(high) * scale;
+  const int lo_sign = sign_at(coefficients, lo, scale, reflected);
+  const int hi_sign = sign_at(coefficients, hi, scale, reflected);
+  if (lo_sign == 0 || hi_sign == 0) {
+    return {lo_sign == 0 ? lo : hi, true};
+  }
+  assert(lo_sign != hi_sign && "p must change sign over the interval");
+  const BigUnsigned one(1);
+  while (lo + one < hi) {
+    BigUnsigned middle = (lo + hi).divide(2).first;
+    const int sign = sign_at(coefficients, middle, scale, reflected);
+    if (sign == 0) {
+      return {middle, true};
+    }
+    (sign == lo_sign ? lo : hi) = std::move(middle);
+  }
+  return {lo, false};
+}
+
+// The root of p in [low, high], rounded to `places` places, half to even.
+// p must change sign over the interval, or be 0 at an end; if it has more
+// than one root there, which one comes back is up to the bisection.
+//
+// Rounding to `places` places turns on which side of the midpoints
+// (2k + 1) / (2 * 10^places) the root falls, so the bisection runs on
+// numerators over 2 * 10^places, keeping the sign change between two of
+// them until they're adjacent. If the root is between a and a + 1, it
+// rounds to a / 2 for an even a, and to (a + 1) / 2 for an odd one; only a
+// rational root can land exactly on an odd a, a tie. Negative roots are
+// found as roots of p(-x).
+inline std::string polynomial_root_fixed(
+    const std::vector<int64_t>& coefficients, int64_t low, int64_t high,
+    int places) {
+  assert(low <= high && places >= 0);
+  const BigUnsigned one(1);
+  bool negative = false;
+  // The interval searched, as magnitudes: of [low, high], or of
+  // [-high, -low] for p(-x)
+  uint64_t from = low < 0 ? 0 : static_cast<uint64_t>(low);
+  uint64_t to = high < 0 ? 0 : static_cast<uint64_t>(high);
+  if (low < 0) {
+    const int at_zero = sign_at(coefficients, BigUnsigned(), one);
+    const bool above =
+        high > 0 &&
+        at_zero * sign_at(coefficients,
+                          BigUnsigned(static_cast<uint64_t>(high)), one) <= 0;
+    if (!above) {
+      from = high > 0 ? 0 : unsigned_abs(high);
+      to = unsigned_abs(low);
+      negative = true;
+    }
+  }
+  const auto [numerator, exact] =
+      bisect_root(coefficients, from, to, places, negative);
+  auto [rounded, odd] = numerator.divide(2);
+  // An exact odd numerator is a tie, which goes to the even neighbor
+  if (odd == 1 && (!exact || rounded.divide(2).second == 1)) {
+    rounded = rounded + one;
+  }
+  negative = negative && !numerator.is_zero();
+  return with_point(rounded, places, negative);
+}
+
+// Newton's method in floating point, for comparison: x -= p(x) / p'(x)
+// until the step is within tolerance or the iterations run out
+inline double newton_root(const std::vector<double>& coefficients, double x,
+                          double tolerance = 1e-15, int iterations = 100) {
+  std::vector<double> derivative;
+  for (size_t i = 0; i + 1 < coefficients.size(); ++i) {
+    derivative.push_back(coefficients[i] *
+                         static_cast<double>(coefficients.size() - 1 - i));
+  }
+  for (int i = 0; i < iterations; ++i) {
+    const double step = evaluate_polynomial(coefficients, x) /
+                        evaluate_polynomial(derivative, x);
+    x -= step;
+    if (std::fabs(step) <= tolerance * std::max(1.0, std::fabs(x))) {
+      break;
+    }
+  }
+  return x;
+}
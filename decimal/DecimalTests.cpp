@@ -0,0 +1,137 @@
+#include <cmath>
+#include <cstdint>
+#include <cstdio>
+#include <limits>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Decimal.h"
+#include "gtest/gtest.h"
+
+// Known digits, more than the tests ask for
+const char* const kSqrt2 =
+    "1.414213562373095048801688724209698078569671875376948073176679737990";
+const char* const kGoldenRatio =
+    "1.618033988749894848204586834365638117720309179805762862135448622705";
+const char* const kCubeRoot2 =
+    "1.259921049894873164767210607278228350570251464701507980081975112155";
+
+// The known digits rounded to `places` places. None of the constants is a
+// tie, so rounding half up is as good as any.
+std::string rounded(const std::string& digits, int places) {
+  const size_t point = digits.find('.');
+  std::string kept = digits.substr(0, point) +
+                     digits.substr(point + 1, static_cast<size_t>(places));
+  if (digits[point + 1 + static_cast<size_t>(places)] >= '5') {
+    size_t i = kept.size();
+    while (i-- > 0 && kept[i] == '9') {
+      kept[i] = '0';
+    }
+    if (i == std::string::npos) {
+      kept.insert(0, "1");
+    } else {
+      ++kept[i];
+    }
+  }
+  if (places > 0) {
+    kept.insert(kept.size() - static_cast<size_t>(places), ".");
+  }
+  return kept;
+}
+
+std::string printf_fixed(double x, int places) {
+  std::vector<char> buffer(400);
+  const int length =
+      std::snprintf(buffer.data(), buffer.size(), "%.*f", places, x);
+  return std::string(buffer.data(), static_cast<size_t>(length));
+}
+
+TEST(Decimal, FormatFixed) {
+  EXPECT_EQ(format_fixed(0.1, 20), "0.10000000000000000555");
+  EXPECT_EQ(format_fixed(2.675, 2), "2.67");  // Just below 2.675
+  EXPECT_EQ(format_fixed(0.125, 2), "0.12");  // Ties go to even
+  EXPECT_EQ(format_fixed(0.375, 2), "0.38");
+  EXPECT_EQ(format_fixed(2.5, 0), "2");
+  EXPECT_EQ(format_fixed(-0.0, 1), "-0.0");
+  EXPECT_EQ(format_fixed(-0.004, 2), "-0.00");
+  EXPECT_EQ(format_fixed(1e22, 0), "10000000000000000000000");
+  EXPECT_EQ(format_fixed(9.9999, 3), "10.000");
+  EXPECT_EQ(format_fixed(std::numeric_limits<double>::infinity(), 2), "inf");
+  EXPECT_EQ(format_fixed(std::numeric_limits<double>::denorm_min(), 330),
+            printf_fixed(std::numeric_limits<double>::denorm_min(), 330));
+
+  // glibc's printf rounds the exact value too
+  Rng gen(200);
+  for (int t = 0; t < 5000; ++t) {
+    const int exponent = uniform_int(gen, -80, 80);
+    const double x = std::ldexp(uniform_unit(gen) - 0.5, exponent);
+    const int places = uniform_int(gen, 0, 40);
+    ASSERT_EQ(format_fixed(x, places), printf_fixed(x, places));
+  }
+  for (int t = 0; t < 5000; ++t) {
+    // Short decimals, which are often exact ties in binary
+    const double x = static_cast<double>(uniform_int(gen, -10000, 10000)) / 64;
+    const int places = uniform_int(gen, 0, 6);
+    ASSERT_EQ(format_fixed(x, places), printf_fixed(x, places));
+  }
+}
+
+TEST(Decimal, IrrationalRoots) {
+  const std::vector<int64_t> two = {1, 0, -2};        // x^2 - 2
+  const std::vector<int64_t> golden = {1, -1, -1};    // x^2 - x - 1
+  const std::vector<int64_t> cube = {1, 0, 0, -2};    // x^3 - 2
+  for (int places : {0, 1, 5, 15, 16, 17, 30, 60}) {
+    EXPECT_EQ(polynomial_root_fixed(two, 1, 2, places),
+              rounded(kSqrt2, places));
+    EXPECT_EQ(polynomial_root_fixed(golden, 1, 2, places),
+              rounded(kGoldenRatio, places));
+    EXPECT_EQ(polynomial_root_fixed(cube, 0, 5, places),
+              rounded(kCubeRoot2, places));
+  }
+  // The other root of the golden ratio's polynomial, 1 - phi, is negative
+  EXPECT_EQ(polynomial_root_fixed(golden, -1, 0, 10), "-0.6180339887");
+  EXPECT_EQ(polynomial_root_fixed(two, -2, -1, 10), "-1.4142135624");
+  // With a root on each side of 0, the sign change from 0 to high wins
+  EXPECT_EQ(polynomial_root_fixed(two, -2, 2, 3), "1.414");
+  EXPECT_EQ(polynomial_root_fixed({1, 1}, -5, 5, 2), "-1.00");
+}
+
+TEST(Decimal, RationalRoots) {
+  EXPECT_EQ(polynomial_root_fixed({2, -1}, 0, 1, 0), "0");  // A tie
+  EXPECT_EQ(polynomial_root_fixed({2, -3}, 0, 9, 0), "2");  // Also a tie
+  EXPECT_EQ(polynomial_root_fixed({8, -1}, 0, 1, 2), "0.12");
+  EXPECT_EQ(polynomial_root_fixed({8, -3}, 0, 1, 2), "0.38");
+  EXPECT_EQ(polynomial_root_fixed({3, -1}, 0, 1, 5), "0.33333");
+  EXPECT_EQ(polynomial_root_fixed({3, -2}, 0, 1, 5), "0.66667");
+  EXPECT_EQ(polynomial_root_fixed({1, 0, 0, 0}, -1, 1, 3), "0.000");
+  EXPECT_EQ(polynomial_root_fixed({1, -4}, 0, 4, 1), "4.0");
+
+  // Reflecting for a negative root can't negate the most negative int64_t,
+  // in a coefficient or a bound
+  const int64_t min = std::numeric_limits<int64_t>::min();
+  EXPECT_EQ(polynomial_root_fixed({min, min}, -2, 0, 2), "-1.00");
+  EXPECT_EQ(polynomial_root_fixed({1, 0, min}, min, -1, 0),
+            "-3037000500");
+  EXPECT_EQ(polynomial_root_fixed({1, 1}, min, 0, 2), "-1.00");
+}
+
+// A double's root is only good for about 16 digits: to 15 places it's the
+// root, and past there it's the double
+TEST(Decimal, NewtonAgainstExact) {
+  const double sqrt2 = newton_root({1, 0, -2}, 1.0);
+  EXPECT_LE(std::fabs(sqrt2 - std::sqrt(2.0)), 4e-16);
+  EXPECT_EQ(format_fixed(sqrt2, 15),
+            polynomial_root_fixed({1, 0, -2}, 1, 2, 15));
+  EXPECT_EQ(format_fixed(std::sqrt(2.0), 20), "1.41421356237309514547");
+  EXPECT_EQ(polynomial_root_fixed({1, 0, -2}, 1, 2, 20),
+            "1.41421356237309504880");
+
+  const double cube = newton_root({1, 0, 0, -2}, 1.0);
+  EXPECT_EQ(format_fixed(cube, 14), rounded(kCubeRoot2, 14));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,177 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <vector>
+
+#include "../backtracking/Backtracking.h"
+
+// Exact cover: given a set of rows, each covering some of the columns,
+// choose rows that together cover every column exactly once. Sudoku,
+// n queens, pentomino tilings and plenty of other puzzles are exact cover
+// problems in disguise.
+//
+// Knuth's Algorithm X is backtracking: pick the column with the fewest
+// rows left, try each of them, and for each, remove every column it
+// covers and every row that clashes with it, then recurse. Dancing links
+// make the removing and restoring cheap. The matrix is a grid of doubly
+// linked lists, a ring per row and a ring per column, holding only its
+// ones; removing a node from its lists leaves the node's own links alone,
+// so it can go back in O(1), and restoring in the reverse order of
+// removing undoes it all exactly.
+//
+// Secondary columns may be covered at most once instead of exactly once,
+// like the diagonals in n queens. They're never chosen to branch on, and
+// it's fine for the search to end with them still uncovered.
+class DancingLinks {
+ public:
+  explicit DancingLinks(size_t primary, size_t secondary = 0)
+      : columns_(primary + secondary), size_(primary + secondary + 1, 0) {
+    // Node 0 is the root, and nodes 1 to columns_ the column headers. Only
+    // primary headers are in the root's ring.
+    for (size_t i = 0; i <= columns_; ++i) {
+      const bool linked = i <= primary;
+      left_.push_back(linked ? (i == 0 ? primary : i - 1) : i);
+      right_.push_back(linked ? (i == primary ? 0 : i + 1) : i);
+      up_.push_back(i);
+      down_.push_back(i);
+      column_.push_back(i);
+      row_.push_back(kNoRow);
+    }
+  }
+
+  size_t columns() const { return columns_; }
+  size_t rows() const { return rows_; }
+
+  // Adds a row covering the given columns, each at most once, and returns
+  // its index, counting from 0. A row that covers nothing is never chosen.
+  size_t add_row(const std::vector<size_t>& columns) {
+    const size_t row = rows_++;
+    const size_t first = left_.size();
+    for (size_t k = 0; k < columns.size(); ++k) {
+      assert(columns[k] < columns_ && "column out of range");
+      const size_t node = left_.size();
+      const size_t header = columns[k] + 1;
+      left_.push_back(k == 0 ? node : node - 1);
+      right_.push_back(first);
+      if (k > 0) {
+        right_[node - 1] = node;
+        left_[first] = node;
+      }
+      up_.push_back(up_[header]);
+      down_.push_back(header);
+      down_[up_[header]] = node;
+      up_[header] = node;
+      column_.push_back(header);
+      row_.push_back(row);
+      ++size_[header];
+    }
+    return row;
+  }
+
+  // Calls visit(rows), the indices of the rows of each exact cover, for as
+  // long as it returns true
+  template <typename Visit>
+  BacktrackStats solve(Visit visit) {
+    BacktrackStats stats;
+    std::vector<size_t> chosen;
+    search(visit, chosen, stats);
+    return stats;
+  }
+
+ private:
+  static constexpr size_t kNoRow = static_cast<size_t>(-1);
+
+  // Takes column c out of the root's ring, and every row through c out of
+  // the other columns it covers
+  void cover(size_t c) {
+    right_[left_[c]] = right_[c];
+    left_[right_[c]] = left_[c];
+    for (size_t i = down_[c]; i != c; i = down_[i]) {
+      for (size_t j = right_[i]; j != i; j = right_[j]) {
+        down_[up_[j]] = down_[j];
+        up_[down_[j]] = up_[j];
+        --size_[column_[j]];
+      }
+    }
+  }
+
+  // cover(c), exactly backwards
+  void uncover(size_t c) {
+    for (size_t i = up_[c]; i != c; i = up_[i]) {
+      for (size_t j = left_[i]; j != i; j = left_[j]) {
+        ++size_[column_[j]];
+        down_[up_[j]] = j;
+        up_[down_[j]] = j;
+      }
+    }
+    right_[left_[c]] = c;
+    left_[right_[c]] = c;
+  }
+
+  template <typename Visit>
+  bool search(Visit& visit, std::vector<size_t>& chosen,
+              BacktrackStats& stats) {
+    ++stats.nodes;
+    if (right_[0] == 0) {
+      ++stats.solutions;
+      if (!visit(static_cast<const std::vector<size_t>&>(chosen))) {
+        stats.stopped = true;
+        return false;
+      }
+      return true;
+    }
+    size_t c = right_[0];
+    for (size_t j = right_[c]; j != 0; j = right_[j]) {
+      if (size_[j] < size_[c]) {
+        c = j;
+      }
+    }
+    if (size_[c] == 0) {
+      return true;
+    }
+    cover(c);
+    bool keep_going = true;
+    for (size_t r = down_[c]; r != c && keep_going; r = down_[r]) {
+      chosen.push_back(row_[r]);
+      for (size_t j = right_[r]; j != r; j = right_[j]) {
+        cover(column_[j]);
+      }
+      keep_going = search(visit, chosen, stats);
+      for (size_t j = left_[r]; j != r; j = left_[j]) {
+        uncover(column_[j]);
+      }
+      chosen.pop_back();
+    }
+    uncover(c);
+    return keep_going;
+  }
+
+  size_t columns_;
+  size_t rows_{0};
+  // The links of every node: the root, the column headers, then the ones
+  // of each row in turn
+  std::vector<size_t> left_;
+  std::vector<size_t> right_;
+  std::vector<size_t> up_;
+  std::vector<size_t> down_;
+  std::vector<size_t> column_;  // the header of a node's column
+  std::vector<size_t> row_;     // the row a node is in
+  std::vector<size_t> size_;    // the ones left in each column
+};
+
+// Every exact cover, each as its rows in the order they were chosen
+inline std::vector<std::vector<size_t>> exact_covers(
+    size_t primary, const std::vector<std::vector<size_t>>& rows,
+    size_t secondary = 0) {
+  DancingLinks links(primary, secondary);
+  for (const auto& row : rows) {
+    links.add_row(row);
+  }
+  std::vector<std::vector<size_t>> covers;
+  links.solve([&](const std::vector<size_t>& chosen) {
+    covers.push_back(chosen);
+    return true;
+  });
+  return covers;
+}
@@ -0,0 +1,53 @@
+#include <cstddef>
+
+#include <benchmark/benchmark.h>
+
+#include "../backtracking/Backtracking.h"
+#include "../backtracking/Sudoku.h"
+#include "../local-search/NQueens.h"
+#include "Dlx.h"
+#include "ExactCoverPuzzles.h"
+
+// Dancing links against the plain backtracking in backtracking/, which
+// branches on the most constrained cell too, but only ever looks at cells:
+// exact cover also branches on where a digit goes in a row, column or box,
+// whichever has the fewest options. N queens takes n as the argument.
+
+// Arto Inkala's, built to be hard for people
+static const char kHardSudoku[] =
+    "8..........36......7..9.2...5...7.......457.....1...3...1....68..85..."
+    "1..9....4..";
+
+static void BM_SudokuBacktracking(benchmark::State& state) {
+  const SudokuGrid puzzle = sudoku_from_string(kHardSudoku);
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(solve_sudoku(puzzle));
+  }
+}
+
+static void BM_SudokuDlx(benchmark::State& state) {
+  const SudokuGrid puzzle = sudoku_from_string(kHardSudoku);
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(solve_sudoku_by_dlx(puzzle));
+  }
+}
+
+static void BM_QueensBacktracking(benchmark::State& state) {
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(count_n_queens(static_cast<int>(state.range(0))));
+  }
+}
+
+static void BM_QueensDlx(benchmark::State& state) {
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(
+        count_n_queens_by_dlx(static_cast<int>(state.range(0))));
+  }
+}
+
+BENCHMARK(BM_SudokuBacktracking);
+BENCHMARK(BM_SudokuDlx);
+BENCHMARK(BM_QueensBacktracking)->DenseRange(6, 12, 2);
+BENCHMARK(BM_QueensDlx)->DenseRange(6, 12, 2);
+
+BENCHMARK_MAIN();
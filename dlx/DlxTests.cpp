@@ -0,0 +1,107 @@
+#include <algorithm>
+#include <cstddef>
+#include <vector>
+
+#include "../backtracking/Backtracking.h"
+#include "../backtracking/Sudoku.h"
+#include "../local-search/NQueens.h"
+#include "../rng/Rng.h"
+#include "Dlx.h"
+#include "ExactCoverPuzzles.h"
+#include "gtest/gtest.h"
+
+// Knuth's example from the Dancing Links paper: rows 0, 3 and 4 are the
+// one exact cover
+TEST(Dlx, KnuthExample) {
+  const std::vector<std::vector<size_t>> rows = {
+      {2, 4, 5}, {0, 3, 6}, {1, 2, 5}, {0, 3}, {1, 6}, {3, 4, 6}};
+  auto covers = exact_covers(7, rows);
+  ASSERT_EQ(covers.size(), 1u);
+  std::sort(covers[0].begin(), covers[0].end());
+  EXPECT_EQ(covers[0], (std::vector<size_t>{0, 3, 4}));
+
+  EXPECT_EQ(exact_covers(0, {}), (std::vector<std::vector<size_t>>{{}}));
+  EXPECT_EQ(exact_covers(2, {{0}, {0, 1}, {0}}).size(), 1u);
+  EXPECT_TRUE(exact_covers(2, {{0}}).empty());
+  // Column 2 is secondary: covered at most once, so both rows can't go
+  // together, but leaving it out is fine
+  EXPECT_EQ(exact_covers(2, {{0, 2}, {1, 2}, {1}}, 1),
+            (std::vector<std::vector<size_t>>{{0, 2}}));
+}
+
+// Against trying every subset of rows, on random small matrices. Rows
+// that cover nothing are left out of both.
+TEST(Dlx, MatchesSubsets) {
+  Rng gen(224);
+  for (int trial = 0; trial < 300; ++trial) {
+    const size_t columns = uniform_below(gen, 6);
+    std::vector<std::vector<size_t>> rows(uniform_below(gen, 10));
+    for (auto& row : rows) {
+      for (size_t c = 0; c < columns; ++c) {
+        if (bernoulli(gen, 0.3)) {
+          row.push_back(c);
+        }
+      }
+    }
+    std::vector<std::vector<size_t>> expected;
+    for (size_t mask = 0; mask < (size_t{1} << rows.size()); ++mask) {
+      std::vector<int> covered(columns, 0);
+      std::vector<size_t> chosen;
+      bool empty_row = false;
+      for (size_t r = 0; r < rows.size(); ++r) {
+        if (mask >> r & 1) {
+          empty_row = empty_row || rows[r].empty();
+          chosen.push_back(r);
+          for (size_t c : rows[r]) {
+            ++covered[c];
+          }
+        }
+      }
+      if (!empty_row && std::all_of(covered.begin(), covered.end(),
+                      [](int k) { return k == 1; })) {
+        expected.push_back(chosen);
+      }
+    }
+    auto covers = exact_covers(columns, rows);
+    for (auto& cover : covers) {
+      std::sort(cover.begin(), cover.end());
+    }
+    std::sort(covers.begin(), covers.end());
+    std::sort(expected.begin(), expected.end());
+    ASSERT_EQ(covers, expected);
+  }
+}
+
+TEST(Dlx, Sudoku) {
+  for (const char* text :
+       {"..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3"
+        "..9..5.1.3..",
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85."
+        "..1..9....4.."}) {
+    const SudokuGrid puzzle = sudoku_from_string(text);
+    const auto solution = solve_sudoku_by_dlx(puzzle);
+    ASSERT_TRUE(solution.has_value());
+    EXPECT_TRUE(is_sudoku_solution(*solution, puzzle));
+    EXPECT_EQ(solution, solve_sudoku(puzzle));
+    EXPECT_EQ(count_sudoku_solutions_by_dlx(puzzle), 1u);
+  }
+  const SudokuGrid empty{};
+  EXPECT_EQ(count_sudoku_solutions_by_dlx(empty), 2u);
+  SudokuGrid clash{};
+  clash[0] = clash[8] = 5;
+  EXPECT_FALSE(solve_sudoku_by_dlx(clash).has_value());
+}
+
+TEST(Dlx, Queens) {
+  for (int n = 0; n <= 9; ++n) {
+    auto ours = n_queens_by_dlx(n);
+    std::sort(ours.begin(), ours.end());
+    EXPECT_EQ(ours, n_queens(n)) << n;
+  }
+  EXPECT_EQ(count_n_queens_by_dlx(10), 724u);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,96 @@
+#pragma once
+
+#include <cstddef>
+#include <optional>
+#include <vector>
+
+#include "../backtracking/Sudoku.h"
+#include "Dlx.h"
+
+// The puzzles from backtracking/, as exact cover problems.
+
+// Sudoku has 324 columns, four kinds of 81 that each need exactly one
+// digit: every cell, every digit in every row, every digit in every column
+// and every digit in every box. Row 9 * cell + digit - 1 puts digit in
+// cell, and covers one of each kind. A given is the only row for its cell.
+inline DancingLinks sudoku_exact_cover(const SudokuGrid& puzzle) {
+  DancingLinks links(4 * 81);
+  for (size_t cell = 0; cell < 81; ++cell) {
+    for (size_t digit = 1; digit <= 9; ++digit) {
+      if (puzzle[cell] != 0 && static_cast<size_t>(puzzle[cell]) != digit) {
+        links.add_row({});  // keeps the row numbering
+        continue;
+      }
+      const size_t d = digit - 1;
+      links.add_row({cell, 81 + (cell / 9) * 9 + d, 162 + (cell % 9) * 9 + d,
+                     243 + sudoku_box(cell) * 9 + d});
+    }
+  }
+  return links;
+}
+
+inline SudokuGrid sudoku_from_cover(const std::vector<size_t>& rows) {
+  SudokuGrid grid{};
+  for (size_t row : rows) {
+    grid[row / 9] = static_cast<int>(row % 9) + 1;
+  }
+  return grid;
+}
+
+inline std::optional<SudokuGrid> solve_sudoku_by_dlx(
+    const SudokuGrid& puzzle) {
+  DancingLinks links = sudoku_exact_cover(puzzle);
+  std::optional<SudokuGrid> solution;
+  links.solve([&](const std::vector<size_t>& rows) {
+    solution = sudoku_from_cover(rows);
+    return false;
+  });
+  return solution;
+}
+
+inline size_t count_sudoku_solutions_by_dlx(const SudokuGrid& puzzle,
+                                            size_t limit = 2) {
+  DancingLinks links = sudoku_exact_cover(puzzle);
+  size_t count = 0;
+  if (limit > 0) {
+    links.solve([&](const std::vector<size_t>&) { return ++count < limit; });
+  }
+  return count;
+}
+
+// N queens: every row and every column needs exactly one queen, the
+// primary columns, and every diagonal at most one, the secondary ones.
+// Row r * n + c puts a queen at (r, c). Solutions as rows[c], the row of
+// column c's queen, as in backtracking/.
+inline DancingLinks queens_exact_cover(int n) {
+  const auto size = static_cast<size_t>(n);
+  const size_t diagonals = size == 0 ? 0 : 2 * size - 1;
+  DancingLinks links(2 * size, 2 * diagonals);
+  for (size_t r = 0; r < size; ++r) {
+    for (size_t c = 0; c < size; ++c) {
+      links.add_row({r, size + c, 2 * size + (r + size - 1 - c),
+                     2 * size + diagonals + (r + c)});
+    }
+  }
+  return links;
+}
+
+inline std::vector<std::vector<int>> n_queens_by_dlx(int n) {
+  DancingLinks links = queens_exact_cover(n);
+  std::vector<std::vector<int>> solutions;
+  links.solve([&](const std::vector<size_t>& chosen) {
+    std::vector<int> rows(n);
+    for (size_t row : chosen) {
+      rows[row % n] = static_cast<int>(row / n);
+    }
+    solutions.push_back(rows);
+    return true;
+  });
+  return solutions;
+}
+
+inline size_t count_n_queens_by_dlx(int n) {
+  DancingLinks links = queens_exact_cover(n);
+  return links.solve([](const std::vector<size_t>&) { return true; })
+      .solutions;
+}
@@ -0,0 +1,55 @@
+// Builds the pure algorithm etudes the way an embedded target would: no
+// exceptions, no RTTI, and no streams. Those fit in a small firmware image
+// and keep the standard containers, since they only need an allocator. A
+// header that starts throwing, or pulls in <ostream> outside an
+// ETUDES_NO_IO guard, breaks this build:
+//
+//   make check
+//
+// main() runs each etude once and returns nonzero on a wrong answer, so
+// the check also works where there's nothing to print to.
+
+#include <cstdint>
+#include <functional>
+#include <vector>
+
+#include "../bits/Bits.h"
+#include "../heap/BinaryHeap.h"
+#include "../patience/Patience.h"
+#include "../sequences/Functional.h"
+#include "../sequences/Sequences.h"
+
+#if defined(_GLIBCXX_OSTREAM) || defined(_LIBCPP_OSTREAM)
+#error "an embedded etude includes <ostream>"
+#endif
+#if defined(__cpp_exceptions) || defined(__cpp_rtti)
+#error "build with -fno-exceptions -fno-rtti"
+#endif
+
+int main() {
+  int failures = 0;
+  auto check = [&](bool ok) { failures += ok ? 0 : 1; };
+
+  const Subarray best = max_subarray({-2, 1, -3, 4, -1, 2, 1, -5, 4});
+  check(best == Subarray{6, 3, 7});
+  check(evaluate_polynomial({2, 3, 4, 5}, 2.0) == 41.0);
+  check(max_subarray_sum_scan({-2, 1, -3, 4, -1, 2, 1, -5, 4}) == 6);
+  check(evaluate_polynomial_fold({2, 3, 4, 5}, 2.0) == 41.0);
+
+  const std::vector<int> cards = {9, 5, 7, 3, 8, 6, 2};
+  check(lis_length(cards) == 3);
+  check(longest_increasing_subsequence(cards) == std::vector<int>{5, 7, 8});
+  check(patience_sort(cards) == std::vector<int>{2, 3, 5, 6, 7, 8, 9});
+
+  BinaryHeap<int, std::greater<int>> heap(cards);
+  std::vector<int> sorted;
+  while (!heap.empty()) {
+    sorted.push_back(heap.pop());
+  }
+  check(sorted == std::vector<int>{2, 3, 5, 6, 7, 8, 9});
+
+  check(popcount(0xff00ff) == 16);
+  check(reverse_bits(1) == uint64_t{1} << 63);
+
+  return failures;
+}
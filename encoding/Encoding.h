@@ -0,0 +1,136 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <vector>
+
+// Compact encodings for integers that are usually small.
+//
+// A varint (LEB128, as in Protocol Buffers) writes a number 7 bits at a
+// time, low bits first, with the high bit of each byte saying whether
+// another byte follows. Numbers below 128 take one byte, and a full 64-bit
+// number takes ten.
+//
+// Negative numbers are huge as unsigned, so they'd always take ten bytes.
+// Zigzag maps them to small unsigned numbers first, interleaving the signs:
+// 0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3, 2 -> 4, ...
+//
+// And a sorted sequence is mostly big numbers close together, so writing
+// the differences between neighbors instead makes them small.
+
+using Bytes = std::vector<uint8_t>;
+
+inline void encode_varint(uint64_t x, Bytes& out) {
+  while (x >= 0x80) {
+    out.push_back(static_cast<uint8_t>(x | 0x80));
+    x >>= 7;
+  }
+  out.push_back(static_cast<uint8_t>(x));
+}
+
+// Reads the varint at bytes[pos] and moves pos past it. Throws
+// std::invalid_argument if the bytes run out first, or if the number
+// doesn't fit in 64 bits.
+inline uint64_t decode_varint(const Bytes& bytes, size_t& pos) {
+  uint64_t x = 0;
+  for (int shift = 0; shift < 64; shift += 7) {
+    if (pos == bytes.size()) {
+      throw std::invalid_argument("truncated varint");
+    }
+    const uint8_t byte = bytes[pos++];
+    if (shift == 63 && byte > 1) {
+      throw std::invalid_argument("varint overflows 64 bits");
+    }
+    x |= static_cast<uint64_t>(byte & 0x7f) << shift;
+    if ((byte & 0x80) == 0) {
+      return x;
+    }
+  }
+  throw std::invalid_argument("varint overflows 64 bits");
+}
+
+inline size_t varint_size(uint64_t x) {
+  size_t size = 1;
+  while (x >= 0x80) {
+    x >>= 7;
+    ++size;
+  }
+  return size;
+}
+
+// The sign goes to the lowest bit: shifting right arithmetically spreads it
+// across the word, which flips all the other bits of negative numbers
+inline uint64_t zigzag_encode(int64_t x) {
+  return (static_cast<uint64_t>(x) << 1) ^ static_cast<uint64_t>(x >> 63);
+}
+
+inline int64_t zigzag_decode(uint64_t x) {
+  return static_cast<int64_t>((x >> 1) ^ (~(x & 1) + 1));
+}
+
+// The count, then the first value, then each value minus the one before.
+// Throws std::invalid_argument unless the values are sorted.
+inline Bytes encode_sorted(const std::vector<uint64_t>& values) {
+  Bytes out;
+  encode_varint(values.size(), out);
+  uint64_t previous = 0;
+  for (uint64_t x : values) {
+    if (x < previous) {
+      throw std::invalid_argument("values must be sorted");
+    }
+    encode_varint(x - previous, out);
+    previous = x;
+  }
+  return out;
+}
+
+inline std::vector<uint64_t> decode_sorted(const Bytes& bytes) {
+  size_t pos = 0;
+  const uint64_t count = decode_varint(bytes, pos);
+  std::vector<uint64_t> values;
+  uint64_t previous = 0;
+  for (uint64_t i = 0; i < count; ++i) {
+    const uint64_t delta = decode_varint(bytes, pos);
+    if (delta > UINT64_MAX - previous) {
+      throw std::invalid_argument("delta overflows 64 bits");
+    }
+    previous += delta;
+    values.push_back(previous);
+  }
+  if (pos != bytes.size()) {
+    throw std::invalid_argument("trailing bytes");
+  }
+  return values;
+}
+
+// The same for any sequence of signed numbers: the differences can be
+// negative, so they go through zigzag. Small when neighbors are close, in
+// whichever direction. The differences wrap around, so even
+// INT64_MIN, INT64_MAX round-trips.
+inline Bytes encode_deltas(const std::vector<int64_t>& values) {
+  Bytes out;
+  encode_varint(values.size(), out);
+  uint64_t previous = 0;
+  for (int64_t x : values) {
+    const uint64_t delta = static_cast<uint64_t>(x) - previous;
+    encode_varint(zigzag_encode(static_cast<int64_t>(delta)), out);
+    previous = static_cast<uint64_t>(x);
+  }
+  return out;
+}
+
+inline std::vector<int64_t> decode_deltas(const Bytes& bytes) {
+  size_t pos = 0;
+  const uint64_t count = decode_varint(bytes, pos);
+  std::vector<int64_t> values;
+  uint64_t previous = 0;
+  for (uint64_t i = 0; i < count; ++i) {
+    previous += static_cast<uint64_t>(zigzag_decode(decode_varint(bytes, pos)));
+    values.push_back(static_cast<int64_t>(previous));
+  }
+  if (pos != bytes.size()) {
+    throw std::invalid_argument("trailing bytes");
+  }
+  return values;
+}
@@ -0,0 +1,138 @@
+#include <algorithm>
+#include <cstdint>
+#include <limits>
+#include <random>
+#include <stdexcept>
+#include <vector>
+
+#include "Encoding.h"
+#include "gtest/gtest.h"
+
+// Small numbers are far more common than big ones in practice, so draw the
+// bit length uniformly and then the bits
+uint64_t random_value(std::mt19937_64& gen) {
+  const int bits = std::uniform_int_distribution<int>(0, 64)(gen);
+  return bits == 0 ? 0 : gen() >> (64 - bits);
+}
+
+TEST(Encoding, VarintExamples) {
+  Bytes out;
+  encode_varint(0, out);
+  encode_varint(127, out);
+  encode_varint(128, out);
+  encode_varint(300, out);
+  EXPECT_EQ(out, (Bytes{0x00, 0x7f, 0x80, 0x01, 0xac, 0x02}));
+
+  size_t pos = 0;
+  EXPECT_EQ(decode_varint(out, pos), 0u);
+  EXPECT_EQ(decode_varint(out, pos), 127u);
+  EXPECT_EQ(decode_varint(out, pos), 128u);
+  EXPECT_EQ(decode_varint(out, pos), 300u);
+  EXPECT_EQ(pos, out.size());
+
+  Bytes max;
+  encode_varint(UINT64_MAX, max);
+  EXPECT_EQ(max.size(), 10u);
+  EXPECT_EQ(max.back(), 0x01);
+}
+
+TEST(Encoding, VarintErrors) {
+  size_t pos = 0;
+  EXPECT_THROW(decode_varint(Bytes{}, pos), std::invalid_argument);
+  pos = 0;
+  EXPECT_THROW(decode_varint(Bytes{0x80, 0x80}, pos), std::invalid_argument);
+
+  // Ten bytes, but the last one carries bits beyond 64
+  Bytes too_big(9, 0xff);
+  too_big.push_back(0x02);
+  pos = 0;
+  EXPECT_THROW(decode_varint(too_big, pos), std::invalid_argument);
+  Bytes too_long(10, 0x80);
+  too_long.push_back(0x00);
+  pos = 0;
+  EXPECT_THROW(decode_varint(too_long, pos), std::invalid_argument);
+}
+
+TEST(Encoding, VarintRoundTrip) {
+  std::mt19937_64 gen(164);
+  for (int trial = 0; trial < 10000; ++trial) {
+    const uint64_t x = random_value(gen);
+    Bytes out = {0xee};  // Decoding starts wherever it's told to
+    encode_varint(x, out);
+    size_t pos = 1;
+
+    ASSERT_EQ(out.size(), 1 + varint_size(x));
+    ASSERT_EQ(decode_varint(out, pos), x);
+    ASSERT_EQ(pos, out.size());
+  }
+}
+
+TEST(Encoding, Zigzag) {
+  EXPECT_EQ(zigzag_encode(0), 0u);
+  EXPECT_EQ(zigzag_encode(-1), 1u);
+  EXPECT_EQ(zigzag_encode(1), 2u);
+  EXPECT_EQ(zigzag_encode(-2), 3u);
+  EXPECT_EQ(zigzag_encode(INT64_MAX), UINT64_MAX - 1);
+  EXPECT_EQ(zigzag_encode(INT64_MIN), UINT64_MAX);
+
+  // Exhaustive near zero: interleaves the signs, and round-trips
+  for (int64_t x = -1000; x <= 1000; ++x) {
+    const uint64_t expected = x >= 0 ? 2 * x : -2 * x - 1;
+    ASSERT_EQ(zigzag_encode(x), expected);
+    ASSERT_EQ(zigzag_decode(zigzag_encode(x)), x);
+  }
+  std::mt19937_64 gen(165);
+  for (int trial = 0; trial < 10000; ++trial) {
+    const uint64_t x = gen();
+    ASSERT_EQ(zigzag_encode(zigzag_decode(x)), x);
+  }
+}
+
+TEST(Encoding, SortedRoundTrip) {
+  EXPECT_EQ(encode_sorted({}), Bytes{0});
+  EXPECT_TRUE(decode_sorted(Bytes{0}).empty());
+  EXPECT_EQ(encode_sorted({1000, 1001, 1003}),
+            (Bytes{3, 0xe8, 0x07, 1, 2}));
+  EXPECT_THROW(encode_sorted({2, 1}), std::invalid_argument);
+  EXPECT_THROW(decode_sorted(Bytes{1, 5, 0}), std::invalid_argument);
+  EXPECT_THROW(decode_sorted(Bytes{2, 5}), std::invalid_argument);
+
+  std::mt19937_64 gen(166);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<uint64_t> values(gen() % 200);
+    for (auto& v : values) {
+      v = random_value(gen);
+    }
+    std::sort(values.begin(), values.end());
+
+    ASSERT_EQ(decode_sorted(encode_sorted(values)), values);
+  }
+
+  // A thousand close neighbors up in the billions take about a byte each
+  std::vector<uint64_t> dense;
+  for (uint64_t x = 5000000000; dense.size() < 1000; x += gen() % 100) {
+    dense.push_back(x);
+  }
+  EXPECT_LT(encode_sorted(dense).size(), 1010u);
+}
+
+TEST(Encoding, DeltasRoundTrip) {
+  const std::vector<int64_t> extremes = {INT64_MIN, INT64_MAX, 0, INT64_MIN};
+  EXPECT_EQ(decode_deltas(encode_deltas(extremes)), extremes);
+  EXPECT_EQ(encode_deltas({5, 3, 4}), (Bytes{3, 10, 3, 2}));
+
+  std::mt19937_64 gen(167);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<int64_t> values(gen() % 200);
+    for (auto& v : values) {
+      v = zigzag_decode(random_value(gen));
+    }
+
+    ASSERT_EQ(decode_deltas(encode_deltas(values)), values);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,67 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../patience/Patience.h"
+
+// Russian doll envelopes: envelope a fits inside b if it's strictly narrower
+// and strictly shorter. Find the longest chain of envelopes, each fitting in
+// the next.
+//
+// That's a longest increasing subsequence in two dimensions, and sorting
+// takes care of one of them. Sort by width, so that any chain appears in
+// order, and look for an increasing subsequence of heights. The catch is
+// equal widths: two envelopes of the same width never nest, so sort those by
+// height in decreasing order, which keeps them out of each other's increasing
+// subsequences. O(n log n), all of it the sort and the patience LIS.
+
+struct Envelope {
+  int width;
+  int height;
+
+  bool operator==(const Envelope&) const = default;
+};
+
+inline bool fits_inside(const Envelope& a, const Envelope& b) {
+  return a.width < b.width && a.height < b.height;
+}
+
+// The longest chain, smallest envelope first
+inline std::vector<Envelope> longest_nesting(std::vector<Envelope> envelopes) {
+  std::sort(envelopes.begin(), envelopes.end(),
+            [](const Envelope& a, const Envelope& b) {
+              return a.width != b.width ? a.width < b.width
+                                        : a.height > b.height;
+            });
+  return longest_increasing_subsequence(
+      envelopes, [](const Envelope& a, const Envelope& b) {
+        return a.height < b.height;
+      });
+}
+
+inline size_t max_nesting(std::vector<Envelope> envelopes) {
+  return longest_nesting(std::move(envelopes)).size();
+}
+
+// O(n^2) for the tests: the longest chain ending in each envelope, in order
+// of area, so every envelope that fits inside another comes first
+inline size_t max_nesting_naive(std::vector<Envelope> envelopes) {
+  std::sort(envelopes.begin(), envelopes.end(),
+            [](const Envelope& a, const Envelope& b) {
+              return a.width * a.height < b.width * b.height;
+            });
+  std::vector<size_t> longest(envelopes.size(), 1);
+  size_t best = 0;
+  for (size_t i = 0; i < envelopes.size(); ++i) {
+    for (size_t j = 0; j < i; ++j) {
+      if (fits_inside(envelopes[j], envelopes[i])) {
+        longest[i] = std::max(longest[i], longest[j] + 1);
+      }
+    }
+    best = std::max(best, longest[i]);
+  }
+  return best;
+}
@@ -0,0 +1,52 @@
+#include <random>
+#include <vector>
+
+#include "Envelopes.h"
+#include "gtest/gtest.h"
+
+bool is_chain(const std::vector<Envelope>& chain) {
+  for (size_t i = 1; i < chain.size(); ++i) {
+    if (!fits_inside(chain[i - 1], chain[i])) {
+      return false;
+    }
+  }
+  return true;
+}
+
+TEST(Envelopes, Example) {
+  std::vector<Envelope> envelopes = {{5, 4}, {6, 4}, {6, 7}, {2, 3}};
+
+  EXPECT_EQ(max_nesting(envelopes), 3u);
+  EXPECT_EQ(longest_nesting(envelopes),
+            (std::vector<Envelope>{{2, 3}, {5, 4}, {6, 7}}));
+}
+
+TEST(Envelopes, EqualSidesNeverNest) {
+  EXPECT_EQ(max_nesting({}), 0u);
+  EXPECT_EQ(max_nesting({{1, 1}, {1, 1}, {1, 1}}), 1u);
+  // Same width, growing heights: sorting heights downwards keeps them apart
+  EXPECT_EQ(max_nesting({{3, 1}, {3, 2}, {3, 3}, {3, 4}}), 1u);
+  EXPECT_EQ(max_nesting({{1, 5}, {2, 5}, {3, 5}}), 1u);
+  EXPECT_EQ(max_nesting({{1, 1}, {2, 2}, {2, 3}, {3, 3}}), 3u);
+}
+
+TEST(Envelopes, MatchesQuadratic) {
+  std::mt19937 gen(141);
+  std::uniform_int_distribution<int> side(1, 12);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<Envelope> envelopes(
+        std::uniform_int_distribution<int>(0, 40)(gen));
+    for (auto& e : envelopes) {
+      e = {side(gen), side(gen)};
+    }
+    auto chain = longest_nesting(envelopes);
+
+    ASSERT_EQ(chain.size(), max_nesting_naive(envelopes));
+    ASSERT_TRUE(is_chain(chain));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
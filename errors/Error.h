@@ -0,0 +1,104 @@
+#pragma once
+
+#include <cassert>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <variant>
+
+// One error type for the etudes, for callers that would rather check a
+// result than catch an exception or trip an assert. A function that can
+// fail on bad input comes as try_name, returning a Result, next to name,
+// which returns the value or throws. The thrown exceptions are the
+// standard ones the etudes have always thrown, picked by the error's kind,
+// so code catching std::invalid_argument keeps working.
+
+enum class ErrorKind {
+  kInvalidInput,   // std::invalid_argument
+  kOverflow,       // std::overflow_error: the answer doesn't fit its type
+  kNotFound,       // std::out_of_range
+  kOutOfRange,     // std::out_of_range: an index or position past the end
+  kCycleDetected,  // std::invalid_argument: the graph had to be acyclic
+};
+
+inline std::string to_string(ErrorKind kind) {
+  switch (kind) {
+    case ErrorKind::kInvalidInput:
+      return "invalid input";
+    case ErrorKind::kOverflow:
+      return "overflow";
+    case ErrorKind::kNotFound:
+      return "not found";
+    case ErrorKind::kOutOfRange:
+      return "out of range";
+    case ErrorKind::kCycleDetected:
+      return "cycle detected";
+  }
+  return "unknown error";
+}
+
+struct Error {
+  ErrorKind kind;
+  std::string message;
+
+  bool operator==(const Error&) const = default;
+};
+
+[[noreturn]] inline void throw_error(const Error& error) {
+  switch (error.kind) {
+    case ErrorKind::kOverflow:
+      throw std::overflow_error(error.message);
+    case ErrorKind::kNotFound:
+    case ErrorKind::kOutOfRange:
+      throw std::out_of_range(error.message);
+    case ErrorKind::kInvalidInput:
+    case ErrorKind::kCycleDetected:
+      break;
+  }
+  throw std::invalid_argument(error.message);
+}
+
+// A T, or the Error that kept the function from making one. Both convert
+// implicitly, so a function returning Result<T> can return either.
+template <typename T>
+class Result {
+ public:
+  Result(T value) : state_(std::move(value)) {}
+  Result(Error error) : state_(std::move(error)) {}
+
+  bool ok() const { return state_.index() == 0; }
+  explicit operator bool() const { return ok(); }
+
+  const T& value() const& {
+    assert(ok() && "no value in an error result");
+    return std::get<0>(state_);
+  }
+  T&& value() && {
+    assert(ok() && "no value in an error result");
+    return std::get<0>(std::move(state_));
+  }
+
+  const Error& error() const {
+    assert(!ok() && "no error in a value result");
+    return std::get<1>(state_);
+  }
+
+  // The value, or throws the error as a standard exception
+  T or_throw() const& {
+    if (!ok()) {
+      throw_error(error());
+    }
+    return std::get<0>(state_);
+  }
+  T or_throw() && {
+    if (!ok()) {
+      throw_error(error());
+    }
+    return std::get<0>(std::move(state_));
+  }
+
+  bool operator==(const Result&) const = default;
+
+ private:
+  std::variant<T, Error> state_;
+};
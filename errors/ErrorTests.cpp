@@ -0,0 +1,58 @@
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "Error.h"
+#include "gtest/gtest.h"
+
+static Result<int> parse_digit(char c) {
+  if (c < '0' || c > '9') {
+    return Error{ErrorKind::kInvalidInput, std::string("not a digit: ") + c};
+  }
+  return c - '0';
+}
+
+TEST(Result, ValueOrError) {
+  const auto seven = parse_digit('7');
+  ASSERT_TRUE(seven.ok());
+  EXPECT_TRUE(static_cast<bool>(seven));
+  EXPECT_EQ(seven.value(), 7);
+  EXPECT_EQ(seven.or_throw(), 7);
+  EXPECT_EQ(seven, Result<int>(7));
+
+  const auto x = parse_digit('x');
+  ASSERT_FALSE(x.ok());
+  EXPECT_EQ(x.error(), (Error{ErrorKind::kInvalidInput, "not a digit: x"}));
+  EXPECT_NE(x, seven);
+}
+
+TEST(Result, MoveOnlyValues) {
+  Result<std::unique_ptr<int>> result = std::make_unique<int>(3);
+  EXPECT_EQ(*std::move(result).or_throw(), 3);
+  Result<std::vector<int>> values = std::vector<int>{1, 2};
+  EXPECT_EQ(std::move(values).value(), (std::vector<int>{1, 2}));
+}
+
+TEST(Result, ThrowsTheStandardException) {
+  auto thrown = [](ErrorKind kind) {
+    return Result<int>(Error{kind, to_string(kind)});
+  };
+  EXPECT_THROW(thrown(ErrorKind::kInvalidInput).or_throw(),
+               std::invalid_argument);
+  EXPECT_THROW(thrown(ErrorKind::kOverflow).or_throw(), std::overflow_error);
+  EXPECT_THROW(thrown(ErrorKind::kNotFound).or_throw(), std::out_of_range);
+  EXPECT_THROW(thrown(ErrorKind::kOutOfRange).or_throw(), std::out_of_range);
+  EXPECT_THROW(thrown(ErrorKind::kCycleDetected).or_throw(),
+               std::invalid_argument);
+  try {
+    thrown(ErrorKind::kCycleDetected).or_throw();
+  } catch (const std::invalid_argument& e) {
+    EXPECT_EQ(std::string(e.what()), "cycle detected");
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,119 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../fenwick-tree/FenwickTree.h"
+#include "../graph/Graph.h"
+#include "../graph/Tree.h"
+
+// The Euler tour technique: number the vertices of a rooted tree in the
+// order a depth-first search enters them. A subtree is entered all at once,
+// so the vertices of v's subtree get the consecutive numbers [enter(v),
+// exit(v)), and a question about a subtree becomes a question about a range
+// of an array, which a Fenwick tree answers in O(log n).
+class EulerTour {
+ public:
+  EulerTour(const AdjacencyList& tree, int root)
+      : EulerTour(tree, RootedTree(tree, root)) {}
+
+  // Iterative, so that path-like trees don't overflow the call stack. Each
+  // frame is a vertex and the index of the next neighbor to visit.
+  EulerTour(const AdjacencyList& tree, const RootedTree& rooted)
+      : enter_(rooted.size()), exit_(rooted.size()) {
+    order_.reserve(rooted.size());
+    std::vector<std::pair<int, size_t>> stack = {{rooted.root, 0}};
+    enter_[rooted.root] = 0;
+    order_.push_back(rooted.root);
+    while (!stack.empty()) {
+      auto& [u, next] = stack.back();
+      if (next == tree[u].size()) {
+        exit_[u] = order_.size();
+        stack.pop_back();
+        continue;
+      }
+      const int v = tree[u][next++];
+      if (v != rooted.parent[u]) {
+        enter_[v] = order_.size();
+        order_.push_back(v);
+        stack.emplace_back(v, 0);
+      }
+    }
+  }
+
+  size_t size() const { return order_.size(); }
+
+  size_t enter(int v) const { return enter_[v]; }
+  size_t exit(int v) const { return exit_[v]; }
+
+  // The vertices in the order they were entered: order()[enter(v)] is v
+  const std::vector<int>& order() const { return order_; }
+
+  // u's subtree holds v exactly when v's range nests inside u's
+  bool is_ancestor(int u, int v) const {
+    return enter_[u] <= enter_[v] && exit_[v] <= exit_[u];
+  }
+
+ private:
+  std::vector<size_t> enter_;
+  std::vector<size_t> exit_;
+  std::vector<int> order_;
+};
+
+// A value on every vertex, with point updates, the sum over any subtree,
+// and the sum along the path from the root to any vertex, all O(log n).
+//
+// Subtree sums keep the values at their entry numbers, so a subtree is a
+// range sum. Root path sums keep a second tree where a vertex's value is
+// added at its entry and taken back at its exit: a prefix up to enter(v)
+// then counts exactly the vertices whose ranges contain v's entry, which
+// are v's ancestors and v.
+template <typename T>
+class SubtreeSums {
+ public:
+  SubtreeSums(const AdjacencyList& tree, int root, const std::vector<T>& values)
+      : tour_(tree, root),
+        values_(values),
+        subtree_(tour_.size()),
+        root_path_(tour_.size() + 1) {
+    assert(values.size() == tour_.size() && "one value per vertex");
+    for (size_t v = 0; v < values.size(); ++v) {
+      place(static_cast<int>(v), values[v]);
+    }
+  }
+
+  const T& get(int v) const { return values_[v]; }
+
+  void add(int v, const T& delta) {
+    values_[v] = values_[v] + delta;
+    place(v, delta);
+  }
+
+  void set(int v, const T& value) { add(v, value - values_[v]); }
+
+  T subtree_sum(int v) const {
+    return subtree_.sum(tour_.enter(v), tour_.exit(v));
+  }
+
+  // The values of v and all its ancestors
+  T root_path_sum(int v) const {
+    return root_path_.prefix_sum(tour_.enter(v) + 1);
+  }
+
+  const EulerTour& tour() const { return tour_; }
+
+ private:
+  void place(int v, const T& delta) {
+    subtree_.add(tour_.enter(v), delta);
+    root_path_.add(tour_.enter(v), delta);
+    root_path_.add(tour_.exit(v), T{} - delta);
+  }
+
+  EulerTour tour_;
+  std::vector<T> values_;
+  FenwickTree<T> subtree_;
+  // One longer, for the exits of subtrees that run to the end
+  FenwickTree<T> root_path_;
+};
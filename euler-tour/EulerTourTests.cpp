@@ -0,0 +1,94 @@
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../graph/ExampleTree.h"
+#include "../rng/Rng.h"
+#include "EulerTour.h"
+#include "gtest/gtest.h"
+
+TEST(EulerTour, Example) {
+  const EulerTour tour(example_tree(), 0);
+  EXPECT_EQ(tour.order(), (std::vector<int>{0, 1, 4, 5, 7, 8, 2, 3, 6}));
+  EXPECT_EQ(tour.enter(5), 3u);
+  EXPECT_EQ(tour.exit(5), 6u);
+  EXPECT_EQ(tour.enter(0), 0u);
+  EXPECT_EQ(tour.exit(0), 9u);
+  EXPECT_TRUE(tour.is_ancestor(1, 8));
+  EXPECT_TRUE(tour.is_ancestor(6, 6));
+  EXPECT_FALSE(tour.is_ancestor(8, 1));
+  EXPECT_FALSE(tour.is_ancestor(2, 6));
+
+  SubtreeSums<int> sums(example_tree(), 0, {1, 2, 3, 4, 5, 6, 7, 8, 9});
+  EXPECT_EQ(sums.subtree_sum(0), 45);
+  EXPECT_EQ(sums.subtree_sum(1), 2 + 5 + 6 + 8 + 9);
+  EXPECT_EQ(sums.subtree_sum(6), 7);
+  EXPECT_EQ(sums.root_path_sum(8), 1 + 2 + 6 + 9);
+  sums.add(5, 10);
+  sums.set(0, 0);
+  EXPECT_EQ(sums.get(5), 16);
+  EXPECT_EQ(sums.subtree_sum(1), 2 + 5 + 16 + 8 + 9);
+  EXPECT_EQ(sums.root_path_sum(8), 2 + 16 + 9);
+  EXPECT_EQ(sums.root_path_sum(6), 4 + 7);
+}
+
+TEST(EulerTour, LongPath) {
+  // Deep enough to overflow a recursive DFS
+  const int n = 200000;
+  std::vector<Edge> edges;
+  for (int i = 1; i < n; ++i) {
+    edges.emplace_back(i - 1, i);
+  }
+  SubtreeSums<int64_t> sums(undirected_from_edges(n, edges), 0,
+                            std::vector<int64_t>(n, 1));
+  EXPECT_EQ(sums.subtree_sum(n / 2), n - n / 2);
+  EXPECT_EQ(sums.root_path_sum(n - 1), n);
+}
+
+TEST(EulerTour, MatchesNaive) {
+  Rng gen(214);
+  for (int trial = 0; trial < 100; ++trial) {
+    const size_t n = 1 + uniform_below(gen, 200);
+    const auto tree = random_labeled_tree(static_cast<int>(n), gen);
+    const int root = static_cast<int>(uniform_below(gen, n));
+    const RootedTree rooted(tree, root);
+    std::vector<int64_t> values(n);
+    for (auto& v : values) {
+      v = uniform_int<int64_t>(gen, -100, 100);
+    }
+    SubtreeSums<int64_t> sums(tree, root, values);
+
+    for (int step = 0; step < 100; ++step) {
+      const int v = static_cast<int>(uniform_below(gen, n));
+      if (bernoulli(gen, 0.5)) {
+        const int64_t delta = uniform_int<int64_t>(gen, -100, 100);
+        values[v] += delta;
+        sums.add(v, delta);
+      }
+      // u is in v's subtree when climbing from u reaches v
+      int64_t subtree = 0;
+      for (size_t u = 0; u < n; ++u) {
+        int x = static_cast<int>(u);
+        while (x != -1 && x != v) {
+          x = rooted.parent[x];
+        }
+        if (x == v) {
+          subtree += values[u];
+        }
+        ASSERT_EQ(sums.tour().is_ancestor(v, static_cast<int>(u)), x == v);
+      }
+      int64_t path = 0;
+      for (int x = v; x != -1; x = rooted.parent[x]) {
+        path += values[x];
+      }
+      ASSERT_EQ(sums.subtree_sum(v), subtree);
+      ASSERT_EQ(sums.root_path_sum(v), path);
+    }
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,53 @@
+#include <iostream>
+#include <stdexcept>
+#include <string>
+
+#include "Expr.h"
+
+// Command-line calculator. Evaluates each argument as an expression, or each
+// line of standard input when there are no arguments.
+//
+//   $ ./calc "1 + 2 * 3" "2 ^ 10"
+//   7
+//   1024
+//
+// With --tree, prints the fully parenthesized expression tree instead of its
+// value. Exits with status 1 if any expression fails to evaluate.
+
+bool run(const std::string& input, bool show_tree) {
+  try {
+    if (show_tree) {
+      std::cout << to_infix(*parse(input)) << '\n';
+    } else {
+      std::cout << format_number(evaluate(input)) << '\n';
+    }
+    return true;
+  } catch (const std::exception& e) {
+    std::cerr << "error: " << input << ": " << e.what() << '\n';
+    return false;
+  }
+}
+
+int main(int argc, char* argv[]) {
+  bool show_tree = false;
+  int first = 1;
+  if (argc > 1 && std::string(argv[1]) == "--tree") {
+    show_tree = true;
+    first = 2;
+  }
+
+  bool ok = true;
+  if (first < argc) {
+    for (int i = first; i < argc; ++i) {
+      ok = run(argv[i], show_tree) && ok;
+    }
+  } else {
+    std::string line;
+    while (std::getline(std::cin, line)) {
+      if (line.find_first_not_of(" \t\r") != std::string::npos) {
+        ok = run(line, show_tree) && ok;
+      }
+    }
+  }
+  return ok ? 0 : 1;
+}
@@ -0,0 +1,295 @@
+#pragma once
+
+#include <cctype>
+#include <cmath>
+#include <cstddef>
+#include <iomanip>
+#include <memory>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../binary-tree/TreeNode.h"
+
+// Arithmetic expressions: tokenize, convert infix to postfix with Dijkstra's
+// shunting-yard algorithm, then evaluate the postfix form directly or build
+// an expression tree from it.
+//
+// Grammar: numbers (e.g. 3, 2.5, .5), the binary operators + - * / ^, unary
+// minus, and parentheses. ^ is right associative and binds tighter than
+// unary minus, so -2^2 is -4 and 2^3^2 is 2^9.
+//
+// Malformed input throws std::invalid_argument; division by zero throws
+// std::domain_error.
+
+struct Token {
+  enum class Kind { Number, Operator, LeftParen, RightParen };
+
+  Kind kind;
+  double value{0.0};  // Only for numbers
+  char op{0};         // Only for operators. Unary minus is '~'.
+
+  static Token number(double v) { return {Kind::Number, v, 0}; }
+  static Token oper(char c) { return {Kind::Operator, 0.0, c}; }
+  static Token left_paren() { return {Kind::LeftParen, 0.0, '('}; }
+  static Token right_paren() { return {Kind::RightParen, 0.0, ')'}; }
+
+  bool operator==(const Token&) const = default;
+};
+
+inline constexpr char kUnaryMinus = '~';
+
+inline int precedence(char op) {
+  switch (op) {
+    case '+':
+    case '-':
+      return 1;
+    case '*':
+    case '/':
+      return 2;
+    case kUnaryMinus:
+      return 3;
+    case '^':
+      return 4;
+  }
+  throw std::invalid_argument(std::string("unknown operator: ") + op);
+}
+
+inline bool is_right_associative(char op) {
+  return op == '^' || op == kUnaryMinus;
+}
+
+inline bool is_unary(char op) { return op == kUnaryMinus; }
+
+inline std::vector<Token> tokenize(const std::string& input) {
+  std::vector<Token> tokens;
+  size_t i = 0;
+  while (i < input.size()) {
+    const char c = input[i];
+    if (std::isspace(static_cast<unsigned char>(c))) {
+      ++i;
+    } else if (std::isdigit(static_cast<unsigned char>(c)) || c == '.') {
+      size_t consumed = 0;
+      double value = 0.0;
+      try {
+        value = std::stod(input.substr(i), &consumed);
+      } catch (const std::out_of_range&) {
+        throw std::invalid_argument("number out of range at position " +
+                                    std::to_string(i));
+      }
+      tokens.push_back(Token::number(value));
+      i += consumed;
+    } else if (c == '(') {
+      tokens.push_back(Token::left_paren());
+      ++i;
+    } else if (c == ')') {
+      tokens.push_back(Token::right_paren());
+      ++i;
+    } else if (c == '+' || c == '-' || c == '*' || c == '/' || c == '^') {
+      // A minus is unary when it can't be the end of a left operand: at the
+      // start, after an operator, or after an opening parenthesis.
+      const bool unary =
+          c == '-' && (tokens.empty() ||
+                       tokens.back().kind == Token::Kind::Operator ||
+                       tokens.back().kind == Token::Kind::LeftParen);
+      tokens.push_back(Token::oper(unary ? kUnaryMinus : c));
+      ++i;
+    } else {
+      throw std::invalid_argument("unexpected character '" +
+                                  std::string(1, c) + "' at position " +
+                                  std::to_string(i));
+    }
+  }
+  return tokens;
+}
+
+// Shunting-yard: numbers go straight to the output, operators wait on a
+// stack until an operator of lower precedence (or a closing parenthesis)
+// forces them out.
+//
+// Operands and binary operators must alternate, which shunting-yard alone
+// doesn't check: "2 3 +" would come out as valid postfix. expect_operand
+// tracks which of the two may come next.
+inline std::vector<Token> to_postfix(const std::vector<Token>& tokens) {
+  std::vector<Token> output;
+  std::vector<Token> stack;
+  bool expect_operand = true;
+  for (const auto& token : tokens) {
+    switch (token.kind) {
+      case Token::Kind::Number:
+        if (!expect_operand) {
+          throw std::invalid_argument("two operands in a row");
+        }
+        expect_operand = false;
+        output.push_back(token);
+        break;
+      case Token::Kind::Operator:
+        if (expect_operand != is_unary(token.op)) {
+          throw std::invalid_argument(
+              expect_operand ? std::string("missing left operand for '") +
+                                   token.op + "'"
+                             : std::string("unary minus after an operand"));
+        }
+        expect_operand = true;
+        // Pop operators that must be applied before this one. A left
+        // associative operator also pops its equals (a - b - c is
+        // (a - b) - c); a right associative one doesn't. Unary operators
+        // have no left operand yet, so nothing can be applied before them.
+        while (!is_unary(token.op) && !stack.empty() &&
+               stack.back().kind == Token::Kind::Operator &&
+               (precedence(stack.back().op) > precedence(token.op) ||
+                (precedence(stack.back().op) == precedence(token.op) &&
+                 !is_right_associative(token.op)))) {
+          output.push_back(stack.back());
+          stack.pop_back();
+        }
+        stack.push_back(token);
+        break;
+      case Token::Kind::LeftParen:
+        if (!expect_operand) {
+          throw std::invalid_argument("two operands in a row");
+        }
+        stack.push_back(token);
+        break;
+      case Token::Kind::RightParen:
+        if (expect_operand) {
+          throw std::invalid_argument("missing operand before ')'");
+        }
+        while (!stack.empty() && stack.back().kind != Token::Kind::LeftParen) {
+          output.push_back(stack.back());
+          stack.pop_back();
+        }
+        if (stack.empty()) {
+          throw std::invalid_argument("mismatched ')'");
+        }
+        stack.pop_back();  // Discard the '('
+        break;
+    }
+  }
+  if (expect_operand && !tokens.empty()) {
+    throw std::invalid_argument("missing operand at end of input");
+  }
+  while (!stack.empty()) {
+    if (stack.back().kind == Token::Kind::LeftParen) {
+      throw std::invalid_argument("mismatched '('");
+    }
+    output.push_back(stack.back());
+    stack.pop_back();
+  }
+  return output;
+}
+
+inline double apply(char op, double lhs, double rhs) {
+  switch (op) {
+    case '+':
+      return lhs + rhs;
+    case '-':
+      return lhs - rhs;
+    case '*':
+      return lhs * rhs;
+    case '/':
+      if (rhs == 0.0) {
+        throw std::domain_error("division by zero");
+      }
+      return lhs / rhs;
+    case '^':
+      return std::pow(lhs, rhs);
+  }
+  throw std::invalid_argument(std::string("unknown operator: ") + op);
+}
+
+inline double evaluate_postfix(const std::vector<Token>& postfix) {
+  std::vector<double> stack;
+  for (const auto& token : postfix) {
+    if (token.kind == Token::Kind::Number) {
+      stack.push_back(token.value);
+      continue;
+    }
+    if (is_unary(token.op)) {
+      if (stack.empty()) {
+        throw std::invalid_argument("missing operand for unary minus");
+      }
+      stack.back() = -stack.back();
+      continue;
+    }
+    if (stack.size() < 2) {
+      throw std::invalid_argument(std::string("missing operand for '") +
+                                  token.op + "'");
+    }
+    double rhs = stack.back();
+    stack.pop_back();
+    stack.back() = apply(token.op, stack.back(), rhs);
+  }
+  if (stack.size() != 1) {
+    throw std::invalid_argument("expected exactly one value");
+  }
+  return stack.back();
+}
+
+inline double evaluate(const std::string& input) {
+  return evaluate_postfix(to_postfix(tokenize(input)));
+}
+
+// Builds the expression tree for a postfix sequence: numbers are leaves,
+// operators are internal nodes. Unary minus keeps its operand on the left.
+inline std::unique_ptr<TreeNode<Token>> build_expression_tree(
+    const std::vector<Token>& postfix) {
+  std::vector<std::unique_ptr<TreeNode<Token>>> stack;
+  for (const auto& token : postfix) {
+    if (token.kind == Token::Kind::Number) {
+      stack.push_back(make_node(token));
+      continue;
+    }
+    const size_t arity = is_unary(token.op) ? 1 : 2;
+    if (stack.size() < arity) {
+      throw std::invalid_argument(std::string("missing operand for '") +
+                                  token.op + "'");
+    }
+    std::unique_ptr<TreeNode<Token>> rhs;
+    if (arity == 2) {
+      rhs = std::move(stack.back());
+      stack.pop_back();
+    }
+    auto lhs = std::move(stack.back());
+    stack.pop_back();
+    stack.push_back(make_node(token, std::move(lhs), std::move(rhs)));
+  }
+  if (stack.size() != 1) {
+    throw std::invalid_argument("expected exactly one value");
+  }
+  return std::move(stack.back());
+}
+
+inline std::unique_ptr<TreeNode<Token>> parse(const std::string& input) {
+  return build_expression_tree(to_postfix(tokenize(input)));
+}
+
+inline double evaluate_tree(const TreeNode<Token>& node) {
+  if (node.val.kind == Token::Kind::Number) {
+    return node.val.value;
+  }
+  if (is_unary(node.val.op)) {
+    return -evaluate_tree(*node.left);
+  }
+  return apply(node.val.op, evaluate_tree(*node.left),
+               evaluate_tree(*node.right));
+}
+
+inline std::string format_number(double value) {
+  std::ostringstream out;
+  out << std::setprecision(15) << value;
+  return out.str();
+}
+
+// Fully parenthesized infix form of the tree, e.g. ((1 + 2) * 3)
+inline std::string to_infix(const TreeNode<Token>& node) {
+  if (node.val.kind == Token::Kind::Number) {
+    return format_number(node.val.value);
+  }
+  if (is_unary(node.val.op)) {
+    return "(-" + to_infix(*node.left) + ")";
+  }
+  return "(" + to_infix(*node.left) + " " + node.val.op + " " +
+         to_infix(*node.right) + ")";
+}
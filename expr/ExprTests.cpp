@@ -0,0 +1,166 @@
+#include <random>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "Expr.h"
+#include "gtest/gtest.h"
+
+std::string postfix_string(const std::string& input) {
+  std::string result;
+  for (const auto& token : to_postfix(tokenize(input))) {
+    if (!result.empty()) {
+      result += ' ';
+    }
+    if (token.kind == Token::Kind::Number) {
+      result += format_number(token.value);
+    } else {
+      result += token.op;
+    }
+  }
+  return result;
+}
+
+TEST(Expr, Tokenize) {
+  std::vector<Token> expected = {
+      Token::left_paren(), Token::number(1.5), Token::oper('+'),
+      Token::number(2),    Token::right_paren(), Token::oper('*'),
+      Token::oper(kUnaryMinus), Token::number(0.25),
+  };
+
+  EXPECT_EQ(tokenize("(1.5 + 2) * -.25"), expected);
+  EXPECT_TRUE(tokenize("   ").empty());
+}
+
+TEST(Expr, TokenizeDistinguishesUnaryMinus) {
+  EXPECT_EQ(tokenize("1-2")[1], Token::oper('-'));
+  EXPECT_EQ(tokenize("-2")[0], Token::oper(kUnaryMinus));
+  EXPECT_EQ(tokenize("1--2")[2], Token::oper(kUnaryMinus));
+  EXPECT_EQ(tokenize("(-2)")[1], Token::oper(kUnaryMinus));
+  EXPECT_EQ(tokenize("(1)-2")[3], Token::oper('-'));
+}
+
+TEST(Expr, ToPostfix) {
+  EXPECT_EQ(postfix_string("1 + 2 * 3"), "1 2 3 * +");
+  EXPECT_EQ(postfix_string("(1 + 2) * 3"), "1 2 + 3 *");
+  EXPECT_EQ(postfix_string("1 - 2 - 3"), "1 2 - 3 -");
+  EXPECT_EQ(postfix_string("2 ^ 3 ^ 2"), "2 3 2 ^ ^");
+  EXPECT_EQ(postfix_string("-2 ^ 2"), "2 2 ^ ~");
+  EXPECT_EQ(postfix_string("3 + 4 * 2 / (1 - 5) ^ 2 ^ 3"),
+            "3 4 2 * 1 5 - 2 3 ^ ^ / +");
+}
+
+TEST(Expr, Evaluate) {
+  EXPECT_DOUBLE_EQ(evaluate("1 + 2 * 3"), 7);
+  EXPECT_DOUBLE_EQ(evaluate("(1 + 2) * 3"), 9);
+  EXPECT_DOUBLE_EQ(evaluate("7 / 2"), 3.5);
+  EXPECT_DOUBLE_EQ(evaluate("((((42))))"), 42);
+}
+
+TEST(Expr, PrecedenceAndAssociativity) {
+  EXPECT_DOUBLE_EQ(evaluate("1 - 2 - 3"), -4);
+  EXPECT_DOUBLE_EQ(evaluate("8 / 4 / 2"), 1);
+  EXPECT_DOUBLE_EQ(evaluate("2 ^ 3 ^ 2"), 512);
+  EXPECT_DOUBLE_EQ(evaluate("-2 ^ 2"), -4);
+  EXPECT_DOUBLE_EQ(evaluate("(-2) ^ 2"), 4);
+  EXPECT_DOUBLE_EQ(evaluate("2 ^ -1"), 0.5);
+  EXPECT_DOUBLE_EQ(evaluate("-3 * 2"), -6);
+  EXPECT_DOUBLE_EQ(evaluate("2 * -3"), -6);
+  EXPECT_DOUBLE_EQ(evaluate("--2"), 2);
+  EXPECT_DOUBLE_EQ(evaluate("1 - -1"), 2);
+}
+
+TEST(Expr, MalformedInputThrows) {
+  EXPECT_THROW(evaluate(""), std::invalid_argument);
+  EXPECT_THROW(evaluate("1 +"), std::invalid_argument);
+  EXPECT_THROW(evaluate("* 2"), std::invalid_argument);
+  EXPECT_THROW(evaluate("1 2"), std::invalid_argument);
+  EXPECT_THROW(evaluate("(1 + 2"), std::invalid_argument);
+  EXPECT_THROW(evaluate("1 + 2)"), std::invalid_argument);
+  EXPECT_THROW(evaluate("2 x 3"), std::invalid_argument);
+  EXPECT_THROW(parse("1 +"), std::invalid_argument);
+}
+
+TEST(Expr, OperandsAndOperatorsMustAlternate) {
+  EXPECT_THROW(evaluate("2 3 +"), std::invalid_argument);
+  EXPECT_THROW(evaluate("+ 2 3"), std::invalid_argument);
+  EXPECT_THROW(evaluate("3 (-)"), std::invalid_argument);
+  EXPECT_THROW(evaluate("(1)(2)+"), std::invalid_argument);
+  EXPECT_THROW(evaluate("()"), std::invalid_argument);
+  EXPECT_THROW(evaluate("1 + ()"), std::invalid_argument);
+  EXPECT_THROW(parse("2 3 +"), std::invalid_argument);
+  EXPECT_THROW(to_postfix({Token::number(1), Token::oper(kUnaryMinus)}),
+               std::invalid_argument);
+}
+
+TEST(Expr, OutOfRangeNumberThrows) {
+  EXPECT_THROW(tokenize("1e999"), std::invalid_argument);
+  EXPECT_THROW(evaluate("1e999 + 1"), std::invalid_argument);
+}
+
+TEST(Expr, DivisionByZeroThrows) {
+  EXPECT_THROW(evaluate("1 / 0"), std::domain_error);
+  EXPECT_THROW(evaluate("1 / (2 - 2)"), std::domain_error);
+  EXPECT_THROW(evaluate_tree(*parse("1 / 0")), std::domain_error);
+}
+
+TEST(Expr, ExpressionTree) {
+  auto tree = parse("(1 + 2) * 3");
+
+  ASSERT_NE(tree, nullptr);
+  EXPECT_EQ(tree->val, Token::oper('*'));
+  EXPECT_EQ(tree->left->val, Token::oper('+'));
+  EXPECT_EQ(tree->left->left->val, Token::number(1));
+  EXPECT_EQ(tree->left->right->val, Token::number(2));
+  EXPECT_EQ(tree->right->val, Token::number(3));
+  EXPECT_DOUBLE_EQ(evaluate_tree(*tree), 9);
+}
+
+TEST(Expr, UnaryMinusHasOneChild) {
+  auto tree = parse("-5");
+
+  EXPECT_EQ(tree->val, Token::oper(kUnaryMinus));
+  EXPECT_EQ(tree->left->val, Token::number(5));
+  EXPECT_EQ(tree->right, nullptr);
+}
+
+TEST(Expr, ToInfix) {
+  EXPECT_EQ(to_infix(*parse("1 + 2 * 3")), "(1 + (2 * 3))");
+  EXPECT_EQ(to_infix(*parse("2 ^ 3 ^ 2")), "(2 ^ (3 ^ 2))");
+  EXPECT_EQ(to_infix(*parse("-2.5 ^ 2")), "(-(2.5 ^ 2))");
+  EXPECT_EQ(to_infix(*parse("7")), "7");
+}
+
+TEST(Expr, TreeAgreesWithPostfixEvaluation) {
+  // Random well-formed expressions; the fully parenthesized infix form must
+  // parse back to the same value.
+  std::mt19937 gen(127);
+  std::uniform_int_distribution<int> digit(1, 9);
+  std::uniform_int_distribution<int> choice(0, 5);
+  const std::string ops = "+-*";
+
+  for (int trial = 0; trial < 200; ++trial) {
+    std::string input = std::to_string(digit(gen));
+    int terms = std::uniform_int_distribution<int>(1, 8)(gen);
+    for (int i = 0; i < terms; ++i) {
+      input += ' ';
+      input += ops[choice(gen) % ops.size()];
+      input += choice(gen) == 0 ? " -" : " ";
+      input += std::to_string(digit(gen));
+      if (choice(gen) == 0) {
+        input = "(" + input + ")";
+      }
+    }
+
+    auto tree = parse(input);
+    double expected = evaluate(input);
+
+    ASSERT_DOUBLE_EQ(evaluate_tree(*tree), expected) << input;
+    ASSERT_DOUBLE_EQ(evaluate(to_infix(*tree)), expected) << input;
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,62 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <vector>
+
+// Fenwick tree (binary indexed tree): point updates and prefix sums, both
+// O(log n), in a plain array of n sums.
+//
+// With 1-based positions, tree_[i] holds the sum of the lowbit(i) values
+// ending at position i, where lowbit(i) = i & -i is i's lowest set bit. A
+// prefix sum adds up the blocks going down by clearing the lowest bit, and
+// an update fixes the blocks that cover its position going up by adding it.
+// Either way, at most one block per bit.
+template <typename T>
+class FenwickTree {
+ public:
+  explicit FenwickTree(size_t n) : tree_(n + 1, T{}) {}
+
+  // O(n): every block adds itself into the next block up that contains it
+  explicit FenwickTree(const std::vector<T>& values)
+      : tree_(values.size() + 1, T{}) {
+    for (size_t i = 1; i < tree_.size(); ++i) {
+      tree_[i] = tree_[i] + values[i - 1];
+      const size_t up = i + lowbit(i);
+      if (up < tree_.size()) {
+        tree_[up] = tree_[up] + tree_[i];
+      }
+    }
+  }
+
+  // Adds delta to the value at index i (0-based)
+  void add(size_t i, const T& delta) {
+    assert(i < size());
+    for (++i; i < tree_.size(); i += lowbit(i)) {
+      tree_[i] = tree_[i] + delta;
+    }
+  }
+
+  // Sum of the values at indices [0, end)
+  T prefix_sum(size_t end) const {
+    assert(end <= size());
+    T sum{};
+    for (; end > 0; end -= lowbit(end)) {
+      sum = sum + tree_[end];
+    }
+    return sum;
+  }
+
+  // Sum of the values at indices [begin, end)
+  T sum(size_t begin, size_t end) const {
+    assert(begin <= end);
+    return prefix_sum(end) - prefix_sum(begin);
+  }
+
+  size_t size() const { return tree_.size() - 1; }
+
+ private:
+  static size_t lowbit(size_t i) { return i & (~i + 1); }
+
+  std::vector<T> tree_;  // 1-based; tree_[0] is unused
+};
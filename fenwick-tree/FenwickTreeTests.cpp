@@ -0,0 +1,58 @@
+#include <cstdint>
+#include <random>
+#include <vector>
+
+#include "FenwickTree.h"
+#include "gtest/gtest.h"
+
+TEST(FenwickTree, Sums) {
+  FenwickTree<int> tree(std::vector<int>{5, 2, 9, -3, 5, 20, 10, -7});
+  EXPECT_EQ(tree.size(), 8u);
+  EXPECT_EQ(tree.prefix_sum(0), 0);
+  EXPECT_EQ(tree.prefix_sum(8), 41);
+  EXPECT_EQ(tree.sum(2, 5), 11);
+
+  tree.add(3, 10);  // -3 becomes 7
+  EXPECT_EQ(tree.sum(2, 5), 21);
+  EXPECT_EQ(tree.prefix_sum(3), 16);
+  EXPECT_EQ(FenwickTree<int>(0).prefix_sum(0), 0);
+}
+
+TEST(FenwickTree, MatchesArray) {
+  std::mt19937 gen(158);
+  for (size_t n : {1, 2, 7, 64, 100}) {
+    std::vector<int64_t> values(n, 0);
+    std::uniform_int_distribution<int64_t> value(-100, 100);
+    for (auto& v : values) {
+      v = value(gen);
+    }
+    FenwickTree<int64_t> built(values);
+    FenwickTree<int64_t> added(n);
+    for (size_t i = 0; i < n; ++i) {
+      added.add(i, values[i]);
+    }
+
+    std::uniform_int_distribution<size_t> index(0, n - 1);
+    for (int step = 0; step < 300; ++step) {
+      const size_t i = index(gen);
+      const int64_t delta = value(gen);
+      values[i] += delta;
+      built.add(i, delta);
+      added.add(i, delta);
+
+      int64_t prefix = 0;
+      for (size_t end = 0; end <= n; ++end) {
+        ASSERT_EQ(built.prefix_sum(end), prefix);
+        ASSERT_EQ(added.prefix_sum(end), prefix);
+        if (end < n) {
+          prefix += values[end];
+        }
+      }
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,38 @@
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "../patience/Patience.h"
+#include "../sequences/Sequences.h"
+#include "etudes.h"
+
+// C can't catch C++ exceptions, so nothing may escape: these are noexcept,
+// and the only thing they could throw is std::bad_alloc, which then ends
+// the program instead of unwinding through C frames.
+
+template <typename T>
+static std::vector<T> to_vector(const T* data, size_t n) {
+  return n == 0 ? std::vector<T>() : std::vector<T>(data, data + n);
+}
+
+extern "C" double etudes_evaluate_polynomial(const double* coefficients,
+                                             size_t n, double x) noexcept {
+  return evaluate_polynomial(to_vector(coefficients, n), x);
+}
+
+extern "C" int64_t etudes_max_subarray(const int64_t* nums, size_t n,
+                                       size_t* begin, size_t* end) noexcept {
+  const Subarray best = max_subarray(to_vector(nums, n));
+  if (begin != nullptr) {
+    *begin = best.begin;
+  }
+  if (end != nullptr) {
+    *end = best.end;
+  }
+  return best.sum;
+}
+
+extern "C" size_t etudes_lis_length(const int64_t* values,
+                                    size_t n) noexcept {
+  return lis_length(to_vector(values, n));
+}
@@ -0,0 +1,40 @@
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "etudes.h"
+#include "gtest/gtest.h"
+
+// Through the C entry points only, as a C caller would see them
+
+TEST(Ffi, EvaluatePolynomial) {
+  const double coefficients[] = {2, 3, 4, 5};
+  EXPECT_EQ(etudes_evaluate_polynomial(coefficients, 4, 2.0), 41.0);
+  EXPECT_EQ(etudes_evaluate_polynomial(nullptr, 0, 2.0), 0.0);
+}
+
+TEST(Ffi, MaxSubarray) {
+  const int64_t nums[] = {-2, 1, -3, 4, -1, 2, 1, -5, 4};
+  size_t begin = 99;
+  size_t end = 99;
+  EXPECT_EQ(etudes_max_subarray(nums, 9, &begin, &end), 6);
+  EXPECT_EQ(begin, 3u);
+  EXPECT_EQ(end, 7u);
+
+  // The run is optional
+  EXPECT_EQ(etudes_max_subarray(nums, 9, nullptr, nullptr), 6);
+  EXPECT_EQ(etudes_max_subarray(nullptr, 0, &begin, &end), 0);
+  EXPECT_EQ(begin, 0u);
+  EXPECT_EQ(end, 0u);
+}
+
+TEST(Ffi, LisLength) {
+  const int64_t values[] = {10, 9, 2, 5, 3, 7, 101, 18};
+  EXPECT_EQ(etudes_lis_length(values, 8), 4u);
+  EXPECT_EQ(etudes_lis_length(nullptr, 0), 0u);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
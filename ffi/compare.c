@@ -0,0 +1,85 @@
+/*
+ * The C entry points against plain C versions of the same algorithms, as
+ * they'd be written from Manber's book or course notes, on random inputs.
+ * Build and run with `make compare`. Prints the first disagreement, if any.
+ */
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "etudes.h"
+
+/* Kadane, straight from the induction: the best run so far, and the best
+ * suffix */
+static int64_t max_subarray_c(const int64_t* nums, size_t n) {
+  int64_t global_max = 0;
+  int64_t suffix_max = 0;
+  for (size_t i = 0; i < n; ++i) {
+    suffix_max = suffix_max + nums[i] > 0 ? suffix_max + nums[i] : 0;
+    global_max = suffix_max > global_max ? suffix_max : global_max;
+  }
+  return global_max;
+}
+
+/* The quadratic dynamic program: lis[i] is the longest run ending at i */
+static size_t lis_length_c(const int64_t* values, size_t n) {
+  size_t* lis = malloc((n + 1) * sizeof *lis);
+  size_t best = 0;
+  for (size_t i = 0; i < n; ++i) {
+    lis[i] = 1;
+    for (size_t j = 0; j < i; ++j) {
+      if (values[j] < values[i] && lis[j] + 1 > lis[i]) {
+        lis[i] = lis[j] + 1;
+      }
+    }
+    best = lis[i] > best ? lis[i] : best;
+  }
+  free(lis);
+  return best;
+}
+
+/* Sum of powers, the way it's written before learning Horner's rule */
+static double evaluate_polynomial_c(const double* coefficients, size_t n,
+                                    double x) {
+  double p = 0.0;
+  for (size_t i = 0; i < n; ++i) {
+    double power = 1.0;
+    for (size_t k = 0; k < n - 1 - i; ++k) {
+      power *= x;
+    }
+    p += coefficients[i] * power;
+  }
+  return p;
+}
+
+int main(void) {
+  enum { kTrials = 1000, kMaxLength = 64 };
+  int64_t nums[kMaxLength];
+  double coefficients[kMaxLength];
+  srand(174);
+  for (int trial = 0; trial < kTrials; ++trial) {
+    const size_t n = (size_t)(rand() % kMaxLength);
+    for (size_t i = 0; i < n; ++i) {
+      nums[i] = rand() % 201 - 100;
+      /* Small integers, so both ways of evaluating are exact */
+      coefficients[i] = rand() % 5 - 2;
+    }
+    const double x = rand() % 3 - 1;
+
+    if (etudes_max_subarray(nums, n, NULL, NULL) != max_subarray_c(nums, n)) {
+      printf("max_subarray differs on trial %d\n", trial);
+      return 1;
+    }
+    if (etudes_lis_length(nums, n) != lis_length_c(nums, n)) {
+      printf("lis_length differs on trial %d\n", trial);
+      return 1;
+    }
+    if (etudes_evaluate_polynomial(coefficients, n, x) !=
+        evaluate_polynomial_c(coefficients, n, x)) {
+      printf("evaluate_polynomial differs on trial %d\n", trial);
+      return 1;
+    }
+  }
+  printf("%d trials agree\n", kTrials);
+  return 0;
+}
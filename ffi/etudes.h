@@ -0,0 +1,43 @@
+/*
+ * C interface to the numeric etudes, for calling them from C, or from
+ * anything else that can call C. They're implemented in Ffi.cpp on top of
+ * the C++ headers; link with its object file and the C++ standard library.
+ *
+ * Arrays are passed as a pointer and a length. A length of 0 is always
+ * fine, and then the pointer may be NULL. None of the functions keep a
+ * pointer after they return.
+ */
+#ifndef ETUDES_FFI_H
+#define ETUDES_FFI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/* C++ callers know the functions never throw */
+#ifdef __cplusplus
+#define ETUDES_NOEXCEPT noexcept
+extern "C" {
+#else
+#define ETUDES_NOEXCEPT
+#endif
+
+/* P(x), for coefficients highest degree first: {2, 3, 4, 5} is
+ * 2x^3 + 3x^2 + 4x + 5. Horner's rule. */
+double etudes_evaluate_polynomial(const double* coefficients, size_t n,
+                                  double x) ETUDES_NOEXCEPT;
+
+/* The largest sum of consecutive elements, counting the empty run, so never
+ * negative. If begin and end aren't NULL, the run is stored there as
+ * nums[*begin .. *end), end exclusive. Kadane's algorithm. */
+int64_t etudes_max_subarray(const int64_t* nums, size_t n, size_t* begin,
+                            size_t* end) ETUDES_NOEXCEPT;
+
+/* The length of a longest strictly increasing subsequence. Patience
+ * sorting, O(n log n). */
+size_t etudes_lis_length(const int64_t* values, size_t n) ETUDES_NOEXCEPT;
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ETUDES_FFI_H */
@@ -1,6 +1,9 @@
 #pragma once
 
 #include <cassert>
+#include <cstdint>
+
+#include "../memo/Memo.h"
 
 uint64_t exp_fib(int n) {
   assert(n >= 0 && "n must be non-negative");
@@ -31,4 +34,19 @@ uint64_t lin_fib(int n) {
   return fib_n;
 }
 
+// The exponential recursion with its answers cached: each fib(k) is
+// computed once, and its second use is a cache hit, so O(n) instead of
+// O(phi^n). The keys are 0 through n, so the answers go in a vector. Pass
+// stats to see the hits and misses.
+uint64_t memo_fib(int n, MemoStats* stats = nullptr) {
+  assert(n >= 0 && "n must be non-negative");
 
+  Memo<int, uint64_t, DenseCache<int, uint64_t>> memo;
+  uint64_t fib_n = memo.recursive(n, [](int k, auto fib) -> uint64_t {
+    return k <= 1 ? k : fib(k - 1) + fib(k - 2);
+  });
+  if (stats != nullptr) {
+    *stats = memo.stats();
+  }
+  return fib_n;
+}
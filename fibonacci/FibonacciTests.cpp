@@ -50,6 +50,19 @@ TEST(Fibonacci, LargerValues) {
   EXPECT_EQ(lin_fib(90), 2880067194370816120ULL);
 }
 
+TEST(Fibonacci, Memoized) {
+  for (int n = 0; n <= 25; ++n) {
+    EXPECT_EQ(memo_fib(n), exp_fib(n));
+  }
+  EXPECT_EQ(memo_fib(90), 2880067194370816120ULL);
+
+  // fib(3) through fib(10) each reuse fib(k - 2), computed by fib(k - 1)
+  MemoStats stats;
+  memo_fib(10, &stats);
+  EXPECT_EQ(stats.misses, 11u);
+  EXPECT_EQ(stats.hits, 8u);
+}
+
 int main(int argc, char* argv[]) {
   testing::InitGoogleTest(&argc, argv);
   return RUN_ALL_TESTS();
@@ -0,0 +1,95 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "../classic-dp/ClassicDp.h"
+#include "../trie/Trie.h"
+
+// Fuzzy dictionary search: every word within edit distance k of a query,
+// without comparing the query against every word.
+//
+// The edit distance DP fills a table one row per letter of the word, and
+// each row depends only on the row before and the next letter. Words that
+// share a prefix share those rows, so walk the trie holding the dictionary
+// and compute one row per node: row[j] is the distance from the node's
+// prefix to the first j letters of the query. Every entry only grows from
+// a row to the rows below it, so once the smallest entry is over k no word
+// further down can match and the whole subtree is skipped. This is a
+// Levenshtein automaton run without building it, the row being its state.
+
+struct FuzzyMatch {
+  std::string word;
+  size_t distance;
+
+  bool operator==(const FuzzyMatch&) const = default;
+};
+
+// The words below `node`, whose prefix is `path` and whose row is `row`
+inline void fuzzy_collect(const TrieNode* node, const std::string& query,
+                          size_t k, std::string& path,
+                          const std::vector<size_t>& row,
+                          std::vector<FuzzyMatch>& found) {
+  if (node->is_end && row.back() <= k) {
+    found.push_back({path, row.back()});
+  }
+  if (*std::min_element(row.begin(), row.end()) > k) {
+    return;
+  }
+  std::vector<size_t> next(row.size());
+  for (const auto& [c, child] : node->children) {
+    next[0] = row[0] + 1;
+    for (size_t j = 1; j < row.size(); ++j) {
+      next[j] = std::min({next[j - 1] + 1, row[j] + 1,
+                          row[j - 1] + (query[j - 1] != c)});
+    }
+    path.push_back(c);
+    fuzzy_collect(child.get(), query, k, path, next, found);
+    path.pop_back();
+  }
+}
+
+// Every word in the trie within edit distance k of the query, with its
+// distance, in alphabetical order. O(m) per trie node visited, for a query
+// of m letters; with small k most of the trie is never reached.
+inline std::vector<FuzzyMatch> fuzzy_search(const Trie& trie,
+                                            const std::string& query,
+                                            size_t k) {
+  std::vector<size_t> row(query.size() + 1);
+  for (size_t j = 0; j <= query.size(); ++j) {
+    row[j] = j;
+  }
+  std::vector<FuzzyMatch> found;
+  std::string path;
+  fuzzy_collect(trie.rootNode(), query, k, path, row, found);
+  return found;
+}
+
+inline std::vector<FuzzyMatch> fuzzy_search(
+    const std::vector<std::string>& dictionary, const std::string& query,
+    size_t k) {
+  Trie trie;
+  for (const auto& word : dictionary) {
+    trie.insert(word);
+  }
+  return fuzzy_search(trie, query, k);
+}
+
+// O(n m L): the edit distance to every word, deduplicated and sorted
+inline std::vector<FuzzyMatch> fuzzy_search_naive(
+    const std::vector<std::string>& dictionary, const std::string& query,
+    size_t k) {
+  std::vector<std::string> words = dictionary;
+  std::sort(words.begin(), words.end());
+  words.erase(std::unique(words.begin(), words.end()), words.end());
+  std::vector<FuzzyMatch> found;
+  for (const auto& word : words) {
+    const size_t distance = edit_distance_tabulated(word, query);
+    if (distance <= k) {
+      found.push_back({word, distance});
+    }
+  }
+  return found;
+}
@@ -0,0 +1,58 @@
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "FuzzySearch.h"
+#include "gtest/gtest.h"
+
+TEST(FuzzySearch, Examples) {
+  const std::vector<std::string> dictionary = {
+      "cat", "cart", "care", "cast", "dog", "dot", "scat", "at", "catalog"};
+  const std::vector<FuzzyMatch> one = {
+      {"at", 1}, {"cart", 1}, {"cast", 1}, {"cat", 0}, {"scat", 1}};
+  EXPECT_EQ(fuzzy_search(dictionary, "cat", 1), one);
+  EXPECT_EQ(fuzzy_search(dictionary, "cat", 0),
+            (std::vector<FuzzyMatch>{{"cat", 0}}));
+  EXPECT_EQ(fuzzy_search(dictionary, "dig", 1),
+            (std::vector<FuzzyMatch>{{"dog", 1}}));
+  EXPECT_TRUE(fuzzy_search(dictionary, "zebra", 2).empty());
+}
+
+// The empty word is a word like any other, and the empty query is within
+// k of every word of at most k letters
+TEST(FuzzySearch, EmptyStrings) {
+  const std::vector<std::string> dictionary = {"", "a", "ab", "abc"};
+  EXPECT_EQ(fuzzy_search(dictionary, "", 2),
+            (std::vector<FuzzyMatch>{{"", 0}, {"a", 1}, {"ab", 2}}));
+  EXPECT_EQ(fuzzy_search(dictionary, "b", 1),
+            (std::vector<FuzzyMatch>{{"", 1}, {"a", 1}, {"ab", 1}}));
+  EXPECT_TRUE(fuzzy_search(std::vector<std::string>{}, "a", 3).empty());
+}
+
+TEST(FuzzySearch, MatchesNaive) {
+  Rng gen(220);
+  for (int trial = 0; trial < 300; ++trial) {
+    const int alphabet = 2 + static_cast<int>(uniform_below(gen, 3));
+    std::vector<std::string> dictionary(uniform_below(gen, 40));
+    for (auto& word : dictionary) {
+      word = random_text(uniform_below(gen, 9), alphabet, gen);
+    }
+    Trie trie;
+    for (const auto& word : dictionary) {
+      trie.insert(word);
+    }
+    const std::string query = random_text(uniform_below(gen, 9), alphabet, gen);
+    for (size_t k = 0; k <= 3; ++k) {
+      ASSERT_EQ(fuzzy_search(trie, query, k),
+                fuzzy_search_naive(dictionary, query, k))
+          << query << " " << k;
+    }
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
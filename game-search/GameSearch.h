@@ -0,0 +1,111 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <concepts>
+#include <cstddef>
+#include <limits>
+#include <vector>
+
+// Minimax for two-player, zero-sum games of perfect information, in negamax
+// form: whatever is good for one player is exactly as bad for the other, so
+// a position's value to the player to move is the best, over their moves, of
+// minus its value to the opponent. A game is a Game if it has State and Move
+// types and:
+//
+// - moves(s), the legal moves in s;
+// - play(s, m), the state after making move m in s;
+// - is_over(s); and
+// - score(s) of a finished game, from the point of view of the player who
+//   would move next, e.g. -1 for a loss, 0 for a draw, 1 for a win.
+template <typename G>
+concept Game = requires(const G& game, const typename G::State& s,
+                        const typename G::Move& m) {
+  { game.moves(s) } -> std::convertible_to<std::vector<typename G::Move>>;
+  { game.play(s, m) } -> std::convertible_to<typename G::State>;
+  { game.is_over(s) } -> std::convertible_to<bool>;
+  { game.score(s) } -> std::convertible_to<int>;
+};
+
+// Counts the positions a search visits, to compare the two searches
+struct SearchStats {
+  size_t nodes{0};
+};
+
+// Scores stay within [-kMaxScore, kMaxScore], so negating never overflows
+inline constexpr int kMaxScore = std::numeric_limits<int>::max();
+
+// Plain minimax: every position in the game tree. O(b^d) for branching
+// factor b and depth d.
+template <Game G>
+int minimax(const G& game, const typename G::State& s,
+            SearchStats* stats = nullptr) {
+  if (stats != nullptr) {
+    ++stats->nodes;
+  }
+  if (game.is_over(s)) {
+    return game.score(s);
+  }
+  int best = -kMaxScore;
+  for (const auto& m : game.moves(s)) {
+    best = std::max(best, -minimax(game, game.play(s, m), stats));
+  }
+  return best;
+}
+
+// Alpha-beta: the same value, searching only the window (alpha, beta). The
+// player to move already has a way to get alpha, and the opponent one to
+// hold them to beta, so as soon as a move reaches beta the opponent won't
+// allow this position and the remaining moves can be skipped. With good move
+// ordering that's O(b^(d/2)). Values outside the window are only bounds:
+// at most alpha, or at least beta.
+template <Game G>
+int alpha_beta(const G& game, const typename G::State& s, int alpha, int beta,
+               SearchStats* stats = nullptr) {
+  if (stats != nullptr) {
+    ++stats->nodes;
+  }
+  if (game.is_over(s)) {
+    return game.score(s);
+  }
+  int best = -kMaxScore;
+  for (const auto& m : game.moves(s)) {
+    best = std::max(best, -alpha_beta(game, game.play(s, m), -beta,
+                                      -std::max(alpha, best), stats));
+    if (best >= beta) {
+      break;
+    }
+  }
+  return best;
+}
+
+// The exact value, with the widest window
+template <Game G>
+int alpha_beta(const G& game, const typename G::State& s,
+               SearchStats* stats = nullptr) {
+  return alpha_beta(game, s, -kMaxScore, kMaxScore, stats);
+}
+
+template <typename Move>
+struct Choice {
+  Move move;
+  int value;
+};
+
+// A move that achieves the position's value; the first one if there's a tie
+template <Game G>
+Choice<typename G::Move> best_move(const G& game, const typename G::State& s,
+                                   SearchStats* stats = nullptr) {
+  assert(!game.is_over(s) && "no moves in a finished game");
+  const auto moves = game.moves(s);
+  Choice<typename G::Move> best{moves.front(), -kMaxScore};
+  for (const auto& m : moves) {
+    // Only a strictly better move matters, so the window starts at best
+    const int value =
+        -alpha_beta(game, game.play(s, m), -kMaxScore, -best.value, stats);
+    if (value > best.value) {
+      best = {m, value};
+    }
+  }
+  return best;
+}
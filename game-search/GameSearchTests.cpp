@@ -0,0 +1,124 @@
+#include <cstddef>
+#include <functional>
+#include <map>
+#include <string>
+#include <vector>
+
+#include "GameSearch.h"
+#include "Nim.h"
+#include "TicTacToe.h"
+#include "gtest/gtest.h"
+
+using Board = TicTacToe::State;
+
+Board board(const std::string& cells, char to_move) {
+  Board s;
+  for (size_t i = 0; i < 9; ++i) {
+    s.board[i] = cells[i] == '.' ? ' ' : cells[i];
+  }
+  s.to_move = to_move;
+  return s;
+}
+
+// Every position reachable from the empty board, keyed by its cells
+std::map<std::string, Board> reachable_positions() {
+  TicTacToe game;
+  std::map<std::string, Board> positions;
+  std::function<void(const Board&)> visit = [&](const Board& s) {
+    if (!positions.emplace(std::string(s.board.begin(), s.board.end()), s)
+             .second ||
+        game.is_over(s)) {
+      return;
+    }
+    for (int cell : game.moves(s)) {
+      visit(game.play(s, cell));
+    }
+  };
+  visit(Board{});
+  return positions;
+}
+
+// Plays `player` perfectly against every possible sequence of replies, and
+// records whether any game ends with the opponent winning
+void expect_never_loses(const TicTacToe& game, const Board& s, char player) {
+  if (game.is_over(s)) {
+    const char winner = TicTacToe::winner(s);
+    EXPECT_TRUE(winner == ' ' || winner == player)
+        << std::string(s.board.begin(), s.board.end());
+    return;
+  }
+  if (s.to_move == player) {
+    expect_never_loses(game, game.play(s, best_move(game, s).move), player);
+  } else {
+    for (int cell : game.moves(s)) {
+      expect_never_loses(game, game.play(s, cell), player);
+    }
+  }
+}
+
+TEST(GameSearch, TicTacToeIsADraw) {
+  TicTacToe game;
+  SearchStats full;
+  SearchStats pruned;
+
+  EXPECT_EQ(minimax(game, Board{}, &full), 0);
+  EXPECT_EQ(alpha_beta(game, Board{}, &pruned), 0);
+  EXPECT_EQ(full.nodes, 549946u);  // The whole game tree
+  EXPECT_LT(pruned.nodes * 10, full.nodes);
+}
+
+TEST(GameSearch, AlphaBetaMatchesMinimax) {
+  TicTacToe game;
+  const auto positions = reachable_positions();
+  EXPECT_EQ(positions.size(), 5478u);
+  for (const auto& [cells, s] : positions) {
+    const int value = minimax(game, s);
+    ASSERT_EQ(alpha_beta(game, s), value) << cells;
+    if (!game.is_over(s)) {
+      const auto choice = best_move(game, s);
+      ASSERT_EQ(choice.value, value) << cells;
+      ASSERT_EQ(-minimax(game, game.play(s, choice.move)), value) << cells;
+    }
+  }
+}
+
+TEST(GameSearch, TakesTheQuickestWin) {
+  TicTacToe game;
+  // X wins at 2 right away; anything else lets O win at 5
+  auto s = board("XX.OO....", 'X');
+  EXPECT_EQ(best_move(game, s).move, 2);
+  EXPECT_GT(best_move(game, s).value, 0);
+
+  // O has to block at 2, or lose
+  s = board("XX..O....", 'O');
+  EXPECT_EQ(best_move(game, s).move, 2);
+}
+
+TEST(GameSearch, PerfectPlayNeverLoses) {
+  TicTacToe game;
+  expect_never_loses(game, Board{}, 'X');
+  expect_never_loses(game, Board{}, 'O');
+}
+
+TEST(GameSearch, NimFollowsBouton) {
+  Nim game;
+  for (int a = 0; a <= 4; ++a) {
+    for (int b = 0; b <= 4; ++b) {
+      for (int c = 0; c <= 4; ++c) {
+        const std::vector<int> heaps = {a, b, c};
+        const bool wins = nim_first_player_wins(heaps);
+        ASSERT_EQ(alpha_beta(game, heaps), wins ? 1 : -1);
+        if (wins) {
+          // The winning move leaves an XOR of 0
+          auto after = game.play(heaps, best_move(game, heaps).move);
+          ASSERT_FALSE(nim_first_player_wins(after));
+        }
+      }
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
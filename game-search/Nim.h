@@ -0,0 +1,51 @@
+#pragma once
+
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+// Nim: take any number of objects from one heap. Whoever takes the last one
+// wins, so facing empty heaps is a loss.
+//
+// Bouton's theorem: the player to move loses exactly when the heap sizes
+// XOR to 0. Any move from there makes the XOR nonzero, and from a nonzero
+// XOR there's always a move back to 0.
+struct Nim {
+  using State = std::vector<int>;
+  using Move = std::pair<size_t, int>;  // Heap, and how many to take
+
+  std::vector<Move> moves(const State& heaps) const {
+    std::vector<Move> all;
+    for (size_t h = 0; h < heaps.size(); ++h) {
+      for (int take = 1; take <= heaps[h]; ++take) {
+        all.push_back({h, take});
+      }
+    }
+    return all;
+  }
+
+  State play(const State& heaps, const Move& m) const {
+    State next = heaps;
+    next[m.first] -= m.second;
+    return next;
+  }
+
+  bool is_over(const State& heaps) const {
+    for (int h : heaps) {
+      if (h > 0) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  int score(const State&) const { return -1; }
+};
+
+inline bool nim_first_player_wins(const std::vector<int>& heaps) {
+  int x = 0;
+  for (int h : heaps) {
+    x ^= h;
+  }
+  return x != 0;
+}
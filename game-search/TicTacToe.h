@@ -0,0 +1,62 @@
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <vector>
+
+// Cells 0..8 row by row, each 'X', 'O', or ' '. X moves first.
+struct TicTacToe {
+  struct State {
+    std::array<char, 9> board{' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' '};
+    char to_move{'X'};
+  };
+  using Move = int;  // The cell to mark
+
+  static constexpr int kLines[8][3] = {
+      {0, 1, 2}, {3, 4, 5}, {6, 7, 8},  // Rows
+      {0, 3, 6}, {1, 4, 7}, {2, 5, 8},  // Columns
+      {0, 4, 8}, {2, 4, 6},             // Diagonals
+  };
+
+  // 'X' or 'O' if they have three in a row, ' ' otherwise
+  static char winner(const State& s) {
+    for (const auto& line : kLines) {
+      const char c = s.board[line[0]];
+      if (c != ' ' && c == s.board[line[1]] && c == s.board[line[2]]) {
+        return c;
+      }
+    }
+    return ' ';
+  }
+
+  std::vector<Move> moves(const State& s) const {
+    std::vector<Move> empty;
+    for (int cell = 0; cell < 9; ++cell) {
+      if (s.board[cell] == ' ') {
+        empty.push_back(cell);
+      }
+    }
+    return empty;
+  }
+
+  State play(const State& s, Move cell) const {
+    State next = s;
+    next.board[cell] = s.to_move;
+    next.to_move = s.to_move == 'X' ? 'O' : 'X';
+    return next;
+  }
+
+  bool is_over(const State& s) const {
+    return winner(s) != ' ' || moves(s).empty();
+  }
+
+  // Only the player who just moved can have won, so the player to move has
+  // either lost or drawn. A loss counts for less the longer it took, so that
+  // the winner goes for the quickest win and the loser holds out longest.
+  int score(const State& s) const {
+    if (winner(s) == ' ') {
+      return 0;
+    }
+    return -static_cast<int>(moves(s).size()) - 1;
+  }
+};
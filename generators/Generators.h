@@ -0,0 +1,233 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <memory>
+#include <queue>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+#include "../binary-tree/TreeNode.h"
+#include "../graph/Graph.h"
+#include "../rng/Rng.h"
+
+// Random inputs for tests and benchmarks. Every generator takes the random
+// engine by reference, so a seeded Rng (rng/) makes the output reproducible,
+// on any standard library, and a sequence of calls draws different inputs
+// from it.
+
+// Fisher-Yates: position i swaps with a uniformly random position at or
+// before it, so each of the n! permutations comes out with probability 1/n!.
+// O(n).
+template <typename Gen>
+std::vector<int> random_permutation(int n, Gen& gen) {
+  assert(n >= 0);
+  std::vector<int> perm(n);
+  for (int i = 0; i < n; ++i) {
+    perm[i] = i;
+  }
+  for (int i = n - 1; i > 0; --i) {
+    std::swap(perm[i], perm[uniform_int(gen, 0, i)]);
+  }
+  return perm;
+}
+
+// n letters drawn uniformly from the first `alphabet` of 'a' to 'z'. Small
+// alphabets make the repeats that string algorithms have to get right.
+template <typename Gen>
+std::string random_text(size_t n, int alphabet, Gen& gen) {
+  assert(1 <= alphabet && alphabet <= 26);
+  std::string text(n, 'a');
+  for (auto& c : text) {
+    c = static_cast<char>('a' + uniform_below(gen, alphabet));
+  }
+  return text;
+}
+
+// n characters drawn uniformly from alphabet, e.g. "ab", for inputs over
+// characters that aren't a prefix of 'a' to 'z'
+template <typename Gen>
+std::string random_text(size_t n, std::string_view alphabet, Gen& gen) {
+  assert(!alphabet.empty());
+  std::string text(n, alphabet[0]);
+  for (auto& c : text) {
+    c = alphabet[uniform_below(gen, alphabet.size())];
+  }
+  return text;
+}
+
+// Rémy's algorithm. A binary tree with n nodes becomes a full binary tree
+// with n internal nodes and n + 1 leaves by filling in every missing child.
+// Grow one uniformly at random: pick any of its 2i - 1 nodes, splice a new
+// internal node in above it, and give the new node a new leaf as its other
+// child, on a random side. Every full tree with i internal nodes comes out
+// of exactly i + 1 of those choices, one for each of its leaves as the new
+// leaf, so uniform stays uniform. Dropping the leaves gives a uniformly
+// random binary tree shape.
+
+inline std::unique_ptr<TreeNode<int>> remy_to_tree(
+    const std::vector<int>& left, const std::vector<int>& right, int node,
+    int& next_val) {
+  if (left[node] == -1) {
+    return nullptr;  // A leaf, i.e. a missing child
+  }
+  auto l = remy_to_tree(left, right, left[node], next_val);
+  auto tree = make_node(next_val++, std::move(l), nullptr);
+  tree->right = remy_to_tree(left, right, right[node], next_val);
+  return tree;
+}
+
+// A uniformly random shape with n nodes, valued 0..n-1 in inorder, so that
+// it's also a BST. O(n).
+template <typename Gen>
+std::unique_ptr<TreeNode<int>> random_binary_tree(int n, Gen& gen) {
+  assert(n >= 0);
+  // Nodes of the full tree; internal nodes have both children, leaves none
+  const int size = 2 * n + 1;
+  std::vector<int> left(size, -1);
+  std::vector<int> right(size, -1);
+  std::vector<int> parent(size, -1);
+  int root = 0;
+  for (int i = 1; i <= n; ++i) {
+    const int x = uniform_int(gen, 0, 2 * i - 2);
+    const int internal = 2 * i - 1;
+    const int leaf = 2 * i;
+
+    // internal takes x's place under x's parent
+    const int p = parent[x];
+    if (p == -1) {
+      root = internal;
+    } else if (left[p] == x) {
+      left[p] = internal;
+    } else {
+      right[p] = internal;
+    }
+    parent[internal] = p;
+
+    const bool leaf_on_left = uniform_below(gen, 2) == 1;
+    left[internal] = leaf_on_left ? leaf : x;
+    right[internal] = leaf_on_left ? x : leaf;
+    parent[x] = parent[leaf] = internal;
+  }
+  int next_val = 0;
+  return remy_to_tree(left, right, root, next_val);
+}
+
+template <typename T>
+void bst_insert(std::unique_ptr<TreeNode<T>>& root, T val) {
+  auto* slot = &root;
+  while (*slot) {
+    slot = val < (*slot)->val ? &(*slot)->left : &(*slot)->right;
+  }
+  *slot = make_node(std::move(val));
+}
+
+// The BST built by inserting 0..n-1 in random order. Not uniform over
+// shapes: balanced shapes come out more often, and the expected depth of a
+// node is about 2 ln n. O(n log n) expected.
+template <typename Gen>
+std::unique_ptr<TreeNode<int>> random_bst(int n, Gen& gen) {
+  std::unique_ptr<TreeNode<int>> root;
+  for (int key : random_permutation(n, gen)) {
+    bst_insert(root, key);
+  }
+  return root;
+}
+
+// A labeled tree on n vertices, uniform over all n^(n - 2) of them, by
+// decoding a random Prüfer sequence: n - 2 vertices, each of which is the
+// neighbor of the smallest remaining leaf when it gets removed. O(n log n).
+template <typename Gen>
+AdjacencyList random_labeled_tree(int n, Gen& gen) {
+  assert(n >= 0);
+  AdjacencyList tree(n);
+  if (n < 2) {
+    return tree;
+  }
+  std::vector<int> prufer(n - 2);
+  std::vector<int> degree(n, 1);
+  for (auto& v : prufer) {
+    v = uniform_int(gen, 0, n - 1);
+    ++degree[v];
+  }
+
+  std::priority_queue<int, std::vector<int>, std::greater<int>> leaves;
+  for (int v = 0; v < n; ++v) {
+    if (degree[v] == 1) {
+      leaves.push(v);
+    }
+  }
+  auto connect = [&](int u, int v) {
+    tree[u].push_back(v);
+    tree[v].push_back(u);
+  };
+  for (int v : prufer) {
+    const int leaf = leaves.top();
+    leaves.pop();
+    connect(leaf, v);
+    if (--degree[v] == 1) {
+      leaves.push(v);
+    }
+  }
+  // Two vertices are left; join them
+  const int u = leaves.top();
+  leaves.pop();
+  connect(u, leaves.top());
+  return tree;
+}
+
+// A random recursive tree, stretched: vertex i hangs off a uniformly
+// random earlier vertex, or with probability stretch off vertex i - 1,
+// which makes long paths. stretch = 0 gives trees about log n deep, and
+// stretch = 1 a path. The labels are shuffled, so that the first vertex
+// isn't always 0. Not uniform over labeled trees, but it reaches shapes,
+// like deep ones, that random_labeled_tree almost never does. O(n).
+template <typename Gen>
+AdjacencyList random_stretched_tree(int n, double stretch, Gen& gen) {
+  assert(n >= 0 && stretch >= 0.0 && stretch <= 1.0);
+  const std::vector<int> label = random_permutation(n, gen);
+  AdjacencyList tree(n);
+  for (int i = 1; i < n; ++i) {
+    const int parent = bernoulli(gen, stretch)
+                           ? i - 1
+                           : static_cast<int>(uniform_below(gen, i));
+    tree[label[parent]].push_back(label[i]);
+    tree[label[i]].push_back(label[parent]);
+  }
+  return tree;
+}
+
+// Erdős–Rényi G(n, p): every one of the n(n - 1)/2 possible edges is
+// present independently with probability p. Undirected, no self-loops,
+// neighbors in increasing order. O(n^2).
+template <typename Gen>
+AdjacencyList erdos_renyi(int n, double p, Gen& gen) {
+  assert(n >= 0 && p >= 0.0 && p <= 1.0);
+  AdjacencyList adj(n);
+  for (int u = 0; u < n; ++u) {
+    for (int v = u + 1; v < n; ++v) {
+      if (bernoulli(gen, p)) {
+        adj[u].push_back(v);
+        adj[v].push_back(u);
+      }
+    }
+  }
+  return adj;
+}
+
+// m edges with both ends drawn uniformly from the n vertices, so self-loops
+// and parallel edges come up: the multigraphs that cut and flow algorithms
+// have to handle. Directed or undirected is up to the caller. O(m).
+template <typename Gen>
+std::vector<Edge> random_multigraph(int n, size_t m, Gen& gen) {
+  assert(n > 0 || m == 0);
+  std::vector<Edge> edges(m);
+  for (auto& [u, v] : edges) {
+    u = static_cast<int>(uniform_below(gen, n));
+    v = static_cast<int>(uniform_below(gen, n));
+  }
+  return edges;
+}
@@ -0,0 +1,200 @@
+#include <algorithm>
+#include <map>
+#include <set>
+#include <string>
+#include <vector>
+
+#include "../binary-tree/Serialize.h"
+#include "../binary-tree/Traversal.h"
+#include "../graph/Tree.h"
+#include "../rng/Rng.h"
+#include "Generators.h"
+#include "gtest/gtest.h"
+
+std::vector<int> identity(int n) {
+  std::vector<int> perm(n);
+  for (int i = 0; i < n; ++i) {
+    perm[i] = i;
+  }
+  return perm;
+}
+
+// Draws outcomes and checks that each of the expected number of distinct
+// ones shows up with a frequency within 10% of uniform
+template <typename Draw>
+void expect_uniform(size_t outcomes, int draws, Draw draw) {
+  std::map<std::string, int> counts;
+  for (int i = 0; i < draws; ++i) {
+    ++counts[draw()];
+  }
+  ASSERT_EQ(counts.size(), outcomes);
+  const double expected = static_cast<double>(draws) / outcomes;
+  for (const auto& [outcome, count] : counts) {
+    EXPECT_NEAR(count, expected, 0.1 * expected) << outcome;
+  }
+}
+
+std::string join(const std::vector<int>& values) {
+  std::string result;
+  for (int v : values) {
+    result += std::to_string(v) + ",";
+  }
+  return result;
+}
+
+TEST(Generators, Reproducible) {
+  Rng a(145);
+  Rng b(145);
+
+  EXPECT_EQ(random_permutation(50, a), random_permutation(50, b));
+  EXPECT_EQ(serialize(random_binary_tree(50, a)),
+            serialize(random_binary_tree(50, b)));
+  EXPECT_EQ(serialize(random_bst(50, a)), serialize(random_bst(50, b)));
+  EXPECT_EQ(random_labeled_tree(50, a), random_labeled_tree(50, b));
+  EXPECT_EQ(erdos_renyi(50, 0.2, a), erdos_renyi(50, 0.2, b));
+  EXPECT_EQ(random_multigraph(10, 30, a), random_multigraph(10, 30, b));
+}
+
+TEST(Generators, RandomPermutation) {
+  Rng gen(146);
+  for (int n = 0; n <= 20; ++n) {
+    auto perm = random_permutation(n, gen);
+    std::sort(perm.begin(), perm.end());
+    ASSERT_EQ(perm, identity(n));
+  }
+  expect_uniform(24, 24000, [&] { return join(random_permutation(4, gen)); });
+}
+
+TEST(Generators, RandomText) {
+  Rng gen(216);
+  EXPECT_EQ(random_text(0, 3, gen), "");
+  EXPECT_EQ(random_text(5, 1, gen), "aaaaa");
+  const std::string text = random_text(1000, 3, gen);
+  ASSERT_EQ(text.size(), 1000u);
+  EXPECT_EQ(std::set<char>(text.begin(), text.end()),
+            (std::set<char>{'a', 'b', 'c'}));
+
+  const std::string dna = random_text(1000, "ACGT", gen);
+  ASSERT_EQ(dna.size(), 1000u);
+  EXPECT_EQ(std::set<char>(dna.begin(), dna.end()),
+            (std::set<char>{'A', 'C', 'G', 'T'}));
+  EXPECT_EQ(random_text(3, "*", gen), "***");
+}
+
+TEST(Generators, RandomBinaryTree) {
+  Rng gen(147);
+  EXPECT_EQ(random_binary_tree(0, gen), nullptr);
+  for (int n = 1; n <= 50; ++n) {
+    auto tree = random_binary_tree(n, gen);
+    ASSERT_EQ(inorder(tree.get()), identity(n));
+  }
+  // The 14 shapes with 4 nodes, equally often
+  expect_uniform(14, 28000,
+                 [&] { return serialize(random_binary_tree(4, gen)); });
+}
+
+TEST(Generators, RandomBst) {
+  Rng gen(148);
+  for (int n = 0; n <= 50; ++n) {
+    auto tree = random_bst(n, gen);
+    ASSERT_EQ(inorder(tree.get()), identity(n));
+  }
+
+  // Of the 6 insertion orders of 3 keys, 2 build the balanced tree
+  std::map<std::string, int> counts;
+  const int draws = 30000;
+  for (int i = 0; i < draws; ++i) {
+    ++counts[serialize(random_bst(3, gen))];
+  }
+  ASSERT_EQ(counts.size(), 5u);
+  for (const auto& [shape, count] : counts) {
+    const double expected = shape == "1,0,2" ? draws / 3.0 : draws / 6.0;
+    EXPECT_NEAR(count, expected, 0.1 * expected) << shape;
+  }
+}
+
+TEST(Generators, RandomLabeledTree) {
+  Rng gen(149);
+  for (int n = 1; n <= 50; ++n) {
+    ASSERT_TRUE(is_tree(random_labeled_tree(n, gen)));
+  }
+  // Cayley: 4^2 labeled trees on 4 vertices
+  expect_uniform(16, 16000, [&] {
+    std::string key;
+    for (const auto& neighbors : random_labeled_tree(4, gen)) {
+      auto sorted = neighbors;
+      std::sort(sorted.begin(), sorted.end());
+      key += join(sorted) + ";";
+    }
+    return key;
+  });
+}
+
+TEST(Generators, RandomStretchedTree) {
+  Rng gen(212);
+  for (int n = 0; n <= 50; ++n) {
+    ASSERT_TRUE(is_tree(random_stretched_tree(n, uniform_unit(gen), gen)));
+  }
+  // Fully stretched, it's a path: two ends and the rest of degree two
+  const auto path = random_stretched_tree(100, 1.0, gen);
+  size_t ends = 0;
+  for (const auto& neighbors : path) {
+    ASSERT_LE(neighbors.size(), 2u);
+    ends += neighbors.size() == 1;
+  }
+  EXPECT_EQ(ends, 2u);
+  // Unstretched, it's shallow from any vertex
+  const auto bushy = random_stretched_tree(1000, 0.0, gen);
+  const auto dist = bfs_distances(bushy, 0);
+  EXPECT_LT(*std::max_element(dist.begin(), dist.end()), 100);
+}
+
+TEST(Generators, ErdosRenyi) {
+  Rng gen(150);
+  const int n = 40;
+  EXPECT_EQ(erdos_renyi(n, 0.0, gen), AdjacencyList(n));
+  auto complete = erdos_renyi(n, 1.0, gen);
+  for (int u = 0; u < n; ++u) {
+    auto others = identity(n);
+    others.erase(others.begin() + u);
+    ASSERT_EQ(complete[u], others);
+  }
+
+  // The edge count is binomial, with mean p * n(n - 1)/2 = 234
+  size_t total = 0;
+  const int trials = 100;
+  for (int trial = 0; trial < trials; ++trial) {
+    auto adj = erdos_renyi(n, 0.3, gen);
+    for (int u = 0; u < n; ++u) {
+      ASSERT_TRUE(std::is_sorted(adj[u].begin(), adj[u].end()));
+      for (int v : adj[u]) {
+        ASSERT_NE(u, v);
+        ASSERT_TRUE(std::binary_search(adj[v].begin(), adj[v].end(), u));
+      }
+      total += adj[u].size();
+    }
+  }
+  EXPECT_NEAR(total / 2.0 / trials, 234.0, 5.0);
+}
+
+TEST(Generators, RandomMultigraph) {
+  Rng gen(226);
+  EXPECT_TRUE(random_multigraph(0, 0, gen).empty());
+  const int n = 5;
+  const auto edges = random_multigraph(n, 2000, gen);
+  ASSERT_EQ(edges.size(), 2000u);
+  // Each of the n^2 ordered pairs, loops included, about 80 times
+  std::vector<int> seen(n * n);
+  for (const auto& [u, v] : edges) {
+    ASSERT_TRUE(0 <= u && u < n && 0 <= v && v < n);
+    ++seen[u * n + v];
+  }
+  for (int count : seen) {
+    EXPECT_GT(count, 40);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,97 @@
+#pragma once
+
+#include <algorithm>
+#include <compare>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+// Convex hulls of points in the plane, with integer coordinates so that
+// every orientation test is exact. (tsp/ has a Point with doubles.)
+
+struct LatticePoint {
+  int64_t x;
+  int64_t y;
+
+  auto operator<=>(const LatticePoint&) const = default;
+};
+
+// Twice the signed area of the triangle o, a, b: positive if o -> a -> b
+// turns counterclockwise, negative if clockwise, 0 if they're collinear
+inline int64_t cross(LatticePoint o, LatticePoint a, LatticePoint b) {
+  return (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+}
+
+// Andrew's monotone chain: sort by x, then build the lower hull left to
+// right and the upper hull right to left, popping every point that doesn't
+// make a counterclockwise turn. The vertices come out counterclockwise from
+// the lowest of the leftmost points, without duplicates or points in the
+// middle of an edge. Fewer than three distinct points are their own hull.
+// O(n log n).
+inline std::vector<LatticePoint> convex_hull(std::vector<LatticePoint> points) {
+  std::sort(points.begin(), points.end());
+  points.erase(std::unique(points.begin(), points.end()), points.end());
+  if (points.size() < 3) {
+    return points;
+  }
+  std::vector<LatticePoint> hull;
+  auto add = [&](LatticePoint p, size_t floor) {
+    while (hull.size() > floor &&
+           cross(hull[hull.size() - 2], hull.back(), p) <= 0) {
+      hull.pop_back();
+    }
+    hull.push_back(p);
+  };
+  for (const LatticePoint& p : points) {
+    add(p, 1);
+  }
+  const size_t lower = hull.size();
+  for (size_t i = points.size() - 1; i-- > 0;) {
+    add(points[i], lower);
+  }
+  hull.pop_back();  // The first point again
+  return hull;
+}
+
+// p lies on the segment from a to b, ends included
+inline bool on_segment(LatticePoint p, LatticePoint a, LatticePoint b) {
+  return cross(a, b, p) == 0 && std::min(a.x, b.x) <= p.x &&
+         p.x <= std::max(a.x, b.x) && std::min(a.y, b.y) <= p.y &&
+         p.y <= std::max(a.y, b.y);
+}
+
+// O(n^4), for the tests: the hull's vertices, sorted, are the points that
+// don't lie in any triangle, or on any segment, made of other points
+inline std::vector<LatticePoint> convex_hull_vertices_naive(
+    std::vector<LatticePoint> points) {
+  std::sort(points.begin(), points.end());
+  points.erase(std::unique(points.begin(), points.end()), points.end());
+  const size_t n = points.size();
+  std::vector<LatticePoint> vertices;
+  for (size_t i = 0; i < n; ++i) {
+    const LatticePoint p = points[i];
+    bool inside = false;
+    for (size_t a = 0; a < n && !inside; ++a) {
+      for (size_t b = a + 1; b < n && !inside; ++b) {
+        if (a == i || b == i) {
+          continue;
+        }
+        inside = on_segment(p, points[a], points[b]);
+        for (size_t c = b + 1; c < n && !inside; ++c) {
+          if (c == i || cross(points[a], points[b], points[c]) == 0) {
+            continue;
+          }
+          const int64_t ab = cross(points[a], points[b], p);
+          const int64_t bc = cross(points[b], points[c], p);
+          const int64_t ca = cross(points[c], points[a], p);
+          inside = (ab >= 0 && bc >= 0 && ca >= 0) ||
+                   (ab <= 0 && bc <= 0 && ca <= 0);
+        }
+      }
+    }
+    if (!inside) {
+      vertices.push_back(p);
+    }
+  }
+  return vertices;
+}
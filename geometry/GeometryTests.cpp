@@ -0,0 +1,73 @@
+#include <algorithm>
+#include <cstdint>
+#include <random>
+#include <vector>
+
+#include "ConvexHull.h"
+#include "Skyline.h"
+#include "gtest/gtest.h"
+
+TEST(ConvexHull, Square) {
+  // The corners, a point inside, and one in the middle of an edge
+  const std::vector<LatticePoint> points = {{0, 0}, {2, 2}, {1, 1}, {0, 2},
+                                     {2, 0}, {1, 0}, {0, 0}};
+  EXPECT_EQ(convex_hull(points),
+            (std::vector<LatticePoint>{{0, 0}, {2, 0}, {2, 2}, {0, 2}}));
+  EXPECT_EQ(convex_hull({}), std::vector<LatticePoint>{});
+  EXPECT_EQ(convex_hull({{3, 4}, {3, 4}}), (std::vector<LatticePoint>{{3, 4}}));
+  EXPECT_EQ(convex_hull({{0, 0}, {1, 1}, {2, 2}}),
+            (std::vector<LatticePoint>{{0, 0}, {2, 2}}));
+}
+
+TEST(ConvexHull, MatchesNaive) {
+  std::mt19937 gen(177);
+  std::uniform_int_distribution<int64_t> coordinate(-5, 5);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<LatticePoint> points(gen() % 12);
+    for (auto& p : points) {
+      p = {coordinate(gen), coordinate(gen)};
+    }
+    const auto hull = convex_hull(points);
+    // Strictly convex, counterclockwise
+    for (size_t i = 0; hull.size() >= 3 && i < hull.size(); ++i) {
+      ASSERT_GT(cross(hull[i], hull[(i + 1) % hull.size()],
+                      hull[(i + 2) % hull.size()]),
+                0);
+    }
+    auto vertices = hull;
+    std::sort(vertices.begin(), vertices.end());
+    ASSERT_EQ(vertices, convex_hull_vertices_naive(points));
+  }
+}
+
+TEST(Skyline, Example) {
+  const std::vector<Building> buildings = {
+      {2, 9, 10}, {3, 7, 15}, {5, 12, 12}, {15, 20, 10}, {19, 24, 8}};
+  const std::vector<KeyPoint> expected = {{2, 10}, {3, 15}, {7, 12}, {12, 0},
+                                          {15, 10}, {20, 8}, {24, 0}};
+  EXPECT_EQ(skyline(buildings), expected);
+  EXPECT_EQ(skyline_naive(buildings), expected);
+  // Same heights side by side merge into one
+  EXPECT_EQ(skyline({{0, 2, 3}, {2, 5, 3}}),
+            (std::vector<KeyPoint>{{0, 3}, {5, 0}}));
+  EXPECT_TRUE(skyline({}).empty());
+}
+
+TEST(Skyline, MatchesNaive) {
+  std::mt19937 gen(177);
+  std::uniform_int_distribution<int64_t> x(0, 20);
+  std::uniform_int_distribution<int64_t> height(1, 6);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<Building> buildings(gen() % 8);
+    for (auto& b : buildings) {
+      const int64_t left = x(gen);
+      b = {left, left + 1 + x(gen) / 2, height(gen)};
+    }
+    ASSERT_EQ(skyline(buildings), skyline_naive(buildings));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
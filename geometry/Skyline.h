@@ -0,0 +1,87 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <set>
+#include <utility>
+#include <vector>
+
+// The skyline problem: the outline of a row of rectangular buildings
+// against the sky, as the points where its height changes. Building
+// [left, right) has the given height; the outline is 0 outside them all.
+
+struct Building {
+  int64_t left;
+  int64_t right;
+  int64_t height;
+};
+
+// The outline takes height `height` from x until the next key point
+struct KeyPoint {
+  int64_t x;
+  int64_t height;
+
+  bool operator==(const KeyPoint&) const = default;
+};
+
+// Sweeps left to right over the walls, keeping the heights of the
+// buildings the sweep is inside in a multiset. At each x, after every wall
+// there has been handled, the outline is the tallest height left, or 0;
+// it's a key point if that changed. O(n log n).
+inline std::vector<KeyPoint> skyline(const std::vector<Building>& buildings) {
+  // (x, height), with height negated for a left wall
+  std::vector<std::pair<int64_t, int64_t>> walls;
+  for (const auto& b : buildings) {
+    if (b.left < b.right && b.height > 0) {
+      walls.emplace_back(b.left, -b.height);
+      walls.emplace_back(b.right, b.height);
+    }
+  }
+  std::sort(walls.begin(), walls.end());
+
+  std::vector<KeyPoint> outline;
+  std::multiset<int64_t> heights;
+  for (size_t i = 0; i < walls.size();) {
+    const int64_t x = walls[i].first;
+    for (; i < walls.size() && walls[i].first == x; ++i) {
+      if (walls[i].second < 0) {
+        heights.insert(-walls[i].second);
+      } else {
+        heights.erase(heights.find(walls[i].second));
+      }
+    }
+    const int64_t height = heights.empty() ? 0 : *heights.rbegin();
+    if (outline.empty() || outline.back().height != height) {
+      outline.push_back({x, height});
+    }
+  }
+  return outline;
+}
+
+// O(n^2), for the tests: the height just right of every wall, from every
+// building
+inline std::vector<KeyPoint> skyline_naive(
+    const std::vector<Building>& buildings) {
+  std::vector<int64_t> xs;
+  for (const auto& b : buildings) {
+    xs.push_back(b.left);
+    xs.push_back(b.right);
+  }
+  std::sort(xs.begin(), xs.end());
+  xs.erase(std::unique(xs.begin(), xs.end()), xs.end());
+
+  std::vector<KeyPoint> outline;
+  for (int64_t x : xs) {
+    int64_t height = 0;
+    for (const auto& b : buildings) {
+      if (b.left <= x && x < b.right) {
+        height = std::max(height, b.height);
+      }
+    }
+    if (outline.empty() ? height > 0 : outline.back().height != height) {
+      outline.push_back({x, height});
+    }
+  }
+  return outline;
+}
@@ -0,0 +1,178 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+#include "../bits/Bits.h"
+
+// Linear algebra over GF(2), the field with two elements: addition is xor
+// and multiplication is and. Gaussian elimination works as it does over the
+// reals, with no division and no rounding error, and a row operation on
+// bits packed 64 to a word is one xor per word.
+
+// A row of bits (a vector over GF(2)), packed 64 to a word. The bits past
+// size() in the last word are always 0, so whole words can be compared.
+class BitRow {
+ public:
+  explicit BitRow(size_t size = 0) : size_(size), words_((size + 63) / 64) {}
+
+  size_t size() const { return size_; }
+
+  bool get(size_t i) const {
+    assert(i < size_ && "bit out of range");
+    return (words_[i / 64] >> (i % 64)) & 1;
+  }
+
+  void set(size_t i, bool value = true) {
+    assert(i < size_ && "bit out of range");
+    const uint64_t bit = uint64_t{1} << (i % 64);
+    words_[i / 64] = value ? words_[i / 64] | bit : words_[i / 64] & ~bit;
+  }
+
+  void flip(size_t i) {
+    assert(i < size_ && "bit out of range");
+    words_[i / 64] ^= uint64_t{1} << (i % 64);
+  }
+
+  bool any() const {
+    for (uint64_t word : words_) {
+      if (word != 0) {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  size_t count() const {
+    size_t total = 0;
+    for (uint64_t word : words_) {
+      total += static_cast<size_t>(popcount(word));
+    }
+    return total;
+  }
+
+  // Adding two vectors
+  BitRow& operator^=(const BitRow& other) {
+    assert(size_ == other.size_ && "rows must be the same length");
+    for (size_t w = 0; w < words_.size(); ++w) {
+      words_[w] ^= other.words_[w];
+    }
+    return *this;
+  }
+
+  // The dot product: the parity of the bits set in both
+  bool dot(const BitRow& other) const {
+    assert(size_ == other.size_ && "rows must be the same length");
+    uint64_t parity = 0;
+    for (size_t w = 0; w < words_.size(); ++w) {
+      parity ^= words_[w] & other.words_[w];
+    }
+    return popcount(parity) % 2 == 1;
+  }
+
+  bool operator==(const BitRow&) const = default;
+
+ private:
+  size_t size_;
+  std::vector<uint64_t> words_;
+};
+
+inline BitRow operator^(BitRow a, const BitRow& b) { return a ^= b; }
+
+// A matrix as its rows, which must all be the same length
+using Gf2Matrix = std::vector<BitRow>;
+
+// a * x
+inline BitRow gf2_multiply(const Gf2Matrix& a, const BitRow& x) {
+  BitRow y(a.size());
+  for (size_t i = 0; i < a.size(); ++i) {
+    y.set(i, a[i].dot(x));
+  }
+  return y;
+}
+
+// Puts the first `cols` columns of a into reduced row echelon form: each
+// pivot is the only 1 in its column, and the rows with no pivot are all 0
+// there and moved to the bottom. Returns the pivot column of each of the
+// first rank rows, in increasing order. Any further columns, such as the
+// right-hand side of a system, are carried along. O(rows * cols^2 / 64).
+inline std::vector<size_t> gf2_row_reduce(Gf2Matrix& a, size_t cols) {
+  std::vector<size_t> pivots;
+  for (size_t col = 0; col < cols && pivots.size() < a.size(); ++col) {
+    const size_t top = pivots.size();
+    size_t pivot = top;
+    while (pivot < a.size() && !a[pivot].get(col)) {
+      ++pivot;
+    }
+    if (pivot == a.size()) {
+      continue;
+    }
+    std::swap(a[top], a[pivot]);
+    for (size_t i = 0; i < a.size(); ++i) {
+      if (i != top && a[i].get(col)) {
+        a[i] ^= a[top];
+      }
+    }
+    pivots.push_back(col);
+  }
+  return pivots;
+}
+
+inline size_t gf2_rank(Gf2Matrix a) {
+  return gf2_row_reduce(a, a.empty() ? 0 : a[0].size()).size();
+}
+
+// One x with a * x = b, or std::nullopt if there's none. The variables
+// without a pivot are free; this one sets them all to 0.
+inline std::optional<BitRow> gf2_solve(const Gf2Matrix& a, const BitRow& b) {
+  assert(a.size() == b.size() && "one right-hand side per row");
+  const size_t cols = a.empty() ? 0 : a[0].size();
+  // Each row with its right-hand side appended as column `cols`
+  Gf2Matrix augmented;
+  for (size_t i = 0; i < a.size(); ++i) {
+    BitRow row(cols + 1);
+    for (size_t j = 0; j < cols; ++j) {
+      row.set(j, a[i].get(j));
+    }
+    row.set(cols, b.get(i));
+    augmented.push_back(std::move(row));
+  }
+  const auto pivots = gf2_row_reduce(augmented, cols);
+  // A row reduced to 0 = 1 is a contradiction
+  for (size_t i = pivots.size(); i < augmented.size(); ++i) {
+    if (augmented[i].get(cols)) {
+      return std::nullopt;
+    }
+  }
+  BitRow x(cols);
+  for (size_t i = 0; i < pivots.size(); ++i) {
+    x.set(pivots[i], augmented[i].get(cols));
+  }
+  return x;
+}
+
+// A basis of the x with a * x = 0, one vector per free variable: that
+// variable set, the other free ones clear, and the pivot variables
+// whatever cancels it. cols - rank vectors.
+inline std::vector<BitRow> gf2_null_space(Gf2Matrix a, size_t cols) {
+  const auto pivots = gf2_row_reduce(a, cols);
+  std::vector<BitRow> basis;
+  size_t next_pivot = 0;
+  for (size_t free = 0; free < cols; ++free) {
+    if (next_pivot < pivots.size() && pivots[next_pivot] == free) {
+      ++next_pivot;
+      continue;
+    }
+    BitRow v(cols);
+    v.set(free);
+    for (size_t i = 0; i < pivots.size(); ++i) {
+      v.set(pivots[i], a[i].get(free));
+    }
+    basis.push_back(std::move(v));
+  }
+  return basis;
+}
@@ -0,0 +1,167 @@
+#include <cstddef>
+#include <cstdint>
+#include <set>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Gf2.h"
+#include "LightsOut.h"
+#include "gtest/gtest.h"
+
+BitRow random_row(size_t size, Rng& gen) {
+  BitRow row(size);
+  for (size_t i = 0; i < size; ++i) {
+    row.set(i, bernoulli(gen, 0.5));
+  }
+  return row;
+}
+
+Gf2Matrix random_matrix(size_t rows, size_t cols, Rng& gen) {
+  Gf2Matrix a;
+  for (size_t i = 0; i < rows; ++i) {
+    a.push_back(random_row(cols, gen));
+  }
+  return a;
+}
+
+// The rank is log2 of the number of vectors the rows span: every sum of a
+// subset of them
+size_t rank_naive(const Gf2Matrix& a, size_t cols) {
+  std::set<std::vector<bool>> span;
+  for (uint64_t subset = 0; subset < (uint64_t{1} << a.size()); ++subset) {
+    BitRow sum(cols);
+    for (size_t i = 0; i < a.size(); ++i) {
+      if ((subset >> i) & 1) {
+        sum ^= a[i];
+      }
+    }
+    std::vector<bool> bits(cols);
+    for (size_t j = 0; j < cols; ++j) {
+      bits[j] = sum.get(j);
+    }
+    span.insert(bits);
+  }
+  size_t rank = 0;
+  while ((size_t{1} << rank) < span.size()) {
+    ++rank;
+  }
+  return rank;
+}
+
+TEST(Gf2, BitRow) {
+  BitRow row(130);
+  EXPECT_FALSE(row.any());
+  row.set(0);
+  row.set(64);
+  row.set(129);
+  row.flip(64);
+  row.flip(100);
+  EXPECT_TRUE(row.get(0));
+  EXPECT_FALSE(row.get(64));
+  EXPECT_TRUE(row.get(100));
+  EXPECT_TRUE(row.get(129));
+  EXPECT_EQ(row.count(), 3u);
+  row.set(0, false);
+  EXPECT_EQ(row.count(), 2u);
+
+  BitRow other(130);
+  other.set(100);
+  other.set(5);
+  EXPECT_TRUE(row.dot(other));  // only bit 100 in common
+  other.set(129);
+  EXPECT_FALSE(row.dot(other));
+  EXPECT_EQ((row ^ row), BitRow(130));
+}
+
+TEST(Gf2, RankMatchesSpan) {
+  Rng gen(208);
+  for (int trial = 0; trial < 200; ++trial) {
+    const size_t rows = uniform_below(gen, 9);
+    const size_t cols = 1 + uniform_below(gen, 8);
+    const Gf2Matrix a = random_matrix(rows, cols, gen);
+    const size_t rank = gf2_rank(a);
+    ASSERT_EQ(rank, rank_naive(a, cols));
+    ASSERT_EQ(gf2_null_space(a, cols).size(), cols - rank);
+  }
+}
+
+TEST(Gf2, SolveAndNullSpace) {
+  Rng gen(208);
+  for (int trial = 0; trial < 200; ++trial) {
+    const size_t rows = 1 + uniform_below(gen, 100);
+    const size_t cols = 1 + uniform_below(gen, 100);
+    const Gf2Matrix a = random_matrix(rows, cols, gen);
+
+    // Right-hand sides made from a known x always have a solution
+    const BitRow b = gf2_multiply(a, random_row(cols, gen));
+    const auto x = gf2_solve(a, b);
+    ASSERT_TRUE(x.has_value());
+    ASSERT_EQ(gf2_multiply(a, *x), b);
+
+    for (const BitRow& v : gf2_null_space(a, cols)) {
+      ASSERT_TRUE(v.any());
+      ASSERT_FALSE(gf2_multiply(a, v).any());
+    }
+
+    // A random right-hand side may have no solution, but only if the rows
+    // don't span everything
+    const BitRow c = random_row(rows, gen);
+    const auto y = gf2_solve(a, c);
+    if (y) {
+      ASSERT_EQ(gf2_multiply(a, *y), c);
+    } else {
+      ASSERT_LT(gf2_rank(a), rows);
+    }
+  }
+}
+
+TEST(Gf2, LightsOutRanks) {
+  // Square boards of side 4 and 5 are the small singular ones
+  const std::vector<size_t> ranks = {1, 4, 9, 12, 23, 36};
+  for (int n = 1; n <= 6; ++n) {
+    EXPECT_EQ(gf2_rank(lights_out_matrix(n, n)), ranks[n - 1]) << n;
+  }
+
+  // The two ways of pressing a 5 by 5 board that change nothing
+  const Lights quiet_a = lights_from_strings(
+      {".###.", "#.#.#", "##.##", "#.#.#", ".###."});
+  const Lights quiet_b = lights_from_strings(
+      {"#.#.#", "#.#.#", ".....", "#.#.#", "#.#.#"});
+  const Lights off(5, 5, false);
+  EXPECT_EQ(apply_presses(off, quiet_a), off);
+  EXPECT_EQ(apply_presses(off, quiet_b), off);
+
+  // A board is solvable only if it's orthogonal to both, and a single
+  // corner light isn't
+  Lights corner = off;
+  corner[{0, 0}] = true;
+  EXPECT_FALSE(solve_lights_out(corner).has_value());
+  Lights center = off;
+  center[{2, 2}] = true;
+  EXPECT_TRUE(solve_lights_out(center).has_value());
+}
+
+TEST(Gf2, LightsOutSolvesRandomBoards) {
+  Rng gen(208);
+  for (int trial = 0; trial < 300; ++trial) {
+    const int rows = 1 + static_cast<int>(uniform_below(gen, 8));
+    const int cols = 1 + static_cast<int>(uniform_below(gen, 8));
+    // Scrambling a solved board by random presses keeps it solvable, and
+    // undoing them is a solution
+    Lights scramble(rows, cols, false);
+    for (Cell c : scramble.cells()) {
+      scramble[c] = bernoulli(gen, 0.4);
+    }
+    const Lights board = apply_presses(Lights(rows, cols, false), scramble);
+
+    const auto presses = solve_lights_out(board);
+    ASSERT_TRUE(presses.has_value()) << rows << " by " << cols;
+    ASSERT_EQ(apply_presses(board, *presses), Lights(rows, cols, false));
+    ASSERT_LE(lights_to_row(*presses).count(), lights_to_row(scramble).count());
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
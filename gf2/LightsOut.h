@@ -0,0 +1,114 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "../grid/Grid.h"
+#include "Gf2.h"
+
+// Lights Out: a grid of lights, some on. Pressing a light toggles it and
+// its four neighbors, and the goal is to turn every light off. Pressing
+// twice undoes a press and the order doesn't matter, so a solution is a
+// set of lights to press once each, and light i ends up toggled once for
+// every pressed light among itself and its neighbors. Over GF(2) that's
+// the linear system A * presses = board, where A[i][j] is 1 when pressing
+// j toggles i.
+//
+// Not every board can be solved: A is singular for some sizes, 5 by 5
+// among them (rank 23 of 25). The null space is then the sets of presses
+// that change nothing, and adding one to a solution gives another.
+
+using Lights = Grid<bool>;
+
+inline void press(Lights& board, Cell c) {
+  board[c] = !board[c];
+  for (Cell n : board.neighbors(c)) {
+    board[n] = !board[n];
+  }
+}
+
+inline Lights apply_presses(Lights board, const Lights& presses) {
+  assert(board.rows() == presses.rows() && board.cols() == presses.cols());
+  for (Cell c : presses.cells()) {
+    if (presses[c]) {
+      press(board, c);
+    }
+  }
+  return board;
+}
+
+// The lights row by row as a vector, and back
+inline BitRow lights_to_row(const Lights& board) {
+  BitRow row(board.size());
+  size_t i = 0;
+  for (Cell c : board.cells()) {
+    row.set(i++, board[c]);
+  }
+  return row;
+}
+
+inline Lights row_to_lights(const BitRow& row, int rows, int cols) {
+  Lights board(rows, cols, false);
+  size_t i = 0;
+  for (Cell c : board.cells()) {
+    board[c] = row.get(i++);
+  }
+  return board;
+}
+
+// A, with the lights numbered row by row. Symmetric, since neighboring is.
+inline Gf2Matrix lights_out_matrix(int rows, int cols) {
+  const Lights off(rows, cols, false);
+  Gf2Matrix a;
+  for (Cell c : off.cells()) {
+    Lights toggled = off;
+    press(toggled, c);
+    a.push_back(lights_to_row(toggled));
+  }
+  return a;
+}
+
+// More free variables than this and solve_lights_out stops looking for the
+// fewest presses
+constexpr size_t kMaxLightsOutNullity = 16;
+
+// The presses that turn every light off, or std::nullopt if the board
+// can't be solved. When there's more than one solution, the fewest
+// presses, found by trying each of the 2^nullity (at most
+// 2^kMaxLightsOutNullity) solutions.
+inline std::optional<Lights> solve_lights_out(const Lights& board) {
+  const Gf2Matrix a = lights_out_matrix(board.rows(), board.cols());
+  const auto solution = gf2_solve(a, lights_to_row(board));
+  if (!solution) {
+    return std::nullopt;
+  }
+  BitRow best = *solution;
+  const auto null_space = gf2_null_space(a, board.size());
+  if (null_space.size() <= kMaxLightsOutNullity) {
+    // In Gray code order each step adds one basis vector: the one numbered
+    // by the lowest set bit of the step
+    BitRow x = *solution;
+    for (uint64_t k = 1; k < (uint64_t{1} << null_space.size()); ++k) {
+      x ^= null_space[static_cast<size_t>(popcount(trailing_zeros_mask(k)))];
+      if (x.count() < best.count()) {
+        best = x;
+      }
+    }
+  }
+  return row_to_lights(best, board.rows(), board.cols());
+}
+
+// '#' for a light that's on, '.' for off
+inline Lights lights_from_strings(const std::vector<std::string>& lines) {
+  const Grid<char> chars = grid_from_strings(lines);
+  Lights board(chars.rows(), chars.cols(), false);
+  for (Cell c : chars.cells()) {
+    assert((chars[c] == '#' || chars[c] == '.') && "lights are # or .");
+    board[c] = chars[c] == '#';
+  }
+  return board;
+}
@@ -0,0 +1,176 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+#include "../errors/Error.h"
+#include "Graph.h"
+#include "TopologicalSort.h"
+
+// Dynamic programming on directed acyclic graphs.
+//
+// In topological order every edge points forward, so by the time a vertex is
+// reached, every path into it has been accounted for. That turns path
+// problems that are hard or slow on general graphs into one O(V + E) sweep:
+// the longest path (NP-hard in general), shortest paths with negative
+// weights (Bellman-Ford territory in general), and counting paths (infinite
+// in general, because of cycles).
+//
+// Everything here throws std::invalid_argument if the graph has a cycle;
+// the try_ versions return a kCycleDetected error instead.
+
+inline Result<std::vector<int>> try_topological_order(
+    const AdjacencyList& adj) {
+  auto order = topological_sort(adj);
+  if (!order) {
+    return Error{ErrorKind::kCycleDetected, "graph has a cycle"};
+  }
+  return std::move(*order);
+}
+
+inline std::vector<int> topological_order_or_throw(const AdjacencyList& adj) {
+  return try_topological_order(adj).or_throw();
+}
+
+struct DagPath {
+  int64_t length{0};
+  std::vector<int> vertices;
+};
+
+// The heaviest path anywhere in the DAG, e.g. the critical path of a project
+// whose edges are task durations. A single vertex is a path of length 0, so
+// the result is never negative. O(V + E).
+inline DagPath longest_path(const WeightedAdjacencyList& dag) {
+  const auto order = topological_order_or_throw(unweighted(dag));
+  DagPath result;
+  if (dag.empty()) {
+    return result;
+  }
+
+  // best[v] is the heaviest path ending at v, entered from parent[v]
+  std::vector<int64_t> best(dag.size(), 0);
+  std::vector<int> parent(dag.size(), -1);
+  for (int u : order) {
+    for (const auto& [v, weight] : dag[u]) {
+      if (best[u] + weight > best[v]) {
+        best[v] = best[u] + weight;
+        parent[v] = u;
+      }
+    }
+  }
+
+  int end = static_cast<int>(std::max_element(best.begin(), best.end()) -
+                             best.begin());
+  result.length = best[end];
+  for (int v = end; v != -1; v = parent[v]) {
+    result.vertices.push_back(v);
+  }
+  std::reverse(result.vertices.begin(), result.vertices.end());
+  return result;
+}
+
+// Number of distinct paths from source to target (1 if they're equal).
+// Counts grow exponentially with the depth of the DAG and wrap around modulo
+// 2^64. O(V + E).
+inline uint64_t count_paths(const AdjacencyList& dag, int source, int target) {
+  const auto order = topological_order_or_throw(dag);
+  std::vector<uint64_t> ways(dag.size(), 0);
+  ways[source] = 1;
+  for (int u : order) {
+    for (int v : dag[u]) {
+      ways[v] += ways[u];
+    }
+  }
+  return ways[target];
+}
+
+// Single-source shortest paths. Unlike Dijkstra, negative weights are fine.
+struct DagShortestPaths {
+  std::vector<std::optional<int64_t>> dist;  // std::nullopt if unreachable
+  std::vector<int> parent;                   // -1 for the source
+
+  // Vertices from the source to v, or empty if v is unreachable
+  std::vector<int> path_to(int v) const {
+    std::vector<int> path;
+    if (!dist[v]) {
+      return path;
+    }
+    for (; v != -1; v = parent[v]) {
+      path.push_back(v);
+    }
+    std::reverse(path.begin(), path.end());
+    return path;
+  }
+};
+
+inline DagShortestPaths dag_shortest_paths(const WeightedAdjacencyList& dag,
+                                           int source) {
+  const auto order = topological_order_or_throw(unweighted(dag));
+  DagShortestPaths result;
+  result.dist.assign(dag.size(), std::nullopt);
+  result.parent.assign(dag.size(), -1);
+  result.dist[source] = 0;
+  for (int u : order) {
+    if (!result.dist[u]) {
+      continue;
+    }
+    for (const auto& [v, weight] : dag[u]) {
+      const int64_t candidate = *result.dist[u] + weight;
+      if (!result.dist[v] || candidate < *result.dist[v]) {
+        result.dist[v] = candidate;
+        result.parent[v] = u;
+      }
+    }
+  }
+  return result;
+}
+
+// Brute force for the tests: follow every path by DFS. Exponential.
+
+inline void extend_paths(const WeightedAdjacencyList& dag, int u,
+                         int64_t length, int64_t& longest, int target,
+                         uint64_t& paths_to_target,
+                         std::vector<std::optional<int64_t>>& shortest) {
+  longest = std::max(longest, length);
+  if (u == target) {
+    ++paths_to_target;
+  }
+  if (!shortest[u] || length < *shortest[u]) {
+    shortest[u] = length;
+  }
+  for (const auto& [v, weight] : dag[u]) {
+    extend_paths(dag, v, length + weight, longest, target, paths_to_target,
+                 shortest);
+  }
+}
+
+inline int64_t longest_path_naive(const WeightedAdjacencyList& dag) {
+  int64_t longest = 0;
+  uint64_t paths = 0;
+  std::vector<std::optional<int64_t>> shortest(dag.size());
+  for (size_t s = 0; s < dag.size(); ++s) {
+    extend_paths(dag, static_cast<int>(s), 0, longest, -1, paths, shortest);
+  }
+  return longest;
+}
+
+inline uint64_t count_paths_naive(const WeightedAdjacencyList& dag,
+                                  int source, int target) {
+  int64_t longest = 0;
+  uint64_t paths = 0;
+  std::vector<std::optional<int64_t>> shortest(dag.size());
+  extend_paths(dag, source, 0, longest, target, paths, shortest);
+  return paths;
+}
+
+inline std::vector<std::optional<int64_t>> dag_shortest_paths_naive(
+    const WeightedAdjacencyList& dag, int source) {
+  int64_t longest = 0;
+  uint64_t paths = 0;
+  std::vector<std::optional<int64_t>> shortest(dag.size());
+  extend_paths(dag, source, 0, longest, -1, paths, shortest);
+  return shortest;
+}
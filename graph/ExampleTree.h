@@ -0,0 +1,20 @@
+#pragma once
+
+#include "Graph.h"
+
+/*
+ * The small tree the tree etudes' tests (lca/, hld/, euler-tour/) work
+ * through by hand, rooted at 0:
+ *
+ *          0
+ *        / | \
+ *       1  2  3
+ *      / \     \
+ *     4   5     6
+ *        / \
+ *       7   8
+ */
+inline AdjacencyList example_tree() {
+  return undirected_from_edges(
+      9, {{0, 1}, {0, 2}, {0, 3}, {1, 4}, {1, 5}, {3, 6}, {5, 7}, {5, 8}});
+}
@@ -0,0 +1,78 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <utility>
+#include <vector>
+
+// Graphs are adjacency lists over vertices 0..n-1: adj[u] lists the
+// neighbors of u. Undirected graphs (and trees) store every edge in both
+// directions.
+using AdjacencyList = std::vector<std::vector<int>>;
+
+using Edge = std::pair<int, int>;
+
+inline AdjacencyList directed_from_edges(size_t n,
+                                         const std::vector<Edge>& edges) {
+  AdjacencyList adj(n);
+  for (const auto& [u, v] : edges) {
+    assert(u >= 0 && static_cast<size_t>(u) < n && "vertex out of range");
+    assert(v >= 0 && static_cast<size_t>(v) < n && "vertex out of range");
+    adj[u].push_back(v);
+  }
+  return adj;
+}
+
+inline AdjacencyList undirected_from_edges(size_t n,
+                                           const std::vector<Edge>& edges) {
+  AdjacencyList adj(n);
+  for (const auto& [u, v] : edges) {
+    assert(u >= 0 && static_cast<size_t>(u) < n && "vertex out of range");
+    assert(v >= 0 && static_cast<size_t>(v) < n && "vertex out of range");
+    adj[u].push_back(v);
+    adj[v].push_back(u);
+  }
+  return adj;
+}
+
+// Weighted graphs list (neighbor, weight) pairs instead
+struct WeightedEdge {
+  int to;
+  int64_t weight;
+
+  bool operator==(const WeightedEdge&) const = default;
+};
+
+using WeightedAdjacencyList = std::vector<std::vector<WeightedEdge>>;
+
+// Directed, weighted edge u -> v
+struct Arc {
+  int from;
+  int to;
+  int64_t weight;
+};
+
+inline WeightedAdjacencyList weighted_from_arcs(size_t n,
+                                                const std::vector<Arc>& arcs) {
+  WeightedAdjacencyList adj(n);
+  for (const auto& arc : arcs) {
+    assert(arc.from >= 0 && static_cast<size_t>(arc.from) < n &&
+           "vertex out of range");
+    assert(arc.to >= 0 && static_cast<size_t>(arc.to) < n &&
+           "vertex out of range");
+    adj[arc.from].push_back({arc.to, arc.weight});
+  }
+  return adj;
+}
+
+// The same graph with the weights dropped
+inline AdjacencyList unweighted(const WeightedAdjacencyList& adj) {
+  AdjacencyList result(adj.size());
+  for (size_t u = 0; u < adj.size(); ++u) {
+    for (const auto& edge : adj[u]) {
+      result[u].push_back(edge.to);
+    }
+  }
+  return result;
+}
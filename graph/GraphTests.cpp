@@ -0,0 +1,303 @@
+#include <algorithm>
+#include <random>
+#include <stdexcept>
+#include <vector>
+
+#include "Dag.h"
+#include "Graph.h"
+#include "TopologicalSort.h"
+#include "Tree.h"
+#include "gtest/gtest.h"
+
+/*
+ * A spider with legs of length 1, 2, and 3:
+ *
+ *   1 - 0 - 2 - 3
+ *       |
+ *       4 - 5 - 6
+ */
+AdjacencyList spider() {
+  return undirected_from_edges(
+      7, {{0, 1}, {0, 2}, {2, 3}, {0, 4}, {4, 5}, {5, 6}});
+}
+
+AdjacencyList random_tree(std::mt19937& gen, size_t n) {
+  std::vector<Edge> edges;
+  for (size_t i = 1; i < n; ++i) {
+    int parent = std::uniform_int_distribution<int>(0, i - 1)(gen);
+    edges.emplace_back(parent, static_cast<int>(i));
+  }
+  return undirected_from_edges(n, edges);
+}
+
+// Random DAG: edges only go from lower to higher rank, and the ranks are a
+// random permutation of the vertices
+WeightedAdjacencyList random_dag(std::mt19937& gen, size_t n, double density,
+                                 int64_t min_weight, int64_t max_weight) {
+  std::vector<int> label(n);
+  for (size_t i = 0; i < n; ++i) {
+    label[i] = static_cast<int>(i);
+  }
+  std::shuffle(label.begin(), label.end(), gen);
+  std::bernoulli_distribution has_edge(density);
+  std::uniform_int_distribution<int64_t> weight(min_weight, max_weight);
+  std::vector<Arc> arcs;
+  for (size_t i = 0; i < n; ++i) {
+    for (size_t j = i + 1; j < n; ++j) {
+      if (has_edge(gen)) {
+        arcs.push_back({label[i], label[j], weight(gen)});
+      }
+    }
+  }
+  return weighted_from_arcs(n, arcs);
+}
+
+/*
+ * Two routes from 0 to 5, and a shortcut with a negative edge:
+ *
+ *   0 --3--> 1 --4--> 3 --2--> 5
+ *   |        |                 ^
+ *   2        1                 |
+ *   v        v                 |
+ *   2 --6--> 4 -------(-2)-----+
+ */
+WeightedAdjacencyList example_dag() {
+  return weighted_from_arcs(6, {{0, 1, 3},
+                                {0, 2, 2},
+                                {1, 3, 4},
+                                {1, 4, 1},
+                                {2, 4, 6},
+                                {3, 5, 2},
+                                {4, 5, -2}});
+}
+
+TEST(Graph, FromEdges) {
+  auto directed = directed_from_edges(3, {{0, 1}, {0, 2}, {2, 1}});
+  auto undirected = undirected_from_edges(3, {{0, 1}, {0, 2}});
+
+  EXPECT_EQ(directed, (AdjacencyList{{1, 2}, {}, {1}}));
+  EXPECT_EQ(undirected, (AdjacencyList{{1, 2}, {0}, {0}}));
+}
+
+TEST(Tree, BfsDistances) {
+  EXPECT_EQ(bfs_distances(spider(), 1),
+            (std::vector<int>{1, 0, 2, 3, 2, 3, 4}));
+
+  auto two_parts = undirected_from_edges(3, {{0, 1}});
+  EXPECT_EQ(bfs_distances(two_parts, 0), (std::vector<int>{0, 1, -1}));
+}
+
+TEST(Tree, IsTree) {
+  EXPECT_TRUE(is_tree(spider()));
+  EXPECT_TRUE(is_tree(AdjacencyList{}));
+  EXPECT_TRUE(is_tree(AdjacencyList(1)));
+  EXPECT_FALSE(is_tree(AdjacencyList(2)));
+  EXPECT_FALSE(is_tree(undirected_from_edges(3, {{0, 1}, {1, 2}, {2, 0}})));
+  // Right number of edges, but a cycle and an isolated vertex
+  EXPECT_FALSE(is_tree(undirected_from_edges(4, {{0, 1}, {1, 2}, {2, 0}})));
+}
+
+TEST(Tree, Diameter) {
+  auto diameter = tree_diameter(spider());
+
+  EXPECT_EQ(diameter.length, 5);
+  ASSERT_EQ(diameter.path.size(), 6u);
+  // The path may run in either direction
+  if (diameter.path.front() != 3) {
+    std::reverse(diameter.path.begin(), diameter.path.end());
+  }
+  EXPECT_EQ(diameter.path, (std::vector<int>{3, 2, 0, 4, 5, 6}));
+}
+
+TEST(Tree, DiameterOfTinyTrees) {
+  EXPECT_EQ(tree_diameter(AdjacencyList{}).length, 0);
+  EXPECT_TRUE(tree_diameter(AdjacencyList{}).path.empty());
+  EXPECT_EQ(tree_diameter(AdjacencyList(1)).path, std::vector<int>{0});
+  EXPECT_EQ(tree_diameter(undirected_from_edges(2, {{0, 1}})).length, 1);
+  EXPECT_THROW(tree_diameter(AdjacencyList(2)), std::invalid_argument);
+}
+
+TEST(Tree, Center) {
+  // The diameter 3-2-0-4-5-6 has an even number of vertices
+  EXPECT_EQ(tree_center(spider()), (std::vector<int>{0, 4}));
+
+  auto path = undirected_from_edges(5, {{0, 1}, {1, 2}, {2, 3}, {3, 4}});
+  EXPECT_EQ(tree_center(path), std::vector<int>{2});
+
+  auto star = undirected_from_edges(4, {{0, 1}, {0, 2}, {0, 3}});
+  EXPECT_EQ(tree_center(star), std::vector<int>{0});
+
+  EXPECT_EQ(tree_center(AdjacencyList(1)), std::vector<int>{0});
+  EXPECT_EQ(tree_center(undirected_from_edges(2, {{0, 1}})),
+            (std::vector<int>{0, 1}));
+  EXPECT_THROW(tree_center(AdjacencyList(3)), std::invalid_argument);
+}
+
+TEST(Tree, MatchesBruteForce) {
+  std::mt19937 gen(129);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(1, 60)(gen);
+    auto tree = random_tree(gen, n);
+    auto diameter = tree_diameter(tree);
+    auto center = tree_center(tree);
+
+    ASSERT_EQ(diameter.length, tree_diameter_naive(tree));
+    ASSERT_EQ(diameter.path.size(), diameter.length + 1u);
+    for (size_t i = 1; i < diameter.path.size(); ++i) {
+      const auto& neighbors = tree[diameter.path[i - 1]];
+      ASSERT_NE(std::find(neighbors.begin(), neighbors.end(),
+                          diameter.path[i]),
+                neighbors.end());
+    }
+    ASSERT_EQ(center, tree_center_naive(tree));
+
+    // The centers are the middle of the diameter
+    std::vector<int> middle = {diameter.path[diameter.length / 2]};
+    if (diameter.length % 2 == 1) {
+      middle.push_back(diameter.path[diameter.length / 2 + 1]);
+      std::sort(middle.begin(), middle.end());
+    }
+    ASSERT_EQ(center, middle);
+  }
+}
+
+TEST(Graph, Unweighted) {
+  EXPECT_EQ(unweighted(example_dag()),
+            (AdjacencyList{{1, 2}, {3, 4}, {4}, {5}, {5}, {}}));
+}
+
+TEST(TopologicalSort, Example) {
+  auto order = topological_sort(unweighted(example_dag()));
+
+  ASSERT_TRUE(order.has_value());
+  EXPECT_EQ(*order, (std::vector<int>{0, 1, 2, 3, 4, 5}));
+}
+
+TEST(TopologicalSort, DetectsCycles) {
+  auto cycle = directed_from_edges(4, {{0, 1}, {1, 2}, {2, 3}, {3, 1}});
+  auto self_loop = directed_from_edges(1, {{0, 0}});
+
+  EXPECT_FALSE(topological_sort(cycle).has_value());
+  EXPECT_FALSE(topological_sort(self_loop).has_value());
+  EXPECT_TRUE(topological_sort(AdjacencyList{}).has_value());
+}
+
+TEST(TopologicalSort, IsTopologicalOrder) {
+  auto dag = unweighted(example_dag());
+
+  EXPECT_TRUE(is_topological_order(dag, {0, 2, 1, 4, 3, 5}));
+  EXPECT_FALSE(is_topological_order(dag, {0, 1, 4, 2, 3, 5}));
+  EXPECT_FALSE(is_topological_order(dag, {0, 1, 2, 3, 4}));
+  EXPECT_FALSE(is_topological_order(dag, {0, 1, 1, 3, 4, 5}));
+}
+
+TEST(TopologicalSort, RandomDags) {
+  std::mt19937 gen(135);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(0, 40)(gen);
+    auto dag = unweighted(random_dag(gen, n, 0.2, 0, 0));
+    auto order = topological_sort(dag);
+
+    ASSERT_TRUE(order.has_value());
+    ASSERT_TRUE(is_topological_order(dag, *order));
+  }
+}
+
+TEST(Dag, LongestPath) {
+  auto path = longest_path(example_dag());
+
+  EXPECT_EQ(path.length, 9);
+  EXPECT_EQ(path.vertices, (std::vector<int>{0, 1, 3, 5}));
+}
+
+TEST(Dag, LongestPathOfEdgelessGraph) {
+  auto path = longest_path(WeightedAdjacencyList(3));
+
+  EXPECT_EQ(path.length, 0);
+  EXPECT_EQ(path.vertices.size(), 1u);
+  EXPECT_TRUE(longest_path({}).vertices.empty());
+}
+
+TEST(Dag, CountPaths) {
+  auto dag = unweighted(example_dag());
+
+  EXPECT_EQ(count_paths(dag, 0, 5), 3u);
+  EXPECT_EQ(count_paths(dag, 0, 4), 2u);
+  EXPECT_EQ(count_paths(dag, 3, 3), 1u);
+  EXPECT_EQ(count_paths(dag, 5, 0), 0u);
+}
+
+TEST(Dag, CountPathsGrowsExponentially) {
+  // A ladder of diamonds: each doubles the number of paths
+  const int diamonds = 40;
+  std::vector<Edge> edges;
+  for (int i = 0; i < diamonds; ++i) {
+    int top = 3 * i;
+    edges.insert(edges.end(), {{top, top + 1},
+                               {top, top + 2},
+                               {top + 1, top + 3},
+                               {top + 2, top + 3}});
+  }
+  auto dag = directed_from_edges(3 * diamonds + 1, edges);
+
+  EXPECT_EQ(count_paths(dag, 0, 3 * diamonds), uint64_t{1} << diamonds);
+}
+
+TEST(Dag, ShortestPaths) {
+  auto paths = dag_shortest_paths(example_dag(), 0);
+
+  EXPECT_EQ(paths.dist[5], std::optional<int64_t>(2));
+  EXPECT_EQ(paths.path_to(5), (std::vector<int>{0, 1, 4, 5}));
+  EXPECT_EQ(paths.dist[0], std::optional<int64_t>(0));
+  EXPECT_EQ(paths.path_to(0), std::vector<int>{0});
+
+  auto from_three = dag_shortest_paths(example_dag(), 3);
+  EXPECT_FALSE(from_three.dist[0].has_value());
+  EXPECT_TRUE(from_three.path_to(0).empty());
+}
+
+TEST(Dag, RejectsCycles) {
+  auto cycle = weighted_from_arcs(2, {{0, 1, 1}, {1, 0, 1}});
+
+  EXPECT_THROW(longest_path(cycle), std::invalid_argument);
+  EXPECT_THROW(count_paths(unweighted(cycle), 0, 1), std::invalid_argument);
+  EXPECT_THROW(dag_shortest_paths(cycle, 0), std::invalid_argument);
+  EXPECT_EQ(try_topological_order(unweighted(cycle)).error(),
+            (Error{ErrorKind::kCycleDetected, "graph has a cycle"}));
+  EXPECT_EQ(try_topological_order(directed_from_edges(2, {{1, 0}})),
+            (std::vector<int>{1, 0}));
+}
+
+TEST(Dag, MatchesBruteForce) {
+  std::mt19937 gen(136);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(1, 12)(gen);
+    auto dag = random_dag(gen, n, 0.35, -5, 10);
+    std::uniform_int_distribution<int> vertex(0, n - 1);
+    int source = vertex(gen);
+    int target = vertex(gen);
+
+    auto longest = longest_path(dag);
+    ASSERT_EQ(longest.length, longest_path_naive(dag));
+    int64_t walked = 0;
+    for (size_t i = 1; i < longest.vertices.size(); ++i) {
+      const auto& out = dag[longest.vertices[i - 1]];
+      auto edge = std::find_if(out.begin(), out.end(), [&](const auto& e) {
+        return e.to == longest.vertices[i];
+      });
+      ASSERT_NE(edge, out.end());
+      walked += edge->weight;
+    }
+    ASSERT_EQ(walked, longest.length);
+
+    ASSERT_EQ(count_paths(unweighted(dag), source, target),
+              count_paths_naive(dag, source, target));
+    ASSERT_EQ(dag_shortest_paths(dag, source).dist,
+              dag_shortest_paths_naive(dag, source));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
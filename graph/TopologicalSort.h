@@ -0,0 +1,68 @@
+#pragma once
+
+#include <cstddef>
+#include <optional>
+#include <vector>
+
+#include "Graph.h"
+
+// Topological order of a directed graph: every edge u -> v has u before v.
+// One exists exactly when the graph has no cycle.
+
+// Kahn's algorithm: repeatedly output a vertex with no incoming edges left,
+// and delete its outgoing edges. If the vertices run out before every vertex
+// is output, the rest all lie on or behind a cycle. Returns std::nullopt in
+// that case. O(V + E).
+inline std::optional<std::vector<int>> topological_sort(
+    const AdjacencyList& adj) {
+  const size_t n = adj.size();
+  std::vector<size_t> in_degree(n, 0);
+  for (const auto& neighbors : adj) {
+    for (int v : neighbors) {
+      ++in_degree[v];
+    }
+  }
+
+  std::vector<int> order;
+  order.reserve(n);
+  for (size_t v = 0; v < n; ++v) {
+    if (in_degree[v] == 0) {
+      order.push_back(static_cast<int>(v));
+    }
+  }
+  // order doubles as the queue of vertices whose in-edges are all gone
+  for (size_t i = 0; i < order.size(); ++i) {
+    for (int v : adj[order[i]]) {
+      if (--in_degree[v] == 0) {
+        order.push_back(v);
+      }
+    }
+  }
+
+  if (order.size() != n) {
+    return std::nullopt;
+  }
+  return order;
+}
+
+inline bool is_topological_order(const AdjacencyList& adj,
+                                 const std::vector<int>& order) {
+  if (order.size() != adj.size()) {
+    return false;
+  }
+  std::vector<int> position(adj.size(), -1);
+  for (size_t i = 0; i < order.size(); ++i) {
+    if (position[order[i]] != -1) {
+      return false;
+    }
+    position[order[i]] = static_cast<int>(i);
+  }
+  for (size_t u = 0; u < adj.size(); ++u) {
+    for (int v : adj[u]) {
+      if (position[u] >= position[v]) {
+        return false;
+      }
+    }
+  }
+  return true;
+}
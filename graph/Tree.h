@@ -0,0 +1,203 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <stdexcept>
+#include <vector>
+
+#include "Graph.h"
+
+// Utilities for unrooted trees stored as undirected adjacency lists.
+
+// Distance in edges from source to every vertex, -1 if unreachable
+inline std::vector<int> bfs_distances(const AdjacencyList& adj, int source) {
+  std::vector<int> dist(adj.size(), -1);
+  std::vector<int> queue = {source};
+  dist[source] = 0;
+  for (size_t i = 0; i < queue.size(); ++i) {
+    const int u = queue[i];
+    for (int v : adj[u]) {
+      if (dist[v] == -1) {
+        dist[v] = dist[u] + 1;
+        queue.push_back(v);
+      }
+    }
+  }
+  return dist;
+}
+
+// A connected graph with n - 1 edges. The empty graph counts as a tree.
+inline bool is_tree(const AdjacencyList& adj) {
+  if (adj.empty()) {
+    return true;
+  }
+  size_t degree_sum = 0;
+  for (const auto& neighbors : adj) {
+    degree_sum += neighbors.size();
+  }
+  if (degree_sum != 2 * (adj.size() - 1)) {
+    return false;
+  }
+  auto dist = bfs_distances(adj, 0);
+  return std::find(dist.begin(), dist.end(), -1) == dist.end();
+}
+
+struct Diameter {
+  int length{0};          // In edges
+  std::vector<int> path;  // length + 1 vertices, one end to the other
+};
+
+// Longest path in the tree, by two BFS passes: the vertex farthest from any
+// start vertex is an end of some diameter, and the vertex farthest from that
+// end is the other one. O(n).
+//
+// Why the first claim holds: suppose a is farthest from s but the diameter
+// runs from u to v. Walk from s towards the u-v path; from where you hit it,
+// a is at least as far as u or v, so swapping that end for a can't make the
+// path shorter.
+inline Diameter tree_diameter(const AdjacencyList& tree) {
+  if (!is_tree(tree)) {
+    throw std::invalid_argument("tree_diameter needs a tree");
+  }
+  Diameter result;
+  if (tree.empty()) {
+    return result;
+  }
+
+  auto first = bfs_distances(tree, 0);
+  const int a = static_cast<int>(
+      std::max_element(first.begin(), first.end()) - first.begin());
+  auto from_a = bfs_distances(tree, a);
+  const int b = static_cast<int>(
+      std::max_element(from_a.begin(), from_a.end()) - from_a.begin());
+
+  // Walk back from b, always to a neighbor one step closer to a
+  result.length = from_a[b];
+  for (int v = b; v != a;) {
+    result.path.push_back(v);
+    for (int u : tree[v]) {
+      if (from_a[u] == from_a[v] - 1) {
+        v = u;
+        break;
+      }
+    }
+  }
+  result.path.push_back(a);
+  return result;
+}
+
+// O(n^2): BFS from every vertex
+inline int tree_diameter_naive(const AdjacencyList& tree) {
+  int best = 0;
+  for (size_t s = 0; s < tree.size(); ++s) {
+    auto dist = bfs_distances(tree, static_cast<int>(s));
+    best = std::max(best, *std::max_element(dist.begin(), dist.end()));
+  }
+  return best;
+}
+
+// The vertices that minimize the distance to the farthest vertex. A tree has
+// one center or two adjacent ones: the middle of any diameter.
+//
+// Found by peeling leaves layer by layer, like an onion, until at most two
+// vertices remain. Returned in ascending order.
+inline std::vector<int> tree_center(const AdjacencyList& tree) {
+  if (!is_tree(tree)) {
+    throw std::invalid_argument("tree_center needs a tree");
+  }
+  const size_t n = tree.size();
+  if (n <= 2) {
+    std::vector<int> all(n);
+    for (size_t v = 0; v < n; ++v) {
+      all[v] = static_cast<int>(v);
+    }
+    return all;
+  }
+
+  std::vector<size_t> degree(n);
+  std::vector<int> leaves;
+  for (size_t v = 0; v < n; ++v) {
+    degree[v] = tree[v].size();
+    if (degree[v] == 1) {
+      leaves.push_back(static_cast<int>(v));
+    }
+  }
+  size_t remaining = n;
+  while (remaining > 2) {
+    remaining -= leaves.size();
+    std::vector<int> next;
+    for (int leaf : leaves) {
+      for (int v : tree[leaf]) {
+        if (--degree[v] == 1) {
+          next.push_back(v);
+        }
+      }
+    }
+    leaves = std::move(next);
+  }
+  std::sort(leaves.begin(), leaves.end());
+  return leaves;
+}
+
+// O(n^2): the eccentricity of every vertex, by BFS
+inline std::vector<int> tree_center_naive(const AdjacencyList& tree) {
+  std::vector<int> eccentricity;
+  for (size_t s = 0; s < tree.size(); ++s) {
+    auto dist = bfs_distances(tree, static_cast<int>(s));
+    eccentricity.push_back(*std::max_element(dist.begin(), dist.end()));
+  }
+  std::vector<int> centers;
+  if (tree.empty()) {
+    return centers;
+  }
+  const int best = *std::min_element(eccentricity.begin(), eccentricity.end());
+  for (size_t v = 0; v < tree.size(); ++v) {
+    if (eccentricity[v] == best) {
+      centers.push_back(static_cast<int>(v));
+    }
+  }
+  return centers;
+}
+
+// Parent and depth of every vertex, and the vertices in BFS order from the
+// root. Throws std::invalid_argument if the graph isn't a tree.
+struct RootedTree {
+  int root;
+  std::vector<int> parent;  // -1 for the root
+  std::vector<int> depth;
+  std::vector<int> order;
+
+  RootedTree(const AdjacencyList& tree, int root)
+      : root(root), parent(tree.size(), -1), depth(tree.size(), -1) {
+    const size_t n = tree.size();
+    if (root < 0 || static_cast<size_t>(root) >= n) {
+      throw std::invalid_argument("root out of range");
+    }
+    size_t degree_sum = 0;
+    for (const auto& neighbors : tree) {
+      degree_sum += neighbors.size();
+    }
+    if (degree_sum != 2 * (n - 1)) {
+      throw std::invalid_argument("a tree on n vertices has n - 1 edges");
+    }
+
+    order.reserve(n);
+    order.push_back(root);
+    depth[root] = 0;
+    for (size_t i = 0; i < order.size(); ++i) {
+      const int u = order[i];
+      for (int v : tree[u]) {
+        if (depth[v] == -1) {
+          depth[v] = depth[u] + 1;
+          parent[v] = u;
+          order.push_back(v);
+        }
+      }
+    }
+    if (order.size() != n) {
+      throw std::invalid_argument("tree is not connected");
+    }
+  }
+
+  size_t size() const { return parent.size(); }
+};
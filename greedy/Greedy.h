@@ -0,0 +1,233 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <numeric>
+#include <optional>
+#include <vector>
+
+// Greedy algorithms commit to the choice that looks best right now and never
+// go back on it. That's only correct when the problem has the right
+// structure, so each one here comes with an exhaustive version to check it
+// against.
+
+// Activity selection: the most activities that don't overlap. Activities
+// occupy [start, finish), so one may start right when another finishes.
+struct Activity {
+  int64_t start;
+  int64_t finish;
+};
+
+// Always take the activity that finishes first among those that still fit:
+// it leaves the most room for the rest, so swapping it into any optimal
+// selection in place of its first activity keeps it optimal. O(n log n).
+// Returns indices, in order of finish time.
+inline std::vector<int> select_activities(
+    const std::vector<Activity>& activities) {
+  std::vector<int> by_finish(activities.size());
+  std::iota(by_finish.begin(), by_finish.end(), 0);
+  std::stable_sort(by_finish.begin(), by_finish.end(), [&](int a, int b) {
+    return activities[a].finish < activities[b].finish;
+  });
+  std::vector<int> chosen;
+  for (int i : by_finish) {
+    if (chosen.empty() ||
+        activities[i].start >= activities[chosen.back()].finish) {
+      chosen.push_back(i);
+    }
+  }
+  return chosen;
+}
+
+// O(2^n * n^2): the largest subset with no overlapping pair
+inline size_t max_activities_naive(const std::vector<Activity>& activities) {
+  assert(activities.size() < 32);
+  size_t best = 0;
+  for (uint32_t mask = 0; mask < (uint32_t{1} << activities.size()); ++mask) {
+    bool compatible = true;
+    for (size_t a = 0; a < activities.size() && compatible; ++a) {
+      for (size_t b = a + 1; b < activities.size() && compatible; ++b) {
+        compatible = !(mask >> a & 1 && mask >> b & 1 &&
+                       activities[a].start < activities[b].finish &&
+                       activities[b].start < activities[a].finish);
+      }
+    }
+    if (compatible) {
+      best = std::max(best, static_cast<size_t>(__builtin_popcount(mask)));
+    }
+  }
+  return best;
+}
+
+// Fractional knapsack: items can be cut, and a fraction of an item is worth
+// that fraction of its value. Fill up with the best value per unit of weight
+// first. Any cargo with less of a denser item and more of a sparser one can
+// trade weight between them for more value, so the greedy's is optimal.
+// Weightless items have no density to sort by; they take no capacity, so
+// they all go in whole, first. O(n log n).
+struct Item {
+  double weight;
+  double value;
+};
+
+inline double fractional_knapsack(const std::vector<Item>& items,
+                                  double capacity) {
+  double value = 0.0;
+  std::vector<Item> by_density;
+  for (const auto& item : items) {
+    if (item.weight == 0) {
+      value += item.value;
+    } else {
+      by_density.push_back(item);
+    }
+  }
+  std::sort(by_density.begin(), by_density.end(),
+            [](const Item& a, const Item& b) {
+              return a.value * b.weight > b.value * a.weight;
+            });
+  for (const auto& item : by_density) {
+    if (capacity <= 0) {
+      break;
+    }
+    const double taken = std::min(item.weight, capacity);
+    value += item.value * taken / item.weight;
+    capacity -= taken;
+  }
+  return value;
+}
+
+// O(2^n * n). Exchanging weight as above, an optimal cargo has at most one
+// item cut. So try every set of whole items that fits, topped up with as
+// much as fits of one more item, or none.
+inline double fractional_knapsack_naive(const std::vector<Item>& items,
+                                        double capacity) {
+  assert(items.size() < 32);
+  double best = 0.0;
+  for (uint32_t mask = 0; mask < (uint32_t{1} << items.size()); ++mask) {
+    double weight = 0.0;
+    double value = 0.0;
+    for (size_t i = 0; i < items.size(); ++i) {
+      if (mask >> i & 1) {
+        weight += items[i].weight;
+        value += items[i].value;
+      }
+    }
+    if (weight > capacity) {
+      continue;
+    }
+    best = std::max(best, value);
+    for (size_t i = 0; i < items.size(); ++i) {
+      if (!(mask >> i & 1) && items[i].weight > 0) {
+        const double taken = std::min(items[i].weight, capacity - weight);
+        best = std::max(best, value + items[i].value * taken / items[i].weight);
+      }
+    }
+  }
+  return best;
+}
+
+// Gas station: stations around a circular route, where station i has gas[i]
+// to give and getting on to station i + 1 burns cost[i]. Starting with an
+// empty tank, from which station can the whole loop be driven?
+//
+// If the tank runs dry on the way from a to b, then no station between a
+// and b works either: each was reached from a with a tank that wasn't
+// empty, and still couldn't make it. So the next candidate is b + 1. And if
+// the total gas covers the total cost, the last candidate standing makes it
+// all the way round. O(n).
+inline std::optional<size_t> gas_station_start(
+    const std::vector<int64_t>& gas, const std::vector<int64_t>& cost) {
+  assert(gas.size() == cost.size());
+  int64_t total = 0;
+  int64_t tank = 0;
+  size_t start = 0;
+  for (size_t i = 0; i < gas.size(); ++i) {
+    const int64_t net = gas[i] - cost[i];
+    total += net;
+    tank += net;
+    if (tank < 0) {
+      start = i + 1;
+      tank = 0;
+    }
+  }
+  if (gas.empty() || total < 0) {
+    return std::nullopt;
+  }
+  return start;
+}
+
+// O(n^2): drive from every station. The greedy finds the first that works.
+inline std::optional<size_t> gas_station_start_naive(
+    const std::vector<int64_t>& gas, const std::vector<int64_t>& cost) {
+  const size_t n = gas.size();
+  for (size_t start = 0; start < n; ++start) {
+    int64_t tank = 0;
+    bool made_it = true;
+    for (size_t k = 0; k < n && made_it; ++k) {
+      const size_t i = (start + k) % n;
+      tank += gas[i] - cost[i];
+      made_it = tank >= 0;
+    }
+    if (made_it) {
+      return start;
+    }
+  }
+  return std::nullopt;
+}
+
+// Jump game: from position i, jump ahead by up to reach[i]. The fewest jumps
+// from the start to the last position, if it can be reached at all.
+//
+// Think of it as BFS by levels: the positions reachable in exactly k jumps
+// form an interval, and the next level extends to the farthest any of them
+// can reach. O(n).
+inline std::optional<int> min_jumps(const std::vector<int>& reach) {
+  if (reach.empty()) {
+    return std::nullopt;
+  }
+  const size_t last = reach.size() - 1;
+  int jumps = 0;
+  size_t level_end = 0;  // Farthest position within `jumps` jumps
+  size_t farthest = 0;   // Farthest position within `jumps + 1` jumps
+  for (size_t i = 0; i < last; ++i) {
+    if (i > level_end) {
+      return std::nullopt;  // Out of range of every jump so far
+    }
+    farthest = std::max(farthest, i + static_cast<size_t>(reach[i]));
+    if (i == level_end && farthest > level_end) {
+      ++jumps;
+      level_end = farthest;
+    }
+  }
+  if (level_end < last) {
+    return std::nullopt;
+  }
+  return jumps;
+}
+
+inline bool can_jump(const std::vector<int>& reach) {
+  return min_jumps(reach).has_value();
+}
+
+// O(n^2) dynamic programming: the fewest jumps to each position, from every
+// position before it
+inline std::optional<int> min_jumps_naive(const std::vector<int>& reach) {
+  if (reach.empty()) {
+    return std::nullopt;
+  }
+  std::vector<std::optional<int>> jumps(reach.size());
+  jumps[0] = 0;
+  for (size_t i = 0; i < reach.size(); ++i) {
+    if (!jumps[i]) {
+      continue;
+    }
+    for (size_t j = i + 1; j < reach.size() && j <= i + reach[i]; ++j) {
+      if (!jumps[j] || *jumps[i] + 1 < *jumps[j]) {
+        jumps[j] = *jumps[i] + 1;
+      }
+    }
+  }
+  return jumps.back();
+}
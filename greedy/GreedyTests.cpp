@@ -0,0 +1,121 @@
+#include <cstdint>
+#include <optional>
+#include <random>
+#include <vector>
+
+#include "Greedy.h"
+#include "gtest/gtest.h"
+
+TEST(Greedy, ActivitySelectionExample) {
+  std::vector<Activity> activities = {{1, 4}, {3, 5}, {0, 6},  {5, 7},
+                                      {3, 9}, {5, 9}, {6, 10}, {8, 11},
+                                      {8, 12}, {2, 14}, {12, 16}};
+  EXPECT_EQ(select_activities(activities), (std::vector<int>{0, 3, 7, 10}));
+  EXPECT_TRUE(select_activities({}).empty());
+  // Touching intervals don't overlap
+  EXPECT_EQ(select_activities({{0, 1}, {1, 2}, {2, 3}}).size(), 3u);
+}
+
+TEST(Greedy, ActivitySelectionMatchesBruteForce) {
+  std::mt19937 gen(155);
+  std::uniform_int_distribution<int64_t> time(0, 20);
+  std::uniform_int_distribution<int64_t> length(1, 8);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<Activity> activities(
+        std::uniform_int_distribution<size_t>(0, 12)(gen));
+    for (auto& a : activities) {
+      a.start = time(gen);
+      a.finish = a.start + length(gen);
+    }
+    auto chosen = select_activities(activities);
+
+    ASSERT_EQ(chosen.size(), max_activities_naive(activities));
+    for (size_t i = 1; i < chosen.size(); ++i) {
+      ASSERT_GE(activities[chosen[i]].start,
+                activities[chosen[i - 1]].finish);
+    }
+  }
+}
+
+TEST(Greedy, FractionalKnapsackExample) {
+  std::vector<Item> items = {{10, 60}, {20, 100}, {30, 120}};
+  // All of the first two, and two thirds of the third
+  EXPECT_DOUBLE_EQ(fractional_knapsack(items, 50), 240);
+  EXPECT_DOUBLE_EQ(fractional_knapsack(items, 0), 0);
+  EXPECT_DOUBLE_EQ(fractional_knapsack(items, 100), 280);
+  EXPECT_DOUBLE_EQ(fractional_knapsack({}, 10), 0);
+}
+
+TEST(Greedy, FractionalKnapsackWeightlessItems) {
+  std::vector<Item> items = {{0, 5}, {10, 60}, {0, 0}, {0, 7}};
+  // They fit even when nothing else does
+  EXPECT_DOUBLE_EQ(fractional_knapsack(items, 0), 12);
+  EXPECT_DOUBLE_EQ(fractional_knapsack(items, 5), 42);
+  EXPECT_DOUBLE_EQ(fractional_knapsack(items, 20), 72);
+  EXPECT_DOUBLE_EQ(fractional_knapsack_naive(items, 5), 42);
+}
+
+TEST(Greedy, FractionalKnapsackMatchesBruteForce) {
+  std::mt19937 gen(156);
+  std::uniform_int_distribution<int> amount(1, 30);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<Item> items(std::uniform_int_distribution<size_t>(0, 10)(gen));
+    for (auto& item : items) {
+      // Some weightless
+      item = {static_cast<double>(amount(gen) - 1),
+              static_cast<double>(amount(gen))};
+    }
+    const double capacity = std::uniform_int_distribution<int>(0, 100)(gen);
+
+    ASSERT_NEAR(fractional_knapsack(items, capacity),
+                fractional_knapsack_naive(items, capacity), 1e-9);
+  }
+}
+
+TEST(Greedy, GasStationExample) {
+  EXPECT_EQ(gas_station_start({1, 2, 3, 4, 5}, {3, 4, 5, 1, 2}), 3u);
+  EXPECT_EQ(gas_station_start({2, 3, 4}, {3, 4, 3}), std::nullopt);
+  EXPECT_EQ(gas_station_start({5}, {5}), 0u);
+  EXPECT_EQ(gas_station_start({}, {}), std::nullopt);
+}
+
+TEST(Greedy, GasStationMatchesBruteForce) {
+  std::mt19937 gen(157);
+  std::uniform_int_distribution<int64_t> amount(0, 10);
+  for (int trial = 0; trial < 500; ++trial) {
+    const size_t n = std::uniform_int_distribution<size_t>(0, 10)(gen);
+    std::vector<int64_t> gas(n);
+    std::vector<int64_t> cost(n);
+    for (size_t i = 0; i < n; ++i) {
+      gas[i] = amount(gen);
+      cost[i] = amount(gen);
+    }
+    ASSERT_EQ(gas_station_start(gas, cost), gas_station_start_naive(gas, cost));
+  }
+}
+
+TEST(Greedy, JumpGameExample) {
+  EXPECT_EQ(min_jumps({2, 3, 1, 1, 4}), 2);
+  EXPECT_EQ(min_jumps({3, 2, 1, 0, 4}), std::nullopt);
+  EXPECT_FALSE(can_jump({3, 2, 1, 0, 4}));
+  EXPECT_TRUE(can_jump({0}));
+  EXPECT_EQ(min_jumps({0}), 0);
+  EXPECT_EQ(min_jumps({}), std::nullopt);
+}
+
+TEST(Greedy, JumpGameMatchesBruteForce) {
+  std::mt19937 gen(158);
+  std::uniform_int_distribution<int> reach(0, 4);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<int> jumps(std::uniform_int_distribution<size_t>(0, 20)(gen));
+    for (auto& r : jumps) {
+      r = reach(gen);
+    }
+    ASSERT_EQ(min_jumps(jumps), min_jumps_naive(jumps));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
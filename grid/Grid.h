@@ -0,0 +1,261 @@
+#pragma once
+
+#include <algorithm>
+#include <array>
+#include <cassert>
+#include <compare>
+#include <cstddef>
+#include <iterator>
+#include <optional>
+#include <queue>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Grids for the 2D etudes: a rectangle of values addressed by cell, the
+// cells next to a cell, and the searches and DPs that come up over and
+// over on them.
+
+struct Cell {
+  int row;
+  int col;
+
+  auto operator<=>(const Cell&) const = default;
+};
+
+// Four neighbors across the sides of a cell, or eight with the corners
+enum class Connectivity { kFour, kEight };
+
+// Offsets in clockwise order from north; the first four are the sides
+constexpr std::array<Cell, 8> kNeighborOffsets = {
+    {{-1, 0}, {0, 1}, {1, 0}, {0, -1}, {-1, 1}, {1, 1}, {1, -1}, {-1, -1}}};
+
+// The neighbors of a cell that are in bounds, as a range for a for loop
+class Neighbors {
+ public:
+  Neighbors(Cell center, int rows, int cols, Connectivity connectivity)
+      : center_(center),
+        rows_(rows),
+        cols_(cols),
+        count_(connectivity == Connectivity::kFour ? 4 : 8) {}
+
+  class iterator {
+   public:
+    using iterator_category = std::input_iterator_tag;
+    using value_type = Cell;
+    using difference_type = std::ptrdiff_t;
+    using pointer = void;
+    using reference = Cell;
+
+    iterator() = default;
+    iterator(const Neighbors* range, size_t i) : range_(range), i_(i) {
+      skip();
+    }
+
+    Cell operator*() const {
+      return {range_->center_.row + kNeighborOffsets[i_].row,
+              range_->center_.col + kNeighborOffsets[i_].col};
+    }
+
+    iterator& operator++() {
+      ++i_;
+      skip();
+      return *this;
+    }
+
+    bool operator==(const iterator& other) const { return i_ == other.i_; }
+
+   private:
+    // Past the offsets that land outside the grid
+    void skip() {
+      while (i_ < range_->count_ && !range_->in_bounds(**this)) {
+        ++i_;
+      }
+    }
+
+    const Neighbors* range_ = nullptr;
+    size_t i_ = 0;
+  };
+
+  iterator begin() const { return iterator(this, 0); }
+  iterator end() const { return iterator(this, count_); }
+
+ private:
+  bool in_bounds(Cell c) const {
+    return 0 <= c.row && c.row < rows_ && 0 <= c.col && c.col < cols_;
+  }
+
+  Cell center_;
+  int rows_;
+  int cols_;
+  size_t count_;
+};
+
+// rows × cols values, stored row by row
+template <typename T>
+class Grid {
+ public:
+  Grid(int rows, int cols, const T& fill = T())
+      : rows_(rows),
+        cols_(cols),
+        cells_(static_cast<size_t>(rows) * static_cast<size_t>(cols), fill) {
+    assert(rows >= 0 && cols >= 0);
+  }
+
+  // From a list of rows, which must all be the same length
+  explicit Grid(const std::vector<std::vector<T>>& rows)
+      : Grid(static_cast<int>(rows.size()),
+             rows.empty() ? 0 : static_cast<int>(rows[0].size())) {
+    for (int r = 0; r < rows_; ++r) {
+      assert(static_cast<int>(rows[r].size()) == cols_ &&
+             "rows must all be the same length");
+      std::copy(rows[r].begin(), rows[r].end(),
+                cells_.begin() + index({r, 0}));
+    }
+  }
+
+  int rows() const { return rows_; }
+  int cols() const { return cols_; }
+  size_t size() const { return cells_.size(); }
+
+  bool in_bounds(Cell c) const {
+    return 0 <= c.row && c.row < rows_ && 0 <= c.col && c.col < cols_;
+  }
+
+  typename std::vector<T>::reference operator[](Cell c) {
+    return cells_[index(c)];
+  }
+  typename std::vector<T>::const_reference operator[](Cell c) const {
+    return cells_[index(c)];
+  }
+
+  Neighbors neighbors(Cell c,
+                      Connectivity connectivity = Connectivity::kFour) const {
+    return Neighbors(c, rows_, cols_, connectivity);
+  }
+
+  // Row by row, then column by column
+  std::vector<Cell> cells() const {
+    std::vector<Cell> all;
+    all.reserve(cells_.size());
+    for (int r = 0; r < rows_; ++r) {
+      for (int c = 0; c < cols_; ++c) {
+        all.push_back({r, c});
+      }
+    }
+    return all;
+  }
+
+  bool operator==(const Grid&) const = default;
+
+ private:
+  size_t index(Cell c) const {
+    assert(in_bounds(c) && "cell out of bounds");
+    return static_cast<size_t>(c.row) * static_cast<size_t>(cols_) +
+           static_cast<size_t>(c.col);
+  }
+
+  int rows_;
+  int cols_;
+  std::vector<T> cells_;
+};
+
+// One character per cell, e.g. a maze drawn with '#' for walls
+inline Grid<char> grid_from_strings(const std::vector<std::string>& lines) {
+  std::vector<std::vector<char>> rows;
+  for (const auto& line : lines) {
+    rows.emplace_back(line.begin(), line.end());
+  }
+  return Grid<char>(rows);
+}
+
+// Sets every cell of start's region to value, where the region is the
+// cells reachable from start through neighbors equal to start's old value.
+// Returns how many cells changed. Breadth first from start, O(cells).
+template <typename T>
+size_t flood_fill(Grid<T>& grid, Cell start, const T& value,
+                  Connectivity connectivity = Connectivity::kFour) {
+  const T old = grid[start];
+  if (old == value) {
+    return 0;
+  }
+  std::queue<Cell> frontier;
+  grid[start] = value;
+  frontier.push(start);
+  size_t filled = 0;
+  while (!frontier.empty()) {
+    const Cell c = frontier.front();
+    frontier.pop();
+    ++filled;
+    for (Cell n : grid.neighbors(c, connectivity)) {
+      if (grid[n] == old) {
+        grid[n] = value;  // Marked when queued, so queued once
+        frontier.push(n);
+      }
+    }
+  }
+  return filled;
+}
+
+// The number of regions of land cells, flooding each one as it's found.
+// O(cells).
+template <typename T>
+size_t count_islands(const Grid<T>& grid, const T& land,
+                     Connectivity connectivity = Connectivity::kFour) {
+  Grid<char> seen(grid.rows(), grid.cols(), 0);
+  size_t islands = 0;
+  for (Cell c : grid.cells()) {
+    if (grid[c] == land && !seen[c]) {
+      ++islands;
+      std::queue<Cell> frontier;
+      seen[c] = 1;
+      frontier.push(c);
+      while (!frontier.empty()) {
+        const Cell x = frontier.front();
+        frontier.pop();
+        for (Cell n : grid.neighbors(x, connectivity)) {
+          if (grid[n] == land && !seen[n]) {
+            seen[n] = 1;
+            frontier.push(n);
+          }
+        }
+      }
+    }
+  }
+  return islands;
+}
+
+// A shortest path from from to to through open cells, moving across
+// sides, both ends included; std::nullopt if there's none. open(value)
+// says whether a cell can be entered. Breadth first, O(cells).
+template <typename T, typename Open>
+std::optional<std::vector<Cell>> shortest_path(const Grid<T>& grid, Cell from,
+                                               Cell to, Open open) {
+  if (!open(grid[from]) || !open(grid[to])) {
+    return std::nullopt;
+  }
+  constexpr Cell kUnseen = {-1, -1};
+  Grid<Cell> parent(grid.rows(), grid.cols(), kUnseen);
+  std::queue<Cell> frontier;
+  parent[from] = from;
+  frontier.push(from);
+  while (!frontier.empty() && parent[to] == kUnseen) {
+    const Cell c = frontier.front();
+    frontier.pop();
+    for (Cell n : grid.neighbors(c)) {
+      if (parent[n] == kUnseen && open(grid[n])) {
+        parent[n] = c;
+        frontier.push(n);
+      }
+    }
+  }
+  if (parent[to] == kUnseen) {
+    return std::nullopt;
+  }
+  std::vector<Cell> path = {to};
+  while (path.back() != from) {
+    path.push_back(parent[path.back()]);
+  }
+  std::reverse(path.begin(), path.end());
+  return path;
+}
@@ -0,0 +1,176 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "Grid.h"
+
+// Dynamic programming on grids. The path DPs move only right or down, so a
+// cell's answer depends on the cell above and the cell to its left, and
+// filling in row by row has both ready. Each comes twice: tabulated, with a
+// table the size of the grid, and rolling, which keeps one row of the
+// table, since a row only ever looks at the row above it. Same time, O(rows
+// · cols); O(cols) space instead.
+
+// The longest path through strictly increasing values, moving across
+// sides, counted in cells. Every step goes uphill, so there are no cycles,
+// and the best path from a cell is one more than the best from its best
+// higher neighbor: fill in the cells from the highest value down. O(cells
+// log cells) for the sort.
+template <typename T>
+size_t longest_increasing_path(const Grid<T>& grid) {
+  std::vector<Cell> order = grid.cells();
+  std::sort(order.begin(), order.end(),
+            [&](Cell a, Cell b) { return grid[b] < grid[a]; });
+  Grid<size_t> longest(grid.rows(), grid.cols(), 1);
+  size_t best = 0;
+  for (Cell c : order) {
+    for (Cell n : grid.neighbors(c)) {
+      if (grid[c] < grid[n]) {
+        longest[c] = std::max(longest[c], longest[n] + 1);
+      }
+    }
+    best = std::max(best, longest[c]);
+  }
+  return best;
+}
+
+// Minimum path sum: the smallest sum along a path from the top left to the
+// bottom right, both ends included. Each cell adds itself to the cheaper of
+// the cells above and to its left.
+template <typename T>
+T min_path_sum(const Grid<T>& grid) {
+  assert(grid.size() > 0 && "no path through an empty grid");
+  Grid<T> best = grid;
+  for (Cell c : grid.cells()) {
+    const bool up = c.row > 0;
+    const bool left = c.col > 0;
+    if (up && left) {
+      best[c] += std::min(best[{c.row - 1, c.col}], best[{c.row, c.col - 1}]);
+    } else if (up) {
+      best[c] += best[{c.row - 1, c.col}];
+    } else if (left) {
+      best[c] += best[{c.row, c.col - 1}];
+    }
+  }
+  return best[{grid.rows() - 1, grid.cols() - 1}];
+}
+
+// row[c] holds the row above until it's overwritten with this row's
+template <typename T>
+T min_path_sum_rolling(const Grid<T>& grid) {
+  assert(grid.size() > 0 && "no path through an empty grid");
+  std::vector<T> row(static_cast<size_t>(grid.cols()));
+  for (int r = 0; r < grid.rows(); ++r) {
+    for (int c = 0; c < grid.cols(); ++c) {
+      const T here = grid[{r, c}];
+      if (r > 0 && c > 0) {
+        row[c] = here + std::min(row[c], row[c - 1]);
+      } else if (r > 0) {
+        row[c] = here + row[c];
+      } else if (c > 0) {
+        row[c] = here + row[c - 1];
+      } else {
+        row[c] = here;
+      }
+    }
+  }
+  return row.back();
+}
+
+// Unique paths with obstacles: how many paths lead from the top left to
+// the bottom right without entering a cell equal to `blocked`. A cell is
+// reached from above or from the left, so it adds their counts; blocked
+// cells stay at 0. Counts wrap modulo 2^64, which first happens on an open
+// 35 × 35 grid.
+template <typename T>
+uint64_t unique_paths(const Grid<T>& grid, const T& blocked) {
+  if (grid.size() == 0) {
+    return 0;
+  }
+  Grid<uint64_t> paths(grid.rows(), grid.cols(), 0);
+  for (Cell c : grid.cells()) {
+    if (grid[c] == blocked) {
+      continue;
+    }
+    if (c.row == 0 && c.col == 0) {
+      paths[c] = 1;
+    }
+    if (c.row > 0) {
+      paths[c] += paths[{c.row - 1, c.col}];
+    }
+    if (c.col > 0) {
+      paths[c] += paths[{c.row, c.col - 1}];
+    }
+  }
+  return paths[{grid.rows() - 1, grid.cols() - 1}];
+}
+
+template <typename T>
+uint64_t unique_paths_rolling(const Grid<T>& grid, const T& blocked) {
+  if (grid.size() == 0) {
+    return 0;
+  }
+  std::vector<uint64_t> row(static_cast<size_t>(grid.cols()), 0);
+  row[0] = 1;  // As if from above the start
+  for (int r = 0; r < grid.rows(); ++r) {
+    for (int c = 0; c < grid.cols(); ++c) {
+      if (grid[{r, c}] == blocked) {
+        row[c] = 0;
+      } else if (c > 0) {
+        row[c] += row[c - 1];
+      }
+    }
+  }
+  return row.back();
+}
+
+// Maximal square: the side of the largest square of cells all equal to
+// `one`. The largest one with its bottom right corner at a cell is one more
+// than the smallest of those at the cells above, to the left, and up and to
+// the left: each of the three squares covers one part of the new one.
+template <typename T>
+size_t maximal_square(const Grid<T>& grid, const T& one) {
+  Grid<size_t> side(grid.rows(), grid.cols(), 0);
+  size_t best = 0;
+  for (Cell c : grid.cells()) {
+    if (!(grid[c] == one)) {
+      continue;
+    }
+    side[c] = 1;
+    if (c.row > 0 && c.col > 0) {
+      side[c] += std::min({side[{c.row - 1, c.col}], side[{c.row, c.col - 1}],
+                           side[{c.row - 1, c.col - 1}]});
+    }
+    best = std::max(best, side[c]);
+  }
+  return best;
+}
+
+// The up-and-left value is the previous row's entry at c - 1, which this
+// row has already overwritten by the time c comes up, so it's saved in
+// `diagonal` first
+template <typename T>
+size_t maximal_square_rolling(const Grid<T>& grid, const T& one) {
+  std::vector<size_t> row(static_cast<size_t>(grid.cols()), 0);
+  size_t best = 0;
+  for (int r = 0; r < grid.rows(); ++r) {
+    size_t diagonal = 0;
+    for (int c = 0; c < grid.cols(); ++c) {
+      const size_t above = row[c];
+      if (!(grid[{r, c}] == one)) {
+        row[c] = 0;
+      } else if (c == 0) {
+        row[c] = 1;
+      } else {
+        row[c] = 1 + std::min({above, row[c - 1], diagonal});
+      }
+      diagonal = above;
+      best = std::max(best, row[c]);
+    }
+  }
+  return best;
+}
@@ -0,0 +1,301 @@
+#include <algorithm>
+#include <cstdlib>
+#include <cstddef>
+#include <functional>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "../union-find/UnionFind.h"
+#include "Grid.h"
+#include "GridDp.h"
+#include "WordSearch.h"
+#include "gtest/gtest.h"
+
+using Rows = std::vector<std::vector<int>>;
+
+TEST(Grid, Neighbors) {
+  const Grid<int> grid(3, 4);
+  std::vector<Cell> corner(grid.neighbors({0, 0}).begin(),
+                           grid.neighbors({0, 0}).end());
+  EXPECT_EQ(corner, (std::vector<Cell>{{0, 1}, {1, 0}}));
+  std::vector<Cell> around;
+  for (Cell c : grid.neighbors({2, 3}, Connectivity::kEight)) {
+    around.push_back(c);
+  }
+  EXPECT_EQ(around, (std::vector<Cell>{{1, 3}, {2, 2}, {1, 2}}));
+  size_t middle = 0;
+  for (Cell c : grid.neighbors({1, 1}, Connectivity::kEight)) {
+    EXPECT_TRUE(grid.in_bounds(c));
+    ++middle;
+  }
+  EXPECT_EQ(middle, 8u);
+  EXPECT_EQ(Grid<int>(0, 0).cells().size(), 0u);
+}
+
+TEST(Grid, FloodFillAndIslands) {
+  auto grid = grid_from_strings({
+      "##..#",
+      "#..#.",
+      "..#..",
+      "#.#.#",
+  });
+  EXPECT_EQ(count_islands(grid, '#'), 6u);
+  EXPECT_EQ(count_islands(grid, '#', Connectivity::kEight), 4u);
+  EXPECT_EQ(count_islands(grid, '.'), 2u);
+
+  EXPECT_EQ(flood_fill(grid, {0, 0}, 'x'), 3u);
+  EXPECT_EQ(flood_fill(grid, {0, 0}, 'x'), 0u);
+  EXPECT_EQ(flood_fill(grid, {3, 2}, 'y', Connectivity::kEight), 4u);
+  EXPECT_EQ(grid, grid_from_strings({
+                      "xx..y",
+                      "x..y.",
+                      "..y..",
+                      "#.y.#",
+                  }));
+}
+
+// On random grids, islands against a union-find over neighboring land cells
+TEST(Grid, IslandsMatchUnionFind) {
+  Rng gen(193);
+  for (int trial = 0; trial < 50; ++trial) {
+    Grid<int> grid(1 + uniform_int(gen, 0, 15), 1 + uniform_int(gen, 0, 15));
+    for (Cell c : grid.cells()) {
+      grid[c] = bernoulli(gen, 0.45);
+    }
+    for (auto connectivity : {Connectivity::kFour, Connectivity::kEight}) {
+      UnionFind sets(grid.size());
+      auto id = [&](Cell c) {
+        return static_cast<size_t>(c.row * grid.cols() + c.col);
+      };
+      size_t water = 0;
+      for (Cell c : grid.cells()) {
+        water += grid[c] == 0;
+        for (Cell n : grid.neighbors(c, connectivity)) {
+          if (grid[c] == 1 && grid[n] == 1) {
+            sets.unite(id(c), id(n));
+          }
+        }
+      }
+      ASSERT_EQ(count_islands(grid, 1, connectivity),
+                sets.components() - water);
+    }
+  }
+}
+
+// Steps from from to to, by relaxing every cell from its neighbors until
+// nothing changes
+size_t distance_naive(const Grid<char>& maze, Cell from, Cell to) {
+  const size_t kFar = maze.size();
+  Grid<size_t> distance(maze.rows(), maze.cols(), kFar);
+  distance[from] = 0;
+  for (bool changed = true; changed;) {
+    changed = false;
+    for (Cell c : maze.cells()) {
+      for (Cell n : maze.neighbors(c)) {
+        if (maze[c] != '#' && distance[n] + 1 < distance[c]) {
+          distance[c] = distance[n] + 1;
+          changed = true;
+        }
+      }
+    }
+  }
+  return distance[to];
+}
+
+TEST(Grid, ShortestPath) {
+  const auto maze = grid_from_strings({
+      "S.#.....",
+      ".##.###.",
+      "....#...",
+      "###.#.##",
+      "....#..G",
+  });
+  auto open = [](char c) { return c != '#'; };
+  const auto path = shortest_path(maze, {0, 0}, {4, 7}, open);
+  ASSERT_TRUE(path);
+  EXPECT_EQ(path->size(), distance_naive(maze, {0, 0}, {4, 7}) + 1);
+  EXPECT_EQ(path->front(), (Cell{0, 0}));
+  EXPECT_EQ(path->back(), (Cell{4, 7}));
+  for (size_t i = 0; i + 1 < path->size(); ++i) {
+    const Cell a = (*path)[i];
+    const Cell b = (*path)[i + 1];
+    ASSERT_EQ(std::abs(a.row - b.row) + std::abs(a.col - b.col), 1);
+    ASSERT_TRUE(open(maze[b]));
+  }
+  EXPECT_EQ(shortest_path(maze, {0, 0}, {0, 0}, open)->size(), 1u);
+  EXPECT_FALSE(shortest_path(maze, {0, 0}, {0, 2}, open));
+  EXPECT_FALSE(shortest_path(maze, {0, 0}, {4, 7},
+                             [](char c) { return c == 'S' || c == '.'; }));
+}
+
+// Brute force: the longest increasing path from every cell, by recursion
+size_t longest_from(const Grid<int>& grid, Cell c) {
+  size_t best = 1;
+  for (Cell n : grid.neighbors(c)) {
+    if (grid[c] < grid[n]) {
+      best = std::max(best, 1 + longest_from(grid, n));
+    }
+  }
+  return best;
+}
+
+TEST(Grid, LongestIncreasingPath) {
+  const Grid<int> grid({{9, 9, 4}, {6, 6, 8}, {2, 1, 1}});
+  EXPECT_EQ(longest_increasing_path(grid), 4u);  // 1 2 6 9
+  EXPECT_EQ(longest_increasing_path(Grid<int>(0, 0)), 0u);
+
+  Rng gen(193);
+  for (int trial = 0; trial < 50; ++trial) {
+    Grid<int> random(1 + uniform_int(gen, 0, 5), 1 + uniform_int(gen, 0, 5));
+    for (Cell c : random.cells()) {
+      random[c] = uniform_int(gen, 0, 9);
+    }
+    size_t expected = 0;
+    for (Cell c : random.cells()) {
+      expected = std::max(expected, longest_from(random, c));
+    }
+    ASSERT_EQ(longest_increasing_path(random), expected);
+  }
+}
+
+// Random grids, tabulated against rolling
+template <typename Generate>
+Grid<int> random_grid(Rng& gen, Generate value) {
+  Grid<int> grid(1 + uniform_int(gen, 0, 12), 1 + uniform_int(gen, 0, 12));
+  for (Cell c : grid.cells()) {
+    grid[c] = value();
+  }
+  return grid;
+}
+
+TEST(Grid, MinPathSum) {
+  const Grid<int> grid(Rows{{1, 3, 1}, {1, 5, 1}, {4, 2, 1}});
+  EXPECT_EQ(min_path_sum(grid), 7);
+  EXPECT_EQ(min_path_sum_rolling(grid), 7);
+  EXPECT_EQ(min_path_sum(Grid<int>(Rows{{5}})), 5);
+  EXPECT_EQ(min_path_sum_rolling(Grid<int>(Rows{{1, 2, 3}})), 6);
+  EXPECT_EQ(min_path_sum_rolling(Grid<int>(Rows{{1}, {2}, {3}})), 6);
+
+  Rng gen(194);
+  for (int trial = 0; trial < 100; ++trial) {
+    const auto random =
+        random_grid(gen, [&] { return uniform_int(gen, -9, 9); });
+    ASSERT_EQ(min_path_sum(random), min_path_sum_rolling(random));
+  }
+}
+
+TEST(Grid, UniquePaths) {
+  const Grid<int> open(3, 7, 0);
+  EXPECT_EQ(unique_paths(open, 1), 28u);  // 8 choose 2
+  EXPECT_EQ(unique_paths_rolling(open, 1), 28u);
+  const Grid<int> rock(Rows{{0, 0, 0}, {0, 1, 0}, {0, 0, 0}});
+  EXPECT_EQ(unique_paths(rock, 1), 2u);
+  EXPECT_EQ(unique_paths_rolling(rock, 1), 2u);
+  const Grid<int> walled(Rows{{0, 1}, {1, 0}});
+  EXPECT_EQ(unique_paths(walled, 1), 0u);
+  EXPECT_EQ(unique_paths_rolling(walled, 1), 0u);
+  EXPECT_EQ(unique_paths_rolling(Grid<int>(Rows{{1}}), 1), 0u);
+  EXPECT_EQ(unique_paths(Grid<int>(0, 0), 1), 0u);
+  // 66 choose 33, the most that fits
+  EXPECT_EQ(unique_paths_rolling(Grid<int>(34, 34, 0), 1),
+            7219428434016265740u);
+
+  Rng gen(194);
+  for (int trial = 0; trial < 100; ++trial) {
+    const auto random = random_grid(gen, [&] { return bernoulli(gen, 0.2); });
+    ASSERT_EQ(unique_paths(random, 1), unique_paths_rolling(random, 1));
+  }
+}
+
+// Brute force: try every square
+size_t maximal_square_naive(const Grid<int>& grid) {
+  size_t best = 0;
+  for (Cell corner : grid.cells()) {
+    for (int side = 1; corner.row + side <= grid.rows() &&
+                       corner.col + side <= grid.cols();
+         ++side) {
+      bool all = true;
+      for (int r = 0; r < side && all; ++r) {
+        for (int c = 0; c < side && all; ++c) {
+          all = grid[{corner.row + r, corner.col + c}] == 1;
+        }
+      }
+      if (all) {
+        best = std::max(best, static_cast<size_t>(side));
+      }
+    }
+  }
+  return best;
+}
+
+TEST(Grid, MaximalSquare) {
+  const Grid<int> grid(Rows{{1, 0, 1, 0, 0},
+                            {1, 0, 1, 1, 1},
+                            {1, 1, 1, 1, 1},
+                            {1, 0, 0, 1, 0}});
+  EXPECT_EQ(maximal_square(grid, 1), 2u);
+  EXPECT_EQ(maximal_square_rolling(grid, 1), 2u);
+  EXPECT_EQ(maximal_square(Grid<int>(0, 0), 1), 0u);
+  EXPECT_EQ(maximal_square_rolling(Grid<int>(3, 5, 1), 1), 3u);
+
+  Rng gen(194);
+  for (int trial = 0; trial < 100; ++trial) {
+    const auto random = random_grid(gen, [&] { return bernoulli(gen, 0.8); });
+    const size_t expected = maximal_square_naive(random);
+    ASSERT_EQ(maximal_square(random, 1), expected);
+    ASSERT_EQ(maximal_square_rolling(random, 1), expected);
+  }
+}
+
+TEST(Grid, WordSearch) {
+  const auto board = grid_from_strings({"ABCE", "SFCS", "ADEE"});
+  EXPECT_TRUE(word_search(board, "ABCCED"));
+  EXPECT_TRUE(word_search(board, "SEE"));
+  EXPECT_FALSE(word_search(board, "ABCB"));  // Would reuse the B
+  EXPECT_FALSE(word_search(board, "BSA"));
+  EXPECT_TRUE(word_search(board, "BSA", Connectivity::kEight));
+  EXPECT_TRUE(word_search(board, ""));
+}
+
+TEST(Grid, Boggle) {
+  const auto board = grid_from_strings({"oaan", "etae", "ihkr", "iflv"});
+  const std::vector<std::string> words = {"oath", "pea", "eat", "rain", "oat",
+                                          "eat", "hike", "tea", "at"};
+  EXPECT_EQ(boggle(board, words, 1, Connectivity::kFour),
+            (std::vector<std::string>{"at", "eat", "oat", "oath"}));
+  EXPECT_EQ(boggle(board, words),
+            (std::vector<std::string>{"eat", "oat", "oath", "tea"}));
+
+  // Against a word search per word, on random boards
+  Rng gen(195);
+  std::vector<std::string> dictionary;
+  for (int i = 0; i < 300; ++i) {
+    std::string word(uniform_int(gen, 1, 6), ' ');
+    for (char& c : word) {
+      c = static_cast<char>('a' + uniform_below(gen, 4));
+    }
+    dictionary.push_back(word);
+  }
+  for (int trial = 0; trial < 20; ++trial) {
+    Grid<char> random(uniform_int(gen, 1, 4), uniform_int(gen, 1, 4));
+    for (Cell c : random.cells()) {
+      random[c] = static_cast<char>('a' + uniform_below(gen, 4));
+    }
+    std::vector<std::string> expected;
+    for (const auto& word : dictionary) {
+      if (word_search(random, word, Connectivity::kEight)) {
+        expected.push_back(word);
+      }
+    }
+    std::sort(expected.begin(), expected.end());
+    expected.erase(std::unique(expected.begin(), expected.end()),
+                   expected.end());
+    ASSERT_EQ(boggle(random, dictionary, 1), expected);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
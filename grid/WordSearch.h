@@ -0,0 +1,106 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "../trie/Trie.h"
+#include "Grid.h"
+
+// Words spelled out on a grid of letters by a path of neighboring cells,
+// using each cell at most once per word.
+
+// Whether word can be spelled from `at` on, its first letter at `at`.
+// Backtracking: mark the cell used, try each neighbor for the rest of the
+// word, and unmark it on the way back out.
+inline bool spell_from(const Grid<char>& board, Grid<char>& used, Cell at,
+                       const std::string& word, size_t i,
+                       Connectivity connectivity) {
+  if (used[at] || board[at] != word[i]) {
+    return false;
+  }
+  if (i + 1 == word.size()) {
+    return true;
+  }
+  used[at] = 1;
+  bool found = false;
+  for (Cell next : board.neighbors(at, connectivity)) {
+    if (spell_from(board, used, next, word, i + 1, connectivity)) {
+      found = true;
+      break;
+    }
+  }
+  used[at] = 0;
+  return found;
+}
+
+// Word search: can word be spelled on the board? O(cells · k^len) in the
+// worst case, for k neighbors per cell, but most paths die after a letter
+// or two.
+inline bool word_search(const Grid<char>& board, const std::string& word,
+                        Connectivity connectivity = Connectivity::kFour) {
+  if (word.empty()) {
+    return true;
+  }
+  Grid<char> used(board.rows(), board.cols(), 0);
+  for (Cell start : board.cells()) {
+    if (spell_from(board, used, start, word, 0, connectivity)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+// Every path from `at` that spells a prefix in the trie, collecting the
+// ones that end a word. The trie node moves along with the path, so a path
+// stops as soon as no dictionary word starts with it, rather than once per
+// word as searching for each word in turn would.
+inline void collect_words(const Grid<char>& board, Grid<char>& used, Cell at,
+                          const TrieNode* node, std::string& path,
+                          Connectivity connectivity,
+                          std::vector<std::string>& found) {
+  if (used[at]) {
+    return;
+  }
+  auto it = node->children.find(board[at]);
+  if (it == node->children.end()) {
+    return;
+  }
+  node = it->second.get();
+  path.push_back(board[at]);
+  if (node->is_end) {
+    found.push_back(path);
+  }
+  used[at] = 1;
+  for (Cell next : board.neighbors(at, connectivity)) {
+    collect_words(board, used, next, node, path, connectivity, found);
+  }
+  used[at] = 0;
+  path.pop_back();
+}
+
+// Boggle: every dictionary word of at least min_length letters that can be
+// spelled on the board, sorted, each once. Moves go to any of the eight
+// neighbors, as in the game.
+inline std::vector<std::string> boggle(
+    const Grid<char>& board, const std::vector<std::string>& dictionary,
+    size_t min_length = 3,
+    Connectivity connectivity = Connectivity::kEight) {
+  Trie trie;
+  for (const auto& word : dictionary) {
+    if (word.size() >= min_length) {
+      trie.insert(word);
+    }
+  }
+  std::vector<std::string> found;
+  Grid<char> used(board.rows(), board.cols(), 0);
+  std::string path;
+  for (Cell start : board.cells()) {
+    collect_words(board, used, start, trie.rootNode(), path, connectivity,
+                  found);
+  }
+  std::sort(found.begin(), found.end());
+  found.erase(std::unique(found.begin(), found.end()), found.end());
+  return found;
+}
@@ -0,0 +1,87 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <utility>
+#include <vector>
+
+// Binary heap in an array: the children of slot i are 2i + 1 and 2i + 2, and
+// every element is at least as large as its children, so the largest is at
+// the root. Like std::priority_queue, Compare = std::greater<T> makes it a
+// min-heap instead.
+//
+// push and pop are O(log n): the new or displaced element sifts up or down
+// one level at a time. Building from a whole vector is O(n), because most
+// elements start near the bottom and have little distance to sift.
+template <typename T, typename Compare = std::less<T>>
+class BinaryHeap {
+ public:
+  explicit BinaryHeap(Compare less = Compare()) : less_(less) {}
+
+  explicit BinaryHeap(std::vector<T> values, Compare less = Compare())
+      : items_(std::move(values)), less_(less) {
+    for (size_t i = items_.size() / 2; i-- > 0;) {
+      sift_down(i);
+    }
+  }
+
+  bool empty() const { return items_.empty(); }
+
+  size_t size() const { return items_.size(); }
+
+  const T& top() const {
+    assert(!empty() && "top of an empty heap");
+    return items_.front();
+  }
+
+  void push(T value) {
+    items_.push_back(std::move(value));
+    sift_up(items_.size() - 1);
+  }
+
+  // Removes and returns the top element
+  T pop() {
+    assert(!empty() && "pop from an empty heap");
+    T result = std::move(items_.front());
+    if (items_.size() > 1) {
+      items_.front() = std::move(items_.back());
+    }
+    items_.pop_back();
+    if (!items_.empty()) {
+      sift_down(0);
+    }
+    return result;
+  }
+
+ private:
+  void sift_up(size_t i) {
+    while (i > 0) {
+      const size_t parent = (i - 1) / 2;
+      if (!less_(items_[parent], items_[i])) {
+        break;
+      }
+      std::swap(items_[parent], items_[i]);
+      i = parent;
+    }
+  }
+
+  void sift_down(size_t i) {
+    while (true) {
+      size_t largest = i;
+      for (size_t child = 2 * i + 1; child <= 2 * i + 2; ++child) {
+        if (child < items_.size() && less_(items_[largest], items_[child])) {
+          largest = child;
+        }
+      }
+      if (largest == i) {
+        return;
+      }
+      std::swap(items_[i], items_[largest]);
+      i = largest;
+    }
+  }
+
+  std::vector<T> items_;
+  Compare less_;
+};
@@ -0,0 +1,68 @@
+#include <algorithm>
+#include <functional>
+#include <random>
+#include <string>
+#include <vector>
+
+#include "BinaryHeap.h"
+#include "gtest/gtest.h"
+
+TEST(BinaryHeap, PushAndPop) {
+  BinaryHeap<int> heap;
+  EXPECT_TRUE(heap.empty());
+  for (int x : {3, 1, 4, 1, 5, 9, 2, 6}) {
+    heap.push(x);
+  }
+  EXPECT_EQ(heap.size(), 8u);
+  EXPECT_EQ(heap.top(), 9);
+
+  std::vector<int> popped;
+  while (!heap.empty()) {
+    popped.push_back(heap.pop());
+  }
+  EXPECT_EQ(popped, (std::vector<int>{9, 6, 5, 4, 3, 2, 1, 1}));
+}
+
+TEST(BinaryHeap, MinHeapFromVector) {
+  BinaryHeap<std::string, std::greater<std::string>> heap(
+      {"pear", "fig", "apple", "kiwi"});
+  EXPECT_EQ(heap.pop(), "apple");
+  EXPECT_EQ(heap.pop(), "fig");
+  heap.push("banana");
+  EXPECT_EQ(heap.pop(), "banana");
+  EXPECT_EQ(heap.size(), 2u);
+}
+
+TEST(BinaryHeap, MatchesSortedOrder) {
+  std::mt19937 gen(159);
+  std::uniform_int_distribution<int> value(-50, 50);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<int> values(gen() % 100);
+    for (auto& v : values) {
+      v = value(gen);
+    }
+    // Half built at once, then pushes and pops interleaved
+    const size_t half = values.size() / 2;
+    std::vector<int> reference(values.begin(), values.begin() + half);
+    BinaryHeap<int> heap(reference);
+    for (size_t i = half; i < values.size(); ++i) {
+      heap.push(values[i]);
+      reference.push_back(values[i]);
+      if (i % 3 == 0) {
+        auto largest = std::max_element(reference.begin(), reference.end());
+        ASSERT_EQ(heap.pop(), *largest);
+        reference.erase(largest);
+      }
+    }
+    std::sort(reference.rbegin(), reference.rend());
+    for (int expected : reference) {
+      ASSERT_EQ(heap.pop(), expected);
+    }
+    ASSERT_TRUE(heap.empty());
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,120 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <iterator>
+#include <unordered_map>
+#include <utility>
+#include <vector>
+
+#include "../heap/BinaryHeap.h"
+
+// Finding the most frequent elements of a stream: exactly, by counting
+// everything, or approximately, in space that doesn't grow with the stream.
+
+template <typename T>
+struct Frequency {
+  T value;
+  uint64_t count;
+
+  bool operator==(const Frequency&) const = default;
+};
+
+// Frequencies order by count, and then the smaller value counts as more
+// frequent, so that ties come out the same way every time
+template <typename T>
+bool more_frequent(const Frequency<T>& a, const Frequency<T>& b) {
+  return a.count != b.count ? a.count > b.count : a.value < b.value;
+}
+
+// The k most frequent values, most frequent first. Count everything, then
+// keep the best k in a min-heap of size k, whose top is the one to beat.
+// O(n + m log k) for m distinct values.
+template <typename T>
+std::vector<Frequency<T>> top_k_frequent(const std::vector<T>& values,
+                                         size_t k) {
+  std::unordered_map<T, uint64_t> counts;
+  for (const auto& v : values) {
+    ++counts[v];
+  }
+
+  // more_frequent as the heap's "less" puts the least frequent on top
+  using Order = bool (*)(const Frequency<T>&, const Frequency<T>&);
+  BinaryHeap<Frequency<T>, Order> best(more_frequent<T>);
+  for (const auto& [value, count] : counts) {
+    Frequency<T> f{value, count};
+    if (best.size() < k) {
+      best.push(std::move(f));
+    } else if (k > 0 && more_frequent(f, best.top())) {
+      best.pop();
+      best.push(std::move(f));
+    }
+  }
+
+  std::vector<Frequency<T>> result;
+  while (!best.empty()) {
+    result.push_back(best.pop());
+  }
+  std::reverse(result.begin(), result.end());
+  return result;
+}
+
+// Misra-Gries: a summary of a stream in k counters. A value with a counter
+// gets counted; a new value takes a free counter; and if there's none, every
+// counter goes down by one instead, as if the new value and k others were
+// thrown away together. Each such decrement throws away k + 1 occurrences,
+// so after n values it has happened at most n / (k + 1) times, and
+//
+//   count(x) - n / (k + 1) <= estimate(x) <= count(x)
+//
+// In particular, every value with more than n / (k + 1) occurrences still
+// has a counter at the end. O(k) space; O(1) amortized per value, since a
+// decrement costs O(k) but each one undoes at least k earlier increments.
+template <typename T>
+class MisraGries {
+ public:
+  explicit MisraGries(size_t counters) : counters_(counters) {
+    assert(counters > 0);
+  }
+
+  void add(const T& x) {
+    ++seen_;
+    if (auto it = counts_.find(x); it != counts_.end()) {
+      ++it->second;
+    } else if (counts_.size() < counters_) {
+      counts_.emplace(x, 1);
+    } else {
+      for (auto it = counts_.begin(); it != counts_.end();) {
+        it = --it->second == 0 ? counts_.erase(it) : std::next(it);
+      }
+    }
+  }
+
+  // A lower bound on the count of x, off by at most error_bound()
+  uint64_t estimate(const T& x) const {
+    auto it = counts_.find(x);
+    return it == counts_.end() ? 0 : it->second;
+  }
+
+  uint64_t error_bound() const { return seen_ / (counters_ + 1); }
+
+  uint64_t seen() const { return seen_; }
+
+  // The values that still have counters, most frequent first. Includes every
+  // value that occurs more than error_bound() times.
+  std::vector<Frequency<T>> candidates() const {
+    std::vector<Frequency<T>> result;
+    for (const auto& [value, count] : counts_) {
+      result.push_back({value, count});
+    }
+    std::sort(result.begin(), result.end(), more_frequent<T>);
+    return result;
+  }
+
+ private:
+  size_t counters_;
+  uint64_t seen_{0};
+  std::unordered_map<T, uint64_t> counts_;
+};
@@ -0,0 +1,118 @@
+#include <algorithm>
+#include <cmath>
+#include <cstdint>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "HeavyHitters.h"
+#include "gtest/gtest.h"
+
+// Values 0..n-1, where value i is drawn with probability proportional to
+// 1 / (i + 1)^s: weights scaled to integers, and a uniform draw below their
+// total looked up in the running sums
+std::vector<int> zipf_stream(Rng& gen, int n, double s, size_t length) {
+  std::vector<uint64_t> cumulative(n);
+  uint64_t total = 0;
+  for (int i = 0; i < n; ++i) {
+    total += static_cast<uint64_t>(1e9 / std::pow(i + 1, s)) + 1;
+    cumulative[i] = total;
+  }
+  std::vector<int> stream(length);
+  for (auto& v : stream) {
+    const uint64_t x = uniform_below(gen, total);
+    v = static_cast<int>(
+        std::upper_bound(cumulative.begin(), cumulative.end(), x) -
+        cumulative.begin());
+  }
+  return stream;
+}
+
+TEST(HeavyHitters, TopKExample) {
+  std::vector<int> values = {1, 1, 1, 2, 2, 3, 4, 4, 4, 4};
+  EXPECT_EQ(top_k_frequent(values, 2),
+            (std::vector<Frequency<int>>{{4, 4}, {1, 3}}));
+  EXPECT_TRUE(top_k_frequent(values, 0).empty());
+  EXPECT_EQ(top_k_frequent(values, 10).size(), 4u);
+
+  // Ties go to the smaller value
+  std::vector<std::string> words = {"b", "a", "c", "b", "a", "c"};
+  EXPECT_EQ(top_k_frequent(words, 2),
+            (std::vector<Frequency<std::string>>{{"a", 2}, {"b", 2}}));
+}
+
+TEST(HeavyHitters, TopKMatchesSorting) {
+  Rng gen(160);
+  for (int trial = 0; trial < 100; ++trial) {
+    auto stream = zipf_stream(gen, 30, 1.0, uniform_below(gen, 500));
+    const size_t k = uniform_below(gen, 10);
+
+    std::unordered_map<int, uint64_t> counts;
+    for (int v : stream) {
+      ++counts[v];
+    }
+    std::vector<Frequency<int>> all;
+    for (const auto& [value, count] : counts) {
+      all.push_back({value, count});
+    }
+    std::sort(all.begin(), all.end(), more_frequent<int>);
+    all.resize(std::min(k, all.size()));
+
+    ASSERT_EQ(top_k_frequent(stream, k), all);
+  }
+}
+
+TEST(HeavyHitters, MisraGriesExample) {
+  MisraGries<char> summary(2);
+  for (char c : std::string("abacabad")) {
+    summary.add(c);
+  }
+  // 'a' is 4 of 8, more than 8 / 3, so it must survive
+  EXPECT_EQ(summary.seen(), 8u);
+  EXPECT_EQ(summary.error_bound(), 2u);
+  EXPECT_GE(summary.estimate('a'), 4u - 2u);
+  EXPECT_LE(summary.estimate('a'), 4u);
+  EXPECT_EQ(summary.candidates().front().value, 'a');
+}
+
+TEST(HeavyHitters, MisraGriesOnZipfianStream) {
+  Rng gen(161);
+  const auto stream = zipf_stream(gen, 10000, 1.1, 200000);
+  std::unordered_map<int, uint64_t> exact;
+  MisraGries<int> summary(100);
+  for (int v : stream) {
+    ++exact[v];
+    summary.add(v);
+  }
+
+  const uint64_t bound = summary.error_bound();
+  EXPECT_EQ(bound, 200000u / 101);
+  for (const auto& [value, count] : exact) {
+    const uint64_t estimate = summary.estimate(value);
+    ASSERT_LE(estimate, count);
+    ASSERT_GE(estimate + bound, count);
+  }
+
+  // Every true heavy hitter is a candidate, and the top few come out in the
+  // right order, since the deficit is spread thinly across the counters
+  std::unordered_map<int, bool> is_candidate;
+  for (const auto& f : summary.candidates()) {
+    is_candidate[f.value] = true;
+  }
+  for (const auto& [value, count] : exact) {
+    if (count > bound) {
+      ASSERT_TRUE(is_candidate[value]) << value;
+    }
+  }
+  const auto top = top_k_frequent(stream, 5);
+  const auto candidates = summary.candidates();
+  for (size_t i = 0; i < top.size(); ++i) {
+    EXPECT_EQ(candidates[i].value, top[i].value);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
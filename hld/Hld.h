@@ -0,0 +1,197 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <utility>
+#include <vector>
+
+#include "../graph/Graph.h"
+#include "../graph/Tree.h"
+#include "../segment-tree/SegmentTree.h"
+
+// Heavy-light decomposition: cut a rooted tree into vertical chains so that
+// any path crosses only O(log n) of them, then lay each chain out as a run
+// of consecutive positions. A path query on the tree becomes O(log n) range
+// queries on an array, which the segment tree answers in O(log n) each.
+//
+// Each vertex's heavy child is its child with the largest subtree, and the
+// heavy edges form the chains. Going down a light edge at least halves the
+// subtree size, so a walk from any vertex to the root meets at most log n
+// light edges, and so at most log n + 1 chains.
+//
+// The positions are a depth-first order that visits the heavy child first:
+// that keeps each chain contiguous, and every subtree too, so subtree
+// queries come for free.
+
+// A [begin, end) range of positions
+using PositionRange = std::pair<size_t, size_t>;
+
+class HeavyLightDecomposition {
+ public:
+  HeavyLightDecomposition(const AdjacencyList& tree, int root)
+      : HeavyLightDecomposition(tree, RootedTree(tree, root)) {}
+
+  HeavyLightDecomposition(const AdjacencyList& tree, const RootedTree& rooted)
+      : parent_(rooted.parent),
+        depth_(rooted.depth),
+        head_(rooted.size()),
+        position_(rooted.size()),
+        subtree_size_(rooted.size(), 1) {
+    const size_t n = rooted.size();
+    // Children come after their parents in BFS order, so going backwards
+    // finishes each subtree before its parent needs it
+    for (size_t i = n; i-- > 1;) {
+      const int v = rooted.order[i];
+      subtree_size_[parent_[v]] += subtree_size_[v];
+    }
+    std::vector<int> heavy(n, -1);
+    for (size_t v = 0; v < n; ++v) {
+      for (int child : tree[v]) {
+        if (child == parent_[v]) {
+          continue;
+        }
+        if (heavy[v] == -1 ||
+            subtree_size_[child] > subtree_size_[heavy[v]]) {
+          heavy[v] = child;
+        }
+      }
+    }
+
+    // Preorder with a stack, pushing the heavy child last so that it's
+    // visited right after its parent
+    std::vector<int> stack = {rooted.root};
+    head_[rooted.root] = rooted.root;
+    size_t next = 0;
+    while (!stack.empty()) {
+      const int v = stack.back();
+      stack.pop_back();
+      position_[v] = next++;
+      for (int child : tree[v]) {
+        if (child != parent_[v] && child != heavy[v]) {
+          head_[child] = child;
+          stack.push_back(child);
+        }
+      }
+      if (heavy[v] != -1) {
+        head_[heavy[v]] = head_[v];
+        stack.push_back(heavy[v]);
+      }
+    }
+  }
+
+  size_t size() const { return parent_.size(); }
+
+  size_t position(int v) const { return position_[v]; }
+
+  // The top of v's chain
+  int head(int v) const { return head_[v]; }
+
+  int lca(int u, int v) const {
+    while (head_[u] != head_[v]) {
+      if (depth_[head_[u]] < depth_[head_[v]]) {
+        std::swap(u, v);
+      }
+      u = parent_[head_[u]];
+    }
+    return depth_[u] < depth_[v] ? u : v;
+  }
+
+  // Ranges of positions that together hold exactly the vertices on the path
+  // from u to v, both included: one per chain the path touches. Always
+  // climbing from the end whose chain starts deeper means neither end
+  // climbs past the LCA's chain.
+  std::vector<PositionRange> path_ranges(int u, int v) const {
+    std::vector<PositionRange> ranges;
+    while (head_[u] != head_[v]) {
+      if (depth_[head_[u]] < depth_[head_[v]]) {
+        std::swap(u, v);
+      }
+      ranges.push_back({position_[head_[u]], position_[u] + 1});
+      u = parent_[head_[u]];
+    }
+    const auto [low, high] = std::minmax(position_[u], position_[v]);
+    ranges.push_back({low, high + 1});
+    return ranges;
+  }
+
+  // The positions of v and everything below it
+  PositionRange subtree_range(int v) const {
+    return {position_[v], position_[v] + subtree_size_[v]};
+  }
+
+ private:
+  std::vector<int> parent_;
+  std::vector<int> depth_;
+  std::vector<int> head_;
+  std::vector<size_t> position_;
+  std::vector<size_t> subtree_size_;
+};
+
+// A value on every vertex, with point updates and queries over paths and
+// subtrees, O(log^2 n) and O(log n). The ranges of a path don't come in
+// path order, so combine must be commutative as well as associative: sums,
+// maxima, and the like.
+template <typename T, typename Combine = std::plus<T>>
+class PathQueries {
+ public:
+  PathQueries(const AdjacencyList& tree, int root,
+              const std::vector<T>& values, T identity,
+              Combine combine = Combine())
+      : hld_(tree, root),
+        identity_(identity),
+        combine_(combine),
+        tree_(by_position(hld_, values), identity, combine) {}
+
+  const T& get(int v) const { return tree_.get(hld_.position(v)); }
+
+  void set(int v, T value) { tree_.set(hld_.position(v), std::move(value)); }
+
+  // Every value on the path from u to v, both included, combined
+  T path(int u, int v) const {
+    T result = identity_;
+    for (const auto& [begin, end] : hld_.path_ranges(u, v)) {
+      result = combine_(result, tree_.query(begin, end));
+    }
+    return result;
+  }
+
+  T subtree(int v) const {
+    const auto [begin, end] = hld_.subtree_range(v);
+    return tree_.query(begin, end);
+  }
+
+  const HeavyLightDecomposition& decomposition() const { return hld_; }
+
+ private:
+  static std::vector<T> by_position(const HeavyLightDecomposition& hld,
+                                    const std::vector<T>& values) {
+    assert(values.size() == hld.size() && "one value per vertex");
+    std::vector<T> placed(values.size(), T{});
+    for (size_t v = 0; v < values.size(); ++v) {
+      placed[hld.position(static_cast<int>(v))] = values[v];
+    }
+    return placed;
+  }
+
+  HeavyLightDecomposition hld_;
+  T identity_;
+  Combine combine_;
+  SegmentTree<T, Combine> tree_;
+};
+
+// O(n): climb from both ends to the LCA
+template <typename T, typename Combine>
+T path_query_naive(const RootedTree& rooted, const std::vector<T>& values,
+                   int u, int v, T identity, Combine combine) {
+  T result = identity;
+  while (u != v) {
+    if (rooted.depth[u] < rooted.depth[v]) {
+      std::swap(u, v);
+    }
+    result = combine(result, values[u]);
+    u = rooted.parent[u];
+  }
+  return combine(result, values[u]);
+}
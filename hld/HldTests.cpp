@@ -0,0 +1,94 @@
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../graph/ExampleTree.h"
+#include "../lca/Lca.h"
+#include "../rng/Rng.h"
+#include "Hld.h"
+#include "gtest/gtest.h"
+
+TEST(Hld, Example) {
+  const auto tree = example_tree();
+  const HeavyLightDecomposition hld(tree, 0);
+  // The heavy path from the root is 0-1-5, then 7 or 8
+  EXPECT_EQ(hld.head(0), 0);
+  EXPECT_EQ(hld.head(1), 0);
+  EXPECT_EQ(hld.head(5), 0);
+  EXPECT_EQ(hld.head(4), 4);
+  EXPECT_EQ(hld.head(6), 3);
+  EXPECT_EQ(hld.position(0), 0u);
+  EXPECT_EQ(hld.position(1), 1u);
+  EXPECT_EQ(hld.position(5), 2u);
+  EXPECT_EQ(hld.lca(7, 4), 1);
+  EXPECT_EQ(hld.lca(8, 6), 0);
+  EXPECT_EQ(hld.lca(3, 6), 3);
+  EXPECT_EQ(hld.subtree_range(1), (PositionRange{1, 6}));
+
+  PathQueries<int64_t> sums(tree, 0, {1, 2, 3, 4, 5, 6, 7, 8, 9}, 0);
+  EXPECT_EQ(sums.path(7, 6), 8 + 6 + 2 + 1 + 4 + 7);
+  EXPECT_EQ(sums.path(4, 4), 5);
+  EXPECT_EQ(sums.subtree(1), 2 + 5 + 6 + 8 + 9);
+  sums.set(1, 100);
+  EXPECT_EQ(sums.path(7, 6), 8 + 6 + 100 + 1 + 4 + 7);
+}
+
+TEST(Hld, FewChainsPerPath) {
+  Rng gen(212);
+  for (size_t n : {1, 2, 100, 1000, 5000}) {
+    const auto tree = random_stretched_tree(static_cast<int>(n), 0.5, gen);
+    const HeavyLightDecomposition hld(tree, 0);
+    size_t log_n = 0;
+    while ((size_t{1} << log_n) < n) {
+      ++log_n;
+    }
+    for (int query = 0; query < 200; ++query) {
+      const int u = static_cast<int>(uniform_below(gen, n));
+      const int v = static_cast<int>(uniform_below(gen, n));
+      ASSERT_LE(hld.path_ranges(u, v).size(), 2 * (log_n + 1));
+    }
+  }
+}
+
+TEST(Hld, MatchesNaivePaths) {
+  Rng gen(212);
+  auto larger = [](int64_t a, int64_t b) { return std::max(a, b); };
+  const int64_t lowest = std::numeric_limits<int64_t>::min();
+  for (int trial = 0; trial < 40; ++trial) {
+    const size_t n = 1 + uniform_below(gen, 300);
+    const auto tree =
+        random_stretched_tree(static_cast<int>(n), uniform_unit(gen), gen);
+    const int root = static_cast<int>(uniform_below(gen, n));
+    const RootedTree rooted(tree, root);
+    std::vector<int64_t> values(n);
+    for (auto& v : values) {
+      v = uniform_int<int64_t>(gen, -1000, 1000);
+    }
+
+    PathQueries<int64_t> sums(tree, root, values, 0);
+    PathQueries<int64_t, decltype(larger)> maxima(tree, root, values, lowest,
+                                                  larger);
+    for (int step = 0; step < 200; ++step) {
+      const int u = static_cast<int>(uniform_below(gen, n));
+      const int v = static_cast<int>(uniform_below(gen, n));
+      if (bernoulli(gen, 0.3)) {
+        values[u] = uniform_int<int64_t>(gen, -1000, 1000);
+        sums.set(u, values[u]);
+        maxima.set(u, values[u]);
+      }
+      ASSERT_EQ(sums.decomposition().lca(u, v), lca_naive(rooted, u, v));
+      ASSERT_EQ(sums.path(u, v), path_query_naive(rooted, values, u, v,
+                                                  int64_t{0}, std::plus<>()));
+      ASSERT_EQ(maxima.path(u, v),
+                path_query_naive(rooted, values, u, v, lowest, larger));
+    }
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,145 @@
+#pragma once
+
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+
+#include "../interpolation/Interpolation.h"
+
+// Numerical integration of f over [a, b], from samples of f.
+//
+// - The trapezoid rule joins the samples with straight lines. Its error
+//   shrinks as h^2 with the spacing h, so halving h quarters it.
+// - Simpson's rule puts a parabola through each three samples. The error
+//   goes as h^4, and the rule is exact for cubics, not just quadratics,
+//   since the error of a cubic term cancels between the two halves.
+// - Adaptive Simpson splits [a, b] in half only where Simpson over the
+//   halves and over the whole still disagree, so the samples crowd in
+//   where f is hard and stay sparse where it's easy.
+//
+// Each reports an estimate of its error, from the difference between the
+// rule at two spacings: if the error goes as h^p, the finer answer's error
+// is about their difference over 2^p - 1 (Richardson). The estimate is only
+// as good as the assumption that f is smooth enough for the order to hold.
+
+struct Quadrature {
+  double value = 0;
+  double error_estimate = 0;
+  size_t evaluations = 0;  // Calls to f
+};
+
+// The sum of the trapezoids over n equal panels, h * (f(a)/2 + f(a + h) +
+// ... + f(b)/2)
+template <typename F>
+double trapezoid_sum(F& f, double a, double b, size_t n) {
+  const double h = (b - a) / static_cast<double>(n);
+  double sum = (f(a) + f(b)) / 2;
+  for (size_t i = 1; i < n; ++i) {
+    sum += f(a + static_cast<double>(i) * h);
+  }
+  return sum * h;
+}
+
+// n panels, and n / 2 for the error estimate. n must be even.
+template <typename F>
+Quadrature trapezoid(F f, double a, double b, size_t n) {
+  assert(n >= 2 && n % 2 == 0 && "trapezoid needs an even panel count");
+  size_t evaluations = 0;
+  auto counted = [&](double x) {
+    ++evaluations;
+    return f(x);
+  };
+  const double fine = trapezoid_sum(counted, a, b, n);
+  const double coarse = trapezoid_sum(counted, a, b, n / 2);
+  return {fine, std::fabs(fine - coarse) / 3, evaluations};
+}
+
+// h / 3 * (f(a) + 4 f(a + h) + 2 f(a + 2h) + ... + 4 f(b - h) + f(b))
+template <typename F>
+double simpson_sum(F& f, double a, double b, size_t n) {
+  const double h = (b - a) / static_cast<double>(n);
+  double sum = f(a) + f(b);
+  for (size_t i = 1; i < n; ++i) {
+    sum += (i % 2 == 1 ? 4 : 2) * f(a + static_cast<double>(i) * h);
+  }
+  return sum * h / 3;
+}
+
+// n panels, and n / 2 for the error estimate. n must be a multiple of 4,
+// so that both are even.
+template <typename F>
+Quadrature simpson(F f, double a, double b, size_t n) {
+  assert(n >= 4 && n % 4 == 0 && "simpson needs a multiple of 4 panels");
+  size_t evaluations = 0;
+  auto counted = [&](double x) {
+    ++evaluations;
+    return f(x);
+  };
+  const double fine = simpson_sum(counted, a, b, n);
+  const double coarse = simpson_sum(counted, a, b, n / 2);
+  return {fine, std::fabs(fine - coarse) / 15, evaluations};
+}
+
+// Simpson over [a, b] is whole; if the two halves agree with it to within
+// 15 * tolerance, the halves plus their Richardson correction are the
+// answer, and otherwise each half gets half the tolerance. Past max_depth
+// the best guess at that depth goes in as is, with its estimate.
+template <typename F>
+void adaptive_simpson_step(F& f, double a, double b, double fa, double fm,
+                           double fb, double whole, double tolerance,
+                           int depth, Quadrature& result) {
+  const double m = (a + b) / 2;
+  const double left_m = (a + m) / 2;
+  const double right_m = (m + b) / 2;
+  const double f_left = f(left_m);
+  const double f_right = f(right_m);
+  result.evaluations += 2;
+  const double left = (m - a) / 6 * (fa + 4 * f_left + fm);
+  const double right = (b - m) / 6 * (fm + 4 * f_right + fb);
+  const double difference = left + right - whole;
+  if (depth <= 0 || std::fabs(difference) <= 15 * tolerance) {
+    result.value += left + right + difference / 15;
+    result.error_estimate += std::fabs(difference) / 15;
+    return;
+  }
+  adaptive_simpson_step(f, a, m, fa, f_left, fm, left, tolerance / 2,
+                        depth - 1, result);
+  adaptive_simpson_step(f, m, b, fm, f_right, fb, right, tolerance / 2,
+                        depth - 1, result);
+}
+
+template <typename F>
+Quadrature adaptive_simpson(F f, double a, double b, double tolerance = 1e-10,
+                            int max_depth = 50) {
+  assert(tolerance > 0);
+  Quadrature result;
+  const double fa = f(a);
+  const double fm = f((a + b) / 2);
+  const double fb = f(b);
+  result.evaluations = 3;
+  const double whole = (b - a) / 6 * (fa + 4 * fm + fb);
+  adaptive_simpson_step(f, a, b, fa, fm, fb, whole, tolerance, max_depth,
+                        result);
+  return result;
+}
+
+// The antiderivative of p that's 0 at 0: each c x^d becomes
+// c / (d + 1) x^(d + 1)
+template <typename T>
+Polynomial<T> antiderivative(const Polynomial<T>& p) {
+  Polynomial<T> integral;
+  for (size_t i = 0; i < p.size(); ++i) {
+    const auto degree = static_cast<int64_t>(p.size() - 1 - i);
+    integral.push_back(p[i] / T(degree + 1));
+  }
+  integral.push_back(T(0));
+  return integral;
+}
+
+// The exact integral of p over [a, b], for checking the rules
+template <typename T>
+T integral(const Polynomial<T>& p, const T& a, const T& b) {
+  const Polynomial<T> q = antiderivative(p);
+  return evaluate_at(q, b) - evaluate_at(q, a);
+}
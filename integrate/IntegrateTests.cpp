@@ -0,0 +1,100 @@
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+
+#include "../continued-fractions/Rational.h"
+#include "../rng/Rng.h"
+#include "Integrate.h"
+#include "gtest/gtest.h"
+
+TEST(Integrate, Antiderivative) {
+  // 3x^2 + 2x + 1 integrates to x^3 + x^2 + x
+  const Polynomial<Rational> p = {3, 2, 1};
+  EXPECT_EQ(antiderivative(p), (Polynomial<Rational>{1, 1, 1, 0}));
+  EXPECT_EQ(integral(p, Rational(0), Rational(2)), Rational(14));
+  EXPECT_EQ(integral(Polynomial<Rational>{1, 0}, Rational(-1), Rational(1)),
+            Rational(0));
+}
+
+// Simpson is exact for cubics, up to rounding, however few the panels;
+// the trapezoid rule only for lines
+TEST(Integrate, ExactDegrees) {
+  const Polynomial<double> cubic = {2, -3, 0.5, 7};
+  auto f = [&](double x) { return evaluate_at(cubic, x); };
+  const double exact = integral(cubic, -1.0, 2.0);
+  EXPECT_NEAR(simpson(f, -1, 2, 4).value, exact, 1e-12);
+  EXPECT_NEAR(adaptive_simpson(f, -1, 2).value, exact, 1e-12);
+  EXPECT_EQ(adaptive_simpson(f, -1, 2).evaluations, 5u);
+
+  const Polynomial<double> line = {4, -1};
+  auto g = [&](double x) { return evaluate_at(line, x); };
+  const Quadrature t = trapezoid(g, 0, 3, 2);
+  EXPECT_NEAR(t.value, integral(line, 0.0, 3.0), 1e-12);
+  EXPECT_NEAR(t.error_estimate, 0, 1e-12);
+  EXPECT_EQ(t.evaluations, 5u);
+}
+
+// Halving h divides the error by about 4 for trapezoids and 16 for Simpson
+TEST(Integrate, ConvergenceOrder) {
+  const Polynomial<double> quintic = {1, 0, -2, 1, 0, 3};
+  auto f = [&](double x) { return evaluate_at(quintic, x); };
+  const double exact = integral(quintic, 0.0, 2.0);
+  for (size_t n = 8; n <= 128; n *= 2) {
+    const double trapezoid_ratio =
+        std::fabs(trapezoid(f, 0, 2, n).value - exact) /
+        std::fabs(trapezoid(f, 0, 2, 2 * n).value - exact);
+    EXPECT_NEAR(trapezoid_ratio, 4, 0.1);
+    const double simpson_ratio =
+        std::fabs(simpson(f, 0, 2, n).value - exact) /
+        std::fabs(simpson(f, 0, 2, 2 * n).value - exact);
+    EXPECT_NEAR(simpson_ratio, 16, 1);
+  }
+}
+
+// The error estimates are close to the real errors for smooth functions
+TEST(Integrate, ErrorEstimates) {
+  Rng gen(204);
+  for (int t = 0; t < 50; ++t) {
+    Polynomial<double> p(uniform_int<size_t>(gen, 5, 9));
+    for (double& c : p) {
+      c = uniform_unit(gen) * 4 - 2;
+    }
+    auto f = [&](double x) { return evaluate_at(p, x); };
+    const double a = uniform_unit(gen) * 2 - 1;
+    const double b = a + 0.5 + uniform_unit(gen) * 2;
+    const double exact = integral(p, a, b);
+
+    const Quadrature s = simpson(f, a, b, 64);
+    const double error = std::fabs(s.value - exact);
+    EXPECT_LE(error, 1.5 * s.error_estimate + 1e-13);
+
+    const Quadrature tr = trapezoid(f, a, b, 1024);
+    EXPECT_LE(std::fabs(tr.value - exact), 1.5 * tr.error_estimate + 1e-13);
+
+    const Quadrature adaptive = adaptive_simpson(f, a, b, 1e-9);
+    EXPECT_LE(std::fabs(adaptive.value - exact), 1e-9);
+  }
+}
+
+// Where f has a kink or a steep spot, adaptive sampling beats a uniform grid
+// with the same budget
+TEST(Integrate, Adaptive) {
+  auto root = [](double x) { return std::sqrt(x); };
+  const Quadrature adaptive = adaptive_simpson(root, 0, 1, 1e-10);
+  EXPECT_NEAR(adaptive.value, 2.0 / 3, 1e-9);
+  const size_t panels = adaptive.evaluations / 2 / 4 * 4;
+  const Quadrature uniform = simpson(root, 0, 1, panels);
+  EXPECT_LT(std::fabs(adaptive.value - 2.0 / 3),
+            std::fabs(uniform.value - 2.0 / 3));
+
+  auto bump = [](double x) { return 1 / (1e-4 + x * x); };
+  const double exact = 2 * std::atan(1 / 1e-2) / 1e-2;
+  const Quadrature steep = adaptive_simpson(bump, -1, 1, 1e-8);
+  EXPECT_NEAR(steep.value, exact, 1e-6 * exact);
+  EXPECT_EQ(adaptive_simpson(bump, -1, 1, 1e-8, 0).evaluations, 5u);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
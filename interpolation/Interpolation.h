@@ -0,0 +1,117 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <vector>
+
+// Polynomial interpolation: through n + 1 points with distinct x there's
+// exactly one polynomial of degree at most n, and two ways to write it.
+//
+// Lagrange's form is a sum of one term per point, each the polynomial
+// that's 1 at its own x, 0 at everyone else's, and scaled by its y. It's
+// the direct route to the value at one x, O(n^2).
+//
+// Newton's form builds the polynomial a point at a time:
+//
+//   p(x) = c0 + c1 (x - x0) + c2 (x - x0)(x - x1) + ...
+//
+// where each new term vanishes at every earlier point, so adding a point
+// leaves the earlier coefficients alone. The coefficients are the divided
+// differences, in O(n^2), and multiplying the form out gives the ordinary
+// coefficients.
+//
+// Everything is a template on the number type, so with Rational (from
+// continued-fractions/) the answers are exact; with doubles, high degrees
+// through evenly spaced points are numerically treacherous.
+
+// Coefficients highest degree first, as sequences/ evaluates them
+template <typename T>
+using Polynomial = std::vector<T>;
+
+template <typename T>
+struct Sample {
+  T x;
+  T y;
+};
+
+// Horner's rule, as in evaluate_polynomial in sequences/, for any number
+// type
+template <typename T>
+T evaluate_at(const Polynomial<T>& p, const T& x) {
+  T value(0);
+  for (const T& c : p) {
+    value = value * x + c;
+  }
+  return value;
+}
+
+// c[i] is the divided difference f[x0, ..., xi], computed in place column
+// by column: after pass j, c[i] for i >= j is f[x(i - j), ..., xi]
+template <typename T>
+std::vector<T> divided_differences(const std::vector<Sample<T>>& points) {
+  std::vector<T> c;
+  for (const auto& point : points) {
+    c.push_back(point.y);
+  }
+  for (size_t j = 1; j < points.size(); ++j) {
+    for (size_t i = points.size() - 1; i >= j; --i) {
+      assert(points[i].x != points[i - j].x && "the x must be distinct");
+      c[i] = (c[i] - c[i - 1]) / (points[i].x - points[i - j].x);
+    }
+  }
+  return c;
+}
+
+// The Newton form at x, nested like Horner's rule from the last term in:
+// c0 + (x - x0)(c1 + (x - x1)(c2 + ...))
+template <typename T>
+T newton_at(const std::vector<Sample<T>>& points,
+            const std::vector<T>& differences, const T& x) {
+  assert(points.size() == differences.size());
+  T value(0);
+  for (size_t i = points.size(); i-- > 0;) {
+    value = value * (x - points[i].x) + differences[i];
+  }
+  return value;
+}
+
+// The interpolating polynomial's ordinary coefficients, by multiplying out
+// the Newton form from the inside: p = p * (x - xi) + ci
+template <typename T>
+Polynomial<T> interpolate(const std::vector<Sample<T>>& points) {
+  assert(!points.empty() && "no polynomial through no points");
+  const std::vector<T> c = divided_differences(points);
+  Polynomial<T> p = {c.back()};
+  for (size_t i = points.size() - 1; i-- > 0;) {
+    // Multiplying by x shifts every coefficient up a degree, and
+    // multiplying by -xi scales them in place
+    p.push_back(T(0));
+    for (size_t d = p.size() - 1; d > 0; --d) {
+      p[d] = p[d] - points[i].x * p[d - 1];
+    }
+    p.back() = p.back() + c[i];
+  }
+  // Fewer points can fix a lower degree, with zeros on top
+  while (p.size() > 1 && p.front() == T(0)) {
+    p.erase(p.begin());
+  }
+  return p;
+}
+
+// The interpolating polynomial at x, by Lagrange's form: the sum of
+// y(i) times the product over j != i of (x - x(j)) / (x(i) - x(j))
+template <typename T>
+T lagrange_at(const std::vector<Sample<T>>& points, const T& x) {
+  T sum(0);
+  for (size_t i = 0; i < points.size(); ++i) {
+    T term = points[i].y;
+    for (size_t j = 0; j < points.size(); ++j) {
+      if (j != i) {
+        assert(points[i].x != points[j].x && "the x must be distinct");
+        term = term * (x - points[j].x) / (points[i].x - points[j].x);
+      }
+    }
+    sum = sum + term;
+  }
+  return sum;
+}
@@ -0,0 +1,110 @@
+#include <cmath>
+#include <cstdint>
+#include <vector>
+
+#include "../continued-fractions/Rational.h"
+#include "../rng/Rng.h"
+#include "../sequences/Sequences.h"
+#include "Interpolation.h"
+#include "gtest/gtest.h"
+
+using Points = std::vector<Sample<Rational>>;
+
+Points sample(const Polynomial<Rational>& p, const std::vector<Rational>& xs) {
+  Points points;
+  for (const Rational& x : xs) {
+    points.push_back({x, evaluate_at(p, x)});
+  }
+  return points;
+}
+
+TEST(Interpolation, Examples) {
+  // 2x^2 - 3x + 1 through three points
+  const Points points = {{0, 1}, {1, 0}, {2, 3}};
+  EXPECT_EQ(interpolate(points), (Polynomial<Rational>{2, -3, 1}));
+  EXPECT_EQ(divided_differences(points),
+            (std::vector<Rational>{1, -1, 2}));
+  EXPECT_EQ(lagrange_at(points, Rational(3)), Rational(10));
+  EXPECT_EQ(lagrange_at(points, Rational(1, 2)), Rational(0));
+
+  // Collinear points make a line, not a quadratic with a zero on top
+  EXPECT_EQ(interpolate(Points{{0, 1}, {1, 3}, {5, 11}}),
+            (Polynomial<Rational>{2, 1}));
+  EXPECT_EQ(interpolate(Points{{7, 4}}), (Polynomial<Rational>{4}));
+  EXPECT_EQ(interpolate(Points{{1, 0}, {2, 0}}), (Polynomial<Rational>{0}));
+}
+
+TEST(Interpolation, RecoversPolynomials) {
+  Rng gen(203);
+  for (int t = 0; t < 200; ++t) {
+    const size_t degree = uniform_below(gen, 7);
+    Polynomial<Rational> p(degree + 1);
+    for (Rational& c : p) {
+      c = Rational(uniform_int<int64_t>(gen, -20, 20),
+                   uniform_int<int64_t>(gen, 1, 4));
+    }
+    if (p.front() == Rational(0)) {
+      p.front() = Rational(1);
+    }
+    // Distinct x, in no particular order, and more of them than needed
+    std::vector<Rational> xs;
+    for (int64_t x = -8; x <= 8; ++x) {
+      xs.push_back(Rational(x, 2));
+    }
+    shuffle_range(xs.begin(), xs.end(), gen);
+    xs.resize(degree + 1 + uniform_below(gen, 3));
+    const Points points = sample(p, xs);
+
+    ASSERT_EQ(interpolate(points), p);
+    const auto differences = divided_differences(points);
+    for (int64_t x = -5; x <= 5; ++x) {
+      const Rational at(x, 3);
+      ASSERT_EQ(lagrange_at(points, at), evaluate_at(p, at));
+      ASSERT_EQ(newton_at(points, differences, at), evaluate_at(p, at));
+    }
+  }
+}
+
+// Newton's form takes a new point without disturbing the coefficients it
+// has
+TEST(Interpolation, AddingAPoint) {
+  Points points = {{0, 2}, {1, 3}, {3, 5}};
+  const auto before = divided_differences(points);
+  points.push_back({4, 1});
+  const auto after = divided_differences(points);
+  ASSERT_EQ(after.size(), 4u);
+  EXPECT_EQ(std::vector<Rational>(after.begin(), after.begin() + 3), before);
+}
+
+TEST(Interpolation, Doubles) {
+  const std::vector<double> cubic = {0.5, -1, 0, 3};
+  std::vector<Sample<double>> points;
+  for (double x : {-1.5, 0.0, 1.0, 2.5}) {
+    points.push_back({x, evaluate_polynomial(cubic, x)});
+  }
+  const Polynomial<double> p = interpolate(points);
+  ASSERT_EQ(p.size(), cubic.size());
+  for (size_t i = 0; i < p.size(); ++i) {
+    EXPECT_NEAR(p[i], cubic[i], 1e-12);
+  }
+  EXPECT_NEAR(lagrange_at(points, 0.7), evaluate_polynomial(cubic, 0.7),
+              1e-12);
+
+  // Runge's function through evenly spaced points: the interpolant still
+  // passes through them, but swings wildly near the ends in between
+  std::vector<Sample<double>> runge;
+  for (int i = 0; i <= 10; ++i) {
+    const double x = -1 + 0.2 * i;
+    runge.push_back({x, 1 / (1 + 25 * x * x)});
+  }
+  for (const auto& point : runge) {
+    EXPECT_NEAR(lagrange_at(runge, point.x), point.y, 1e-9);
+  }
+  EXPECT_GT(std::fabs(lagrange_at(runge, 0.95) - 1 / (1 + 25 * 0.95 * 0.95)),
+            1.0);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
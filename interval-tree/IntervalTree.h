@@ -0,0 +1,172 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <utility>
+#include <vector>
+
+#include "../order-statistics/Treap.h"
+#include "../rng/Rng.h"
+
+// The closed interval [low, high]; low <= high
+template <typename T>
+struct Interval {
+  T low;
+  T high;
+
+  bool overlaps(const Interval& other) const {
+    return low <= other.high && other.low <= high;
+  }
+
+  auto operator<=>(const Interval&) const = default;
+};
+
+// Interval tree: a BST of intervals ordered by low end (then high), where
+// every node also knows the largest high end in its subtree. That's the
+// augmentation in order-statistics/'s tree with max in place of size, and
+// it's what lets a query skip subtrees: if the largest high end below a node
+// is left of the query, nothing below it can overlap; and everything to the
+// right of a node starts no earlier than it, so once a node starts right of
+// the query, so does its whole right subtree.
+//
+// Balanced as a treap, with the same split and merge as OrderStatisticTree
+// (order-statistics/Treap.h). O(log n) expected to insert or erase, and
+// O((k + 1) log n) expected to report k overlaps: the search only enters
+// subtrees that hold an overlap or border the path to one. Duplicates are
+// allowed.
+template <typename T>
+class IntervalTree {
+ private:
+  struct Node {
+    Interval<T> key;
+    uint32_t priority;
+    T max_high;
+    std::unique_ptr<Node> left;
+    std::unique_ptr<Node> right;
+
+    Node(Interval<T> k, uint32_t p)
+        : key(std::move(k)), priority(p), max_high(key.high) {}
+
+    void update() {
+      max_high = key.high;
+      for (const Link* child : {&left, &right}) {
+        if (*child && max_high < (*child)->max_high) {
+          max_high = (*child)->max_high;
+        }
+      }
+    }
+  };
+
+  using Link = std::unique_ptr<Node>;
+
+  Link root_;
+  size_t size_{0};
+  Rng gen_;
+
+  // Splits node's tree into keys before x and the rest. With or_equal, keys
+  // equal to x go in the first part too.
+  static std::pair<Link, Link> split(Link node, const Interval<T>& x,
+                                     bool or_equal) {
+    return treap_split(std::move(node), [&](const Interval<T>& key) {
+      return or_equal ? !(x < key) : key < x;
+    });
+  }
+
+  static Link merge(Link a, Link b) {
+    return treap_merge(std::move(a), std::move(b));
+  }
+
+  // Appends every interval under node that overlaps query, in order
+  static void collect(const Node* node, const Interval<T>& query,
+                      std::vector<Interval<T>>& out) {
+    if (!node || node->max_high < query.low) {
+      return;
+    }
+    collect(node->left.get(), query, out);
+    if (query.high < node->key.low) {
+      return;
+    }
+    if (node->key.overlaps(query)) {
+      out.push_back(node->key);
+    }
+    collect(node->right.get(), query, out);
+  }
+
+ public:
+  // The seed drives the priorities: same seed, same shapes
+  explicit IntervalTree(uint64_t seed = 188) : gen_(seed) {}
+
+  size_t size() const { return size_; }
+
+  bool empty() const { return !root_; }
+
+  void insert(Interval<T> interval) {
+    assert(!(interval.high < interval.low) && "interval ends out of order");
+    auto [before, after] = split(std::move(root_), interval, false);
+    Link node = std::make_unique<Node>(std::move(interval),
+                                     static_cast<uint32_t>(gen_() >> 32));
+    root_ = merge(merge(std::move(before), std::move(node)), std::move(after));
+    ++size_;
+  }
+
+  // Removes one copy of interval. False if there was none.
+  bool erase(const Interval<T>& interval) {
+    auto [before, rest] = split(std::move(root_), interval, false);
+    auto [equal, after] = split(std::move(rest), interval, true);
+    const bool found = equal != nullptr;
+    if (found) {
+      equal = merge(std::move(equal->left), std::move(equal->right));
+      --size_;
+    }
+    root_ = merge(merge(std::move(before), std::move(equal)), std::move(after));
+    return found;
+  }
+
+  // Every interval that overlaps query, ordered by low end, then high
+  std::vector<Interval<T>> overlapping(const Interval<T>& query) const {
+    std::vector<Interval<T>> out;
+    collect(root_.get(), query, out);
+    return out;
+  }
+
+  // Every interval that contains point: a stabbing query
+  std::vector<Interval<T>> stabbing(const T& point) const {
+    return overlapping({point, point});
+  }
+
+  // Some interval that overlaps query, along one root-to-leaf path: go left
+  // whenever the left subtree reaches the query at all, since if nothing
+  // there overlaps, nothing to the right can either (CLRS 14.3)
+  std::optional<Interval<T>> any_overlapping(const Interval<T>& query) const {
+    const Node* node = root_.get();
+    while (node && !node->key.overlaps(query)) {
+      if (node->left && !(node->left->max_high < query.low)) {
+        node = node->left.get();
+      } else {
+        node = node->right.get();
+      }
+    }
+    return node ? std::optional<Interval<T>>(node->key) : std::nullopt;
+  }
+
+  // Length of the longest root-to-leaf path, to check the balancing
+  size_t height() const { return treap_height(root_); }
+};
+
+// O(n), for the tests: every interval that overlaps query, sorted
+template <typename T>
+std::vector<Interval<T>> overlapping_naive(std::vector<Interval<T>> intervals,
+                                           const Interval<T>& query) {
+  std::sort(intervals.begin(), intervals.end());
+  std::vector<Interval<T>> out;
+  for (const auto& interval : intervals) {
+    if (interval.overlaps(query)) {
+      out.push_back(interval);
+    }
+  }
+  return out;
+}
@@ -0,0 +1,77 @@
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "IntervalTree.h"
+#include "gtest/gtest.h"
+
+using Span = Interval<int>;
+
+TEST(IntervalTree, Example) {
+  IntervalTree<int> tree;
+  for (Span s : {Span{15, 20}, Span{10, 30}, Span{17, 19}, Span{5, 20},
+                 Span{12, 15}, Span{30, 40}}) {
+    tree.insert(s);
+  }
+  EXPECT_EQ(tree.size(), 6u);
+  EXPECT_EQ(tree.stabbing(16),
+            (std::vector<Span>{{5, 20}, {10, 30}, {15, 20}}));
+  EXPECT_EQ(tree.stabbing(30), (std::vector<Span>{{10, 30}, {30, 40}}));
+  EXPECT_EQ(tree.stabbing(41), std::vector<Span>{});
+  EXPECT_EQ(tree.overlapping({18, 18}),
+            (std::vector<Span>{{5, 20}, {10, 30}, {15, 20}, {17, 19}}));
+  EXPECT_EQ(tree.overlapping({0, 5}), (std::vector<Span>{{5, 20}}));
+  EXPECT_FALSE(tree.any_overlapping({41, 50}));
+  EXPECT_TRUE(tree.any_overlapping({21, 22})->overlaps({21, 22}));
+
+  EXPECT_TRUE(tree.erase({10, 30}));
+  EXPECT_FALSE(tree.erase({10, 30}));
+  EXPECT_EQ(tree.stabbing(30), (std::vector<Span>{{30, 40}}));
+  EXPECT_FALSE(tree.any_overlapping({21, 22}));
+}
+
+TEST(IntervalTree, MatchesBruteForce) {
+  Rng gen(188);
+  IntervalTree<int> tree;
+  std::vector<Span> all;
+  for (int step = 0; step < 3000; ++step) {
+    if (all.empty() || uniform_below(gen, 3) != 0) {
+      const int low = uniform_int(gen, 0, 1000);
+      const Span s{low, low + uniform_int(gen, 0, 50)};
+      tree.insert(s);
+      all.push_back(s);
+    } else {
+      const size_t i = uniform_below(gen, all.size());
+      ASSERT_TRUE(tree.erase(all[i]));
+      all.erase(all.begin() + static_cast<std::ptrdiff_t>(i));
+    }
+    ASSERT_EQ(tree.size(), all.size());
+
+    const int low = uniform_int(gen, -10, 1060);
+    const Span query{low, low + uniform_int(gen, 0, 20)};
+    const auto expected = overlapping_naive(all, query);
+    ASSERT_EQ(tree.overlapping(query), expected);
+    ASSERT_EQ(tree.stabbing(low), overlapping_naive(all, {low, low}));
+    const auto any = tree.any_overlapping(query);
+    ASSERT_EQ(any.has_value(), !expected.empty());
+    if (any) {
+      ASSERT_TRUE(std::count(expected.begin(), expected.end(), *any) > 0);
+    }
+  }
+}
+
+TEST(IntervalTree, SortedInsertsStayShallow) {
+  IntervalTree<int64_t> tree;
+  for (int64_t i = 0; i < 100000; ++i) {
+    tree.insert({i, i + 10});
+  }
+  EXPECT_LT(tree.height(), 60u);
+  EXPECT_EQ(tree.stabbing(50000).size(), 11u);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,173 @@
+#pragma once
+
+#include <algorithm>
+#include <array>
+#include <cassert>
+#include <cstddef>
+#include <optional>
+#include <utility>
+#include <vector>
+
+// A point in D dimensions. geometry/'s LatticePoint {x, y} is
+// KdPoint<int64_t, 2>{x, y}.
+template <typename T, size_t D>
+using KdPoint = std::array<T, D>;
+
+template <typename T, size_t D>
+T squared_distance(const KdPoint<T, D>& a, const KdPoint<T, D>& b) {
+  T sum = 0;
+  for (size_t i = 0; i < D; ++i) {
+    const T d = a[i] - b[i];
+    sum += d * d;
+  }
+  return sum;
+}
+
+template <typename T, size_t D>
+struct Neighbor {
+  KdPoint<T, D> point;
+  T squared_distance;
+};
+
+// k-d tree (Bentley): a BST over points that splits on the x coordinate at
+// the root, y one level down, and so on around the axes. Each subtree then
+// covers a box, and a search can skip any box that's farther away than the
+// best answer so far, or outside the range.
+//
+// The tree is static and implicit: build sorts the points so that each
+// range [begin, end) has its median, on that level's axis, in the middle,
+// with the smaller half before it and the larger after. No pointers, O(n
+// log n) to build with nth_element at each level. Queries are O(log n)
+// expected on well-spread points, and degrade toward O(n) as the dimension
+// grows. With integer coordinates squared distances must fit in T.
+template <typename T, size_t D>
+class KdTree {
+ public:
+  using Point = KdPoint<T, D>;
+
+  explicit KdTree(std::vector<Point> points) : points_(std::move(points)) {
+    build(0, points_.size(), 0);
+  }
+
+  size_t size() const { return points_.size(); }
+
+  bool empty() const { return points_.empty(); }
+
+  // The closest point to query, by Euclidean distance; any of them if
+  // several tie. std::nullopt if the tree is empty.
+  std::optional<Neighbor<T, D>> nearest(const Point& query) const {
+    std::optional<Neighbor<T, D>> best;
+    nearest(0, points_.size(), 0, query, best);
+    return best;
+  }
+
+  // Every point inside the box [low, high], boundary included, in no
+  // particular order
+  std::vector<Point> in_box(const Point& low, const Point& high) const {
+    std::vector<Point> out;
+    in_box(0, points_.size(), 0, low, high, out);
+    return out;
+  }
+
+ private:
+  void build(size_t begin, size_t end, size_t axis) {
+    if (end - begin < 2) {
+      return;
+    }
+    const size_t mid = begin + (end - begin) / 2;
+    std::nth_element(points_.begin() + begin, points_.begin() + mid,
+                     points_.begin() + end,
+                     [axis](const Point& a, const Point& b) {
+                       return a[axis] < b[axis];
+                     });
+    build(begin, mid, (axis + 1) % D);
+    build(mid + 1, end, (axis + 1) % D);
+  }
+
+  void nearest(size_t begin, size_t end, size_t axis, const Point& query,
+               std::optional<Neighbor<T, D>>& best) const {
+    if (begin == end) {
+      return;
+    }
+    const size_t mid = begin + (end - begin) / 2;
+    const Point& here = points_[mid];
+    const T d = squared_distance(here, query);
+    if (!best || d < best->squared_distance) {
+      best = Neighbor<T, D>{here, d};
+    }
+    // The side the query is on first, since that's where the answer
+    // probably is; the other side only if the splitting plane is closer
+    // than the best so far
+    const T gap = query[axis] - here[axis];
+    const size_t next = (axis + 1) % D;
+    if (gap < 0) {
+      nearest(begin, mid, next, query, best);
+      if (gap * gap < best->squared_distance) {
+        nearest(mid + 1, end, next, query, best);
+      }
+    } else {
+      nearest(mid + 1, end, next, query, best);
+      if (gap * gap < best->squared_distance) {
+        nearest(begin, mid, next, query, best);
+      }
+    }
+  }
+
+  void in_box(size_t begin, size_t end, size_t axis, const Point& low,
+              const Point& high, std::vector<Point>& out) const {
+    if (begin == end) {
+      return;
+    }
+    const size_t mid = begin + (end - begin) / 2;
+    const Point& here = points_[mid];
+    bool inside = true;
+    for (size_t i = 0; i < D; ++i) {
+      inside = inside && low[i] <= here[i] && here[i] <= high[i];
+    }
+    if (inside) {
+      out.push_back(here);
+    }
+    // Equal coordinates can land on either side of the median
+    const size_t next = (axis + 1) % D;
+    if (low[axis] <= here[axis]) {
+      in_box(begin, mid, next, low, high, out);
+    }
+    if (here[axis] <= high[axis]) {
+      in_box(mid + 1, end, next, low, high, out);
+    }
+  }
+
+  std::vector<Point> points_;
+};
+
+// O(n) linear scans, for the tests
+
+template <typename T, size_t D>
+std::optional<Neighbor<T, D>> nearest_naive(
+    const std::vector<KdPoint<T, D>>& points, const KdPoint<T, D>& query) {
+  std::optional<Neighbor<T, D>> best;
+  for (const auto& p : points) {
+    const T d = squared_distance(p, query);
+    if (!best || d < best->squared_distance) {
+      best = Neighbor<T, D>{p, d};
+    }
+  }
+  return best;
+}
+
+template <typename T, size_t D>
+std::vector<KdPoint<T, D>> in_box_naive(
+    const std::vector<KdPoint<T, D>>& points, const KdPoint<T, D>& low,
+    const KdPoint<T, D>& high) {
+  std::vector<KdPoint<T, D>> out;
+  for (const auto& p : points) {
+    bool inside = true;
+    for (size_t i = 0; i < D; ++i) {
+      inside = inside && low[i] <= p[i] && p[i] <= high[i];
+    }
+    if (inside) {
+      out.push_back(p);
+    }
+  }
+  return out;
+}
@@ -0,0 +1,93 @@
+#include <algorithm>
+#include <cstdint>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "KdTree.h"
+#include "gtest/gtest.h"
+
+using Point2 = KdPoint<double, 2>;
+using Point3 = KdPoint<int64_t, 3>;
+
+TEST(KdTree, Example) {
+  const std::vector<Point2> points = {{2, 3}, {5, 4}, {9, 6},
+                                      {4, 7}, {8, 1}, {7, 2}};
+  const KdTree<double, 2> tree(points);
+  EXPECT_EQ(tree.size(), 6u);
+
+  auto near = tree.nearest({9, 2});
+  ASSERT_TRUE(near);
+  EXPECT_EQ(near->point, (Point2{8, 1}));
+  EXPECT_EQ(near->squared_distance, 2.0);
+
+  auto box = tree.in_box({3, 1}, {8, 5});
+  std::sort(box.begin(), box.end());
+  EXPECT_EQ(box, (std::vector<Point2>{{5, 4}, {7, 2}, {8, 1}}));
+
+  const KdTree<double, 2> empty({});
+  EXPECT_FALSE(empty.nearest({0, 0}));
+  EXPECT_TRUE(empty.in_box({0, 0}, {1, 1}).empty());
+}
+
+TEST(KdTree, MatchesLinearScan2D) {
+  Rng gen(189);
+  for (size_t n : {1, 2, 3, 10, 1000}) {
+    std::vector<Point2> points(n);
+    for (auto& p : points) {
+      p = {100 * uniform_unit(gen), 100 * uniform_unit(gen)};
+    }
+    const KdTree<double, 2> tree(points);
+    for (int q = 0; q < 200; ++q) {
+      const Point2 query = {120 * uniform_unit(gen) - 10,
+                            120 * uniform_unit(gen) - 10};
+      ASSERT_EQ(tree.nearest(query)->squared_distance,
+                nearest_naive(points, query)->squared_distance);
+
+      const Point2 low = {100 * uniform_unit(gen), 100 * uniform_unit(gen)};
+      const Point2 high = {low[0] + 30 * uniform_unit(gen),
+                           low[1] + 30 * uniform_unit(gen)};
+      auto box = tree.in_box(low, high);
+      auto expected = in_box_naive(points, low, high);
+      std::sort(box.begin(), box.end());
+      std::sort(expected.begin(), expected.end());
+      ASSERT_EQ(box, expected);
+    }
+  }
+}
+
+// Integer coordinates on a small grid, so there are lots of duplicates and
+// points on the splitting planes
+TEST(KdTree, MatchesLinearScan3D) {
+  Rng gen(189);
+  std::vector<Point3> points(2000);
+  for (auto& p : points) {
+    p = {uniform_int<int64_t>(gen, 0, 9), uniform_int<int64_t>(gen, 0, 9),
+         uniform_int<int64_t>(gen, 0, 9)};
+  }
+  const KdTree<int64_t, 3> tree(points);
+  for (int q = 0; q < 500; ++q) {
+    Point3 query;
+    Point3 low;
+    Point3 high;
+    for (size_t i = 0; i < 3; ++i) {
+      query[i] = uniform_int<int64_t>(gen, -3, 12);
+      low[i] = uniform_int<int64_t>(gen, 0, 9);
+      high[i] = low[i] + uniform_int<int64_t>(gen, 0, 3);
+    }
+    const auto near = tree.nearest(query);
+    ASSERT_EQ(near->squared_distance,
+              nearest_naive(points, query)->squared_distance);
+    ASSERT_EQ(squared_distance(near->point, query), near->squared_distance);
+
+    auto box = tree.in_box(low, high);
+    auto expected = in_box_naive(points, low, high);
+    std::sort(box.begin(), box.end());
+    std::sort(expected.begin(), expected.end());
+    ASSERT_EQ(box, expected);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
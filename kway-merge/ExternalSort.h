@@ -0,0 +1,57 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <utility>
+#include <vector>
+
+#include "KwayMerge.h"
+
+// External sorting, for data too big for memory: sort it a memory-full at
+// a time into runs, then merge the runs, at most fan_in of them at once,
+// pass after pass until one is left. A merge only ever looks at the front
+// of each run, so on disk it reads each run in order through one buffer
+// apiece, and memory bounds fan_in rather than the data. n / memory runs
+// take about log(n / memory) / log(fan_in) passes, each reading and
+// writing everything once.
+//
+// Here the runs are vectors standing in for the files.
+
+struct ExternalSortStats {
+  size_t runs{0};    // sorted in memory at the start
+  size_t passes{0};  // of merging
+};
+
+// Stable, like merging sorted runs in order always is
+template <typename T, typename Less = std::less<>>
+std::vector<T> external_sort(const std::vector<T>& input, size_t memory,
+                             size_t fan_in, ExternalSortStats* stats = nullptr,
+                             Less less = Less()) {
+  assert(memory > 0 && fan_in > 1 && "can't make progress");
+  std::vector<std::vector<T>> runs;
+  for (size_t begin = 0; begin < input.size(); begin += memory) {
+    const size_t end = std::min(input.size(), begin + memory);
+    runs.emplace_back(input.begin() + begin, input.begin() + end);
+    std::stable_sort(runs.back().begin(), runs.back().end(), less);
+  }
+  if (stats) {
+    *stats = {runs.size(), 0};
+  }
+  while (runs.size() > 1) {
+    std::vector<std::vector<T>> merged;
+    for (size_t first = 0; first < runs.size(); first += fan_in) {
+      const size_t last = std::min(runs.size(), first + fan_in);
+      std::vector<std::vector<T>> group(
+          std::make_move_iterator(runs.begin() + first),
+          std::make_move_iterator(runs.begin() + last));
+      merged.push_back(merge_sorted(group, Duplicates::kKeep, less));
+    }
+    runs.swap(merged);
+    if (stats) {
+      ++stats->passes;
+    }
+  }
+  return runs.empty() ? std::vector<T>() : std::move(runs[0]);
+}
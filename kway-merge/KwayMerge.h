@@ -0,0 +1,143 @@
+#pragma once
+
+#include <cstddef>
+#include <functional>
+#include <iterator>
+#include <utility>
+#include <vector>
+
+#include "../heap/BinaryHeap.h"
+
+// Merging k sorted sequences into one. A min-heap holds the next element
+// of each input, so the smallest of all is on top; taking it and pushing
+// the element after it in the same input is O(log k), O(n log k) for
+// everything. Ties come out in input order, making the merge stable.
+//
+// The merge is lazy, one element at a time, and says which input each
+// element came from. With Duplicates::kDrop it skips elements equal to
+// the one before, across inputs too, keeping the first of each run of
+// equal elements: the union of the inputs as sets.
+
+enum class Duplicates { kKeep, kDrop };
+
+template <typename It, typename Less = std::less<>>
+class KwayMerge {
+ public:
+  using Value = typename std::iterator_traits<It>::value_type;
+
+  // Where an element is in the inputs
+  struct Merged {
+    It position;
+    size_t source;  // the index of its input
+
+    const Value& value() const { return *position; }
+  };
+
+  // Each input as [begin, end), sorted by less
+  explicit KwayMerge(const std::vector<std::pair<It, It>>& inputs,
+                     Duplicates duplicates = Duplicates::kKeep,
+                     Less less = Less())
+      : ends_(inputs.size()),
+        duplicates_(duplicates),
+        less_(less),
+        heap_(Later{&less_}) {
+    for (size_t i = 0; i < inputs.size(); ++i) {
+      ends_[i] = inputs[i].second;
+      if (inputs[i].first != inputs[i].second) {
+        heap_.push({inputs[i].first, i});
+      }
+    }
+    advance();
+  }
+
+  KwayMerge(const KwayMerge&) = delete;
+  KwayMerge& operator=(const KwayMerge&) = delete;
+
+  bool done() const { return done_; }
+
+  const Merged& current() const { return current_; }
+
+  // Moves on to the next element
+  void next() { advance(); }
+
+  class Iterator {
+   public:
+    const Merged& operator*() const { return owner_->current_; }
+    const Merged* operator->() const { return &owner_->current_; }
+    Iterator& operator++() {
+      owner_->advance();
+      return *this;
+    }
+    bool operator==(const Iterator& other) const {
+      return done() == other.done();
+    }
+
+   private:
+    friend class KwayMerge;
+    explicit Iterator(KwayMerge* owner) : owner_(owner) {}
+    bool done() const { return owner_ == nullptr || owner_->done_; }
+
+    KwayMerge* owner_;
+  };
+
+  // Iterating consumes the merge, so begin() can only be used once
+  Iterator begin() { return Iterator(this); }
+  Iterator end() { return Iterator(nullptr); }
+
+ private:
+  // Orders the heap so that the smallest value, then the earliest input,
+  // is on top
+  struct Later {
+    const Less* less;
+    bool operator()(const Merged& a, const Merged& b) const {
+      if ((*less)(*b.position, *a.position)) {
+        return true;
+      }
+      return !(*less)(*a.position, *b.position) && a.source > b.source;
+    }
+  };
+
+  void advance() {
+    while (!heap_.empty()) {
+      Merged top = heap_.pop();
+      Merged following = {std::next(top.position), top.source};
+      if (following.position != ends_[top.source]) {
+        heap_.push(following);
+      }
+      // Sorted inputs make anything not after the last element equal to it
+      if (duplicates_ == Duplicates::kDrop && started_ &&
+          !less_(*current_.position, *top.position)) {
+        continue;
+      }
+      current_ = top;
+      started_ = true;
+      return;
+    }
+    done_ = true;
+  }
+
+  std::vector<It> ends_;
+  Duplicates duplicates_;
+  Less less_;
+  BinaryHeap<Merged, Later> heap_;
+  Merged current_{};
+  bool started_{false};
+  bool done_{false};
+};
+
+// The inputs merged into a vector
+template <typename T, typename Less = std::less<>>
+std::vector<T> merge_sorted(const std::vector<std::vector<T>>& inputs,
+                            Duplicates duplicates = Duplicates::kKeep,
+                            Less less = Less()) {
+  using It = typename std::vector<T>::const_iterator;
+  std::vector<std::pair<It, It>> ranges;
+  for (const auto& input : inputs) {
+    ranges.push_back({input.begin(), input.end()});
+  }
+  std::vector<T> merged;
+  for (const auto& element : KwayMerge<It, Less>(ranges, duplicates, less)) {
+    merged.push_back(element.value());
+  }
+  return merged;
+}
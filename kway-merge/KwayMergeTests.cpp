@@ -0,0 +1,137 @@
+#include <algorithm>
+#include <cstddef>
+#include <functional>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "ExternalSort.h"
+#include "KwayMerge.h"
+#include "gtest/gtest.h"
+
+using Inputs = std::vector<std::vector<int>>;
+
+Inputs random_inputs(Rng& gen) {
+  Inputs inputs(uniform_below(gen, 7));
+  for (auto& input : inputs) {
+    input.resize(uniform_below(gen, 12));
+    for (int& x : input) {
+      x = uniform_int(gen, 0, 15);
+    }
+    std::sort(input.begin(), input.end());
+  }
+  return inputs;
+}
+
+TEST(KwayMerge, Examples) {
+  const Inputs inputs = {{1, 4, 7}, {}, {2, 4, 8, 9}, {0, 4}};
+  EXPECT_EQ(merge_sorted(inputs),
+            (std::vector<int>{0, 1, 2, 4, 4, 4, 7, 8, 9}));
+  EXPECT_EQ(merge_sorted(inputs, Duplicates::kDrop),
+            (std::vector<int>{0, 1, 2, 4, 7, 8, 9}));
+  EXPECT_TRUE(merge_sorted(Inputs{}).empty());
+  EXPECT_TRUE(merge_sorted(Inputs{{}, {}}).empty());
+
+  // Sorted the other way, with a comparator to match
+  const Inputs descending = {{9, 3}, {8, 3, 1}};
+  EXPECT_EQ(merge_sorted(descending, Duplicates::kDrop, std::greater<>()),
+            (std::vector<int>{9, 8, 3, 1}));
+}
+
+// Which input each element came from, with ties in input order
+TEST(KwayMerge, Provenance) {
+  const std::vector<std::string> a = {"apple", "cherry"};
+  const std::vector<std::string> b = {"banana", "cherry", "date"};
+  using It = std::vector<std::string>::const_iterator;
+  std::vector<std::pair<std::string, size_t>> merged;
+  std::vector<std::pair<It, It>> inputs = {{a.begin(), a.end()},
+                                           {b.begin(), b.end()}};
+  for (const auto& element : KwayMerge<It>(inputs)) {
+    merged.push_back({element.value(), element.source});
+  }
+  EXPECT_EQ(merged, (std::vector<std::pair<std::string, size_t>>{
+                        {"apple", 0}, {"banana", 1}, {"cherry", 0},
+                        {"cherry", 1}, {"date", 1}}));
+
+  // Dropping duplicates keeps the first, and says where it is
+  KwayMerge<It> unique(inputs, Duplicates::kDrop);
+  std::vector<std::pair<size_t, size_t>> positions;
+  for (; !unique.done(); unique.next()) {
+    const auto& input = unique.current().source == 0 ? a : b;
+    positions.push_back(
+        {unique.current().source,
+         static_cast<size_t>(unique.current().position - input.begin())});
+  }
+  EXPECT_EQ(positions, (std::vector<std::pair<size_t, size_t>>{
+                           {0, 0}, {1, 0}, {0, 1}, {1, 2}}));
+}
+
+// Against concatenating and sorting
+TEST(KwayMerge, MatchesSorting) {
+  Rng gen(228);
+  for (int trial = 0; trial < 500; ++trial) {
+    const Inputs inputs = random_inputs(gen);
+    std::vector<std::pair<int, size_t>> expected;
+    for (size_t i = 0; i < inputs.size(); ++i) {
+      for (int x : inputs[i]) {
+        expected.push_back({x, i});
+      }
+    }
+    std::sort(expected.begin(), expected.end());
+
+    using It = std::vector<int>::const_iterator;
+    std::vector<std::pair<It, It>> ranges;
+    for (const auto& input : inputs) {
+      ranges.push_back({input.begin(), input.end()});
+    }
+    std::vector<std::pair<int, size_t>> merged;
+    for (const auto& element : KwayMerge<It>(ranges)) {
+      merged.push_back({element.value(), element.source});
+    }
+    ASSERT_EQ(merged, expected);
+
+    std::vector<int> unique;
+    for (const auto& [x, source] : expected) {
+      unique.push_back(x);
+    }
+    unique.erase(std::unique(unique.begin(), unique.end()), unique.end());
+    ASSERT_EQ(merge_sorted(inputs, Duplicates::kDrop), unique);
+  }
+}
+
+TEST(KwayMerge, ExternalSort) {
+  Rng gen(228);
+  std::vector<int> values(1000);
+  for (int& x : values) {
+    x = uniform_int(gen, -500, 500);
+  }
+  std::vector<int> expected = values;
+  std::sort(expected.begin(), expected.end());
+
+  ExternalSortStats stats;
+  EXPECT_EQ(external_sort(values, 10, 4, &stats), expected);
+  EXPECT_EQ(stats.runs, 100u);
+  EXPECT_EQ(stats.passes, 4u);  // 100, 25, 7, 2, 1 runs
+  EXPECT_EQ(external_sort(values, 2000, 2, &stats), expected);
+  EXPECT_EQ(stats.runs, 1u);
+  EXPECT_EQ(stats.passes, 0u);
+  EXPECT_TRUE(external_sort(std::vector<int>{}, 3, 2).empty());
+
+  // Stable: pairs ordered by their first element keep their input order
+  std::vector<std::pair<int, int>> pairs;
+  for (int i = 0; i < 200; ++i) {
+    pairs.push_back({uniform_int(gen, 0, 9), i});
+  }
+  const auto by_first = [](const auto& a, const auto& b) {
+    return a.first < b.first;
+  };
+  auto stable = pairs;
+  std::stable_sort(stable.begin(), stable.end(), by_first);
+  EXPECT_EQ(external_sort(pairs, 7, 3, nullptr, by_first), stable);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
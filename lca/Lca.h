@@ -0,0 +1,196 @@
+#pragma once
+
+#include <bit>
+#include <cassert>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../graph/Graph.h"
+#include "../graph/Tree.h"
+
+// Lowest common ancestor queries on a rooted tree.
+//
+// The tree is an undirected AdjacencyList on vertices 0..n-1 plus a root.
+// Two classic ways to answer LCA(u, v) after preprocessing:
+//
+// - Binary lifting: store the 2^j-th ancestor of every vertex. Lift the
+//   deeper vertex to the other's depth, then lift both together by the
+//   largest jumps that keep them apart. O(n log n) space, O(log n) query.
+//
+// - Euler tour + RMQ: write down the vertices as a DFS enters and returns to
+//   them. Between the first visits of u and v the tour passes through their
+//   LCA and nothing shallower, so the LCA is the shallowest vertex in that
+//   range. With a sparse table, O(n log n) space and O(1) query.
+
+using LcaQuery = std::pair<int, int>;
+
+class BinaryLiftingLca {
+ private:
+  // up_[j][v] is the 2^j-th ancestor of v, or the root if v isn't that deep
+  std::vector<std::vector<int>> up_;
+  std::vector<int> depth_;
+
+ public:
+  BinaryLiftingLca(const AdjacencyList& tree, int root)
+      : BinaryLiftingLca(RootedTree(tree, root)) {}
+
+  explicit BinaryLiftingLca(const RootedTree& rooted) : depth_(rooted.depth) {
+    const size_t n = rooted.size();
+    const size_t levels = std::bit_width(n);
+    up_.assign(levels, std::vector<int>(n));
+    for (size_t v = 0; v < n; ++v) {
+      up_[0][v] = rooted.parent[v] == -1 ? rooted.root : rooted.parent[v];
+    }
+    for (size_t j = 1; j < levels; ++j) {
+      for (size_t v = 0; v < n; ++v) {
+        up_[j][v] = up_[j - 1][up_[j - 1][v]];
+      }
+    }
+  }
+
+  int depth(int v) const { return depth_[v]; }
+
+  // The k-th ancestor of v (v itself for k = 0), or -1 if v has fewer than
+  // k ancestors
+  int ancestor(int v, int k) const {
+    if (k > depth_[v]) {
+      return -1;
+    }
+    for (size_t j = 0; k > 0; ++j, k >>= 1) {
+      if (k & 1) {
+        v = up_[j][v];
+      }
+    }
+    return v;
+  }
+
+  int query(int u, int v) const {
+    if (depth_[u] < depth_[v]) {
+      std::swap(u, v);
+    }
+    u = ancestor(u, depth_[u] - depth_[v]);
+    if (u == v) {
+      return u;
+    }
+    // Take every jump that keeps u and v apart; afterwards they're children
+    // of the LCA.
+    for (size_t j = up_.size(); j-- > 0;) {
+      if (up_[j][u] != up_[j][v]) {
+        u = up_[j][u];
+        v = up_[j][v];
+      }
+    }
+    return up_[0][u];
+  }
+
+  std::vector<int> query_all(const std::vector<LcaQuery>& queries) const {
+    std::vector<int> result;
+    result.reserve(queries.size());
+    for (const auto& [u, v] : queries) {
+      result.push_back(query(u, v));
+    }
+    return result;
+  }
+
+  int distance(int u, int v) const {
+    return depth_[u] + depth_[v] - 2 * depth_[query(u, v)];
+  }
+};
+
+class EulerTourLca {
+ private:
+  std::vector<int> tour_;   // 2n - 1 vertices
+  std::vector<int> first_;  // Index of each vertex's first visit in tour_
+  std::vector<int> depth_;
+  // sparse_[j][i] is the shallowest vertex in tour_[i, i + 2^j)
+  std::vector<std::vector<int>> sparse_;
+
+  int shallower(int u, int v) const { return depth_[u] <= depth_[v] ? u : v; }
+
+ public:
+  EulerTourLca(const AdjacencyList& tree, int root)
+      : EulerTourLca(tree, RootedTree(tree, root)) {}
+
+  EulerTourLca(const AdjacencyList& tree, const RootedTree& rooted)
+      : first_(rooted.size(), -1), depth_(rooted.depth) {
+    const size_t n = rooted.size();
+    tour_.reserve(2 * n - 1);
+
+    // Iterative DFS so that path-like trees don't overflow the call stack.
+    // Each frame is a vertex and the index of the next neighbor to visit.
+    std::vector<std::pair<int, size_t>> stack = {{rooted.root, 0}};
+    first_[rooted.root] = 0;
+    tour_.push_back(rooted.root);
+    while (!stack.empty()) {
+      auto& [u, next] = stack.back();
+      if (next == tree[u].size()) {
+        stack.pop_back();
+        if (!stack.empty()) {
+          tour_.push_back(stack.back().first);  // Back in the parent
+        }
+        continue;
+      }
+      const int v = tree[u][next++];
+      if (v != rooted.parent[u]) {
+        first_[v] = static_cast<int>(tour_.size());
+        tour_.push_back(v);
+        stack.emplace_back(v, 0);
+      }
+    }
+
+    sparse_.push_back(tour_);
+    for (size_t j = 1; (size_t{1} << j) <= tour_.size(); ++j) {
+      const auto& prev = sparse_[j - 1];
+      const size_t half = size_t{1} << (j - 1);
+      std::vector<int> row(tour_.size() - (size_t{1} << j) + 1);
+      for (size_t i = 0; i < row.size(); ++i) {
+        row[i] = shallower(prev[i], prev[i + half]);
+      }
+      sparse_.push_back(std::move(row));
+    }
+  }
+
+  const std::vector<int>& tour() const { return tour_; }
+
+  int depth(int v) const { return depth_[v]; }
+
+  int query(int u, int v) const {
+    size_t lo = first_[u];
+    size_t hi = first_[v];
+    if (lo > hi) {
+      std::swap(lo, hi);
+    }
+    // Two overlapping power-of-two blocks cover [lo, hi]
+    const size_t j = std::bit_width(hi - lo + 1) - 1;
+    return shallower(sparse_[j][lo], sparse_[j][hi + 1 - (size_t{1} << j)]);
+  }
+
+  std::vector<int> query_all(const std::vector<LcaQuery>& queries) const {
+    std::vector<int> result;
+    result.reserve(queries.size());
+    for (const auto& [u, v] : queries) {
+      result.push_back(query(u, v));
+    }
+    return result;
+  }
+
+  int distance(int u, int v) const {
+    return depth_[u] + depth_[v] - 2 * depth_[query(u, v)];
+  }
+};
+
+// O(depth): climb from the deeper vertex until the two paths meet
+inline int lca_naive(const RootedTree& rooted, int u, int v) {
+  while (rooted.depth[u] > rooted.depth[v]) {
+    u = rooted.parent[u];
+  }
+  while (rooted.depth[v] > rooted.depth[u]) {
+    v = rooted.parent[v];
+  }
+  while (u != v) {
+    u = rooted.parent[u];
+    v = rooted.parent[v];
+  }
+  return u;
+}
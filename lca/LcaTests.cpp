@@ -0,0 +1,121 @@
+#include <stdexcept>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../graph/ExampleTree.h"
+#include "../rng/Rng.h"
+#include "Lca.h"
+#include "gtest/gtest.h"
+
+TEST(Lca, RootedTree) {
+  RootedTree rooted(example_tree(), 0);
+
+  EXPECT_EQ(rooted.parent, (std::vector<int>{-1, 0, 0, 0, 1, 1, 3, 5, 5}));
+  EXPECT_EQ(rooted.depth, (std::vector<int>{0, 1, 1, 1, 2, 2, 2, 3, 3}));
+  EXPECT_EQ(rooted.order.front(), 0);
+}
+
+TEST(Lca, RejectsNonTrees) {
+  // Too many edges, disconnected, and a bad root
+  auto cycle = undirected_from_edges(3, {{0, 1}, {1, 2}, {2, 0}});
+  auto forest = undirected_from_edges(4, {{0, 1}, {2, 3}, {2, 3}});
+
+  EXPECT_THROW(RootedTree(cycle, 0), std::invalid_argument);
+  EXPECT_THROW(RootedTree(forest, 0), std::invalid_argument);
+  EXPECT_THROW(RootedTree(example_tree(), 9), std::invalid_argument);
+}
+
+TEST(Lca, BinaryLifting) {
+  BinaryLiftingLca lca(example_tree(), 0);
+
+  EXPECT_EQ(lca.query(7, 8), 5);
+  EXPECT_EQ(lca.query(7, 4), 1);
+  EXPECT_EQ(lca.query(8, 6), 0);
+  EXPECT_EQ(lca.query(5, 7), 5);
+  EXPECT_EQ(lca.query(2, 2), 2);
+  EXPECT_EQ(lca.distance(7, 6), 5);
+}
+
+TEST(Lca, Ancestor) {
+  BinaryLiftingLca lca(example_tree(), 0);
+
+  EXPECT_EQ(lca.ancestor(7, 0), 7);
+  EXPECT_EQ(lca.ancestor(7, 1), 5);
+  EXPECT_EQ(lca.ancestor(7, 3), 0);
+  EXPECT_EQ(lca.ancestor(7, 4), -1);
+}
+
+TEST(Lca, EulerTour) {
+  EulerTourLca lca(example_tree(), 0);
+
+  EXPECT_EQ(lca.tour(),
+            (std::vector<int>{0, 1, 4, 1, 5, 7, 5, 8, 5, 1, 0, 2, 0, 3, 6, 3,
+                              0}));
+  EXPECT_EQ(lca.query(7, 8), 5);
+  EXPECT_EQ(lca.query(7, 4), 1);
+  EXPECT_EQ(lca.query(8, 6), 0);
+  EXPECT_EQ(lca.query(5, 7), 5);
+  EXPECT_EQ(lca.query(2, 2), 2);
+  EXPECT_EQ(lca.distance(7, 6), 5);
+}
+
+TEST(Lca, DifferentRoot) {
+  BinaryLiftingLca lifting(example_tree(), 7);
+  EulerTourLca euler(example_tree(), 7);
+
+  EXPECT_EQ(lifting.query(4, 6), 1);
+  EXPECT_EQ(euler.query(4, 6), 1);
+  EXPECT_EQ(lifting.query(0, 8), 5);
+  EXPECT_EQ(euler.query(0, 8), 5);
+}
+
+TEST(Lca, SingleVertex) {
+  AdjacencyList tree(1);
+
+  EXPECT_EQ(BinaryLiftingLca(tree, 0).query(0, 0), 0);
+  EXPECT_EQ(EulerTourLca(tree, 0).query(0, 0), 0);
+}
+
+TEST(Lca, LongPath) {
+  // Deep enough to overflow a recursive DFS
+  const int n = 200000;
+  std::vector<Edge> edges;
+  for (int i = 1; i < n; ++i) {
+    edges.emplace_back(i - 1, i);
+  }
+  auto tree = undirected_from_edges(n, edges);
+  BinaryLiftingLca lifting(tree, 0);
+  EulerTourLca euler(tree, 0);
+
+  EXPECT_EQ(lifting.query(n - 1, n / 2), n / 2);
+  EXPECT_EQ(euler.query(n - 1, n / 2), n / 2);
+  EXPECT_EQ(lifting.ancestor(n - 1, n - 1), 0);
+}
+
+TEST(Lca, MethodsAgreeOnRandomTrees) {
+  Rng gen(128);
+  for (int trial = 0; trial < 100; ++trial) {
+    const int n = 1 + static_cast<int>(uniform_below(gen, 200));
+    auto tree = random_stretched_tree(n, uniform_unit(gen), gen);
+    int root = uniform_int(gen, 0, n - 1);
+    RootedTree rooted(tree, root);
+    BinaryLiftingLca lifting(rooted);
+    EulerTourLca euler(tree, rooted);
+
+    std::vector<LcaQuery> queries(300);
+    std::vector<int> expected;
+    for (auto& [u, v] : queries) {
+      u = uniform_int(gen, 0, n - 1);
+      v = uniform_int(gen, 0, n - 1);
+      expected.push_back(lca_naive(rooted, u, v));
+    }
+
+    ASSERT_EQ(lifting.query_all(queries), expected);
+    ASSERT_EQ(euler.query_all(queries), expected);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,78 @@
+#pragma once
+
+#include <cstddef>
+#include <string>
+#include <string_view>
+#include <vector>
+
+// The lexicographically least rotation of a string: the canonical form of
+// a necklace, so two strings are rotations of each other exactly when
+// their least rotations are equal.
+
+// Where the least rotation starts, the first such place if there are
+// several (as in abab). 0 for the empty string.
+//
+// Booth's algorithm: the Knuth-Morris-Pratt failure function over the
+// string doubled, s + s, where every rotation is a substring, computed
+// relative to the best start k found so far. When a mismatch shows that
+// the text is smaller than what the candidate at k predicts, the smaller
+// rotation starts later and k jumps there, keeping the failure values
+// that still apply. Each character moves the scan or k forward, so O(n).
+inline size_t least_rotation(std::string_view s) {
+  const size_t n = s.size();
+  if (n == 0) {
+    return 0;
+  }
+  auto at = [&](size_t i) { return s[i % n]; };
+  // failure[i] is the failure function of the doubled string read from k,
+  // at length i + 1, or -1
+  std::vector<long> failure(2 * n, -1);
+  size_t k = 0;
+  for (size_t j = 1; j < 2 * n; ++j) {
+    const char c = at(j);
+    long i = failure[j - k - 1];
+    while (i != -1 && c != at(k + static_cast<size_t>(i) + 1)) {
+      if (c < at(k + static_cast<size_t>(i) + 1)) {
+        k = j - static_cast<size_t>(i) - 1;
+      }
+      i = failure[static_cast<size_t>(i)];
+    }
+    if (i == -1 && c != at(k)) {
+      if (c < at(k)) {
+        k = j;
+      }
+      failure[j - k] = -1;
+    } else {
+      failure[j - k] = i + 1;
+    }
+  }
+  return k % n;
+}
+
+// s rotated to start at k
+inline std::string rotated(std::string_view s, size_t k) {
+  std::string result(s.substr(k));
+  result.append(s.substr(0, k));
+  return result;
+}
+
+inline std::string least_rotation_string(std::string_view s) {
+  return rotated(s, least_rotation(s));
+}
+
+// Whether b is a rotation of a, by comparing canonical forms. O(n).
+inline bool are_rotations(std::string_view a, std::string_view b) {
+  return a.size() == b.size() &&
+         least_rotation_string(a) == least_rotation_string(b);
+}
+
+// O(n^2): every rotation
+inline size_t least_rotation_naive(std::string_view s) {
+  size_t best = 0;
+  for (size_t k = 1; k < s.size(); ++k) {
+    if (rotated(s, k) < rotated(s, best)) {
+      best = k;
+    }
+  }
+  return best;
+}
@@ -0,0 +1,63 @@
+#include <cstddef>
+#include <string>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "LeastRotation.h"
+#include "gtest/gtest.h"
+
+TEST(LeastRotation, Examples) {
+  EXPECT_EQ(least_rotation(""), 0u);
+  EXPECT_EQ(least_rotation("a"), 0u);
+  EXPECT_EQ(least_rotation("bca"), 2u);
+  EXPECT_EQ(least_rotation("abab"), 0u);
+  EXPECT_EQ(least_rotation("baba"), 1u);
+  EXPECT_EQ(least_rotation("aaaa"), 0u);
+  EXPECT_EQ(least_rotation_string("cabbage"), "abbagec");
+  EXPECT_EQ(least_rotation_string("bbaaccaadd"), "aaccaaddbb");
+
+  EXPECT_TRUE(are_rotations("waterbottle", "erbottlewat"));
+  EXPECT_TRUE(are_rotations("", ""));
+  EXPECT_FALSE(are_rotations("abc", "acb"));
+  EXPECT_FALSE(are_rotations("abc", "abca"));
+}
+
+TEST(LeastRotation, MatchesNaive) {
+  Rng gen(219);
+  for (int trial = 0; trial < 2000; ++trial) {
+    const std::string s =
+        random_text(uniform_below(gen, 30), 1 + uniform_below(gen, 3), gen);
+    ASSERT_EQ(least_rotation(s), least_rotation_naive(s)) << s;
+
+    // Any rotation of s is a rotation of s; changing one character of a
+    // rotation usually isn't
+    const size_t k = s.empty() ? 0 : uniform_below(gen, s.size());
+    const std::string r = rotated(s, k);
+    ASSERT_TRUE(are_rotations(s, r));
+    if (!s.empty()) {
+      std::string changed = r;
+      changed[uniform_below(gen, s.size())] = 'z';
+      ASSERT_FALSE(are_rotations(s, changed));
+    }
+  }
+}
+
+// Periodic strings, where the least rotation appears several times
+TEST(LeastRotation, Periodic) {
+  Rng gen(219);
+  for (int trial = 0; trial < 300; ++trial) {
+    const std::string unit =
+        random_text(1 + uniform_below(gen, 5), 1 + uniform_below(gen, 3), gen);
+    std::string s;
+    for (size_t copies = 1 + uniform_below(gen, 6); copies > 0; --copies) {
+      s += unit;
+    }
+    s = rotated(s, uniform_below(gen, s.size()));
+    ASSERT_EQ(least_rotation(s), least_rotation_naive(s)) << s;
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,349 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cctype>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <optional>
+#include <ostream>
+#include <sstream>
+#include <string>
+#include <thread>
+#include <unordered_map>
+#include <unordered_set>
+#include <utility>
+#include <vector>
+
+#include "../grid/Grid.h"
+#include "../parse/Parse.h"
+
+// Conway's Game of Life: a grid of cells, each alive or dead, all updated
+// at once each generation. A live cell with two or three live neighbors
+// (of its eight) survives, a dead cell with exactly three is born, and
+// every other cell is dead in the next generation.
+//
+// Two boards. A torus is a fixed grid whose edges wrap around, so a glider
+// leaving on the right comes back on the left. The unbounded board keeps
+// only the live cells, in a hash set, so patterns can grow or travel
+// forever, and a generation costs time in the live cells rather than the
+// area.
+
+// A pattern is its live cells, sorted, with (0, 0) the top left corner of
+// the pattern as written
+using Pattern = std::vector<Cell>;
+
+struct CellHash {
+  size_t operator()(Cell c) const {
+    const auto packed = (static_cast<uint64_t>(static_cast<uint32_t>(c.row))
+                         << 32) |
+                        static_cast<uint32_t>(c.col);
+    return std::hash<uint64_t>()(packed);
+  }
+};
+
+// The rule, given whether a cell is alive and how many neighbors are
+inline bool next_alive(bool alive, int neighbors) {
+  return neighbors == 3 || (alive && neighbors == 2);
+}
+
+class Torus {
+ public:
+  Torus(int rows, int cols) : cells_(rows, cols, 0) {
+    assert(rows > 0 && cols > 0 && "a torus needs a cell");
+  }
+
+  // The pattern with its top left corner at `at`, wrapped onto the torus
+  Torus(int rows, int cols, const Pattern& pattern, Cell at = {0, 0})
+      : Torus(rows, cols) {
+    for (Cell c : pattern) {
+      set({c.row + at.row, c.col + at.col}, true);
+    }
+  }
+
+  int rows() const { return cells_.rows(); }
+  int cols() const { return cells_.cols(); }
+  size_t generation() const { return generation_; }
+
+  // Any cell, wrapped onto the torus
+  bool alive(Cell c) const { return cells_[wrap(c)] != 0; }
+  void set(Cell c, bool alive) { cells_[wrap(c)] = alive; }
+
+  size_t population() const { return live_cells().size(); }
+
+  Pattern live_cells() const {
+    Pattern live;
+    for (Cell c : cells_.cells()) {
+      if (cells_[c]) {
+        live.push_back(c);
+      }
+    }
+    return live;
+  }
+
+  void step() {
+    Grid<char> next(rows(), cols(), 0);
+    for (Cell c : cells_.cells()) {
+      int neighbors = 0;
+      for (auto [dr, dc] : kNeighborOffsets) {
+        neighbors += alive({c.row + dr, c.col + dc});
+      }
+      next[c] = next_alive(cells_[c] != 0, neighbors);
+    }
+    cells_ = std::move(next);
+    ++generation_;
+  }
+
+  bool operator==(const Torus& other) const { return cells_ == other.cells_; }
+
+ private:
+  Cell wrap(Cell c) const {
+    const int r = c.row % rows();
+    const int col = c.col % cols();
+    return {r < 0 ? r + rows() : r, col < 0 ? col + cols() : col};
+  }
+
+  Grid<char> cells_;
+  size_t generation_ = 0;
+};
+
+class Life {
+ public:
+  Life() = default;
+
+  explicit Life(const Pattern& pattern, Cell at = {0, 0}) {
+    for (Cell c : pattern) {
+      live_.insert({c.row + at.row, c.col + at.col});
+    }
+  }
+
+  size_t generation() const { return generation_; }
+  size_t population() const { return live_.size(); }
+  bool alive(Cell c) const { return live_.count(c) > 0; }
+
+  void set(Cell c, bool alive) {
+    if (alive) {
+      live_.insert(c);
+    } else {
+      live_.erase(c);
+    }
+  }
+
+  // Sorted, so two boards can be compared
+  Pattern live_cells() const {
+    Pattern live(live_.begin(), live_.end());
+    std::sort(live.begin(), live.end());
+    return live;
+  }
+
+  // Only live cells and their neighbors can be alive next, so each live
+  // cell adds one to the count of each of its neighbors, and the cells
+  // with a count are the only ones to look at
+  void step() {
+    std::unordered_map<Cell, int, CellHash> neighbors;
+    neighbors.reserve(live_.size() * 8);
+    for (Cell c : live_) {
+      for (auto [dr, dc] : kNeighborOffsets) {
+        ++neighbors[{c.row + dr, c.col + dc}];
+      }
+    }
+    std::unordered_set<Cell, CellHash> next;
+    for (const auto& [c, count] : neighbors) {
+      if (next_alive(alive(c), count)) {
+        next.insert(c);
+      }
+    }
+    live_ = std::move(next);
+    ++generation_;
+  }
+
+ private:
+  std::unordered_set<Cell, CellHash> live_;
+  size_t generation_ = 0;
+};
+
+// The smallest box holding every cell, as its top left and bottom right
+// corners, or std::nullopt if there are none
+inline std::optional<std::pair<Cell, Cell>> bounding_box(
+    const Pattern& cells) {
+  if (cells.empty()) {
+    return std::nullopt;
+  }
+  Cell low = cells[0];
+  Cell high = cells[0];
+  for (Cell c : cells) {
+    low = {std::min(low.row, c.row), std::min(low.col, c.col)};
+    high = {std::max(high.row, c.row), std::max(high.col, c.col)};
+  }
+  return std::pair{low, high};
+}
+
+// The rows × cols window with its top left corner at `at`, 'O' for live
+// cells and '.' for dead ones, as in the plaintext format
+inline std::string render_life(const Pattern& cells, Cell at, int rows,
+                               int cols) {
+  std::vector<std::string> lines(rows, std::string(cols, '.'));
+  for (Cell c : cells) {
+    const int r = c.row - at.row;
+    const int col = c.col - at.col;
+    if (0 <= r && r < rows && 0 <= col && col < cols) {
+      lines[r][col] = 'O';
+    }
+  }
+  std::string text;
+  for (const auto& line : lines) {
+    text += line + '\n';
+  }
+  return text;
+}
+
+// Patterns as text, in the two formats the Life community trades them in.
+// Both throw ParseError on anything they don't understand.
+
+// Plaintext (.cells): one line per row, 'O' alive and '.' dead, with lines
+// starting '!' as comments. Rows may stop short; the rest is dead.
+inline Pattern parse_plaintext(const std::string& text) {
+  Pattern pattern;
+  std::istringstream lines(text);
+  std::string line;
+  int row = 0;
+  for (size_t number = 1; std::getline(lines, line); ++number) {
+    if (!line.empty() && line.back() == '\r') {
+      line.pop_back();
+    }
+    if (!line.empty() && line[0] == '!') {
+      continue;
+    }
+    for (size_t col = 0; col < line.size(); ++col) {
+      if (line[col] == 'O' || line[col] == '*') {
+        pattern.push_back({row, static_cast<int>(col)});
+      } else if (line[col] != '.') {
+        throw ParseError("line " + std::to_string(number) + ": bad cell '" +
+                         std::string(1, line[col]) + "'");
+      }
+    }
+    ++row;
+  }
+  return pattern;
+}
+
+// Run length encoded (.rle): a header line "x = 3, y = 3, rule = B3/S23",
+// then runs like "2o" (two live cells), "3b" (three dead), or "2$" (end
+// two rows), finished by '!'. A run without a count is one long. Lines
+// starting '#' before the header are comments. Only Conway's rule, B3/S23,
+// is accepted, since it's the only one the boards play.
+inline Pattern parse_rle(const std::string& text) {
+  std::istringstream lines(text);
+  std::string line;
+  size_t number = 0;
+  bool header = false;
+  while (!header && std::getline(lines, line)) {
+    ++number;
+    if (line.empty() || line[0] == '#') {
+      continue;
+    }
+    std::string fields = line;
+    fields.erase(std::remove_if(fields.begin(), fields.end(),
+                                [](char c) { return c == ' ' || c == '\r'; }),
+                 fields.end());
+    auto rule = fields.find(",rule=");
+    if (fields.rfind("x=", 0) != 0 || fields.find(",y=") == std::string::npos) {
+      throw ParseError("line " + std::to_string(number) +
+                       ": expected the header, x = ..., y = ...");
+    }
+    if (rule != std::string::npos) {
+      std::string name = fields.substr(rule + 6);
+      for (char& c : name) {
+        c = static_cast<char>(std::toupper(static_cast<unsigned char>(c)));
+      }
+      if (name != "B3/S23" && name != "23/3") {
+        throw ParseError("line " + std::to_string(number) +
+                         ": only Conway's rule B3/S23 is supported, not " +
+                         fields.substr(rule + 6));
+      }
+    }
+    header = true;
+  }
+  if (!header) {
+    throw ParseError("rle: missing the header");
+  }
+
+  Pattern pattern;
+  int row = 0;
+  int col = 0;
+  std::string count;
+  while (std::getline(lines, line)) {
+    ++number;
+    for (char c : line) {
+      if ('0' <= c && c <= '9') {
+        count += c;
+        continue;
+      }
+      if (c == ' ' || c == '\t' || c == '\r') {
+        continue;
+      }
+      int run = 1;
+      if (!count.empty()) {
+        if (count.size() > 9) {
+          throw ParseError("line " + std::to_string(number) +
+                           ": run too long");
+        }
+        run = std::stoi(count);
+        count.clear();
+      }
+      if (c == 'o') {
+        for (int i = 0; i < run; ++i) {
+          pattern.push_back({row, col++});
+        }
+      } else if (c == 'b') {
+        col += run;
+      } else if (c == '$') {
+        row += run;
+        col = 0;
+      } else if (c == '!') {
+        std::sort(pattern.begin(), pattern.end());
+        return pattern;
+      } else {
+        throw ParseError("line " + std::to_string(number) + ": bad tag '" +
+                         std::string(1, c) + "'");
+      }
+    }
+  }
+  throw ParseError("rle: missing the closing '!'");
+}
+
+// RLE if there's a header line, plaintext otherwise
+inline Pattern parse_pattern(const std::string& text) {
+  std::istringstream lines(text);
+  std::string line;
+  while (std::getline(lines, line)) {
+    if (line.empty() || line[0] == '#' || line[0] == '!') {
+      continue;
+    }
+    const size_t first = line.find_first_not_of(' ');
+    return first != std::string::npos && line[first] == 'x'
+               ? parse_rle(text)
+               : parse_plaintext(text);
+  }
+  return {};
+}
+
+// Draws `generations` generations after the current one, `delay` apart, in
+// the rows × cols window with its top left corner at `at`. With `clear`,
+// clears a terminal before each so it's drawn over the last one.
+template <typename Board>
+void play_life(Board& board, size_t generations, Cell at, int rows, int cols,
+               std::ostream& out, std::chrono::milliseconds delay,
+               bool clear) {
+  for (size_t i = 0; i <= generations; ++i) {
+    if (i > 0) {
+      std::this_thread::sleep_for(delay);
+      board.step();
+    }
+    out << (clear ? "\x1b[H\x1b[2J" : i > 0 ? "\n" : "") << "generation "
+        << board.generation() << ", population " << board.population() << '\n'
+        << render_life(board.live_cells(), at, rows, cols) << std::flush;
+  }
+}
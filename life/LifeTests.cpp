@@ -0,0 +1,163 @@
+#include <cstddef>
+#include <set>
+#include <sstream>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Life.h"
+#include "gtest/gtest.h"
+
+const char* const kGlider = "bo$2bo$3o!";
+
+const char* const kGosperGun =
+    "#N Gosper glider gun\n"
+    "x = 36, y = 9, rule = B3/S23\n"
+    "24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4b\n"
+    "obo$10bo5bo7bo$11bo3bo$12b2o!\n";
+
+Pattern shifted(const Pattern& pattern, int rows, int cols) {
+  Pattern moved;
+  for (Cell c : pattern) {
+    moved.push_back({c.row + rows, c.col + cols});
+  }
+  return moved;
+}
+
+// One generation by brute force over the box around the live cells
+Pattern step_naive(const Pattern& live) {
+  const std::set<Cell> alive(live.begin(), live.end());
+  Pattern next;
+  const auto box = bounding_box(live);
+  if (!box) {
+    return next;
+  }
+  for (int r = box->first.row - 1; r <= box->second.row + 1; ++r) {
+    for (int c = box->first.col - 1; c <= box->second.col + 1; ++c) {
+      int neighbors = 0;
+      for (int dr = -1; dr <= 1; ++dr) {
+        for (int dc = -1; dc <= 1; ++dc) {
+          neighbors += (dr != 0 || dc != 0) && alive.count({r + dr, c + dc});
+        }
+      }
+      if (next_alive(alive.count({r, c}) > 0, neighbors)) {
+        next.push_back({r, c});
+      }
+    }
+  }
+  return next;
+}
+
+TEST(Life, Oscillators) {
+  const Pattern block = parse_plaintext("OO\nOO\n");
+  const Pattern blinker = parse_plaintext("!Name: Blinker\n...\nOOO\n");
+  Life board(block);
+  board.step();
+  EXPECT_EQ(board.live_cells(), block);
+
+  Life blinking(blinker);
+  blinking.step();
+  EXPECT_EQ(blinking.live_cells(), (Pattern{{0, 1}, {1, 1}, {2, 1}}));
+  blinking.step();
+  EXPECT_EQ(blinking.live_cells(), blinker);
+  EXPECT_EQ(blinking.generation(), 2u);
+
+  Torus torus(5, 5, blinker, {1, 1});
+  torus.step();
+  torus.step();
+  EXPECT_EQ(torus.live_cells(), shifted(blinker, 1, 1));
+}
+
+TEST(Life, GliderTravels) {
+  const Pattern glider = parse_rle("x = 3, y = 3\n" + std::string(kGlider));
+  EXPECT_EQ(glider, parse_plaintext(".O\n..O\nOOO\n"));
+
+  // Every four generations the glider moves one cell down and right, off
+  // forever on the unbounded board, and around and back on the torus
+  Life board(glider);
+  Torus torus(8, 6, glider);
+  for (int g = 0; g < 4 * 24; ++g) {
+    board.step();
+    torus.step();
+  }
+  EXPECT_EQ(board.live_cells(), shifted(glider, 24, 24));
+  EXPECT_EQ(torus.live_cells(), glider);
+  EXPECT_EQ(torus.population(), 5u);
+
+  // A torus wraps patterns placed across its edges
+  const Torus across(4, 4, glider, {3, 3});
+  EXPECT_TRUE(across.alive({3, 0}));
+  EXPECT_TRUE(across.alive({-1, 4}));
+  EXPECT_EQ(across.population(), 5u);
+}
+
+TEST(Life, MatchesNaive) {
+  Rng gen(197);
+  for (int t = 0; t < 20; ++t) {
+    Pattern soup;
+    for (int r = 0; r < 10; ++r) {
+      for (int c = 0; c < 10; ++c) {
+        if (bernoulli(gen, 0.4)) {
+          soup.push_back({r, c});
+        }
+      }
+    }
+    Life board(soup);
+    // Far enough from the edges that nothing wraps in 20 generations
+    Torus torus(60, 60, soup, {25, 25});
+    Pattern expected = soup;
+    for (int g = 0; g < 20; ++g) {
+      board.step();
+      torus.step();
+      expected = step_naive(expected);
+      ASSERT_EQ(board.live_cells(), expected);
+      ASSERT_EQ(torus.live_cells(), shifted(expected, 25, 25));
+    }
+  }
+}
+
+TEST(Life, GosperGun) {
+  Life gun(parse_pattern(kGosperGun));
+  EXPECT_EQ(gun.population(), 36u);
+  const auto box = bounding_box(gun.live_cells());
+  ASSERT_TRUE(box.has_value());
+  EXPECT_EQ(box->second, (Cell{8, 35}));
+
+  // A new glider every 30 generations
+  for (int g = 0; g < 120; ++g) {
+    gun.step();
+  }
+  const Pattern after = gun.live_cells();
+  Life again(after);
+  for (int g = 0; g < 30; ++g) {
+    again.step();
+  }
+  EXPECT_EQ(again.population(), after.size() + 5);
+}
+
+TEST(Life, Parsing) {
+  EXPECT_EQ(parse_pattern("!comment\n.O\nO\n"), (Pattern{{0, 1}, {1, 0}}));
+  EXPECT_EQ(parse_pattern("x = 2, y = 3, rule = b3/s23\n2o2$o!"),
+            (Pattern{{0, 0}, {0, 1}, {2, 0}}));
+  EXPECT_TRUE(parse_pattern("").empty());
+  EXPECT_THROW(parse_plaintext(".O\nOx\n"), ParseError);
+  EXPECT_THROW(parse_rle("bo$2bo$3o!"), ParseError);
+  EXPECT_THROW(parse_rle("x = 3, y = 3\nbo$2bo$3o"), ParseError);
+  EXPECT_THROW(parse_rle("x = 3, y = 3\nbo$2bq!"), ParseError);
+  EXPECT_THROW(parse_rle("x = 3, y = 3, rule = B36/S23\nbo!"), ParseError);
+  EXPECT_EQ(render_life(parse_plaintext(".O\n..O\nOOO\n"), {0, 0}, 3, 4),
+            ".O..\n..O.\nOOO.\n");
+
+  std::ostringstream out;
+  Life board(parse_plaintext("OOO\n"));
+  play_life(board, 1, {-1, 0}, 3, 3, out, std::chrono::milliseconds(0),
+            false);
+  EXPECT_EQ(out.str(),
+            "generation 0, population 3\n...\nOOO\n...\n\n"
+            "generation 1, population 3\n.O.\n.O.\n.O.\n");
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
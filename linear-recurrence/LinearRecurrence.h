@@ -0,0 +1,185 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <utility>
+#include <vector>
+
+// Linear recurrences with constant coefficients,
+//
+//   a(n) = c(1) a(n - 1) + c(2) a(n - 2) + ... + c(k) a(n - k),
+//
+// given c(1), ..., c(k) and the first k terms a(0), ..., a(k - 1), with
+// everything mod m. Fibonacci is c = {1, 1} from {0, 1}.
+//
+// Iterating gets a(n) in O(nk). Two ways do it in O(log n) steps instead:
+//
+// - The companion matrix, which shifts the window (a(i + k - 1), ...,
+//   a(i)) along by one, raised to the nth power by repeated squaring.
+//   O(k^3 log n).
+// - Kitamasa's method, the same idea done with polynomials. Since x^k is
+//   c(1) x^(k - 1) + ... + c(k) modulo the characteristic polynomial
+//   P(x) = x^k - c(1) x^(k - 1) - ... - c(k), any x^n reduces to a
+//   polynomial r(x) of degree below k, and a(n) is the same combination
+//   of the first k terms: the sum of r(i) a(i). Squaring and reducing a
+//   polynomial of degree k is O(k^2), so O(k^2 log n).
+
+// a * b mod m without overflow, in 128 bits
+inline uint64_t mul_mod(uint64_t a, uint64_t b, uint64_t m) {
+  return static_cast<uint64_t>(static_cast<unsigned __int128>(a) * b % m);
+}
+
+inline uint64_t add_mod(uint64_t a, uint64_t b, uint64_t m) {
+  return a >= m - b ? a - (m - b) : a + b;
+}
+
+// The coefficients and initial terms reduced mod m, checked against each
+// other
+struct Recurrence {
+  std::vector<uint64_t> coefficients;  // c(1) first
+  std::vector<uint64_t> initial;       // a(0) first
+  uint64_t modulus;
+
+  Recurrence(const std::vector<uint64_t>& c, const std::vector<uint64_t>& a,
+             uint64_t m)
+      : coefficients(c), initial(a), modulus(m) {
+    assert(!c.empty() && c.size() == a.size() &&
+           "k coefficients need k initial terms");
+    assert(m >= 1 && "the modulus must be positive");
+    for (uint64_t& x : coefficients) {
+      x %= m;
+    }
+    for (uint64_t& x : initial) {
+      x %= m;
+    }
+  }
+
+  size_t order() const { return coefficients.size(); }
+};
+
+// O(nk), for small n and the tests
+inline uint64_t nth_term_naive(const Recurrence& r, uint64_t n) {
+  const size_t k = r.order();
+  std::vector<uint64_t> terms = r.initial;
+  for (uint64_t i = k; i <= n; ++i) {
+    uint64_t next = 0;
+    for (size_t j = 1; j <= k; ++j) {
+      next = add_mod(next, mul_mod(r.coefficients[j - 1],
+                                   terms[terms.size() - j], r.modulus),
+                     r.modulus);
+    }
+    terms.push_back(next);
+  }
+  return terms[n];
+}
+
+// Square matrices mod m
+using ModMatrix = std::vector<std::vector<uint64_t>>;
+
+inline ModMatrix multiply_mod(const ModMatrix& a, const ModMatrix& b,
+                              uint64_t m) {
+  const size_t k = a.size();
+  ModMatrix c(k, std::vector<uint64_t>(k, 0));
+  for (size_t i = 0; i < k; ++i) {
+    for (size_t l = 0; l < k; ++l) {
+      if (a[i][l] == 0) {
+        continue;
+      }
+      for (size_t j = 0; j < k; ++j) {
+        c[i][j] = add_mod(c[i][j], mul_mod(a[i][l], b[l][j], m), m);
+      }
+    }
+  }
+  return c;
+}
+
+// The companion matrix takes (a(i + k - 1), ..., a(i)) to (a(i + k), ...,
+// a(i + 1)): its first row is the coefficients, and below that the
+// identity shifted down a row. Its nth power applied to the initial
+// window, (a(k - 1), ..., a(0)), has a(n) in the last place.
+inline uint64_t nth_term_matrix(const Recurrence& r, uint64_t n) {
+  const size_t k = r.order();
+  const uint64_t m = r.modulus;
+  ModMatrix power(k, std::vector<uint64_t>(k, 0));
+  power[0] = r.coefficients;
+  for (size_t i = 1; i < k; ++i) {
+    power[i][i - 1] = 1 % m;
+  }
+  ModMatrix result(k, std::vector<uint64_t>(k, 0));
+  for (size_t i = 0; i < k; ++i) {
+    result[i][i] = 1 % m;
+  }
+  for (; n > 0; n >>= 1) {
+    if (n & 1) {
+      result = multiply_mod(result, power, m);
+    }
+    power = multiply_mod(power, power, m);
+  }
+  uint64_t term = 0;
+  for (size_t j = 0; j < k; ++j) {
+    term = add_mod(term, mul_mod(result[k - 1][j], r.initial[k - 1 - j], m),
+                   m);
+  }
+  return term;
+}
+
+// Reduces a polynomial (lowest degree first) modulo the characteristic
+// polynomial, top degree down: x^d is x^(d - k) times x^k, which becomes
+// c(1) x^(d - 1) + ... + c(k) x^(d - k)
+inline std::vector<uint64_t> reduce_mod_characteristic(
+    std::vector<uint64_t> p, const Recurrence& r) {
+  const size_t k = r.order();
+  for (size_t d = p.size(); d-- > k;) {
+    if (p[d] == 0) {
+      continue;
+    }
+    for (size_t j = 1; j <= k; ++j) {
+      p[d - j] = add_mod(p[d - j], mul_mod(p[d], r.coefficients[j - 1],
+                                           r.modulus),
+                         r.modulus);
+    }
+  }
+  p.resize(k, 0);
+  return p;
+}
+
+inline std::vector<uint64_t> multiply_mod_characteristic(
+    const std::vector<uint64_t>& a, const std::vector<uint64_t>& b,
+    const Recurrence& r) {
+  std::vector<uint64_t> product(a.size() + b.size() - 1, 0);
+  for (size_t i = 0; i < a.size(); ++i) {
+    if (a[i] == 0) {
+      continue;
+    }
+    for (size_t j = 0; j < b.size(); ++j) {
+      product[i + j] = add_mod(product[i + j], mul_mod(a[i], b[j], r.modulus),
+                               r.modulus);
+    }
+  }
+  return reduce_mod_characteristic(std::move(product), r);
+}
+
+// Kitamasa: x^n modulo P(x) by repeated squaring, then the same
+// combination of the initial terms
+inline uint64_t nth_term(const Recurrence& r, uint64_t n) {
+  const uint64_t m = r.modulus;
+  std::vector<uint64_t> result = reduce_mod_characteristic({1 % m}, r);
+  std::vector<uint64_t> power = reduce_mod_characteristic({0, 1 % m}, r);
+  for (; n > 0; n >>= 1) {
+    if (n & 1) {
+      result = multiply_mod_characteristic(result, power, r);
+    }
+    power = multiply_mod_characteristic(power, power, r);
+  }
+  uint64_t term = 0;
+  for (size_t i = 0; i < r.order(); ++i) {
+    term = add_mod(term, mul_mod(result[i], r.initial[i], m), m);
+  }
+  return term;
+}
+
+// F(n) mod m, with F(0) = 0 and F(1) = 1
+inline uint64_t fibonacci_mod(uint64_t n, uint64_t m) {
+  return nth_term(Recurrence({1, 1}, {0, 1}, m), n);
+}
@@ -0,0 +1,60 @@
+#include <cstdint>
+#include <vector>
+
+#include "../fibonacci/Fibonacci.h"
+#include "../rng/Rng.h"
+#include "LinearRecurrence.h"
+#include "gtest/gtest.h"
+
+TEST(LinearRecurrence, Fibonacci) {
+  // F(93) is the largest that fits in 64 bits, so mod 2^64 - 1 is exact
+  const uint64_t all = UINT64_MAX;
+  for (int n = 0; n <= 93; ++n) {
+    ASSERT_EQ(fibonacci_mod(static_cast<uint64_t>(n), all), lin_fib(n));
+  }
+  EXPECT_EQ(fibonacci_mod(1'000'000'000'000'000'000, 1'000'000'007),
+            209783453u);
+  EXPECT_EQ(fibonacci_mod(UINT64_MAX, (uint64_t{1} << 63) + 1),
+            7406001816220101517u);
+  // The last digits repeat every 60, the Pisano period of 10
+  for (uint64_t n = 0; n < 200; ++n) {
+    ASSERT_EQ(fibonacci_mod(n + 60'000'000'000, 10), fibonacci_mod(n, 10));
+  }
+  EXPECT_EQ(fibonacci_mod(12345, 1), 0u);
+}
+
+TEST(LinearRecurrence, Tribonacci) {
+  const Recurrence tribonacci({1, 1, 1}, {0, 0, 1}, 998'244'353);
+  const std::vector<uint64_t> start = {0, 0, 1, 1, 2, 4, 7, 13, 24, 44, 81};
+  for (uint64_t n = 0; n < start.size(); ++n) {
+    EXPECT_EQ(nth_term(tribonacci, n), start[n]);
+    EXPECT_EQ(nth_term_matrix(tribonacci, n), start[n]);
+  }
+  EXPECT_EQ(nth_term(tribonacci, 1'000'000'000'000'000), 990728666u);
+  EXPECT_EQ(nth_term_matrix(tribonacci, 1'000'000'000'000'000), 990728666u);
+}
+
+TEST(LinearRecurrence, MatchesIteration) {
+  Rng gen(202);
+  for (int t = 0; t < 500; ++t) {
+    const size_t k = uniform_int<size_t>(gen, 1, 6);
+    const uint64_t m = bernoulli(gen, 0.5) ? uniform_int<uint64_t>(gen, 1, 50)
+                                           : gen() | 1;
+    std::vector<uint64_t> c(k);
+    std::vector<uint64_t> a(k);
+    for (size_t i = 0; i < k; ++i) {
+      c[i] = gen();
+      a[i] = gen();
+    }
+    const Recurrence r(c, a, m);
+    const uint64_t n = uniform_below(gen, 300);
+    const uint64_t expected = nth_term_naive(r, n);
+    ASSERT_EQ(nth_term(r, n), expected) << k << " " << m << " " << n;
+    ASSERT_EQ(nth_term_matrix(r, n), expected);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
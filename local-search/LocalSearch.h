@@ -0,0 +1,79 @@
+#pragma once
+
+#include <cmath>
+#include <cstddef>
+#include <utility>
+
+#include "../rng/Rng.h"
+
+// Local search: start somewhere, and keep moving to a random neighbor of the
+// current state. The problem supplies
+//
+// - cost(s), to be minimized, and
+// - neighbor(s, gen), a random small change to s drawn from gen.
+//
+// Hill climbing only takes moves that don't make things worse, so it gets
+// stuck in the first local minimum. Simulated annealing also takes a move
+// that's worse by delta with probability exp(-delta / T), which lets it climb
+// out of local minima while the temperature T is high. As T cools it turns
+// into hill climbing. Neither comes with a guarantee; both return the best
+// state they saw.
+
+template <typename State>
+struct LocalSearchResult {
+  State best;
+  double cost;
+  size_t accepted{0};  // Moves taken, better or worse
+};
+
+// T(step) = initial * factor^step, the usual schedule
+struct GeometricCooling {
+  double initial;
+  double factor;
+
+  double operator()(size_t step) const {
+    return initial * std::pow(factor, static_cast<double>(step));
+  }
+};
+
+// Runs for `steps` proposed moves at temperature schedule(step), stopping
+// early if the cost reaches `target`
+template <typename State, typename Cost, typename Neighbor, typename Schedule,
+          typename Gen>
+LocalSearchResult<State> simulated_annealing(State start, Cost cost,
+                                             Neighbor neighbor,
+                                             Schedule schedule, size_t steps,
+                                             Gen& gen, double target = 0.0) {
+  double current_cost = cost(start);
+  LocalSearchResult<State> result{start, current_cost};
+  State current = std::move(start);
+  for (size_t step = 0; step < steps && result.cost > target; ++step) {
+    State candidate = neighbor(current, gen);
+    const double candidate_cost = cost(candidate);
+    const double delta = candidate_cost - current_cost;
+    const double temperature = schedule(step);
+    if (delta <= 0 ||
+        (temperature > 0 &&
+         uniform_unit(gen) < std::exp(-delta / temperature))) {
+      current = std::move(candidate);
+      current_cost = candidate_cost;
+      ++result.accepted;
+      if (current_cost < result.cost) {
+        result.best = current;
+        result.cost = current_cost;
+      }
+    }
+  }
+  return result;
+}
+
+// Annealing frozen at T = 0: only moves that don't increase the cost. Equal
+// moves are taken, so it can drift along plateaus.
+template <typename State, typename Cost, typename Neighbor, typename Gen>
+LocalSearchResult<State> hill_climb(State start, Cost cost, Neighbor neighbor,
+                                    size_t steps, Gen& gen,
+                                    double target = 0.0) {
+  return simulated_annealing(
+      std::move(start), cost, neighbor, [](size_t) { return 0.0; }, steps,
+      gen, target);
+}
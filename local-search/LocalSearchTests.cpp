@@ -0,0 +1,169 @@
+#include <algorithm>
+#include <cmath>
+#include <cstddef>
+#include <numbers>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "LocalSearch.h"
+#include "NQueens.h"
+#include "TspMoves.h"
+#include "gtest/gtest.h"
+
+std::vector<Point> random_points(Rng& gen, size_t n) {
+  std::vector<Point> points(n);
+  for (auto& p : points) {
+    p = {100 * uniform_unit(gen), 100 * uniform_unit(gen)};
+  }
+  return points;
+}
+
+std::vector<int> identity_rows(int n) {
+  std::vector<int> rows(n);
+  for (int i = 0; i < n; ++i) {
+    rows[i] = i;
+  }
+  return rows;
+}
+
+bool is_solution(const std::vector<int>& rows) {
+  for (size_t a = 0; a < rows.size(); ++a) {
+    for (size_t b = a + 1; b < rows.size(); ++b) {
+      if (rows[a] == rows[b] ||
+          std::abs(rows[a] - rows[b]) == static_cast<int>(b - a)) {
+        return false;
+      }
+    }
+  }
+  return true;
+}
+
+TEST(LocalSearch, DiagonalConflicts) {
+  EXPECT_EQ(diagonal_conflicts({}), 0);
+  // All on the main diagonal: every pair conflicts
+  EXPECT_EQ(diagonal_conflicts(identity_rows(5)), 10);
+  EXPECT_EQ(diagonal_conflicts({1, 3, 0, 2}), 0);
+  EXPECT_EQ(diagonal_conflicts({0, 2, 1, 3}), 2);
+}
+
+TEST(LocalSearch, BacktrackingFindsAllQueens) {
+  const std::vector<size_t> counts = {1, 1, 0, 0, 2, 10, 4, 40, 92, 352};
+  for (int n = 0; n < static_cast<int>(counts.size()); ++n) {
+    EXPECT_EQ(count_n_queens(n), counts[n]) << n;
+  }
+  for (const auto& rows : n_queens(8)) {
+    std::vector<int> sorted = rows;
+    std::sort(sorted.begin(), sorted.end());
+    ASSERT_EQ(sorted, (std::vector<int>{0, 1, 2, 3, 4, 5, 6, 7}));
+    ASSERT_EQ(diagonal_conflicts(rows), 0);
+  }
+  EXPECT_EQ(n_queens(4), (std::vector<std::vector<int>>{{1, 3, 0, 2},
+                                                        {2, 0, 3, 1}}));
+}
+
+TEST(LocalSearch, AnnealingSolvesNQueens) {
+  Rng gen(153);
+  auto cost = [](const std::vector<int>& rows) {
+    return diagonal_conflicts(rows);
+  };
+  for (int n : {8, 20, 50}) {
+    auto result = simulated_annealing(
+        identity_rows(n), cost, swap_two_queens<Rng>,
+        GeometricCooling{2.0, 0.9995}, 200000, gen);
+
+    EXPECT_EQ(result.cost, 0) << n;
+    EXPECT_TRUE(is_solution(result.best)) << n;
+  }
+}
+
+TEST(LocalSearch, AnnealingBeatsHillClimbingOnTsp) {
+  Rng gen(154);
+  double annealed_total = 0.0;
+  double climbed_total = 0.0;
+  const int trials = 10;
+  for (int trial = 0; trial < trials; ++trial) {
+    auto dist = euclidean_distances(random_points(gen, 11));
+    auto cost = [&](const std::vector<int>& order) {
+      return tour_cost(dist, order);
+    };
+    const double optimal = held_karp(dist).cost;
+    const auto start = identity_tour(11);
+
+    auto annealed =
+        simulated_annealing(start, cost, reverse_random_stretch<Rng>,
+                            GeometricCooling{50.0, 0.999}, 20000, gen);
+    auto climbed =
+        hill_climb(start, cost, reverse_random_stretch<Rng>, 20000,
+                   gen);
+
+    ASSERT_NEAR(cost(annealed.best), annealed.cost, 1e-9);
+    ASSERT_NEAR(cost(climbed.best), climbed.cost, 1e-9);
+    ASSERT_GE(annealed.cost, optimal - 1e-9);
+    ASSERT_GE(climbed.cost, optimal - 1e-9);
+    annealed_total += annealed.cost / optimal;
+    climbed_total += climbed.cost / optimal;
+  }
+  EXPECT_LT(annealed_total / trials, 1.01);
+  EXPECT_LE(annealed_total, climbed_total + 1e-9);
+}
+
+TEST(LocalSearch, AnnealingUntanglesACircle) {
+  const int n = 30;
+  std::vector<Point> points;
+  Rng gen(155);
+  auto order = identity_tour(n);
+  shuffle_range(order.begin(), order.end(), gen);
+  for (int i : order) {
+    double angle = 2 * std::numbers::pi * i / n;
+    points.push_back({std::cos(angle), std::sin(angle)});
+  }
+  auto dist = euclidean_distances(points);
+  auto cost = [&](const std::vector<int>& tour) {
+    return tour_cost(dist, tour);
+  };
+  double perimeter = n * 2 * std::sin(std::numbers::pi / n);
+
+  auto result =
+      simulated_annealing(identity_tour(n), cost,
+                          reverse_random_stretch<Rng>,
+                          GeometricCooling{1.0, 0.9998}, 100000, gen);
+  EXPECT_NEAR(result.cost, perimeter, 1e-9);
+}
+
+TEST(LocalSearch, Reproducible) {
+  auto cost = [](const std::vector<int>& rows) {
+    return diagonal_conflicts(rows);
+  };
+  Rng a(156);
+  Rng b(156);
+  auto first = simulated_annealing(identity_rows(30), cost,
+                                   swap_two_queens<Rng>,
+                                   GeometricCooling{2.0, 0.999}, 5000, a, -1);
+  auto second = simulated_annealing(identity_rows(30), cost,
+                                    swap_two_queens<Rng>,
+                                    GeometricCooling{2.0, 0.999}, 5000, b, -1);
+
+  EXPECT_EQ(first.best, second.best);
+  EXPECT_EQ(first.accepted, second.accepted);
+}
+
+TEST(LocalSearch, HillClimbingNeverGoesUphill) {
+  Rng gen(157);
+  auto cost = [](const std::vector<int>& rows) {
+    return diagonal_conflicts(rows);
+  };
+  // With a single step, the result is the start or a better neighbor
+  for (int trial = 0; trial < 100; ++trial) {
+    auto start = identity_rows(10);
+    shuffle_range(start.begin(), start.end(), gen);
+    auto result = hill_climb(start, cost, swap_two_queens<Rng>, 1,
+                             gen);
+    ASSERT_LE(result.cost, cost(start));
+    ASSERT_LE(result.accepted, 1u);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
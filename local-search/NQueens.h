@@ -0,0 +1,107 @@
+#pragma once
+
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../backtracking/Backtracking.h"
+#include "../rng/Rng.h"
+
+// N queens: n queens on an n x n board, no two in the same row, column, or
+// diagonal. A state puts the queen of column c in row rows[c], and there
+// are two ways to find one:
+//
+// - Backtracking, with the engine from backtracking/, finds them all.
+// - Local search keeps rows a permutation, so no two queens ever share a
+//   row or a column. The cost is the number of pairs that share a
+//   diagonal, and a move swaps the rows of two columns. It finds one
+//   solution, for n far beyond what backtracking can reach.
+
+inline int diagonal_conflicts(const std::vector<int>& rows) {
+  const int n = static_cast<int>(rows.size());
+  // Queens on a diagonal share row - col; on an anti-diagonal, row + col
+  std::vector<int> down(2 * n, 0);
+  std::vector<int> up(2 * n, 0);
+  int conflicts = 0;
+  for (int c = 0; c < n; ++c) {
+    conflicts += down[rows[c] - c + n]++;
+    conflicts += up[rows[c] + c]++;
+  }
+  return conflicts;
+}
+
+// Backtracking, a column at a time: the choice is the queen's row, and the
+// constraint is that no earlier queen shares its row or either diagonal,
+// indexed as in diagonal_conflicts. Solutions in increasing order.
+class QueensSearch {
+ public:
+  using Choice = int;  // the row for the next column
+
+  explicit QueensSearch(int n)
+      : n_(n), row_(n, false), down_(2 * n, false), up_(2 * n, false) {}
+
+  bool is_complete() const { return static_cast<int>(rows_.size()) == n_; }
+
+  std::vector<Choice> choices() const {
+    std::vector<Choice> rows(n_);
+    for (int r = 0; r < n_; ++r) {
+      rows[r] = r;
+    }
+    return rows;
+  }
+
+  bool accepts(Choice r) const {
+    const int c = static_cast<int>(rows_.size());
+    return !row_[r] && !down_[r - c + n_] && !up_[r + c];
+  }
+
+  void choose(Choice r) { mark(r, true); }
+
+  void unchoose(Choice r) { mark(r, false); }
+
+  const std::vector<int>& solution() const { return rows_; }
+
+ private:
+  void mark(Choice r, bool taken) {
+    if (!taken) {
+      rows_.pop_back();
+    }
+    const int c = static_cast<int>(rows_.size());
+    row_[r] = down_[r - c + n_] = up_[r + c] = taken;
+    if (taken) {
+      rows_.push_back(r);
+    }
+  }
+
+  int n_;
+  std::vector<int> rows_;
+  // Whether a queen is in a row, a diagonal r - c, or a diagonal r + c
+  std::vector<bool> row_;
+  std::vector<bool> down_;
+  std::vector<bool> up_;
+};
+
+inline std::vector<std::vector<int>> n_queens(int n) {
+  QueensSearch search(n);
+  return collect_solutions(search);
+}
+
+inline size_t count_n_queens(int n) {
+  QueensSearch search(n);
+  return count_solutions(search).solutions;
+}
+
+template <typename Gen>
+std::vector<int> swap_two_queens(const std::vector<int>& rows, Gen& gen) {
+  std::vector<int> next = rows;
+  if (next.size() < 2) {
+    return next;
+  }
+  const size_t a = uniform_below(gen, next.size());
+  size_t b = uniform_below(gen, next.size());
+  while (b == a) {
+    b = uniform_below(gen, next.size());
+  }
+  std::swap(next[a], next[b]);
+  return next;
+}
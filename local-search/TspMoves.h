@@ -0,0 +1,37 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "../tsp/Tsp.h"
+
+// TSP as local search: a state is a tour starting at city 0, and a move is a
+// random 2-opt move, reversing a stretch of the tour that leaves city 0 in
+// place.
+template <typename Gen>
+std::vector<int> reverse_random_stretch(const std::vector<int>& order,
+                                        Gen& gen) {
+  std::vector<int> next = order;
+  if (next.size() < 3) {
+    return next;
+  }
+  size_t i = uniform_int<size_t>(gen, 1, next.size() - 1);
+  size_t j = uniform_int<size_t>(gen, 1, next.size() - 1);
+  if (i > j) {
+    std::swap(i, j);
+  }
+  std::reverse(next.begin() + i, next.begin() + j + 1);
+  return next;
+}
+
+// The cities in order, as a starting tour
+inline std::vector<int> identity_tour(size_t n) {
+  std::vector<int> order(n);
+  for (size_t i = 0; i < n; ++i) {
+    order[i] = static_cast<int>(i);
+  }
+  return order;
+}
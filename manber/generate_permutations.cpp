@@ -1,79 +1,15 @@
-/*
-Problem:
-    Generate all permutations of a given word
-
-Solution:
-    Base case (pos=0): word of size one
-        Return the word as a single permutation
-
-    Induction hypothesis:
-        We know how to generate all permutations of a word with one less
-character
-
-    Induction step:
-        Remove the character at position pos from the word.
-        Generate all permutations of the word with the character removed (using
-        induction hypothesis).
-        For each permutation, interpolate the removed character at every
-        possible position.
-        Return the concatenation of all these interpolated results.
-*/
+// Generate all permutations of a given word; the algorithm is in
+// generate_permutations.h
 
 #include <cassert>
 #include <iostream>
 #include <set>
+#include <stdexcept>
 #include <string>
+#include <string_view>
 #include <vector>
 
-/*
-Insert character ch at every possible position in word.
-
-We need to iterate up to len(word) + 1 because we want to insert the character
-ch at:
-- Before the first character (position 0)
-- Between each pair of characters (positions 1, 2, ..., len(word)-1)
-- After the last character (position len(word))
-
-For example, with word = "ab" (length 2), we need 3 positions:
-- i = 0: "" + ch + "ab" --> "cab"
-- i = 1: "a" + ch + "b" --> "acb"
-- i = 2: "ab" + ch + "" --> "abc"
-*/
-std::vector<std::string> interpolate(char ch, const std::string& word) {
-  std::vector<std::string> res;
-  res.reserve(word.length() + 1);
-  for (size_t i = 0; i <= word.length(); ++i) {
-    // word.substr(0, i) will be empty when i == 0
-    // word.substr(i) will be empty when i == word.length()
-    auto interpolation = word.substr(0, i) + ch + word.substr(i);
-    res.push_back(interpolation);
-  }
-  return res;
-}
-
-std::vector<std::string> generate_permutations(const std::string& word,
-                                               size_t pos) {
-  if (pos == 0) {
-    return {word};
-  }
-
-  // Word with ch removed where ch is the char at word[pos]
-  std::string smaller_word = word.substr(0, pos) + word.substr(pos + 1);
-
-  // All permutations of word without ch
-  auto perms = generate_permutations(smaller_word, pos - 1);
-
-  std::vector<std::string> res;
-
-  // Interpolate ch back into every position of every permutation of the word
-  char ch = word[pos];
-  for (const auto& perm : perms) {
-    auto interpolations = interpolate(ch, perm);
-    res.insert(res.end(), interpolations.begin(), interpolations.end());
-  }
-
-  return res;
-}
+#include "generate_permutations.h"
 
 void print_permutations(const std::string& word) {
   if (word.empty()) {
@@ -81,25 +17,38 @@ void print_permutations(const std::string& word) {
   }
 
   auto perms = generate_permutations(word, word.length() - 1);
-  for (const auto& perm : perms) {
-    std::cout << perm << "\n";
+  for (size_t i = 0; i < perms.size(); ++i) {
+    std::cout << perms[i] << "\n";
   }
   std::cout << "\n";
 }
 
 // Tests
 
+bool same(const Permutations& result,
+          const std::vector<std::string>& expected) {
+  if (result.size() != expected.size()) {
+    return false;
+  }
+  for (size_t i = 0; i < result.size(); ++i) {
+    if (result[i] != expected[i]) {
+      return false;
+    }
+  }
+  return true;
+}
+
 void test_generate_permutations_a() {
   auto result = generate_permutations("A", 0);
   std::vector<std::string> expected = {"A"};
-  assert(result == expected);
+  assert(same(result, expected));
   std::cout << "test_generate_permutations_a passed\n";
 }
 
 void test_generate_permutations_ab() {
   auto result = generate_permutations("AB", 1);
   std::vector<std::string> expected = {"BA", "AB"};
-  assert(result == expected);
+  assert(same(result, expected));
   std::cout << "test_generate_permutations_ab passed\n";
 }
 
@@ -107,7 +56,7 @@ void test_generate_permutations_abc() {
   auto result = generate_permutations("ABC", 2);
   std::vector<std::string> expected = {"CBA", "BCA", "BAC",
                                        "CAB", "ACB", "ABC"};
-  assert(result == expected);
+  assert(same(result, expected));
   std::cout << "test_generate_permutations_abc passed\n";
 }
 
@@ -118,11 +67,14 @@ void test_generate_permutations_abcd() {
   assert(result.size() == 24);
 
   // Check that all permutations are unique
-  std::set<std::string> unique_perms(result.begin(), result.end());
+  std::set<std::string_view> unique_perms;
+  for (size_t i = 0; i < result.size(); ++i) {
+    unique_perms.insert(result[i]);
+  }
   assert(unique_perms.size() == 24);
 
   // Check that each permutation contains all original characters
-  for (const auto& perm : result) {
+  for (const auto perm : unique_perms) {
     assert(perm.length() == 4);
     assert(perm.find('A') != std::string::npos);
     assert(perm.find('B') != std::string::npos);
@@ -133,18 +85,48 @@ void test_generate_permutations_abcd() {
 }
 
 void test_interpolate() {
-  auto result = interpolate('X', "AB");
+  Permutations result(3, 3);
+  interpolate('X', "AB", result);
   std::vector<std::string> expected = {"XAB", "AXB", "ABX"};
-  assert(result == expected);
+  assert(same(result, expected));
   std::cout << "test_interpolate passed\n";
 }
 
+void test_one_buffer_per_level() {
+  // Every permutation of the level lives in the one string
+  auto result = generate_permutations("ABCDE", 4);
+  assert(result.size() == 120);
+  assert(result.length() == 5);
+  for (size_t i = 1; i < result.size(); ++i) {
+    assert(result[i].data() == result[i - 1].data() + 5);
+  }
+  std::cout << "test_one_buffer_per_level passed\n";
+}
+
+void test_pos_out_of_range() {
+  auto result = try_generate_permutations("AB", 2);
+  assert(!result.ok());
+  assert(result.error().kind == ErrorKind::kOutOfRange);
+  assert(try_generate_permutations("", 0).ok());
+
+  bool threw = false;
+  try {
+    generate_permutations("AB", 5);
+  } catch (const std::out_of_range&) {
+    threw = true;
+  }
+  assert(threw);
+  std::cout << "test_pos_out_of_range passed\n";
+}
+
 int main() {
   test_generate_permutations_a();
   test_generate_permutations_ab();
   test_generate_permutations_abc();
   test_generate_permutations_abcd();
   test_interpolate();
+  test_one_buffer_per_level();
+  test_pos_out_of_range();
 
   std::cout << "\nAll tests passed!\n";
   return 0;
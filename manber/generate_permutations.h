@@ -0,0 +1,138 @@
+#pragma once
+
+/*
+Problem:
+    Generate all permutations of a given word
+
+Solution:
+    Base case (pos=0): word of size one
+        Return the word as a single permutation
+
+    Induction hypothesis:
+        We know how to generate all permutations of a word with one less
+character
+
+    Induction step:
+        Remove the character at position pos from the word.
+        Generate all permutations of the word with the character removed (using
+        induction hypothesis).
+        For each permutation, interpolate the removed character at every
+        possible position.
+        Return the concatenation of all these interpolated results.
+
+Storage:
+    Building each interpolation as its own string costs an allocation per
+    permutation at every level, n * n! in all. Instead, the permutations of
+    one level are written back to back into a single buffer, each one a row
+    of the same length. Interpolating appends rows to the next level's
+    buffer, which is sized up front, so a level costs one allocation and
+    every character is written once.
+
+Errors:
+    pos must index a character of word (or be 0 for an empty word).
+    try_generate_permutations reports anything else as kOutOfRange;
+    generate_permutations throws it as std::out_of_range.
+*/
+
+#include <cassert>
+#include <cstddef>
+#include <string>
+#include <string_view>
+
+#include "../errors/Error.h"
+
+// Permutations of the same length, stored back to back in one string
+class Permutations {
+ public:
+  Permutations(size_t length, size_t count) : length_(length) {
+    chars_.reserve(length * count);
+  }
+
+  size_t size() const { return count_; }
+
+  size_t length() const { return length_; }
+
+  std::string_view operator[](size_t i) const {
+    assert(i < size());
+    return std::string_view(chars_).substr(i * length_, length_);
+  }
+
+  // Adds a permutation made of prefix, ch, and suffix, without building it
+  // as a string first
+  void append(std::string_view prefix, char ch, std::string_view suffix) {
+    assert(prefix.size() + 1 + suffix.size() == length_);
+    chars_.append(prefix);
+    chars_.push_back(ch);
+    chars_.append(suffix);
+    ++count_;
+  }
+
+  void append(std::string_view word) {
+    assert(word.size() == length_);
+    chars_.append(word);
+    ++count_;
+  }
+
+ private:
+  size_t length_;
+  size_t count_{0};
+  std::string chars_;
+};
+
+/*
+Insert character ch at every possible position in word, appending each
+result to out.
+
+We need to iterate up to len(word) + 1 because we want to insert the character
+ch at:
+- Before the first character (position 0)
+- Between each pair of characters (positions 1, 2, ..., len(word)-1)
+- After the last character (position len(word))
+
+For example, with word = "ab" (length 2), we need 3 positions:
+- i = 0: "" + ch + "ab" --> "cab"
+- i = 1: "a" + ch + "b" --> "acb"
+- i = 2: "ab" + ch + "" --> "abc"
+*/
+inline void interpolate(char ch, std::string_view word, Permutations& out) {
+  for (size_t i = 0; i <= word.length(); ++i) {
+    // word.substr(0, i) will be empty when i == 0
+    // word.substr(i) will be empty when i == word.length()
+    out.append(word.substr(0, i), ch, word.substr(i));
+  }
+}
+
+inline Result<Permutations> try_generate_permutations(const std::string& word,
+                                                     size_t pos) {
+  if (pos > 0 && pos >= word.length()) {
+    return Error{ErrorKind::kOutOfRange, "pos must index a character of word"};
+  }
+  if (pos == 0) {
+    Permutations res(word.length(), 1);
+    res.append(word);
+    return res;
+  }
+
+  // Word with ch removed where ch is the char at word[pos]
+  std::string smaller_word = word.substr(0, pos) + word.substr(pos + 1);
+
+  // All permutations of word without ch
+  const Permutations perms =
+      try_generate_permutations(smaller_word, pos - 1).value();
+
+  // Each of them gives word.length() permutations of word
+  Permutations res(word.length(), perms.size() * word.length());
+
+  // Interpolate ch back into every position of every permutation of the word
+  char ch = word[pos];
+  for (size_t i = 0; i < perms.size(); ++i) {
+    interpolate(ch, perms[i], res);
+  }
+
+  return res;
+}
+
+inline Permutations generate_permutations(const std::string& word,
+                                          size_t pos) {
+  return try_generate_permutations(word, pos).or_throw();
+}
@@ -36,7 +36,9 @@ Solution:
 
 #include <algorithm>
 #include <cassert>
+#include <cstdint>
 #include <iostream>
+#include <random>
 #include <vector>
 
 size_t longest_increasing_subsequence(const std::vector<int>& arr) {
@@ -63,6 +65,80 @@ size_t longest_increasing_subsequence(const std::vector<int>& arr) {
   return *std::max_element(dp.begin(), dp.end());
 }
 
+// Counting the longest increasing subsequences, i.e. how many distinct sets of
+// positions form an increasing subsequence of the maximum length. The same
+// induction, strengthened: along with dp[i], we also know count[i], the number
+// of increasing subsequences of length dp[i] that end exactly at position i.
+//
+// When extending from a position j with arr[j] < arr[i]:
+//     - dp[j] + 1 > dp[i]: a longer subsequence ending at i, so every one of
+//       the count[j] subsequences ending at j becomes a new best for i.
+//     - dp[j] + 1 == dp[i]: just as long, so count[j] more ways to end at i.
+//
+// The answer sums count[i] over the positions where dp[i] is the maximum.
+// Counts grow exponentially (e.g. [2, 1, 4, 3, 6, 5, ...]) and wrap around
+// modulo 2^64.
+uint64_t count_longest_increasing_subsequences(const std::vector<int>& arr) {
+  if (arr.empty()) {
+    return 0;
+  }
+
+  std::vector<size_t> dp(arr.size(), 1);
+  std::vector<uint64_t> count(arr.size(), 1);
+  for (size_t i = 1; i < arr.size(); ++i) {
+    for (size_t j = 0; j < i; ++j) {
+      if (arr[j] >= arr[i]) {
+        continue;
+      }
+      if (dp[j] + 1 > dp[i]) {
+        dp[i] = dp[j] + 1;
+        count[i] = count[j];
+      } else if (dp[j] + 1 == dp[i]) {
+        count[i] += count[j];
+      }
+    }
+  }
+
+  size_t longest = *std::max_element(dp.begin(), dp.end());
+  uint64_t total = 0;
+  for (size_t i = 0; i < arr.size(); ++i) {
+    if (dp[i] == longest) {
+      total += count[i];
+    }
+  }
+  return total;
+}
+
+// Brute force: try every set of positions. O(2^n * n), for small arrays only.
+uint64_t count_longest_increasing_subsequences_naive(
+    const std::vector<int>& arr) {
+  size_t longest = 0;
+  uint64_t total = 0;
+  for (uint32_t mask = 1; mask < (uint32_t{1} << arr.size()); ++mask) {
+    bool increasing = true;
+    size_t length = 0;
+    int last = 0;
+    for (size_t i = 0; i < arr.size() && increasing; ++i) {
+      if (mask >> i & 1) {
+        increasing = length == 0 || last < arr[i];
+        last = arr[i];
+        ++length;
+      }
+    }
+    if (!increasing) {
+      continue;
+    }
+    if (length > longest) {
+      longest = length;
+      total = 0;
+    }
+    if (length == longest) {
+      ++total;
+    }
+  }
+  return total;
+}
+
 // TODO: Implement a O(n log n) version of the solution (Manber page 167-169).
 
 // Tests
@@ -158,6 +234,45 @@ void test_large_numbers() {
   std::cout << "test_large_numbers passed\n";
 }
 
+void test_count_examples() {
+  assert(count_longest_increasing_subsequences({}) == 0);
+  assert(count_longest_increasing_subsequences({5}) == 1);
+  assert(count_longest_increasing_subsequences({1, 3, 5, 4, 7}) == 2);
+  // Any single element is a longest one
+  assert(count_longest_increasing_subsequences({2, 2, 2, 2, 2}) == 5);
+  assert(count_longest_increasing_subsequences({5, 4, 3, 2, 1}) == 5);
+  // [2, 5, 7, 101], [2, 5, 7, 18], [2, 3, 7, 101], [2, 3, 7, 18]
+  assert(count_longest_increasing_subsequences(
+             {10, 9, 2, 5, 3, 7, 101, 18}) == 4);
+  std::cout << "test_count_examples passed\n";
+}
+
+void test_count_doubles_per_pair() {
+  // Each pair contributes one of two elements, so 2^20 choices of length 20
+  std::vector<int> arr;
+  for (int i = 0; i < 20; ++i) {
+    arr.push_back(2 * i + 1);
+    arr.push_back(2 * i);
+  }
+  assert(longest_increasing_subsequence(arr) == 20);
+  assert(count_longest_increasing_subsequences(arr) == uint64_t{1} << 20);
+  std::cout << "test_count_doubles_per_pair passed\n";
+}
+
+void test_count_matches_brute_force() {
+  std::mt19937 gen(140);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<int> arr(std::uniform_int_distribution<int>(0, 14)(gen));
+    std::uniform_int_distribution<int> value(0, 8);
+    for (auto& v : arr) {
+      v = value(gen);
+    }
+    assert(count_longest_increasing_subsequences(arr) ==
+           count_longest_increasing_subsequences_naive(arr));
+  }
+  std::cout << "test_count_matches_brute_force passed\n";
+}
+
 int main() {
   test_empty_array();
   test_single_element();
@@ -172,6 +287,9 @@ int main() {
   test_negative_numbers();
   test_classic_example();
   test_large_numbers();
+  test_count_examples();
+  test_count_doubles_per_pair();
+  test_count_matches_brute_force();
 
   std::cout << "\nAll tests passed!\n";
   return 0;
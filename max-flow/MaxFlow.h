@@ -0,0 +1,116 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <queue>
+#include <vector>
+
+// Maximum flow from a source s to a sink t through edges of limited
+// capacity. Every edge has a residual twin going back, whose capacity is
+// the flow on the edge, so pushing flow back along it undoes that flow.
+// Ford-Fulkerson: while some path from s to t has capacity left in the
+// residual network, push as much as it takes.
+//
+// Max-flow min-cut: when no path is left, the vertices that s can still
+// reach form one side of a cut whose edges are all full, so the flow equals
+// the capacity of that cut, and no cut has less. The max flow is the
+// capacity of a minimum s-t cut.
+//
+// Edmonds-Karp takes the shortest augmenting path each time, by BFS. The
+// distance from s to each vertex never goes down, and each edge can be the
+// bottleneck O(V) times, so it's O(V E^2) whatever the capacities.
+class FlowNetwork {
+ public:
+  explicit FlowNetwork(size_t n) : adj_(n) {}
+
+  size_t size() const { return adj_.size(); }
+
+  // A directed edge
+  void add_edge(int from, int to, int64_t capacity) {
+    add_pair(from, to, capacity, 0);
+  }
+
+  // Capacity both ways, shared: an edge and its twin start equal
+  void add_undirected_edge(int u, int v, int64_t capacity) {
+    add_pair(u, v, capacity, capacity);
+  }
+
+  // Pushes as much flow as fits from s to t, on top of any already there,
+  // and returns how much
+  int64_t max_flow(int s, int t) {
+    assert(s != t && "the source is the sink");
+    int64_t total = 0;
+    std::vector<size_t> via(adj_.size());  // the edge into each vertex
+    while (true) {
+      std::vector<bool> reached(adj_.size(), false);
+      reached[s] = true;
+      std::queue<int> frontier;
+      frontier.push(s);
+      while (!frontier.empty() && !reached[t]) {
+        const int u = frontier.front();
+        frontier.pop();
+        for (size_t e : adj_[u]) {
+          const int v = edges_[e].to;
+          if (!reached[v] && edges_[e].residual > 0) {
+            reached[v] = true;
+            via[v] = e;
+            frontier.push(v);
+          }
+        }
+      }
+      if (!reached[t]) {
+        return total;
+      }
+      int64_t push = std::numeric_limits<int64_t>::max();
+      for (int v = t; v != s; v = edges_[via[v] ^ 1].to) {
+        push = std::min(push, edges_[via[v]].residual);
+      }
+      for (int v = t; v != s; v = edges_[via[v] ^ 1].to) {
+        edges_[via[v]].residual -= push;
+        edges_[via[v] ^ 1].residual += push;
+      }
+      total += push;
+    }
+  }
+
+  // After max_flow(s, t): the vertices s still reaches, the source side of
+  // a minimum cut
+  std::vector<bool> source_side(int s) const {
+    std::vector<bool> reached(adj_.size(), false);
+    reached[s] = true;
+    std::vector<int> stack = {s};
+    while (!stack.empty()) {
+      const int u = stack.back();
+      stack.pop_back();
+      for (size_t e : adj_[u]) {
+        const int v = edges_[e].to;
+        if (!reached[v] && edges_[e].residual > 0) {
+          reached[v] = true;
+          stack.push_back(v);
+        }
+      }
+    }
+    return reached;
+  }
+
+ private:
+  struct Edge {
+    int to;
+    int64_t residual;
+  };
+
+  // Edges 2i and 2i + 1 are twins, so e ^ 1 is e's
+  void add_pair(int u, int v, int64_t forward, int64_t backward) {
+    assert(forward >= 0 && backward >= 0 && "negative capacity");
+    adj_[u].push_back(edges_.size());
+    edges_.push_back({v, forward});
+    adj_[v].push_back(edges_.size());
+    edges_.push_back({u, backward});
+  }
+
+  std::vector<Edge> edges_;
+  std::vector<std::vector<size_t>> adj_;  // edge indices out of each vertex
+};
@@ -0,0 +1,46 @@
+#include <cstdint>
+#include <vector>
+
+#include "MaxFlow.h"
+#include "gtest/gtest.h"
+
+// The network from CLRS, figure 26.1
+TEST(MaxFlow, Example) {
+  FlowNetwork network(6);
+  network.add_edge(0, 1, 16);
+  network.add_edge(0, 2, 13);
+  network.add_edge(2, 1, 4);
+  network.add_edge(1, 3, 12);
+  network.add_edge(3, 2, 9);
+  network.add_edge(2, 4, 14);
+  network.add_edge(4, 3, 7);
+  network.add_edge(3, 5, 20);
+  network.add_edge(4, 5, 4);
+  EXPECT_EQ(network.max_flow(0, 5), 23);
+  EXPECT_EQ(network.source_side(0),
+            (std::vector<bool>{true, true, true, false, true, false}));
+  EXPECT_EQ(network.max_flow(0, 5), 0);  // nothing more fits
+}
+
+TEST(MaxFlow, EdgeCases) {
+  FlowNetwork disconnected(3);
+  disconnected.add_edge(0, 1, 5);
+  EXPECT_EQ(disconnected.max_flow(0, 2), 0);
+
+  // Flow goes either way along an undirected edge, and parallel edges add
+  FlowNetwork undirected(3);
+  undirected.add_undirected_edge(1, 0, 3);
+  undirected.add_undirected_edge(1, 2, 2);
+  undirected.add_undirected_edge(1, 2, 2);
+  EXPECT_EQ(undirected.max_flow(0, 2), 3);
+
+  FlowNetwork backwards(2);
+  backwards.add_edge(1, 0, 7);
+  EXPECT_EQ(backwards.max_flow(0, 1), 0);
+  EXPECT_EQ(backwards.max_flow(1, 0), 7);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,267 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <optional>
+#include <queue>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../grid/Grid.h"
+#include "../rng/Rng.h"
+#include "../union-find/UnionFind.h"
+
+// Mazes: a rows × cols grid of cells with a wall between every pair of
+// neighbors, some of which are knocked down into passages. The generators
+// below all make perfect mazes, where every cell can be reached from every
+// other by exactly one path: the passages form a spanning tree of the grid
+// graph, and each generator is a way of picking a random spanning tree,
+// with its own look.
+class Maze {
+ public:
+  Maze(int rows, int cols)
+      : rows_(rows), cols_(cols), east_(rows, cols, 0), south_(rows, cols, 0) {
+    assert(rows > 0 && cols > 0 && "a maze needs a cell");
+  }
+
+  int rows() const { return rows_; }
+  int cols() const { return cols_; }
+
+  bool in_bounds(Cell c) const {
+    return 0 <= c.row && c.row < rows_ && 0 <= c.col && c.col < cols_;
+  }
+
+  // Knocks down the wall between two neighboring cells
+  void carve(Cell a, Cell b) {
+    const auto [east, c] = locate(a, b);
+    (east ? east_ : south_)[c] = 1;
+  }
+
+  // Whether a passage joins two neighboring cells
+  bool open(Cell a, Cell b) const {
+    const auto [east, c] = locate(a, b);
+    return (east ? east_ : south_)[c] != 0;
+  }
+
+  // The cells a passage leads to from c
+  std::vector<Cell> exits(Cell c) const {
+    std::vector<Cell> out;
+    for (Cell n : Neighbors(c, rows_, cols_, Connectivity::kFour)) {
+      if (open(c, n)) {
+        out.push_back(n);
+      }
+    }
+    return out;
+  }
+
+  size_t passages() const {
+    size_t count = 0;
+    for (Cell c : east_.cells()) {
+      count += (east_[c] != 0) + (south_[c] != 0);
+    }
+    return count;
+  }
+
+ private:
+  // The wall between two neighbors is kept with whichever is west or north
+  // of the other: whether it's an east wall, and of which cell
+  std::pair<bool, Cell> locate(Cell a, Cell b) const {
+    if (b < a) {
+      std::swap(a, b);
+    }
+    assert(in_bounds(a) && in_bounds(b) && "cell out of bounds");
+    if (a.row == b.row && a.col + 1 == b.col) {
+      return {true, a};
+    }
+    assert(a.col == b.col && a.row + 1 == b.row && "cells aren't neighbors");
+    return {false, a};
+  }
+
+  int rows_;
+  int cols_;
+  Grid<char> east_;   // Passage to the cell on the right
+  Grid<char> south_;  // Passage to the cell below
+};
+
+// Recursive backtracking: a random walk that carves into unvisited cells,
+// backing up along its own trail when it's boxed in. It makes long,
+// winding corridors with few branches. The trail is an explicit stack, so a
+// big maze doesn't need a deep call stack.
+template <typename Gen>
+Maze backtracker_maze(int rows, int cols, Gen& gen) {
+  Maze maze(rows, cols);
+  Grid<char> visited(rows, cols, 0);
+  std::vector<Cell> trail = {{0, 0}};
+  visited[{0, 0}] = 1;
+  while (!trail.empty()) {
+    const Cell c = trail.back();
+    std::vector<Cell> fresh;
+    for (Cell n : visited.neighbors(c)) {
+      if (!visited[n]) {
+        fresh.push_back(n);
+      }
+    }
+    if (fresh.empty()) {
+      trail.pop_back();
+      continue;
+    }
+    const Cell next = fresh[uniform_below(gen, fresh.size())];
+    maze.carve(c, next);
+    visited[next] = 1;
+    trail.push_back(next);
+  }
+  return maze;
+}
+
+// Kruskal's: every wall in random order, knocked down whenever the cells
+// on its two sides aren't connected yet, which the union-find knows. Many
+// short dead ends, growing from everywhere at once.
+template <typename Gen>
+Maze kruskal_maze(int rows, int cols, Gen& gen) {
+  Maze maze(rows, cols);
+  std::vector<std::pair<Cell, Cell>> walls;
+  for (int r = 0; r < rows; ++r) {
+    for (int c = 0; c < cols; ++c) {
+      if (c + 1 < cols) {
+        walls.push_back({{r, c}, {r, c + 1}});
+      }
+      if (r + 1 < rows) {
+        walls.push_back({{r, c}, {r + 1, c}});
+      }
+    }
+  }
+  shuffle_range(walls.begin(), walls.end(), gen);
+  UnionFind sets(static_cast<size_t>(rows) * static_cast<size_t>(cols));
+  auto id = [cols](Cell c) {
+    return static_cast<size_t>(c.row) * static_cast<size_t>(cols) +
+           static_cast<size_t>(c.col);
+  };
+  for (const auto& [a, b] : walls) {
+    if (sets.unite(id(a), id(b))) {
+      maze.carve(a, b);
+    }
+  }
+  return maze;
+}
+
+// Prim's: grow one tree from a corner, each time knocking down a random
+// wall between the tree and a cell outside it. The frontier holds those
+// walls; one whose far cell joined the tree in the meantime is skipped.
+// Short corridors radiating from the start.
+template <typename Gen>
+Maze prim_maze(int rows, int cols, Gen& gen) {
+  Maze maze(rows, cols);
+  Grid<char> in_tree(rows, cols, 0);
+  std::vector<std::pair<Cell, Cell>> frontier;
+  auto add = [&](Cell c) {
+    in_tree[c] = 1;
+    for (Cell n : in_tree.neighbors(c)) {
+      if (!in_tree[n]) {
+        frontier.push_back({c, n});
+      }
+    }
+  };
+  add({0, 0});
+  while (!frontier.empty()) {
+    const size_t i = uniform_below(gen, frontier.size());
+    const auto [from, to] = frontier[i];
+    frontier[i] = frontier.back();
+    frontier.pop_back();
+    if (!in_tree[to]) {
+      maze.carve(from, to);
+      add(to);
+    }
+  }
+  return maze;
+}
+
+// "backtracker", "kruskal", or "prim"; std::nullopt for anything else
+template <typename Gen>
+std::optional<Maze> maze_by_name(const std::string& name, int rows, int cols,
+                                 Gen& gen) {
+  if (name == "backtracker") {
+    return backtracker_maze(rows, cols, gen);
+  }
+  if (name == "kruskal") {
+    return kruskal_maze(rows, cols, gen);
+  }
+  if (name == "prim") {
+    return prim_maze(rows, cols, gen);
+  }
+  return std::nullopt;
+}
+
+// The path from `from` to `to` through the passages, both ends included,
+// breadth first. Every perfect maze has exactly one, but a maze with
+// walls still up may have none.
+inline std::optional<std::vector<Cell>> solve_maze(const Maze& maze, Cell from,
+                                                   Cell to) {
+  constexpr Cell kUnseen = {-1, -1};
+  Grid<Cell> parent(maze.rows(), maze.cols(), kUnseen);
+  std::queue<Cell> frontier;
+  parent[from] = from;
+  frontier.push(from);
+  while (!frontier.empty() && parent[to] == kUnseen) {
+    const Cell c = frontier.front();
+    frontier.pop();
+    for (Cell n : maze.exits(c)) {
+      if (parent[n] == kUnseen) {
+        parent[n] = c;
+        frontier.push(n);
+      }
+    }
+  }
+  if (parent[to] == kUnseen) {
+    return std::nullopt;
+  }
+  std::vector<Cell> path = {to};
+  while (path.back() != from) {
+    path.push_back(parent[path.back()]);
+  }
+  std::reverse(path.begin(), path.end());
+  return path;
+}
+
+// The maze drawn in characters: cell (r, c) is at (2r + 1, 2c + 1) of a
+// (2 rows + 1) × (2 cols + 1) picture, with '#' for walls and ' ' for
+// cells and passages. Marks, if given, are drawn over the cells.
+inline Grid<char> maze_picture(const Maze& maze) {
+  Grid<char> picture(2 * maze.rows() + 1, 2 * maze.cols() + 1, '#');
+  for (int r = 0; r < maze.rows(); ++r) {
+    for (int c = 0; c < maze.cols(); ++c) {
+      picture[{2 * r + 1, 2 * c + 1}] = ' ';
+      if (c + 1 < maze.cols() && maze.open({r, c}, {r, c + 1})) {
+        picture[{2 * r + 1, 2 * c + 2}] = ' ';
+      }
+      if (r + 1 < maze.rows() && maze.open({r, c}, {r + 1, c})) {
+        picture[{2 * r + 2, 2 * c + 1}] = ' ';
+      }
+    }
+  }
+  return picture;
+}
+
+// The picture as text, with the path drawn in '.' (including the passages
+// between its cells) if there is one
+inline std::string render_maze(const Maze& maze,
+                               const std::vector<Cell>& path = {}) {
+  Grid<char> picture = maze_picture(maze);
+  for (size_t i = 0; i < path.size(); ++i) {
+    const Cell c = path[i];
+    picture[{2 * c.row + 1, 2 * c.col + 1}] = '.';
+    if (i + 1 < path.size()) {
+      const Cell n = path[i + 1];
+      picture[{c.row + n.row + 1, c.col + n.col + 1}] = '.';
+    }
+  }
+  std::string text;
+  for (int r = 0; r < picture.rows(); ++r) {
+    for (int c = 0; c < picture.cols(); ++c) {
+      text += picture[{r, c}];
+    }
+    text += '\n';
+  }
+  return text;
+}
@@ -0,0 +1,130 @@
+#include <cstddef>
+#include <cstdlib>
+#include <sstream>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Maze.h"
+#include "Play.h"
+#include "gtest/gtest.h"
+
+// A perfect maze is a spanning tree of the grid: one passage fewer than
+// cells, and every cell reachable from the corner
+void expect_perfect(const Maze& maze) {
+  const size_t cells = static_cast<size_t>(maze.rows() * maze.cols());
+  EXPECT_EQ(maze.passages(), cells - 1);
+  Grid<char> seen(maze.rows(), maze.cols(), 0);
+  std::vector<Cell> stack = {{0, 0}};
+  seen[{0, 0}] = 1;
+  size_t reached = 1;
+  while (!stack.empty()) {
+    const Cell c = stack.back();
+    stack.pop_back();
+    for (Cell n : maze.exits(c)) {
+      if (!seen[n]) {
+        seen[n] = 1;
+        ++reached;
+        stack.push_back(n);
+      }
+    }
+  }
+  EXPECT_EQ(reached, cells);
+}
+
+TEST(Maze, GeneratorsMakePerfectMazes) {
+  Rng gen(196);
+  for (const std::string name : {"backtracker", "kruskal", "prim"}) {
+    for (int t = 0; t < 30; ++t) {
+      const int rows = uniform_int(gen, 1, 12);
+      const int cols = uniform_int(gen, 1, 12);
+      const auto maze = maze_by_name(name, rows, cols, gen);
+      ASSERT_TRUE(maze.has_value());
+      EXPECT_EQ(maze->rows(), rows);
+      EXPECT_EQ(maze->cols(), cols);
+      expect_perfect(*maze);
+    }
+  }
+  EXPECT_FALSE(maze_by_name("eller", 3, 3, gen).has_value());
+}
+
+TEST(Maze, Reproducible) {
+  for (const std::string name : {"backtracker", "kruskal", "prim"}) {
+    Rng a(196);
+    Rng b(196);
+    Rng c(197);
+    const std::string first = render_maze(*maze_by_name(name, 10, 10, a));
+    EXPECT_EQ(first, render_maze(*maze_by_name(name, 10, 10, b)));
+    EXPECT_NE(first, render_maze(*maze_by_name(name, 10, 10, c)));
+  }
+}
+
+TEST(Maze, SolveAndRender) {
+  Maze maze(2, 3);
+  maze.carve({0, 0}, {0, 1});
+  maze.carve({0, 1}, {1, 1});
+  maze.carve({1, 1}, {1, 2});
+  EXPECT_TRUE(maze.open({1, 1}, {0, 1}));
+  EXPECT_FALSE(maze.open({0, 1}, {0, 2}));
+  EXPECT_FALSE(solve_maze(maze, {0, 0}, {1, 0}).has_value());
+  maze.carve({1, 0}, {1, 1});
+
+  const auto path = solve_maze(maze, {0, 0}, {1, 2});
+  ASSERT_TRUE(path.has_value());
+  EXPECT_EQ(*path, (std::vector<Cell>{{0, 0}, {0, 1}, {1, 1}, {1, 2}}));
+  EXPECT_EQ(render_maze(maze),
+            "#######\n"
+            "#   # #\n"
+            "### ###\n"
+            "#     #\n"
+            "#######\n");
+  EXPECT_EQ(render_maze(maze, *path),
+            "#######\n"
+            "#...# #\n"
+            "###.###\n"
+            "#  ...#\n"
+            "#######\n");
+
+  // Every step of a solution goes through a passage
+  Rng gen(196);
+  const Maze big = kruskal_maze(20, 30, gen);
+  const auto long_path = solve_maze(big, {0, 0}, {19, 29});
+  ASSERT_TRUE(long_path.has_value());
+  EXPECT_EQ(long_path->front(), (Cell{0, 0}));
+  EXPECT_EQ(long_path->back(), (Cell{19, 29}));
+  for (size_t i = 0; i + 1 < long_path->size(); ++i) {
+    const Cell a = (*long_path)[i];
+    const Cell b = (*long_path)[i + 1];
+    EXPECT_EQ(std::abs(a.row - b.row) + std::abs(a.col - b.col), 1);
+    EXPECT_TRUE(big.open(a, b));
+  }
+}
+
+TEST(Maze, Play) {
+  Maze maze(2, 3);
+  maze.carve({0, 0}, {0, 1});
+  maze.carve({0, 1}, {1, 1});
+  maze.carve({1, 1}, {1, 2});
+  maze.carve({1, 0}, {1, 1});
+
+  // Walls stop the player, and the steps after the way out are ignored
+  std::istringstream in("dd\nx\nsdwww\n");
+  std::ostringstream out;
+  EXPECT_TRUE(play_maze(maze, in, out));
+  const std::string shown = out.str();
+  EXPECT_NE(shown.find("#@  # #"), std::string::npos);
+  EXPECT_NE(shown.find("#  @# #"), std::string::npos);
+  EXPECT_NE(shown.find("#    @#"), std::string::npos);
+  EXPECT_NE(shown.find("out!"), std::string::npos);
+
+  std::istringstream quit("d\nq\n");
+  std::ostringstream ignored;
+  EXPECT_FALSE(play_maze(maze, quit, ignored));
+  std::istringstream dry("ds");
+  EXPECT_FALSE(play_maze(maze, dry, ignored));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,80 @@
+#pragma once
+
+#include <istream>
+#include <ostream>
+#include <string>
+
+#include "Maze.h"
+
+// Walking a maze in a terminal, from the top left corner to the bottom
+// right. Moves are read a line at a time, since a terminal hands over
+// nothing before Enter: w, a, s, and d step up, left, down, and right, as
+// many as the line holds, and q gives up. A step into a wall is ignored.
+
+// The picture with '@' for the player and '$' for the way out
+inline std::string render_play(const Maze& maze, Cell player) {
+  Grid<char> picture = maze_picture(maze);
+  picture[{2 * maze.rows() - 1, 2 * maze.cols() - 1}] = '$';
+  picture[{2 * player.row + 1, 2 * player.col + 1}] = '@';
+  std::string text;
+  for (int r = 0; r < picture.rows(); ++r) {
+    for (int c = 0; c < picture.cols(); ++c) {
+      text += picture[{r, c}];
+    }
+    text += '\n';
+  }
+  return text;
+}
+
+// Where a move leads from `from`: the same cell if it's blocked or isn't a
+// move at all
+inline Cell step(const Maze& maze, Cell from, char move) {
+  Cell to = from;
+  switch (move) {
+    case 'w':
+      --to.row;
+      break;
+    case 'a':
+      --to.col;
+      break;
+    case 's':
+      ++to.row;
+      break;
+    case 'd':
+      ++to.col;
+      break;
+    default:
+      return from;
+  }
+  return maze.in_bounds(to) && maze.open(from, to) ? to : from;
+}
+
+// Plays until the player gets out, quits, or the input runs dry, drawing
+// the maze after every line of moves. Returns whether the player got out.
+inline bool play_maze(const Maze& maze, std::istream& in, std::ostream& out) {
+  const Cell goal = {maze.rows() - 1, maze.cols() - 1};
+  Cell player = {0, 0};
+  out << render_play(maze, player) << "move (wasd, q quits): " << std::flush;
+  std::string line;
+  while (player != goal && std::getline(in, line)) {
+    for (char move : line) {
+      if (move == 'q') {
+        out << "\ngave up\n";
+        return false;
+      }
+      player = step(maze, player, move);
+      if (player == goal) {
+        break;
+      }
+    }
+    out << '\n' << render_play(maze, player);
+    if (player != goal) {
+      out << "move (wasd, q quits): " << std::flush;
+    }
+  }
+  if (player == goal) {
+    out << "out!\n";
+    return true;
+  }
+  return false;
+}
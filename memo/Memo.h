@@ -0,0 +1,263 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <list>
+#include <optional>
+#include <type_traits>
+#include <unordered_map>
+#include <utility>
+#include <vector>
+
+// A cache of subproblem answers for top-down dynamic programming, with two
+// ways to drive it.
+//
+// recursive() is memoization as usually written: the function computes a
+// subproblem by calling back for the smaller ones, and the cache answers
+// any it has seen before. Simple, but the recursion can get as deep as the
+// longest chain of subproblems, which overflows the call stack on large
+// inputs (edit distance of two long strings recurses m + n deep).
+//
+// iterative() computes the same values with an explicit stack instead. It
+// needs the subproblems split into two functions: one listing the
+// subproblems a key depends on, and one combining their values once they're
+// all known. Every subproblem gets solved before whatever needs it, just
+// like the recursion, but on the heap.
+//
+// The stats count how the cache was used. A miss is a subproblem computed,
+// which without eviction is once per distinct subproblem under either
+// strategy. A hit is an answer found already cached, and what counts as one
+// differs: recursive() counts every call for a cached key, each one a
+// recomputation the memo cut short; iterative() counts a dependency found
+// cached when a subproblem is expanded, and not one that's already waiting
+// on the stack, so it can report fewer hits for the same problem.
+//
+// Where the answers live is the cache policy, the third parameter:
+//
+// - HashCache keeps every answer in a hash map, for any hashable key.
+// - DenseCache keeps them in a vector indexed by the key, for small
+//   non-negative integer keys like fib(n). No hashing, and no per-entry
+//   allocation.
+// - LruCache keeps at most a fixed number of answers, evicting the least
+//   recently used. Anything evicted is recomputed if it's asked for again,
+//   trading time for a bounded footprint.
+//
+// A policy has find(key), returning a pointer to the cached value or null,
+// insert(key, value), size(), and clear(), and says whether it evicts.
+
+struct MemoStats {
+  size_t hits{0};
+  size_t misses{0};
+};
+
+// For keys that are pairs, like (i, j) in a table
+struct PairHash {
+  template <typename A, typename B>
+  size_t operator()(const std::pair<A, B>& p) const {
+    const size_t a = std::hash<A>()(p.first);
+    return a ^ (std::hash<B>()(p.second) + 0x9e3779b97f4a7c15 + (a << 6) +
+                (a >> 2));
+  }
+};
+
+template <typename Key, typename Value, typename Hash = std::hash<Key>>
+class HashCache {
+ public:
+  static constexpr bool evicts = false;
+
+  const Value* find(const Key& key) const {
+    auto it = values_.find(key);
+    return it == values_.end() ? nullptr : &it->second;
+  }
+
+  void insert(const Key& key, Value value) {
+    values_.emplace(key, std::move(value));
+  }
+
+  size_t size() const { return values_.size(); }
+
+  void clear() { values_.clear(); }
+
+ private:
+  std::unordered_map<Key, Value, Hash> values_;
+};
+
+// The vector grows to the largest key seen, so the keys should be dense
+template <typename Key, typename Value>
+class DenseCache {
+  static_assert(std::is_integral_v<Key>, "DenseCache needs integer keys");
+
+ public:
+  static constexpr bool evicts = false;
+
+  const Value* find(const Key& key) const {
+    const size_t i = index(key);
+    return i < values_.size() && values_[i] ? &*values_[i] : nullptr;
+  }
+
+  void insert(const Key& key, Value value) {
+    const size_t i = index(key);
+    if (i >= values_.size()) {
+      values_.resize(i + 1);
+    }
+    if (!values_[i]) {
+      ++size_;
+    }
+    values_[i] = std::move(value);
+  }
+
+  size_t size() const { return size_; }
+
+  void clear() {
+    values_.clear();
+    size_ = 0;
+  }
+
+ private:
+  static size_t index(const Key& key) {
+    if constexpr (std::is_signed_v<Key>) {
+      assert(key >= 0 && "DenseCache keys must be non-negative");
+    }
+    return static_cast<size_t>(key);
+  }
+
+  std::vector<std::optional<Value>> values_;
+  size_t size_{0};
+};
+
+// The most recently used answers are at the front of the list, and the map
+// points into it, so finding, touching, and evicting are all O(1)
+template <typename Key, typename Value, typename Hash = std::hash<Key>>
+class LruCache {
+ public:
+  static constexpr bool evicts = true;
+
+  explicit LruCache(size_t capacity = 1024) : capacity_(capacity) {
+    assert(capacity > 0 && "LruCache needs room for at least one answer");
+  }
+
+  const Value* find(const Key& key) {
+    auto it = index_.find(key);
+    if (it == index_.end()) {
+      return nullptr;
+    }
+    entries_.splice(entries_.begin(), entries_, it->second);
+    return &it->second->second;
+  }
+
+  void insert(const Key& key, Value value) {
+    if (auto it = index_.find(key); it != index_.end()) {
+      it->second->second = std::move(value);
+      entries_.splice(entries_.begin(), entries_, it->second);
+      return;
+    }
+    if (entries_.size() == capacity_) {
+      index_.erase(entries_.back().first);
+      entries_.pop_back();
+    }
+    entries_.emplace_front(key, std::move(value));
+    index_.emplace(key, entries_.begin());
+  }
+
+  size_t size() const { return entries_.size(); }
+
+  size_t capacity() const { return capacity_; }
+
+  void clear() {
+    entries_.clear();
+    index_.clear();
+  }
+
+ private:
+  size_t capacity_;
+  std::list<std::pair<Key, Value>> entries_;
+  std::unordered_map<Key, typename std::list<std::pair<Key, Value>>::iterator,
+                     Hash>
+      index_;
+};
+
+template <typename Key, typename Value,
+          typename Cache = HashCache<Key, Value>>
+class Memo {
+ public:
+  Memo() = default;
+
+  explicit Memo(Cache cache) : cache_(std::move(cache)) {}
+
+  // compute(key, recurse) returns the value for key, calling recurse(k) for
+  // the value of each subproblem k
+  template <typename Compute>
+  Value recursive(const Key& key, Compute compute) {
+    if (const Value* cached = cache_.find(key)) {
+      ++stats_.hits;
+      return *cached;
+    }
+    ++stats_.misses;
+    Value value =
+        compute(key, [&](const Key& k) { return recursive(k, compute); });
+    cache_.insert(key, value);
+    return value;
+  }
+
+  // dependencies(key) lists the subproblems key needs, and
+  // compute(key, lookup) returns the value for key, calling lookup(k) for
+  // the value of each of those subproblems. The subproblems must not
+  // depend on each other in a cycle, and the cache must not evict, since a
+  // subproblem is looked up again only after its dependencies are computed.
+  template <typename Dependencies, typename Compute>
+  Value iterative(const Key& key, Dependencies dependencies, Compute compute) {
+    static_assert(!Cache::evicts,
+                  "iterative() needs every answer to stay cached");
+    if (const Value* cached = cache_.find(key)) {
+      ++stats_.hits;
+      return *cached;
+    }
+    // A frame is expanded once its missing dependencies have been pushed
+    // above it, so when it comes back to the top they're all computed. A
+    // subproblem can be pushed twice before it's solved, and its second
+    // frame is dropped without counting: the hit was counted when some
+    // key found it cached, not when the stack did.
+    std::vector<std::pair<Key, bool>> stack = {{key, false}};
+    while (!stack.empty()) {
+      auto [k, expanded] = stack.back();
+      if (cache_.find(k) != nullptr) {
+        stack.pop_back();
+        continue;
+      }
+      if (expanded) {
+        stack.pop_back();
+        ++stats_.misses;
+        Value value = compute(k, [&](const Key& d) -> const Value& {
+          return *cache_.find(d);
+        });
+        cache_.insert(k, std::move(value));
+        continue;
+      }
+      stack.back().second = true;
+      const std::vector<Key> needs = dependencies(k);
+      // Pushed in reverse, so they're solved in the order listed
+      for (auto it = needs.rbegin(); it != needs.rend(); ++it) {
+        if (cache_.find(*it) != nullptr) {
+          ++stats_.hits;
+        } else {
+          stack.push_back({*it, false});
+        }
+      }
+    }
+    return *cache_.find(key);
+  }
+
+  const MemoStats& stats() const { return stats_; }
+
+  size_t size() const { return cache_.size(); }
+
+  void clear() {
+    cache_.clear();
+    stats_ = {};
+  }
+
+ private:
+  Cache cache_;
+  MemoStats stats_;
+};
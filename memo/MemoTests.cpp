@@ -0,0 +1,125 @@
+#include <cstdint>
+#include <utility>
+#include <vector>
+
+#include "Memo.h"
+#include "gtest/gtest.h"
+
+// Binomials by Pascal's rule, C(n, k) = C(n - 1, k - 1) + C(n - 1, k): lots
+// of overlapping subproblems, with two dependencies each
+using Choose = std::pair<int, int>;
+
+bool is_edge(const Choose& c) { return c.second == 0 || c.second == c.first; }
+
+TEST(Memo, DriversAgree) {
+  Memo<Choose, uint64_t, HashCache<Choose, uint64_t, PairHash>> recursive;
+  Memo<Choose, uint64_t, HashCache<Choose, uint64_t, PairHash>> iterative;
+  auto dependencies = [](const Choose& c) -> std::vector<Choose> {
+    if (is_edge(c)) {
+      return {};
+    }
+    return {{c.first - 1, c.second - 1}, {c.first - 1, c.second}};
+  };
+  auto compute = [](const Choose& c, auto choose) -> uint64_t {
+    if (is_edge(c)) {
+      return 1;
+    }
+    return choose({c.first - 1, c.second - 1}) +
+           choose({c.first - 1, c.second});
+  };
+
+  EXPECT_EQ(recursive.recursive({60, 30}, compute), 118264581564861424u);
+  EXPECT_EQ(iterative.iterative({60, 30}, dependencies, compute),
+            118264581564861424u);
+
+  // The same subproblems get solved either way, each exactly once
+  EXPECT_EQ(recursive.size(), iterative.size());
+  EXPECT_EQ(recursive.stats().misses, recursive.size());
+  EXPECT_EQ(iterative.stats().misses, iterative.size());
+  EXPECT_GT(iterative.stats().hits, 0u);
+
+  // Asking again is a single hit
+  const size_t hits = iterative.stats().hits;
+  EXPECT_EQ(iterative.iterative({30, 15}, dependencies, compute), 155117520u);
+  EXPECT_EQ(iterative.stats().hits, hits + 1);
+
+  iterative.clear();
+  EXPECT_EQ(iterative.size(), 0u);
+  EXPECT_EQ(iterative.stats().hits, 0u);
+  EXPECT_EQ(iterative.stats().misses, 0u);
+}
+
+TEST(Memo, IterativeHandlesLongChains) {
+  // sum(n) = n + sum(n - 1) is a million subproblems deep
+  Memo<int, int64_t> memo;
+  const int64_t sum = memo.iterative(
+      1000000,
+      [](int n) {
+        return n == 0 ? std::vector<int>{} : std::vector<int>{n - 1};
+      },
+      [](int n, auto sum) -> int64_t { return n == 0 ? 0 : n + sum(n - 1); });
+
+  EXPECT_EQ(sum, int64_t{1000000} * 1000001 / 2);
+  EXPECT_EQ(memo.stats().misses, 1000001u);
+  EXPECT_EQ(memo.stats().hits, 0u);
+}
+
+TEST(Memo, DuplicateFramesAreNotHits) {
+  // fib(k) is pushed by fib(k + 1) and again by fib(k + 2) before either is
+  // solved, so nothing is ever found cached: the second frames are dropped
+  auto dependencies = [](int n) {
+    return n <= 1 ? std::vector<int>{} : std::vector<int>{n - 1, n - 2};
+  };
+  auto compute = [](int n, auto fib) -> uint64_t {
+    return n <= 1 ? n : fib(n - 1) + fib(n - 2);
+  };
+  Memo<int, uint64_t> memo;
+  EXPECT_EQ(memo.iterative(10, dependencies, compute), 55u);
+  EXPECT_EQ(memo.stats().misses, 11u);
+  EXPECT_EQ(memo.stats().hits, 0u);
+}
+
+TEST(Memo, CachePolicies) {
+  auto compute = [](int n, auto fib) -> uint64_t {
+    return n <= 1 ? n : fib(n - 1) + fib(n - 2);
+  };
+
+  Memo<int, uint64_t> hashed;
+  Memo<int, uint64_t, DenseCache<int, uint64_t>> dense;
+  EXPECT_EQ(hashed.recursive(80, compute), 23416728348467685u);
+  EXPECT_EQ(dense.recursive(80, compute), 23416728348467685u);
+  EXPECT_EQ(dense.size(), 81u);
+  EXPECT_EQ(dense.stats().misses, hashed.stats().misses);
+  EXPECT_EQ(dense.stats().hits, hashed.stats().hits);
+
+  // fib(k) only needs the two answers below it, and the one it just
+  // computed, so an LRU cache of three does the same work as an unbounded
+  // one
+  Memo<int, uint64_t, LruCache<int, uint64_t>> small(
+      LruCache<int, uint64_t>(3));
+  EXPECT_EQ(small.recursive(80, compute), 23416728348467685u);
+  EXPECT_EQ(small.size(), 3u);
+  EXPECT_EQ(small.stats().misses, hashed.stats().misses);
+  EXPECT_EQ(small.stats().hits, hashed.stats().hits);
+
+  // Asking for an evicted answer computes it again
+  EXPECT_EQ(small.recursive(3, compute), 2u);
+  EXPECT_GT(small.stats().misses, 81u);
+}
+
+TEST(Memo, LruEvictsLeastRecentlyUsed) {
+  LruCache<int, int> cache(2);
+  cache.insert(1, 10);
+  cache.insert(2, 20);
+  ASSERT_NE(cache.find(1), nullptr);  // 1 is now the most recent
+  cache.insert(3, 30);
+  EXPECT_EQ(cache.find(2), nullptr);
+  EXPECT_EQ(*cache.find(1), 10);
+  EXPECT_EQ(*cache.find(3), 30);
+  EXPECT_EQ(cache.size(), 2u);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
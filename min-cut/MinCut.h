@@ -0,0 +1,209 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <vector>
+
+#include "../graph/Graph.h"
+#include "../max-flow/MaxFlow.h"
+#include "../rng/Rng.h"
+#include "../union-find/UnionFind.h"
+
+// Global minimum cut: the fewest edges of an undirected multigraph whose
+// removal splits it in two, over all ways to split it, with no source or
+// sink given. Parallel edges count separately.
+//
+// Karger's contraction: merge the two ends of a random edge, keeping
+// parallel edges and dropping loops, until two vertices are left; the
+// edges between them are a cut. A particular minimum cut of k edges
+// survives as long as none of its edges is picked, and while i vertices
+// are left every vertex has at least k edges, so the chance of picking one
+// is at most 2 / i. It survives to the end with probability at least
+// 2 / (n (n - 1)), and n^2 ln n / 2 trials miss it with probability at
+// most 1 / n.
+//
+// Contracting edges in a random order while skipping loops is Kruskal's
+// algorithm on random weights, so a trial is a shuffle and union-find,
+// O(m α(n)).
+
+struct Cut {
+  int64_t size;
+  std::vector<bool> side;  // which vertices are on one side, not all or none
+};
+
+inline int64_t cut_size(const std::vector<Edge>& edges,
+                        const std::vector<bool>& side) {
+  int64_t size = 0;
+  for (const auto& [u, v] : edges) {
+    size += side[u] != side[v];
+  }
+  return size;
+}
+
+// The merging: `labels` numbers the merged vertices from 0, and the edges
+// between them, loops left out, come back in place of `edges`. Stops at
+// `target` vertices, or sooner if the edges run out.
+template <typename Gen>
+std::vector<int> contract(size_t n, std::vector<Edge>& edges, size_t target,
+                          Gen& gen) {
+  shuffle_range(edges.begin(), edges.end(), gen);
+  UnionFind sets(n);
+  for (size_t i = 0; i < edges.size() && sets.components() > target; ++i) {
+    sets.unite(edges[i].first, edges[i].second);
+  }
+  std::vector<int> label(n, -1);
+  std::vector<int> labels(n);
+  int next = 0;
+  for (size_t v = 0; v < n; ++v) {
+    const size_t root = sets.find(v);
+    if (label[root] == -1) {
+      label[root] = next++;
+    }
+    labels[v] = label[root];
+  }
+  std::vector<Edge> remaining;
+  for (const auto& [u, v] : edges) {
+    if (labels[u] != labels[v]) {
+      remaining.push_back({labels[u], labels[v]});
+    }
+  }
+  edges.swap(remaining);
+  return labels;
+}
+
+// The merged vertex holding vertex 0 against the others. When the edges
+// run out with more than two merged vertices left, the graph is
+// disconnected, and that's a cut of no edges.
+inline std::vector<bool> with_vertex_zero(const std::vector<int>& labels) {
+  std::vector<bool> side(labels.size());
+  for (size_t v = 0; v < labels.size(); ++v) {
+    side[v] = labels[v] == labels[0];
+  }
+  return side;
+}
+
+template <typename Gen>
+Cut karger_trial(size_t n, std::vector<Edge> edges, Gen& gen) {
+  assert(n >= 2 && "a cut needs two vertices");
+  const auto labels = contract(n, edges, 2, gen);
+  return {static_cast<int64_t>(edges.size()), with_vertex_zero(labels)};
+}
+
+// Enough trials for the 1 / n chance of a miss
+inline size_t karger_trials(size_t n) {
+  const double m = static_cast<double>(n);
+  return static_cast<size_t>(std::ceil(m * (m - 1) / 2 * std::log(m))) + 1;
+}
+
+// The smallest cut found in `trials` contractions, 0 for karger_trials(n).
+// O(n^2 m log n) with the default.
+template <typename Gen>
+Cut karger_min_cut(size_t n, const std::vector<Edge>& edges, Gen& gen,
+                   size_t trials = 0) {
+  if (trials == 0) {
+    trials = karger_trials(n);
+  }
+  Cut best{std::numeric_limits<int64_t>::max(), {}};
+  for (size_t trial = 0; trial < trials && best.size > 0; ++trial) {
+    Cut cut = karger_trial(n, edges, gen);
+    if (cut.size < best.size) {
+      best = std::move(cut);
+    }
+  }
+  return best;
+}
+
+// O(2^n m): every split, for small n, with vertex 0 always on the true side
+inline Cut min_cut_naive(size_t n, const std::vector<Edge>& edges) {
+  assert(n >= 2 && n < 32 && "a cut needs two vertices");
+  Cut best{std::numeric_limits<int64_t>::max(), {}};
+  for (uint32_t mask = 0; mask + 1 < (uint32_t{1} << (n - 1)); ++mask) {
+    std::vector<bool> side(n, true);
+    for (size_t v = 1; v < n; ++v) {
+      side[v] = mask >> (v - 1) & 1;
+    }
+    const int64_t size = cut_size(edges, side);
+    if (size < best.size) {
+      best = {size, side};
+    }
+  }
+  return best;
+}
+
+// Karger-Stein: the early contractions are the safe ones, since the chance
+// of hitting the minimum cut only gets large near the end. So contract to
+// about n / sqrt(2) vertices, where the cut survives with probability about
+// 1 / 2, and from there recurse twice, independently, keeping the better
+// cut. One run finds the minimum with probability Ω(1 / log n) in
+// O(n^2 log n) time.
+template <typename Gen>
+Cut karger_stein_run(size_t n, const std::vector<Edge>& edges, Gen& gen) {
+  if (n <= 6) {
+    return min_cut_naive(n, edges);
+  }
+  const auto target =
+      static_cast<size_t>(std::ceil(1 + static_cast<double>(n) / std::sqrt(2)));
+  Cut best{std::numeric_limits<int64_t>::max(), {}};
+  for (int branch = 0; branch < 2 && best.size > 0; ++branch) {
+    std::vector<Edge> contracted = edges;
+    const auto labels = contract(n, contracted, target, gen);
+    const auto m =
+        static_cast<size_t>(*std::max_element(labels.begin(), labels.end())) +
+        1;
+    if (m > target) {
+      return {0, with_vertex_zero(labels)};
+    }
+    const Cut inner = karger_stein_run(m, contracted, gen);
+    if (inner.size < best.size) {
+      best.size = inner.size;
+      best.side.assign(n, false);
+      for (size_t v = 0; v < n; ++v) {
+        best.side[v] = inner.side[labels[v]];
+      }
+    }
+  }
+  return best;
+}
+
+// The smallest cut of `runs` runs, by default log2(n)^2 of them, which
+// miss with probability polynomially small in n
+template <typename Gen>
+Cut karger_stein_min_cut(size_t n, const std::vector<Edge>& edges, Gen& gen,
+                         size_t runs = 0) {
+  assert(n >= 2 && "a cut needs two vertices");
+  if (runs == 0) {
+    const double log_n = std::log2(static_cast<double>(n));
+    runs = static_cast<size_t>(std::ceil(log_n * log_n)) + 1;
+  }
+  Cut best{std::numeric_limits<int64_t>::max(), {}};
+  for (size_t run = 0; run < runs && best.size > 0; ++run) {
+    Cut cut = karger_stein_run(n, edges, gen);
+    if (cut.size < best.size) {
+      best = std::move(cut);
+    }
+  }
+  return best;
+}
+
+// The deterministic answer: some minimum cut separates vertex 0 from some
+// other vertex t, so it's the smallest of the n - 1 minimum 0-t cuts, each
+// a max flow with every edge of capacity 1 both ways. O(n V E^2).
+inline Cut min_cut_by_max_flow(size_t n, const std::vector<Edge>& edges) {
+  assert(n >= 2 && "a cut needs two vertices");
+  Cut best{std::numeric_limits<int64_t>::max(), {}};
+  for (size_t t = 1; t < n; ++t) {
+    FlowNetwork network(n);
+    for (const auto& [u, v] : edges) {
+      network.add_undirected_edge(u, v, 1);
+    }
+    const int64_t size = network.max_flow(0, static_cast<int>(t));
+    if (size < best.size) {
+      best = {size, network.source_side(0)};
+    }
+  }
+  return best;
+}
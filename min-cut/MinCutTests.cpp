@@ -0,0 +1,108 @@
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../graph/Graph.h"
+#include "../rng/Rng.h"
+#include "MinCut.h"
+#include "gtest/gtest.h"
+
+std::vector<Edge> clique(int first, int size) {
+  std::vector<Edge> edges;
+  for (int u = first; u < first + size; ++u) {
+    for (int v = u + 1; v < first + size; ++v) {
+      edges.push_back({u, v});
+    }
+  }
+  return edges;
+}
+
+// A cut is a proper split, and its size is what it says
+void expect_cut(size_t n, const std::vector<Edge>& edges, const Cut& cut) {
+  ASSERT_EQ(cut.side.size(), n);
+  size_t on_side = 0;
+  for (bool s : cut.side) {
+    on_side += s;
+  }
+  EXPECT_GT(on_side, 0u);
+  EXPECT_LT(on_side, n);
+  EXPECT_EQ(cut_size(edges, cut.side), cut.size);
+}
+
+// Two cliques of five joined by two edges
+TEST(MinCut, Example) {
+  std::vector<Edge> edges = clique(0, 5);
+  const auto other = clique(5, 5);
+  edges.insert(edges.end(), other.begin(), other.end());
+  edges.push_back({0, 5});
+  edges.push_back({4, 9});
+  Rng gen(226);
+  for (const Cut& cut : {karger_min_cut(10, edges, gen),
+                         karger_stein_min_cut(10, edges, gen),
+                         min_cut_by_max_flow(10, edges)}) {
+    EXPECT_EQ(cut.size, 2);
+    expect_cut(10, edges, cut);
+    for (int v = 1; v < 5; ++v) {
+      EXPECT_EQ(cut.side[v], cut.side[0]);
+      EXPECT_NE(cut.side[v + 5], cut.side[0]);
+    }
+  }
+
+  // Disconnected, and two vertices with parallel edges
+  const std::vector<Edge> apart = {{0, 1}, {2, 3}};
+  EXPECT_EQ(karger_min_cut(4, apart, gen).size, 0);
+  EXPECT_EQ(karger_stein_min_cut(4, apart, gen).size, 0);
+  expect_cut(4, apart, karger_min_cut(4, apart, gen));
+  const std::vector<Edge> parallel = {{0, 1}, {1, 0}, {0, 1}};
+  EXPECT_EQ(karger_min_cut(2, parallel, gen).size, 3);
+}
+
+// Random multigraphs, some disconnected, against max flow and against
+// every split
+TEST(MinCut, MatchesMaxFlow) {
+  Rng gen(226);
+  for (int trial = 0; trial < 200; ++trial) {
+    const size_t n = 2 + uniform_below(gen, 9);
+    const std::vector<Edge> edges =
+        random_multigraph(n, uniform_below(gen, 3 * n), gen);
+    const Cut exact = min_cut_by_max_flow(n, edges);
+    ASSERT_EQ(exact.size, min_cut_naive(n, edges).size);
+    expect_cut(n, edges, exact);
+    const Cut karger = karger_min_cut(n, edges, gen);
+    ASSERT_EQ(karger.size, exact.size);
+    expect_cut(n, edges, karger);
+    const Cut stein = karger_stein_min_cut(n, edges, gen);
+    ASSERT_EQ(stein.size, exact.size);
+    expect_cut(n, edges, stein);
+  }
+}
+
+// Bigger graphs: two dense random halves with three edges between them,
+// usually the minimum cut, where a single contraction would rarely find it
+TEST(MinCut, KargerSteinPlanted) {
+  Rng gen(226);
+  for (int trial = 0; trial < 5; ++trial) {
+    const size_t n = 40;
+    std::vector<Edge> edges;
+    for (size_t u = 0; u < n; ++u) {
+      for (size_t v = u + 1; v < n; ++v) {
+        if ((u < n / 2) == (v < n / 2) && bernoulli(gen, 0.5)) {
+          edges.push_back({static_cast<int>(u), static_cast<int>(v)});
+        }
+      }
+    }
+    for (int k = 0; k < 3; ++k) {
+      edges.push_back({static_cast<int>(uniform_below(gen, n / 2)),
+                       static_cast<int>(n / 2 + uniform_below(gen, n / 2))});
+    }
+    const Cut stein = karger_stein_min_cut(n, edges, gen);
+    EXPECT_EQ(stein.size, min_cut_by_max_flow(n, edges).size);
+    expect_cut(n, edges, stein);
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
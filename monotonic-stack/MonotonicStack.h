@@ -0,0 +1,170 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+// Monotonic stack etudes.
+//
+// All of these problems ask, for every element, "where is the nearest element
+// to my left (or right) that is bigger (or smaller) than me?". Scanning left
+// to right with a stack of indices answers it in O(n) total: when x arrives,
+// pop every index whose element x beats -- x is their answer -- and then push
+// x. The stack stays monotonic, and each index is pushed and popped once.
+
+// For each i, the index of the first element to the right of i that is
+// strictly greater than nums[i], or -1 if there is none.
+inline std::vector<int> next_greater_index(const std::vector<int>& nums) {
+  std::vector<int> result(nums.size(), -1);
+  // Indices still waiting for their answer. Their values are non-increasing
+  // from bottom to top.
+  std::vector<size_t> stack;
+  for (size_t i = 0; i < nums.size(); ++i) {
+    while (!stack.empty() && nums[stack.back()] < nums[i]) {
+      result[stack.back()] = static_cast<int>(i);
+      stack.pop_back();
+    }
+    stack.push_back(i);
+  }
+  return result;
+}
+
+inline std::vector<int> next_greater_index_naive(const std::vector<int>& nums) {
+  std::vector<int> result(nums.size(), -1);
+  for (size_t i = 0; i < nums.size(); ++i) {
+    for (size_t j = i + 1; j < nums.size(); ++j) {
+      if (nums[j] > nums[i]) {
+        result[i] = static_cast<int>(j);
+        break;
+      }
+    }
+  }
+  return result;
+}
+
+// For each day, how many days until a strictly warmer one (0 if never)
+inline std::vector<int> daily_temperatures(const std::vector<int>& temps) {
+  auto next = next_greater_index(temps);
+  std::vector<int> result(temps.size(), 0);
+  for (size_t i = 0; i < temps.size(); ++i) {
+    if (next[i] != -1) {
+      result[i] = next[i] - static_cast<int>(i);
+    }
+  }
+  return result;
+}
+
+// Stock span: for each day, the number of consecutive days ending today
+// (including today) whose price was at most today's price. This is the same
+// scan looking left: the span ends just after the previous strictly greater
+// price.
+inline std::vector<int> stock_span(const std::vector<int>& prices) {
+  std::vector<int> result(prices.size());
+  std::vector<size_t> stack;
+  for (size_t i = 0; i < prices.size(); ++i) {
+    while (!stack.empty() && prices[stack.back()] <= prices[i]) {
+      stack.pop_back();
+    }
+    const int previous_greater =
+        stack.empty() ? -1 : static_cast<int>(stack.back());
+    result[i] = static_cast<int>(i) - previous_greater;
+    stack.push_back(i);
+  }
+  return result;
+}
+
+inline std::vector<int> stock_span_naive(const std::vector<int>& prices) {
+  std::vector<int> result(prices.size());
+  for (size_t i = 0; i < prices.size(); ++i) {
+    int span = 0;
+    for (size_t j = i + 1; j-- > 0 && prices[j] <= prices[i];) {
+      ++span;
+    }
+    result[i] = span;
+  }
+  return result;
+}
+
+// Area of the largest rectangle that fits under the histogram.
+//
+// The best rectangle is as tall as its shortest bar. So for each bar, extend
+// it left and right until a strictly shorter bar gets in the way; the widest
+// rectangle of that bar's height is between those two shorter bars. When a
+// bar is popped from the stack, the bar that pops it is the first shorter one
+// on its right, and the bar below it on the stack is the first shorter one on
+// its left.
+inline int64_t largest_rectangle_in_histogram(
+    const std::vector<int>& heights) {
+  int64_t best = 0;
+  std::vector<size_t> stack;  // Bar heights increase from bottom to top
+  for (size_t i = 0; i <= heights.size(); ++i) {
+    // A zero-height sentinel at the end flushes the stack
+    const int h = i < heights.size() ? heights[i] : 0;
+    while (!stack.empty() && heights[stack.back()] >= h) {
+      const int64_t height = heights[stack.back()];
+      stack.pop_back();
+      const size_t left = stack.empty() ? 0 : stack.back() + 1;
+      best = std::max(best, height * static_cast<int64_t>(i - left));
+    }
+    stack.push_back(i);
+  }
+  return best;
+}
+
+inline int64_t largest_rectangle_in_histogram_naive(
+    const std::vector<int>& heights) {
+  int64_t best = 0;
+  for (size_t i = 0; i < heights.size(); ++i) {
+    int64_t min_height = heights[i];
+    for (size_t j = i; j < heights.size(); ++j) {
+      min_height = std::min<int64_t>(min_height, heights[j]);
+      best = std::max(best, min_height * static_cast<int64_t>(j - i + 1));
+    }
+  }
+  return best;
+}
+
+// Area of the largest all-ones rectangle in a binary matrix.
+//
+// Reduce to histograms: for each row, let height[c] be the number of
+// consecutive ones ending at that row in column c. Every all-ones rectangle
+// has a bottom row, and under that row it is a rectangle in that row's
+// histogram. O(rows * cols).
+inline int64_t maximal_rectangle(const std::vector<std::vector<int>>& matrix) {
+  if (matrix.empty()) {
+    return 0;
+  }
+  std::vector<int> heights(matrix[0].size(), 0);
+  int64_t best = 0;
+  for (const auto& row : matrix) {
+    for (size_t c = 0; c < row.size(); ++c) {
+      heights[c] = row[c] ? heights[c] + 1 : 0;
+    }
+    best = std::max(best, largest_rectangle_in_histogram(heights));
+  }
+  return best;
+}
+
+// O(rows^2 * cols^2): try every top-left corner and grow the rectangle
+inline int64_t maximal_rectangle_naive(
+    const std::vector<std::vector<int>>& matrix) {
+  int64_t best = 0;
+  const size_t rows = matrix.size();
+  const size_t cols = rows ? matrix[0].size() : 0;
+  for (size_t top = 0; top < rows; ++top) {
+    for (size_t left = 0; left < cols; ++left) {
+      // Widest all-ones run starting at (row, left), shrinking as we go down
+      size_t width = cols - left;
+      for (size_t row = top; row < rows && width > 0; ++row) {
+        size_t run = 0;
+        while (run < width && matrix[row][left + run]) {
+          ++run;
+        }
+        width = run;
+        best = std::max(best, static_cast<int64_t>(width * (row - top + 1)));
+      }
+    }
+  }
+  return best;
+}
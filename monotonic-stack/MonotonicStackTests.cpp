@@ -0,0 +1,106 @@
+#include <random>
+#include <vector>
+
+#include "MonotonicStack.h"
+#include "gtest/gtest.h"
+
+std::vector<int> random_vector(std::mt19937& gen, size_t n, int lo, int hi) {
+  std::uniform_int_distribution<int> value(lo, hi);
+  std::vector<int> v(n);
+  for (auto& x : v) {
+    x = value(gen);
+  }
+  return v;
+}
+
+TEST(MonotonicStack, NextGreaterIndex) {
+  std::vector<int> nums = {2, 1, 2, 4, 3};
+
+  EXPECT_EQ(next_greater_index(nums), (std::vector<int>{3, 2, 3, -1, -1}));
+  EXPECT_TRUE(next_greater_index({}).empty());
+}
+
+TEST(MonotonicStack, NextGreaterIndexIgnoresEqualElements) {
+  std::vector<int> nums = {3, 3, 3};
+
+  EXPECT_EQ(next_greater_index(nums), (std::vector<int>{-1, -1, -1}));
+}
+
+TEST(MonotonicStack, DailyTemperatures) {
+  std::vector<int> temps = {73, 74, 75, 71, 69, 72, 76, 73};
+
+  EXPECT_EQ(daily_temperatures(temps),
+            (std::vector<int>{1, 1, 4, 2, 1, 1, 0, 0}));
+}
+
+TEST(MonotonicStack, StockSpan) {
+  std::vector<int> prices = {100, 80, 60, 70, 60, 75, 85};
+
+  EXPECT_EQ(stock_span(prices), (std::vector<int>{1, 1, 1, 2, 1, 4, 6}));
+}
+
+TEST(MonotonicStack, LargestRectangleInHistogram) {
+  EXPECT_EQ(largest_rectangle_in_histogram({2, 1, 5, 6, 2, 3}), 10);
+  EXPECT_EQ(largest_rectangle_in_histogram({2, 4}), 4);
+  EXPECT_EQ(largest_rectangle_in_histogram({}), 0);
+  EXPECT_EQ(largest_rectangle_in_histogram({0, 0}), 0);
+  EXPECT_EQ(largest_rectangle_in_histogram({3, 3, 3, 3}), 12);
+  EXPECT_EQ(largest_rectangle_in_histogram({1, 2, 3, 4, 5}), 9);
+}
+
+TEST(MonotonicStack, LargestRectangleDoesNotOverflow) {
+  std::vector<int> heights(100000, 1000000);
+
+  EXPECT_EQ(largest_rectangle_in_histogram(heights), 100000000000LL);
+}
+
+TEST(MonotonicStack, MaximalRectangle) {
+  std::vector<std::vector<int>> matrix = {
+      {1, 0, 1, 0, 0},
+      {1, 0, 1, 1, 1},
+      {1, 1, 1, 1, 1},
+      {1, 0, 0, 1, 0},
+  };
+
+  EXPECT_EQ(maximal_rectangle(matrix), 6);
+  EXPECT_EQ(maximal_rectangle({}), 0);
+  EXPECT_EQ(maximal_rectangle({{0}}), 0);
+  EXPECT_EQ(maximal_rectangle({{1}}), 1);
+}
+
+TEST(MonotonicStack, MatchesBruteForce) {
+  std::mt19937 gen(8);
+  for (int trial = 0; trial < 300; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(0, 60)(gen);
+    auto nums = random_vector(gen, n, 0, 10);
+
+    ASSERT_EQ(next_greater_index(nums), next_greater_index_naive(nums));
+    ASSERT_EQ(stock_span(nums), stock_span_naive(nums));
+    ASSERT_EQ(largest_rectangle_in_histogram(nums),
+              largest_rectangle_in_histogram_naive(nums));
+  }
+}
+
+TEST(MonotonicStack, MaximalRectangleMatchesBruteForce) {
+  std::mt19937 gen(9);
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t rows = std::uniform_int_distribution<size_t>(1, 8)(gen);
+    size_t cols = std::uniform_int_distribution<size_t>(1, 8)(gen);
+    std::vector<std::vector<int>> matrix;
+    for (size_t r = 0; r < rows; ++r) {
+      // Bias towards ones so that big rectangles show up
+      auto row = random_vector(gen, cols, 0, 3);
+      for (auto& x : row) {
+        x = x > 0 ? 1 : 0;
+      }
+      matrix.push_back(row);
+    }
+
+    ASSERT_EQ(maximal_rectangle(matrix), maximal_rectangle_naive(matrix));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,232 @@
+#pragma once
+
+#include <array>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <string>
+#include <string_view>
+#include <type_traits>
+#include <utility>
+
+#include "../errors/Error.h"
+
+// Numbers to text and back, by hand: integers in any base from 2 to 36,
+// C's forgiving atoi, and Roman numerals.
+//
+// The one trick in integer parsing is overflow. The magnitude is built up
+// unsigned, and before each digit it's checked against the limit for the
+// sign, (limit - digit) / base, which can't itself overflow. The limit for
+// a negative number is one more than for a positive one, so the most
+// negative value parses too, though its magnitude has no positive twin.
+
+// The digit a character stands for, in any base up to 36 ('a' and 'A' are
+// 10, 'z' and 'Z' are 35), or 36 for anything that isn't one
+inline int digit_value(char c) {
+  if ('0' <= c && c <= '9') {
+    return c - '0';
+  }
+  if ('a' <= c && c <= 'z') {
+    return c - 'a' + 10;
+  }
+  if ('A' <= c && c <= 'Z') {
+    return c - 'A' + 10;
+  }
+  return 36;
+}
+
+inline bool is_space(char c) {
+  return c == ' ' || c == '\t' || c == '\n' || c == '\v' || c == '\f' ||
+         c == '\r';
+}
+
+// value in base 2 to 36, with lowercase letters for the digits past 9, and
+// a '-' in front if it's negative
+template <typename Int>
+std::string format_integer(Int value, int base = 10) {
+  static_assert(std::is_integral_v<Int>);
+  assert(2 <= base && base <= 36 && "bases run from 2 to 36");
+  using Unsigned = std::make_unsigned_t<Int>;
+  const bool negative = value < 0;
+  // Negating in unsigned arithmetic wraps the most negative value to its
+  // own magnitude
+  auto magnitude = static_cast<Unsigned>(value);
+  if (negative) {
+    magnitude = static_cast<Unsigned>(0 - magnitude);
+  }
+  std::string text;
+  do {
+    text += "0123456789abcdefghijklmnopqrstuvwxyz"[magnitude % base];
+    magnitude /= static_cast<Unsigned>(base);
+  } while (magnitude > 0);
+  if (negative) {
+    text += '-';
+  }
+  return {text.rbegin(), text.rend()};
+}
+
+// All of text as an Int in base 2 to 36: whitespace around it is fine, and
+// so is a sign in front ('-' only for signed types), but nothing else. A
+// value out of Int's range is an overflow.
+template <typename Int>
+Result<Int> try_parse_integer(std::string_view text, int base = 10) {
+  static_assert(std::is_integral_v<Int>);
+  assert(2 <= base && base <= 36 && "bases run from 2 to 36");
+  using Unsigned = std::make_unsigned_t<Int>;
+  size_t i = 0;
+  while (i < text.size() && is_space(text[i])) {
+    ++i;
+  }
+  bool negative = false;
+  if (i < text.size() && (text[i] == '+' || text[i] == '-')) {
+    negative = text[i] == '-';
+    ++i;
+  }
+  if (negative && !std::is_signed_v<Int>) {
+    return Error{ErrorKind::kInvalidInput, "a sign on an unsigned value"};
+  }
+  const Unsigned limit =
+      static_cast<Unsigned>(std::numeric_limits<Int>::max()) + negative;
+  Unsigned magnitude = 0;
+  const size_t first = i;
+  for (; i < text.size(); ++i) {
+    const int digit = digit_value(text[i]);
+    if (digit >= base) {
+      break;
+    }
+    if (magnitude > (limit - static_cast<Unsigned>(digit)) / base) {
+      return Error{ErrorKind::kOverflow,
+                   std::string(text) + " doesn't fit " +
+                       std::to_string(sizeof(Int) * 8) + " bits"};
+    }
+    magnitude = static_cast<Unsigned>(magnitude * base + digit);
+  }
+  if (i == first) {
+    return Error{ErrorKind::kInvalidInput,
+                 "no digits in '" + std::string(text) + "'"};
+  }
+  while (i < text.size() && is_space(text[i])) {
+    ++i;
+  }
+  if (i < text.size()) {
+    return Error{ErrorKind::kInvalidInput,
+                 "bad digit '" + std::string(1, text[i]) + "' in base " +
+                     std::to_string(base)};
+  }
+  return negative ? static_cast<Int>(0 - magnitude)
+                  : static_cast<Int>(magnitude);
+}
+
+template <typename Int>
+Int parse_integer(std::string_view text, int base = 10) {
+  return try_parse_integer<Int>(text, base).or_throw();
+}
+
+// C's atoi, made safe: skips leading whitespace, takes a sign, then as
+// many decimal digits as there are, and ignores the rest. No digits is 0,
+// and a value out of range saturates at the nearest end, as strtoll does.
+inline int64_t saturating_atoi(std::string_view text) {
+  size_t i = 0;
+  while (i < text.size() && is_space(text[i])) {
+    ++i;
+  }
+  bool negative = false;
+  if (i < text.size() && (text[i] == '+' || text[i] == '-')) {
+    negative = text[i] == '-';
+    ++i;
+  }
+  const uint64_t limit =
+      static_cast<uint64_t>(std::numeric_limits<int64_t>::max()) + negative;
+  uint64_t magnitude = 0;
+  for (; i < text.size() && '0' <= text[i] && text[i] <= '9'; ++i) {
+    const auto digit = static_cast<uint64_t>(text[i] - '0');
+    if (magnitude > (limit - digit) / 10) {
+      return negative ? std::numeric_limits<int64_t>::min()
+                      : std::numeric_limits<int64_t>::max();
+    }
+    magnitude = magnitude * 10 + digit;
+  }
+  return negative ? static_cast<int64_t>(0 - magnitude)
+                  : static_cast<int64_t>(magnitude);
+}
+
+// Roman numerals, 1 to 3999 in the standard form: the thousands, hundreds,
+// tens, and ones written separately, largest first, each with at most
+// three of a symbol and the subtractive pairs (IV, IX, XL, XC, CD, CM) for
+// fours and nines.
+
+constexpr std::array<std::pair<int, std::string_view>, 13> kRomanSymbols = {{
+    {1000, "M"},
+    {900, "CM"},
+    {500, "D"},
+    {400, "CD"},
+    {100, "C"},
+    {90, "XC"},
+    {50, "L"},
+    {40, "XL"},
+    {10, "X"},
+    {9, "IX"},
+    {5, "V"},
+    {4, "IV"},
+    {1, "I"},
+}};
+
+// Greedy: the largest symbol that fits, as many times as it fits
+inline Result<std::string> try_to_roman(int value) {
+  if (value < 1 || value > 3999) {
+    return Error{ErrorKind::kInvalidInput,
+                 "Roman numerals run from 1 to 3999, not " +
+                     std::to_string(value)};
+  }
+  std::string roman;
+  for (const auto& [amount, symbol] : kRomanSymbols) {
+    for (; value >= amount; value -= amount) {
+      roman += symbol;
+    }
+  }
+  return roman;
+}
+
+inline std::string to_roman(int value) {
+  return try_to_roman(value).or_throw();
+}
+
+// Left to right, a symbol smaller than the one after it subtracts, and
+// every other symbol adds. That reads any well-formed numeral, and plenty
+// of ill-formed ones too (IIII, IM, VX), so a numeral only counts if
+// writing its value back gives the same text.
+inline Result<int> try_from_roman(std::string_view roman) {
+  auto symbol_value = [](char c) {
+    for (const auto& [amount, symbol] : kRomanSymbols) {
+      if (symbol.size() == 1 && symbol[0] == c) {
+        return amount;
+      }
+    }
+    return 0;
+  };
+  // MMMDCCCLXXXVIII, 3888, is the longest
+  if (roman.size() > 15) {
+    return Error{ErrorKind::kInvalidInput,
+                 "'" + std::string(roman) + "' is too long"};
+  }
+  int value = 0;
+  for (size_t i = 0; i < roman.size(); ++i) {
+    const int current = symbol_value(roman[i]);
+    if (current == 0) {
+      return Error{ErrorKind::kInvalidInput,
+                   "'" + std::string(roman) + "' isn't a Roman numeral"};
+    }
+    const int next = i + 1 < roman.size() ? symbol_value(roman[i + 1]) : 0;
+    value += current < next ? -current : current;
+  }
+  if (value < 1 || value > 3999 || try_to_roman(value).value() != roman) {
+    return Error{ErrorKind::kInvalidInput,
+                 "'" + std::string(roman) + "' isn't in standard form"};
+  }
+  return value;
+}
+
+inline int from_roman(std::string_view roman) {
+  return try_from_roman(roman).or_throw();
+}
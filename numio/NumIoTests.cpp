@@ -0,0 +1,181 @@
+#include <charconv>
+#include <cstdint>
+#include <cstdlib>
+#include <limits>
+#include <optional>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <system_error>
+
+#include "../rng/Rng.h"
+#include "NumIo.h"
+#include "gtest/gtest.h"
+
+std::string std_format(int64_t value, int base) {
+  char buffer[80];
+  auto [end, error] =
+      std::to_chars(buffer, buffer + sizeof buffer, value, base);
+  EXPECT_TRUE(error == std::errc());
+  return std::string(buffer, end);
+}
+
+// std::from_chars takes no whitespace or '+', so only call it on text
+// without them
+template <typename Int>
+std::optional<Int> std_parse(const std::string& text, int base) {
+  Int value;
+  const char* end = text.data() + text.size();
+  auto [stop, error] = std::from_chars(text.data(), end, value, base);
+  if (error != std::errc() || stop != end) {
+    return std::nullopt;
+  }
+  return value;
+}
+
+TEST(NumIo, Format) {
+  EXPECT_EQ(format_integer(0), "0");
+  EXPECT_EQ(format_integer(-42), "-42");
+  EXPECT_EQ(format_integer(255, 16), "ff");
+  EXPECT_EQ(format_integer(-5, 2), "-101");
+  EXPECT_EQ(format_integer(std::numeric_limits<int64_t>::min()),
+            "-9223372036854775808");
+  EXPECT_EQ(format_integer(std::numeric_limits<uint64_t>::max(), 36),
+            "3w5e11264sgsf");
+  EXPECT_EQ(format_integer(std::numeric_limits<int8_t>::min(), 2),
+            "-10000000");
+
+  // Every 16-bit value in every base
+  for (int base = 2; base <= 36; ++base) {
+    for (int v = -32768; v <= 32767; ++v) {
+      ASSERT_EQ(format_integer(static_cast<int16_t>(v), base),
+                std_format(v, base));
+    }
+  }
+  Rng gen(199);
+  for (int t = 0; t < 20000; ++t) {
+    const auto v = static_cast<int64_t>(gen());
+    const int base = uniform_int(gen, 2, 36);
+    ASSERT_EQ(format_integer(v, base), std_format(v, base));
+  }
+}
+
+TEST(NumIo, Parse) {
+  EXPECT_EQ(parse_integer<int>("  -42 "), -42);
+  EXPECT_EQ(parse_integer<int>("+7"), 7);
+  EXPECT_EQ(parse_integer<int>("FF", 16), 255);
+  EXPECT_EQ(parse_integer<int64_t>("-9223372036854775808"),
+            std::numeric_limits<int64_t>::min());
+  EXPECT_EQ(parse_integer<uint64_t>("18446744073709551615"),
+            std::numeric_limits<uint64_t>::max());
+  EXPECT_EQ(try_parse_integer<int64_t>("9223372036854775808").error().kind,
+            ErrorKind::kOverflow);
+  EXPECT_THROW(parse_integer<int8_t>("128"), std::overflow_error);
+  EXPECT_EQ(parse_integer<int8_t>("-128"), -128);
+  EXPECT_THROW(parse_integer<unsigned>("-1"), std::invalid_argument);
+  EXPECT_THROW(parse_integer<int>(""), std::invalid_argument);
+  EXPECT_THROW(parse_integer<int>(" - 1"), std::invalid_argument);
+  EXPECT_THROW(parse_integer<int>("12a"), std::invalid_argument);
+  EXPECT_THROW(parse_integer<int>("2", 2), std::invalid_argument);
+
+  // Every string of up to four characters from a set that makes digits,
+  // signs, and junk, as an 8-bit value in a handful of bases
+  const std::string alphabet = "-019aZz@";
+  for (int length = 1; length <= 4; ++length) {
+    std::string text(length, alphabet[0]);
+    for (int code = 0;; ++code) {
+      int rest = code;
+      for (char& c : text) {
+        c = alphabet[rest % alphabet.size()];
+        rest /= static_cast<int>(alphabet.size());
+      }
+      if (rest > 0) {
+        break;
+      }
+      for (int base : {2, 10, 11, 16, 36}) {
+        const auto expected = std_parse<int8_t>(text, base);
+        const auto ours = try_parse_integer<int8_t>(text, base);
+        ASSERT_EQ(ours.ok(), expected.has_value()) << text << " " << base;
+        if (ours.ok()) {
+          ASSERT_EQ(ours.value(), *expected);
+        }
+        const auto unsigned_expected = std_parse<uint8_t>(text, base);
+        ASSERT_EQ(try_parse_integer<uint8_t>(text, base).ok(),
+                  unsigned_expected.has_value());
+      }
+    }
+  }
+
+  Rng gen(199);
+  for (int t = 0; t < 20000; ++t) {
+    const int base = uniform_int(gen, 2, 36);
+    const auto v = static_cast<int64_t>(gen());
+    const std::string text = format_integer(v, base);
+    ASSERT_EQ(parse_integer<int64_t>(text, base), v);
+    // One more digit is almost always too many
+    const auto longer = try_parse_integer<int64_t>(text + "0", base);
+    const bool fits = std_parse<int64_t>(text + "0", base).has_value();
+    ASSERT_EQ(longer.ok(), fits);
+  }
+}
+
+TEST(NumIo, SaturatingAtoi) {
+  EXPECT_EQ(saturating_atoi("42"), 42);
+  EXPECT_EQ(saturating_atoi("   -42"), -42);
+  EXPECT_EQ(saturating_atoi("4193 with words"), 4193);
+  EXPECT_EQ(saturating_atoi("words and 987"), 0);
+  EXPECT_EQ(saturating_atoi("-91283472332919283472332"),
+            std::numeric_limits<int64_t>::min());
+  EXPECT_EQ(saturating_atoi("+-1"), 0);
+
+  Rng gen(199);
+  const std::string alphabet = " \t+-0123456789x";
+  for (int t = 0; t < 50000; ++t) {
+    std::string text(uniform_below(gen, 25), ' ');
+    for (char& c : text) {
+      c = alphabet[uniform_below(gen, alphabet.size())];
+    }
+    const long long expected = std::strtoll(text.c_str(), nullptr, 10);
+    ASSERT_EQ(saturating_atoi(text), expected) << text;
+  }
+}
+
+TEST(NumIo, Roman) {
+  EXPECT_EQ(to_roman(1994), "MCMXCIV");
+  EXPECT_EQ(to_roman(3888), "MMMDCCCLXXXVIII");
+  EXPECT_EQ(from_roman("LVIII"), 58);
+  EXPECT_THROW(to_roman(0), std::invalid_argument);
+  EXPECT_THROW(to_roman(4000), std::invalid_argument);
+  for (const char* bad : {"", "IIII", "IM", "VX", "XIIX", "MMMM", "ABC"}) {
+    EXPECT_FALSE(try_from_roman(bad).ok()) << bad;
+  }
+
+  std::set<std::string> numerals;
+  for (int v = 1; v <= 3999; ++v) {
+    const std::string roman = to_roman(v);
+    ASSERT_EQ(from_roman(roman), v);
+    numerals.insert(roman);
+  }
+  // Every string of up to five symbols is a numeral exactly when it's one
+  // of the standard forms
+  const std::string symbols = "IVXLCDM";
+  for (int length = 1; length <= 5; ++length) {
+    std::string text(length, 'I');
+    for (int code = 0;; ++code) {
+      int rest = code;
+      for (char& c : text) {
+        c = symbols[rest % symbols.size()];
+        rest /= static_cast<int>(symbols.size());
+      }
+      if (rest > 0) {
+        break;
+      }
+      ASSERT_EQ(try_from_roman(text).ok(), numerals.count(text) > 0) << text;
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
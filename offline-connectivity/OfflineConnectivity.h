@@ -0,0 +1,186 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <map>
+#include <queue>
+#include <set>
+#include <utility>
+#include <vector>
+
+#include "../graph/Graph.h"
+#include "../union-find/UnionFind.h"
+
+// Dynamic connectivity, offline: a graph on vertices 0..n-1 changes by
+// edges coming and going, and queries along the way ask whether two
+// vertices are connected at that moment. Knowing all of it up front turns
+// deletions, which union-find can't do, into rollbacks, which it can.
+//
+// Every edge is present for an interval of queries. Put the queries on a
+// segment tree, and each edge on the O(log q) nodes that cover its
+// interval. A depth-first walk then unites a node's edges on the way down
+// and rolls them back on the way up, so at each leaf exactly the edges
+// present at that query are united. O((q + m log q) log n) for m edge
+// events and q queries.
+
+enum class ConnectivityOp { kAdd, kRemove, kQuery };
+
+// Add or remove the undirected edge u-v, or ask if u and v are connected.
+// Parallel edges are fine, and a remove takes away one copy, which must be
+// there.
+struct ConnectivityEvent {
+  ConnectivityOp op;
+  int u;
+  int v;
+};
+
+// The answers to the queries, in order
+inline std::vector<bool> offline_connectivity(
+    size_t n, const std::vector<ConnectivityEvent>& events) {
+  std::vector<Edge> queries;
+  // Each edge's [first, last) query interval, and when the copies still
+  // present arrived
+  std::vector<std::pair<Edge, std::pair<size_t, size_t>>> lifetimes;
+  std::map<Edge, std::vector<size_t>> present;
+  for (const auto& event : events) {
+    assert(event.u >= 0 && event.v >= 0 && static_cast<size_t>(event.u) < n &&
+           static_cast<size_t>(event.v) < n);
+    const Edge edge = std::minmax(event.u, event.v);
+    switch (event.op) {
+      case ConnectivityOp::kAdd:
+        present[edge].push_back(queries.size());
+        break;
+      case ConnectivityOp::kRemove: {
+        auto it = present.find(edge);
+        assert(it != present.end() && "removing an edge that isn't there");
+        lifetimes.push_back({edge, {it->second.back(), queries.size()}});
+        it->second.pop_back();
+        if (it->second.empty()) {
+          present.erase(it);
+        }
+        break;
+      }
+      case ConnectivityOp::kQuery:
+        queries.push_back({event.u, event.v});
+        break;
+    }
+  }
+  for (const auto& [edge, arrivals] : present) {
+    for (size_t first : arrivals) {
+      lifetimes.push_back({edge, {first, queries.size()}});
+    }
+  }
+
+  const size_t q = queries.size();
+  std::vector<bool> answers(q);
+  if (q == 0) {
+    return answers;
+  }
+  // Node 1 covers queries [0, q), and node i's children are 2i and 2i + 1
+  std::vector<std::vector<Edge>> edges_at(4 * q);
+  auto cover = [&](auto& self, size_t node, size_t lo, size_t hi,
+                   size_t first, size_t last, Edge edge) -> void {
+    if (last <= lo || hi <= first) {
+      return;
+    }
+    if (first <= lo && hi <= last) {
+      edges_at[node].push_back(edge);
+      return;
+    }
+    const size_t mid = lo + (hi - lo) / 2;
+    self(self, 2 * node, lo, mid, first, last, edge);
+    self(self, 2 * node + 1, mid, hi, first, last, edge);
+  };
+  for (const auto& [edge, interval] : lifetimes) {
+    cover(cover, 1, 0, q, interval.first, interval.second, edge);
+  }
+
+  RollbackUnionFind sets(n);
+  auto walk = [&](auto& self, size_t node, size_t lo, size_t hi) -> void {
+    const size_t snapshot = sets.snapshot();
+    for (const auto& [u, v] : edges_at[node]) {
+      sets.unite(u, v);
+    }
+    if (hi - lo == 1) {
+      answers[lo] = sets.connected(queries[lo].first, queries[lo].second);
+    } else {
+      const size_t mid = lo + (hi - lo) / 2;
+      self(self, 2 * node, lo, mid);
+      self(self, 2 * node + 1, mid, hi);
+    }
+    sets.rollback(snapshot);
+  };
+  walk(walk, 1, 0, q);
+  return answers;
+}
+
+// "Are u and v connected after the first t insertions?"
+struct TimedQuery {
+  int u;
+  int v;
+  size_t time;
+};
+
+// With insertions only, nothing ever needs undoing: answer the queries in
+// order of time with a plain UnionFind, uniting edges as time catches up.
+// O((m + q) α(n)) after sorting the queries; the answers come back in the
+// order the queries were given.
+inline std::vector<bool> connected_at(size_t n,
+                                      const std::vector<Edge>& insertions,
+                                      const std::vector<TimedQuery>& queries) {
+  std::vector<size_t> order(queries.size());
+  for (size_t i = 0; i < order.size(); ++i) {
+    order[i] = i;
+  }
+  std::stable_sort(order.begin(), order.end(), [&](size_t a, size_t b) {
+    return queries[a].time < queries[b].time;
+  });
+  UnionFind sets(n);
+  std::vector<bool> answers(queries.size());
+  size_t added = 0;
+  for (size_t i : order) {
+    const TimedQuery& query = queries[i];
+    assert(query.time <= insertions.size() && "query after the last edge");
+    for (; added < query.time; ++added) {
+      sets.unite(insertions[added].first, insertions[added].second);
+    }
+    answers[i] = sets.connected(query.u, query.v);
+  }
+  return answers;
+}
+
+// O(n + m) per query, for the tests: keep the current edges, and search
+// from u at every query
+inline std::vector<bool> offline_connectivity_naive(
+    size_t n, const std::vector<ConnectivityEvent>& events) {
+  std::multiset<Edge> edges;
+  std::vector<bool> answers;
+  for (const auto& event : events) {
+    const Edge edge = std::minmax(event.u, event.v);
+    if (event.op == ConnectivityOp::kAdd) {
+      edges.insert(edge);
+    } else if (event.op == ConnectivityOp::kRemove) {
+      edges.erase(edges.find(edge));
+    } else {
+      const AdjacencyList adj = undirected_from_edges(
+          n, std::vector<Edge>(edges.begin(), edges.end()));
+      std::vector<bool> seen(n, false);
+      std::queue<int> frontier;
+      frontier.push(event.u);
+      seen[event.u] = true;
+      while (!frontier.empty()) {
+        const int x = frontier.front();
+        frontier.pop();
+        for (int y : adj[x]) {
+          if (!seen[y]) {
+            seen[y] = true;
+            frontier.push(y);
+          }
+        }
+      }
+      answers.push_back(seen[event.v]);
+    }
+  }
+  return answers;
+}
@@ -0,0 +1,90 @@
+#include <cstddef>
+#include <cstdint>
+#include <utility>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "OfflineConnectivity.h"
+#include "gtest/gtest.h"
+
+using Op = ConnectivityOp;
+
+TEST(OfflineConnectivity, Example) {
+  // 0-1-2 joined, then 1-2 cut, then 0-2 joined directly
+  const std::vector<ConnectivityEvent> events = {
+      {Op::kQuery, 0, 2}, {Op::kAdd, 0, 1},    {Op::kAdd, 2, 1},
+      {Op::kQuery, 0, 2}, {Op::kRemove, 1, 2}, {Op::kQuery, 0, 2},
+      {Op::kQuery, 1, 0}, {Op::kAdd, 0, 2},    {Op::kQuery, 1, 2},
+      {Op::kQuery, 3, 3}};
+  const std::vector<bool> expected = {false, true, false, true, true, true};
+  EXPECT_EQ(offline_connectivity(4, events), expected);
+  EXPECT_EQ(offline_connectivity_naive(4, events), expected);
+  EXPECT_TRUE(offline_connectivity(4, {{Op::kAdd, 0, 1}}).empty());
+}
+
+// Random adds, removes of present edges, and queries, against a search per
+// query. Few vertices and many parallel edges, so components keep merging
+// and splitting.
+TEST(OfflineConnectivity, MatchesSearch) {
+  Rng gen(191);
+  for (int trial = 0; trial < 20; ++trial) {
+    const size_t n = 2 + uniform_below(gen, 20);
+    std::vector<ConnectivityEvent> events;
+    std::vector<Edge> present;
+    for (int step = 0; step < 400; ++step) {
+      const int u = static_cast<int>(uniform_below(gen, n));
+      const int v = static_cast<int>(uniform_below(gen, n));
+      const uint64_t choice = uniform_below(gen, 3);
+      if (choice == 0) {
+        events.push_back({Op::kAdd, u, v});
+        present.push_back({u, v});
+      } else if (choice == 1 && !present.empty()) {
+        const size_t i = uniform_below(gen, present.size());
+        // Either way round
+        auto [a, b] = present[i];
+        if (uniform_below(gen, 2) == 0) {
+          std::swap(a, b);
+        }
+        events.push_back({Op::kRemove, a, b});
+        present.erase(present.begin() + static_cast<std::ptrdiff_t>(i));
+      } else {
+        events.push_back({Op::kQuery, u, v});
+      }
+    }
+    ASSERT_EQ(offline_connectivity(n, events),
+              offline_connectivity_naive(n, events));
+  }
+}
+
+TEST(OfflineConnectivity, ConnectedAt) {
+  Rng gen(191);
+  const size_t n = 30;
+  std::vector<Edge> insertions;
+  std::vector<ConnectivityEvent> events;
+  std::vector<TimedQuery> queries;
+  for (int step = 0; step < 60; ++step) {
+    const int u = static_cast<int>(uniform_below(gen, n));
+    const int v = static_cast<int>(uniform_below(gen, n));
+    insertions.push_back({u, v});
+    events.push_back({Op::kAdd, u, v});
+  }
+  // Queries at random times, out of order
+  for (int i = 0; i < 200; ++i) {
+    queries.push_back({static_cast<int>(uniform_below(gen, n)),
+                       static_cast<int>(uniform_below(gen, n)),
+                       uniform_below(gen, insertions.size() + 1)});
+  }
+  const auto answers = connected_at(n, insertions, queries);
+  for (size_t i = 0; i < queries.size(); ++i) {
+    std::vector<ConnectivityEvent> prefix(
+        events.begin(),
+        events.begin() + static_cast<std::ptrdiff_t>(queries[i].time));
+    prefix.push_back({Op::kQuery, queries[i].u, queries[i].v});
+    ASSERT_EQ(answers[i], offline_connectivity_naive(n, prefix)[0]);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
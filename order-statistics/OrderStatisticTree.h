@@ -0,0 +1,149 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <memory>
+#include <utility>
+
+#include "../rng/Rng.h"
+#include "Treap.h"
+
+// Order statistic tree: a balanced BST where every node also knows the size
+// of its subtree. That's enough to find the k-th smallest element, or the
+// rank of a value, along a single root-to-leaf path: at each node, the left
+// subtree's size says whether the answer is to the left, here, or to the
+// right (skipping that many elements).
+//
+// The balancing is a treap (Treap.h): each node gets a random priority, and the tree
+// is a BST by key and a max-heap by priority, which makes it shaped like a
+// BST built from random insertions. O(log n) expected depth, whatever the
+// order of the operations. Duplicates are allowed, so it's a multiset.
+template <typename T, typename Compare = std::less<T>>
+class OrderStatisticTree {
+ private:
+  struct Node {
+    T key;
+    uint32_t priority;
+    size_t size{1};
+    std::unique_ptr<Node> left;
+    std::unique_ptr<Node> right;
+
+    Node(T k, uint32_t p) : key(std::move(k)), priority(p) {}
+
+    void update() { size = 1 + size_of(left) + size_of(right); }
+  };
+
+  using Link = std::unique_ptr<Node>;
+
+  Link root_;
+  Compare less_;
+  Rng gen_;
+
+  static size_t size_of(const Link& node) { return node ? node->size : 0; }
+
+  // Splits node's tree into keys before x and the rest. With or_equal, keys
+  // equal to x go in the first part too.
+  std::pair<Link, Link> split(Link node, const T& x, bool or_equal) const {
+    return treap_split(std::move(node), [&](const T& key) {
+      return or_equal ? !less_(x, key) : less_(key, x);
+    });
+  }
+
+  static Link merge(Link a, Link b) {
+    return treap_merge(std::move(a), std::move(b));
+  }
+
+ public:
+  // The seed drives the priorities: same seed, same shapes
+  explicit OrderStatisticTree(uint64_t seed = 158, Compare less = Compare())
+      : less_(less), gen_(seed) {}
+
+  size_t size() const { return size_of(root_); }
+
+  bool empty() const { return !root_; }
+
+  void insert(T key) {
+    auto [before, after] = split(std::move(root_), key, false);
+    Link node = std::make_unique<Node>(std::move(key),
+                                     static_cast<uint32_t>(gen_() >> 32));
+    root_ = merge(merge(std::move(before), std::move(node)), std::move(after));
+  }
+
+  // Removes one copy of key. False if there was none.
+  bool erase(const T& key) {
+    auto [before, rest] = split(std::move(root_), key, false);
+    auto [equal, after] = split(std::move(rest), key, true);
+    const bool found = equal != nullptr;
+    if (found) {
+      equal = merge(std::move(equal->left), std::move(equal->right));
+    }
+    root_ = merge(merge(std::move(before), std::move(equal)), std::move(after));
+    return found;
+  }
+
+  bool contains(const T& key) const {
+    const Node* node = root_.get();
+    while (node) {
+      if (less_(key, node->key)) {
+        node = node->left.get();
+      } else if (less_(node->key, key)) {
+        node = node->right.get();
+      } else {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  // The k-th smallest element, counting from 0
+  const T& select(size_t k) const {
+    assert(k < size() && "select out of range");
+    const Node* node = root_.get();
+    while (true) {
+      const size_t left = size_of(node->left);
+      if (k < left) {
+        node = node->left.get();
+      } else if (k == left) {
+        return node->key;
+      } else {
+        k -= left + 1;
+        node = node->right.get();
+      }
+    }
+  }
+
+  // Number of elements less than x
+  size_t rank(const T& x) const {
+    size_t smaller = 0;
+    const Node* node = root_.get();
+    while (node) {
+      if (less_(node->key, x)) {
+        smaller += size_of(node->left) + 1;
+        node = node->right.get();
+      } else {
+        node = node->left.get();
+      }
+    }
+    return smaller;
+  }
+
+  // Number of elements equal to x
+  size_t count(const T& x) const {
+    size_t not_greater = 0;
+    const Node* node = root_.get();
+    while (node) {
+      if (less_(x, node->key)) {
+        node = node->left.get();
+      } else {
+        not_greater += size_of(node->left) + 1;
+        node = node->right.get();
+      }
+    }
+    return not_greater - rank(x);
+  }
+
+  // Length of the longest root-to-leaf path, to check the balancing
+  size_t height() const { return treap_height(root_); }
+};
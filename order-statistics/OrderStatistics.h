@@ -0,0 +1,86 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../fenwick-tree/FenwickTree.h"
+#include "OrderStatisticTree.h"
+
+// Online median: the median of everything added so far, at any time. With
+// an even count, the mean of the two middle elements. O(log n) per add or
+// query. The two-heap RunningMedian in running-median/ is simpler and
+// faster; the tree would also allow removing values, or any other quantile.
+template <typename T>
+class TreeMedian {
+ public:
+  void add(T x) { tree_.insert(std::move(x)); }
+
+  size_t size() const { return tree_.size(); }
+
+  double median() const {
+    assert(!tree_.empty() && "no median of nothing");
+    const size_t n = tree_.size();
+    const double upper = static_cast<double>(tree_.select(n / 2));
+    if (n % 2 == 1) {
+      return upper;
+    }
+    return (static_cast<double>(tree_.select(n / 2 - 1)) + upper) / 2;
+  }
+
+ private:
+  OrderStatisticTree<T> tree_;
+};
+
+// smaller[i] = how many of nums[i + 1], nums[i + 2], ... are less than
+// nums[i]. From right to left, each element ranks itself among those after
+// it, then joins them. O(n log n).
+template <typename T>
+std::vector<size_t> count_smaller_to_the_right(const std::vector<T>& nums) {
+  std::vector<size_t> smaller(nums.size());
+  OrderStatisticTree<T> seen;
+  for (size_t i = nums.size(); i-- > 0;) {
+    smaller[i] = seen.rank(nums[i]);
+    seen.insert(nums[i]);
+  }
+  return smaller;
+}
+
+// The same with a Fenwick tree counting how many of each value have been
+// seen, after replacing the values by their positions among the distinct
+// values. O(n log n), and less work per element, but not online: every value
+// has to be known up front.
+template <typename T>
+std::vector<size_t> count_smaller_to_the_right_fenwick(
+    const std::vector<T>& nums) {
+  std::vector<T> distinct = nums;
+  std::sort(distinct.begin(), distinct.end());
+  distinct.erase(std::unique(distinct.begin(), distinct.end()),
+                 distinct.end());
+
+  std::vector<size_t> smaller(nums.size());
+  FenwickTree<size_t> seen(distinct.size());
+  for (size_t i = nums.size(); i-- > 0;) {
+    const size_t value = static_cast<size_t>(
+        std::lower_bound(distinct.begin(), distinct.end(), nums[i]) -
+        distinct.begin());
+    smaller[i] = seen.prefix_sum(value);
+    seen.add(value, 1);
+  }
+  return smaller;
+}
+
+// O(n^2)
+template <typename T>
+std::vector<size_t> count_smaller_to_the_right_naive(
+    const std::vector<T>& nums) {
+  std::vector<size_t> smaller(nums.size(), 0);
+  for (size_t i = 0; i < nums.size(); ++i) {
+    for (size_t j = i + 1; j < nums.size(); ++j) {
+      smaller[i] += nums[j] < nums[i];
+    }
+  }
+  return smaller;
+}
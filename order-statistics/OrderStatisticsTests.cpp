@@ -0,0 +1,148 @@
+#include <algorithm>
+#include <cmath>
+#include <random>
+#include <string>
+#include <vector>
+
+#include "OrderStatisticTree.h"
+#include "OrderStatistics.h"
+#include "gtest/gtest.h"
+
+TEST(OrderStatisticTree, SelectAndRank) {
+  OrderStatisticTree<int> tree;
+  for (int x : {50, 20, 70, 20, 90, 10}) {
+    tree.insert(x);
+  }
+  EXPECT_EQ(tree.size(), 6u);
+  EXPECT_EQ(tree.select(0), 10);
+  EXPECT_EQ(tree.select(1), 20);
+  EXPECT_EQ(tree.select(2), 20);
+  EXPECT_EQ(tree.select(5), 90);
+  EXPECT_EQ(tree.rank(20), 1u);
+  EXPECT_EQ(tree.rank(21), 3u);
+  EXPECT_EQ(tree.rank(5), 0u);
+  EXPECT_EQ(tree.rank(100), 6u);
+  EXPECT_EQ(tree.count(20), 2u);
+  EXPECT_EQ(tree.count(30), 0u);
+
+  EXPECT_TRUE(tree.erase(20));
+  EXPECT_EQ(tree.count(20), 1u);
+  EXPECT_FALSE(tree.erase(30));
+  EXPECT_TRUE(tree.contains(20));
+  EXPECT_FALSE(tree.contains(30));
+  EXPECT_EQ(tree.size(), 5u);
+}
+
+TEST(OrderStatisticTree, CustomOrder) {
+  OrderStatisticTree<std::string, std::greater<std::string>> tree;
+  for (const char* word : {"pear", "apple", "fig"}) {
+    tree.insert(word);
+  }
+  EXPECT_EQ(tree.select(0), "pear");
+  EXPECT_EQ(tree.rank("apple"), 2u);
+}
+
+TEST(OrderStatisticTree, MatchesSortedVector) {
+  std::mt19937 gen(158);
+  std::uniform_int_distribution<int> value(0, 100);
+  OrderStatisticTree<int> tree;
+  std::vector<int> sorted;
+  for (int step = 0; step < 3000; ++step) {
+    const int x = value(gen);
+    if (gen() % 3 == 0) {
+      auto it = std::lower_bound(sorted.begin(), sorted.end(), x);
+      const bool present = it != sorted.end() && *it == x;
+      if (present) {
+        sorted.erase(it);
+      }
+      ASSERT_EQ(tree.erase(x), present);
+    } else {
+      sorted.insert(std::upper_bound(sorted.begin(), sorted.end(), x), x);
+      tree.insert(x);
+    }
+
+    ASSERT_EQ(tree.size(), sorted.size());
+    const size_t rank = static_cast<size_t>(
+        std::lower_bound(sorted.begin(), sorted.end(), x) - sorted.begin());
+    ASSERT_EQ(tree.rank(x), rank);
+    ASSERT_EQ(tree.count(x),
+              static_cast<size_t>(std::count(sorted.begin(), sorted.end(), x)));
+    if (!sorted.empty()) {
+      const size_t k = gen() % sorted.size();
+      ASSERT_EQ(tree.select(k), sorted[k]);
+    }
+  }
+}
+
+TEST(OrderStatisticTree, StaysBalancedOnSortedInput) {
+  OrderStatisticTree<int> tree;
+  const int n = 100000;
+  for (int i = 0; i < n; ++i) {
+    tree.insert(i);
+  }
+  // A random BST has expected height about 4.3 ln n, 50 here
+  EXPECT_LT(tree.height(), 80u);
+  for (int i = 0; i < n; i += 997) {
+    ASSERT_EQ(tree.select(i), i);
+    ASSERT_EQ(tree.rank(i), static_cast<size_t>(i));
+  }
+}
+
+TEST(OrderStatistics, TreeMedian) {
+  TreeMedian<int> median;
+  std::vector<double> medians;
+  for (int x : {5, 15, 1, 3, 8}) {
+    median.add(x);
+    medians.push_back(median.median());
+  }
+  EXPECT_EQ(medians, (std::vector<double>{5, 10, 5, 4, 5}));
+
+  std::mt19937 gen(159);
+  std::uniform_int_distribution<int> value(-1000, 1000);
+  TreeMedian<int> random;
+  std::vector<int> values;
+  for (int step = 0; step < 500; ++step) {
+    values.push_back(value(gen));
+    random.add(values.back());
+    auto sorted = values;
+    std::sort(sorted.begin(), sorted.end());
+    const size_t n = sorted.size();
+    const double expected =
+        n % 2 == 1 ? sorted[n / 2] : (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0;
+    ASSERT_EQ(random.median(), expected);
+  }
+}
+
+TEST(OrderStatistics, CountSmallerToTheRight) {
+  EXPECT_EQ(count_smaller_to_the_right(std::vector<int>{5, 2, 6, 1}),
+            (std::vector<size_t>{2, 1, 1, 0}));
+  EXPECT_EQ(count_smaller_to_the_right(std::vector<int>{-1, -1}),
+            (std::vector<size_t>{0, 0}));
+  EXPECT_TRUE(count_smaller_to_the_right(std::vector<int>{}).empty());
+
+  std::mt19937 gen(160);
+  for (int trial = 0; trial < 200; ++trial) {
+    std::vector<int> nums(gen() % 60);
+    std::uniform_int_distribution<int> value(-20, 20);
+    for (auto& x : nums) {
+      x = value(gen);
+    }
+    const auto expected = count_smaller_to_the_right_naive(nums);
+    ASSERT_EQ(count_smaller_to_the_right(nums), expected);
+    ASSERT_EQ(count_smaller_to_the_right_fenwick(nums), expected);
+  }
+
+  // The two fast versions on something bigger
+  std::vector<int> big(20000);
+  std::uniform_int_distribution<int> value(0, 1'000'000);
+  for (auto& x : big) {
+    x = value(gen);
+  }
+  EXPECT_EQ(count_smaller_to_the_right(big),
+            count_smaller_to_the_right_fenwick(big));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,63 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <memory>
+#include <utility>
+
+// The split and merge that OrderStatisticTree and IntervalTree are built on.
+// A treap keeps its nodes a BST by key and a max-heap by random priority;
+// split and merge are the only operations that restructure it, and insert
+// and erase are a few of each. Each tree keeps its own augmentation (a
+// subtree size, a largest high end) in its Node, which has to provide:
+//
+// - priority, compared with >;
+// - left and right, as std::unique_ptr<Node>;
+// - update(), which recomputes the augmentation from the node's children,
+//   and is called bottom-up on every node whose children changed.
+
+// Splits node's tree into the nodes for which goes_first(key) holds and the
+// rest. goes_first must hold for a prefix of the keys in order.
+template <typename Node, typename GoesFirst>
+std::pair<std::unique_ptr<Node>, std::unique_ptr<Node>> treap_split(
+    std::unique_ptr<Node> node, const GoesFirst& goes_first) {
+  if (!node) {
+    return {nullptr, nullptr};
+  }
+  if (goes_first(node->key)) {
+    auto [middle, after] = treap_split(std::move(node->right), goes_first);
+    node->right = std::move(middle);
+    node->update();
+    return {std::move(node), std::move(after)};
+  }
+  auto [before, middle] = treap_split(std::move(node->left), goes_first);
+  node->left = std::move(middle);
+  node->update();
+  return {std::move(before), std::move(node)};
+}
+
+// Joins two trees, every key of a no greater than any key of b. The root
+// with the higher priority stays on top.
+template <typename Node>
+std::unique_ptr<Node> treap_merge(std::unique_ptr<Node> a,
+                                  std::unique_ptr<Node> b) {
+  if (!a || !b) {
+    return a ? std::move(a) : std::move(b);
+  }
+  if (a->priority > b->priority) {
+    a->right = treap_merge(std::move(a->right), std::move(b));
+    a->update();
+    return a;
+  }
+  b->left = treap_merge(std::move(a), std::move(b->left));
+  b->update();
+  return b;
+}
+
+// Length of the longest root-to-leaf path, to check the balancing
+template <typename Node>
+size_t treap_height(const std::unique_ptr<Node>& node) {
+  return node ? 1 + std::max(treap_height(node->left),
+                             treap_height(node->right))
+              : 0;
+}
@@ -0,0 +1,128 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+#include "../graph/Graph.h"
+
+// PageRank: the share of time a random surfer spends on each page. At
+// every step the surfer follows a random link out of the current page with
+// probability d, the damping factor, and jumps to a page chosen uniformly
+// at random otherwise, or always from a page without links. The ranks are
+// the stationary distribution of that Markov chain:
+//
+//   rank[v] = (1 - d) / n + d (sum over u -> v of rank[u] / out(u)
+//                              + sum over pages u without links of rank[u] / n)
+//
+// Power iteration: start uniform, and apply the right-hand side until the
+// ranks stop moving. The jump makes the chain mix, so each step shrinks
+// the distance to the answer by a factor d, whatever the graph, and
+// O(log(tolerance) / log d) steps of O(V + E) each are enough. The graph
+// stays sparse the whole time: a step only follows the edges.
+
+struct PageRankOptions {
+  double damping = 0.85;
+  double tolerance = 1e-10;  // on the L1 change in one step
+  size_t max_iterations = 1000;
+};
+
+struct PageRankResult {
+  std::vector<double> rank;  // adds up to 1
+  size_t iterations{0};
+  bool converged{false};  // the last step changed less than the tolerance
+};
+
+// graph[u] holds the pages u links to; parallel edges are links that count
+// twice
+inline PageRankResult pagerank(const AdjacencyList& graph,
+                               PageRankOptions options = {}) {
+  assert(options.damping >= 0 && options.damping <= 1 &&
+         "damping is a probability");
+  const size_t n = graph.size();
+  PageRankResult result;
+  if (n == 0) {
+    result.converged = true;
+    return result;
+  }
+  const double d = options.damping;
+  result.rank.assign(n, 1.0 / static_cast<double>(n));
+  std::vector<double> next(n);
+  while (result.iterations < options.max_iterations) {
+    double dangling = 0;
+    for (size_t u = 0; u < n; ++u) {
+      if (graph[u].empty()) {
+        dangling += result.rank[u];
+      }
+    }
+    const double base = ((1 - d) + d * dangling) / static_cast<double>(n);
+    std::fill(next.begin(), next.end(), base);
+    for (size_t u = 0; u < n; ++u) {
+      const double share =
+          d * result.rank[u] / static_cast<double>(graph[u].size());
+      for (int v : graph[u]) {
+        next[v] += share;
+      }
+    }
+    double change = 0;
+    for (size_t v = 0; v < n; ++v) {
+      change += std::abs(next[v] - result.rank[v]);
+    }
+    result.rank.swap(next);
+    ++result.iterations;
+    if (change < options.tolerance) {
+      result.converged = true;
+      break;
+    }
+  }
+  return result;
+}
+
+// O(n^3): the same equations solved exactly, by Gaussian elimination with
+// partial pivoting. With d < 1 the system is never singular.
+inline std::vector<double> pagerank_naive(const AdjacencyList& graph,
+                                          double damping = 0.85) {
+  const size_t n = graph.size();
+  const double size = static_cast<double>(n);
+  // (I - d M) rank = (1 - d) / n, as an augmented matrix
+  std::vector<std::vector<double>> a(n, std::vector<double>(n + 1, 0));
+  for (size_t v = 0; v < n; ++v) {
+    a[v][v] = 1;
+    a[v][n] = (1 - damping) / size;
+  }
+  for (size_t u = 0; u < n; ++u) {
+    if (graph[u].empty()) {
+      for (size_t v = 0; v < n; ++v) {
+        a[v][u] -= damping / size;
+      }
+    }
+    for (int v : graph[u]) {
+      a[v][u] -= damping / static_cast<double>(graph[u].size());
+    }
+  }
+  for (size_t col = 0; col < n; ++col) {
+    size_t pivot = col;
+    for (size_t row = col + 1; row < n; ++row) {
+      if (std::abs(a[row][col]) > std::abs(a[pivot][col])) {
+        pivot = row;
+      }
+    }
+    std::swap(a[col], a[pivot]);
+    for (size_t row = 0; row < n; ++row) {
+      if (row != col) {
+        const double factor = a[row][col] / a[col][col];
+        for (size_t k = col; k <= n; ++k) {
+          a[row][k] -= factor * a[col][k];
+        }
+      }
+    }
+  }
+  std::vector<double> rank(n);
+  for (size_t v = 0; v < n; ++v) {
+    rank[v] = a[v][n] / a[v][v];
+  }
+  return rank;
+}
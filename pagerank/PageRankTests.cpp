@@ -0,0 +1,94 @@
+#include <cmath>
+#include <cstddef>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../graph/Graph.h"
+#include "../rng/Rng.h"
+#include "PageRank.h"
+#include "gtest/gtest.h"
+
+void expect_ranks_near(const std::vector<double>& actual,
+                       const std::vector<double>& expected, double error) {
+  ASSERT_EQ(actual.size(), expected.size());
+  for (size_t v = 0; v < actual.size(); ++v) {
+    EXPECT_NEAR(actual[v], expected[v], error) << v;
+  }
+}
+
+TEST(PageRank, KnownDistributions) {
+  // A directed cycle looks the same from every page
+  const AdjacencyList cycle =
+      directed_from_edges(4, {{0, 1}, {1, 2}, {2, 3}, {3, 0}});
+  const PageRankResult around = pagerank(cycle);
+  EXPECT_TRUE(around.converged);
+  expect_ranks_near(around.rank, {0.25, 0.25, 0.25, 0.25}, 1e-12);
+
+  // Without damping, a walk on a connected undirected graph that isn't
+  // bipartite spends time in proportion to degree: a triangle 0 1 2 with
+  // a tail 2 3 has degrees 2, 2, 3 and 1 of 8
+  const AdjacencyList kite =
+      undirected_from_edges(4, {{0, 1}, {1, 2}, {0, 2}, {2, 3}});
+  const PageRankResult walk = pagerank(kite, {1.0, 1e-13, 10000});
+  EXPECT_TRUE(walk.converged);
+  expect_ranks_near(walk.rank, {0.25, 0.25, 0.375, 0.125}, 1e-9);
+
+  // No links anywhere: every step is a jump
+  const PageRankResult isolated = pagerank(AdjacencyList(5));
+  expect_ranks_near(isolated.rank, std::vector<double>(5, 0.2), 1e-12);
+  EXPECT_TRUE(pagerank(AdjacencyList()).rank.empty());
+
+  // Two pages linking to each other and a third linking in, by hand:
+  // r2 = (1 - d) / 3, r0 = r2 + d (r1 + r2), r1 = r2 + d r0
+  const double d = 0.85;
+  const double r2 = (1 - d) / 3;
+  const double r0 = (r2 + d * r2 + d * r2) / (1 - d * d);
+  const double r1 = r2 + d * r0;
+  const AdjacencyList in = directed_from_edges(3, {{0, 1}, {1, 0}, {2, 0}});
+  expect_ranks_near(pagerank(in).rank, {r0, r1, r2}, 1e-9);
+}
+
+// Random graphs, with pages that have no links, against solving the
+// equations exactly
+TEST(PageRank, MatchesLinearSystem) {
+  Rng gen(227);
+  for (int trial = 0; trial < 100; ++trial) {
+    const size_t n = 1 + uniform_below(gen, 12);
+    const std::vector<Edge> edges =
+        random_multigraph(n, uniform_below(gen, 3 * n), gen);
+    const AdjacencyList graph = directed_from_edges(n, edges);
+    const double damping = 0.5 + 0.45 * uniform_unit(gen);
+    const PageRankResult result = pagerank(graph, {damping, 1e-12, 10000});
+    ASSERT_TRUE(result.converged);
+    double total = 0;
+    for (double r : result.rank) {
+      total += r;
+    }
+    EXPECT_NEAR(total, 1.0, 1e-9);
+    expect_ranks_near(result.rank, pagerank_naive(graph, damping), 1e-9);
+  }
+}
+
+// A looser tolerance stops sooner, and running out of iterations says so
+TEST(PageRank, Convergence) {
+  const AdjacencyList graph = directed_from_edges(
+      5, {{0, 1}, {1, 2}, {2, 0}, {2, 3}, {3, 4}, {4, 0}, {1, 4}});
+  const PageRankResult tight = pagerank(graph, {0.85, 1e-12, 1000});
+  const PageRankResult loose = pagerank(graph, {0.85, 1e-4, 1000});
+  EXPECT_TRUE(tight.converged);
+  EXPECT_TRUE(loose.converged);
+  EXPECT_LT(loose.iterations, tight.iterations);
+  expect_ranks_near(loose.rank, tight.rank, 1e-4);
+  // Each step shrinks the error by at least a factor d
+  EXPECT_LE(tight.iterations,
+            static_cast<size_t>(std::log(1e-12) / std::log(0.85)) + 2);
+
+  const PageRankResult cut_short = pagerank(graph, {0.85, 1e-12, 3});
+  EXPECT_FALSE(cut_short.converged);
+  EXPECT_EQ(cut_short.iterations, 3u);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
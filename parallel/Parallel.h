@@ -0,0 +1,195 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <thread>
+#include <vector>
+
+#include "../sequences/Sequences.h"
+
+// Embarrassingly parallel etudes: problems that split into independent
+// pieces, each solved on its own thread, then combined. Two primitives do
+// all the threading, and every etude is a sequential version next to a
+// parallel one that must give exactly the same answer.
+//
+// Work is dealt out round-robin: thread t gets items t, t + threads, and
+// so on. That keeps the threads balanced even when the cost of an item
+// grows with its index, as it does in the quadratic maximum subsequence.
+// The body must not throw: an exception escaping a std::thread terminates.
+
+inline unsigned default_threads() {
+  return std::max(1u, std::thread::hardware_concurrency());
+}
+
+// Calls body(i) for every i in [0, n), on up to `threads` threads (one of
+// them the caller's). Bodies for different i must not write to the same
+// memory.
+template <typename Body>
+void parallel_for(size_t n, Body body, unsigned threads = default_threads()) {
+  assert(threads > 0);
+  auto run = [&](unsigned t) {
+    for (size_t i = t; i < n; i += threads) {
+      body(i);
+    }
+  };
+  std::vector<std::thread> workers;
+  for (unsigned t = 1; t < threads && t < n; ++t) {
+    workers.emplace_back(run, t);
+  }
+  run(0);
+  for (auto& worker : workers) {
+    worker.join();
+  }
+}
+
+// combine(identity, map(0), map(1), ..., map(n - 1)), with each thread
+// folding its own items first. combine must be associative and commutative,
+// since the items aren't combined in order.
+template <typename T, typename Map, typename Combine>
+T parallel_reduce(size_t n, T identity, Map map, Combine combine,
+                  unsigned threads = default_threads()) {
+  assert(threads > 0);
+  if (n == 0) {
+    return identity;
+  }
+  threads = static_cast<unsigned>(std::min<size_t>(threads, n));
+  std::vector<T> partial(threads, identity);
+  parallel_for(
+      threads,
+      [&](size_t t) {
+        for (size_t i = t; i < n; i += threads) {
+          partial[t] = combine(partial[t], map(i));
+        }
+      },
+      threads);
+  T result = identity;
+  for (const T& p : partial) {
+    result = combine(result, p);
+  }
+  return result;
+}
+
+// Divide and conquer runs the two halves at once: left on a new thread,
+// right on this one. Only the top few levels of the recursion should fork,
+// enough for every thread to get a subproblem, so the recursive algorithms
+// pass down how many more levels may fork.
+template <typename Left, typename Right>
+void fork_join(bool fork, Left left, Right right) {
+  if (!fork) {
+    left();
+    right();
+    return;
+  }
+  std::thread worker(left);
+  right();
+  worker.join();
+}
+
+// Forking for this many levels makes at least `threads` leaves
+inline int fork_levels(unsigned threads) {
+  int levels = 0;
+  while ((1u << levels) < threads) {
+    ++levels;
+  }
+  return levels;
+}
+
+// For the divide and conquer algorithms: subproblems of at most `cutoff`
+// elements run sequentially, since below some size starting a thread
+// costs more than it saves
+struct ParallelOptions {
+  size_t cutoff = 1 << 12;
+  unsigned threads = default_threads();
+};
+
+// Maximum consecutive subsequence, the O(n^2) way (manber/ has the linear
+// one): for each start, the best sum of a run starting there. The starts
+// are independent, so they parallelize perfectly. 0 for the empty run.
+
+// The best sum of a run starting at nums[i]
+inline int64_t best_run_from(const std::vector<int>& nums, size_t i) {
+  int64_t best = 0;
+  int64_t sum = 0;
+  for (size_t j = i; j < nums.size(); ++j) {
+    sum += nums[j];
+    best = std::max(best, sum);
+  }
+  return best;
+}
+
+inline int64_t max_subsequence_naive(const std::vector<int>& nums) {
+  int64_t best = 0;
+  for (size_t i = 0; i < nums.size(); ++i) {
+    best = std::max(best, best_run_from(nums, i));
+  }
+  return best;
+}
+
+inline int64_t max_subsequence_parallel(const std::vector<int>& nums,
+                                        unsigned threads = default_threads()) {
+  return parallel_reduce(
+      nums.size(), int64_t{0},
+      [&](size_t i) { return best_run_from(nums, i); },
+      [](int64_t a, int64_t b) { return std::max(a, b); }, threads);
+}
+
+// Matrix multiplication, one row of the product per item. Integer entries,
+// so the two versions agree exactly; they wrap around on overflow.
+
+using Matrix = std::vector<std::vector<int64_t>>;
+
+// Row i of a * b, with the loops ordered so that both matrices are read
+// along rows
+inline void multiply_row(const Matrix& a, const Matrix& b, size_t i,
+                         std::vector<int64_t>& row) {
+  row.assign(b.empty() ? 0 : b[0].size(), 0);
+  for (size_t k = 0; k < b.size(); ++k) {
+    for (size_t j = 0; j < row.size(); ++j) {
+      row[j] += a[i][k] * b[k][j];
+    }
+  }
+}
+
+inline Matrix multiply(const Matrix& a, const Matrix& b) {
+  Matrix c(a.size());
+  for (size_t i = 0; i < a.size(); ++i) {
+    assert(a[i].size() == b.size() && "inner dimensions must match");
+    multiply_row(a, b, i, c[i]);
+  }
+  return c;
+}
+
+inline Matrix multiply_parallel(const Matrix& a, const Matrix& b,
+                                unsigned threads = default_threads()) {
+  Matrix c(a.size());
+  parallel_for(
+      a.size(), [&](size_t i) { multiply_row(a, b, i, c[i]); }, threads);
+  return c;
+}
+
+// Evaluating one polynomial at many points, by Horner's rule at each.
+// Coefficients go highest degree first, as in sequences/.
+
+inline std::vector<double> evaluate_at(const std::vector<double>& coefficients,
+                                       const std::vector<double>& points) {
+  std::vector<double> values(points.size());
+  for (size_t i = 0; i < points.size(); ++i) {
+    values[i] = evaluate_polynomial(coefficients, points[i]);
+  }
+  return values;
+}
+
+inline std::vector<double> evaluate_at_parallel(
+    const std::vector<double>& coefficients, const std::vector<double>& points,
+    unsigned threads = default_threads()) {
+  std::vector<double> values(points.size());
+  parallel_for(
+      points.size(),
+      [&](size_t i) {
+        values[i] = evaluate_polynomial(coefficients, points[i]);
+      },
+      threads);
+  return values;
+}
@@ -0,0 +1,69 @@
+#include <cstdint>
+#include <random>
+#include <vector>
+
+#include <benchmark/benchmark.h>
+
+#include "Parallel.h"
+
+// Each benchmark takes the thread count as its argument, with 1 as the
+// sequential baseline; the speedup is the ratio of the times. Expect it to
+// level off at the number of cores.
+
+static std::vector<int> random_nums(size_t n) {
+  std::mt19937 gen(166);
+  std::uniform_int_distribution<int> value(-100, 100);
+  std::vector<int> nums(n);
+  for (auto& x : nums) {
+    x = value(gen);
+  }
+  return nums;
+}
+
+static Matrix random_matrix(size_t n) {
+  std::mt19937 gen(167);
+  std::uniform_int_distribution<int64_t> entry(-1000, 1000);
+  Matrix m(n, std::vector<int64_t>(n));
+  for (auto& row : m) {
+    for (auto& x : row) {
+      x = entry(gen);
+    }
+  }
+  return m;
+}
+
+static void BM_MaxSubsequence(benchmark::State& state) {
+  const auto nums = random_nums(20000);
+  const auto threads = static_cast<unsigned>(state.range(0));
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(max_subsequence_parallel(nums, threads));
+  }
+}
+
+static void BM_Multiply(benchmark::State& state) {
+  const Matrix a = random_matrix(300);
+  const Matrix b = random_matrix(300);
+  const auto threads = static_cast<unsigned>(state.range(0));
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(multiply_parallel(a, b, threads));
+  }
+}
+
+static void BM_EvaluateAt(benchmark::State& state) {
+  const std::vector<double> coefficients(200, 0.5);
+  std::vector<double> points(100000);
+  for (size_t i = 0; i < points.size(); ++i) {
+    points[i] = static_cast<double>(i) / points.size();
+  }
+  const auto threads = static_cast<unsigned>(state.range(0));
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(
+        evaluate_at_parallel(coefficients, points, threads));
+  }
+}
+
+BENCHMARK(BM_MaxSubsequence)->RangeMultiplier(2)->Range(1, 16)->UseRealTime();
+BENCHMARK(BM_Multiply)->RangeMultiplier(2)->Range(1, 16)->UseRealTime();
+BENCHMARK(BM_EvaluateAt)->RangeMultiplier(2)->Range(1, 16)->UseRealTime();
+
+BENCHMARK_MAIN();
@@ -0,0 +1,57 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <iterator>
+#include <vector>
+
+#include "Parallel.h"
+
+// Merge sort splits the work evenly by construction: the two halves sort
+// independently, so they can sort at the same time, and only the merges
+// made on the way back up wait for both. Stable, O(n log n) work and O(n)
+// extra memory. The merges themselves are sequential, so the top merge
+// alone takes O(n) and caps the speedup at O(log n).
+
+// Sorts [first, last), using buffer, which starts at the same offset in a
+// scratch vector of the same size
+template <typename It, typename Compare>
+void merge_sort_range(It first, It last, It buffer, Compare less,
+                      size_t cutoff, int fork_levels) {
+  const size_t n = static_cast<size_t>(last - first);
+  if (n <= std::max<size_t>(cutoff, 1)) {
+    std::stable_sort(first, last, less);
+    return;
+  }
+  const It mid = first + n / 2;
+  fork_join(
+      fork_levels > 0,
+      [&] {
+        merge_sort_range(first, mid, buffer, less, cutoff, fork_levels - 1);
+      },
+      [&] {
+        merge_sort_range(mid, last, buffer + n / 2, less, cutoff,
+                         fork_levels - 1);
+      });
+  std::merge(std::make_move_iterator(first), std::make_move_iterator(mid),
+             std::make_move_iterator(mid), std::make_move_iterator(last),
+             buffer, less);
+  std::move(buffer, buffer + n, first);
+}
+
+template <typename T, typename Compare = std::less<T>>
+void merge_sort(std::vector<T>& values, Compare less = Compare()) {
+  std::vector<T> buffer(values.size());
+  merge_sort_range(values.begin(), values.end(), buffer.begin(), less, 1, 0);
+}
+
+template <typename T, typename Compare = std::less<T>>
+void merge_sort_parallel(std::vector<T>& values, ParallelOptions options = {},
+                         Compare less = Compare()) {
+  assert(options.threads > 0);
+  std::vector<T> buffer(values.size());
+  merge_sort_range(values.begin(), values.end(), buffer.begin(), less,
+                   options.cutoff, fork_levels(options.threads));
+}
@@ -0,0 +1,121 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <utility>
+#include <vector>
+
+#include "Parallel.h"
+
+// Prefix sums look inherently sequential, each one needing the one before,
+// but any associative operation can be regrouped. Blelloch's scan does it
+// in two passes over a balanced tree of ranges:
+//
+// - Up: each range's total is its left half's total plus its right half's,
+//   and the halves can be added up at the same time.
+// - Down: a range starts from the total of everything before it. Its left
+//   half starts from the same value, and its right half from that plus the
+//   left half's total, which the up pass saved; again, the halves proceed
+//   at the same time.
+//
+// O(n) work (about twice the sequential scan's) in O(log n) levels, so
+// unlike merge sort, nothing forces the threads to wait on a long serial
+// step. Ranges of at most the cutoff scan sequentially.
+//
+// The scan is exclusive: out[i] combines values[0], ..., values[i - 1],
+// so out[0] is the identity.
+
+template <typename T, typename Op = std::plus<T>>
+std::vector<T> exclusive_scan(const std::vector<T>& values, T identity,
+                              Op op = Op()) {
+  std::vector<T> out(values.size());
+  T sum = identity;
+  for (size_t i = 0; i < values.size(); ++i) {
+    out[i] = sum;
+    sum = op(sum, values[i]);
+  }
+  return out;
+}
+
+template <typename T, typename Op>
+class BlellochScan {
+ public:
+  BlellochScan(const std::vector<T>& values, T identity, Op op,
+               ParallelOptions options)
+      : values_(values),
+        identity_(std::move(identity)),
+        op_(op),
+        cutoff_(std::max<size_t>(options.cutoff, 1)),
+        totals_(tree_size(values.size(), cutoff_), identity_),
+        out_(values.size()) {
+    assert(options.threads > 0);
+    const int levels = fork_levels(options.threads);
+    up(0, 0, values_.size(), levels);
+    down(0, 0, values_.size(), identity_, levels);
+  }
+
+  std::vector<T> result() && { return std::move(out_); }
+
+ private:
+  // Halving, the larger half has ceil(n / 2) values, and the tree has
+  // 2^levels leaves at most
+  static size_t tree_size(size_t n, size_t cutoff) {
+    size_t leaves = 1;
+    for (; n > cutoff; n = (n + 1) / 2) {
+      leaves *= 2;
+    }
+    return 2 * leaves;
+  }
+
+  // totals_[node] = the total of [begin, end)
+  void up(size_t node, size_t begin, size_t end, int fork_levels) {
+    if (end - begin <= cutoff_) {
+      T sum = identity_;
+      for (size_t i = begin; i < end; ++i) {
+        sum = op_(sum, values_[i]);
+      }
+      totals_[node] = sum;
+      return;
+    }
+    const size_t mid = begin + (end - begin) / 2;
+    fork_join(
+        fork_levels > 0, [&] { up(2 * node + 1, begin, mid, fork_levels - 1); },
+        [&] { up(2 * node + 2, mid, end, fork_levels - 1); });
+    totals_[node] = op_(totals_[2 * node + 1], totals_[2 * node + 2]);
+  }
+
+  // Fills out_[begin, end), where `before` is the total of [0, begin)
+  void down(size_t node, size_t begin, size_t end, T before,
+            int fork_levels) {
+    if (end - begin <= cutoff_) {
+      for (size_t i = begin; i < end; ++i) {
+        out_[i] = before;
+        before = op_(before, values_[i]);
+      }
+      return;
+    }
+    const size_t mid = begin + (end - begin) / 2;
+    const T middle = op_(before, totals_[2 * node + 1]);
+    fork_join(
+        fork_levels > 0,
+        [&] { down(2 * node + 1, begin, mid, before, fork_levels - 1); },
+        [&] { down(2 * node + 2, mid, end, middle, fork_levels - 1); });
+  }
+
+  const std::vector<T>& values_;
+  T identity_;
+  Op op_;
+  size_t cutoff_;
+  std::vector<T> totals_;  // A node's children are 2 node + 1 and 2 node + 2
+  std::vector<T> out_;
+};
+
+template <typename T, typename Op = std::plus<T>>
+std::vector<T> exclusive_scan_parallel(const std::vector<T>& values,
+                                       T identity, Op op = Op(),
+                                       ParallelOptions options = {}) {
+  return BlellochScan<T, Op>(values, std::move(identity), op, options)
+      .result();
+}
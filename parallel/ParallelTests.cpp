@@ -0,0 +1,196 @@
+#include <algorithm>
+#include <atomic>
+#include <cstddef>
+#include <cstdint>
+#include <random>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "Parallel.h"
+#include "ParallelMergeSort.h"
+#include "ParallelScan.h"
+#include "gtest/gtest.h"
+
+// More threads than this sandbox or any laptop has cores, to shake out
+// problems with the splitting rather than the hardware
+const std::vector<unsigned> kThreadCounts = {1, 2, 3, 8, 64};
+
+TEST(Parallel, ForVisitsEachIndexOnce) {
+  for (unsigned threads : kThreadCounts) {
+    for (size_t n : {0, 1, 5, 1000}) {
+      std::vector<std::atomic<int>> visits(n);
+      parallel_for(n, [&](size_t i) { ++visits[i]; }, threads);
+      for (size_t i = 0; i < n; ++i) {
+        ASSERT_EQ(visits[i], 1) << threads << " threads, n = " << n;
+      }
+    }
+  }
+}
+
+TEST(Parallel, Reduce) {
+  for (unsigned threads : kThreadCounts) {
+    EXPECT_EQ(parallel_reduce(
+                  1000, int64_t{0}, [](size_t i) { return int64_t(i); },
+                  [](int64_t a, int64_t b) { return a + b; }, threads),
+              999 * 1000 / 2);
+    EXPECT_EQ(parallel_reduce(
+                  0, 7, [](size_t) { return 1; },
+                  [](int a, int b) { return a + b; }, threads),
+              7);
+  }
+}
+
+TEST(Parallel, MaxSubsequenceMatchesSequential) {
+  EXPECT_EQ(max_subsequence_parallel({-2, 1, -3, 4, -1, 2, 1, -5, 4}), 6);
+  EXPECT_EQ(max_subsequence_parallel({}), 0);
+  EXPECT_EQ(max_subsequence_parallel({-1, -2}), 0);
+
+  std::mt19937 gen(166);
+  std::uniform_int_distribution<int> value(-100, 100);
+  for (int trial = 0; trial < 50; ++trial) {
+    std::vector<int> nums(gen() % 300);
+    for (auto& x : nums) {
+      x = value(gen);
+    }
+    const int64_t expected = max_subsequence_naive(nums);
+    for (unsigned threads : kThreadCounts) {
+      ASSERT_EQ(max_subsequence_parallel(nums, threads), expected);
+    }
+  }
+}
+
+TEST(Parallel, MultiplyMatchesSequential) {
+  const Matrix a = {{1, 2, 3}, {4, 5, 6}};
+  const Matrix b = {{7, 8}, {9, 10}, {11, 12}};
+  EXPECT_EQ(multiply_parallel(a, b), (Matrix{{58, 64}, {139, 154}}));
+
+  std::mt19937 gen(167);
+  std::uniform_int_distribution<int64_t> entry(-1000, 1000);
+  auto random_matrix = [&](size_t rows, size_t cols) {
+    Matrix m(rows, std::vector<int64_t>(cols));
+    for (auto& row : m) {
+      for (auto& x : row) {
+        x = entry(gen);
+      }
+    }
+    return m;
+  };
+  for (int trial = 0; trial < 20; ++trial) {
+    const size_t n = gen() % 40 + 1;
+    const size_t m = gen() % 40 + 1;
+    const size_t p = gen() % 40 + 1;
+    const Matrix x = random_matrix(n, m);
+    const Matrix y = random_matrix(m, p);
+    const Matrix expected = multiply(x, y);
+    for (unsigned threads : kThreadCounts) {
+      ASSERT_EQ(multiply_parallel(x, y, threads), expected);
+    }
+  }
+}
+
+TEST(Parallel, EvaluateMatchesSequential) {
+  // 2x^3 + 3x^2 + 4x + 5
+  EXPECT_EQ(evaluate_at_parallel({2, 3, 4, 5}, {0, 1, -1, 2}),
+            (std::vector<double>{5, 14, 2, 41}));
+
+  std::mt19937 gen(168);
+  std::uniform_real_distribution<double> real(-2, 2);
+  std::vector<double> coefficients(50);
+  for (auto& c : coefficients) {
+    c = real(gen);
+  }
+  std::vector<double> points(10000);
+  for (auto& x : points) {
+    x = real(gen);
+  }
+  // Each point is evaluated the same way, so exactly the same doubles
+  const auto expected = evaluate_at(coefficients, points);
+  for (unsigned threads : kThreadCounts) {
+    ASSERT_EQ(evaluate_at_parallel(coefficients, points, threads), expected);
+  }
+}
+
+TEST(Parallel, MergeSortMatchesSerial) {
+  std::vector<int> values = {5, 2, 9, 1, 5, 6};
+  merge_sort_parallel(values, {.cutoff = 1, .threads = 4});
+  EXPECT_EQ(values, (std::vector<int>{1, 2, 5, 5, 6, 9}));
+
+  std::mt19937 gen(167);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<int> values(gen() % 5000);
+    for (auto& x : values) {
+      x = static_cast<int>(gen() % 1000);
+    }
+    auto expected = values;
+    std::sort(expected.begin(), expected.end());
+    auto serial = values;
+    merge_sort(serial);
+    ASSERT_EQ(serial, expected);
+
+    for (unsigned threads : kThreadCounts) {
+      for (size_t cutoff : {1, 16, 1000, 10000}) {
+        auto sorted = values;
+        merge_sort_parallel(sorted, {cutoff, threads});
+        ASSERT_EQ(sorted, expected) << threads << " threads, cutoff " << cutoff;
+      }
+    }
+  }
+}
+
+TEST(Parallel, MergeSortIsStable) {
+  // Sorting by the key alone keeps equal keys in their original order
+  std::mt19937 gen(168);
+  std::vector<std::pair<int, int>> values(3000);
+  for (size_t i = 0; i < values.size(); ++i) {
+    values[i] = {static_cast<int>(gen() % 20), static_cast<int>(i)};
+  }
+  auto expected = values;
+  std::sort(expected.begin(), expected.end());
+
+  auto by_key = [](const auto& a, const auto& b) { return a.first < b.first; };
+  merge_sort_parallel(values, {.cutoff = 64, .threads = 8}, by_key);
+  EXPECT_EQ(values, expected);
+}
+
+TEST(Parallel, ScanMatchesSerial) {
+  EXPECT_EQ(exclusive_scan_parallel(std::vector<int>{3, 1, 4, 1, 5}, 0),
+            (std::vector<int>{0, 3, 4, 8, 9}));
+  EXPECT_TRUE(exclusive_scan_parallel(std::vector<int>{}, 0).empty());
+
+  std::mt19937 gen(169);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<int64_t> values(gen() % 5000);
+    for (auto& x : values) {
+      x = static_cast<int64_t>(gen() % 2001) - 1000;
+    }
+    const auto expected = exclusive_scan(values, int64_t{0});
+    for (unsigned threads : kThreadCounts) {
+      for (size_t cutoff : {1, 7, 1000, 10000}) {
+        ASSERT_EQ(exclusive_scan_parallel(values, int64_t{0}, std::plus<>(),
+                                          {cutoff, threads}),
+                  expected)
+            << threads << " threads, cutoff " << cutoff;
+      }
+    }
+  }
+}
+
+TEST(Parallel, ScanWithOtherOperations) {
+  // Associative is enough, even without commutativity: concatenation
+  std::vector<std::string> words = {"a", "b", "c", "d", "e"};
+  EXPECT_EQ(exclusive_scan_parallel(words, std::string(), std::plus<>(),
+                                    {.cutoff = 1, .threads = 4}),
+            (std::vector<std::string>{"", "a", "ab", "abc", "abcd"}));
+
+  // Running maximum
+  std::vector<int> values = {2, 7, 1, 8, 2, 8, 1, 8};
+  auto max = [](int a, int b) { return std::max(a, b); };
+  EXPECT_EQ(exclusive_scan_parallel(values, 0, max, {2, 3}),
+            exclusive_scan(values, 0, max));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
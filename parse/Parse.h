@@ -0,0 +1,233 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <istream>
+#include <memory>
+#include <optional>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <type_traits>
+#include <vector>
+
+#include "../binary-tree/Serialize.h"
+#include "../binary-tree/TreeNode.h"
+#include "../graph/Graph.h"
+#include "../numio/NumIo.h"
+
+// Reading the etudes' inputs from plain text, for the command line tool and
+// for tests that keep their cases in files. Three formats:
+//
+// - values: whitespace-separated, e.g. "3 1 4 1 5";
+// - trees: LeetCode's level-order arrays, e.g. "[1,2,3,null,null,4,5]";
+// - graphs: the vertex count on the first line, then one edge per line,
+//   "u v" or, for weighted graphs, "u v weight".
+//
+// '#' starts a comment that runs to the end of the line, in values and
+// graphs. Errors throw ParseError, which says where the input went wrong.
+
+class ParseError : public std::invalid_argument {
+ public:
+  using std::invalid_argument::invalid_argument;
+};
+
+// Reads all of text as a T, or fails. Integers go through numio's parser,
+// since a stream happily reads "-1" into an unsigned type by wrapping it
+// around.
+template <typename T>
+bool parse_one(const std::string& text, T& value) {
+  if constexpr (std::is_integral_v<T> && !std::is_same_v<T, bool> &&
+                !std::is_same_v<T, char>) {
+    auto parsed = try_parse_integer<T>(text);
+    if (!parsed.ok()) {
+      return false;
+    }
+    value = parsed.value();
+    return true;
+  } else {
+    std::istringstream in(text);
+    return in >> value && (in >> std::ws).eof();
+  }
+}
+
+inline std::string strip_comment(const std::string& line) {
+  return line.substr(0, line.find('#'));
+}
+
+template <typename T>
+std::vector<T> parse_values(const std::string& text) {
+  std::vector<T> values;
+  std::istringstream lines(text);
+  std::string line;
+  for (size_t number = 1; std::getline(lines, line); ++number) {
+    std::istringstream tokens(strip_comment(line));
+    std::string token;
+    while (tokens >> token) {
+      T value;
+      if (!parse_one(token, value)) {
+        throw ParseError("line " + std::to_string(number) + ": bad value '" +
+                         token + "'");
+      }
+      values.push_back(std::move(value));
+    }
+  }
+  return values;
+}
+
+// Everything left in the stream
+template <typename T>
+std::vector<T> parse_values(std::istream& in) {
+  std::ostringstream text;
+  text << in.rdbuf();
+  return parse_values<T>(text.str());
+}
+
+// "[]" is the empty tree. Throws if a value isn't a T, and as
+// tree_from_level_order() (in binary-tree/Serialize.h) does.
+template <typename T>
+std::unique_ptr<TreeNode<T>> parse_tree(const std::string& text) {
+  const size_t open = text.find_first_not_of(" \t\n");
+  const size_t close = text.find_last_not_of(" \t\n");
+  if (open == std::string::npos || text[open] != '[' || text[close] != ']') {
+    throw ParseError("tree: expected [...]");
+  }
+
+  const std::string inside = text.substr(open + 1, close - open - 1);
+  if (inside.find_first_not_of(" \t\n") == std::string::npos) {
+    return nullptr;
+  }
+
+  std::vector<std::optional<T>> values;
+  std::istringstream in(inside);
+  std::string field;
+  for (size_t index = 0; std::getline(in, field, ','); ++index) {
+    const size_t begin = field.find_first_not_of(" \t\n");
+    const size_t end = field.find_last_not_of(" \t\n");
+    field = begin == std::string::npos ? ""
+                                       : field.substr(begin, end - begin + 1);
+    T value;
+    if (field == "null") {
+      values.push_back(std::nullopt);
+    } else if (parse_one(field, value)) {
+      values.push_back(std::move(value));
+    } else {
+      throw ParseError("tree: bad value '" + field + "' at index " +
+                       std::to_string(index));
+    }
+  }
+  try {
+    return tree_from_level_order(values);
+  } catch (const std::invalid_argument& e) {
+    throw ParseError(std::string("tree: ") + e.what());
+  }
+}
+
+// The lines of an edge list with comments and blank lines dropped, each
+// split into fields and remembering its line number
+struct EdgeListLine {
+  size_t number;
+  std::vector<std::string> fields;
+};
+
+inline std::vector<EdgeListLine> edge_list_lines(const std::string& text) {
+  std::vector<EdgeListLine> lines;
+  std::istringstream in(text);
+  std::string line;
+  for (size_t number = 1; std::getline(in, line); ++number) {
+    std::istringstream tokens(strip_comment(line));
+    EdgeListLine parsed{number, {}};
+    for (std::string token; tokens >> token;) {
+      parsed.fields.push_back(token);
+    }
+    if (!parsed.fields.empty()) {
+      lines.push_back(std::move(parsed));
+    }
+  }
+  if (lines.empty()) {
+    throw ParseError("graph: expected the vertex count");
+  }
+  return lines;
+}
+
+inline size_t parse_vertex_count(const EdgeListLine& line) {
+  int64_t n;
+  if (line.fields.size() != 1 || !parse_one(line.fields[0], n) || n < 0) {
+    throw ParseError("line " + std::to_string(line.number) +
+                     ": expected the vertex count");
+  }
+  return static_cast<size_t>(n);
+}
+
+inline int parse_vertex(const EdgeListLine& line, size_t field, size_t n) {
+  int64_t v;
+  if (!parse_one(line.fields[field], v)) {
+    throw ParseError("line " + std::to_string(line.number) + ": bad vertex '" +
+                     line.fields[field] + "'");
+  }
+  if (v < 0 || static_cast<uint64_t>(v) >= n) {
+    throw ParseError("line " + std::to_string(line.number) + ": vertex " +
+                     std::to_string(v) + " out of range for " +
+                     std::to_string(n) + " vertices");
+  }
+  return static_cast<int>(v);
+}
+
+struct EdgeList {
+  size_t vertices;
+  std::vector<Edge> edges;
+};
+
+inline EdgeList parse_edge_list(const std::string& text) {
+  const auto lines = edge_list_lines(text);
+  EdgeList result{parse_vertex_count(lines[0]), {}};
+  for (size_t i = 1; i < lines.size(); ++i) {
+    if (lines[i].fields.size() != 2) {
+      throw ParseError("line " + std::to_string(lines[i].number) +
+                       ": expected an edge 'u v'");
+    }
+    result.edges.push_back({parse_vertex(lines[i], 0, result.vertices),
+                            parse_vertex(lines[i], 1, result.vertices)});
+  }
+  return result;
+}
+
+struct ArcList {
+  size_t vertices;
+  std::vector<Arc> arcs;
+};
+
+inline ArcList parse_arc_list(const std::string& text) {
+  const auto lines = edge_list_lines(text);
+  ArcList result{parse_vertex_count(lines[0]), {}};
+  for (size_t i = 1; i < lines.size(); ++i) {
+    const auto& line = lines[i];
+    int64_t weight;
+    if (line.fields.size() != 3) {
+      throw ParseError("line " + std::to_string(line.number) +
+                       ": expected an edge 'u v weight'");
+    }
+    if (!parse_one(line.fields[2], weight)) {
+      throw ParseError("line " + std::to_string(line.number) +
+                       ": bad weight '" + line.fields[2] + "'");
+    }
+    result.arcs.push_back({parse_vertex(line, 0, result.vertices),
+                           parse_vertex(line, 1, result.vertices), weight});
+  }
+  return result;
+}
+
+inline AdjacencyList parse_directed_graph(const std::string& text) {
+  const EdgeList list = parse_edge_list(text);
+  return directed_from_edges(list.vertices, list.edges);
+}
+
+inline AdjacencyList parse_undirected_graph(const std::string& text) {
+  const EdgeList list = parse_edge_list(text);
+  return undirected_from_edges(list.vertices, list.edges);
+}
+
+inline WeightedAdjacencyList parse_weighted_graph(const std::string& text) {
+  const ArcList list = parse_arc_list(text);
+  return weighted_from_arcs(list.vertices, list.arcs);
+}
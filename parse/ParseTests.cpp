@@ -0,0 +1,113 @@
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "../binary-tree/Serialize.h"
+#include "Parse.h"
+#include "gtest/gtest.h"
+
+// The message of the ParseError that parsing throws
+template <typename Parse>
+std::string error_from(Parse parse) {
+  try {
+    parse();
+  } catch (const ParseError& e) {
+    return e.what();
+  }
+  return "no error";
+}
+
+TEST(Parse, Values) {
+  EXPECT_EQ(parse_values<int>("3 1  4\n1\t5\n"),
+            (std::vector<int>{3, 1, 4, 1, 5}));
+  EXPECT_EQ(parse_values<int>("1 2 # the rest is ignored 3\n4"),
+            (std::vector<int>{1, 2, 4}));
+  EXPECT_TRUE(parse_values<int>("").empty());
+  EXPECT_EQ(parse_values<double>("0.5 -2"), (std::vector<double>{0.5, -2}));
+  EXPECT_EQ(parse_values<std::string>("to be"),
+            (std::vector<std::string>{"to", "be"}));
+
+  EXPECT_EQ(error_from([] { parse_values<int>("1 2\n3 4x"); }),
+            "line 2: bad value '4x'");
+  EXPECT_EQ(error_from([] { parse_values<int>("99999999999"); }),
+            "line 1: bad value '99999999999'");
+
+  // A stream would read these into an unsigned type, wrapped around
+  EXPECT_EQ(parse_values<uint64_t>("0 18446744073709551615"),
+            (std::vector<uint64_t>{0, 18446744073709551615u}));
+  EXPECT_EQ(error_from([] { parse_values<uint64_t>("3 -1"); }),
+            "line 1: bad value '-1'");
+  EXPECT_EQ(error_from([] { parse_values<size_t>("-0"); }),
+            "line 1: bad value '-0'");
+}
+
+TEST(Parse, Trees) {
+  auto root = parse_tree<int>("[1,2,3,null,null,4,5]");
+  EXPECT_EQ(serialize(root), "1,2,3,#,#,4,5");
+  EXPECT_EQ(serialize(parse_tree<int>(" [ 1 , null , 2 ] ")), "1,#,2");
+  EXPECT_EQ(parse_tree<int>("[]"), nullptr);
+  EXPECT_EQ(parse_tree<int>("[ ]"), nullptr);
+  // Trailing nulls are allowed, as LeetCode writes them sometimes
+  EXPECT_EQ(serialize(parse_tree<int>("[1,null,null]")), "1");
+
+  EXPECT_EQ(error_from([] { parse_tree<int>("1,2"); }),
+            "tree: expected [...]");
+  EXPECT_EQ(error_from([] { parse_tree<int>("[1,two]"); }),
+            "tree: bad value 'two' at index 1");
+  EXPECT_EQ(error_from([] { parse_tree<int>("[null,1]"); }),
+            "tree: missing root");
+  EXPECT_EQ(error_from([] { parse_tree<int>("[1,null,null,2]"); }),
+            "tree: more values than the tree has room for");
+}
+
+TEST(Parse, TreeFromLevelOrder) {
+  const std::vector<std::optional<int>> values = {1, 2, 3, std::nullopt,
+                                                  std::nullopt, 4, 5};
+  EXPECT_EQ(serialize(tree_from_level_order(values)), "1,2,3,#,#,4,5");
+  EXPECT_EQ(tree_from_level_order(std::vector<std::optional<int>>{}),
+            nullptr);
+  EXPECT_THROW(tree_from_level_order(std::vector<std::optional<int>>{
+                   std::nullopt}),
+               std::invalid_argument);
+}
+
+TEST(Parse, Graphs) {
+  const std::string text =
+      "# a triangle with a tail\n"
+      "4\n"
+      "0 1\n"
+      "1 2  # the long side\n"
+      "\n"
+      "2 0\n"
+      "2 3\n";
+  EXPECT_EQ(parse_directed_graph(text), (AdjacencyList{{1}, {2}, {0, 3}, {}}));
+  EXPECT_EQ(parse_undirected_graph(text),
+            (AdjacencyList{{1, 2}, {0, 2}, {1, 0, 3}, {2}}));
+  EXPECT_EQ(parse_edge_list("0\n").vertices, 0u);
+
+  EXPECT_EQ(parse_weighted_graph("2\n0 1 -5\n1 0 7\n"),
+            (WeightedAdjacencyList{{{1, -5}}, {{0, 7}}}));
+
+  EXPECT_EQ(error_from([] { parse_edge_list(""); }),
+            "graph: expected the vertex count");
+  EXPECT_EQ(error_from([] { parse_edge_list("3 2\n0 1\n"); }),
+            "line 1: expected the vertex count");
+  EXPECT_EQ(error_from([] { parse_edge_list("3\n0 1\n\n1 3\n"); }),
+            "line 4: vertex 3 out of range for 3 vertices");
+  EXPECT_EQ(error_from([] { parse_edge_list("3\n0 1 2\n"); }),
+            "line 2: expected an edge 'u v'");
+  EXPECT_EQ(error_from([] { parse_edge_list("3\n0 b\n"); }),
+            "line 2: bad vertex 'b'");
+  EXPECT_EQ(error_from([] { parse_arc_list("3\n0 1\n"); }),
+            "line 2: expected an edge 'u v weight'");
+  EXPECT_EQ(error_from([] { parse_arc_list("3\n0 1 heavy\n"); }),
+            "line 2: bad weight 'heavy'");
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
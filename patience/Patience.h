@@ -0,0 +1,183 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <functional>
+#include <queue>
+#include <utility>
+#include <vector>
+
+#ifndef ETUDES_NO_IO
+#include <ostream>
+#include <sstream>
+#include <string>
+#endif
+
+// Patience sorting, after the solitaire game. Deal the cards one at a time
+// onto a row of piles: each card goes on the leftmost pile whose top card is
+// at least as large, or starts a new pile on the right if there's none.
+//
+// Two invariants fall out of the rule:
+//
+// - Within a pile, cards only get smaller going up, so every pile is a
+//   sorted run read from the top.
+// - The top cards increase from left to right, so the leftmost pile with a
+//   big enough top can be found by binary search. O(n log n) to deal.
+//
+// The number of piles is the length of the longest strictly increasing
+// subsequence: an increasing subsequence can use at most one card from each
+// pile (a later card on the same pile is no larger), and following each
+// card back to the top of the pile on its left at the moment it was dealt
+// traces an increasing subsequence through every pile.
+
+// The piles, left to right. Each pile lists its cards bottom first, so the
+// top card is back().
+template <typename T>
+using Piles = std::vector<std::vector<T>>;
+
+// Building with ETUDES_NO_IO leaves out the streams, for targets that have
+// none (see embedded/); there's no trace then, and trace must be nullptr
+#ifdef ETUDES_NO_IO
+struct PileTrace {};
+#else
+using PileTrace = std::ostream;
+
+template <typename T>
+std::string format_piles(const Piles<T>& piles) {
+  std::ostringstream out;
+  for (size_t p = 0; p < piles.size(); ++p) {
+    out << (p > 0 ? " [" : "[");
+    for (size_t i = 0; i < piles[p].size(); ++i) {
+      out << (i > 0 ? " " : "") << piles[p][i];
+    }
+    out << ']';
+  }
+  return out.str();
+}
+#endif
+
+// Index of the pile that card x goes on: the first pile whose top is >= x
+template <typename T, typename Compare>
+size_t pile_for(const Piles<T>& piles, const T& x, Compare less) {
+  auto it = std::lower_bound(
+      piles.begin(), piles.end(), x,
+      [&](const std::vector<T>& pile, const T& card) {
+        return less(pile.back(), card);
+      });
+  return static_cast<size_t>(it - piles.begin());
+}
+
+// Deals every card. If trace is set, writes the piles after each card, one
+// line per card, e.g. "deal 3 -> pile 0: [9 3] [5]".
+template <typename T, typename Compare = std::less<T>>
+Piles<T> patience_piles(const std::vector<T>& cards,
+                        PileTrace* trace = nullptr,
+                        Compare less = Compare()) {
+  Piles<T> piles;
+  for (const auto& card : cards) {
+    const size_t p = pile_for(piles, card, less);
+    if (p == piles.size()) {
+      piles.emplace_back();
+    }
+    piles[p].push_back(card);
+#ifndef ETUDES_NO_IO
+    if (trace != nullptr) {
+      *trace << "deal " << card << " -> pile " << p << ": "
+             << format_piles(piles) << '\n';
+    }
+#endif
+  }
+#ifdef ETUDES_NO_IO
+  (void)trace;
+#endif
+  return piles;
+}
+
+// Sorts by dealing into piles and merging them: each pile is a sorted run
+// from its top down, so repeatedly take the smallest top card. The merge
+// keeps a min-heap of one top card per pile. O(n log n).
+template <typename T, typename Compare = std::less<T>>
+std::vector<T> patience_sort(const std::vector<T>& cards,
+                             PileTrace* trace = nullptr,
+                             Compare less = Compare()) {
+  Piles<T> piles = patience_piles(cards, trace, less);
+
+  // Heap entries are pile indices, ordered by their top cards. priority_queue
+  // is a max-heap, so flip the comparison.
+  auto larger_top = [&](size_t a, size_t b) {
+    return less(piles[b].back(), piles[a].back());
+  };
+  std::priority_queue<size_t, std::vector<size_t>, decltype(larger_top)> heap(
+      larger_top);
+  for (size_t p = 0; p < piles.size(); ++p) {
+    heap.push(p);
+  }
+
+  std::vector<T> sorted;
+  sorted.reserve(cards.size());
+  while (!heap.empty()) {
+    const size_t p = heap.top();
+    heap.pop();
+    sorted.push_back(std::move(piles[p].back()));
+    piles[p].pop_back();
+    if (!piles[p].empty()) {
+      heap.push(p);
+    }
+  }
+  return sorted;
+}
+
+// One longest strictly increasing subsequence, by dealing positions instead
+// of cards and remembering, for each card, the top of the pile to its left
+// when it was dealt. O(n log n).
+template <typename T, typename Compare = std::less<T>>
+std::vector<T> longest_increasing_subsequence(const std::vector<T>& values,
+                                              Compare less = Compare()) {
+  // tops[p] is the position of the top card of pile p
+  std::vector<size_t> tops;
+  std::vector<size_t> previous(values.size());
+  for (size_t i = 0; i < values.size(); ++i) {
+    auto it = std::lower_bound(
+        tops.begin(), tops.end(), i,
+        [&](size_t top, size_t) { return less(values[top], values[i]); });
+    const size_t p = static_cast<size_t>(it - tops.begin());
+    previous[i] = p > 0 ? tops[p - 1] : i;  // i marks the start
+    if (p == tops.size()) {
+      tops.push_back(i);
+    } else {
+      tops[p] = i;
+    }
+  }
+
+  std::vector<T> result;
+  if (tops.empty()) {
+    return result;
+  }
+  for (size_t i = tops.back();; i = previous[i]) {
+    result.push_back(values[i]);
+    if (previous[i] == i) {
+      break;
+    }
+  }
+  std::reverse(result.begin(), result.end());
+  return result;
+}
+
+template <typename T, typename Compare = std::less<T>>
+size_t lis_length(const std::vector<T>& values, Compare less = Compare()) {
+  return patience_piles(values, nullptr, less).size();
+}
+
+// O(n^2) for the tests: lis[i] is the longest run ending at position i
+template <typename T>
+size_t lis_length_naive(const std::vector<T>& values) {
+  std::vector<size_t> lis(values.size(), 1);
+  for (size_t i = 0; i < values.size(); ++i) {
+    for (size_t j = 0; j < i; ++j) {
+      if (values[j] < values[i]) {
+        lis[i] = std::max(lis[i], lis[j] + 1);
+      }
+    }
+  }
+  return values.empty() ? 0 : *std::max_element(lis.begin(), lis.end());
+}
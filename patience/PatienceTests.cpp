@@ -0,0 +1,135 @@
+#include <algorithm>
+#include <functional>
+#include <random>
+#include <sstream>
+#include <string>
+#include <vector>
+
+#include "Patience.h"
+#include "gtest/gtest.h"
+
+bool is_strictly_increasing(const std::vector<int>& values) {
+  return std::adjacent_find(values.begin(), values.end(),
+                            std::greater_equal<int>()) == values.end();
+}
+
+bool is_subsequence(const std::vector<int>& sub,
+                    const std::vector<int>& values) {
+  size_t i = 0;
+  for (size_t j = 0; j < values.size() && i < sub.size(); ++j) {
+    if (values[j] == sub[i]) {
+      ++i;
+    }
+  }
+  return i == sub.size();
+}
+
+TEST(Patience, Piles) {
+  std::vector<int> cards = {9, 5, 7, 3, 8, 6, 2};
+  Piles<int> expected = {{9, 5, 3, 2}, {7, 6}, {8}};
+
+  EXPECT_EQ(patience_piles(cards), expected);
+  EXPECT_EQ(format_piles(expected), "[9 5 3 2] [7 6] [8]");
+}
+
+TEST(Patience, PileInvariants) {
+  std::mt19937 gen(139);
+  std::uniform_int_distribution<int> value(0, 50);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<int> cards(value(gen));
+    for (auto& card : cards) {
+      card = value(gen);
+    }
+    auto piles = patience_piles(cards);
+
+    size_t total = 0;
+    for (size_t p = 0; p < piles.size(); ++p) {
+      ASSERT_FALSE(piles[p].empty());
+      ASSERT_TRUE(std::is_sorted(piles[p].rbegin(), piles[p].rend()));
+      if (p > 0) {
+        ASSERT_LT(piles[p - 1].back(), piles[p].back());
+      }
+      total += piles[p].size();
+    }
+    ASSERT_EQ(total, cards.size());
+  }
+}
+
+TEST(Patience, Trace) {
+  std::ostringstream trace;
+  patience_piles(std::vector<int>{4, 1, 3}, &trace);
+
+  EXPECT_EQ(trace.str(),
+            "deal 4 -> pile 0: [4]\n"
+            "deal 1 -> pile 0: [4 1]\n"
+            "deal 3 -> pile 1: [4 1] [3]\n");
+}
+
+TEST(Patience, Sort) {
+  EXPECT_TRUE(patience_sort(std::vector<int>{}).empty());
+  EXPECT_EQ(patience_sort(std::vector<int>{3, 1, 2, 1, 3}),
+            (std::vector<int>{1, 1, 2, 3, 3}));
+  EXPECT_EQ(patience_sort(std::vector<std::string>{"pear", "fig", "apple"}),
+            (std::vector<std::string>{"apple", "fig", "pear"}));
+
+  // A custom comparison sorts in that order
+  EXPECT_EQ(patience_sort(std::vector<int>{2, 5, 1, 4}, nullptr,
+                          std::greater<int>()),
+            (std::vector<int>{5, 4, 2, 1}));
+}
+
+TEST(Patience, SortMatchesStdSort) {
+  std::mt19937 gen(140);
+  for (int trial = 0; trial < 200; ++trial) {
+    std::vector<int> cards(std::uniform_int_distribution<int>(0, 100)(gen));
+    std::uniform_int_distribution<int> value(-20, 20);
+    for (auto& card : cards) {
+      card = value(gen);
+    }
+    auto expected = cards;
+    std::sort(expected.begin(), expected.end());
+
+    ASSERT_EQ(patience_sort(cards), expected);
+  }
+}
+
+TEST(Patience, LongestIncreasingSubsequence) {
+  std::vector<int> values = {10, 9, 2, 5, 3, 7, 101, 18};
+  auto lis = longest_increasing_subsequence(values);
+
+  EXPECT_EQ(lis_length(values), 4u);
+  EXPECT_EQ(lis.size(), 4u);
+  EXPECT_TRUE(is_strictly_increasing(lis));
+  EXPECT_TRUE(is_subsequence(lis, values));
+
+  EXPECT_TRUE(longest_increasing_subsequence(std::vector<int>{}).empty());
+  EXPECT_EQ(longest_increasing_subsequence(std::vector<int>{7, 7, 7}),
+            std::vector<int>{7});
+  EXPECT_EQ(longest_increasing_subsequence(std::vector<int>{1, 2, 3}),
+            (std::vector<int>{1, 2, 3}));
+  EXPECT_EQ(longest_increasing_subsequence(std::vector<int>{3, 2, 1}),
+            std::vector<int>{1});
+}
+
+TEST(Patience, LisMatchesQuadratic) {
+  std::mt19937 gen(141);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<int> values(std::uniform_int_distribution<int>(0, 60)(gen));
+    std::uniform_int_distribution<int> value(0, 30);
+    for (auto& v : values) {
+      v = value(gen);
+    }
+    auto lis = longest_increasing_subsequence(values);
+    const size_t expected = lis_length_naive(values);
+
+    ASSERT_EQ(lis_length(values), expected);
+    ASSERT_EQ(lis.size(), expected);
+    ASSERT_TRUE(is_strictly_increasing(lis));
+    ASSERT_TRUE(is_subsequence(lis, values));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
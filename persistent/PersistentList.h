@@ -0,0 +1,161 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <initializer_list>
+#include <iterator>
+#include <memory>
+#include <utility>
+#include <vector>
+
+// Immutable singly-linked list (a Lisp-style cons list).
+//
+// No operation modifies an existing list. push_front allocates one cell that
+// points at the old list, so the old and new versions share every cell but
+// the first. Cells are reference counted, and a cell lives as long as any
+// version that can reach it.
+//
+// Operations that change the middle of the list (set, insert_at) copy the
+// cells in front of the change and share the rest.
+template <typename T>
+class PersistentList {
+ private:
+  struct Cell {
+    T value;
+    std::shared_ptr<Cell> next;
+    size_t length;  // Number of cells from here to the end of the list
+
+    Cell(T v, std::shared_ptr<Cell> n)
+        : value(std::move(v)),
+          next(std::move(n)),
+          length(1 + (next ? next->length : 0)) {}
+  };
+
+  std::shared_ptr<Cell> head_;
+
+  explicit PersistentList(std::shared_ptr<Cell> head)
+      : head_(std::move(head)) {}
+
+  // Rebuilds the first count cells on top of rest, preserving their order.
+  // Used by the operations that copy a prefix.
+  static std::shared_ptr<Cell> copy_prefix(const Cell* first, size_t count,
+                                           std::shared_ptr<Cell> rest) {
+    std::vector<const T*> prefix;
+    prefix.reserve(count);
+    for (; count > 0; --count, first = first->next.get()) {
+      prefix.push_back(&first->value);
+    }
+    for (auto it = prefix.rbegin(); it != prefix.rend(); ++it) {
+      rest = std::make_shared<Cell>(**it, std::move(rest));
+    }
+    return rest;
+  }
+
+ public:
+  PersistentList() = default;
+
+  PersistentList(std::initializer_list<T> values) {
+    for (auto it = std::rbegin(values); it != std::rend(values); ++it) {
+      head_ = std::make_shared<Cell>(*it, std::move(head_));
+    }
+  }
+
+  PersistentList(const PersistentList&) = default;
+  PersistentList(PersistentList&&) = default;
+
+  // Copy-and-swap, so that the cells this list drops are released by the
+  // destructor below rather than recursively by shared_ptr
+  PersistentList& operator=(PersistentList other) {
+    std::swap(head_, other.head_);
+    return *this;
+  }
+
+  ~PersistentList() {
+    // The default destructor releases cells recursively, which overflows the
+    // stack on long lists. Unlink the cells that this version owns alone one
+    // at a time instead; the first shared cell stops the loop.
+    while (head_ && head_.use_count() == 1) {
+      head_ = std::move(head_->next);
+    }
+  }
+
+  bool empty() const { return !head_; }
+
+  size_t size() const { return head_ ? head_->length : 0; }
+
+  const T& front() const {
+    assert(head_ && "front called on empty list");
+    return head_->value;
+  }
+
+  // O(1): the new list's tail is this list
+  PersistentList push_front(T value) const {
+    return PersistentList(std::make_shared<Cell>(std::move(value), head_));
+  }
+
+  // O(1): the result is this list's tail
+  PersistentList pop_front() const {
+    assert(head_ && "pop_front called on empty list");
+    return PersistentList(head_->next);
+  }
+
+  const T& at(size_t index) const {
+    assert(index < size() && "index out of range");
+    const Cell* cell = head_.get();
+    for (; index > 0; --index) {
+      cell = cell->next.get();
+    }
+    return cell->value;
+  }
+
+  // O(index): copies the cells before index, shares the ones after
+  PersistentList set(size_t index, T value) const {
+    assert(index < size() && "index out of range");
+    const Cell* cell = head_.get();
+    for (size_t i = 0; i < index; ++i) {
+      cell = cell->next.get();
+    }
+    auto rest = std::make_shared<Cell>(std::move(value), cell->next);
+    return PersistentList(copy_prefix(head_.get(), index, std::move(rest)));
+  }
+
+  // O(index): copies the cells before index, shares the ones after
+  PersistentList insert_at(size_t index, T value) const {
+    assert(index <= size() && "index out of range");
+    std::shared_ptr<Cell> suffix = head_;
+    for (size_t i = 0; i < index; ++i) {
+      suffix = suffix->next;
+    }
+    auto rest = std::make_shared<Cell>(std::move(value), std::move(suffix));
+    return PersistentList(copy_prefix(head_.get(), index, std::move(rest)));
+  }
+
+  // O(size()): copies this list and shares all of other
+  PersistentList concat(const PersistentList& other) const {
+    return PersistentList(copy_prefix(head_.get(), size(), other.head_));
+  }
+
+  // O(size()): nothing can be shared, since every next pointer changes
+  PersistentList reverse() const {
+    std::shared_ptr<Cell> result;
+    for (const Cell* cell = head_.get(); cell; cell = cell->next.get()) {
+      result = std::make_shared<Cell>(cell->value, std::move(result));
+    }
+    return PersistentList(std::move(result));
+  }
+
+  // True if both lists are the very same cells in memory (not just equal
+  // values). This is how the tests observe structural sharing.
+  bool shares_structure_with(const PersistentList& other) const {
+    return head_ == other.head_;
+  }
+
+  std::vector<T> to_vector() const {
+    std::vector<T> result;
+    result.reserve(size());
+    for (const Cell* cell = head_.get(); cell; cell = cell->next.get()) {
+      result.push_back(cell->value);
+    }
+    return result;
+  }
+};
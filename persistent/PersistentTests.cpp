@@ -0,0 +1,246 @@
+#include <algorithm>
+#include <random>
+#include <set>
+#include <vector>
+
+#include "PersistentList.h"
+#include "PersistentTree.h"
+#include "gtest/gtest.h"
+
+TEST(PersistentList, EmptyList) {
+  PersistentList<int> list;
+
+  EXPECT_TRUE(list.empty());
+  EXPECT_EQ(list.size(), 0u);
+  EXPECT_TRUE(list.to_vector().empty());
+}
+
+TEST(PersistentList, PushFrontKeepsOldVersion) {
+  PersistentList<int> v0;
+  auto v1 = v0.push_front(1);
+  auto v2 = v1.push_front(2);
+  auto v3 = v2.push_front(3);
+
+  EXPECT_TRUE(v0.empty());
+  EXPECT_EQ(v1.to_vector(), (std::vector<int>{1}));
+  EXPECT_EQ(v2.to_vector(), (std::vector<int>{2, 1}));
+  EXPECT_EQ(v3.to_vector(), (std::vector<int>{3, 2, 1}));
+  EXPECT_EQ(v3.front(), 3);
+  EXPECT_EQ(v3.size(), 3u);
+}
+
+TEST(PersistentList, TailIsShared) {
+  PersistentList<int> list{2, 3, 4};
+  auto longer = list.push_front(1);
+
+  // The new version's tail is the old version, cell for cell
+  EXPECT_TRUE(longer.pop_front().shares_structure_with(list));
+
+  // Two lists built independently are equal but not shared
+  PersistentList<int> copy{2, 3, 4};
+  EXPECT_EQ(copy.to_vector(), list.to_vector());
+  EXPECT_FALSE(copy.shares_structure_with(list));
+}
+
+TEST(PersistentList, BranchingVersions) {
+  // Two versions grow from the same base
+  PersistentList<int> base{10, 20};
+  auto a = base.push_front(1);
+  auto b = base.push_front(2);
+
+  EXPECT_EQ(a.to_vector(), (std::vector<int>{1, 10, 20}));
+  EXPECT_EQ(b.to_vector(), (std::vector<int>{2, 10, 20}));
+  EXPECT_TRUE(a.pop_front().shares_structure_with(b.pop_front()));
+}
+
+TEST(PersistentList, SetCopiesOnlyThePrefix) {
+  PersistentList<int> v1{1, 2, 3, 4, 5};
+  auto v2 = v1.set(2, 30);
+
+  EXPECT_EQ(v1.to_vector(), (std::vector<int>{1, 2, 3, 4, 5}));
+  EXPECT_EQ(v2.to_vector(), (std::vector<int>{1, 2, 30, 4, 5}));
+
+  // The cells after the change are shared: [4, 5]
+  EXPECT_TRUE(v2.pop_front().pop_front().pop_front().shares_structure_with(
+      v1.pop_front().pop_front().pop_front()));
+  // The cells before it are copies
+  EXPECT_FALSE(v2.pop_front().shares_structure_with(v1.pop_front()));
+}
+
+TEST(PersistentList, InsertAtConcatAndReverse) {
+  PersistentList<int> list{1, 2, 4};
+  auto inserted = list.insert_at(2, 3);
+  auto at_end = list.insert_at(3, 5);
+  auto joined = list.concat(PersistentList<int>{7, 8});
+  auto reversed = list.reverse();
+
+  EXPECT_EQ(list.to_vector(), (std::vector<int>{1, 2, 4}));
+  EXPECT_EQ(inserted.to_vector(), (std::vector<int>{1, 2, 3, 4}));
+  EXPECT_EQ(at_end.to_vector(), (std::vector<int>{1, 2, 4, 5}));
+  EXPECT_EQ(joined.to_vector(), (std::vector<int>{1, 2, 4, 7, 8}));
+  EXPECT_EQ(reversed.to_vector(), (std::vector<int>{4, 2, 1}));
+  EXPECT_EQ(inserted.at(2), 3);
+}
+
+TEST(PersistentList, EveryVersionSurvivesRandomEdits) {
+  // Apply random edits, each to a random earlier version, and check every
+  // version against a snapshot taken when it was created.
+  std::mt19937 gen(99);
+  std::vector<PersistentList<int>> versions{PersistentList<int>{}};
+  std::vector<std::vector<int>> snapshots{{}};
+
+  for (int step = 0; step < 500; ++step) {
+    size_t base = std::uniform_int_distribution<size_t>(
+        0, versions.size() - 1)(gen);
+    const auto& list = versions[base];
+    std::vector<int> expected = snapshots[base];
+    PersistentList<int> next;
+
+    int value = step;
+    int op = std::uniform_int_distribution<int>(0, 2)(gen);
+    if (op == 0 || list.empty()) {
+      next = list.push_front(value);
+      expected.insert(expected.begin(), value);
+    } else if (op == 1) {
+      size_t i =
+          std::uniform_int_distribution<size_t>(0, list.size() - 1)(gen);
+      next = list.set(i, value);
+      expected[i] = value;
+    } else {
+      size_t i = std::uniform_int_distribution<size_t>(0, list.size())(gen);
+      next = list.insert_at(i, value);
+      expected.insert(expected.begin() + i, value);
+    }
+
+    versions.push_back(next);
+    snapshots.push_back(expected);
+  }
+
+  for (size_t i = 0; i < versions.size(); ++i) {
+    ASSERT_EQ(versions[i].to_vector(), snapshots[i]) << "version " << i;
+  }
+}
+
+TEST(PersistentList, LongListDestructsWithoutRecursion) {
+  PersistentList<int> list;
+  for (int i = 0; i < 1000000; ++i) {
+    list = list.push_front(i);
+  }
+  EXPECT_EQ(list.size(), 1000000u);
+}
+
+TEST(PersistentTree, EmptyTree) {
+  PersistentTree<int> tree;
+
+  EXPECT_TRUE(tree.empty());
+  EXPECT_FALSE(tree.contains(1));
+  EXPECT_TRUE(tree.remove(1).empty());
+}
+
+TEST(PersistentTree, InsertKeepsOldVersion) {
+  PersistentTree<int> v0;
+  auto v1 = v0.insert(5);
+  auto v2 = v1.insert(3);
+  auto v3 = v2.insert(8);
+
+  EXPECT_TRUE(v0.empty());
+  EXPECT_EQ(v1.in_order(), (std::vector<int>{5}));
+  EXPECT_EQ(v2.in_order(), (std::vector<int>{3, 5}));
+  EXPECT_EQ(v3.in_order(), (std::vector<int>{3, 5, 8}));
+
+  EXPECT_FALSE(v2.contains(8));
+  EXPECT_TRUE(v3.contains(8));
+}
+
+TEST(PersistentTree, RemoveKeepsOldVersion) {
+  PersistentTree<int> tree;
+  for (int x : {50, 30, 70, 20, 40, 60, 80}) {
+    tree = tree.insert(x);
+  }
+
+  auto without_root = tree.remove(50);
+  auto without_leaf = tree.remove(20);
+  auto unchanged = tree.remove(99);
+
+  EXPECT_EQ(tree.in_order(), (std::vector<int>{20, 30, 40, 50, 60, 70, 80}));
+  EXPECT_EQ(without_root.in_order(),
+            (std::vector<int>{20, 30, 40, 60, 70, 80}));
+  EXPECT_EQ(without_leaf.in_order(),
+            (std::vector<int>{30, 40, 50, 60, 70, 80}));
+  EXPECT_EQ(unchanged.size(), tree.size());
+}
+
+TEST(PersistentTree, DuplicateInsertSharesEverything) {
+  PersistentTree<int> tree;
+  for (int x : {2, 1, 3}) {
+    tree = tree.insert(x);
+  }
+  auto same = tree.insert(2);
+
+  EXPECT_EQ(same.size(), 3u);
+  EXPECT_EQ(PersistentTree<int>::count_distinct_nodes({tree, same}), 3u);
+}
+
+TEST(PersistentTree, UpdateOfVersionNLeavesVersionNMinusOneIntact) {
+  std::mt19937 gen(31337);
+  std::uniform_int_distribution<int> key(0, 1000);
+
+  std::vector<PersistentTree<int>> versions{PersistentTree<int>{}};
+  std::vector<std::set<int>> snapshots{{}};
+
+  for (int step = 0; step < 2000; ++step) {
+    const auto& latest = versions.back();
+    std::set<int> expected = snapshots.back();
+    int k = key(gen);
+    if (step % 3 == 2) {
+      versions.push_back(latest.remove(k));
+      expected.erase(k);
+    } else {
+      versions.push_back(latest.insert(k));
+      expected.insert(k);
+    }
+    snapshots.push_back(expected);
+
+    // Version N - 1 must still look exactly like its snapshot
+    const auto& previous = versions[versions.size() - 2];
+    ASSERT_EQ(previous.in_order(),
+              std::vector<int>(snapshots[snapshots.size() - 2].begin(),
+                               snapshots[snapshots.size() - 2].end()));
+  }
+
+  for (size_t i = 0; i < versions.size(); i += 50) {
+    ASSERT_EQ(versions[i].in_order(),
+              std::vector<int>(snapshots[i].begin(), snapshots[i].end()));
+    for (int k = 0; k <= 1000; k += 37) {
+      ASSERT_EQ(versions[i].contains(k), snapshots[i].count(k) == 1);
+    }
+  }
+}
+
+TEST(PersistentTree, PathCopyingSharesMostNodes) {
+  std::mt19937 gen(5);
+  std::vector<int> keys(1000);
+  for (size_t i = 0; i < keys.size(); ++i) {
+    keys[i] = static_cast<int>(i);
+  }
+  std::shuffle(keys.begin(), keys.end(), gen);
+
+  std::vector<PersistentTree<int>> versions{PersistentTree<int>{}};
+  size_t total_size = 0;
+  for (int k : keys) {
+    versions.push_back(versions.back().insert(k));
+    total_size += versions.back().size();
+  }
+
+  // Keeping all 1000 versions as full copies would take ~500k nodes. Path
+  // copying only pays for the search path of each insert.
+  size_t distinct = PersistentTree<int>::count_distinct_nodes(versions);
+  EXPECT_GE(distinct, keys.size());
+  EXPECT_LT(distinct, 50 * keys.size());
+  EXPECT_LT(distinct * 10, total_size);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
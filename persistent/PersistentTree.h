@@ -0,0 +1,177 @@
+#pragma once
+
+#include <cstddef>
+#include <memory>
+#include <unordered_set>
+#include <utility>
+#include <vector>
+
+// Immutable binary search tree built by path copying.
+//
+// An update never touches an existing node. Instead it copies the nodes on
+// the path from the root to the change and points the copies at the
+// untouched subtrees of the old version. Each update therefore allocates
+// O(height) nodes, and every earlier version stays valid and queryable:
+// it's just a different root into a shared set of nodes.
+//
+// The tree is not rebalanced, so the usual BST caveat applies: sorted input
+// degenerates into a path.
+template <typename K>
+class PersistentTree {
+ private:
+  struct Node {
+    K key;
+    std::shared_ptr<const Node> left;
+    std::shared_ptr<const Node> right;
+
+    Node(K k, std::shared_ptr<const Node> l, std::shared_ptr<const Node> r)
+        : key(std::move(k)), left(std::move(l)), right(std::move(r)) {}
+  };
+  using NodePtr = std::shared_ptr<const Node>;
+
+  NodePtr root_;
+  size_t size_{0};
+
+  PersistentTree(NodePtr root, size_t size)
+      : root_(std::move(root)), size_(size) {}
+
+  // Returns the root of the updated copy, or nullptr if key was already
+  // present (so the caller can share the whole old version).
+  static NodePtr insert(const NodePtr& node, const K& key) {
+    if (!node) {
+      return std::make_shared<const Node>(key, nullptr, nullptr);
+    }
+    if (key < node->key) {
+      auto left = insert(node->left, key);
+      return left ? std::make_shared<const Node>(node->key, left, node->right)
+                  : nullptr;
+    }
+    if (node->key < key) {
+      auto right = insert(node->right, key);
+      return right ? std::make_shared<const Node>(node->key, node->left, right)
+                   : nullptr;
+    }
+    return nullptr;
+  }
+
+  static NodePtr remove_min(const NodePtr& node, const Node*& min) {
+    if (!node->left) {
+      min = node.get();
+      return node->right;
+    }
+    return std::make_shared<const Node>(node->key,
+                                        remove_min(node->left, min),
+                                        node->right);
+  }
+
+  // Sets removed to false (and returns nullptr) if the key is absent
+  static NodePtr remove(const NodePtr& node, const K& key, bool& removed) {
+    if (!node) {
+      removed = false;
+      return nullptr;
+    }
+    if (key < node->key) {
+      auto left = remove(node->left, key, removed);
+      return removed ? std::make_shared<const Node>(node->key, left,
+                                                    node->right)
+                     : nullptr;
+    }
+    if (node->key < key) {
+      auto right = remove(node->right, key, removed);
+      return removed ? std::make_shared<const Node>(node->key, node->left,
+                                                    right)
+                     : nullptr;
+    }
+
+    removed = true;
+    if (!node->left) {
+      return node->right;
+    }
+    if (!node->right) {
+      return node->left;
+    }
+    // Two children: the in-order successor takes this node's place
+    const Node* successor = nullptr;
+    auto right = remove_min(node->right, successor);
+    return std::make_shared<const Node>(successor->key, node->left, right);
+  }
+
+  static void in_order(const Node* node, std::vector<K>& out) {
+    if (!node) {
+      return;
+    }
+    in_order(node->left.get(), out);
+    out.push_back(node->key);
+    in_order(node->right.get(), out);
+  }
+
+  static void collect(const Node* node,
+                      std::unordered_set<const Node*>& seen) {
+    // Stop at nodes already reached from another version: their whole
+    // subtree has been counted.
+    if (!node || !seen.insert(node).second) {
+      return;
+    }
+    collect(node->left.get(), seen);
+    collect(node->right.get(), seen);
+  }
+
+ public:
+  PersistentTree() = default;
+
+  size_t size() const { return size_; }
+
+  bool empty() const { return size_ == 0; }
+
+  bool contains(const K& key) const {
+    const Node* node = root_.get();
+    while (node) {
+      if (key < node->key) {
+        node = node->left.get();
+      } else if (node->key < key) {
+        node = node->right.get();
+      } else {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  // A new version with key added. This version is unchanged.
+  PersistentTree insert(const K& key) const {
+    auto root = insert(root_, key);
+    if (!root) {
+      return *this;
+    }
+    return PersistentTree(std::move(root), size_ + 1);
+  }
+
+  // A new version without key. This version is unchanged.
+  PersistentTree remove(const K& key) const {
+    bool removed = false;
+    auto root = remove(root_, key, removed);
+    if (!removed) {
+      return *this;
+    }
+    return PersistentTree(std::move(root), size_ - 1);
+  }
+
+  std::vector<K> in_order() const {
+    std::vector<K> result;
+    result.reserve(size_);
+    in_order(root_.get(), result);
+    return result;
+  }
+
+  // Number of distinct nodes reachable from any of the given versions. With
+  // full copying this would be the sum of their sizes; path copying brings it
+  // down to roughly the size of the first version plus O(height) per update.
+  static size_t count_distinct_nodes(
+      const std::vector<PersistentTree>& versions) {
+    std::unordered_set<const Node*> seen;
+    for (const auto& version : versions) {
+      collect(version.root_.get(), seen);
+    }
+    return seen.size();
+  }
+};
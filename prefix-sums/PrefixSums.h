@@ -0,0 +1,142 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <unordered_map>
+#include <vector>
+
+// Prefix sums: store prefix[i] = values[0] + ... + values[i - 1], and any
+// range sum is a difference of two of them, O(1) after O(n) to build.
+// Ranges are half-open, [begin, end).
+template <typename T>
+class PrefixSums {
+ public:
+  explicit PrefixSums(const std::vector<T>& values)
+      : prefix_(values.size() + 1, T{}) {
+    for (size_t i = 0; i < values.size(); ++i) {
+      prefix_[i + 1] = prefix_[i] + values[i];
+    }
+  }
+
+  T sum(size_t begin, size_t end) const {
+    assert(begin <= end && end < prefix_.size());
+    return prefix_[end] - prefix_[begin];
+  }
+
+  T total() const { return prefix_.back(); }
+
+  size_t size() const { return prefix_.size() - 1; }
+
+ private:
+  std::vector<T> prefix_;
+};
+
+// The same in two dimensions: prefix_[r][c] sums the r x c rectangle at the
+// top left. A rectangle's sum is its corner's prefix, minus the strips above
+// and to the left, plus the top-left block that both of those took away.
+template <typename T>
+class PrefixSums2D {
+ public:
+  // grid must be rectangular
+  explicit PrefixSums2D(const std::vector<std::vector<T>>& grid)
+      : prefix_(grid.size() + 1,
+                std::vector<T>((grid.empty() ? 0 : grid[0].size()) + 1, T{})) {
+    for (size_t r = 0; r < grid.size(); ++r) {
+      assert(grid[r].size() == grid[0].size() && "grid must be rectangular");
+      for (size_t c = 0; c < grid[r].size(); ++c) {
+        prefix_[r + 1][c + 1] =
+            grid[r][c] + prefix_[r][c + 1] + prefix_[r + 1][c] - prefix_[r][c];
+      }
+    }
+  }
+
+  // Rows [row_begin, row_end), columns [col_begin, col_end)
+  T sum(size_t row_begin, size_t col_begin, size_t row_end,
+        size_t col_end) const {
+    assert(row_begin <= row_end && row_end <= rows());
+    assert(col_begin <= col_end && col_end <= cols());
+    return prefix_[row_end][col_end] - prefix_[row_begin][col_end] -
+           prefix_[row_end][col_begin] + prefix_[row_begin][col_begin];
+  }
+
+  size_t rows() const { return prefix_.size() - 1; }
+
+  size_t cols() const { return prefix_[0].size() - 1; }
+
+ private:
+  std::vector<std::vector<T>> prefix_;
+};
+
+// Difference array: the inverse of prefix sums. Keep diff[i] = values[i] -
+// values[i - 1], and adding delta to a whole range only changes where the
+// range starts and where it stops: O(1) per update. The values come back as
+// the prefix sums of diff, O(n), so this suits many updates followed by
+// reading everything.
+template <typename T>
+class DifferenceArray {
+ public:
+  explicit DifferenceArray(size_t n) : diff_(n + 1, T{}) {}
+
+  explicit DifferenceArray(const std::vector<T>& values)
+      : diff_(values.size() + 1, T{}) {
+    for (size_t i = 0; i < values.size(); ++i) {
+      diff_[i] = i == 0 ? values[i] : values[i] - values[i - 1];
+    }
+  }
+
+  // Adds delta to every value in [begin, end)
+  void add(size_t begin, size_t end, const T& delta) {
+    assert(begin <= end && end < diff_.size());
+    diff_[begin] = diff_[begin] + delta;
+    diff_[end] = diff_[end] - delta;
+  }
+
+  std::vector<T> values() const {
+    std::vector<T> result(diff_.size() - 1);
+    T running{};
+    for (size_t i = 0; i < result.size(); ++i) {
+      running = running + diff_[i];
+      result[i] = running;
+    }
+    return result;
+  }
+
+  size_t size() const { return diff_.size() - 1; }
+
+ private:
+  std::vector<T> diff_;  // One extra slot, so that a range may end at size()
+};
+
+// Number of subarrays summing to k. A subarray [i, j) sums to k exactly when
+// prefix[j] - prefix[i] == k, so at each j, count the earlier prefixes
+// equal to prefix[j] - k. Negative values are fine, unlike with a sliding
+// window. O(n) expected.
+inline uint64_t count_subarrays_with_sum(const std::vector<int64_t>& nums,
+                                         int64_t k) {
+  std::unordered_map<int64_t, uint64_t> seen = {{0, 1}};  // The empty prefix
+  int64_t prefix = 0;
+  uint64_t count = 0;
+  for (int64_t x : nums) {
+    prefix += x;
+    if (auto it = seen.find(prefix - k); it != seen.end()) {
+      count += it->second;
+    }
+    ++seen[prefix];
+  }
+  return count;
+}
+
+// O(n^2)
+inline uint64_t count_subarrays_with_sum_naive(const std::vector<int64_t>& nums,
+                                               int64_t k) {
+  uint64_t count = 0;
+  for (size_t i = 0; i < nums.size(); ++i) {
+    int64_t sum = 0;
+    for (size_t j = i; j < nums.size(); ++j) {
+      sum += nums[j];
+      count += sum == k;
+    }
+  }
+  return count;
+}
@@ -0,0 +1,121 @@
+#include <cstdint>
+#include <random>
+#include <utility>
+#include <vector>
+
+#include "PrefixSums.h"
+#include "gtest/gtest.h"
+
+TEST(PrefixSums, RangeSums) {
+  PrefixSums<int> sums({3, -1, 4, 1, 5});
+  EXPECT_EQ(sums.size(), 5u);
+  EXPECT_EQ(sums.total(), 12);
+  EXPECT_EQ(sums.sum(0, 5), 12);
+  EXPECT_EQ(sums.sum(1, 3), 3);
+  EXPECT_EQ(sums.sum(2, 2), 0);
+  EXPECT_EQ(PrefixSums<double>({}).total(), 0.0);
+
+  std::mt19937 gen(157);
+  std::uniform_int_distribution<int64_t> value(-100, 100);
+  std::vector<int64_t> values(50);
+  for (auto& v : values) {
+    v = value(gen);
+  }
+  PrefixSums<int64_t> random(values);
+  for (size_t begin = 0; begin <= values.size(); ++begin) {
+    int64_t expected = 0;
+    for (size_t end = begin; end <= values.size(); ++end) {
+      ASSERT_EQ(random.sum(begin, end), expected);
+      if (end < values.size()) {
+        expected += values[end];
+      }
+    }
+  }
+}
+
+TEST(PrefixSums, RectangleSums) {
+  std::vector<std::vector<int>> grid = {
+      {3, 0, 1, 4, 2},
+      {5, 6, 3, 2, 1},
+      {1, 2, 0, 1, 5},
+      {4, 1, 0, 1, 7},
+      {1, 0, 3, 0, 5},
+  };
+  PrefixSums2D<int> sums(grid);
+  EXPECT_EQ(sums.rows(), 5u);
+  EXPECT_EQ(sums.cols(), 5u);
+  EXPECT_EQ(sums.sum(2, 1, 5, 4), 8);
+  EXPECT_EQ(sums.sum(1, 1, 3, 3), 11);
+  EXPECT_EQ(sums.sum(1, 2, 3, 5), 12);
+  EXPECT_EQ(sums.sum(0, 0, 0, 5), 0);
+
+  for (size_t r0 = 0; r0 <= 5; ++r0) {
+    for (size_t c0 = 0; c0 <= 5; ++c0) {
+      for (size_t r1 = r0; r1 <= 5; ++r1) {
+        for (size_t c1 = c0; c1 <= 5; ++c1) {
+          int expected = 0;
+          for (size_t r = r0; r < r1; ++r) {
+            for (size_t c = c0; c < c1; ++c) {
+              expected += grid[r][c];
+            }
+          }
+          ASSERT_EQ(sums.sum(r0, c0, r1, c1), expected);
+        }
+      }
+    }
+  }
+  EXPECT_EQ(PrefixSums2D<int>({}).rows(), 0u);
+}
+
+TEST(PrefixSums, DifferenceArray) {
+  DifferenceArray<int> diff(std::vector<int>{1, 2, 3, 4});
+  EXPECT_EQ(diff.values(), (std::vector<int>{1, 2, 3, 4}));
+  diff.add(1, 3, 10);
+  diff.add(0, 4, -1);
+  diff.add(3, 4, 5);
+  EXPECT_EQ(diff.values(), (std::vector<int>{0, 11, 12, 8}));
+
+  std::mt19937 gen(158);
+  const size_t n = 40;
+  DifferenceArray<int64_t> random(n);
+  std::vector<int64_t> expected(n, 0);
+  std::uniform_int_distribution<size_t> position(0, n);
+  std::uniform_int_distribution<int64_t> delta(-50, 50);
+  for (int update = 0; update < 500; ++update) {
+    size_t begin = position(gen);
+    size_t end = position(gen);
+    if (begin > end) {
+      std::swap(begin, end);
+    }
+    const int64_t d = delta(gen);
+    random.add(begin, end, d);
+    for (size_t i = begin; i < end; ++i) {
+      expected[i] += d;
+    }
+  }
+  EXPECT_EQ(random.values(), expected);
+}
+
+TEST(PrefixSums, SubarraysWithSum) {
+  EXPECT_EQ(count_subarrays_with_sum({1, 1, 1}, 2), 2u);
+  EXPECT_EQ(count_subarrays_with_sum({1, 2, 3}, 3), 2u);
+  EXPECT_EQ(count_subarrays_with_sum({0, 0, 0}, 0), 6u);
+  EXPECT_EQ(count_subarrays_with_sum({}, 0), 0u);
+
+  std::mt19937 gen(159);
+  std::uniform_int_distribution<int64_t> value(-3, 3);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<int64_t> nums(gen() % 40);
+    for (auto& x : nums) {
+      x = value(gen);
+    }
+    const int64_t k = std::uniform_int_distribution<int64_t>(-4, 4)(gen);
+    ASSERT_EQ(count_subarrays_with_sum(nums, k),
+              count_subarrays_with_sum_naive(nums, k));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
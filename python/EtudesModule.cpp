@@ -0,0 +1,163 @@
+// The sequence, tree, and combinatorics etudes as a Python module, for
+// calling from a notebook next to one's own Python solutions. Needs
+// pybind11 (pip install pybind11); `make` builds etudes.*.so here, and then
+//
+//   >>> import etudes
+//   >>> etudes.max_subarray([-2, 1, -3, 4, -1, 2, 1, -5, 4])
+//   (6, 3, 7)
+//
+// Python lists come in as vectors, and trees as LeetCode-style level-order
+// lists with None for a missing child. Bad arguments raise ValueError
+// instead of tripping the C++ asserts, which would take the interpreter
+// down with them.
+
+#include <pybind11/pybind11.h>
+#include <pybind11/stl.h>
+
+#include <cstdint>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <tuple>
+#include <vector>
+
+#include "../binary-tree/BalanceFactors.h"
+#include "../binary-tree/Traversal.h"
+#include "../combinatorics/Catalan.h"
+#include "../combinatorics/Counting.h"
+#include "../combinatorics/Derangements.h"
+#include "../combinatorics/Permutation.h"
+#include "../parse/Parse.h"
+#include "../patience/Patience.h"
+#include "../sequences/Sequences.h"
+
+namespace py = pybind11;
+
+using LevelOrder = std::vector<std::optional<int64_t>>;
+
+static void check_range(int n, int lo, int hi, const char* what) {
+  if (n < lo || n > hi) {
+    throw std::invalid_argument(std::string(what) + " needs " +
+                                std::to_string(lo) + " <= n <= " +
+                                std::to_string(hi));
+  }
+}
+
+PYBIND11_MODULE(etudes, m) {
+  m.doc() = "Algorithm etudes, implemented in C++";
+
+  // Sequences
+
+  m.def(
+      "max_subarray",
+      [](const std::vector<int64_t>& nums) {
+        const Subarray best = max_subarray(nums);
+        return std::make_tuple(best.sum, best.begin, best.end);
+      },
+      py::arg("nums"),
+      "(sum, begin, end) of a maximum run nums[begin:end], by Kadane's "
+      "algorithm. The empty run counts, so the sum is never negative.");
+  m.def("evaluate_polynomial", &evaluate_polynomial, py::arg("coefficients"),
+        py::arg("x"),
+        "P(x) by Horner's rule, coefficients highest degree first.");
+  m.def(
+      "longest_increasing_subsequence",
+      [](const std::vector<int64_t>& values) {
+        return longest_increasing_subsequence(values);
+      },
+      py::arg("values"),
+      "One longest strictly increasing subsequence, by patience sorting.");
+  m.def(
+      "patience_piles",
+      [](const std::vector<int64_t>& cards) { return patience_piles(cards); },
+      py::arg("cards"), "The piles after dealing, each listed bottom first.");
+  m.def(
+      "patience_sort",
+      [](const std::vector<int64_t>& cards) { return patience_sort(cards); },
+      py::arg("cards"), "The cards in increasing order.");
+
+  // Trees
+
+  m.def(
+      "balance_factors",
+      [](const LevelOrder& tree) {
+        return compute_balance_factors(tree_from_level_order(tree).get());
+      },
+      py::arg("tree"),
+      "Left height minus right height for each node, in postorder.");
+  m.def(
+      "is_height_balanced",
+      [](const LevelOrder& tree) {
+        return is_height_balanced(tree_from_level_order(tree).get());
+      },
+      py::arg("tree"), "Whether every balance factor is -1, 0, or 1.");
+  m.def(
+      "height",
+      [](const LevelOrder& tree) {
+        return height(tree_from_level_order(tree).get());
+      },
+      py::arg("tree"), "Nodes on the longest root-to-leaf path.");
+  m.def(
+      "inorder",
+      [](const LevelOrder& tree) {
+        return inorder(tree_from_level_order(tree).get());
+      },
+      py::arg("tree"), "The values in inorder.");
+
+  // Combinatorics
+
+  m.def(
+      "factorial",
+      [](int n) {
+        check_range(n, 0, 20, "factorial");
+        return factorial(n);
+      },
+      py::arg("n"));
+  m.def(
+      "binomial", [](int64_t n, int64_t k) { return binomial(n, k); },
+      py::arg("n"), py::arg("k"),
+      "C(n, k); raises OverflowError past 64 bits.");
+  m.def(
+      "catalan",
+      [](int n) {
+        check_range(n, 0, 35, "catalan");
+        return catalan(n);
+      },
+      py::arg("n"), "The nth Catalan number.");
+  m.def(
+      "bell",
+      [](int n) {
+        check_range(n, 0, 1000, "bell");
+        return py::int_(py::str(bell<BigUnsigned>(n).to_string()));
+      },
+      py::arg("n"), "The number of partitions of an n-element set.");
+  m.def(
+      "count_derangements",
+      [](int n) {
+        check_range(n, 0, 10000, "count_derangements");
+        return py::int_(py::str(count_derangements_exact(n)));
+      },
+      py::arg("n"), "Permutations of n elements with no fixed point.");
+  m.def(
+      "derangements",
+      [](const std::vector<int64_t>& values) {
+        check_range(static_cast<int>(values.size()), 0, 10, "derangements");
+        return derangements(values);
+      },
+      py::arg("values"), "Every derangement of values, for up to 10.");
+  m.def(
+      "permutation_rank",
+      [](const std::vector<int>& perm) {
+        check_range(static_cast<int>(perm.size()), 0, 20, "permutation_rank");
+        return permutation_rank(perm);
+      },
+      py::arg("perm"),
+      "Position of a permutation of 0..n-1 in lexicographic order.");
+  m.def(
+      "permutation_unrank",
+      [](int n, uint64_t rank) {
+        check_range(n, 0, 20, "permutation_unrank");
+        return permutation_unrank(n, rank);
+      },
+      py::arg("n"), py::arg("rank"), "Inverse of permutation_rank.");
+}
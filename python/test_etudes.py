@@ -0,0 +1,116 @@
+"""Checks the etudes module against plain Python versions of each etude.
+
+Run with `make test` after `make`.
+"""
+
+import itertools
+import math
+import random
+import unittest
+
+import etudes
+
+
+def max_subarray_naive(nums):
+    best = (0, 0, 0)
+    for begin in range(len(nums)):
+        for end in range(begin + 1, len(nums) + 1):
+            if sum(nums[begin:end]) > best[0]:
+                best = (sum(nums[begin:end]), begin, end)
+    return best
+
+
+def count_derangements_naive(n):
+    return sum(
+        all(p[i] != i for i in range(n))
+        for p in itertools.permutations(range(n)))
+
+
+class SequencesTest(unittest.TestCase):
+
+    def test_max_subarray(self):
+        self.assertEqual(
+            etudes.max_subarray([-2, 1, -3, 4, -1, 2, 1, -5, 4]), (6, 3, 7))
+        self.assertEqual(etudes.max_subarray([]), (0, 0, 0))
+        rng = random.Random(175)
+        for _ in range(200):
+            nums = [rng.randint(-10, 10) for _ in range(rng.randint(0, 12))]
+            self.assertEqual(
+                etudes.max_subarray(nums)[0], max_subarray_naive(nums)[0])
+
+    def test_evaluate_polynomial(self):
+        # 2x^2 - 3x + 1
+        self.assertEqual(etudes.evaluate_polynomial([2, -3, 1], 4), 21)
+
+    def test_patience(self):
+        cards = [9, 4, 7, 2, 5, 8, 1, 6, 3]
+        self.assertEqual(etudes.patience_sort(cards), sorted(cards))
+        self.assertEqual(
+            len(etudes.longest_increasing_subsequence(cards)),
+            len(etudes.patience_piles(cards)))
+        lis = etudes.longest_increasing_subsequence(cards)
+        self.assertEqual(lis, sorted(set(lis)))
+
+
+class TreesTest(unittest.TestCase):
+
+    def test_balance_factors(self):
+        tree = [3, 9, 20, None, None, 15, 7]
+        self.assertEqual(etudes.inorder(tree), [9, 3, 15, 20, 7])
+        self.assertEqual(etudes.height(tree), 3)
+        self.assertEqual(etudes.balance_factors(tree), [0, 0, 0, 0, -1])
+        self.assertTrue(etudes.is_height_balanced(tree))
+        self.assertFalse(etudes.is_height_balanced([1, 2, None, 3]))
+        self.assertEqual(etudes.height([]), 0)
+
+    def test_bad_tree(self):
+        with self.assertRaises(ValueError):
+            etudes.inorder([None, 1])
+        with self.assertRaises(ValueError):
+            etudes.inorder([1, None, None, 2])
+
+
+class CombinatoricsTest(unittest.TestCase):
+
+    def test_counts(self):
+        for n in range(21):
+            self.assertEqual(etudes.factorial(n), math.factorial(n))
+        for n in range(15):
+            for k in range(n + 1):
+                self.assertEqual(etudes.binomial(n, k), math.comb(n, k))
+        for n in range(36):
+            self.assertEqual(
+                etudes.catalan(n), math.comb(2 * n, n) // (n + 1))
+        self.assertEqual(
+            [etudes.bell(n) for n in range(8)], [1, 1, 2, 5, 15, 52, 203, 877])
+
+    def test_derangements(self):
+        for n in range(8):
+            self.assertEqual(
+                etudes.count_derangements(n), count_derangements_naive(n))
+        # Exact past 64 bits, as a Python int
+        self.assertEqual(
+            etudes.count_derangements(30), round(math.factorial(30) / math.e))
+        self.assertEqual(
+            etudes.derangements([1, 2, 3]), [[2, 3, 1], [3, 1, 2]])
+
+    def test_permutation_rank(self):
+        for rank, perm in enumerate(itertools.permutations(range(5))):
+            self.assertEqual(etudes.permutation_rank(list(perm)), rank)
+            self.assertEqual(etudes.permutation_unrank(5, rank), list(perm))
+
+    def test_bad_arguments(self):
+        with self.assertRaises(ValueError):
+            etudes.factorial(21)
+        with self.assertRaises(ValueError):
+            etudes.catalan(-1)
+        with self.assertRaises(ValueError):
+            etudes.permutation_rank([0, 0])
+        with self.assertRaises(ValueError):
+            etudes.permutation_unrank(3, 6)
+        with self.assertRaises(OverflowError):
+            etudes.binomial(100, 50)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,240 @@
+#pragma once
+
+#include <array>
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <memory>
+#include <vector>
+
+// A point with a mass; for counting, every mass is 1
+struct Body {
+  double x;
+  double y;
+  double mass = 1;
+
+  bool operator==(const Body&) const = default;
+};
+
+// The box [min_x, max_x] × [min_y, max_y]
+struct Rect {
+  double min_x;
+  double min_y;
+  double max_x;
+  double max_y;
+
+  bool contains(double x, double y) const {
+    return min_x <= x && x <= max_x && min_y <= y && y <= max_y;
+  }
+
+  bool intersects(const Rect& other) const {
+    return min_x <= other.max_x && other.min_x <= max_x &&
+           min_y <= other.max_y && other.min_y <= max_y;
+  }
+};
+
+// The sum of mass / distance from (x, y) over bodies, skipping any at
+// distance 0: the gravitational potential, up to sign and a constant
+inline double potential_of(const std::vector<Body>& bodies, double x,
+                           double y) {
+  double sum = 0;
+  for (const Body& b : bodies) {
+    const double d = std::hypot(b.x - x, b.y - y);
+    if (d > 0) {
+      sum += b.mass / d;
+    }
+  }
+  return sum;
+}
+
+// Quadtree: a square region, split into four quadrants once it holds too
+// many points, and each quadrant split again the same way. Dense regions
+// get deep, fine cells and empty ones stay a single leaf, so it adapts to
+// clustered points in a way a fixed grid can't.
+//
+// Every cell also keeps the total mass and center of mass of everything in
+// it, which is what Barnes-Hut needs: from far enough away, a whole cell
+// pulls like one body at its center of mass. "Far enough" is s / d < theta
+// for a cell of width s at distance d, so theta = 0 is the exact O(n) sum,
+// and theta around 0.5 visits O(log n) cells for well-spread points.
+class Quadtree {
+ public:
+  // Leaves split once they hold more than leaf_capacity points, except at
+  // max_depth, where many copies of one point would split forever
+  explicit Quadtree(Rect bounds, size_t leaf_capacity = 4, int max_depth = 24)
+      : root_(std::make_unique<Node>(bounds)),
+        leaf_capacity_(leaf_capacity),
+        max_depth_(max_depth) {
+    assert(leaf_capacity > 0 && "leaves must hold something");
+  }
+
+  size_t size() const { return root_->count; }
+
+  // False, and nothing added, if body is outside the bounds
+  bool insert(const Body& body) {
+    if (!root_->bounds.contains(body.x, body.y)) {
+      return false;
+    }
+    Node* node = root_.get();
+    for (int depth = 0;; ++depth) {
+      node->add(body);
+      if (node->is_leaf()) {
+        node->bodies.push_back(body);
+        if (node->bodies.size() > leaf_capacity_ && depth < max_depth_) {
+          node->split();
+        }
+        return true;
+      }
+      node = node->children[node->quadrant(body.x, body.y)].get();
+    }
+  }
+
+  // Every body inside region, in no particular order
+  std::vector<Body> in_region(const Rect& region) const {
+    std::vector<Body> out;
+    in_region(root_.get(), region, out);
+    return out;
+  }
+
+  // The total mass and center of mass of everything, as one body; mass 0
+  // at the center of the bounds if there's nothing
+  Body total() const { return root_->aggregate(); }
+
+  // The bodies that Barnes-Hut would see from (x, y): whole cells that pass
+  // the s / d < theta test as their aggregates, and single bodies from the
+  // leaves that don't. Their masses add up to the total either way.
+  std::vector<Body> barnes_hut(double x, double y, double theta) const {
+    std::vector<Body> out;
+    barnes_hut(root_.get(), x, y, theta, out);
+    return out;
+  }
+
+  // The potential at (x, y) from the Barnes-Hut bodies
+  double potential(double x, double y, double theta) const {
+    return potential_of(barnes_hut(x, y, theta), x, y);
+  }
+
+  // Length of the longest root-to-leaf path
+  size_t height() const { return height(root_.get()); }
+
+ private:
+  struct Node {
+    Rect bounds;
+    size_t count = 0;
+    double mass = 0;
+    double moment_x = 0;  // Sum of mass * x
+    double moment_y = 0;
+    std::vector<Body> bodies;  // Only in leaves
+    std::array<std::unique_ptr<Node>, 4> children;
+
+    explicit Node(Rect b) : bounds(b) {}
+
+    bool is_leaf() const { return !children[0]; }
+
+    void add(const Body& body) {
+      ++count;
+      mass += body.mass;
+      moment_x += body.mass * body.x;
+      moment_y += body.mass * body.y;
+    }
+
+    Body aggregate() const {
+      if (mass == 0) {
+        return {(bounds.min_x + bounds.max_x) / 2,
+                (bounds.min_y + bounds.max_y) / 2, 0};
+      }
+      return {moment_x / mass, moment_y / mass, mass};
+    }
+
+    double mid_x() const { return (bounds.min_x + bounds.max_x) / 2; }
+    double mid_y() const { return (bounds.min_y + bounds.max_y) / 2; }
+
+    // 0 southwest, 1 southeast, 2 northwest, 3 northeast; points on a
+    // dividing line go east or north
+    size_t quadrant(double x, double y) const {
+      return (x < mid_x() ? 0 : 1) + (y < mid_y() ? 0 : 2);
+    }
+
+    void split() {
+      const double mx = mid_x();
+      const double my = mid_y();
+      const Rect& b = bounds;
+      children[0] = std::make_unique<Node>(Rect{b.min_x, b.min_y, mx, my});
+      children[1] = std::make_unique<Node>(Rect{mx, b.min_y, b.max_x, my});
+      children[2] = std::make_unique<Node>(Rect{b.min_x, my, mx, b.max_y});
+      children[3] = std::make_unique<Node>(Rect{mx, my, b.max_x, b.max_y});
+      for (const Body& body : bodies) {
+        Node* child = children[quadrant(body.x, body.y)].get();
+        child->add(body);
+        child->bodies.push_back(body);
+      }
+      bodies.clear();
+      bodies.shrink_to_fit();
+    }
+  };
+
+  static void in_region(const Node* node, const Rect& region,
+                        std::vector<Body>& out) {
+    if (node->count == 0 || !node->bounds.intersects(region)) {
+      return;
+    }
+    for (const Body& body : node->bodies) {
+      if (region.contains(body.x, body.y)) {
+        out.push_back(body);
+      }
+    }
+    if (!node->is_leaf()) {
+      for (const auto& child : node->children) {
+        in_region(child.get(), region, out);
+      }
+    }
+  }
+
+  static void barnes_hut(const Node* node, double x, double y, double theta,
+                         std::vector<Body>& out) {
+    if (node->count == 0) {
+      return;
+    }
+    const Body center = node->aggregate();
+    const double width = node->bounds.max_x - node->bounds.min_x;
+    const double d = std::hypot(center.x - x, center.y - y);
+    if (node->count == 1 || width < theta * d) {
+      out.push_back(center);
+      return;
+    }
+    if (node->is_leaf()) {
+      out.insert(out.end(), node->bodies.begin(), node->bodies.end());
+      return;
+    }
+    for (const auto& child : node->children) {
+      barnes_hut(child.get(), x, y, theta, out);
+    }
+  }
+
+  static size_t height(const Node* node) {
+    size_t tallest = 0;
+    if (!node->is_leaf()) {
+      for (const auto& child : node->children) {
+        const size_t h = height(child.get());
+        tallest = h > tallest ? h : tallest;
+      }
+    }
+    return 1 + tallest;
+  }
+
+  std::unique_ptr<Node> root_;
+  size_t leaf_capacity_;
+  int max_depth_;
+};
+
+// O(n), for the tests
+inline std::vector<Body> in_region_naive(const std::vector<Body>& bodies,
+                                         const Rect& region) {
+  std::vector<Body> out;
+  for (const Body& b : bodies) {
+    if (region.contains(b.x, b.y)) {
+      out.push_back(b);
+    }
+  }
+  return out;
+}
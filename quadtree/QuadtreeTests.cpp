@@ -0,0 +1,121 @@
+#include <algorithm>
+#include <cmath>
+#include <cstddef>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Quadtree.h"
+#include "gtest/gtest.h"
+
+const Rect kBounds = {0, 0, 100, 100};
+
+std::vector<Body> uniform_bodies(Rng& gen, size_t n) {
+  std::vector<Body> bodies(n);
+  for (auto& b : bodies) {
+    b = {100 * uniform_unit(gen), 100 * uniform_unit(gen),
+         1 + uniform_unit(gen)};
+  }
+  return bodies;
+}
+
+// A few tight clusters: each coordinate a cluster center plus the mean of
+// four uniforms, which piles up near the center
+std::vector<Body> clustered_bodies(Rng& gen, size_t n) {
+  const std::vector<Body> centers = {{20, 20}, {75, 30}, {50, 80}};
+  std::vector<Body> bodies(n);
+  for (auto& b : bodies) {
+    const Body& c = centers[uniform_below(gen, centers.size())];
+    auto spread = [&] {
+      double sum = 0;
+      for (int i = 0; i < 4; ++i) {
+        sum += uniform_unit(gen) - 0.5;
+      }
+      return 10 * sum / 4;
+    };
+    b = {c.x + spread(), c.y + spread(), 1};
+  }
+  return bodies;
+}
+
+bool by_position(const Body& a, const Body& b) {
+  return a.x != b.x ? a.x < b.x : a.y != b.y ? a.y < b.y : a.mass < b.mass;
+}
+
+void check(const std::vector<Body>& bodies, Rng& gen) {
+  Quadtree tree(kBounds);
+  for (const Body& b : bodies) {
+    ASSERT_TRUE(tree.insert(b));
+  }
+  ASSERT_EQ(tree.size(), bodies.size());
+
+  for (int q = 0; q < 100; ++q) {
+    const double x = 100 * uniform_unit(gen);
+    const double y = 100 * uniform_unit(gen);
+    const Rect region = {x, y, x + 30 * uniform_unit(gen),
+                         y + 30 * uniform_unit(gen)};
+    auto found = tree.in_region(region);
+    auto expected = in_region_naive(bodies, region);
+    std::sort(found.begin(), found.end(), by_position);
+    std::sort(expected.begin(), expected.end(), by_position);
+    ASSERT_EQ(found, expected);
+  }
+
+  double mass = 0;
+  double moment_x = 0;
+  for (const Body& b : bodies) {
+    mass += b.mass;
+    moment_x += b.mass * b.x;
+  }
+  const Body total = tree.total();
+  EXPECT_NEAR(total.mass, mass, 1e-9 * mass);
+  EXPECT_NEAR(total.x, moment_x / mass, 1e-9);
+
+  // theta = 0 sees every body; a sensible theta sees far fewer, with the
+  // same mass and a potential within a percent
+  const double x = 100 * uniform_unit(gen);
+  const double y = 100 * uniform_unit(gen);
+  EXPECT_EQ(tree.barnes_hut(x, y, 0).size(), bodies.size());
+  const auto seen = tree.barnes_hut(x, y, 0.5);
+  EXPECT_LT(seen.size(), bodies.size() / 4);
+  double seen_mass = 0;
+  for (const Body& b : seen) {
+    seen_mass += b.mass;
+  }
+  EXPECT_NEAR(seen_mass, mass, 1e-9 * mass);
+  const double exact = potential_of(bodies, x, y);
+  EXPECT_NEAR(tree.potential(x, y, 0), exact, 1e-9 * exact);
+  EXPECT_NEAR(tree.potential(x, y, 0.5), exact, 0.01 * exact);
+}
+
+TEST(Quadtree, Uniform) {
+  Rng gen(190);
+  check(uniform_bodies(gen, 5000), gen);
+}
+
+TEST(Quadtree, Clustered) {
+  Rng gen(190);
+  check(clustered_bodies(gen, 5000), gen);
+}
+
+TEST(Quadtree, EdgesAndDuplicates) {
+  Quadtree tree(kBounds, 2, 10);
+  EXPECT_EQ(tree.total().mass, 0);
+  EXPECT_FALSE(tree.insert({100.5, 50}));
+  EXPECT_TRUE(tree.insert({100, 100}));
+  EXPECT_TRUE(tree.insert({0, 0}));
+  // Copies of one point stop splitting at the maximum depth
+  for (int i = 0; i < 100; ++i) {
+    ASSERT_TRUE(tree.insert({50, 50}));
+  }
+  EXPECT_EQ(tree.size(), 102u);
+  EXPECT_LE(tree.height(), 11u);
+  EXPECT_EQ(tree.in_region({50, 50, 50, 50}).size(), 100u);
+  EXPECT_EQ(tree.in_region({99, 99, 100, 100}),
+            (std::vector<Body>{{100, 100}}));
+  EXPECT_EQ(tree.barnes_hut(50, 50, 0).size(), 102u);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,42 @@
+#pragma once
+
+#include <chrono>
+#include <concepts>
+
+// Rate limiters decide, request by request, whether a request may go ahead
+// now or has to be turned away. A type is a RateLimiter if try_acquire()
+// answers that for one request, counting it if it's allowed.
+//
+// The limiters read the time from a Clock, anything with a now() that
+// returns the time since some fixed starting point. SteadyClock is the real
+// one; ManualClock only moves when told to, so a test can replay a whole
+// schedule of requests with exact timings and no sleeping.
+//
+// None of this is thread-safe: share a limiter behind a mutex.
+template <typename L>
+concept RateLimiter = requires(L limiter) {
+  { limiter.try_acquire() } -> std::same_as<bool>;
+};
+
+using Duration = std::chrono::nanoseconds;
+
+template <typename C>
+concept Clock = requires(const C& clock) {
+  { clock.now() } -> std::convertible_to<Duration>;
+};
+
+struct SteadyClock {
+  Duration now() const {
+    return std::chrono::steady_clock::now().time_since_epoch();
+  }
+};
+
+class ManualClock {
+ public:
+  Duration now() const { return now_; }
+
+  void advance(Duration by) { now_ += by; }
+
+ private:
+  Duration now_{0};
+};
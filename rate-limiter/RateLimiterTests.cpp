@@ -0,0 +1,124 @@
+#include <algorithm>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <random>
+#include <vector>
+
+#include "RateLimiter.h"
+#include "SlidingWindowLog.h"
+#include "TokenBucket.h"
+#include "gtest/gtest.h"
+
+using namespace std::chrono_literals;
+
+static_assert(RateLimiter<TokenBucket<>>);
+static_assert(RateLimiter<SlidingWindowLog<ManualClock>>);
+
+// Sends one request at each of the given gaps after the previous one, and
+// returns which were allowed. Works for any limiter with a ManualClock.
+template <RateLimiter L>
+std::vector<bool> replay(L& limiter, const std::vector<Duration>& gaps) {
+  std::vector<bool> allowed;
+  for (Duration gap : gaps) {
+    limiter.clock().advance(gap);
+    allowed.push_back(limiter.try_acquire());
+  }
+  return allowed;
+}
+
+TEST(RateLimiter, TokenBucketBurstThenRate) {
+  TokenBucket<ManualClock> bucket(3, 2);  // Bursts of 3, then 2 per second
+
+  // The full bucket lets a burst of three through at once
+  EXPECT_EQ(replay(bucket, {0ms, 0ms, 0ms, 0ms}),
+            (std::vector<bool>{true, true, true, false}));
+  // One token every half second
+  EXPECT_EQ(replay(bucket, {499ms, 1ms, 0ms}),
+            (std::vector<bool>{false, true, false}));
+  // A long pause refills it only up to capacity
+  bucket.clock().advance(1h);
+  EXPECT_DOUBLE_EQ(bucket.tokens(), 3);
+  EXPECT_TRUE(bucket.try_acquire(2.5));
+  EXPECT_FALSE(bucket.try_acquire(1));
+}
+
+TEST(RateLimiter, SlidingWindowLogLimit) {
+  SlidingWindowLog<ManualClock> log(2, 1s);  // 2 in any second
+
+  EXPECT_EQ(replay(log, {0ms, 400ms, 400ms}),
+            (std::vector<bool>{true, true, false}));
+  EXPECT_EQ(log.in_window(), 2u);
+  // The first request slides out exactly one second after it was made
+  EXPECT_EQ(replay(log, {199ms, 1ms, 0ms}),
+            (std::vector<bool>{false, true, false}));
+  EXPECT_EQ(log.in_window(), 2u);
+  log.clock().advance(1s);
+  EXPECT_EQ(log.in_window(), 0u);
+}
+
+TEST(RateLimiter, NeverExceedsTheLimit) {
+  // Under random traffic, every one-second window holds at most `limit`
+  // allowed requests for the log, and at most capacity + rate for the bucket
+  std::mt19937 gen(163);
+  std::exponential_distribution<double> gap_ms(1.0 / 20);
+  std::vector<Duration> gaps(5000);
+  for (auto& gap : gaps) {
+    gap = std::chrono::microseconds(static_cast<int64_t>(gap_ms(gen) * 1000));
+  }
+
+  auto max_in_any_second = [&](const std::vector<bool>& allowed) {
+    std::vector<Duration> times;
+    Duration now{0};
+    for (size_t i = 0; i < gaps.size(); ++i) {
+      now += gaps[i];
+      if (allowed[i]) {
+        times.push_back(now);
+      }
+    }
+    size_t most = 0;
+    for (size_t begin = 0, end = 0; end < times.size(); ++end) {
+      while (times[begin] <= times[end] - 1s) {
+        ++begin;
+      }
+      most = std::max(most, end - begin + 1);
+    }
+    return most;
+  };
+
+  SlidingWindowLog<ManualClock> log(10, 1s);
+  const auto by_log = replay(log, gaps);
+  EXPECT_EQ(max_in_any_second(by_log), 10u);
+
+  TokenBucket<ManualClock> bucket(5, 10);
+  const auto by_bucket = replay(bucket, gaps);
+  EXPECT_LE(max_in_any_second(by_bucket), 15u);
+
+  // Both let through about 10 per second of the ~50 per second offered
+  Duration total{0};
+  for (Duration gap : gaps) {
+    total += gap;
+  }
+  const double seconds = std::chrono::duration<double>(total).count();
+  const auto allowed = [](const std::vector<bool>& v) {
+    return static_cast<double>(std::count(v.begin(), v.end(), true));
+  };
+  EXPECT_NEAR(allowed(by_log) / seconds, 10, 1);
+  EXPECT_NEAR(allowed(by_bucket) / seconds, 10, 1);
+}
+
+TEST(RateLimiter, SteadyClock) {
+  // Enough capacity that real time can't matter
+  TokenBucket<> bucket(100, 1);
+  SlidingWindowLog<> log(100, 1h);
+  for (int i = 0; i < 100; ++i) {
+    ASSERT_TRUE(bucket.try_acquire());
+    ASSERT_TRUE(log.try_acquire());
+  }
+  EXPECT_FALSE(log.try_acquire());
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,54 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <deque>
+#include <utility>
+
+#include "RateLimiter.h"
+
+// Sliding window log: allows at most `limit` requests in any window of
+// length `window`, by keeping the time of every request allowed in the
+// last window. No bursts beyond the limit, however quiet it's been, unlike
+// a token bucket; the price is O(limit) memory for the log. Each call drops
+// the entries that have slid out of the window, so it's O(1) amortized.
+template <Clock C = SteadyClock>
+class SlidingWindowLog {
+ public:
+  SlidingWindowLog(size_t limit, Duration window, C clock = C())
+      : limit_(limit), window_(window), clock_(std::move(clock)) {
+    assert(limit > 0 && window > Duration::zero());
+  }
+
+  bool try_acquire() {
+    const Duration now = clock_.now();
+    forget_before(now);
+    if (log_.size() == limit_) {
+      return false;
+    }
+    log_.push_back(now);
+    return true;
+  }
+
+  // Requests allowed in the window ending now
+  size_t in_window() {
+    forget_before(clock_.now());
+    return log_.size();
+  }
+
+  C& clock() { return clock_; }
+
+ private:
+  // The window is (now - window, now]: a request exactly one window ago
+  // no longer counts
+  void forget_before(Duration now) {
+    while (!log_.empty() && log_.front() <= now - window_) {
+      log_.pop_front();
+    }
+  }
+
+  size_t limit_;
+  Duration window_;
+  C clock_;
+  std::deque<Duration> log_;  // Oldest first
+};
@@ -0,0 +1,62 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <chrono>
+#include <cstddef>
+#include <utility>
+
+#include "RateLimiter.h"
+
+// Token bucket: a bucket holds up to `capacity` tokens and refills at `rate`
+// tokens per second; each request takes a token, and is refused if the
+// bucket is empty. Over a long time that allows `rate` requests per second
+// on average, while a quiet spell lets up to `capacity` requests through
+// in a burst. O(1) time and space: the refill is worked out lazily, from
+// the time elapsed since the last request.
+template <Clock C = SteadyClock>
+class TokenBucket {
+ public:
+  // The bucket starts full
+  TokenBucket(double capacity, double rate, C clock = C())
+      : capacity_(capacity),
+        rate_(rate),
+        tokens_(capacity),
+        clock_(std::move(clock)),
+        last_(clock_.now()) {
+    assert(capacity >= 1 && rate > 0);
+  }
+
+  bool try_acquire() { return try_acquire(1.0); }
+
+  // For requests that weigh more than others, e.g. by size in bytes
+  bool try_acquire(double cost) {
+    refill();
+    if (tokens_ < cost) {
+      return false;
+    }
+    tokens_ -= cost;
+    return true;
+  }
+
+  double tokens() {
+    refill();
+    return tokens_;
+  }
+
+  C& clock() { return clock_; }
+
+ private:
+  void refill() {
+    const Duration now = clock_.now();
+    const std::chrono::duration<double> elapsed = now - last_;
+    tokens_ = std::min(capacity_, tokens_ + elapsed.count() * rate_);
+    last_ = now;
+  }
+
+  double capacity_;
+  double rate_;  // Tokens per second
+  double tokens_;
+  C clock_;
+  Duration last_;
+};
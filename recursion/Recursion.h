@@ -0,0 +1,223 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../backtracking/Backtracking.h"
+
+// Recursion to iteration. Each etude comes twice: recursive, as in manber/,
+// and with the call stack made explicit, giving the same output in the
+// same order. The transformation is always the same: a frame holds a
+// call's arguments and locals, plus how far through its body the call
+// got; a call pushes a frame, a return pops one, and the loop runs until
+// the stack is empty. The explicit stack lives on the heap, so its depth
+// is limited by memory rather than by the call stack.
+
+// All binary numbers of n digits, in increasing order, as in
+// manber/generate_binary_nums.cpp: those of n - 1 digits, each followed by
+// 0 and then by 1. O(n * 2^n).
+inline std::vector<std::string> binary_numbers_recursive(size_t n) {
+  if (n == 0) {
+    return {""};
+  }
+  std::vector<std::string> numbers;
+  for (const auto& shorter : binary_numbers_recursive(n - 1)) {
+    numbers.push_back(shorter + "0");
+    numbers.push_back(shorter + "1");
+  }
+  return numbers;
+}
+
+// The same as a search with a stack of prefixes. Pushing the 1 before the
+// 0 pops the 0 first, which keeps the order increasing.
+inline std::vector<std::string> binary_numbers_iterative(size_t n) {
+  std::vector<std::string> numbers;
+  std::vector<std::string> stack = {""};
+  while (!stack.empty()) {
+    std::string prefix = std::move(stack.back());
+    stack.pop_back();
+    if (prefix.size() == n) {
+      numbers.push_back(std::move(prefix));
+      continue;
+    }
+    stack.push_back(prefix + "1");
+    stack.push_back(prefix + "0");
+  }
+  return numbers;
+}
+
+// All permutations of word, as in manber/generate_permutations.cpp: the
+// permutations of all but the last character, with the last one inserted
+// at every position of each. Repeated characters give repeated
+// permutations. O(n * n!).
+//
+// The recursion is backtrack()'s, from backtracking/: the characters go in
+// one at a time, in word's order, and the choice is where the next one
+// goes among those already placed. Trying the positions in increasing
+// order lists the permutations of each shorter prefix in the order that
+// prefix's own search would, each followed by its insertions, as above.
+class PermutationSearch {
+ public:
+  using Choice = size_t;  // where the next character goes
+
+  explicit PermutationSearch(std::string word) : word_(std::move(word)) {}
+
+  bool is_complete() const { return current_.size() == word_.size(); }
+
+  std::vector<Choice> choices() const {
+    std::vector<Choice> positions(current_.size() + 1);
+    for (size_t i = 0; i < positions.size(); ++i) {
+      positions[i] = i;
+    }
+    return positions;
+  }
+
+  bool accepts(Choice) const { return true; }
+
+  void choose(Choice i) {
+    current_.insert(current_.begin() + i, word_[current_.size()]);
+  }
+
+  void unchoose(Choice i) { current_.erase(i, 1); }
+
+  const std::string& solution() const { return current_; }
+
+ private:
+  std::string word_;
+  std::string current_;
+};
+
+inline std::vector<std::string> permutations_recursive(
+    const std::string& word) {
+  PermutationSearch search(word);
+  return collect_solutions(search);
+}
+
+// The same, building each permutation from the front of word instead of
+// from the back: a frame is a permutation of word's first k characters
+// and the position at which character k goes in next. Frames for the
+// positions are pushed last first, so they come off in the recursive
+// version's order.
+inline std::vector<std::string> permutations_iterative(
+    const std::string& word) {
+  if (word.empty()) {
+    return {word};
+  }
+  std::vector<std::string> result;
+  std::vector<std::string> stack = {word.substr(0, 1)};
+  while (!stack.empty()) {
+    std::string partial = std::move(stack.back());
+    stack.pop_back();
+    if (partial.size() == word.size()) {
+      result.push_back(std::move(partial));
+      continue;
+    }
+    const char next = word[partial.size()];
+    for (size_t i = partial.size() + 1; i-- > 0;) {
+      stack.push_back(partial.substr(0, i) + next + partial.substr(i));
+    }
+  }
+  return result;
+}
+
+// Both versions above build every result through temporaries, two
+// substrings and a concatenation per insertion, and store n! separate
+// strings. manber/generate_permutations.h now writes each level into one
+// buffer instead; Heap's algorithm below goes further and keeps a single
+// permutation, changing it a swap at a time (see RecursionBench.cpp).
+
+// Heap's algorithm: every permutation in turn, each one swap away from the
+// last, all in one buffer. c[i] counts how many times position i has been
+// swapped with an earlier one; it's the loop counter of the recursive
+// version, kept in an array. visit sees the buffer after each swap, so
+// nothing is allocated past the copy of word and the counters, but the
+// order is different from the others'. n! - 1 swaps.
+template <typename Visit>
+void for_each_permutation_heap(std::string word, Visit visit) {
+  std::vector<size_t> c(word.size(), 0);
+  visit(std::as_const(word));
+  for (size_t i = 1; i < word.size();) {
+    if (c[i] < i) {
+      std::swap(word[i % 2 == 0 ? 0 : c[i]], word[i]);
+      visit(std::as_const(word));
+      ++c[i];
+      i = 1;
+    } else {
+      c[i] = 0;
+      ++i;
+    }
+  }
+}
+
+// Towers of Hanoi: to move n disks from one peg to another, move the top
+// n - 1 out of the way onto the third peg, move the largest, and move the
+// n - 1 back on top of it. 2^n - 1 moves, the fewest possible.
+
+struct HanoiMove {
+  int disk;  // 1 is the smallest
+  int from;
+  int to;
+
+  bool operator==(const HanoiMove&) const = default;
+};
+
+inline void hanoi_recursive(int n, int from, int to, int via,
+                            std::vector<HanoiMove>& moves) {
+  if (n == 0) {
+    return;
+  }
+  hanoi_recursive(n - 1, from, via, to, moves);
+  moves.push_back({n, from, to});
+  hanoi_recursive(n - 1, via, to, from, moves);
+}
+
+inline std::vector<HanoiMove> hanoi_recursive(int n, int from = 0,
+                                              int to = 2) {
+  assert(n >= 0 && n < 63 && from != to);
+  std::vector<HanoiMove> moves;
+  hanoi_recursive(n, from, to, 3 - from - to, moves);
+  return moves;
+}
+
+// The body is a call, a move, and another call, so a frame remembers
+// whether the first call is done. The second call is the last thing the
+// body does, so instead of pushing a frame for it, it takes over the
+// caller's: a tail call, which the recursive version can't make.
+inline std::vector<HanoiMove> hanoi_iterative(int n, int from = 0,
+                                              int to = 2) {
+  assert(n >= 0 && n < 63 && from != to);
+  struct Frame {
+    int n;
+    int from;
+    int to;
+    int via;
+    bool first_call_done;
+  };
+  std::vector<HanoiMove> moves;
+  std::vector<Frame> stack;
+  if (n > 0) {
+    stack.push_back({n, from, to, 3 - from - to, false});
+  }
+  while (!stack.empty()) {
+    Frame& frame = stack.back();
+    if (!frame.first_call_done) {
+      frame.first_call_done = true;
+      if (frame.n > 1) {
+        const Frame call = {frame.n - 1, frame.from, frame.via, frame.to,
+                            false};
+        stack.push_back(call);
+      }
+      continue;
+    }
+    moves.push_back({frame.n, frame.from, frame.to});
+    if (frame.n > 1) {
+      frame = {frame.n - 1, frame.via, frame.to, frame.from, false};
+    } else {
+      stack.pop_back();
+    }
+  }
+  return moves;
+}
@@ -0,0 +1,105 @@
+#include <algorithm>
+#include <cstddef>
+#include <set>
+#include <string>
+#include <vector>
+
+#include "Recursion.h"
+#include "gtest/gtest.h"
+
+// Each test runs on the recursive version and the iterative one alike
+
+using BinaryNumbers = std::vector<std::string> (*)(size_t);
+using Permutations = std::vector<std::string> (*)(const std::string&);
+using Hanoi = std::vector<HanoiMove> (*)(int, int, int);
+
+static const std::vector<BinaryNumbers> kBinaryNumbers = {
+    binary_numbers_recursive, binary_numbers_iterative};
+static const std::vector<Permutations> kPermutations = {
+    permutations_recursive, permutations_iterative};
+static const std::vector<Hanoi> kHanoi = {hanoi_recursive, hanoi_iterative};
+
+TEST(Recursion, BinaryNumbers) {
+  for (auto binary_numbers : kBinaryNumbers) {
+    EXPECT_EQ(binary_numbers(0), std::vector<std::string>{""});
+    EXPECT_EQ(binary_numbers(1), (std::vector<std::string>{"0", "1"}));
+    EXPECT_EQ(binary_numbers(3),
+              (std::vector<std::string>{"000", "001", "010", "011", "100",
+                                        "101", "110", "111"}));
+  }
+  for (size_t n = 0; n <= 12; ++n) {
+    ASSERT_EQ(binary_numbers_iterative(n), binary_numbers_recursive(n));
+  }
+}
+
+TEST(Recursion, Permutations) {
+  for (auto permutations : kPermutations) {
+    EXPECT_EQ(permutations(""), std::vector<std::string>{""});
+    EXPECT_EQ(permutations("A"), std::vector<std::string>{"A"});
+    // The order of manber/generate_permutations.cpp
+    EXPECT_EQ(permutations("ABC"), (std::vector<std::string>{
+                                       "CBA", "BCA", "BAC", "CAB", "ACB",
+                                       "ABC"}));
+    EXPECT_EQ(permutations("AAB").size(), 6u);
+
+    const auto all = permutations("ABCDEF");
+    EXPECT_EQ(all.size(), 720u);
+    EXPECT_EQ(std::set<std::string>(all.begin(), all.end()).size(), 720u);
+  }
+  EXPECT_EQ(permutations_iterative("ABCDEFGH"),
+            permutations_recursive("ABCDEFGH"));
+}
+
+TEST(Recursion, HeapsAlgorithm) {
+  for (const std::string word : {"", "A", "ABCD", "ABCDEFG"}) {
+    std::vector<std::string> seen;
+    for_each_permutation_heap(word, [&](const std::string& perm) {
+      if (!seen.empty()) {
+        // One swap from the one before
+        size_t differences = 0;
+        for (size_t i = 0; i < perm.size(); ++i) {
+          differences += perm[i] != seen.back()[i];
+        }
+        ASSERT_EQ(differences, 2u);
+      }
+      seen.push_back(perm);
+    });
+    auto expected = permutations_recursive(word);
+    std::sort(seen.begin(), seen.end());
+    std::sort(expected.begin(), expected.end());
+    ASSERT_EQ(seen, expected);
+  }
+}
+
+TEST(Recursion, Hanoi) {
+  for (auto hanoi : kHanoi) {
+    EXPECT_TRUE(hanoi(0, 0, 2).empty());
+    EXPECT_EQ(hanoi(2, 0, 2), (std::vector<HanoiMove>{
+                                  {1, 0, 1}, {2, 0, 2}, {1, 1, 2}}));
+
+    // Play the moves: each takes the top disk, never onto a smaller one,
+    // and all the disks end up on the target peg
+    for (int n = 1; n <= 10; ++n) {
+      std::vector<std::vector<int>> pegs(3);
+      for (int disk = n; disk >= 1; --disk) {
+        pegs[1].push_back(disk);
+      }
+      const auto moves = hanoi(n, 1, 0);
+      ASSERT_EQ(moves.size(), (size_t{1} << n) - 1);
+      for (const auto& move : moves) {
+        ASSERT_FALSE(pegs[move.from].empty());
+        ASSERT_EQ(pegs[move.from].back(), move.disk);
+        ASSERT_TRUE(pegs[move.to].empty() || pegs[move.to].back() > move.disk);
+        pegs[move.from].pop_back();
+        pegs[move.to].push_back(move.disk);
+      }
+      ASSERT_EQ(pegs[0].size(), static_cast<size_t>(n));
+    }
+  }
+  EXPECT_EQ(hanoi_iterative(16, 0, 2), hanoi_recursive(16, 0, 2));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,245 @@
+#pragma once
+
+#include <cstddef>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Regular expressions from first principles, in a small dialect: literal
+// characters, '.' for any character, '*' for zero or more of what it
+// follows, '|' between alternatives, and parentheses for grouping. '*'
+// binds tightest, then concatenation, then '|'. A match must cover the
+// whole text, and malformed patterns throw std::invalid_argument.
+//
+// Two engines:
+//
+// - Thompson's construction compiles the pattern into an NFA with a state
+//   per character or operator, and simulation runs every possible path
+//   through it at once, keeping the set of states they're in. O(m) states
+//   for a pattern of length m, so O(nm) to match a text of length n, with
+//   no backtracking and no exponential blowup.
+// - The classic recursive matcher for the '.' and '*' subset (no '|' or
+//   parentheses), memoized on positions in the text and the pattern, so
+//   also O(nm).
+
+// One state of the NFA. A char or any state consumes a character and moves
+// to out; a split moves to out and out1 without consuming anything.
+struct NfaState {
+  enum class Kind { kChar, kAny, kSplit, kMatch };
+
+  Kind kind;
+  char c = 0;  // Only for kChar
+  int out = -1;
+  int out1 = -1;  // Only for kSplit
+};
+
+class Nfa {
+ public:
+  explicit Nfa(const std::string& pattern) : pattern_(pattern) {
+    Fragment whole = parse_alternation();
+    if (position_ != pattern_.size()) {
+      // The only way to stop early is an unmatched ')'
+      throw std::invalid_argument("unmatched ')' at " +
+                                  std::to_string(position_));
+    }
+    const int match = add({NfaState::Kind::kMatch});
+    patch(whole.dangling, match);
+    start_ = whole.start;
+  }
+
+  size_t size() const { return states_.size(); }
+  const std::vector<NfaState>& states() const { return states_; }
+  int start() const { return start_; }
+
+  // Whether the pattern matches all of text
+  bool matches(const std::string& text) const {
+    std::vector<int> current;
+    std::vector<int> next;
+    std::vector<size_t> seen(states_.size(), 0);
+    size_t step = 1;
+    add_state(current, start_, seen, step);
+    for (char c : text) {
+      next.clear();
+      ++step;
+      for (int s : current) {
+        const NfaState& state = states_[s];
+        if (state.kind == NfaState::Kind::kAny ||
+            (state.kind == NfaState::Kind::kChar && state.c == c)) {
+          add_state(next, state.out, seen, step);
+        }
+      }
+      std::swap(current, next);
+      if (current.empty()) {
+        return false;
+      }
+    }
+    for (int s : current) {
+      if (states_[s].kind == NfaState::Kind::kMatch) {
+        return true;
+      }
+    }
+    return false;
+  }
+
+ private:
+  // A piece of the NFA under construction: where it starts, and the
+  // arrows leaving it that don't point anywhere yet, as (state, which),
+  // with which 0 for out and 1 for out1
+  struct Fragment {
+    int start;
+    std::vector<std::pair<int, int>> dangling;
+  };
+
+  int add(NfaState state) {
+    states_.push_back(state);
+    return static_cast<int>(states_.size()) - 1;
+  }
+
+  void patch(const std::vector<std::pair<int, int>>& dangling, int to) {
+    for (auto [s, which] : dangling) {
+      (which == 0 ? states_[s].out : states_[s].out1) = to;
+    }
+  }
+
+  // The empty pattern, as a split with nowhere to go yet on either side,
+  // and only its out dangling; out1 stays -1 and is never followed
+  Fragment empty() {
+    const int s = add({NfaState::Kind::kSplit});
+    return {s, {{s, 0}}};
+  }
+
+  bool at(char c) const {
+    return position_ < pattern_.size() && pattern_[position_] == c;
+  }
+
+  // alternation := concatenation ('|' concatenation)*
+  Fragment parse_alternation() {
+    Fragment left = parse_concatenation();
+    while (at('|')) {
+      ++position_;
+      Fragment right = parse_concatenation();
+      const int split = add({NfaState::Kind::kSplit, 0, left.start,
+                             right.start});
+      left.start = split;
+      left.dangling.insert(left.dangling.end(), right.dangling.begin(),
+                           right.dangling.end());
+    }
+    return left;
+  }
+
+  // concatenation := repetition*, possibly none
+  Fragment parse_concatenation() {
+    std::optional<Fragment> whole;
+    while (position_ < pattern_.size() && !at('|') && !at(')')) {
+      Fragment next = parse_repetition();
+      if (!whole) {
+        whole = std::move(next);
+      } else {
+        patch(whole->dangling, next.start);
+        whole->dangling = std::move(next.dangling);
+      }
+    }
+    return whole ? std::move(*whole) : empty();
+  }
+
+  // repetition := atom '*'*
+  Fragment parse_repetition() {
+    Fragment atom = parse_atom();
+    while (at('*')) {
+      ++position_;
+      const int split = add({NfaState::Kind::kSplit, 0, atom.start, -1});
+      patch(atom.dangling, split);
+      atom = {split, {{split, 1}}};
+    }
+    return atom;
+  }
+
+  // atom := character | '.' | '(' alternation ')'
+  Fragment parse_atom() {
+    const size_t where = position_;
+    const char c = pattern_[position_++];
+    if (c == '(') {
+      Fragment inside = parse_alternation();
+      if (!at(')')) {
+        throw std::invalid_argument("unmatched '(' at " +
+                                    std::to_string(where));
+      }
+      ++position_;
+      return inside;
+    }
+    if (c == '*') {
+      throw std::invalid_argument("'*' with nothing to repeat at " +
+                                  std::to_string(where));
+    }
+    const int s = c == '.' ? add({NfaState::Kind::kAny})
+                           : add({NfaState::Kind::kChar, c});
+    return {s, {{s, 0}}};
+  }
+
+  // Adds s to the list, following splits, unless this step has been there
+  void add_state(std::vector<int>& list, int s, std::vector<size_t>& seen,
+                 size_t step) const {
+    if (s < 0 || seen[s] == step) {
+      return;
+    }
+    seen[s] = step;
+    if (states_[s].kind == NfaState::Kind::kSplit) {
+      add_state(list, states_[s].out, seen, step);
+      add_state(list, states_[s].out1, seen, step);
+      return;
+    }
+    list.push_back(s);
+  }
+
+  std::string pattern_;
+  size_t position_ = 0;
+  std::vector<NfaState> states_;
+  int start_ = -1;
+};
+
+inline bool nfa_match(const std::string& text, const std::string& pattern) {
+  return Nfa(pattern).matches(text);
+}
+
+// The recursive matcher, for patterns of literals, '.', and '*' only.
+// match(i, j) is whether text[i:] matches pattern[j:]. If pattern[j + 1]
+// is a '*', pattern[j] either matches nothing, skipping both, or matches
+// text[i] and stays to match more; otherwise it must match text[i] once.
+inline bool dp_match(const std::string& text, const std::string& pattern) {
+  for (size_t j = 0; j < pattern.size(); ++j) {
+    if (pattern[j] == '|' || pattern[j] == '(' || pattern[j] == ')') {
+      throw std::invalid_argument("dp_match takes only literals, '.', and "
+                                  "'*', not '" +
+                                  std::string(1, pattern[j]) + "'");
+    }
+    if (pattern[j] == '*' && (j == 0 || pattern[j - 1] == '*')) {
+      throw std::invalid_argument("'*' with nothing to repeat at " +
+                                  std::to_string(j));
+    }
+  }
+  // memo[i][j]: 0 unknown, 1 no, 2 yes
+  std::vector<std::vector<char>> memo(
+      text.size() + 1, std::vector<char>(pattern.size() + 1, 0));
+  auto match = [&](auto& self, size_t i, size_t j) -> bool {
+    if (memo[i][j] != 0) {
+      return memo[i][j] == 2;
+    }
+    bool result;
+    if (j == pattern.size()) {
+      result = i == text.size();
+    } else {
+      const bool first =
+          i < text.size() && (pattern[j] == '.' || pattern[j] == text[i]);
+      if (j + 1 < pattern.size() && pattern[j + 1] == '*') {
+        result = self(self, i, j + 2) || (first && self(self, i + 1, j));
+      } else {
+        result = first && self(self, i + 1, j + 1);
+      }
+    }
+    memo[i][j] = result ? 2 : 1;
+    return result;
+  };
+  return match(match, 0, 0);
+}
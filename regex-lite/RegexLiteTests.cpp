@@ -0,0 +1,118 @@
+#include <cstddef>
+#include <regex>
+#include <stdexcept>
+#include <string>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "RegexLite.h"
+#include "gtest/gtest.h"
+
+// A random pattern over a and b from the grammar, `depth` levels of
+// nesting at most, with or without '|' and parentheses
+std::string random_pattern(Rng& gen, int depth, bool groups) {
+  std::string pattern;
+  const size_t parts = uniform_below(gen, 4);
+  for (size_t i = 0; i < parts; ++i) {
+    const size_t kind = uniform_below(gen, groups && depth > 0 ? 4 : 3);
+    if (kind == 3) {
+      pattern += "(" + random_pattern(gen, depth - 1, groups);
+      if (bernoulli(gen, 0.5)) {
+        pattern += "|" + random_pattern(gen, depth - 1, groups);
+      }
+      pattern += ")";
+    } else {
+      pattern += "ab."[kind];
+    }
+    if (bernoulli(gen, 0.3)) {
+      pattern += '*';
+    }
+  }
+  return pattern;
+}
+
+TEST(RegexLite, Examples) {
+  EXPECT_TRUE(nfa_match("", ""));
+  EXPECT_FALSE(nfa_match("a", ""));
+  EXPECT_TRUE(nfa_match("abc", "abc"));
+  EXPECT_FALSE(nfa_match("abcd", "abc"));
+  EXPECT_TRUE(nfa_match("axc", "a.c"));
+  EXPECT_TRUE(nfa_match("", "a*"));
+  EXPECT_TRUE(nfa_match("aaaa", "a*"));
+  EXPECT_TRUE(nfa_match("mississippi", "mis*is*ip*.*"));
+  EXPECT_FALSE(nfa_match("mississippi", "mis*is*p*."));
+  EXPECT_TRUE(nfa_match("cat", "cat|dog"));
+  EXPECT_TRUE(nfa_match("dog", "cat|dog"));
+  EXPECT_FALSE(nfa_match("cadog", "cat|dog"));
+  EXPECT_TRUE(nfa_match("ababab", "(ab)*"));
+  EXPECT_FALSE(nfa_match("aba", "(ab)*"));
+  EXPECT_TRUE(nfa_match("", "a|"));
+  EXPECT_TRUE(nfa_match("", "()*"));
+  EXPECT_TRUE(nfa_match("aaa", "(a*)*"));
+  EXPECT_TRUE(nfa_match("abba", "a(b|c)*a"));
+
+  EXPECT_TRUE(dp_match("aab", "c*a*b"));
+  EXPECT_TRUE(dp_match("ab", ".*"));
+  EXPECT_FALSE(dp_match("aa", "a"));
+  EXPECT_FALSE(dp_match("mississippi", "mis*is*p*."));
+}
+
+TEST(RegexLite, Malformed) {
+  EXPECT_THROW(Nfa("*a"), std::invalid_argument);
+  EXPECT_THROW(Nfa("a|*"), std::invalid_argument);
+  EXPECT_THROW(Nfa("(ab"), std::invalid_argument);
+  EXPECT_THROW(Nfa("ab)"), std::invalid_argument);
+  EXPECT_THROW(dp_match("a", "*a"), std::invalid_argument);
+  EXPECT_THROW(dp_match("a", "a|b"), std::invalid_argument);
+}
+
+// Thompson's NFA takes one state per character or operator, and doesn't
+// back up into the exponential blowup that (a*)*b-style patterns cause in
+// a backtracking matcher
+TEST(RegexLite, NoBlowup) {
+  std::string pattern;
+  for (int i = 0; i < 30; ++i) {
+    pattern += "a*";
+  }
+  pattern += "b";
+  const std::string text(200, 'a');
+  const Nfa nfa(pattern);
+  EXPECT_LE(nfa.size(), pattern.size() + 1);
+  EXPECT_FALSE(nfa.matches(text));
+  EXPECT_TRUE(nfa.matches(text + "b"));
+  EXPECT_FALSE(dp_match(text, pattern));
+  EXPECT_TRUE(nfa_match(std::string(5000, 'a'), "(a|aa)*"));
+}
+
+TEST(RegexLite, EnginesAgree) {
+  Rng gen(198);
+  for (int t = 0; t < 2000; ++t) {
+    const std::string pattern = random_pattern(gen, 0, false);
+    const Nfa nfa(pattern);
+    for (int k = 0; k < 10; ++k) {
+      const std::string text = random_text(uniform_below(gen, 7), "ab", gen);
+      ASSERT_EQ(nfa.matches(text), dp_match(text, pattern))
+          << pattern << " on " << text;
+    }
+  }
+}
+
+// std::regex speaks a superset of the dialect, with the same meaning
+TEST(RegexLite, MatchesStdRegex) {
+  Rng gen(198);
+  for (int t = 0; t < 1000; ++t) {
+    const std::string pattern = random_pattern(gen, 2, true);
+    const Nfa nfa(pattern);
+    const std::regex reference(pattern);
+    for (int k = 0; k < 10; ++k) {
+      const std::string text = random_text(uniform_below(gen, 7), "ab", gen);
+      ASSERT_EQ(nfa.matches(text), std::regex_match(text, reference))
+          << pattern << " on " << text;
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
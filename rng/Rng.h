@@ -0,0 +1,173 @@
+#pragma once
+
+#include <array>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <iterator>
+#include <limits>
+#include <type_traits>
+#include <utility>
+
+// Random numbers that come out the same everywhere. std::mt19937 is exactly
+// specified, but the distributions on top of it aren't: libstdc++ and libc++
+// turn the same engine output into different integers, so a seeded test
+// that draws through std::uniform_int_distribution only reproduces on one
+// standard library. Everything randomized here draws through Rng and the
+// functions below instead, which are specified down to the bit.
+//
+// Rng is xoshiro256** (Blackman and Vigna): 256 bits of state, period
+// 2^256 - 1, four xors, two shifts, two rotations, and two multiplications
+// per 64-bit output. It passes every statistical test suite there is, and
+// isn't cryptographic: the state can be recovered from a few outputs.
+
+// SplitMix64: adds a constant to the state, and returns the state run
+// through a finalizer that spreads every bit over all 64. Good enough to
+// turn a small seed, even 0, into a full xoshiro state.
+inline uint64_t splitmix64(uint64_t& state) {
+  uint64_t z = (state += 0x9e3779b97f4a7c15);
+  z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9;
+  z = (z ^ (z >> 27)) * 0x94d049bb133111eb;
+  return z ^ (z >> 31);
+}
+
+class Rng {
+ public:
+  using result_type = uint64_t;
+
+  static constexpr uint64_t kDefaultSeed = 186;
+
+  // The state comes from SplitMix64, so nearby seeds give unrelated streams
+  explicit Rng(uint64_t seed = kDefaultSeed) {
+    for (auto& word : state_) {
+      word = splitmix64(seed);
+    }
+  }
+
+  // The raw state, which must not be all zeros
+  explicit Rng(const std::array<uint64_t, 4>& state) : state_(state) {
+    assert((state[0] | state[1] | state[2] | state[3]) != 0 &&
+           "the all-zero state only ever produces zeros");
+  }
+
+  static constexpr result_type min() { return 0; }
+  static constexpr result_type max() {
+    return std::numeric_limits<result_type>::max();
+  }
+
+  result_type operator()() {
+    const uint64_t result = rotl(state_[1] * 5, 7) * 9;
+    const uint64_t t = state_[1] << 17;
+    state_[2] ^= state_[0];
+    state_[3] ^= state_[1];
+    state_[1] ^= state_[2];
+    state_[0] ^= state_[3];
+    state_[2] ^= t;
+    state_[3] = rotl(state_[3], 45);
+    return result;
+  }
+
+  // Advances as far as 2^128 calls would. Jumping k times from one seed
+  // gives the starts of streams that can't overlap unless one of them runs
+  // for 2^128 outputs, e.g. one per worker thread.
+  void jump() {
+    static constexpr std::array<uint64_t, 4> kJump = {
+        0x180ec6d33cfd0aba, 0xd5a61266f0c9392c, 0xa9582618e03fc9aa,
+        0x39abdc4529b1661c};
+    std::array<uint64_t, 4> jumped = {0, 0, 0, 0};
+    for (uint64_t word : kJump) {
+      for (int bit = 0; bit < 64; ++bit) {
+        if (word & (uint64_t{1} << bit)) {
+          for (size_t i = 0; i < 4; ++i) {
+            jumped[i] ^= state_[i];
+          }
+        }
+        (*this)();
+      }
+    }
+    state_ = jumped;
+  }
+
+  // A new generator seeded from this one's next output, as Java's
+  // SplittableRandom does. Splits of splits are fine, so a recursive
+  // algorithm can hand each subproblem its own stream, and the same seed
+  // always splits the same way. Unlike jump(), there's no guarantee the
+  // streams don't overlap, only that it's astronomically unlikely.
+  Rng split() { return Rng((*this)()); }
+
+  const std::array<uint64_t, 4>& state() const { return state_; }
+
+  bool operator==(const Rng&) const = default;
+
+ private:
+  static uint64_t rotl(uint64_t x, int k) { return (x << k) | (x >> (64 - k)); }
+
+  std::array<uint64_t, 4> state_;
+};
+
+// The functions below take any generator of uniform 64-bit words (Rng, or
+// std::mt19937_64, or a test's fake), and promise the same result for the
+// same sequence of words.
+template <typename Gen>
+constexpr bool kDraws64Bits =
+    Gen::min() == 0 && Gen::max() == std::numeric_limits<uint64_t>::max();
+
+// Uniform on [0, n), for n > 0, by rejection: words below 2^64 mod n are
+// redrawn, and what's left splits evenly into n residues. Fewer than one
+// redraw in two on average, and almost never for small n.
+template <typename Gen>
+uint64_t uniform_below(Gen& gen, uint64_t n) {
+  static_assert(kDraws64Bits<Gen>, "needs uniform 64-bit words");
+  assert(n > 0 && "no values below 0");
+  const uint64_t threshold = (0 - n) % n;
+  for (;;) {
+    const uint64_t x = gen();
+    if (x >= threshold) {
+      return x % n;
+    }
+  }
+}
+
+// Uniform on [low, high], both ends included
+template <typename Int, typename Gen>
+Int uniform_int(Gen& gen, Int low, Int high) {
+  static_assert(std::is_integral_v<Int>);
+  assert(low <= high && "empty range");
+  using Unsigned = std::make_unsigned_t<Int>;
+  const uint64_t span = static_cast<uint64_t>(
+      static_cast<Unsigned>(static_cast<Unsigned>(high) -
+                            static_cast<Unsigned>(low)));
+  const uint64_t offset = span == std::numeric_limits<uint64_t>::max()
+                              ? gen()
+                              : uniform_below(gen, span + 1);
+  return static_cast<Int>(static_cast<Unsigned>(low) +
+                          static_cast<Unsigned>(offset));
+}
+
+// Uniform on [0, 1): the top 53 bits, which is every double in the range
+// that's a multiple of 2^-53
+template <typename Gen>
+double uniform_unit(Gen& gen) {
+  static_assert(kDraws64Bits<Gen>, "needs uniform 64-bit words");
+  return static_cast<double>(gen() >> 11) * 0x1.0p-53;
+}
+
+// True with probability p
+template <typename Gen>
+bool bernoulli(Gen& gen, double p) {
+  assert(p >= 0.0 && p <= 1.0);
+  return uniform_unit(gen) < p;
+}
+
+// Fisher-Yates: position i swaps with a uniformly random position at or
+// before it, so each of the n! orders comes out with probability 1/n!. Not
+// called shuffle, which argument-dependent lookup would confuse with
+// std::shuffle.
+template <typename RandomIt, typename Gen>
+void shuffle_range(RandomIt first, RandomIt last, Gen& gen) {
+  const auto n = static_cast<uint64_t>(std::distance(first, last));
+  for (uint64_t i = n; i > 1; --i) {
+    using std::swap;
+    swap(first[i - 1], first[uniform_below(gen, i)]);
+  }
+}
@@ -0,0 +1,108 @@
+#include <algorithm>
+#include <array>
+#include <cstdint>
+#include <numeric>
+#include <set>
+#include <vector>
+
+#include "Rng.h"
+#include "gtest/gtest.h"
+
+TEST(Rng, KnownOutputs) {
+  uint64_t state = 0;
+  EXPECT_EQ(splitmix64(state), 0xe220a8397b1dcdaf);
+  EXPECT_EQ(splitmix64(state), 0x6e789e6aa1b965f4);
+  EXPECT_EQ(splitmix64(state), 0x06c45d188009454f);
+
+  // From state {1, 2, 3, 4}: rotl(2 * 5, 7) * 9, then the state moves on
+  Rng gen(std::array<uint64_t, 4>{1, 2, 3, 4});
+  EXPECT_EQ(gen(), 11520);
+  EXPECT_EQ(gen(), 0);
+  EXPECT_EQ(gen(), 1509978240);
+  EXPECT_EQ(gen(), 1215971899390074240);
+}
+
+TEST(Rng, Streams) {
+  Rng a(186);
+  Rng b(186);
+  EXPECT_EQ(a, b);
+  for (int i = 0; i < 100; ++i) {
+    ASSERT_EQ(a(), b());
+  }
+  EXPECT_NE(Rng(186)(), Rng(187)());
+
+  // A split draws from its parent, and the same parent splits the same way
+  Rng child = a.split();
+  EXPECT_NE(child, a);
+  EXPECT_EQ(child, b.split());
+  EXPECT_EQ(a, b);
+
+  Rng jumped = a;
+  jumped.jump();
+  std::set<uint64_t> seen;
+  for (int i = 0; i < 1000; ++i) {
+    seen.insert(a());
+    seen.insert(jumped());
+  }
+  EXPECT_EQ(seen.size(), 2000u);
+  b.jump();
+  b.jump();
+  jumped.jump();
+  EXPECT_NE(b, jumped);  // jumped also made 1000 calls
+}
+
+TEST(Rng, Uniform) {
+  Rng gen(186);
+  std::array<int, 6> counts{};
+  for (int i = 0; i < 60000; ++i) {
+    ++counts[uniform_below(gen, 6)];
+  }
+  for (int count : counts) {
+    EXPECT_NEAR(count, 10000, 400);
+  }
+
+  for (int i = 0; i < 1000; ++i) {
+    const int x = uniform_int(gen, -3, 3);
+    ASSERT_TRUE(-3 <= x && x <= 3);
+    const double u = uniform_unit(gen);
+    ASSERT_TRUE(0 <= u && u < 1);
+  }
+  EXPECT_EQ(uniform_int(gen, 7, 7), 7);
+  EXPECT_EQ(uniform_int<int8_t>(gen, -128, -128), -128);
+  EXPECT_FALSE(bernoulli(gen, 0.0));
+  EXPECT_TRUE(bernoulli(gen, 1.0));
+
+  // The whole range of int64_t, which takes the raw word
+  std::set<int64_t> signs;
+  for (int i = 0; i < 100; ++i) {
+    signs.insert(uniform_int(gen, INT64_MIN, INT64_MAX) < 0);
+  }
+  EXPECT_EQ(signs.size(), 2u);
+}
+
+TEST(Rng, Shuffle) {
+  Rng gen(186);
+  std::vector<int> v(100);
+  std::iota(v.begin(), v.end(), 0);
+  auto shuffled = v;
+  shuffle_range(shuffled.begin(), shuffled.end(), gen);
+  EXPECT_NE(shuffled, v);
+  std::sort(shuffled.begin(), shuffled.end());
+  EXPECT_EQ(shuffled, v);
+
+  // Each of 3 elements comes first about a third of the time
+  std::array<int, 3> first{};
+  for (int i = 0; i < 30000; ++i) {
+    std::array<int, 3> a = {0, 1, 2};
+    shuffle_range(a.begin(), a.end(), gen);
+    ++first[a[0]];
+  }
+  for (int count : first) {
+    EXPECT_NEAR(count, 10000, 400);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
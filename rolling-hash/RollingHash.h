@@ -0,0 +1,266 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+#include "../linear-recurrence/LinearRecurrence.h"
+#include "../rng/Rng.h"
+#include "../segment-tree/SegmentTree.h"
+
+// Polynomial hashing: read a string as a number in base B modulo a prime
+// m, hash(s) = s[0] B^(n-1) + s[1] B^(n-2) + ... + s[n-1]. Then
+//
+// - the hash of every prefix comes from the one before, h * B + c, so one
+//   pass gives them all, and
+// - the hash of any substring falls out of two prefixes:
+//   hash(s[i, j)) = prefix(j) - prefix(i) B^(j-i).
+//
+// Equal strings always hash equal. Two different strings of length n
+// collide for at most n - 1 of the m possible bases, because their
+// difference is a nonzero polynomial in B of degree below n, so with a
+// random base the chance is below n / m. One mod near 10^9 is too small
+// once there are a lot of substrings to compare (the birthday bound makes
+// collisions likely around 10^5 of them); two independent hashes together
+// make it negligible.
+
+struct HashParams {
+  uint64_t base;
+  uint64_t mod;  // prime, below 2^63
+};
+
+// Bases larger than any char, and primes far apart
+constexpr HashParams kHashA = {1'000'003, (uint64_t{1} << 61) - 1};
+constexpr HashParams kHashB = {911'382'323, 1'000'000'007};
+
+// A base drawn uniformly from [256, mod - 1), which is what the collision
+// bound needs
+template <typename Gen>
+HashParams random_hash_params(Gen& gen, uint64_t mod) {
+  return {256 + uniform_below(gen, mod - 257), mod};
+}
+
+inline uint64_t pow_mod(uint64_t base, uint64_t exponent, uint64_t m) {
+  uint64_t result = 1 % m;
+  for (base %= m; exponent > 0; exponent >>= 1) {
+    if (exponent & 1) {
+      result = mul_mod(result, base, m);
+    }
+    base = mul_mod(base, base, m);
+  }
+  return result;
+}
+
+inline uint64_t char_value(char c) { return static_cast<unsigned char>(c); }
+
+inline uint64_t hash_string(std::string_view s, HashParams params) {
+  uint64_t h = 0;
+  for (char c : s) {
+    h = add_mod(mul_mod(h, params.base, params.mod), char_value(c),
+                params.mod);
+  }
+  return h;
+}
+
+// Prefix hashes of a text, for the hash of any substring in O(1)
+class RollingHash {
+ public:
+  RollingHash(std::string_view text, HashParams params = kHashA)
+      : params_(params), prefix_(text.size() + 1, 0), power_(text.size() + 1) {
+    power_[0] = 1 % params.mod;
+    for (size_t i = 0; i < text.size(); ++i) {
+      prefix_[i + 1] = add_mod(mul_mod(prefix_[i], params.base, params.mod),
+                               char_value(text[i]), params.mod);
+      power_[i + 1] = mul_mod(power_[i], params.base, params.mod);
+    }
+  }
+
+  size_t size() const { return prefix_.size() - 1; }
+  const HashParams& params() const { return params_; }
+
+  // The hash of text[begin, end)
+  uint64_t substring(size_t begin, size_t end) const {
+    assert(begin <= end && end <= size() && "range out of bounds");
+    const uint64_t shifted =
+        mul_mod(prefix_[begin], power_[end - begin], params_.mod);
+    return add_mod(prefix_[end], params_.mod - shifted, params_.mod);
+  }
+
+  // B^k, from the table when k is short enough
+  uint64_t power(size_t k) const {
+    return k < power_.size() ? power_[k]
+                             : pow_mod(params_.base, k, params_.mod);
+  }
+
+  // The hash of a string whose first part hashes to `left` and whose last
+  // `right_length` characters hash to `right`
+  uint64_t concat(uint64_t left, uint64_t right, size_t right_length) const {
+    return add_mod(mul_mod(left, power(right_length), params_.mod), right,
+                   params_.mod);
+  }
+
+ private:
+  HashParams params_;
+  std::vector<uint64_t> prefix_;  // prefix_[i] hashes text[0, i)
+  std::vector<uint64_t> power_;   // power_[k] is B^k
+};
+
+// Two hashes at once, compared as a pair
+using DoubleHash = std::pair<uint64_t, uint64_t>;
+
+class DoubleRollingHash {
+ public:
+  explicit DoubleRollingHash(std::string_view text, HashParams a = kHashA,
+                             HashParams b = kHashB)
+      : a_(text, a), b_(text, b) {}
+
+  size_t size() const { return a_.size(); }
+
+  DoubleHash substring(size_t begin, size_t end) const {
+    return {a_.substring(begin, end), b_.substring(begin, end)};
+  }
+
+  DoubleHash concat(const DoubleHash& left, const DoubleHash& right,
+                    size_t right_length) const {
+    return {a_.concat(left.first, right.first, right_length),
+            b_.concat(left.second, right.second, right_length)};
+  }
+
+ private:
+  RollingHash a_;
+  RollingHash b_;
+};
+
+inline DoubleHash double_hash_string(std::string_view s, HashParams a = kHashA,
+                                     HashParams b = kHashB) {
+  return {hash_string(s, a), hash_string(s, b)};
+}
+
+// Rabin-Karp: slide a window the length of the pattern along the text and
+// compare hashes, checking each hash match character by character so that
+// a collision can't give a wrong answer, only a slower one. Every position
+// where the pattern starts, in order. O(n + m) expected.
+inline std::vector<size_t> rabin_karp(std::string_view text,
+                                      std::string_view pattern) {
+  std::vector<size_t> matches;
+  if (pattern.size() > text.size()) {
+    return matches;
+  }
+  const RollingHash windows(text);
+  const uint64_t target = hash_string(pattern, windows.params());
+  for (size_t i = 0; i + pattern.size() <= text.size(); ++i) {
+    if (windows.substring(i, i + pattern.size()) == target &&
+        text.substr(i, pattern.size()) == pattern) {
+      matches.push_back(i);
+    }
+  }
+  return matches;
+}
+
+// Palindromes by comparing a substring's hash with the hash of the same
+// stretch of the reversed text
+class PalindromeQueries {
+ public:
+  explicit PalindromeQueries(std::string_view text)
+      : forward_(text), backward_(std::string(text.rbegin(), text.rend())) {}
+
+  // Whether text[begin, end) reads the same backwards. O(1).
+  bool is_palindrome(size_t begin, size_t end) const {
+    const size_t n = forward_.size();
+    return forward_.substring(begin, end) == backward_.substring(n - end,
+                                                                 n - begin);
+  }
+
+  // The [begin, end) of a longest palindrome, the leftmost of them. Around
+  // each center, binary search for the longest palindrome: a palindrome
+  // stays one after trimming both ends. O(n log n).
+  std::pair<size_t, size_t> longest() const {
+    const size_t n = forward_.size();
+    std::pair<size_t, size_t> best = {0, 0};
+    // Center c is between characters c / 2 - 1 and c / 2 for even c, and
+    // on character c / 2 for odd c
+    for (size_t center = 1; center < 2 * n + 1; ++center) {
+      const size_t left = center / 2;
+      const size_t right = (center + 1) / 2;
+      // The longest radius r with text[left - r, right + r) a palindrome
+      size_t lo = 0;
+      size_t hi = std::min(left, n - right);
+      while (lo < hi) {
+        const size_t r = (lo + hi + 1) / 2;
+        if (is_palindrome(left - r, right + r)) {
+          lo = r;
+        } else {
+          hi = r - 1;
+        }
+      }
+      if (right - left + 2 * lo > best.second - best.first) {
+        best = {left - lo, right + lo};
+      }
+    }
+    return best;
+  }
+
+ private:
+  DoubleRollingHash forward_;
+  DoubleRollingHash backward_;
+};
+
+// A string that changes one character at a time, still with substring
+// hashes. The text's hash is a sum of one term per character, s[i]
+// B^(n-1-i), so a segment tree of the terms under addition mod m updates
+// in O(log n); a range of terms is the substring's hash times B^(n-end),
+// which dividing out, by Fermat's little theorem since m is prime, undoes.
+// The hashes agree with RollingHash over the current text, so two
+// EditableHashes, or one and a RollingHash, can be compared as the texts
+// change. O(log n) per edit and per query.
+class EditableHash {
+ public:
+  explicit EditableHash(std::string_view text, HashParams params = kHashA)
+      : params_(params),
+        inverse_base_(pow_mod(params.base, params.mod - 2, params.mod)),
+        terms_(initial_terms(text, params), 0, AddMod{params.mod}) {}
+
+  size_t size() const { return terms_.size(); }
+
+  void set(size_t i, char c) {
+    const uint64_t weight = pow_mod(params_.base, size() - 1 - i, params_.mod);
+    terms_.set(i, mul_mod(char_value(c), weight, params_.mod));
+  }
+
+  uint64_t substring(size_t begin, size_t end) const {
+    assert(begin <= end && end <= size() && "range out of bounds");
+    return mul_mod(terms_.query(begin, end),
+                   pow_mod(inverse_base_, size() - end, params_.mod),
+                   params_.mod);
+  }
+
+  uint64_t hash() const { return substring(0, size()); }
+
+ private:
+  struct AddMod {
+    uint64_t mod;
+    uint64_t operator()(uint64_t a, uint64_t b) const {
+      return add_mod(a, b, mod);
+    }
+  };
+
+  static std::vector<uint64_t> initial_terms(std::string_view text,
+                                             HashParams params) {
+    std::vector<uint64_t> terms(text.size());
+    uint64_t weight = 1 % params.mod;
+    for (size_t i = text.size(); i-- > 0;) {
+      terms[i] = mul_mod(char_value(text[i]), weight, params.mod);
+      weight = mul_mod(weight, params.base, params.mod);
+    }
+    return terms;
+  }
+
+  HashParams params_;
+  uint64_t inverse_base_;
+  SegmentTree<uint64_t, AddMod> terms_;
+};
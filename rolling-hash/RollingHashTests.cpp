@@ -0,0 +1,138 @@
+#include <cstddef>
+#include <cstdint>
+#include <map>
+#include <set>
+#include <string>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "RollingHash.h"
+#include "gtest/gtest.h"
+
+TEST(RollingHash, Substrings) {
+  const std::string text = "abracadabra";
+  const RollingHash hash(text);
+  EXPECT_EQ(hash.substring(0, 4), hash.substring(7, 11));  // abra
+  EXPECT_NE(hash.substring(0, 4), hash.substring(1, 5));
+  EXPECT_EQ(hash.substring(3, 3), 0u);
+  EXPECT_EQ(hash.substring(2, 9), hash_string("racadab", kHashA));
+  // abra + cad
+  EXPECT_EQ(hash.concat(hash.substring(0, 4), hash.substring(4, 7), 3),
+            hash.substring(0, 7));
+  EXPECT_EQ(hash.concat(hash_string("long", kHashA),
+                        hash_string(std::string(100, 'x'), kHashA), 100),
+            hash_string("long" + std::string(100, 'x'), kHashA));
+
+  const DoubleRollingHash both(text);
+  EXPECT_EQ(both.substring(0, 4), double_hash_string("abra"));
+  EXPECT_EQ(both.concat(both.substring(0, 1), both.substring(1, 11), 10),
+            both.substring(0, 11));
+}
+
+TEST(RollingHash, RabinKarp) {
+  EXPECT_EQ(rabin_karp("abracadabra", "abra"), (std::vector<size_t>{0, 7}));
+  EXPECT_EQ(rabin_karp("aaaaa", "aa"), (std::vector<size_t>{0, 1, 2, 3}));
+  EXPECT_EQ(rabin_karp("abc", ""), (std::vector<size_t>{0, 1, 2, 3}));
+  EXPECT_TRUE(rabin_karp("ab", "abc").empty());
+
+  Rng gen(218);
+  for (int trial = 0; trial < 200; ++trial) {
+    const std::string text = random_text(uniform_below(gen, 200), 2, gen);
+    const std::string pattern = random_text(1 + uniform_below(gen, 6), 2, gen);
+    std::vector<size_t> expected;
+    for (size_t i = text.find(pattern); i != std::string::npos;
+         i = text.find(pattern, i + 1)) {
+      expected.push_back(i);
+    }
+    ASSERT_EQ(rabin_karp(text, pattern), expected);
+  }
+}
+
+TEST(RollingHash, Palindromes) {
+  const PalindromeQueries racecar("xracecary");
+  EXPECT_TRUE(racecar.is_palindrome(1, 8));
+  EXPECT_TRUE(racecar.is_palindrome(3, 6));
+  EXPECT_TRUE(racecar.is_palindrome(4, 4));
+  EXPECT_FALSE(racecar.is_palindrome(0, 8));
+  EXPECT_EQ(racecar.longest(), (std::pair<size_t, size_t>{1, 8}));
+  EXPECT_EQ(PalindromeQueries("abba").longest(),
+            (std::pair<size_t, size_t>{0, 4}));
+  EXPECT_EQ(PalindromeQueries("").longest(), (std::pair<size_t, size_t>{0, 0}));
+
+  Rng gen(218);
+  for (int trial = 0; trial < 100; ++trial) {
+    const std::string text = random_text(uniform_below(gen, 60), 2, gen);
+    const PalindromeQueries queries(text);
+    size_t longest = 0;
+    for (size_t i = 0; i <= text.size(); ++i) {
+      for (size_t j = i; j <= text.size(); ++j) {
+        const std::string s = text.substr(i, j - i);
+        const bool palindrome = std::string(s.rbegin(), s.rend()) == s;
+        ASSERT_EQ(queries.is_palindrome(i, j), palindrome);
+        if (palindrome) {
+          longest = std::max(longest, j - i);
+        }
+      }
+    }
+    const auto [begin, end] = queries.longest();
+    ASSERT_EQ(end - begin, longest);
+  }
+}
+
+// Change random characters of two copies of a text, sometimes the same
+// way, and compare them by hash
+TEST(RollingHash, EqualityUnderEdits) {
+  Rng gen(218);
+  const std::string start = random_text(500, 3, gen);
+  std::string a = start;
+  std::string b = start;
+  EditableHash hash_a(a);
+  EditableHash hash_b(b);
+  for (int step = 0; step < 2000; ++step) {
+    const size_t i = uniform_below(gen, a.size());
+    const char c = static_cast<char>('a' + uniform_below(gen, 3));
+    a[i] = c;
+    hash_a.set(i, c);
+    if (bernoulli(gen, 0.9)) {
+      b[i] = c;
+      hash_b.set(i, c);
+    }
+    ASSERT_EQ(hash_a.hash() == hash_b.hash(), a == b);
+    const size_t begin = uniform_below(gen, a.size() + 1);
+    const size_t end = begin + uniform_below(gen, a.size() - begin + 1);
+    ASSERT_EQ(hash_a.substring(begin, end),
+              RollingHash(a).substring(begin, end));
+  }
+}
+
+// Every distinct substring of a long text gets a distinct double hash, while
+// a single hash mod a small prime collides, as the birthday bound says
+TEST(RollingHash, Collisions) {
+  Rng gen(218);
+  const std::string text = random_text(400, 4, gen);
+  const DoubleRollingHash both(text);
+  const HashParams small = random_hash_params(gen, 10007);
+  const RollingHash weak(text, small);
+  std::map<DoubleHash, std::string> seen;
+  std::set<uint64_t> weak_hashes;
+  size_t distinct = 0;
+  for (size_t i = 0; i < text.size(); ++i) {
+    for (size_t j = i + 1; j <= text.size(); ++j) {
+      const std::string s = text.substr(i, j - i);
+      const auto [it, inserted] = seen.emplace(both.substring(i, j), s);
+      ASSERT_EQ(it->second, s);  // the same hash, so the same string
+      if (inserted) {
+        ++distinct;
+        weak_hashes.insert(weak.substring(i, j));
+      }
+    }
+  }
+  EXPECT_GT(distinct, 70000u);
+  EXPECT_LT(weak_hashes.size(), distinct);
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
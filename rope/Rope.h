@@ -0,0 +1,241 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+#include "../rng/Rng.h"
+
+// Rope: a string stored as a balanced binary tree of chunks.
+//
+// Editing the middle of a std::string shifts everything after the edit, so
+// an insert or erase costs O(n). A rope keeps the text in short chunks at the
+// nodes of a tree ordered by position. Every node knows how many characters
+// its subtree holds, which is enough to find position i by walking down from
+// the root.
+//
+// Everything is built from two primitives on the tree:
+//   - split(t, pos): cut t into the first pos characters and the rest
+//   - merge(a, b): join two trees, with all of a before all of b
+//
+// insert is split + merge + merge, erase is split + split + merge, and
+// concatenation is a single merge.
+//
+// The tree is a treap: each node gets a random priority and the tree is a
+// heap on priorities. That keeps the expected depth at O(log n) no matter
+// which order the edits arrive in, so every operation is O(log n) expected
+// (plus the length of the text being inserted).
+class Rope {
+ public:
+  // Text is stored in chunks of at most this many characters
+  static constexpr size_t kChunkSize = 64;
+
+ private:
+  struct Node {
+    std::string chunk;
+    uint32_t priority;
+    size_t size;  // Characters in this subtree
+    std::unique_ptr<Node> left;
+    std::unique_ptr<Node> right;
+
+    Node(std::string c, uint32_t p)
+        : chunk(std::move(c)), priority(p), size(chunk.size()) {}
+  };
+  using NodePtr = std::unique_ptr<Node>;
+
+  NodePtr root_;
+  Rng gen_;
+
+  static size_t size_of(const NodePtr& node) { return node ? node->size : 0; }
+
+  static void update(Node& node) {
+    node.size = size_of(node.left) + node.chunk.size() + size_of(node.right);
+  }
+
+  NodePtr make_node(std::string chunk) {
+    return std::make_unique<Node>(std::move(chunk),
+                                  static_cast<uint32_t>(gen_() >> 32));
+  }
+
+  // All of a comes before all of b
+  static NodePtr merge(NodePtr a, NodePtr b) {
+    if (!a) {
+      return b;
+    }
+    if (!b) {
+      return a;
+    }
+    if (a->priority > b->priority) {
+      a->right = merge(std::move(a->right), std::move(b));
+      update(*a);
+      return a;
+    }
+    b->left = merge(std::move(a), std::move(b->left));
+    update(*b);
+    return b;
+  }
+
+  // Returns {first pos characters, the rest}
+  std::pair<NodePtr, NodePtr> split(NodePtr node, size_t pos) {
+    if (!node) {
+      return {nullptr, nullptr};
+    }
+
+    const size_t left_size = size_of(node->left);
+    const size_t chunk_end = left_size + node->chunk.size();
+
+    if (pos <= left_size) {
+      auto [l, r] = split(std::move(node->left), pos);
+      node->left = std::move(r);
+      update(*node);
+      return {std::move(l), std::move(node)};
+    }
+
+    if (pos >= chunk_end) {
+      auto [l, r] = split(std::move(node->right), pos - chunk_end);
+      node->right = std::move(l);
+      update(*node);
+      return {std::move(node), std::move(r)};
+    }
+
+    // The cut falls inside this node's chunk. The node keeps the front of
+    // the chunk and its left subtree; the back of the chunk becomes a new
+    // node that goes in front of the right subtree.
+    const size_t offset = pos - left_size;
+    auto tail = make_node(node->chunk.substr(offset));
+    node->chunk.resize(offset);
+    NodePtr right = std::move(node->right);
+    update(*node);
+    return {std::move(node), merge(std::move(tail), std::move(right))};
+  }
+
+  // Builds a tree for s without touching any existing tree
+  NodePtr build(const std::string& s) {
+    NodePtr result;
+    for (size_t i = 0; i < s.size(); i += kChunkSize) {
+      result = merge(std::move(result), make_node(s.substr(i, kChunkSize)));
+    }
+    return result;
+  }
+
+  static void append_to(const Node* node, std::string& out) {
+    if (!node) {
+      return;
+    }
+    append_to(node->left.get(), out);
+    out += node->chunk;
+    append_to(node->right.get(), out);
+  }
+
+  // Appends the characters of node's subtree that fall in [begin, end), where
+  // positions are relative to the start of the subtree. Subtrees entirely
+  // outside the range are skipped.
+  static void append_range(const Node* node, size_t begin, size_t end,
+                           std::string& out) {
+    if (!node || begin >= end) {
+      return;
+    }
+    const size_t left_size = size_of(node->left);
+    const size_t chunk_end = left_size + node->chunk.size();
+    if (begin < left_size) {
+      append_range(node->left.get(), begin, std::min(end, left_size), out);
+    }
+    if (begin < chunk_end && end > left_size) {
+      const size_t from = std::max(begin, left_size) - left_size;
+      const size_t to = std::min(end, chunk_end) - left_size;
+      out.append(node->chunk, from, to - from);
+    }
+    if (end > chunk_end) {
+      append_range(node->right.get(), std::max(begin, chunk_end) - chunk_end,
+                   end - chunk_end, out);
+    }
+  }
+
+  static size_t height_of(const Node* node) {
+    if (!node) {
+      return 0;
+    }
+    return 1 + std::max(height_of(node->left.get()),
+                        height_of(node->right.get()));
+  }
+
+ public:
+  explicit Rope(const std::string& s = "", uint64_t seed = 5489u)
+      : gen_(seed) {
+    root_ = build(s);
+  }
+
+  size_t size() const { return size_of(root_); }
+
+  bool empty() const { return size() == 0; }
+
+  char char_at(size_t index) const {
+    assert(index < size() && "index out of range");
+    const Node* node = root_.get();
+    while (true) {
+      const size_t left_size = size_of(node->left);
+      if (index < left_size) {
+        node = node->left.get();
+      } else if (index < left_size + node->chunk.size()) {
+        return node->chunk[index - left_size];
+      } else {
+        index -= left_size + node->chunk.size();
+        node = node->right.get();
+      }
+    }
+  }
+
+  // Insert s so that it starts at position pos
+  void insert(size_t pos, const std::string& s) {
+    assert(pos <= size() && "position out of range");
+    auto [left, right] = split(std::move(root_), pos);
+    root_ = merge(merge(std::move(left), build(s)), std::move(right));
+  }
+
+  // Remove up to len characters starting at pos
+  void erase(size_t pos, size_t len) {
+    assert(pos <= size() && "position out of range");
+    auto [left, rest] = split(std::move(root_), pos);
+    auto [removed, right] = split(std::move(rest), len);
+    root_ = merge(std::move(left), std::move(right));
+  }
+
+  // Append other to this rope in O(log n), leaving other empty
+  void concat(Rope&& other) {
+    root_ = merge(std::move(root_), std::move(other.root_));
+  }
+
+  // Remove and return everything from pos onwards, in O(log n)
+  Rope split_off(size_t pos) {
+    assert(pos <= size() && "position out of range");
+    auto [left, right] = split(std::move(root_), pos);
+    root_ = std::move(left);
+    Rope result("", gen_());
+    result.root_ = std::move(right);
+    return result;
+  }
+
+  // Up to len characters starting at pos
+  std::string substr(size_t pos, size_t len) const {
+    assert(pos <= size() && "position out of range");
+    std::string result;
+    len = std::min(len, size() - pos);
+    result.reserve(len);
+    append_range(root_.get(), pos, pos + len, result);
+    return result;
+  }
+
+  std::string to_string() const {
+    std::string result;
+    result.reserve(size());
+    append_to(root_.get(), result);
+    return result;
+  }
+
+  // Number of nodes on the longest root-to-leaf path
+  size_t height() const { return height_of(root_.get()); }
+};
@@ -0,0 +1,154 @@
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "Rope.h"
+#include "gtest/gtest.h"
+
+TEST(Rope, EmptyRope) {
+  Rope rope;
+
+  EXPECT_TRUE(rope.empty());
+  EXPECT_EQ(rope.size(), 0u);
+  EXPECT_EQ(rope.to_string(), "");
+  EXPECT_EQ(rope.height(), 0u);
+}
+
+TEST(Rope, BuildFromString) {
+  std::string text = "The quick brown fox jumps over the lazy dog";
+  Rope rope(text);
+
+  EXPECT_EQ(rope.size(), text.size());
+  EXPECT_EQ(rope.to_string(), text);
+  for (size_t i = 0; i < text.size(); ++i) {
+    EXPECT_EQ(rope.char_at(i), text[i]);
+  }
+}
+
+TEST(Rope, Insert) {
+  Rope rope("helld");
+
+  rope.insert(3, "lo wor");
+  EXPECT_EQ(rope.to_string(), "hello world");
+
+  rope.insert(0, ">> ");
+  rope.insert(rope.size(), "!");
+  EXPECT_EQ(rope.to_string(), ">> hello world!");
+}
+
+TEST(Rope, Erase) {
+  Rope rope("hello cruel world");
+
+  rope.erase(5, 6);
+  EXPECT_EQ(rope.to_string(), "hello world");
+
+  rope.erase(0, 6);
+  EXPECT_EQ(rope.to_string(), "world");
+
+  // Erasing past the end just truncates
+  rope.erase(3, 100);
+  EXPECT_EQ(rope.to_string(), "wor");
+}
+
+TEST(Rope, ConcatAndSplitOff) {
+  Rope left("abc");
+  Rope right("def");
+
+  left.concat(std::move(right));
+  EXPECT_EQ(left.to_string(), "abcdef");
+
+  Rope tail = left.split_off(2);
+  EXPECT_EQ(left.to_string(), "ab");
+  EXPECT_EQ(tail.to_string(), "cdef");
+}
+
+TEST(Rope, Substr) {
+  Rng gen(1);
+  std::string text = random_text(1000, 26, gen);
+  Rope rope(text);
+
+  EXPECT_EQ(rope.substr(0, 10), text.substr(0, 10));
+  EXPECT_EQ(rope.substr(60, 10), text.substr(60, 10));  // Crosses a chunk
+  EXPECT_EQ(rope.substr(990, 50), text.substr(990, 50));
+  EXPECT_EQ(rope.substr(1000, 5), "");
+  EXPECT_EQ(rope.substr(0, 1000), text);
+}
+
+TEST(Rope, RandomEditScriptsMatchString) {
+  // Property test: run the same random edits on a rope and a std::string and
+  // compare after every step.
+  for (uint32_t seed = 0; seed < 20; ++seed) {
+    Rng gen(seed);
+    std::string expected = random_text(200, 26, gen);
+    Rope rope(expected, seed);
+
+    for (int step = 0; step < 300; ++step) {
+      const uint64_t op = uniform_below(gen, 5);
+      size_t pos = uniform_below(gen, expected.size() + 1);
+      size_t len = uniform_below(gen, 151);
+
+      if (op <= 1) {
+        std::string s = random_text(len, 26, gen);
+        rope.insert(pos, s);
+        expected.insert(pos, s);
+      } else if (op == 2) {
+        rope.erase(pos, len);
+        expected.erase(pos, len);
+      } else if (op == 3) {
+        // Split and glue back together in the other order
+        Rope tail = rope.split_off(pos);
+        tail.concat(std::move(rope));
+        rope = std::move(tail);
+        expected = expected.substr(pos) + expected.substr(0, pos);
+      } else {
+        ASSERT_EQ(rope.substr(pos, len), expected.substr(pos, len));
+      }
+
+      ASSERT_EQ(rope.size(), expected.size());
+      if (!expected.empty()) {
+        size_t i = uniform_below(gen, expected.size());
+        ASSERT_EQ(rope.char_at(i), expected[i]);
+      }
+    }
+    ASSERT_EQ(rope.to_string(), expected) << "seed " << seed;
+  }
+}
+
+TEST(Rope, ManySingleCharacterInsertsStayShallow) {
+  // Typing one character at a time at random positions is the workload that
+  // makes a plain string quadratic. The treap keeps the depth logarithmic in
+  // the number of nodes.
+  Rng gen(7);
+  Rope rope;
+  const size_t n = 100000;
+  for (size_t i = 0; i < n; ++i) {
+    size_t pos = uniform_below(gen, rope.size() + 1);
+    rope.insert(pos, "x");
+  }
+
+  EXPECT_EQ(rope.size(), n);
+  EXPECT_LE(rope.height(), static_cast<size_t>(4 * std::log2(n)));
+}
+
+TEST(Rope, LargeTextEdits) {
+  Rng gen(3);
+  std::string text = random_text(1 << 20, 26, gen);
+  Rope rope(text);
+
+  rope.insert(text.size() / 2, "MIDDLE");
+  rope.erase(10, 100000);
+
+  text.insert(text.size() / 2, "MIDDLE");
+  text.erase(10, 100000);
+
+  EXPECT_EQ(rope.size(), text.size());
+  EXPECT_EQ(rope.to_string(), text);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,57 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <utility>
+
+#include "../heap/BinaryHeap.h"
+
+// The median of a stream, with two heaps: a max-heap holding the smaller
+// half of the elements, and a min-heap holding the larger half. The lower
+// half may have one element more. Then the median is at the top of the
+// lower half, or between the two tops.
+//
+// push puts the new element in the half it belongs to, and moves one top
+// across if that unbalances the sizes. O(log n) per push, O(1) per median.
+template <typename T>
+class RunningMedian {
+ public:
+  void push(T x) {
+    if (lower_.empty() || !(lower_.top() < x)) {
+      lower_.push(std::move(x));
+    } else {
+      upper_.push(std::move(x));
+    }
+    if (lower_.size() > upper_.size() + 1) {
+      upper_.push(lower_.pop());
+    } else if (upper_.size() > lower_.size()) {
+      lower_.push(upper_.pop());
+    }
+  }
+
+  size_t size() const { return lower_.size() + upper_.size(); }
+
+  bool empty() const { return lower_.empty(); }
+
+  // With an even count, the mean of the two middle elements
+  double median() const {
+    assert(!empty() && "no median of nothing");
+    if (lower_.size() > upper_.size()) {
+      return static_cast<double>(lower_.top());
+    }
+    const double sum = static_cast<double>(lower_.top()) +
+                       static_cast<double>(upper_.top());
+    return sum / 2;
+  }
+
+  // The middle element, or the lower of the two middle elements
+  const T& lower_median() const {
+    assert(!empty() && "no median of nothing");
+    return lower_.top();
+  }
+
+ private:
+  BinaryHeap<T> lower_;                   // Max-heap
+  BinaryHeap<T, std::greater<T>> upper_;  // Min-heap
+};
@@ -0,0 +1,58 @@
+#include <algorithm>
+#include <random>
+#include <vector>
+
+#include "../order-statistics/OrderStatistics.h"
+#include "RunningMedian.h"
+#include "gtest/gtest.h"
+
+double sorted_median(std::vector<int> values) {
+  std::sort(values.begin(), values.end());
+  const size_t n = values.size();
+  return n % 2 == 1 ? values[n / 2] : (values[n / 2 - 1] + values[n / 2]) / 2.0;
+}
+
+TEST(RunningMedian, Example) {
+  RunningMedian<int> median;
+  std::vector<double> medians;
+  for (int x : {5, 15, 1, 3, 8, 7}) {
+    median.push(x);
+    medians.push_back(median.median());
+  }
+  EXPECT_EQ(medians, (std::vector<double>{5, 10, 5, 4, 5, 6}));
+  EXPECT_EQ(median.lower_median(), 5);
+  EXPECT_EQ(median.size(), 6u);
+}
+
+TEST(RunningMedian, MatchesSorting) {
+  std::mt19937 gen(159);
+  for (int trial = 0; trial < 20; ++trial) {
+    // Narrow ranges produce lots of duplicates
+    std::uniform_int_distribution<int> value(0, trial < 10 ? 5 : 1'000'000);
+    RunningMedian<int> median;
+    std::vector<int> values;
+    for (int step = 0; step < 300; ++step) {
+      values.push_back(value(gen));
+      median.push(values.back());
+      ASSERT_EQ(median.median(), sorted_median(values));
+    }
+  }
+}
+
+TEST(RunningMedian, MatchesOrderStatisticTree) {
+  std::mt19937 gen(160);
+  std::uniform_int_distribution<int> value(-1000, 1000);
+  RunningMedian<int> heaps;
+  TreeMedian<int> tree;
+  for (int step = 0; step < 20000; ++step) {
+    const int x = value(gen);
+    heaps.push(x);
+    tree.add(x);
+    ASSERT_EQ(heaps.median(), tree.median());
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
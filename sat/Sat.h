@@ -0,0 +1,316 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <sstream>
+#include <string>
+#include <vector>
+
+#include "../parse/Parse.h"
+
+// Boolean satisfiability by DPLL (Davis, Putnam, Logemann, Loveland), the
+// backtracking search under every modern SAT solver. A formula in
+// conjunctive normal form is a list of clauses, each an or of literals,
+// and it's satisfied when every clause has a true literal.
+//
+// The search sets a variable and recurses, undoing the assignment when
+// that leads to a contradiction, like every backtracking etude here. Two
+// rules keep it from guessing when it doesn't have to:
+//
+// - Unit propagation: a clause with every literal false but one forces
+//   that one true. One forced literal can make another clause a unit, so
+//   it repeats until nothing changes, or until a clause has no true or
+//   unassigned literal left, a conflict.
+// - Pure literals: a variable that only ever appears with one sign, among
+//   the clauses not yet satisfied, can be set to satisfy all of them at no
+//   risk.
+//
+// Literals are as in DIMACS: variable v is v, and its negation -v, counting
+// from 1.
+
+using Clause = std::vector<int>;
+
+struct Cnf {
+  int variables = 0;
+  std::vector<Clause> clauses;
+};
+
+// DIMACS CNF: lines starting 'c' are comments, then a header
+// "p cnf <variables> <clauses>", then the clauses as literals, each clause
+// ended by a 0, free to run across lines. A '%' line ends the formula, as
+// in the SATLIB benchmarks. Throws ParseError.
+inline Cnf parse_dimacs(const std::string& text) {
+  Cnf cnf;
+  bool header = false;
+  size_t expected = 0;
+  Clause clause;
+  std::istringstream lines(text);
+  std::string line;
+  size_t number = 0;
+  auto fail = [&](const std::string& message) {
+    throw ParseError("line " + std::to_string(number) + ": " + message);
+  };
+  while (std::getline(lines, line)) {
+    ++number;
+    std::istringstream tokens(line);
+    std::string first;
+    if (!(tokens >> first) || first == "c") {
+      continue;
+    }
+    if (first == "%") {
+      break;
+    }
+    if (first == "p") {
+      std::string format;
+      int64_t variables;
+      int64_t clauses;
+      if (header || !(tokens >> format >> variables >> clauses) ||
+          format != "cnf" || variables < 0 || clauses < 0 ||
+          variables > 1'000'000 || !(tokens >> std::ws).eof()) {
+        fail("expected one header, p cnf <variables> <clauses>");
+      }
+      header = true;
+      cnf.variables = static_cast<int>(variables);
+      expected = static_cast<size_t>(clauses);
+      continue;
+    }
+    if (!header) {
+      fail("expected the header before the clauses");
+    }
+    std::string token = first;
+    do {
+      int64_t literal;
+      if (!parse_one(token, literal)) {
+        fail("bad literal '" + token + "'");
+      }
+      if (literal == 0) {
+        cnf.clauses.push_back(std::move(clause));
+        clause.clear();
+      } else if (std::llabs(literal) > cnf.variables) {
+        fail("literal " + token + " out of range for " +
+             std::to_string(cnf.variables) + " variables");
+      } else {
+        clause.push_back(static_cast<int>(literal));
+      }
+    } while (tokens >> token);
+  }
+  if (!header) {
+    throw ParseError("cnf: missing the header");
+  }
+  if (!clause.empty()) {
+    throw ParseError("cnf: the last clause has no closing 0");
+  }
+  if (cnf.clauses.size() != expected) {
+    throw ParseError("cnf: the header promised " + std::to_string(expected) +
+                     " clauses, but there are " +
+                     std::to_string(cnf.clauses.size()));
+  }
+  return cnf;
+}
+
+// model[v] is variable v's value, for v from 1; model[0] is unused
+using Model = std::vector<bool>;
+
+inline bool literal_true(const Model& model, int literal) {
+  return literal > 0 ? model[literal] : !model[-literal];
+}
+
+// Whether every clause has a true literal
+inline bool satisfies(const Cnf& cnf, const Model& model) {
+  if (model.size() != static_cast<size_t>(cnf.variables) + 1) {
+    return false;
+  }
+  for (const Clause& clause : cnf.clauses) {
+    bool satisfied = false;
+    for (int literal : clause) {
+      satisfied = satisfied || literal_true(model, literal);
+    }
+    if (!satisfied) {
+      return false;
+    }
+  }
+  return true;
+}
+
+struct SatStats {
+  size_t decisions = 0;
+  size_t propagations = 0;   // Literals forced by unit clauses
+  size_t pure_literals = 0;  // Literals set by pure literal elimination
+  size_t conflicts = 0;
+};
+
+struct SatResult {
+  bool satisfiable = false;
+  Model model;  // Only if satisfiable
+  SatStats stats;
+};
+
+class Dpll {
+ public:
+  explicit Dpll(const Cnf& cnf)
+      : cnf_(cnf), values_(static_cast<size_t>(cnf.variables) + 1, 0) {}
+
+  SatResult solve() {
+    SatResult result;
+    result.satisfiable = search();
+    if (result.satisfiable) {
+      // Anything the search never needed to set can be false
+      result.model.assign(values_.size(), false);
+      for (size_t v = 1; v < values_.size(); ++v) {
+        result.model[v] = values_[v] > 0;
+      }
+    }
+    result.stats = stats_;
+    return result;
+  }
+
+ private:
+  // 1 true, -1 false, 0 unassigned
+  int value(int literal) const {
+    const int v = values_[static_cast<size_t>(std::abs(literal))];
+    return literal > 0 ? v : -v;
+  }
+
+  void assign(int literal) {
+    values_[static_cast<size_t>(std::abs(literal))] = literal > 0 ? 1 : -1;
+    trail_.push_back(literal);
+  }
+
+  void undo(size_t mark) {
+    while (trail_.size() > mark) {
+      values_[static_cast<size_t>(std::abs(trail_.back()))] = 0;
+      trail_.pop_back();
+    }
+  }
+
+  bool satisfied(const Clause& clause) const {
+    for (int literal : clause) {
+      if (value(literal) > 0) {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  // Unit propagation to a fixed point; false on a conflict
+  bool propagate() {
+    for (bool changed = true; changed;) {
+      changed = false;
+      for (const Clause& clause : cnf_.clauses) {
+        if (satisfied(clause)) {
+          continue;
+        }
+        int unassigned = 0;
+        int last = 0;
+        for (int literal : clause) {
+          if (value(literal) == 0) {
+            ++unassigned;
+            last = literal;
+          }
+        }
+        if (unassigned == 0) {
+          ++stats_.conflicts;
+          return false;
+        }
+        if (unassigned == 1) {
+          assign(last);
+          ++stats_.propagations;
+          changed = true;
+        }
+      }
+    }
+    return true;
+  }
+
+  // Sets every pure literal among the unsatisfied clauses
+  void eliminate_pure_literals() {
+    // Bit 1 for a positive occurrence, bit 2 for a negative one
+    std::vector<uint8_t> signs(values_.size(), 0);
+    for (const Clause& clause : cnf_.clauses) {
+      if (satisfied(clause)) {
+        continue;
+      }
+      for (int literal : clause) {
+        if (value(literal) == 0) {
+          signs[static_cast<size_t>(std::abs(literal))] |= literal > 0 ? 1 : 2;
+        }
+      }
+    }
+    for (size_t v = 1; v < signs.size(); ++v) {
+      if (signs[v] == 1 || signs[v] == 2) {
+        assign(signs[v] == 1 ? static_cast<int>(v) : -static_cast<int>(v));
+        ++stats_.pure_literals;
+      }
+    }
+  }
+
+  // The first unassigned literal of a shortest unsatisfied clause, to
+  // make the most of propagation; 0 if every clause is satisfied
+  int choose() const {
+    int best = 0;
+    size_t best_size = SIZE_MAX;
+    for (const Clause& clause : cnf_.clauses) {
+      if (satisfied(clause)) {
+        continue;
+      }
+      size_t size = 0;
+      int first = 0;
+      for (int literal : clause) {
+        if (value(literal) == 0) {
+          ++size;
+          first = first == 0 ? literal : first;
+        }
+      }
+      if (size < best_size) {
+        best_size = size;
+        best = first;
+      }
+    }
+    return best;
+  }
+
+  bool search() {
+    const size_t mark = trail_.size();
+    if (!propagate()) {
+      undo(mark);
+      return false;
+    }
+    eliminate_pure_literals();
+    const int literal = choose();
+    if (literal == 0) {
+      return true;
+    }
+    const size_t decided = trail_.size();
+    for (int guess : {literal, -literal}) {
+      ++stats_.decisions;
+      assign(guess);
+      if (search()) {
+        return true;
+      }
+      undo(decided);
+    }
+    undo(mark);
+    return false;
+  }
+
+  const Cnf& cnf_;
+  std::vector<int> values_;
+  std::vector<int> trail_;  // Assigned literals, in order
+  SatStats stats_;
+};
+
+inline SatResult solve_sat(const Cnf& cnf) { return Dpll(cnf).solve(); }
+
+// SAT competition output: "s SATISFIABLE" and the model as a "v" line of
+// literals ended by 0, or "s UNSATISFIABLE"
+inline std::string format_sat_result(const SatResult& result) {
+  if (!result.satisfiable) {
+    return "s UNSATISFIABLE\n";
+  }
+  std::string text = "s SATISFIABLE\nv";
+  for (size_t v = 1; v < result.model.size(); ++v) {
+    text += ' ' + std::string(result.model[v] ? "" : "-") + std::to_string(v);
+  }
+  return text + " 0\n";
+}
@@ -0,0 +1,161 @@
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Sat.h"
+#include "gtest/gtest.h"
+
+// Every assignment, for up to about 20 variables
+bool satisfiable_naive(const Cnf& cnf) {
+  Model model(static_cast<size_t>(cnf.variables) + 1, false);
+  for (uint64_t bits = 0; bits < (uint64_t{1} << cnf.variables); ++bits) {
+    for (int v = 1; v <= cnf.variables; ++v) {
+      model[v] = (bits >> (v - 1)) & 1;
+    }
+    if (satisfies(cnf, model)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+// n + 1 pigeons in n holes: variable p * n + h + 1 says pigeon p is in
+// hole h. Every pigeon is somewhere, and no two share a hole. Unsatisfiable,
+// and hard for DPLL: it has to try everything.
+Cnf pigeonhole(int holes) {
+  Cnf cnf{(holes + 1) * holes, {}};
+  auto in = [&](int p, int h) { return p * holes + h + 1; };
+  for (int p = 0; p <= holes; ++p) {
+    Clause somewhere;
+    for (int h = 0; h < holes; ++h) {
+      somewhere.push_back(in(p, h));
+    }
+    cnf.clauses.push_back(somewhere);
+  }
+  for (int h = 0; h < holes; ++h) {
+    for (int p = 0; p <= holes; ++p) {
+      for (int q = p + 1; q <= holes; ++q) {
+        cnf.clauses.push_back({-in(p, h), -in(q, h)});
+      }
+    }
+  }
+  return cnf;
+}
+
+// n queens: variable r * n + c + 1 says there's a queen at (r, c). A queen
+// in every row, and none sharing a row, column, or diagonal.
+Cnf queens(int n) {
+  Cnf cnf{n * n, {}};
+  auto at = [&](int r, int c) { return r * n + c + 1; };
+  for (int r = 0; r < n; ++r) {
+    Clause row;
+    for (int c = 0; c < n; ++c) {
+      row.push_back(at(r, c));
+    }
+    cnf.clauses.push_back(row);
+  }
+  for (int a = 0; a < n * n; ++a) {
+    for (int b = a + 1; b < n * n; ++b) {
+      const int ra = a / n;
+      const int ca = a % n;
+      const int rb = b / n;
+      const int cb = b % n;
+      if (ra == rb || ca == cb || ra - ca == rb - cb || ra + ca == rb + cb) {
+        cnf.clauses.push_back({-(a + 1), -(b + 1)});
+      }
+    }
+  }
+  return cnf;
+}
+
+TEST(Sat, Dimacs) {
+  const Cnf cnf = parse_dimacs(
+      "c a comment\n"
+      "p cnf 3 2\n"
+      "1 -3 0\n"
+      "2 3\n"
+      "-1 0\n"
+      "%\n"
+      "0\n");
+  EXPECT_EQ(cnf.variables, 3);
+  EXPECT_EQ(cnf.clauses, (std::vector<Clause>{{1, -3}, {2, 3, -1}}));
+  EXPECT_THROW(parse_dimacs("1 2 0\n"), ParseError);
+  EXPECT_THROW(parse_dimacs("p cnf 2 1\n1 3 0\n"), ParseError);
+  EXPECT_THROW(parse_dimacs("p cnf 2 2\n1 2 0\n"), ParseError);
+  EXPECT_THROW(parse_dimacs("p cnf 2 1\n1 2\n"), ParseError);
+  EXPECT_THROW(parse_dimacs("p cnf 2 1\n1 x 0\n"), ParseError);
+  EXPECT_THROW(parse_dimacs("p dnf 2 1\n1 0\n"), ParseError);
+}
+
+TEST(Sat, Small) {
+  const SatResult sat = solve_sat(parse_dimacs("p cnf 3 2\n1 -3 0\n2 3 -1 0"));
+  ASSERT_TRUE(sat.satisfiable);
+  EXPECT_TRUE(satisfies(parse_dimacs("p cnf 3 2\n1 -3 0\n2 3 -1 0"),
+                        sat.model));
+  EXPECT_FALSE(solve_sat(Cnf{1, {{1}, {-1}}}).satisfiable);
+  EXPECT_FALSE(solve_sat(Cnf{2, {{1, 2}, {}}}).satisfiable);
+  EXPECT_TRUE(solve_sat(Cnf{0, {}}).satisfiable);
+
+  // Found by propagation alone, without a guess
+  const SatResult forced = solve_sat(Cnf{3, {{1}, {-1, 2}, {-2, 3}}});
+  ASSERT_TRUE(forced.satisfiable);
+  EXPECT_EQ(forced.stats.decisions, 0u);
+  EXPECT_EQ(forced.stats.propagations, 3u);
+  EXPECT_EQ(format_sat_result(forced), "s SATISFIABLE\nv 1 2 3 0\n");
+  EXPECT_EQ(format_sat_result(solve_sat(Cnf{1, {{1}, {-1}}})),
+            "s UNSATISFIABLE\n");
+
+  // And by pure literals alone
+  const SatResult pure = solve_sat(Cnf{3, {{1, 2}, {1, -3}, {2, -3}}});
+  ASSERT_TRUE(pure.satisfiable);
+  EXPECT_EQ(pure.stats.decisions, 0u);
+  EXPECT_GT(pure.stats.pure_literals, 0u);
+}
+
+TEST(Sat, Puzzles) {
+  for (int holes = 1; holes <= 6; ++holes) {
+    EXPECT_FALSE(solve_sat(pigeonhole(holes)).satisfiable) << holes;
+  }
+  for (int n = 1; n <= 10; ++n) {
+    const Cnf cnf = queens(n);
+    const SatResult result = solve_sat(cnf);
+    EXPECT_EQ(result.satisfiable, n == 1 || n >= 4) << n;
+    if (result.satisfiable) {
+      EXPECT_TRUE(satisfies(cnf, result.model));
+    }
+  }
+}
+
+// Random 3-SAT around 4.26 clauses per variable, where about half the
+// formulas are satisfiable
+TEST(Sat, MatchesBruteForce) {
+  Rng gen(207);
+  int satisfiable = 0;
+  for (int t = 0; t < 300; ++t) {
+    const int n = uniform_int(gen, 1, 14);
+    Cnf cnf{n, {}};
+    const int clauses = static_cast<int>(4.26 * n + 0.5);
+    for (int i = 0; i < clauses; ++i) {
+      Clause clause;
+      for (int k = 0; k < 3; ++k) {
+        const int v = uniform_int(gen, 1, n);
+        clause.push_back(bernoulli(gen, 0.5) ? v : -v);
+      }
+      cnf.clauses.push_back(clause);
+    }
+    const SatResult result = solve_sat(cnf);
+    ASSERT_EQ(result.satisfiable, satisfiable_naive(cnf)) << t;
+    if (result.satisfiable) {
+      ASSERT_TRUE(satisfies(cnf, result.model));
+      ++satisfiable;
+    }
+  }
+  EXPECT_GT(satisfiable, 50);
+  EXPECT_LT(satisfiable, 250);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,153 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <numeric>
+#include <vector>
+
+#include "../union-find/UnionFind.h"
+
+// Greedy scheduling, where the proof is an exchange argument: take any
+// optimal schedule that differs from the greedy one, and swap two of its
+// jobs to make it agree with the greedy one a little more, without making
+// it any worse. Repeat until the two are the same.
+
+// Minimizing the maximum lateness. One machine runs the jobs back to back
+// from time 0; a job finishing at f is late by max(0, f - deadline).
+struct Job {
+  int64_t duration;
+  int64_t deadline;
+};
+
+struct Schedule {
+  int64_t max_lateness{0};
+  std::vector<int> order;  // Indices into the jobs, in the order they run
+};
+
+// Lateness of running the jobs in the given order
+inline int64_t max_lateness(const std::vector<Job>& jobs,
+                            const std::vector<int>& order) {
+  int64_t time = 0;
+  int64_t worst = 0;
+  for (int j : order) {
+    time += jobs[j].duration;
+    worst = std::max(worst, time - jobs[j].deadline);
+  }
+  return worst;
+}
+
+// Earliest deadline first. Say a schedule runs job a right before job b,
+// whose deadline is earlier. Swap them: b now finishes earlier, and a
+// finishes when b used to, which is less late for a than it was for b,
+// because a's deadline is later. Neither swap makes the maximum worse, so
+// sorting by deadline is optimal, without ever looking at the durations.
+// O(n log n).
+inline Schedule minimize_max_lateness(const std::vector<Job>& jobs) {
+  Schedule schedule;
+  schedule.order.resize(jobs.size());
+  std::iota(schedule.order.begin(), schedule.order.end(), 0);
+  std::stable_sort(schedule.order.begin(), schedule.order.end(),
+                   [&](int a, int b) {
+                     return jobs[a].deadline < jobs[b].deadline;
+                   });
+  schedule.max_lateness = max_lateness(jobs, schedule.order);
+  return schedule;
+}
+
+// O(n! * n): every order
+inline int64_t min_max_lateness_naive(const std::vector<Job>& jobs) {
+  std::vector<int> order(jobs.size());
+  std::iota(order.begin(), order.end(), 0);
+  int64_t best = max_lateness(jobs, order);
+  while (std::next_permutation(order.begin(), order.end())) {
+    best = std::min(best, max_lateness(jobs, order));
+  }
+  return best;
+}
+
+// Job sequencing with deadlines: every job takes one unit of time, and pays
+// its profit only if it's done by its deadline, i.e. runs in one of the time
+// slots 1..deadline. Pick the jobs (and slots) that pay the most.
+struct ProfitJob {
+  int deadline;
+  int64_t profit;
+};
+
+struct JobSequence {
+  int64_t profit{0};
+  std::vector<int> slots;  // slots[t - 1] is the job in slot t, or -1
+};
+
+// Most profitable first, each in the latest free slot by its deadline, which
+// leaves the earlier slots open for jobs with earlier deadlines. If an
+// optimal choice leaves out a job the greedy takes, it can swap that job in
+// for a job it chose instead, which pays no more.
+//
+// Finding the latest free slot is a union-find: slots that are taken merge
+// with the slot before them, and each set remembers the one free slot at
+// its bottom. Slot 0 stands for "none left". O(n log n) for the sort, then
+// nearly O(1) per job.
+inline JobSequence sequence_jobs(const std::vector<ProfitJob>& jobs) {
+  int last_slot = 0;
+  for (const auto& job : jobs) {
+    assert(job.deadline >= 0 && "deadlines are slot numbers");
+    last_slot = std::max(last_slot, job.deadline);
+  }
+  const int horizon = std::min(last_slot, static_cast<int>(jobs.size()));
+
+  std::vector<int> by_profit(jobs.size());
+  std::iota(by_profit.begin(), by_profit.end(), 0);
+  std::stable_sort(by_profit.begin(), by_profit.end(), [&](int a, int b) {
+    return jobs[a].profit > jobs[b].profit;
+  });
+
+  UnionFind taken(horizon + 1);
+  std::vector<int> free_slot(horizon + 1);  // For roots only
+  std::iota(free_slot.begin(), free_slot.end(), 0);
+
+  JobSequence result;
+  result.slots.assign(horizon, -1);
+  for (int j : by_profit) {
+    if (jobs[j].profit <= 0) {
+      break;  // Doing it is no better than not
+    }
+    const int slot = free_slot[taken.find(std::min(jobs[j].deadline, horizon))];
+    if (slot == 0) {
+      continue;
+    }
+    result.slots[slot - 1] = j;
+    result.profit += jobs[j].profit;
+    const int below = free_slot[taken.find(slot - 1)];
+    taken.unite(slot, slot - 1);
+    free_slot[taken.find(slot)] = below;
+  }
+  return result;
+}
+
+// O(2^n * n): every subset of the jobs that fits, i.e. whose k-th earliest
+// deadline is at least k
+inline int64_t max_profit_naive(const std::vector<ProfitJob>& jobs) {
+  assert(jobs.size() < 32);
+  int64_t best = 0;
+  for (uint32_t mask = 0; mask < (uint32_t{1} << jobs.size()); ++mask) {
+    std::vector<int> deadlines;
+    int64_t profit = 0;
+    for (size_t j = 0; j < jobs.size(); ++j) {
+      if (mask >> j & 1) {
+        deadlines.push_back(jobs[j].deadline);
+        profit += jobs[j].profit;
+      }
+    }
+    std::sort(deadlines.begin(), deadlines.end());
+    bool fits = true;
+    for (size_t k = 0; k < deadlines.size(); ++k) {
+      fits = fits && deadlines[k] >= static_cast<int>(k + 1);
+    }
+    if (fits) {
+      best = std::max(best, profit);
+    }
+  }
+  return best;
+}
@@ -0,0 +1,128 @@
+#include <algorithm>
+#include <cstdint>
+#include <random>
+#include <set>
+#include <vector>
+
+#include "Scheduling.h"
+#include "gtest/gtest.h"
+
+std::vector<Job> random_jobs(std::mt19937& gen, size_t n) {
+  std::uniform_int_distribution<int64_t> duration(1, 10);
+  std::uniform_int_distribution<int64_t> deadline(0, 30);
+  std::vector<Job> jobs(n);
+  for (auto& job : jobs) {
+    job = {duration(gen), deadline(gen)};
+  }
+  return jobs;
+}
+
+std::vector<ProfitJob> random_profit_jobs(std::mt19937& gen, size_t n) {
+  std::uniform_int_distribution<int> deadline(0, 6);
+  std::uniform_int_distribution<int64_t> profit(0, 50);
+  std::vector<ProfitJob> jobs(n);
+  for (auto& job : jobs) {
+    job = {deadline(gen), profit(gen)};
+  }
+  return jobs;
+}
+
+// Every job at most once, each slot by its job's deadline, and the profit
+// adds up
+bool is_valid_sequence(const std::vector<ProfitJob>& jobs,
+                       const JobSequence& sequence) {
+  std::set<int> used;
+  int64_t profit = 0;
+  for (size_t t = 0; t < sequence.slots.size(); ++t) {
+    const int j = sequence.slots[t];
+    if (j == -1) {
+      continue;
+    }
+    if (!used.insert(j).second || jobs[j].deadline < static_cast<int>(t + 1)) {
+      return false;
+    }
+    profit += jobs[j].profit;
+  }
+  return profit == sequence.profit;
+}
+
+TEST(Scheduling, MaxLatenessExample) {
+  std::vector<Job> jobs = {{3, 6}, {2, 8}, {1, 9}, {4, 9}, {3, 14}, {2, 15}};
+  auto schedule = minimize_max_lateness(jobs);
+
+  EXPECT_EQ(schedule.order, (std::vector<int>{0, 1, 2, 3, 4, 5}));
+  EXPECT_EQ(schedule.max_lateness, 1);  // Job 3 finishes at 10
+  EXPECT_EQ(minimize_max_lateness({}).max_lateness, 0);
+}
+
+TEST(Scheduling, EarliestDeadlineFirstIsOptimal) {
+  std::mt19937 gen(154);
+  for (int trial = 0; trial < 200; ++trial) {
+    const size_t n = std::uniform_int_distribution<size_t>(0, 7)(gen);
+    auto jobs = random_jobs(gen, n);
+    auto schedule = minimize_max_lateness(jobs);
+
+    ASSERT_EQ(schedule.max_lateness, min_max_lateness_naive(jobs));
+    ASSERT_EQ(schedule.max_lateness, max_lateness(jobs, schedule.order));
+  }
+}
+
+TEST(Scheduling, ExchangeArgument) {
+  // From any order, swapping adjacent jobs whose deadlines are out of order
+  // never makes things worse, and ends at the greedy order's lateness
+  std::mt19937 gen(155);
+  for (int trial = 0; trial < 200; ++trial) {
+    auto jobs = random_jobs(gen, 12);
+    std::vector<int> order(jobs.size());
+    for (size_t i = 0; i < order.size(); ++i) {
+      order[i] = static_cast<int>(i);
+    }
+    std::shuffle(order.begin(), order.end(), gen);
+
+    int64_t lateness = max_lateness(jobs, order);
+    for (bool swapped = true; swapped;) {
+      swapped = false;
+      for (size_t i = 0; i + 1 < order.size(); ++i) {
+        if (jobs[order[i]].deadline > jobs[order[i + 1]].deadline) {
+          std::swap(order[i], order[i + 1]);
+          const int64_t after = max_lateness(jobs, order);
+          ASSERT_LE(after, lateness);
+          lateness = after;
+          swapped = true;
+        }
+      }
+    }
+    ASSERT_EQ(lateness, minimize_max_lateness(jobs).max_lateness);
+  }
+}
+
+TEST(Scheduling, JobSequencingExample) {
+  std::vector<ProfitJob> jobs = {{2, 100}, {1, 19}, {2, 27}, {1, 25}, {3, 15}};
+  auto sequence = sequence_jobs(jobs);
+
+  EXPECT_EQ(sequence.profit, 142);
+  EXPECT_EQ(sequence.slots, (std::vector<int>{2, 0, 4}));
+  EXPECT_TRUE(is_valid_sequence(jobs, sequence));
+
+  // Deadlines far in the future only need as many slots as there are jobs
+  sequence = sequence_jobs({{1'000'000'000, 5}, {1'000'000'000, 7}});
+  EXPECT_EQ(sequence.profit, 12);
+  EXPECT_EQ(sequence.slots, (std::vector<int>{0, 1}));
+}
+
+TEST(Scheduling, JobSequencingMatchesBruteForce) {
+  std::mt19937 gen(156);
+  for (int trial = 0; trial < 300; ++trial) {
+    const size_t n = std::uniform_int_distribution<size_t>(0, 10)(gen);
+    auto jobs = random_profit_jobs(gen, n);
+    auto sequence = sequence_jobs(jobs);
+
+    ASSERT_EQ(sequence.profit, max_profit_naive(jobs));
+    ASSERT_TRUE(is_valid_sequence(jobs, sequence));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,73 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <utility>
+#include <vector>
+
+// Segment tree: point updates and range queries under any associative
+// combine with an identity (sums, minima, maxima, gcds, matrix products),
+// both O(log n). The Fenwick tree in fenwick-tree/ is smaller and faster
+// for sums, but needs subtraction to answer a range.
+//
+// Bottom-up, in an array of 2n: the values are the leaves tree_[n..2n),
+// and node i above them combines its children 2i and 2i + 1. A query
+// climbs from both ends of the range at once, taking in each node that
+// hangs off the range's edge, and keeps the pieces from the left and the
+// right apart so that combine needn't be commutative.
+template <typename T, typename Combine = std::plus<T>>
+class SegmentTree {
+ public:
+  SegmentTree(const std::vector<T>& values, T identity,
+              Combine combine = Combine())
+      : n_(values.size()),
+        identity_(std::move(identity)),
+        combine_(std::move(combine)),
+        tree_(2 * values.size(), identity_) {
+    std::copy(values.begin(), values.end(), tree_.begin() + n_);
+    for (size_t i = n_; i-- > 1;) {
+      tree_[i] = combine_(tree_[2 * i], tree_[2 * i + 1]);
+    }
+  }
+
+  size_t size() const { return n_; }
+
+  const T& get(size_t i) const {
+    assert(i < n_ && "index out of range");
+    return tree_[n_ + i];
+  }
+
+  void set(size_t i, T value) {
+    assert(i < n_ && "index out of range");
+    i += n_;
+    tree_[i] = std::move(value);
+    for (i /= 2; i >= 1; i /= 2) {
+      tree_[i] = combine_(tree_[2 * i], tree_[2 * i + 1]);
+    }
+  }
+
+  // The values at [begin, end) combined in order, or the identity if the
+  // range is empty
+  T query(size_t begin, size_t end) const {
+    assert(begin <= end && end <= n_ && "range out of bounds");
+    T left = identity_;
+    T right = identity_;
+    for (begin += n_, end += n_; begin < end; begin /= 2, end /= 2) {
+      if (begin & 1) {
+        left = combine_(left, tree_[begin++]);
+      }
+      if (end & 1) {
+        right = combine_(tree_[--end], right);
+      }
+    }
+    return combine_(left, right);
+  }
+
+ private:
+  size_t n_;
+  T identity_;
+  Combine combine_;
+  std::vector<T> tree_;  // tree_[0] is unused
+};
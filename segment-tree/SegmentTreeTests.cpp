@@ -0,0 +1,75 @@
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "SegmentTree.h"
+#include "gtest/gtest.h"
+
+TEST(SegmentTree, Sums) {
+  SegmentTree<int> tree({5, 2, 9, -3, 5, 20, 10, -7}, 0);
+  EXPECT_EQ(tree.size(), 8u);
+  EXPECT_EQ(tree.query(0, 0), 0);
+  EXPECT_EQ(tree.query(0, 8), 41);
+  EXPECT_EQ(tree.query(2, 5), 11);
+  tree.set(3, 7);
+  EXPECT_EQ(tree.get(3), 7);
+  EXPECT_EQ(tree.query(2, 5), 21);
+  EXPECT_EQ(SegmentTree<int>({}, 0).query(0, 0), 0);
+}
+
+// Concatenation isn't commutative, so this checks that queries combine in
+// order
+TEST(SegmentTree, KeepsOrder) {
+  std::vector<std::string> letters;
+  for (char c = 'a'; c <= 'k'; ++c) {
+    letters.push_back(std::string(1, c));
+  }
+  SegmentTree<std::string> tree(letters, "");
+  EXPECT_EQ(tree.query(0, 11), "abcdefghijk");
+  EXPECT_EQ(tree.query(3, 10), "defghij");
+  tree.set(5, "XY");
+  EXPECT_EQ(tree.query(4, 7), "eXYg");
+}
+
+TEST(SegmentTree, MatchesArray) {
+  Rng gen(212);
+  auto larger = [](int64_t a, int64_t b) { return std::max(a, b); };
+  const int64_t lowest = std::numeric_limits<int64_t>::min();
+  for (size_t n : {1, 2, 7, 64, 100}) {
+    std::vector<int64_t> values(n);
+    for (auto& v : values) {
+      v = uniform_int<int64_t>(gen, -100, 100);
+    }
+    SegmentTree<int64_t> sums(values, 0);
+    SegmentTree<int64_t, decltype(larger)> maxima(values, lowest, larger);
+    for (int step = 0; step < 300; ++step) {
+      const size_t i = uniform_below(gen, n);
+      values[i] = uniform_int<int64_t>(gen, -100, 100);
+      sums.set(i, values[i]);
+      maxima.set(i, values[i]);
+
+      size_t begin = uniform_below(gen, n + 1);
+      size_t end = uniform_below(gen, n + 1);
+      if (begin > end) {
+        std::swap(begin, end);
+      }
+      int64_t sum = 0;
+      int64_t max = lowest;
+      for (size_t j = begin; j < end; ++j) {
+        sum += values[j];
+        max = std::max(max, values[j]);
+      }
+      ASSERT_EQ(sums.query(begin, end), sum);
+      ASSERT_EQ(maxima.query(begin, end), max);
+    }
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
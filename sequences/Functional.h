@@ -0,0 +1,97 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <numeric>
+#include <vector>
+
+// The sequence etudes again, without index loops: as folds
+// (std::accumulate), as scans (std::partial_sum), and as tail recursion.
+// Each gives the same answer as its loop in Sequences.h or patience/; the
+// point is to see the same algorithm written as a combination of standard
+// pieces. C++ doesn't promise to turn tail calls into jumps, although
+// optimizing compilers do, so the tail-recursive versions are for reading
+// rather than for inputs of a million elements.
+
+// Kadane as a fold: the state carried from element to element is the best
+// sum so far and the best sum of a suffix so far
+struct KadaneState {
+  int64_t best;
+  int64_t suffix;
+};
+
+inline KadaneState kadane_step(KadaneState state, int64_t x) {
+  const int64_t suffix = std::max<int64_t>(state.suffix + x, 0);
+  return {std::max(state.best, suffix), suffix};
+}
+
+inline int64_t max_subarray_sum_fold(const std::vector<int64_t>& nums) {
+  return std::accumulate(nums.begin(), nums.end(), KadaneState{0, 0},
+                         kadane_step)
+      .best;
+}
+
+// And as scans, which unlike kadane_step are associative, so they could
+// run in parallel: the sum of nums[i, j) is prefix[j] - prefix[i], so the
+// best run ending at j starts where the prefix sum so far was lowest
+inline int64_t max_subarray_sum_scan(const std::vector<int64_t>& nums) {
+  std::vector<int64_t> prefix(nums.size() + 1, 0);
+  std::partial_sum(nums.begin(), nums.end(), prefix.begin() + 1);
+  std::vector<int64_t> lowest(prefix.size());
+  std::partial_sum(prefix.begin(), prefix.end(), lowest.begin(),
+                   [](int64_t a, int64_t b) { return std::min(a, b); });
+  return std::transform_reduce(
+      prefix.begin(), prefix.end(), lowest.begin(), int64_t{0},
+      [](int64_t a, int64_t b) { return std::max(a, b); },
+      std::minus<int64_t>());
+}
+
+// And by tail recursion, with the fold's state as the accumulating
+// arguments
+inline int64_t max_subarray_sum_tail(const std::vector<int64_t>& nums,
+                                     size_t i = 0, int64_t best = 0,
+                                     int64_t suffix = 0) {
+  if (i == nums.size()) {
+    return best;
+  }
+  const KadaneState next = kadane_step({best, suffix}, nums[i]);
+  return max_subarray_sum_tail(nums, i + 1, next.best, next.suffix);
+}
+
+// Horner's rule is already a fold, with the value so far as the state
+inline double evaluate_polynomial_fold(const std::vector<double>& coefficients,
+                                       double x) {
+  return std::accumulate(coefficients.begin(), coefficients.end(), 0.0,
+                         [x](double p, double c) { return p * x + c; });
+}
+
+inline double evaluate_polynomial_tail(const std::vector<double>& coefficients,
+                                       double x, size_t i = 0, double p = 0.0) {
+  if (i == coefficients.size()) {
+    return p;
+  }
+  return evaluate_polynomial_tail(coefficients, x, i + 1,
+                                  p * x + coefficients[i]);
+}
+
+// The length of a longest strictly increasing subsequence, as a fold over
+// the tops of the patience piles: each value goes on the leftmost pile
+// whose top isn't less than it, or starts a new pile. accumulate moves the
+// state from step to step, so the piles aren't copied.
+template <typename T, typename Compare = std::less<T>>
+size_t lis_length_fold(const std::vector<T>& values, Compare less = Compare()) {
+  return std::accumulate(values.begin(), values.end(), std::vector<T>(),
+                         [&](std::vector<T> tops, const T& x) {
+                           auto pile = std::lower_bound(tops.begin(),
+                                                        tops.end(), x, less);
+                           if (pile == tops.end()) {
+                             tops.push_back(x);
+                           } else {
+                             *pile = x;
+                           }
+                           return tops;
+                         })
+      .size();
+}
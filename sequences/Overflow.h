@@ -0,0 +1,131 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <type_traits>
+#include <vector>
+
+#include "../errors/Error.h"
+#include "Sequences.h"
+
+// Arithmetic modes for the sequence etudes whose sums can outgrow their
+// integers. max_subarray adds int64_ts, which is undefined behavior past
+// 2^63 and in practice wraps around; the counts of longest increasing
+// subsequences grow exponentially and wrap around modulo 2^64. Each
+// algorithm here takes a mode that decides what an addition does when it
+// doesn't fit:
+//
+// - Wrapping: wraps around, like the unsigned arithmetic it's done in;
+// - Checked: fails, and the whole computation returns a kOverflow error;
+// - Saturating: sticks at the largest (or smallest) value of the type.
+//
+// A mode's add(a, b, sum) sets sum and returns whether to carry on.
+
+struct Wrapping {
+  template <typename T>
+  static bool add(T a, T b, T& sum) {
+    using Unsigned = std::make_unsigned_t<T>;
+    sum = static_cast<T>(static_cast<Unsigned>(a) + static_cast<Unsigned>(b));
+    return true;
+  }
+};
+
+struct Checked {
+  template <typename T>
+  static bool add(T a, T b, T& sum) {
+    return !__builtin_add_overflow(a, b, &sum);
+  }
+};
+
+struct Saturating {
+  template <typename T>
+  static bool add(T a, T b, T& sum) {
+    if (__builtin_add_overflow(a, b, &sum)) {
+      // Only same signs overflow, and then in their direction
+      sum = b > 0 ? std::numeric_limits<T>::max()
+                  : std::numeric_limits<T>::min();
+    }
+    return true;
+  }
+};
+
+// Kadane, exactly as in max_subarray, with its one addition done in Mode.
+// A suffix sum only grows when both it and the element are positive, and
+// then it's the sum of an actual run, so the answer overflows exactly when
+// some addition does: Checked fails precisely when the best sum
+// doesn't fit in 64 bits, and Saturating gives the best sum capped at
+// INT64_MAX, with a run that reaches the cap.
+template <typename Mode>
+Result<Subarray> max_subarray_in(const std::vector<int64_t>& nums) {
+  Subarray best = {0, 0, 0};
+  Subarray suffix = {0, 0, 0};
+  for (size_t i = 0; i < nums.size(); ++i) {
+    int64_t sum;
+    if (!Mode::add(suffix.sum, nums[i], sum)) {
+      return Error{ErrorKind::kOverflow, "subarray sum overflows int64_t"};
+    }
+    if (sum <= 0) {
+      suffix = {0, i + 1, i + 1};
+    } else {
+      suffix.sum = sum;
+      suffix.end = i + 1;
+    }
+    if (suffix.sum > best.sum) {
+      best = suffix;
+    }
+  }
+  return best;
+}
+
+inline Result<Subarray> try_max_subarray(const std::vector<int64_t>& nums) {
+  return max_subarray_in<Checked>(nums);
+}
+
+inline Subarray max_subarray_saturating(const std::vector<int64_t>& nums) {
+  return max_subarray_in<Saturating>(nums).value();
+}
+
+// The number of longest strictly increasing subsequences, counted as sets
+// of positions, by the O(n^2) induction in manber/lis.cpp: along with the
+// length of the longest subsequence ending at each position, how many of
+// them end there. [2, 1, 4, 3, 6, 5, ...] has 2^(n/2) of them.
+template <typename Mode, typename Count = uint64_t, typename T>
+Result<Count> count_longest_increasing_in(const std::vector<T>& values) {
+  std::vector<size_t> length(values.size(), 1);
+  std::vector<Count> count(values.size(), 1);
+  for (size_t i = 0; i < values.size(); ++i) {
+    for (size_t j = 0; j < i; ++j) {
+      if (!(values[j] < values[i])) {
+        continue;
+      }
+      if (length[j] + 1 > length[i]) {
+        length[i] = length[j] + 1;
+        count[i] = count[j];
+      } else if (length[j] + 1 == length[i] &&
+                 !Mode::add(count[i], count[j], count[i])) {
+        return Error{ErrorKind::kOverflow, "subsequence count overflows"};
+      }
+    }
+  }
+  const size_t longest =
+      values.empty() ? 0 : *std::max_element(length.begin(), length.end());
+  Count total = 0;
+  for (size_t i = 0; i < values.size(); ++i) {
+    if (length[i] == longest && !Mode::add(total, count[i], total)) {
+      return Error{ErrorKind::kOverflow, "subsequence count overflows"};
+    }
+  }
+  return total;
+}
+
+template <typename T>
+Result<uint64_t> try_count_longest_increasing(const std::vector<T>& values) {
+  return count_longest_increasing_in<Checked>(values);
+}
+
+template <typename T>
+uint64_t count_longest_increasing_saturating(const std::vector<T>& values) {
+  return count_longest_increasing_in<Saturating>(values).value();
+}
@@ -0,0 +1,66 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+// The sequence etudes from manber/, as functions to include rather than
+// programs to run: no I/O and no exceptions, so they build anywhere,
+// including embedded targets (see embedded/).
+
+// A run nums[begin, end) and its sum
+struct Subarray {
+  int64_t sum;
+  size_t begin;
+  size_t end;
+
+  bool operator==(const Subarray&) const = default;
+};
+
+// Kadane: the maximum consecutive subsequence, carrying along the best
+// suffix as in manber/maximum_consecutive_subsequence.cpp. A suffix whose
+// sum drops to 0 or below can only hurt what comes after it, so it's
+// dropped and the next suffix starts fresh. The empty run counts, so the
+// sum is never negative, and it comes back as {0, 0, 0}. O(n). The sums
+// must fit in 64 bits; Overflow.h has checked and saturating versions.
+inline Subarray max_subarray(const std::vector<int64_t>& nums) {
+  Subarray best = {0, 0, 0};
+  Subarray suffix = {0, 0, 0};
+  for (size_t i = 0; i < nums.size(); ++i) {
+    if (suffix.sum + nums[i] <= 0) {
+      suffix = {0, i + 1, i + 1};
+    } else {
+      suffix.sum += nums[i];
+      suffix.end = i + 1;
+    }
+    if (suffix.sum > best.sum) {
+      best = suffix;
+    }
+  }
+  return best;
+}
+
+// O(n^2), for the tests: every start, every end
+inline int64_t max_subarray_sum_naive(const std::vector<int64_t>& nums) {
+  int64_t best = 0;
+  for (size_t i = 0; i < nums.size(); ++i) {
+    int64_t sum = 0;
+    for (size_t j = i; j < nums.size(); ++j) {
+      sum += nums[j];
+      best = sum > best ? sum : best;
+    }
+  }
+  return best;
+}
+
+// Horner's rule, as in manber/evaluate_polynomials.cpp: coefficients go
+// highest degree first, and each step multiplies what's there by x and adds
+// the next coefficient. n multiplications and n additions.
+inline double evaluate_polynomial(const std::vector<double>& coefficients,
+                                  double x) {
+  double p = 0.0;
+  for (double c : coefficients) {
+    p = p * x + c;
+  }
+  return p;
+}
@@ -0,0 +1,204 @@
+#include <cmath>
+#include <cstdint>
+#include <functional>
+#include <limits>
+#include <random>
+#include <vector>
+
+#include "../patience/Patience.h"
+#include "Functional.h"
+#include "Overflow.h"
+#include "Sequences.h"
+#include "gtest/gtest.h"
+
+TEST(Sequences, MaxSubarray) {
+  EXPECT_EQ(max_subarray({-2, 1, -3, 4, -1, 2, 1, -5, 4}),
+            (Subarray{6, 3, 7}));
+  EXPECT_EQ(max_subarray({}), (Subarray{0, 0, 0}));
+  EXPECT_EQ(max_subarray({-5, -2}), (Subarray{0, 0, 0}));
+  EXPECT_EQ(max_subarray({1, 2, 3}), (Subarray{6, 0, 3}));
+  EXPECT_EQ(max_subarray({5, -10, 3, 4}), (Subarray{7, 2, 4}));
+}
+
+TEST(Sequences, MaxSubarrayMatchesQuadratic) {
+  std::mt19937 gen(173);
+  std::uniform_int_distribution<int64_t> value(-50, 50);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<int64_t> nums(gen() % 40);
+    for (auto& x : nums) {
+      x = value(gen);
+    }
+    const Subarray best = max_subarray(nums);
+    int64_t sum = 0;
+    for (size_t i = best.begin; i < best.end; ++i) {
+      sum += nums[i];
+    }
+
+    ASSERT_EQ(best.sum, max_subarray_sum_naive(nums));
+    ASSERT_LE(best.begin, best.end);
+    ASSERT_LE(best.end, nums.size());
+    ASSERT_EQ(sum, best.sum);
+  }
+}
+
+TEST(Sequences, EvaluatePolynomial) {
+  EXPECT_EQ(evaluate_polynomial({}, 5.0), 0.0);
+  EXPECT_EQ(evaluate_polynomial({5.0}, 2.0), 5.0);
+  EXPECT_EQ(evaluate_polynomial({2, 3, 4, 5}, 2.0), 41.0);
+  EXPECT_EQ(evaluate_polynomial({1, -3, 2, -1, 5}, -1.0), 12.0);
+
+  // Against the sum of powers
+  std::mt19937 gen(174);
+  std::uniform_real_distribution<double> real(-2, 2);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<double> coefficients(gen() % 10);
+    for (auto& c : coefficients) {
+      c = real(gen);
+    }
+    const double x = real(gen);
+    double expected = 0.0;
+    for (size_t i = 0; i < coefficients.size(); ++i) {
+      expected += coefficients[i] *
+                  std::pow(x, static_cast<double>(coefficients.size() - 1 - i));
+    }
+    ASSERT_NEAR(evaluate_polynomial(coefficients, x), expected, 1e-9);
+  }
+}
+
+TEST(Functional, Kadane) {
+  const std::vector<int64_t> example = {-2, 1, -3, 4, -1, 2, 1, -5, 4};
+  EXPECT_EQ(max_subarray_sum_fold(example), 6);
+  EXPECT_EQ(max_subarray_sum_scan(example), 6);
+  EXPECT_EQ(max_subarray_sum_tail(example), 6);
+  EXPECT_EQ(max_subarray_sum_scan({}), 0);
+  EXPECT_EQ(max_subarray_sum_scan({-3, -1}), 0);
+
+  std::mt19937 gen(180);
+  std::uniform_int_distribution<int64_t> value(-50, 50);
+  for (int trial = 0; trial < 500; ++trial) {
+    std::vector<int64_t> nums(gen() % 40);
+    for (auto& x : nums) {
+      x = value(gen);
+    }
+    const int64_t expected = max_subarray(nums).sum;
+    ASSERT_EQ(max_subarray_sum_fold(nums), expected);
+    ASSERT_EQ(max_subarray_sum_scan(nums), expected);
+    ASSERT_EQ(max_subarray_sum_tail(nums), expected);
+  }
+}
+
+TEST(Functional, Horner) {
+  EXPECT_EQ(evaluate_polynomial_fold({2, 3, 4, 5}, 2.0), 41.0);
+  EXPECT_EQ(evaluate_polynomial_tail({2, 3, 4, 5}, 2.0), 41.0);
+  EXPECT_EQ(evaluate_polynomial_fold({}, 2.0), 0.0);
+
+  // The same operations in the same order, so exactly the same result
+  std::mt19937 gen(180);
+  std::uniform_real_distribution<double> real(-2, 2);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<double> coefficients(gen() % 10);
+    for (auto& c : coefficients) {
+      c = real(gen);
+    }
+    const double x = real(gen);
+    ASSERT_EQ(evaluate_polynomial_fold(coefficients, x),
+              evaluate_polynomial(coefficients, x));
+    ASSERT_EQ(evaluate_polynomial_tail(coefficients, x),
+              evaluate_polynomial(coefficients, x));
+  }
+}
+
+TEST(Functional, LisLength) {
+  EXPECT_EQ(lis_length_fold(std::vector<int>{9, 5, 7, 3, 8, 6, 2}), 3u);
+  EXPECT_EQ(lis_length_fold(std::vector<int>{}), 0u);
+  EXPECT_EQ(lis_length_fold(std::vector<int>{2, 2, 2}), 1u);
+  EXPECT_EQ(lis_length_fold(std::vector<int>{1, 2, 3}, std::greater<int>()),
+            1u);
+
+  std::mt19937 gen(180);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<int> values(gen() % 50);
+    for (auto& v : values) {
+      v = static_cast<int>(gen() % 20);
+    }
+    ASSERT_EQ(lis_length_fold(values), lis_length(values));
+  }
+}
+
+constexpr int64_t kMax = std::numeric_limits<int64_t>::max();
+
+// Kadane in 128 bits, which the tests' sums can't overflow
+static __int128 max_subarray_sum_wide(const std::vector<int64_t>& nums) {
+  __int128 best = 0;
+  __int128 suffix = 0;
+  for (int64_t x : nums) {
+    suffix = std::max<__int128>(suffix + x, 0);
+    best = std::max(best, suffix);
+  }
+  return best;
+}
+
+TEST(Overflow, MaxSubarray) {
+  EXPECT_EQ(try_max_subarray({kMax, 1}).error().kind, ErrorKind::kOverflow);
+  EXPECT_EQ(max_subarray_saturating({kMax, 1}), (Subarray{kMax, 0, 1}));
+  // Dips below the cap and comes back without overflowing
+  EXPECT_EQ(try_max_subarray({kMax, -1, 1}), (Subarray{kMax, 0, 1}));
+  EXPECT_EQ(try_max_subarray({kMax - 5, -1, 15}).error().kind,
+            ErrorKind::kOverflow);
+  EXPECT_EQ(max_subarray_saturating({kMax - 5, -1, 15}).sum, kMax);
+
+  // int32 extremes can't overflow 64-bit sums
+  const int64_t int32_max = std::numeric_limits<int32_t>::max();
+  const std::vector<int64_t> int32s(1000, int32_max);
+  EXPECT_EQ(try_max_subarray(int32s), (Subarray{1000 * int32_max, 0, 1000}));
+
+  std::mt19937 gen(181);
+  std::uniform_int_distribution<int64_t> value(-kMax / 2, kMax / 2);
+  for (int trial = 0; trial < 1000; ++trial) {
+    std::vector<int64_t> nums(gen() % 8);
+    for (auto& x : nums) {
+      x = gen() % 4 == 0 ? kMax : value(gen);
+    }
+    const __int128 wide = max_subarray_sum_wide(nums);
+    const auto checked = try_max_subarray(nums);
+    ASSERT_EQ(checked.ok(), wide <= kMax);
+    if (checked) {
+      ASSERT_EQ(checked.value(), max_subarray(nums));
+    } else {
+      ASSERT_EQ(checked.error().kind, ErrorKind::kOverflow);
+    }
+    ASSERT_EQ(max_subarray_saturating(nums).sum,
+              static_cast<int64_t>(std::min<__int128>(wide, kMax)));
+  }
+}
+
+TEST(Overflow, CountLongestIncreasing) {
+  EXPECT_EQ(try_count_longest_increasing(std::vector<int>{}), 0u);
+  EXPECT_EQ(try_count_longest_increasing(std::vector<int>{1, 3, 5, 4, 7}),
+            2u);
+  EXPECT_EQ(try_count_longest_increasing(std::vector<int>{2, 2, 2}), 3u);
+
+  // 2^pairs of them
+  auto pairs = [](int n) {
+    std::vector<int> values;
+    for (int i = 0; i < n; ++i) {
+      values.push_back(2 * i + 1);
+      values.push_back(2 * i);
+    }
+    return values;
+  };
+  EXPECT_EQ(try_count_longest_increasing(pairs(63)), uint64_t{1} << 63);
+  EXPECT_EQ(try_count_longest_increasing(pairs(64)).error().kind,
+            ErrorKind::kOverflow);
+  EXPECT_EQ(count_longest_increasing_saturating(pairs(64)),
+            std::numeric_limits<uint64_t>::max());
+  EXPECT_EQ(count_longest_increasing_in<Wrapping>(pairs(64)), 0u);
+  EXPECT_EQ((count_longest_increasing_in<Checked, unsigned __int128>(
+                pairs(64))),
+            static_cast<unsigned __int128>(1) << 64);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
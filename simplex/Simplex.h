@@ -0,0 +1,267 @@
+#pragma once
+
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Linear programming by the simplex method, for small problems in
+// standard form:
+//
+//   maximize c . x  subject to  A x <= b  and  x >= 0.
+//
+// Each constraint gets a slack variable, b - A x, which must also stay
+// nonnegative, and the method walks from vertex to vertex of the feasible
+// region, each time trading one variable that's zero (nonbasic) for one
+// that isn't (basic) along an edge that improves the objective. It keeps
+// the basic variables written in terms of the nonbasic ones, a dictionary,
+// and stops when no nonbasic variable would improve the objective if it
+// grew.
+//
+// Bland's rule picks, among the improving variables, the one with the
+// smallest index to enter, and among the tied constraints, the one whose
+// basic variable has the smallest index to leave. That's never the
+// fastest route, but it can't cycle forever through degenerate pivots
+// that improve nothing, as picking the largest improvement can.
+//
+// If b has negative entries, x = 0 isn't feasible, so a first phase finds
+// somewhere to start: it solves an auxiliary problem with one more
+// variable x0, subtracted from every constraint, minimizing x0. The
+// original problem is feasible exactly when that minimum is 0.
+
+enum class LpStatus { kOptimal, kInfeasible, kUnbounded };
+
+inline std::string to_string(LpStatus status) {
+  switch (status) {
+    case LpStatus::kOptimal:
+      return "optimal";
+    case LpStatus::kInfeasible:
+      return "infeasible";
+    case LpStatus::kUnbounded:
+      return "unbounded";
+  }
+  return "unknown";
+}
+
+struct LpSolution {
+  LpStatus status;
+  double value = 0;       // Only if optimal
+  std::vector<double> x;  // Only if optimal
+};
+
+// The dictionary: basic variable basic_[i] = rows_[i][n] - sum over j of
+// rows_[i][j] * (nonbasic variable nonbasic_[j]), and the objective is
+// objective_[n] + sum over j of objective_[j] * nonbasic_[j]. Variables
+// 0 to n - 1 are x, n to n + m - 1 the slacks, and n + m is x0.
+class Simplex {
+ public:
+  static constexpr double kEpsilon = 1e-9;
+
+  Simplex(const std::vector<std::vector<double>>& a,
+          const std::vector<double>& b, const std::vector<double>& c)
+      : m_(b.size()),
+        n_(c.size()),
+        rows_(m_, std::vector<double>(n_ + 1)),
+        objective_(n_ + 1, 0),
+        basic_(m_),
+        nonbasic_(n_) {
+    assert(a.size() == m_ && "A needs a row per constraint");
+    for (size_t i = 0; i < m_; ++i) {
+      assert(a[i].size() == n_ && "A needs a column per variable");
+      for (size_t j = 0; j < n_; ++j) {
+        rows_[i][j] = a[i][j];
+      }
+      rows_[i][n_] = b[i];
+      basic_[i] = n_ + i;
+    }
+    for (size_t j = 0; j < n_; ++j) {
+      objective_[j] = c[j];
+      nonbasic_[j] = j;
+    }
+  }
+
+  LpSolution solve() {
+    if (!find_feasible()) {
+      return {LpStatus::kInfeasible, 0, {}};
+    }
+    if (!optimize()) {
+      return {LpStatus::kUnbounded, 0, {}};
+    }
+    LpSolution solution{LpStatus::kOptimal, objective_[n_],
+                        std::vector<double>(n_, 0)};
+    for (size_t i = 0; i < m_; ++i) {
+      if (basic_[i] < n_) {
+        solution.x[basic_[i]] = rows_[i][n_];
+      }
+    }
+    return solution;
+  }
+
+  size_t pivots() const { return pivots_; }
+
+ private:
+  // Swaps basic_[r] out and nonbasic_[s] in, rewriting every row and the
+  // objective in terms of the new nonbasic variables
+  void pivot(size_t r, size_t s) {
+    ++pivots_;
+    const size_t width = nonbasic_.size() + 1;
+    std::vector<double>& row = rows_[r];
+    const double scale = 1 / row[s];
+    for (size_t j = 0; j < width; ++j) {
+      row[j] *= scale;
+    }
+    row[s] = scale;
+    // Substituting for the entering variable in every other row
+    for (size_t i = 0; i < m_; ++i) {
+      const double factor = rows_[i][s];
+      if (i == r || factor == 0) {
+        continue;
+      }
+      for (size_t j = 0; j < width; ++j) {
+        rows_[i][j] -= factor * row[j];
+      }
+      rows_[i][s] = -factor * scale;
+    }
+    // The objective adds its terms where the rows subtract them
+    const double factor = objective_[s];
+    for (size_t j = 0; j + 1 < width; ++j) {
+      if (j != s) {
+        objective_[j] -= factor * row[j];
+      }
+    }
+    objective_[width - 1] += factor * row[width - 1];
+    objective_[s] = -factor * scale;
+    std::swap(basic_[r], nonbasic_[s]);
+  }
+
+  // Pivots under Bland's rule until optimal (true) or unbounded (false)
+  bool optimize() {
+    const size_t rhs = nonbasic_.size();
+    while (true) {
+      size_t s = rhs;
+      for (size_t j = 0; j < rhs; ++j) {
+        if (objective_[j] > kEpsilon &&
+            (s == rhs || nonbasic_[j] < nonbasic_[s])) {
+          s = j;
+        }
+      }
+      if (s == rhs) {
+        return true;
+      }
+      size_t r = m_;
+      double best = 0;
+      for (size_t i = 0; i < m_; ++i) {
+        if (rows_[i][s] <= kEpsilon) {
+          continue;
+        }
+        const double ratio = rows_[i][rhs] / rows_[i][s];
+        if (r == m_ || ratio < best - kEpsilon ||
+            (ratio <= best + kEpsilon && basic_[i] < basic_[r])) {
+          r = i;
+          best = ratio;
+        }
+      }
+      if (r == m_) {
+        return false;
+      }
+      pivot(r, s);
+    }
+  }
+
+  // Phase one. Leaves a feasible dictionary for the original objective, or
+  // returns false if there's none.
+  bool find_feasible() {
+    if (m_ == 0) {
+      return true;
+    }
+    size_t lowest = 0;
+    for (size_t i = 1; i < m_; ++i) {
+      if (rows_[i][n_] < rows_[lowest][n_]) {
+        lowest = i;
+      }
+    }
+    if (rows_[lowest][n_] >= -kEpsilon) {
+      return true;
+    }
+    // x0 goes in as nonbasic column n, just before the constants, with
+    // coefficient -1 everywhere, and the objective becomes -x0
+    const size_t x0 = n_ + m_;
+    for (auto& row : rows_) {
+      row.insert(row.begin() + static_cast<std::ptrdiff_t>(n_), -1);
+    }
+    const std::vector<double> original(objective_.begin(),
+                                       objective_.end() - 1);
+    objective_.assign(n_ + 2, 0);
+    objective_[n_] = -1;
+    nonbasic_.push_back(x0);
+    // Entering x0 at the most violated constraint makes every slack
+    // nonnegative at once
+    pivot(lowest, n_);
+    optimize();
+    if (objective_.back() < -kEpsilon) {
+      return false;
+    }
+    // x0 is 0 now; if it's still basic, any pivot on its row takes it
+    // out, and if there's none, the row says 0 = 0, and can go
+    for (size_t i = 0; i < m_; ++i) {
+      if (basic_[i] != x0) {
+        continue;
+      }
+      size_t s = 0;
+      while (s < n_ + 1 && std::fabs(rows_[i][s]) <= kEpsilon) {
+        ++s;
+      }
+      if (s < n_ + 1) {
+        pivot(i, s);
+      } else {
+        rows_.erase(rows_.begin() + static_cast<std::ptrdiff_t>(i));
+        basic_.erase(basic_.begin() + static_cast<std::ptrdiff_t>(i));
+        --m_;
+      }
+      break;
+    }
+    // Drop x0's column
+    size_t column = 0;
+    while (nonbasic_[column] != x0) {
+      ++column;
+    }
+    for (auto& row : rows_) {
+      row.erase(row.begin() + static_cast<std::ptrdiff_t>(column));
+    }
+    nonbasic_.erase(nonbasic_.begin() + static_cast<std::ptrdiff_t>(column));
+    // The original objective, in terms of the current nonbasic variables
+    objective_.assign(n_ + 1, 0);
+    for (size_t j = 0; j < n_; ++j) {
+      if (nonbasic_[j] < n_) {
+        objective_[j] += original[nonbasic_[j]];
+      }
+    }
+    for (size_t i = 0; i < m_; ++i) {
+      if (basic_[i] >= n_) {
+        continue;
+      }
+      const double c = original[basic_[i]];
+      for (size_t j = 0; j < n_; ++j) {
+        objective_[j] -= c * rows_[i][j];
+      }
+      objective_[n_] += c * rows_[i][n_];
+    }
+    return true;
+  }
+
+  size_t m_;
+  size_t n_;
+  std::vector<std::vector<double>> rows_;
+  std::vector<double> objective_;
+  std::vector<size_t> basic_;
+  std::vector<size_t> nonbasic_;
+  size_t pivots_ = 0;
+};
+
+inline LpSolution solve_lp(const std::vector<std::vector<double>>& a,
+                           const std::vector<double>& b,
+                           const std::vector<double>& c) {
+  return Simplex(a, b, c).solve();
+}
@@ -0,0 +1,203 @@
+#include <cmath>
+#include <cstddef>
+#include <optional>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "Simplex.h"
+#include "gtest/gtest.h"
+
+using Matrix = std::vector<std::vector<double>>;
+using Vector = std::vector<double>;
+
+// A x = b for square A by Gaussian elimination with partial pivoting, or
+// std::nullopt if A is singular
+std::optional<Vector> solve_square(Matrix a, Vector b) {
+  const size_t n = b.size();
+  for (size_t col = 0; col < n; ++col) {
+    size_t pivot = col;
+    for (size_t i = col + 1; i < n; ++i) {
+      if (std::fabs(a[i][col]) > std::fabs(a[pivot][col])) {
+        pivot = i;
+      }
+    }
+    if (std::fabs(a[pivot][col]) < 1e-9) {
+      return std::nullopt;
+    }
+    std::swap(a[col], a[pivot]);
+    std::swap(b[col], b[pivot]);
+    for (size_t i = 0; i < n; ++i) {
+      if (i == col) {
+        continue;
+      }
+      const double factor = a[i][col] / a[col][col];
+      for (size_t j = col; j < n; ++j) {
+        a[i][j] -= factor * a[col][j];
+      }
+      b[i] -= factor * b[col];
+    }
+  }
+  Vector x(n);
+  for (size_t i = 0; i < n; ++i) {
+    x[i] = b[i] / a[i][i];
+  }
+  return x;
+}
+
+// Every vertex: n of the m + n constraints (counting x >= 0) tight, solved
+// for x, kept if it satisfies the rest. For bounded problems only, where
+// a feasible region has a vertex and the optimum is at one.
+std::optional<double> best_vertex(const Matrix& a, const Vector& b,
+                                  const Vector& c) {
+  const size_t m = b.size();
+  const size_t n = c.size();
+  std::optional<double> best;
+  for (size_t mask = 0; mask < (size_t{1} << (m + n)); ++mask) {
+    if (static_cast<size_t>(__builtin_popcountll(mask)) != n) {
+      continue;
+    }
+    Matrix tight;
+    Vector rhs;
+    for (size_t k = 0; k < m + n; ++k) {
+      if ((mask >> k & 1) == 0) {
+        continue;
+      }
+      if (k < m) {
+        tight.push_back(a[k]);
+        rhs.push_back(b[k]);
+      } else {
+        Vector unit(n, 0);
+        unit[k - m] = 1;
+        tight.push_back(unit);
+        rhs.push_back(0);
+      }
+    }
+    const auto x = solve_square(tight, rhs);
+    if (!x) {
+      continue;
+    }
+    bool feasible = true;
+    double value = 0;
+    for (size_t j = 0; j < n; ++j) {
+      feasible = feasible && (*x)[j] >= -1e-7;
+      value += c[j] * (*x)[j];
+    }
+    for (size_t i = 0; i < m; ++i) {
+      double lhs = 0;
+      for (size_t j = 0; j < n; ++j) {
+        lhs += a[i][j] * (*x)[j];
+      }
+      feasible = feasible && lhs <= b[i] + 1e-7;
+    }
+    if (feasible && (!best || value > *best)) {
+      best = value;
+    }
+  }
+  return best;
+}
+
+TEST(Simplex, Textbook) {
+  // CLRS 29.3: 28 at (8, 4, 0)
+  const LpSolution clrs = solve_lp({{1, 1, 3}, {2, 2, 5}, {4, 1, 2}},
+                                   {30, 24, 36}, {3, 1, 2});
+  ASSERT_EQ(clrs.status, LpStatus::kOptimal);
+  EXPECT_NEAR(clrs.value, 28, 1e-9);
+  EXPECT_NEAR(clrs.x[0], 8, 1e-9);
+  EXPECT_NEAR(clrs.x[1], 4, 1e-9);
+  EXPECT_NEAR(clrs.x[2], 0, 1e-9);
+
+  // Chvatal's first example: 13 at (2, 0, 1)
+  const LpSolution chvatal =
+      solve_lp({{2, 3, 1}, {4, 1, 2}, {3, 4, 2}}, {5, 11, 8}, {5, 4, 3});
+  ASSERT_EQ(chvatal.status, LpStatus::kOptimal);
+  EXPECT_NEAR(chvatal.value, 13, 1e-9);
+  EXPECT_NEAR(chvatal.x[0], 2, 1e-9);
+  EXPECT_NEAR(chvatal.x[2], 1, 1e-9);
+
+  // CLRS 29.5: x = 0 isn't feasible, so this takes phase one. The optimum,
+  // 2, is along a whole edge, so only the value is pinned down.
+  const LpSolution start = solve_lp({{2, -1}, {1, -5}}, {2, -4}, {2, -1});
+  ASSERT_EQ(start.status, LpStatus::kOptimal);
+  EXPECT_NEAR(start.value, 2, 1e-9);
+  EXPECT_NEAR(2 * start.x[0] - start.x[1], 2, 1e-9);
+  EXPECT_LE(start.x[0] - 5 * start.x[1], -4 + 1e-9);
+}
+
+TEST(Simplex, InfeasibleAndUnbounded) {
+  EXPECT_EQ(solve_lp({{1}, {-1}}, {1, -2}, {1}).status,
+            LpStatus::kInfeasible);
+  EXPECT_EQ(solve_lp({{1, 1}, {-1, -1}}, {2, -3}, {1, 0}).status,
+            LpStatus::kInfeasible);
+  EXPECT_EQ(solve_lp({{-1, 1}}, {1}, {1, 0}).status, LpStatus::kUnbounded);
+  // Unbounded after phase one
+  EXPECT_EQ(solve_lp({{-1, 0}}, {-2}, {1, 1}).status, LpStatus::kUnbounded);
+  EXPECT_EQ(to_string(LpStatus::kUnbounded), "unbounded");
+  // No constraints: 0 is the best of nothing to gain
+  const LpSolution empty = solve_lp({}, {}, {-1, -2});
+  EXPECT_EQ(empty.status, LpStatus::kOptimal);
+  EXPECT_EQ(empty.value, 0);
+}
+
+// Beale's example cycles forever if the entering variable is the one with
+// the largest coefficient; Bland's rule gets out
+TEST(Simplex, DegenerateCycling) {
+  Simplex beale({{0.25, -8, -1, 9}, {0.5, -12, -0.5, 3}, {0, 0, 1, 0}},
+                {0, 0, 1}, {0.75, -20, 0.5, -6});
+  const LpSolution solution = beale.solve();
+  ASSERT_EQ(solution.status, LpStatus::kOptimal);
+  EXPECT_NEAR(solution.value, 1.25, 1e-9);
+  EXPECT_NEAR(solution.x[0], 1, 1e-9);
+  EXPECT_NEAR(solution.x[2], 1, 1e-9);
+  EXPECT_LT(beale.pivots(), 20u);
+}
+
+TEST(Simplex, MatchesVertexEnumeration) {
+  Rng gen(205);
+  for (int t = 0; t < 300; ++t) {
+    const size_t n = uniform_int<size_t>(gen, 1, 3);
+    const size_t m = uniform_int<size_t>(gen, 1, 4);
+    Matrix a;
+    Vector b;
+    // Random constraints, some with negative b, and a box to keep it
+    // bounded
+    for (size_t i = 0; i < m; ++i) {
+      Vector row(n);
+      for (double& v : row) {
+        v = static_cast<double>(uniform_int(gen, -5, 5));
+      }
+      a.push_back(row);
+      b.push_back(static_cast<double>(uniform_int(gen, -4, 10)));
+    }
+    for (size_t j = 0; j < n; ++j) {
+      Vector unit(n, 0);
+      unit[j] = 1;
+      a.push_back(unit);
+      b.push_back(static_cast<double>(uniform_int(gen, 1, 8)));
+    }
+    Vector c(n);
+    for (double& v : c) {
+      v = static_cast<double>(uniform_int(gen, -5, 5));
+    }
+    const LpSolution solution = solve_lp(a, b, c);
+    const auto expected = best_vertex(a, b, c);
+    if (!expected) {
+      ASSERT_EQ(solution.status, LpStatus::kInfeasible) << t;
+      continue;
+    }
+    ASSERT_EQ(solution.status, LpStatus::kOptimal) << t;
+    ASSERT_NEAR(solution.value, *expected, 1e-7) << t;
+    for (size_t i = 0; i < a.size(); ++i) {
+      double lhs = 0;
+      for (size_t j = 0; j < n; ++j) {
+        ASSERT_GE(solution.x[j], -1e-9);
+        lhs += a[i][j] * solution.x[j];
+      }
+      ASSERT_LE(lhs, b[i] + 1e-7);
+    }
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,89 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <numbers>
+#include <vector>
+
+#include "Hash.h"
+
+// Count-min sketch: approximate counts of every value in a stream, in
+// depth × width counters. Each row has its own hash function, and adding x
+// bumps one counter per row. Other values colliding with x only ever add
+// to its counters, so the smallest of its depth counters is the estimate:
+//
+//   count(x) <= estimate(x)
+//
+// Within one row, the expected overcount is at most n / width for n values
+// added, so by Markov it exceeds e · n / width with probability at most
+// 1 / e, and the minimum over independent rows exceeds it with probability
+// at most e^-depth. For an error of at most ε · n with probability 1 - δ,
+// take width = ⌈e / ε⌉ and depth = ⌈ln(1 / δ)⌉.
+//
+// Unlike Misra-Gries, the sketch answers for any value, not just the heavy
+// ones, and two sketches with the same shape and seed merge by adding.
+template <typename T>
+class CountMinSketch {
+ public:
+  CountMinSketch(size_t width, size_t depth, uint64_t seed = 161)
+      : width_(width), depth_(depth), seed_(seed), counts_(width * depth, 0) {
+    assert(width > 0 && depth > 0);
+  }
+
+  void add(const T& x, uint64_t count = 1) {
+    total_ += count;
+    for (size_t row = 0; row < depth_; ++row) {
+      counts_[slot(x, row)] += count;
+    }
+  }
+
+  // Never less than the true count
+  uint64_t estimate(const T& x) const {
+    uint64_t best = std::numeric_limits<uint64_t>::max();
+    for (size_t row = 0; row < depth_; ++row) {
+      best = std::min(best, counts_[slot(x, row)]);
+    }
+    return best;
+  }
+
+  // The overcount that estimate() stays within with probability
+  // 1 - failure_probability()
+  double error_bound() const {
+    return std::numbers::e * static_cast<double>(total_) / width_;
+  }
+
+  double failure_probability() const {
+    return std::exp(-static_cast<double>(depth_));
+  }
+
+  // Afterwards, this sketch is the one that would have seen both streams
+  void merge(const CountMinSketch& other) {
+    assert(width_ == other.width_ && depth_ == other.depth_ &&
+           seed_ == other.seed_ && "sketches must share a shape and seed");
+    total_ += other.total_;
+    for (size_t i = 0; i < counts_.size(); ++i) {
+      counts_[i] += other.counts_[i];
+    }
+  }
+
+  uint64_t total() const { return total_; }
+
+  size_t width() const { return width_; }
+
+  size_t depth() const { return depth_; }
+
+ private:
+  size_t slot(const T& x, size_t row) const {
+    return row * width_ + seeded_hash(x, seed_ + row) % width_;
+  }
+
+  size_t width_;
+  size_t depth_;
+  uint64_t seed_;
+  uint64_t total_{0};
+  std::vector<uint64_t> counts_;  // Row by row
+};
@@ -0,0 +1,21 @@
+#pragma once
+
+#include <cstdint>
+#include <functional>
+
+// The splitmix64 finalizer: every input bit affects every output bit, so
+// the low bits of even std::hash<int>, which is the identity, come out well
+// spread. Combining with a seed first gives a family of different-looking
+// hash functions, which is all the sketches need in practice (the proofs
+// ask for pairwise independence, which this doesn't promise).
+inline uint64_t mix(uint64_t x) {
+  x += 0x9e3779b97f4a7c15;
+  x = (x ^ (x >> 30)) * 0xbf58476d1ce4e5b9;
+  x = (x ^ (x >> 27)) * 0x94d049bb133111eb;
+  return x ^ (x >> 31);
+}
+
+template <typename T>
+uint64_t seeded_hash(const T& x, uint64_t seed) {
+  return mix(std::hash<T>()(x) ^ mix(seed));
+}
@@ -0,0 +1,95 @@
+#pragma once
+
+#include <algorithm>
+#include <bit>
+#include <cassert>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "Hash.h"
+
+// HyperLogLog: the number of distinct values in a stream, from 2^precision
+// small registers. In a uniformly random hash, a run of r leading zeros
+// turns up about once per 2^(r + 1) distinct values, so the longest run
+// seen says roughly how many there were. One register alone is very noisy;
+// instead the first bits of the hash pick one of m = 2^precision registers,
+// each register keeps the longest run (plus one) in its share of the
+// values, and the estimate combines them with a harmonic mean, which damps
+// the occasional register that got lucky:
+//
+//   estimate = α_m · m² / Σ 2^-register[j]
+//
+// The relative standard error is about 1.04 / √m: 1.6% with 4096 one-byte
+// registers, whether the stream has a thousand distinct values or a
+// billion. When many registers are still empty the harmonic mean is biased
+// and linear counting, m ln(m / empty), is better, so small counts use that.
+// Duplicates hash the same, so they never change anything.
+template <typename T>
+class HyperLogLog {
+ public:
+  explicit HyperLogLog(int precision = 12, uint64_t seed = 161)
+      : precision_(precision), seed_(seed) {
+    assert(precision >= 4 && precision <= 18);
+    registers_.assign(size_t{1} << precision, 0);
+  }
+
+  void add(const T& x) {
+    const uint64_t h = seeded_hash(x, seed_);
+    const size_t j = h >> (64 - precision_);
+    // The rest of the bits, with a 1 shifted in so the run always ends
+    const uint64_t rest = (h << precision_) | (uint64_t{1} << (precision_ - 1));
+    const uint8_t rank = static_cast<uint8_t>(std::countl_zero(rest) + 1);
+    registers_[j] = std::max(registers_[j], rank);
+  }
+
+  double estimate() const {
+    const double m = static_cast<double>(registers_.size());
+    double sum = 0.0;
+    size_t empty = 0;
+    for (uint8_t r : registers_) {
+      sum += std::ldexp(1.0, -r);
+      empty += r == 0;
+    }
+    const double raw = alpha() * m * m / sum;
+    if (raw <= 2.5 * m && empty > 0) {
+      return m * std::log(m / static_cast<double>(empty));
+    }
+    return raw;
+  }
+
+  double standard_error() const {
+    return 1.04 / std::sqrt(static_cast<double>(registers_.size()));
+  }
+
+  // Afterwards, this estimates the distinct values of both streams together
+  void merge(const HyperLogLog& other) {
+    assert(precision_ == other.precision_ && seed_ == other.seed_ &&
+           "sketches must share a precision and seed");
+    for (size_t j = 0; j < registers_.size(); ++j) {
+      registers_[j] = std::max(registers_[j], other.registers_[j]);
+    }
+  }
+
+  size_t registers() const { return registers_.size(); }
+
+ private:
+  // Corrects the bias of the harmonic mean, from the original paper
+  double alpha() const {
+    switch (registers_.size()) {
+      case 16:
+        return 0.673;
+      case 32:
+        return 0.697;
+      case 64:
+        return 0.709;
+      default:
+        return 0.7213 / (1.0 + 1.079 / static_cast<double>(registers_.size()));
+    }
+  }
+
+  int precision_;
+  uint64_t seed_;
+  std::vector<uint8_t> registers_;
+};
@@ -0,0 +1,121 @@
+#include <cmath>
+#include <cstdint>
+#include <random>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+#include "CountMinSketch.h"
+#include "HyperLogLog.h"
+#include "gtest/gtest.h"
+
+TEST(Sketches, CountMinIsExactWithoutCollisions) {
+  CountMinSketch<std::string> sketch(1 << 16, 4);
+  sketch.add("apple", 3);
+  sketch.add("pear");
+  sketch.add("apple");
+
+  EXPECT_EQ(sketch.estimate("apple"), 4u);
+  EXPECT_EQ(sketch.estimate("pear"), 1u);
+  EXPECT_EQ(sketch.estimate("fig"), 0u);
+  EXPECT_EQ(sketch.total(), 5u);
+}
+
+TEST(Sketches, CountMinErrorBound) {
+  // width = ⌈e / 0.01⌉ and depth = 5: off by at most 1% of the stream,
+  // except for about e^-5 < 1% of the values
+  std::mt19937 gen(161);
+  std::vector<double> weights(5000);
+  for (size_t i = 0; i < weights.size(); ++i) {
+    weights[i] = 1.0 / static_cast<double>(i + 1);
+  }
+  std::discrete_distribution<int> value(weights.begin(), weights.end());
+
+  CountMinSketch<int> sketch(272, 5);
+  std::unordered_map<int, uint64_t> exact;
+  for (int i = 0; i < 100000; ++i) {
+    const int v = value(gen);
+    ++exact[v];
+    sketch.add(v);
+  }
+
+  size_t failures = 0;
+  for (const auto& [v, count] : exact) {
+    const uint64_t estimate = sketch.estimate(v);
+    ASSERT_GE(estimate, count);
+    failures += estimate - count > sketch.error_bound();
+  }
+  EXPECT_LE(failures, 2 * sketch.failure_probability() * exact.size());
+}
+
+TEST(Sketches, CountMinMerge) {
+  CountMinSketch<int> left(64, 3);
+  CountMinSketch<int> right(64, 3);
+  CountMinSketch<int> both(64, 3);
+  for (int i = 0; i < 1000; ++i) {
+    (i % 2 == 0 ? left : right).add(i % 37);
+    both.add(i % 37);
+  }
+  left.merge(right);
+
+  EXPECT_EQ(left.total(), both.total());
+  for (int v = 0; v < 37; ++v) {
+    EXPECT_EQ(left.estimate(v), both.estimate(v));
+  }
+}
+
+TEST(Sketches, HyperLogLogWithinStandardError) {
+  for (int n : {100, 1000, 10000, 100000, 1000000}) {
+    HyperLogLog<int> hll(12);
+    for (int i = 0; i < n; ++i) {
+      hll.add(i);
+      hll.add(i);  // Duplicates don't count
+    }
+    // Three standard errors, so a failure would be a one-in-a-few-hundred
+    // fluke
+    EXPECT_NEAR(hll.estimate(), n, 3 * hll.standard_error() * n) << n;
+  }
+  EXPECT_EQ(HyperLogLog<int>().estimate(), 0.0);
+}
+
+TEST(Sketches, HyperLogLogErrorAcrossSeeds) {
+  // Over many independent sketches, the relative error averages out to
+  // about the standard error
+  const int n = 20000;
+  const int trials = 50;
+  double squares = 0.0;
+  for (int seed = 0; seed < trials; ++seed) {
+    HyperLogLog<int> hll(10, seed);
+    for (int i = 0; i < n; ++i) {
+      hll.add(i);
+    }
+    const double error = (hll.estimate() - n) / n;
+    squares += error * error;
+  }
+  const double rms = std::sqrt(squares / trials);
+
+  EXPECT_LT(rms, 1.5 * HyperLogLog<int>(10).standard_error());
+}
+
+TEST(Sketches, HyperLogLogMerge) {
+  HyperLogLog<std::string> left;
+  HyperLogLog<std::string> right;
+  HyperLogLog<std::string> both;
+  for (int i = 0; i < 30000; ++i) {
+    const std::string key = "key" + std::to_string(i);
+    (i < 20000 ? left : right).add(key);
+    if (i >= 10000) {
+      left.add(key);  // Overlap
+    }
+    both.add(key);
+  }
+  left.merge(right);
+
+  EXPECT_EQ(left.estimate(), both.estimate());
+  EXPECT_NEAR(left.estimate(), 30000, 3 * left.standard_error() * 30000);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,111 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+// Double-ended queue on top of a growable ring buffer.
+//
+// Elements live in a contiguous array, starting at head_ and wrapping around
+// the end. Pushing or popping at either end just moves head_ or the size, so
+// all four operations are O(1) (amortized for pushes, which may double the
+// array). The capacity is always a power of two, which turns the wraparound
+// modulo into a mask.
+template <typename T>
+class RingDeque {
+ private:
+  static constexpr size_t kInitialCapacity = 8;
+
+  std::vector<T> buffer_;
+  size_t head_{0};  // Index of the front element
+  size_t size_{0};
+
+  size_t mask() const { return buffer_.size() - 1; }
+
+  // Physical slot of the i-th element from the front
+  size_t slot(size_t i) const { return (head_ + i) & mask(); }
+
+  void grow() {
+    // Unroll the ring into the front of a buffer twice as large
+    std::vector<T> bigger(buffer_.size() * 2);
+    for (size_t i = 0; i < size_; ++i) {
+      bigger[i] = std::move(buffer_[slot(i)]);
+    }
+    buffer_ = std::move(bigger);
+    head_ = 0;
+  }
+
+ public:
+  RingDeque() : buffer_(kInitialCapacity) {}
+
+  bool empty() const { return size_ == 0; }
+
+  size_t size() const { return size_; }
+
+  size_t capacity() const { return buffer_.size(); }
+
+  void push_back(T value) {
+    if (size_ == buffer_.size()) {
+      grow();
+    }
+    buffer_[slot(size_)] = std::move(value);
+    ++size_;
+  }
+
+  void push_front(T value) {
+    if (size_ == buffer_.size()) {
+      grow();
+    }
+    // Step back one slot; unsigned wraparound plus the mask does the modulo
+    head_ = (head_ - 1) & mask();
+    buffer_[head_] = std::move(value);
+    ++size_;
+  }
+
+  void pop_back() {
+    assert(size_ > 0 && "pop_back called on empty deque");
+    --size_;
+  }
+
+  void pop_front() {
+    assert(size_ > 0 && "pop_front called on empty deque");
+    head_ = (head_ + 1) & mask();
+    --size_;
+  }
+
+  T& front() {
+    assert(size_ > 0 && "front called on empty deque");
+    return buffer_[head_];
+  }
+
+  const T& front() const {
+    assert(size_ > 0 && "front called on empty deque");
+    return buffer_[head_];
+  }
+
+  T& back() {
+    assert(size_ > 0 && "back called on empty deque");
+    return buffer_[slot(size_ - 1)];
+  }
+
+  const T& back() const {
+    assert(size_ > 0 && "back called on empty deque");
+    return buffer_[slot(size_ - 1)];
+  }
+
+  T& operator[](size_t i) {
+    assert(i < size_ && "index out of range");
+    return buffer_[slot(i)];
+  }
+
+  const T& operator[](size_t i) const {
+    assert(i < size_ && "index out of range");
+    return buffer_[slot(i)];
+  }
+
+  void clear() {
+    head_ = 0;
+    size_ = 0;
+  }
+};
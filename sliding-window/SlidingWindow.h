@@ -0,0 +1,108 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <functional>
+#include <vector>
+
+#include "RingDeque.h"
+
+// Queue that also reports its best element (the maximum with the default
+// comparator) in O(1).
+//
+// The trick is to forget elements that can never be the answer again. When x
+// is pushed, every element already in the queue that is worse than x will
+// leave the queue before x does, so none of them can be the best while x is
+// present. Dropping them on push keeps the deque monotonic: from front to
+// back, each element is at least as good as the one behind it, so the best
+// element is always at the front.
+//
+// Each element is pushed and popped from the deque at most once, so a
+// sequence of n operations costs O(n) in total.
+template <typename T, typename Compare = std::less<T>>
+class MonotonicQueue {
+ private:
+  RingDeque<T> deque_;
+  Compare less_;
+
+ public:
+  bool empty() const { return deque_.empty(); }
+
+  void push(const T& x) {
+    // Drop everything that x dominates. Equal elements stay, because each
+    // copy must still be popped individually.
+    while (!deque_.empty() && less_(deque_.back(), x)) {
+      deque_.pop_back();
+    }
+    deque_.push_back(x);
+  }
+
+  // Remove x from the front of the logical queue. The caller passes the
+  // element that is leaving; if it was dominated, it's already gone.
+  void pop(const T& x) {
+    if (!deque_.empty() && !less_(deque_.front(), x) &&
+        !less_(x, deque_.front())) {
+      deque_.pop_front();
+    }
+  }
+
+  const T& best() const {
+    assert(!deque_.empty() && "best called on empty queue");
+    return deque_.front();
+  }
+};
+
+// The best element of every window of k consecutive elements, in O(n)
+template <typename T, typename Compare>
+std::vector<T> sliding_window_best(const std::vector<T>& nums, size_t k) {
+  assert(k > 0 && "window size must be positive");
+  std::vector<T> result;
+  if (nums.size() < k) {
+    return result;
+  }
+  result.reserve(nums.size() - k + 1);
+
+  MonotonicQueue<T, Compare> window;
+  for (size_t i = 0; i < nums.size(); ++i) {
+    window.push(nums[i]);
+    if (i >= k) {
+      window.pop(nums[i - k]);
+    }
+    if (i + 1 >= k) {
+      result.push_back(window.best());
+    }
+  }
+  return result;
+}
+
+template <typename T>
+std::vector<T> sliding_window_max(const std::vector<T>& nums, size_t k) {
+  return sliding_window_best<T, std::less<T>>(nums, k);
+}
+
+template <typename T>
+std::vector<T> sliding_window_min(const std::vector<T>& nums, size_t k) {
+  return sliding_window_best<T, std::greater<T>>(nums, k);
+}
+
+// O(nk): scan every window from scratch
+template <typename T>
+std::vector<T> sliding_window_max_naive(const std::vector<T>& nums, size_t k) {
+  assert(k > 0 && "window size must be positive");
+  std::vector<T> result;
+  for (size_t i = 0; i + k <= nums.size(); ++i) {
+    result.push_back(*std::max_element(nums.begin() + i, nums.begin() + i + k));
+  }
+  return result;
+}
+
+template <typename T>
+std::vector<T> sliding_window_min_naive(const std::vector<T>& nums, size_t k) {
+  assert(k > 0 && "window size must be positive");
+  std::vector<T> result;
+  for (size_t i = 0; i + k <= nums.size(); ++i) {
+    result.push_back(*std::min_element(nums.begin() + i, nums.begin() + i + k));
+  }
+  return result;
+}
@@ -0,0 +1,161 @@
+#include <deque>
+#include <random>
+#include <string>
+#include <vector>
+
+#include "RingDeque.h"
+#include "SlidingWindow.h"
+#include "gtest/gtest.h"
+
+TEST(RingDeque, PushAndPopBothEnds) {
+  RingDeque<int> deque;
+
+  deque.push_back(2);
+  deque.push_back(3);
+  deque.push_front(1);
+  deque.push_front(0);
+
+  EXPECT_EQ(deque.size(), 4u);
+  EXPECT_EQ(deque.front(), 0);
+  EXPECT_EQ(deque.back(), 3);
+  EXPECT_EQ(deque[1], 1);
+  EXPECT_EQ(deque[2], 2);
+
+  deque.pop_front();
+  deque.pop_back();
+  EXPECT_EQ(deque.front(), 1);
+  EXPECT_EQ(deque.back(), 2);
+}
+
+TEST(RingDeque, GrowsAcrossTheWraparound) {
+  RingDeque<int> deque;
+
+  // Leave head_ in the middle of the buffer, then fill past capacity
+  for (int i = 0; i < 5; ++i) {
+    deque.push_back(i);
+  }
+  for (int i = 0; i < 5; ++i) {
+    deque.pop_front();
+  }
+  size_t initial_capacity = deque.capacity();
+  for (int i = 0; i < 100; ++i) {
+    deque.push_back(i);
+  }
+
+  EXPECT_GT(deque.capacity(), initial_capacity);
+  EXPECT_EQ(deque.size(), 100u);
+  for (int i = 0; i < 100; ++i) {
+    EXPECT_EQ(deque[i], i);
+  }
+}
+
+TEST(RingDeque, MatchesStdDeque) {
+  RingDeque<std::string> deque;
+  std::deque<std::string> expected;
+  std::mt19937 gen(17);
+  std::uniform_int_distribution<int> op(0, 3);
+
+  for (int i = 0; i < 10000; ++i) {
+    std::string value = std::to_string(i);
+    switch (op(gen)) {
+      case 0:
+        deque.push_back(value);
+        expected.push_back(value);
+        break;
+      case 1:
+        deque.push_front(value);
+        expected.push_front(value);
+        break;
+      case 2:
+        if (!expected.empty()) {
+          deque.pop_back();
+          expected.pop_back();
+        }
+        break;
+      default:
+        if (!expected.empty()) {
+          deque.pop_front();
+          expected.pop_front();
+        }
+    }
+
+    ASSERT_EQ(deque.size(), expected.size());
+    if (!expected.empty()) {
+      ASSERT_EQ(deque.front(), expected.front());
+      ASSERT_EQ(deque.back(), expected.back());
+    }
+  }
+}
+
+TEST(SlidingWindow, ClassicExample) {
+  std::vector<int> nums = {1, 3, -1, -3, 5, 3, 6, 7};
+
+  EXPECT_EQ(sliding_window_max(nums, 3),
+            (std::vector<int>{3, 3, 5, 5, 6, 7}));
+  EXPECT_EQ(sliding_window_min(nums, 3),
+            (std::vector<int>{-1, -3, -3, -3, 3, 3}));
+}
+
+TEST(SlidingWindow, WindowOfOneIsTheInput) {
+  std::vector<int> nums = {4, 2, 12, 3};
+
+  EXPECT_EQ(sliding_window_max(nums, 1), nums);
+  EXPECT_EQ(sliding_window_min(nums, 1), nums);
+}
+
+TEST(SlidingWindow, WindowOfWholeArray) {
+  std::vector<int> nums = {4, 2, 12, 3};
+
+  EXPECT_EQ(sliding_window_max(nums, 4), std::vector<int>{12});
+  EXPECT_EQ(sliding_window_min(nums, 4), std::vector<int>{2});
+}
+
+TEST(SlidingWindow, WindowLargerThanArray) {
+  std::vector<int> nums = {1, 2};
+
+  EXPECT_TRUE(sliding_window_max(nums, 3).empty());
+  EXPECT_TRUE(sliding_window_min(nums, 3).empty());
+}
+
+TEST(SlidingWindow, Duplicates) {
+  // A dominated copy must not hide an equal one that is still in the window
+  std::vector<int> nums = {5, 5, 1, 5, 1, 1, 1};
+
+  EXPECT_EQ(sliding_window_max(nums, 2),
+            (std::vector<int>{5, 5, 5, 5, 1, 1}));
+  EXPECT_EQ(sliding_window_max(nums, 2), sliding_window_max_naive(nums, 2));
+}
+
+TEST(SlidingWindow, MonotonicInputs) {
+  std::vector<int> increasing = {1, 2, 3, 4, 5, 6};
+  std::vector<int> decreasing = {6, 5, 4, 3, 2, 1};
+
+  EXPECT_EQ(sliding_window_max(increasing, 2),
+            (std::vector<int>{2, 3, 4, 5, 6}));
+  EXPECT_EQ(sliding_window_max(decreasing, 2),
+            (std::vector<int>{6, 5, 4, 3, 2}));
+  EXPECT_EQ(sliding_window_min(increasing, 2),
+            (std::vector<int>{1, 2, 3, 4, 5}));
+}
+
+TEST(SlidingWindow, MatchesNaive) {
+  std::mt19937 gen(123);
+  std::uniform_int_distribution<int> value(-50, 50);
+
+  for (int trial = 0; trial < 200; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(1, 300)(gen);
+    std::vector<int> nums(n);
+    for (auto& x : nums) {
+      x = value(gen);
+    }
+    size_t k = std::uniform_int_distribution<size_t>(1, n)(gen);
+
+    ASSERT_EQ(sliding_window_max(nums, k), sliding_window_max_naive(nums, k));
+    ASSERT_EQ(sliding_window_min(nums, k), sliding_window_min_naive(nums, k));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
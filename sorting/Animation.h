@@ -0,0 +1,72 @@
+#pragma once
+
+#include <algorithm>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <ostream>
+#include <string>
+#include <thread>
+#include <vector>
+
+#include "../wasm/Json.h"
+#include "Sorting.h"
+
+// Playing back the frames a sort recorded: as text, one bar per element,
+// redrawn in place in a terminal; or as JSON, for an animator elsewhere.
+
+constexpr double kMaxBar = 60;
+
+// Whether the step touched position i
+inline bool touched(const Step& step, size_t i) {
+  if (step.kind == StepKind::kSwap) {
+    return i == step.first || i == step.second;
+  }
+  return step.first <= i && i < step.second;
+}
+
+// One line per element: its value, then a bar as long as the value (scaled
+// down if the largest wouldn't fit), with the positions the step touched
+// marked. Negative values get no bar.
+inline std::string render_frame(const Frame<int64_t>& frame) {
+  int64_t largest = 1;
+  for (int64_t v : frame.values) {
+    largest = std::max(largest, v);
+  }
+  const double scale = std::min(1.0, kMaxBar / static_cast<double>(largest));
+  std::string text = to_string(frame.step.kind) + " " +
+                     std::to_string(frame.step.first) + " " +
+                     std::to_string(frame.step.second) + "\n";
+  for (size_t i = 0; i < frame.values.size(); ++i) {
+    const int64_t v = frame.values[i];
+    const auto length =
+        v > 0 ? static_cast<size_t>(static_cast<double>(v) * scale) : 0;
+    std::string number = std::to_string(v);
+    number.insert(0, number.size() < 6 ? 6 - number.size() : 0, ' ');
+    text += number + " " + std::string(length, '#') +
+            (touched(frame.step, i) ? " <" : "") + "\n";
+  }
+  return text;
+}
+
+// Writes each frame in turn, `delay` apart. With `clear`, clears a
+// terminal before each frame so it's drawn over the last one.
+inline void play_frames(const std::vector<Frame<int64_t>>& frames,
+                        std::ostream& out, std::chrono::milliseconds delay,
+                        bool clear) {
+  for (size_t i = 0; i < frames.size(); ++i) {
+    if (i > 0) {
+      std::this_thread::sleep_for(delay);
+    }
+    out << (clear ? "\x1b[H\x1b[2J" : i > 0 ? "\n" : "")
+        << render_frame(frames[i]) << std::flush;
+  }
+}
+
+// {"kind": "swap", "first": 0, "second": 1, "values": [...]} per frame
+inline std::string to_json(const Frame<int64_t>& frame) {
+  return json_object({{"kind", to_json(to_string(frame.step.kind))},
+                      {"first", to_json(frame.step.first)},
+                      {"second", to_json(frame.step.second)},
+                      {"values", to_json(frame.values)}});
+}
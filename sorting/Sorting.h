@@ -0,0 +1,194 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <functional>
+#include <string>
+#include <utility>
+#include <vector>
+
+// The classic comparison sorts, each of which can report every step it
+// takes to an observer: after each swap, or each merge, the observer gets
+// the step and the whole array as it is then. Recording the steps makes
+// frames for an animation (see Animation.h); without an observer, the
+// calls compile away.
+
+enum class StepKind {
+  kSwap,   // values[first] and values[second] traded places
+  kMerge,  // values[first, second) is now one sorted run
+};
+
+struct Step {
+  StepKind kind;
+  size_t first;
+  size_t second;
+
+  bool operator==(const Step&) const = default;
+};
+
+inline std::string to_string(StepKind kind) {
+  return kind == StepKind::kSwap ? "swap" : "merge";
+}
+
+struct NoObserver {
+  template <typename T>
+  void operator()(const Step&, const std::vector<T>&) const {}
+};
+
+// The array after each step, with the step that made it
+template <typename T>
+struct Frame {
+  Step step;
+  std::vector<T> values;
+};
+
+template <typename T>
+class FrameRecorder {
+ public:
+  void operator()(const Step& step, const std::vector<T>& values) {
+    frames_.push_back({step, values});
+  }
+
+  const std::vector<Frame<T>>& frames() const { return frames_; }
+
+ private:
+  std::vector<Frame<T>> frames_;
+};
+
+template <typename T, typename Observer>
+void swap_and_observe(std::vector<T>& values, size_t i, size_t j,
+                      Observer& observe) {
+  std::swap(values[i], values[j]);
+  observe(Step{StepKind::kSwap, i, j}, std::as_const(values));
+}
+
+// Each element swaps its way left past the bigger ones before it. O(n^2)
+// swaps at worst, none for sorted input; stable.
+template <typename T, typename Less = std::less<>,
+          typename Observer = NoObserver>
+void insertion_sort(std::vector<T>& values, Less less = Less(),
+                    Observer&& observe = Observer()) {
+  for (size_t i = 1; i < values.size(); ++i) {
+    for (size_t j = i; j > 0 && less(values[j], values[j - 1]); --j) {
+      swap_and_observe(values, j - 1, j, observe);
+    }
+  }
+}
+
+// Lomuto's partition around the last element, then each side. O(n log n)
+// expected on shuffled input, O(n^2) on sorted input; not stable.
+template <typename T, typename Less, typename Observer>
+void quicksort_range(std::vector<T>& values, size_t begin, size_t end,
+                     Less& less, Observer& observe) {
+  while (end - begin > 1) {
+    const size_t pivot = end - 1;
+    size_t boundary = begin;  // values[begin, boundary) < pivot
+    for (size_t i = begin; i < pivot; ++i) {
+      if (less(values[i], values[pivot])) {
+        if (i != boundary) {
+          swap_and_observe(values, i, boundary, observe);
+        }
+        ++boundary;
+      }
+    }
+    if (boundary != pivot) {
+      swap_and_observe(values, boundary, pivot, observe);
+    }
+    // Recurse into the smaller side, so the stack stays O(log n) deep
+    if (boundary - begin < end - boundary - 1) {
+      quicksort_range(values, begin, boundary, less, observe);
+      begin = boundary + 1;
+    } else {
+      quicksort_range(values, boundary + 1, end, less, observe);
+      end = boundary;
+    }
+  }
+}
+
+template <typename T, typename Less = std::less<>,
+          typename Observer = NoObserver>
+void quicksort(std::vector<T>& values, Less less = Less(),
+               Observer&& observe = Observer()) {
+  quicksort_range(values, 0, values.size(), less, observe);
+}
+
+// Heapsort: make a max-heap in place, then swap its top to the end of the
+// array and sift the new top down, until the heap is empty. O(n log n)
+// always; not stable.
+template <typename T, typename Less, typename Observer>
+void sift_down(std::vector<T>& values, size_t i, size_t size, Less& less,
+               Observer& observe) {
+  while (2 * i + 1 < size) {
+    size_t child = 2 * i + 1;
+    if (child + 1 < size && less(values[child], values[child + 1])) {
+      ++child;
+    }
+    if (!less(values[i], values[child])) {
+      return;
+    }
+    swap_and_observe(values, i, child, observe);
+    i = child;
+  }
+}
+
+template <typename T, typename Less = std::less<>,
+          typename Observer = NoObserver>
+void heapsort(std::vector<T>& values, Less less = Less(),
+              Observer&& observe = Observer()) {
+  const size_t n = values.size();
+  for (size_t i = n / 2; i-- > 0;) {
+    sift_down(values, i, n, less, observe);
+  }
+  for (size_t size = n; size > 1; --size) {
+    swap_and_observe(values, 0, size - 1, observe);
+    sift_down(values, 0, size - 1, less, observe);
+  }
+}
+
+// Bottom-up merge sort: merge runs of 1 into runs of 2, then 4, and so on,
+// through a buffer. Each merge is one step. O(n log n) always; stable.
+template <typename T, typename Less = std::less<>,
+          typename Observer = NoObserver>
+void merge_sort(std::vector<T>& values, Less less = Less(),
+                Observer&& observe = Observer()) {
+  const size_t n = values.size();
+  std::vector<T> buffer;
+  buffer.reserve(n);
+  for (size_t width = 1; width < n; width *= 2) {
+    for (size_t begin = 0; begin + width < n; begin += 2 * width) {
+      const size_t middle = begin + width;
+      const size_t end = std::min(n, begin + 2 * width);
+      buffer.clear();
+      size_t i = begin, j = middle;
+      while (i < middle || j < end) {
+        // Ties go to the left run, which keeps the sort stable
+        if (j == end || (i < middle && !less(values[j], values[i]))) {
+          buffer.push_back(std::move(values[i++]));
+        } else {
+          buffer.push_back(std::move(values[j++]));
+        }
+      }
+      std::move(buffer.begin(), buffer.end(), values.begin() + begin);
+      observe(Step{StepKind::kMerge, begin, end}, std::as_const(values));
+    }
+  }
+}
+
+// The sorts by name, for the command line: "insertion", "quick", "heap",
+// or "merge". Returns false for any other name.
+template <typename T, typename Observer>
+bool sort_by_name(const std::string& name, std::vector<T>& values,
+                  Observer&& observe) {
+  if (name == "insertion") {
+    insertion_sort(values, std::less<>(), observe);
+  } else if (name == "quick") {
+    quicksort(values, std::less<>(), observe);
+  } else if (name == "heap") {
+    heapsort(values, std::less<>(), observe);
+  } else if (name == "merge") {
+    merge_sort(values, std::less<>(), observe);
+  } else {
+    return false;
+  }
+  return true;
+}
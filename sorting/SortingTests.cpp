@@ -0,0 +1,130 @@
+#include <algorithm>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <random>
+#include <sstream>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "Animation.h"
+#include "Sorting.h"
+#include "gtest/gtest.h"
+
+static const std::vector<std::string> kSorts = {"insertion", "quick", "heap",
+                                                "merge"};
+
+static std::vector<int64_t> random_values(std::mt19937& gen, size_t max_size) {
+  std::uniform_int_distribution<int64_t> value(-20, 20);
+  std::vector<int64_t> values(gen() % (max_size + 1));
+  for (auto& v : values) {
+    v = value(gen);
+  }
+  return values;
+}
+
+TEST(Sorting, Sorts) {
+  std::mt19937 gen(178);
+  for (int trial = 0; trial < 300; ++trial) {
+    const auto values = random_values(gen, 40);
+    auto expected = values;
+    std::sort(expected.begin(), expected.end());
+    for (const auto& name : kSorts) {
+      auto sorted = values;
+      ASSERT_TRUE(sort_by_name(name, sorted, NoObserver()));
+      ASSERT_EQ(sorted, expected) << name;
+    }
+
+    auto descending = values;
+    heapsort(descending, std::greater<>());
+    std::reverse(expected.begin(), expected.end());
+    ASSERT_EQ(descending, expected);
+  }
+  std::vector<int64_t> values = {2, 1};
+  EXPECT_FALSE(sort_by_name("bogo", values, NoObserver()));
+}
+
+TEST(Sorting, Stable) {
+  std::mt19937 gen(178);
+  auto by_key = [](const auto& a, const auto& b) { return a.first < b.first; };
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<std::pair<int, size_t>> values(gen() % 30);
+    for (size_t i = 0; i < values.size(); ++i) {
+      values[i] = {static_cast<int>(gen() % 4), i};
+    }
+    auto expected = values;
+    std::stable_sort(expected.begin(), expected.end(), by_key);
+    auto insertion = values;
+    insertion_sort(insertion, by_key);
+    ASSERT_EQ(insertion, expected);
+    auto merge = values;
+    merge_sort(merge, by_key);
+    ASSERT_EQ(merge, expected);
+  }
+}
+
+// Every frame follows from the one before by its step
+TEST(Sorting, FramesReplay) {
+  std::mt19937 gen(178);
+  for (int trial = 0; trial < 100; ++trial) {
+    const auto values = random_values(gen, 20);
+    for (const auto& name : kSorts) {
+      auto sorted = values;
+      FrameRecorder<int64_t> recorder;
+      sort_by_name(name, sorted, recorder);
+      auto replay = values;
+      for (const auto& frame : recorder.frames()) {
+        const Step& step = frame.step;
+        if (step.kind == StepKind::kSwap) {
+          ASSERT_NE(name, "merge");
+          std::swap(replay[step.first], replay[step.second]);
+        } else {
+          ASSERT_EQ(name, "merge");
+          ASSERT_LT(step.first, step.second);
+          ASSERT_TRUE(std::is_sorted(frame.values.begin() + step.first,
+                                     frame.values.begin() + step.second));
+          replay = frame.values;
+        }
+        ASSERT_EQ(replay, frame.values) << name;
+      }
+      ASSERT_EQ(replay, sorted) << name;
+    }
+  }
+}
+
+TEST(Sorting, InsertionSortSwapsInversions) {
+  std::vector<int64_t> values = {3, 1, 2};
+  FrameRecorder<int64_t> recorder;
+  insertion_sort(values, std::less<>(), recorder);
+  ASSERT_EQ(recorder.frames().size(), 2u);
+  EXPECT_EQ(recorder.frames()[0].step, (Step{StepKind::kSwap, 0, 1}));
+  EXPECT_EQ(recorder.frames()[0].values, (std::vector<int64_t>{1, 3, 2}));
+  EXPECT_EQ(recorder.frames()[1].step, (Step{StepKind::kSwap, 1, 2}));
+  EXPECT_EQ(recorder.frames()[1].values, (std::vector<int64_t>{1, 2, 3}));
+}
+
+TEST(Animation, Frames) {
+  const Frame<int64_t> frame = {{StepKind::kSwap, 0, 2}, {2, -1, 3}};
+  EXPECT_EQ(render_frame(frame),
+            "swap 0 2\n"
+            "     2 ## <\n"
+            "    -1 \n"
+            "     3 ### <\n");
+  EXPECT_EQ(render_frame({{StepKind::kMerge, 0, 1}, {120}}),
+            "merge 0 1\n"
+            "   120 " + std::string(60, '#') + " <\n");
+  EXPECT_EQ(to_json(std::vector<Frame<int64_t>>{frame}),
+            "[{\"kind\":\"swap\",\"first\":0,\"second\":2,"
+            "\"values\":[2,-1,3]}]");
+
+  std::ostringstream out;
+  play_frames({frame, frame}, out, std::chrono::milliseconds(0), false);
+  EXPECT_EQ(out.str(), render_frame(frame) + "\n" + render_frame(frame));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,110 @@
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <vector>
+
+// The letters a phone number could spell, one letter per digit from the
+// letters on its key: 23 is ad, ae, af, bd, ..., cf. 0 and 1 have no
+// letters, so numbers with them spell nothing. The number of words is the
+// product of the letter counts, 3 or 4 a digit.
+
+inline std::string_view keypad_letters(char digit) {
+  static constexpr std::array<std::string_view, 10> kLetters = {
+      "", "", "abc", "def", "ghi", "jkl", "mno", "pqrs", "tuv", "wxyz"};
+  if (digit < '0' || digit > '9') {
+    throw std::invalid_argument("not a digit");
+  }
+  return kLetters[digit - '0'];
+}
+
+// Backtracking: try each letter of the next digit's key, spell the rest
+// after it, and take it back off. The words come out in alphabetical
+// order; the empty number spells the empty word.
+inline void keypad_words_from(std::string_view digits, std::string& word,
+                              std::vector<std::string>& words) {
+  if (word.size() == digits.size()) {
+    words.push_back(word);
+    return;
+  }
+  for (char letter : keypad_letters(digits[word.size()])) {
+    word.push_back(letter);
+    keypad_words_from(digits, word, words);
+    word.pop_back();
+  }
+}
+
+inline std::vector<std::string> keypad_words(std::string_view digits) {
+  std::vector<std::string> words;
+  std::string word;
+  keypad_words_from(digits, word, words);
+  return words;
+}
+
+// The same words in the same order, one at a time, like an odometer
+// whose wheels have the letters of each key:
+//
+//   for (const auto& word : KeypadWords("2273")) { ... }
+//
+// Each step turns the last wheel, carrying into the ones before when it
+// comes back around; amortized O(1) a word, and only the current word is
+// stored.
+class KeypadWords {
+ public:
+  explicit KeypadWords(std::string_view digits)
+      : digits_(digits), letter_(digits.size(), 0), word_(digits.size(), ' ') {
+    for (size_t i = 0; i < digits_.size(); ++i) {
+      const std::string_view letters = keypad_letters(digits_[i]);
+      if (letters.empty()) {
+        done_ = true;
+        return;
+      }
+      word_[i] = letters[0];
+    }
+  }
+
+  class Iterator {
+   public:
+    const std::string& operator*() const { return owner_->word_; }
+    Iterator& operator++() {
+      owner_->advance();
+      return *this;
+    }
+    bool operator==(const Iterator& other) const {
+      return done() == other.done();
+    }
+
+   private:
+    friend class KeypadWords;
+    explicit Iterator(KeypadWords* owner) : owner_(owner) {}
+    bool done() const { return owner_ == nullptr || owner_->done_; }
+
+    KeypadWords* owner_;
+  };
+
+  // Iterating consumes the sequence, so begin() can only be used once
+  Iterator begin() { return Iterator(this); }
+  Iterator end() { return Iterator(nullptr); }
+
+ private:
+  void advance() {
+    for (size_t i = digits_.size(); i-- > 0;) {
+      const std::string_view letters = keypad_letters(digits_[i]);
+      if (++letter_[i] < letters.size()) {
+        word_[i] = letters[letter_[i]];
+        return;
+      }
+      letter_[i] = 0;
+      word_[i] = letters[0];
+    }
+    done_ = true;  // every wheel came back around
+  }
+
+  std::string digits_;
+  std::vector<size_t> letter_;  // letter_[i] indexes digit i's letters
+  std::string word_;
+  bool done_{false};
+};
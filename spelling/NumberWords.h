@@ -0,0 +1,144 @@
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <sstream>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+// Integers in English words, the way a check is written out:
+// 1234567 is "one million two hundred thirty-four thousand five hundred
+// sixty-seven". Digits go in groups of three from the right, each group
+// is said as a number below a thousand followed by its scale word, and
+// groups that are zero are left out. No "and", and tens and ones are
+// joined by a hyphen.
+
+constexpr std::array<std::string_view, 20> kSmallNumbers = {
+    "zero",    "one",     "two",       "three",    "four",
+    "five",    "six",     "seven",     "eight",    "nine",
+    "ten",     "eleven",  "twelve",    "thirteen", "fourteen",
+    "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"};
+
+// kTens[t] is 10 t, from twenty on
+constexpr std::array<std::string_view, 10> kTens = {
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy",
+    "eighty", "ninety"};
+
+// kScales[i] is 1000^i; 2^64 is below 1000^7
+constexpr std::array<std::string_view, 7> kScales = {
+    "", "thousand", "million", "billion", "trillion", "quadrillion",
+    "quintillion"};
+
+// 1 to 999
+inline std::string group_to_words(unsigned group) {
+  std::string words;
+  if (group >= 100) {
+    words = std::string(kSmallNumbers[group / 100]) + " hundred";
+    group %= 100;
+    if (group == 0) {
+      return words;
+    }
+    words += ' ';
+  }
+  if (group < 20) {
+    return words + std::string(kSmallNumbers[group]);
+  }
+  words += kTens[group / 10];
+  if (group % 10 != 0) {
+    words += '-';
+    words += kSmallNumbers[group % 10];
+  }
+  return words;
+}
+
+inline std::string number_to_words(int64_t n) {
+  if (n == 0) {
+    return "zero";
+  }
+  // Negating in unsigned arithmetic works for the smallest int64_t too
+  uint64_t magnitude = n < 0 ? uint64_t{0} - static_cast<uint64_t>(n)
+                             : static_cast<uint64_t>(n);
+  std::vector<std::string> parts;  // from the lowest group up
+  for (size_t scale = 0; magnitude > 0; ++scale, magnitude /= 1000) {
+    const auto group = static_cast<unsigned>(magnitude % 1000);
+    if (group == 0) {
+      continue;
+    }
+    std::string part = group_to_words(group);
+    if (scale > 0) {
+      part += ' ';
+      part += kScales[scale];
+    }
+    parts.push_back(std::move(part));
+  }
+  std::string words = n < 0 ? "negative" : "";
+  for (size_t i = parts.size(); i-- > 0;) {
+    if (!words.empty()) {
+      words += ' ';
+    }
+    words += parts[i];
+  }
+  return words;
+}
+
+// The index of word in the table, if it's there
+template <size_t N>
+std::optional<uint64_t> find_word(
+    const std::array<std::string_view, N>& table, std::string_view word) {
+  for (size_t i = 0; i < N; ++i) {
+    if (!table[i].empty() && table[i] == word) {
+      return i;
+    }
+  }
+  return std::nullopt;
+}
+
+// The inverse of number_to_words, for exactly the strings it produces, and
+// nullopt for anything else. Adding up the words is lenient, taking "one
+// thousand thousand" or "twenty five" as well, so the result is checked by
+// spelling it out again: every number has only one spelling, so getting
+// the text back means it was that spelling. Arithmetic wraps around for
+// nonsense that overflows, which the check also catches.
+inline std::optional<int64_t> words_to_number(std::string_view text) {
+  std::istringstream in{std::string(text)};
+  bool negative = false;
+  uint64_t total = 0;
+  uint64_t group = 0;
+  for (std::string token; in >> token;) {
+    const size_t hyphen = token.find('-');
+    if (token == "negative") {
+      negative = true;
+    } else if (token == "hundred") {
+      group *= 100;
+    } else if (const auto scale = find_word(kScales, token)) {
+      for (uint64_t k = 0; k < *scale; ++k) {
+        group *= 1000;
+      }
+      total += group;
+      group = 0;
+    } else if (const auto small = find_word(kSmallNumbers, token)) {
+      group += *small;
+    } else if (const auto tens = find_word(kTens, token.substr(0, hyphen))) {
+      group += 10 * *tens;
+      if (hyphen != std::string::npos) {
+        const auto ones = find_word(kSmallNumbers, token.substr(hyphen + 1));
+        if (!ones) {
+          return std::nullopt;
+        }
+        group += *ones;
+      }
+    } else {
+      return std::nullopt;
+    }
+  }
+  total += group;
+  const auto n = static_cast<int64_t>(negative ? uint64_t{0} - total : total);
+  if (number_to_words(n) != text) {
+    return std::nullopt;
+  }
+  return n;
+}
@@ -0,0 +1,121 @@
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+#include <optional>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "KeypadWords.h"
+#include "NumberWords.h"
+#include "gtest/gtest.h"
+
+TEST(NumberWords, Examples) {
+  EXPECT_EQ(number_to_words(0), "zero");
+  EXPECT_EQ(number_to_words(7), "seven");
+  EXPECT_EQ(number_to_words(13), "thirteen");
+  EXPECT_EQ(number_to_words(40), "forty");
+  EXPECT_EQ(number_to_words(99), "ninety-nine");
+  EXPECT_EQ(number_to_words(100), "one hundred");
+  EXPECT_EQ(number_to_words(-115), "negative one hundred fifteen");
+  EXPECT_EQ(number_to_words(1'000'010), "one million ten");
+  EXPECT_EQ(number_to_words(1'234'567),
+            "one million two hundred thirty-four thousand five hundred "
+            "sixty-seven");
+  EXPECT_EQ(number_to_words(std::numeric_limits<int64_t>::min()),
+            "negative nine quintillion two hundred twenty-three quadrillion "
+            "three hundred seventy-two trillion thirty-six billion eight "
+            "hundred fifty-four million seven hundred seventy-five thousand "
+            "eight hundred eight");
+
+  EXPECT_EQ(words_to_number("twenty-one thousand"), 21'000);
+  EXPECT_EQ(words_to_number("negative zero"), std::nullopt);
+  EXPECT_EQ(words_to_number("one thousand thousand"), std::nullopt);
+  EXPECT_EQ(words_to_number("twenty one"), std::nullopt);
+  EXPECT_EQ(words_to_number("twenty-zero"), std::nullopt);
+  EXPECT_EQ(words_to_number("one hundred and one"), std::nullopt);
+  EXPECT_EQ(words_to_number("ten quintillion"), std::nullopt);
+  EXPECT_EQ(words_to_number(""), std::nullopt);
+}
+
+// Every number below a million spells differently and reads back, and the
+// groups reappear unchanged at every scale
+TEST(NumberWords, Exhaustive) {
+  std::set<std::string> seen;
+  for (int64_t n = 0; n < 1'000'000; ++n) {
+    const std::string words = number_to_words(n);
+    ASSERT_TRUE(seen.insert(words).second) << words;
+    ASSERT_EQ(words_to_number(words), n) << words;
+    ASSERT_EQ(words_to_number("negative " + words),
+              n == 0 ? std::nullopt : std::optional<int64_t>(-n));
+  }
+  for (int64_t group = 1; group < 1000; ++group) {
+    ASSERT_EQ(number_to_words(group * 1'000'000'000),
+              number_to_words(group) + " billion");
+  }
+
+  Rng gen(221);
+  for (int trial = 0; trial < 10000; ++trial) {
+    const auto n = static_cast<int64_t>(gen());
+    ASSERT_EQ(words_to_number(number_to_words(n)), n);
+  }
+}
+
+TEST(KeypadWords, Examples) {
+  EXPECT_EQ(keypad_words("23"),
+            (std::vector<std::string>{"ad", "ae", "af", "bd", "be", "bf",
+                                      "cd", "ce", "cf"}));
+  EXPECT_EQ(keypad_words(""), (std::vector<std::string>{""}));
+  EXPECT_TRUE(keypad_words("210").empty());
+  EXPECT_EQ(keypad_words("7").size(), 4u);
+  EXPECT_THROW(keypad_words("2a"), std::invalid_argument);
+
+  std::vector<std::string> words;
+  for (const auto& word : KeypadWords("2273")) {
+    words.push_back(word);
+  }
+  EXPECT_EQ(words.size(), 3u * 3u * 4u * 3u);
+  EXPECT_NE(std::find(words.begin(), words.end(), "case"), words.end());
+}
+
+// Every number of up to four digits: the iterator and the backtracking
+// agree, and give each combination once, in order
+TEST(KeypadWords, Exhaustive) {
+  std::vector<std::string> numbers = {""};
+  for (size_t length = 1; length <= 4; ++length) {
+    std::vector<std::string> longer;
+    for (const auto& number : numbers) {
+      if (number.size() == length - 1) {
+        for (char digit = '0'; digit <= '9'; ++digit) {
+          longer.push_back(number + digit);
+        }
+      }
+    }
+    numbers.insert(numbers.end(), longer.begin(), longer.end());
+  }
+  for (const auto& number : numbers) {
+    size_t count = 1;
+    for (char digit : number) {
+      count *= keypad_letters(digit).size();
+    }
+    const auto expected = keypad_words(number);
+    ASSERT_EQ(expected.size(), count) << number;
+    ASSERT_TRUE(std::is_sorted(expected.begin(), expected.end()));
+    ASSERT_EQ(std::set<std::string>(expected.begin(), expected.end()).size(),
+              count);
+
+    std::vector<std::string> words;
+    for (const auto& word : KeypadWords(number)) {
+      words.push_back(word);
+    }
+    ASSERT_EQ(words, expected) << number;
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,298 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <vector>
+
+// Self-adjusting binary search tree (Sleator and Tarjan, 1985).
+//
+// Every access splays the touched node to the root through a sequence of
+// zig, zig-zig, and zig-zag rotations. A single operation can cost O(n), but
+// any sequence of m operations on a tree of n nodes costs O((m + n) log n),
+// so each operation is O(log n) amortized.
+//
+// The tree counts every rotation it performs. Rotations are the unit of work
+// in the amortized analysis, so dividing the counter by the number of
+// operations gives an empirical amortized cost per operation.
+template <typename T, typename Compare = std::less<T>>
+class SplayTree {
+ public:
+  // Counters for the amortized analysis
+  struct Stats {
+    uint64_t operations{0};
+    uint64_t rotations{0};
+  };
+
+ private:
+  struct Node {
+    T key;
+    Node* left{nullptr};
+    Node* right{nullptr};
+    Node* parent{nullptr};
+
+    explicit Node(const T& k) : key(k) {}
+  };
+
+  Node* root_{nullptr};
+  size_t size_{0};
+  Compare less_;
+  Stats stats_;
+
+  /*
+         p              x
+        / \            / \
+       x   c   --->   a   p
+      / \                / \
+     a   b              b   c
+
+     And the mirror image when x is the right child of p.
+  */
+  void rotate(Node* x) {
+    Node* p = x->parent;
+    Node* g = p->parent;
+    if (x == p->left) {
+      p->left = x->right;
+      if (x->right) {
+        x->right->parent = p;
+      }
+      x->right = p;
+    } else {
+      p->right = x->left;
+      if (x->left) {
+        x->left->parent = p;
+      }
+      x->left = p;
+    }
+    p->parent = x;
+    x->parent = g;
+    if (!g) {
+      root_ = x;
+    } else if (g->left == p) {
+      g->left = x;
+    } else {
+      g->right = x;
+    }
+    ++stats_.rotations;
+  }
+
+  // Move x to the root
+  void splay(Node* x) {
+    while (x->parent) {
+      Node* p = x->parent;
+      Node* g = p->parent;
+      if (!g) {
+        // Zig: p is the root
+        rotate(x);
+      } else if ((g->left == p) == (p->left == x)) {
+        // Zig-zig: x and p are both left (or both right) children. Rotating p
+        // first is what makes the amortized bound work; rotating x twice
+        // would just move the long path around.
+        rotate(p);
+        rotate(x);
+      } else {
+        // Zig-zag
+        rotate(x);
+        rotate(x);
+      }
+    }
+  }
+
+  // Returns the node holding key, or the last node visited on the search path
+  // if the key is absent. Returns nullptr only for an empty tree.
+  Node* find_node(const T& key) const {
+    Node* curr = root_;
+    Node* last = nullptr;
+    while (curr) {
+      last = curr;
+      if (less_(key, curr->key)) {
+        curr = curr->left;
+      } else if (less_(curr->key, key)) {
+        curr = curr->right;
+      } else {
+        return curr;
+      }
+    }
+    return last;
+  }
+
+  bool is_equal(const T& a, const T& b) const {
+    return !less_(a, b) && !less_(b, a);
+  }
+
+  static void destroy(Node* node) {
+    // Iterative, so that degenerate (path-shaped) trees don't blow the stack
+    std::vector<Node*> stack;
+    if (node) {
+      stack.push_back(node);
+    }
+    while (!stack.empty()) {
+      Node* curr = stack.back();
+      stack.pop_back();
+      if (curr->left) {
+        stack.push_back(curr->left);
+      }
+      if (curr->right) {
+        stack.push_back(curr->right);
+      }
+      delete curr;
+    }
+  }
+
+ public:
+  SplayTree() = default;
+
+  // Disable copy and move
+  SplayTree(const SplayTree&) = delete;
+  SplayTree& operator=(const SplayTree&) = delete;
+
+  ~SplayTree() { destroy(root_); }
+
+  size_t size() const { return size_; }
+
+  bool empty() const { return size_ == 0; }
+
+  const Stats& stats() const { return stats_; }
+
+  void reset_stats() { stats_ = Stats{}; }
+
+  // Returns false if the key was already present. Either way, the node
+  // holding the key ends up at the root.
+  bool insert(const T& key) {
+    ++stats_.operations;
+
+    Node* last = find_node(key);
+    if (last && is_equal(last->key, key)) {
+      splay(last);
+      return false;
+    }
+
+    Node* node = new Node(key);
+    node->parent = last;
+    if (!last) {
+      root_ = node;
+    } else if (less_(key, last->key)) {
+      last->left = node;
+    } else {
+      last->right = node;
+    }
+    splay(node);
+    ++size_;
+    return true;
+  }
+
+  // Splays the found node (or the last node on the search path) to the root
+  bool contains(const T& key) {
+    ++stats_.operations;
+
+    Node* last = find_node(key);
+    if (!last) {
+      return false;
+    }
+    splay(last);
+    return is_equal(last->key, key);
+  }
+
+  bool remove(const T& key) {
+    ++stats_.operations;
+
+    Node* node = find_node(key);
+    if (!node) {
+      return false;
+    }
+    splay(node);
+    if (!is_equal(node->key, key)) {
+      return false;
+    }
+
+    // node is now the root. Detach both subtrees, splay the maximum of the
+    // left subtree to its root (so it has no right child), and hang the right
+    // subtree there.
+    Node* left = node->left;
+    Node* right = node->right;
+    delete node;
+    --size_;
+
+    if (left) {
+      left->parent = nullptr;
+    }
+    if (right) {
+      right->parent = nullptr;
+    }
+
+    if (!left) {
+      root_ = right;
+      return true;
+    }
+
+    root_ = left;
+    Node* max = left;
+    while (max->right) {
+      max = max->right;
+    }
+    splay(max);
+    max->right = right;
+    if (right) {
+      right->parent = max;
+    }
+    return true;
+  }
+
+  // Smallest key, if any. Like every access, this splays.
+  const T* min() {
+    ++stats_.operations;
+    if (!root_) {
+      return nullptr;
+    }
+    Node* curr = root_;
+    while (curr->left) {
+      curr = curr->left;
+    }
+    splay(curr);
+    return &curr->key;
+  }
+
+  // The key currently at the root (the most recently accessed one)
+  const T* root() const { return root_ ? &root_->key : nullptr; }
+
+  // Number of edges on the longest root-to-leaf path. Doesn't splay.
+  size_t height() const {
+    if (!root_) {
+      return 0;
+    }
+    size_t result = 0;
+    std::vector<std::pair<Node*, size_t>> stack{{root_, 0}};
+    while (!stack.empty()) {
+      auto [node, depth] = stack.back();
+      stack.pop_back();
+      result = std::max(result, depth);
+      if (node->left) {
+        stack.push_back({node->left, depth + 1});
+      }
+      if (node->right) {
+        stack.push_back({node->right, depth + 1});
+      }
+    }
+    return result;
+  }
+
+  // Keys in sorted order. Doesn't splay.
+  std::vector<T> in_order() const {
+    std::vector<T> result;
+    result.reserve(size_);
+    std::vector<Node*> stack;
+    Node* curr = root_;
+    while (curr || !stack.empty()) {
+      while (curr) {
+        stack.push_back(curr);
+        curr = curr->left;
+      }
+      curr = stack.back();
+      stack.pop_back();
+      result.push_back(curr->key);
+      curr = curr->right;
+    }
+    return result;
+  }
+};
@@ -0,0 +1,216 @@
+#include <cmath>
+#include <cstdint>
+#include <random>
+#include <set>
+#include <vector>
+
+#include "SplayTree.h"
+#include "gtest/gtest.h"
+
+// Upper bound on the amortized number of rotations per operation implied by
+// the access lemma: a splay costs at most 3 * log2(n) + 1 amortized
+// rotations, and an insert can raise the potential by at most another
+// log2(n). Starting from an empty tree, the total actual cost of a sequence
+// of operations is bounded by the total amortized cost.
+double amortized_bound(size_t n) {
+  return 4.0 * std::log2(static_cast<double>(n)) + 2.0;
+}
+
+double rotations_per_operation(const SplayTree<int>& tree) {
+  const auto& stats = tree.stats();
+  return static_cast<double>(stats.rotations) /
+         static_cast<double>(stats.operations);
+}
+
+TEST(SplayTree, EmptyTree) {
+  SplayTree<int> tree;
+
+  EXPECT_TRUE(tree.empty());
+  EXPECT_EQ(tree.size(), 0u);
+  EXPECT_FALSE(tree.contains(42));
+  EXPECT_FALSE(tree.remove(42));
+  EXPECT_EQ(tree.min(), nullptr);
+  EXPECT_EQ(tree.root(), nullptr);
+}
+
+TEST(SplayTree, InsertAndContains) {
+  SplayTree<int> tree;
+
+  EXPECT_TRUE(tree.insert(5));
+  EXPECT_TRUE(tree.insert(3));
+  EXPECT_TRUE(tree.insert(8));
+
+  EXPECT_EQ(tree.size(), 3u);
+  EXPECT_TRUE(tree.contains(5));
+  EXPECT_TRUE(tree.contains(3));
+  EXPECT_TRUE(tree.contains(8));
+  EXPECT_FALSE(tree.contains(4));
+}
+
+TEST(SplayTree, InsertDuplicates) {
+  SplayTree<int> tree;
+
+  EXPECT_TRUE(tree.insert(1));
+  EXPECT_FALSE(tree.insert(1));
+  EXPECT_EQ(tree.size(), 1u);
+}
+
+TEST(SplayTree, AccessMovesKeyToRoot) {
+  SplayTree<int> tree;
+  for (int i = 0; i < 10; ++i) {
+    tree.insert(i);
+  }
+  EXPECT_EQ(*tree.root(), 9);
+
+  tree.contains(3);
+  EXPECT_EQ(*tree.root(), 3);
+
+  EXPECT_EQ(*tree.min(), 0);
+  EXPECT_EQ(*tree.root(), 0);
+}
+
+TEST(SplayTree, Remove) {
+  SplayTree<int> tree;
+  for (int x : {5, 2, 8, 1, 3, 7, 9}) {
+    tree.insert(x);
+  }
+
+  EXPECT_TRUE(tree.remove(5));
+  EXPECT_FALSE(tree.remove(5));
+  EXPECT_TRUE(tree.remove(1));
+  EXPECT_TRUE(tree.remove(9));
+  EXPECT_FALSE(tree.remove(4));
+
+  EXPECT_EQ(tree.size(), 4u);
+  EXPECT_EQ(tree.in_order(), (std::vector<int>{2, 3, 7, 8}));
+}
+
+TEST(SplayTree, MatchesStdSet) {
+  SplayTree<int> tree;
+  std::set<int> expected;
+  std::mt19937 gen(12345);
+  std::uniform_int_distribution<int> key(0, 500);
+  std::uniform_int_distribution<int> op(0, 2);
+
+  for (int i = 0; i < 20000; ++i) {
+    int k = key(gen);
+    switch (op(gen)) {
+      case 0:
+        EXPECT_EQ(tree.insert(k), expected.insert(k).second);
+        break;
+      case 1:
+        EXPECT_EQ(tree.remove(k), expected.erase(k) == 1);
+        break;
+      default:
+        EXPECT_EQ(tree.contains(k), expected.count(k) == 1);
+    }
+  }
+
+  EXPECT_EQ(tree.size(), expected.size());
+  EXPECT_EQ(tree.in_order(),
+            std::vector<int>(expected.begin(), expected.end()));
+}
+
+TEST(SplayTree, SequentialInsertBuildsAPath) {
+  // Inserting in increasing order makes each new key the root with the old
+  // tree as its left child, so the tree degenerates into a path. Each insert
+  // is a single rotation, though.
+  const int n = 1000;
+  SplayTree<int> tree;
+  for (int i = 0; i < n; ++i) {
+    tree.insert(i);
+  }
+
+  EXPECT_EQ(tree.height(), static_cast<size_t>(n - 1));
+  EXPECT_EQ(tree.stats().rotations, static_cast<uint64_t>(n - 1));
+
+  // The first access to the deepest key is expensive...
+  tree.reset_stats();
+  tree.contains(0);
+  EXPECT_EQ(tree.stats().rotations, static_cast<uint64_t>(n - 1));
+
+  // ...but it roughly halves the depth of every node on the path.
+  EXPECT_LE(tree.height(), static_cast<size_t>(n / 2 + 1));
+}
+
+TEST(SplayTree, SequentialAccessIsLinearOverall) {
+  // Sequential access theorem: accessing every key in order costs O(n) in
+  // total, even starting from the worst possible shape.
+  const int n = 4096;
+  SplayTree<int> tree;
+  for (int i = 0; i < n; ++i) {
+    tree.insert(i);
+  }
+
+  tree.reset_stats();
+  for (int round = 0; round < 3; ++round) {
+    for (int i = 0; i < n; ++i) {
+      ASSERT_TRUE(tree.contains(i));
+    }
+  }
+
+  EXPECT_LE(rotations_per_operation(tree), 5.0);
+}
+
+TEST(SplayTree, AlternatingExtremesIsLogarithmicAmortized) {
+  // Repeatedly jumping between the smallest and largest key always touches
+  // the deepest node of the previous shape.
+  const int n = 4096;
+  SplayTree<int> tree;
+  for (int i = 0; i < n; ++i) {
+    tree.insert(i);
+  }
+
+  for (int i = 0; i < 10000; ++i) {
+    ASSERT_TRUE(tree.contains(i % 2 == 0 ? 0 : n - 1));
+  }
+
+  EXPECT_LE(rotations_per_operation(tree), amortized_bound(n));
+}
+
+TEST(SplayTree, BitReversalAccessIsLogarithmicAmortized) {
+  // The bit-reversal permutation is a classic hard access sequence: any
+  // binary search tree needs Omega(log n) per access to serve it.
+  const int bits = 12;
+  const int n = 1 << bits;
+  SplayTree<int> tree;
+  for (int i = 0; i < n; ++i) {
+    tree.insert(i);
+  }
+
+  for (int round = 0; round < 3; ++round) {
+    for (int i = 0; i < n; ++i) {
+      int reversed = 0;
+      for (int b = 0; b < bits; ++b) {
+        if (i & (1 << b)) {
+          reversed |= 1 << (bits - 1 - b);
+        }
+      }
+      ASSERT_TRUE(tree.contains(reversed));
+    }
+  }
+
+  EXPECT_LE(rotations_per_operation(tree), amortized_bound(n));
+}
+
+TEST(SplayTree, RandomWorkloadIsLogarithmicAmortized) {
+  const int n = 10000;
+  SplayTree<int> tree;
+  std::mt19937 gen(42);
+  std::uniform_int_distribution<int> key(0, n - 1);
+
+  for (int i = 0; i < 100000; ++i) {
+    if (i % 4 == 0) {
+      tree.remove(key(gen));
+    } else {
+      tree.insert(key(gen));
+    }
+  }
+
+  EXPECT_LE(rotations_per_operation(tree), amortized_bound(n));
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
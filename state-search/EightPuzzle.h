@@ -0,0 +1,64 @@
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+// The 8-puzzle: eight numbered tiles and a blank on a 3x3 board, read row by
+// row with 0 for the blank. Slide a tile next to the blank into it, until
+// the board reads 1, 2, ..., 8 and the blank is last.
+struct EightPuzzle {
+  using State = std::array<int, 9>;
+
+  static constexpr State kSolved = {1, 2, 3, 4, 5, 6, 7, 8, 0};
+
+  State start;
+
+  State initial() const { return start; }
+
+  bool is_goal(const State& s) const { return s == kSolved; }
+
+  // Moving the blank instead of a tile: up, down, left, right
+  std::vector<State> successors(const State& s) const {
+    size_t blank = 0;
+    while (s[blank] != 0) {
+      ++blank;
+    }
+    const size_t row = blank / 3;
+    const size_t col = blank % 3;
+    std::vector<State> next;
+    auto swap_with = [&](size_t cell) {
+      State moved = s;
+      std::swap(moved[blank], moved[cell]);
+      next.push_back(moved);
+    };
+    if (row > 0) {
+      swap_with(blank - 3);
+    }
+    if (row < 2) {
+      swap_with(blank + 3);
+    }
+    if (col > 0) {
+      swap_with(blank - 1);
+    }
+    if (col < 2) {
+      swap_with(blank + 1);
+    }
+    return next;
+  }
+};
+
+// On a board of odd width, a move either swaps nothing in the order of the
+// tiles (sideways) or moves one tile past two others (up or down), so the
+// parity of the number of inversions never changes. Half the boards have
+// the solved board's parity, even, and all of those can be solved.
+inline bool is_solvable(const EightPuzzle::State& s) {
+  int inversions = 0;
+  for (size_t i = 0; i < s.size(); ++i) {
+    for (size_t j = i + 1; j < s.size(); ++j) {
+      inversions += s[i] != 0 && s[j] != 0 && s[i] > s[j];
+    }
+  }
+  return inversions % 2 == 0;
+}
@@ -0,0 +1,140 @@
+#pragma once
+
+#include <algorithm>
+#include <concepts>
+#include <cstddef>
+#include <map>
+#include <optional>
+#include <queue>
+#include <set>
+#include <utility>
+#include <vector>
+
+// Generic search over implicit graphs. A problem describes its states and
+// moves, and the solvers here find a path from the initial state to a goal
+// without the graph ever being built. A problem is a StateSpace if it has:
+//
+// - a State type, ordered so that visited states can go in a std::set;
+// - initial(), the state to start from;
+// - successors(s), the states one move away from s; and
+// - is_goal(s).
+//
+// Each solver returns the states along the path it found, initial state
+// first and goal last, or std::nullopt if there's no path.
+template <typename P>
+concept StateSpace =
+    std::totally_ordered<typename P::State> &&
+    requires(const P& problem, const typename P::State& s) {
+      { problem.initial() } -> std::convertible_to<typename P::State>;
+      {
+        problem.successors(s)
+      } -> std::convertible_to<std::vector<typename P::State>>;
+      { problem.is_goal(s) } -> std::convertible_to<bool>;
+    };
+
+template <typename P>
+using Path = std::vector<typename P::State>;
+
+template <typename State>
+std::vector<State> path_to(const std::map<State, State>& parent,
+                           const State& initial, State s) {
+  std::vector<State> path = {s};
+  while (!(s == initial)) {
+    s = parent.at(s);
+    path.push_back(s);
+  }
+  std::reverse(path.begin(), path.end());
+  return path;
+}
+
+// Breadth-first: states in order of distance, so the path found has the
+// fewest moves. Stores every state reached, O(states) memory.
+template <StateSpace P>
+std::optional<Path<P>> bfs(const P& problem) {
+  using State = typename P::State;
+  const State initial = problem.initial();
+  std::map<State, State> parent;
+  std::set<State> visited = {initial};
+  std::queue<State> frontier;
+  frontier.push(initial);
+  while (!frontier.empty()) {
+    State s = std::move(frontier.front());
+    frontier.pop();
+    if (problem.is_goal(s)) {
+      return path_to(parent, initial, s);
+    }
+    for (auto& next : problem.successors(s)) {
+      if (visited.insert(next).second) {
+        parent.emplace(next, s);
+        frontier.push(std::move(next));
+      }
+    }
+  }
+  return std::nullopt;
+}
+
+// Depth-first with a stack and a visited set: finds some path, usually far
+// from the shortest, but its frontier stays small on deep, narrow spaces.
+template <StateSpace P>
+std::optional<Path<P>> dfs(const P& problem) {
+  using State = typename P::State;
+  const State initial = problem.initial();
+  std::map<State, State> parent;
+  std::set<State> visited;
+  std::vector<State> stack = {initial};
+  while (!stack.empty()) {
+    State s = std::move(stack.back());
+    stack.pop_back();
+    if (!visited.insert(s).second) {
+      continue;  // Pushed more than once before it was expanded
+    }
+    if (problem.is_goal(s)) {
+      return path_to(parent, initial, s);
+    }
+    for (auto& next : problem.successors(s)) {
+      if (!visited.count(next)) {
+        parent.insert_or_assign(next, s);
+        stack.push_back(std::move(next));
+      }
+    }
+  }
+  return std::nullopt;
+}
+
+// Follows paths of at most `depth` more moves from the end of path, never
+// revisiting a state already on it
+template <StateSpace P>
+bool depth_limited(const P& problem, Path<P>& path, size_t depth) {
+  if (problem.is_goal(path.back())) {
+    return true;
+  }
+  if (depth == 0) {
+    return false;
+  }
+  for (auto& next : problem.successors(path.back())) {
+    if (std::find(path.begin(), path.end(), next) != path.end()) {
+      continue;
+    }
+    path.push_back(std::move(next));
+    if (depth_limited(problem, path, depth - 1)) {
+      return true;
+    }
+    path.pop_back();
+  }
+  return false;
+}
+
+// Iterative deepening: depth-limited DFS with limits 0, 1, 2, ... up to
+// max_depth. Like BFS, the first path found has the fewest moves; like DFS,
+// it only stores the current path. The shallow levels get searched again on
+// every round, but in a branching space the deepest round dominates anyway.
+template <StateSpace P>
+std::optional<Path<P>> iddfs(const P& problem, size_t max_depth) {
+  for (size_t depth = 0; depth <= max_depth; ++depth) {
+    Path<P> path = {problem.initial()};
+    if (depth_limited(problem, path, depth)) {
+      return path;
+    }
+  }
+  return std::nullopt;
+}
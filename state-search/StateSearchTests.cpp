@@ -0,0 +1,121 @@
+#include <algorithm>
+#include <cstddef>
+#include <numeric>
+#include <optional>
+#include <random>
+#include <utility>
+#include <vector>
+
+#include "EightPuzzle.h"
+#include "StateSearch.h"
+#include "WaterJug.h"
+#include "gtest/gtest.h"
+
+// Starts at the initial state, ends at a goal, and every step is a move
+template <typename P>
+bool is_solution(const P& problem, const Path<P>& path) {
+  if (path.empty() || !(path.front() == problem.initial()) ||
+      !problem.is_goal(path.back())) {
+    return false;
+  }
+  for (size_t i = 1; i < path.size(); ++i) {
+    auto next = problem.successors(path[i - 1]);
+    if (std::find(next.begin(), next.end(), path[i]) == next.end()) {
+      return false;
+    }
+  }
+  return true;
+}
+
+// Scrambles the solved board with random moves, so that it stays solvable
+EightPuzzle::State scrambled(std::mt19937& gen, int moves) {
+  EightPuzzle puzzle{EightPuzzle::kSolved};
+  EightPuzzle::State s = EightPuzzle::kSolved;
+  for (int i = 0; i < moves; ++i) {
+    auto next = puzzle.successors(s);
+    s = next[std::uniform_int_distribution<size_t>(0, next.size() - 1)(gen)];
+  }
+  return s;
+}
+
+TEST(StateSearch, DieHardJugs) {
+  // Fill 5, pour into 3, empty 3, pour 2 into 3, fill 5, top up 3: 4 left
+  WaterJug jugs{3, 5, 4};
+  auto path = bfs(jugs);
+
+  ASSERT_TRUE(path);
+  EXPECT_EQ(path->size(), 7u);  // 6 moves
+  EXPECT_TRUE(is_solution(jugs, *path));
+  EXPECT_EQ(iddfs(jugs, 10)->size(), 7u);
+  EXPECT_TRUE(is_solution(jugs, *iddfs(jugs, 10)));
+  EXPECT_TRUE(is_solution(jugs, *dfs(jugs)));
+
+  // Not enough moves allowed
+  EXPECT_FALSE(iddfs(jugs, 5));
+}
+
+TEST(StateSearch, JugsSolvableIffGcdDivides) {
+  for (int a = 1; a <= 7; ++a) {
+    for (int b = 1; b <= 7; ++b) {
+      for (int target = 0; target <= 8; ++target) {
+        WaterJug jugs{a, b, target};
+        const bool solvable =
+            target <= std::max(a, b) && target % std::gcd(a, b) == 0;
+        auto shortest = bfs(jugs);
+        auto some = dfs(jugs);
+
+        ASSERT_EQ(shortest.has_value(), solvable);
+        ASSERT_EQ(some.has_value(), solvable);
+        if (solvable) {
+          ASSERT_TRUE(is_solution(jugs, *shortest));
+          ASSERT_TRUE(is_solution(jugs, *some));
+          ASSERT_LE(shortest->size(), some->size());
+          ASSERT_EQ(iddfs(jugs, shortest->size())->size(), shortest->size());
+        }
+      }
+    }
+  }
+}
+
+TEST(StateSearch, EightPuzzle) {
+  EightPuzzle solved{EightPuzzle::kSolved};
+  EXPECT_EQ(bfs(solved)->size(), 1u);
+
+  EightPuzzle two_moves{{1, 2, 3, 4, 5, 6, 0, 7, 8}};
+  EXPECT_EQ(bfs(two_moves)->size(), 3u);
+  EXPECT_EQ(iddfs(two_moves, 5)->size(), 3u);
+
+  // One of the hardest boards, 31 moves from solved
+  EightPuzzle hardest{{8, 6, 7, 2, 5, 4, 3, 0, 1}};
+  auto path = bfs(hardest);
+  ASSERT_TRUE(path);
+  EXPECT_EQ(path->size(), 32u);
+  EXPECT_TRUE(is_solution(hardest, *path));
+}
+
+TEST(StateSearch, EightPuzzleParity) {
+  // Two tiles swapped: every one of the 9!/2 reachable boards gets searched
+  EightPuzzle swapped{{2, 1, 3, 4, 5, 6, 7, 8, 0}};
+  EXPECT_FALSE(is_solvable(swapped.start));
+  EXPECT_FALSE(bfs(swapped));
+  EXPECT_FALSE(dfs(swapped));
+
+  std::mt19937 gen(150);
+  for (int trial = 0; trial < 20; ++trial) {
+    EightPuzzle puzzle{scrambled(gen, 12)};
+    ASSERT_TRUE(is_solvable(puzzle.start));
+    auto shortest = bfs(puzzle);
+    ASSERT_TRUE(shortest);
+    ASSERT_LE(shortest->size(), 13u);
+    ASSERT_TRUE(is_solution(puzzle, *shortest));
+    auto deepening = iddfs(puzzle, 12);
+    ASSERT_TRUE(deepening);
+    ASSERT_EQ(deepening->size(), shortest->size());
+    ASSERT_TRUE(is_solution(puzzle, *deepening));
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,42 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <utility>
+#include <vector>
+
+// Two jugs with no markings and capacities a and b, and an endless supply of
+// water. Fill a jug, empty one, or pour one into the other until it's empty
+// or the other is full. Measure exactly `target` in either jug.
+//
+// Solvable if and only if target fits in a jug and is a multiple of
+// gcd(a, b): every amount that can be measured is.
+struct WaterJug {
+  // Water in the first and the second jug
+  using State = std::pair<int, int>;
+
+  int capacity_a;
+  int capacity_b;
+  int target;
+
+  State initial() const { return {0, 0}; }
+
+  bool is_goal(const State& s) const {
+    return s.first == target || s.second == target;
+  }
+
+  std::vector<State> successors(const State& s) const {
+    assert(capacity_a >= 0 && capacity_b >= 0);
+    const auto [a, b] = s;
+    const int into_b = std::min(a, capacity_b - b);
+    const int into_a = std::min(b, capacity_a - a);
+    return {
+        {capacity_a, b},           // Fill a
+        {a, capacity_b},           // Fill b
+        {0, b},                    // Empty a
+        {a, 0},                    // Empty b
+        {a - into_b, b + into_b},  // Pour a into b
+        {a + into_a, b - into_a},  // Pour b into a
+    };
+  }
+};
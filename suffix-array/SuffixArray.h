@@ -0,0 +1,184 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <numeric>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+#include "../kway-merge/KwayMerge.h"
+
+// Suffix arrays: the starting positions of a string's suffixes, in sorted
+// order. Every substring is a prefix of some suffix, and the suffixes that
+// start with a given substring sit next to each other, so sorted suffixes
+// answer most questions about substrings with a binary search or a scan.
+// The LCP array, the length of the common prefix of each pair of
+// neighbors, answers the rest.
+
+// Over symbols rather than chars, so that callers can add separators that
+// can't appear in the text (see longest_common_substring).
+//
+// Prefix doubling: sort the suffixes by their first 2^k symbols for k = 0,
+// 1, 2, ..., each round ranking them by the pair of ranks of their two
+// halves from the round before; suffixes too short for a second half rank
+// it lowest. It stops once every rank is distinct. O(n log^2 n) with
+// std::sort for the rounds.
+inline std::vector<size_t> suffix_array(const std::vector<int>& s) {
+  const size_t n = s.size();
+  std::vector<size_t> sa(n);
+  std::iota(sa.begin(), sa.end(), 0);
+  if (n == 0) {
+    return sa;
+  }
+  std::vector<int64_t> rank(s.begin(), s.end());
+  std::vector<int64_t> next(n);
+  for (size_t half = 1;; half *= 2) {
+    auto key = [&](size_t i) {
+      return std::pair(rank[i], i + half < n ? rank[i + half] : int64_t{-1});
+    };
+    std::sort(sa.begin(), sa.end(),
+              [&](size_t a, size_t b) { return key(a) < key(b); });
+    next[sa[0]] = 0;
+    for (size_t i = 1; i < n; ++i) {
+      next[sa[i]] = next[sa[i - 1]] + (key(sa[i - 1]) < key(sa[i]));
+    }
+    rank.swap(next);
+    if (rank[sa[n - 1]] == static_cast<int64_t>(n) - 1) {
+      return sa;
+    }
+  }
+}
+
+inline std::vector<int> symbols(std::string_view s) {
+  std::vector<int> result;
+  result.reserve(s.size());
+  for (char c : s) {
+    result.push_back(static_cast<unsigned char>(c));
+  }
+  return result;
+}
+
+inline std::vector<size_t> suffix_array(std::string_view s) {
+  return suffix_array(symbols(s));
+}
+
+// O(n^2 log n): compare the suffixes themselves
+inline std::vector<size_t> suffix_array_naive(std::string_view s) {
+  std::vector<size_t> sa(s.size());
+  std::iota(sa.begin(), sa.end(), 0);
+  std::sort(sa.begin(), sa.end(),
+            [&](size_t a, size_t b) { return s.substr(a) < s.substr(b); });
+  return sa;
+}
+
+// lcp[i] is the length of the common prefix of the suffixes at sa[i - 1]
+// and sa[i], and lcp[0] is 0.
+//
+// Kasai's algorithm: go through the suffixes in text order rather than
+// sorted order. Dropping the first symbol of a suffix and of its sorted
+// predecessor gives two suffixes that still share all but one of those
+// symbols, and the suffix sorted just before the shorter one shares at
+// least as many, so each LCP is at least one less than the last. The
+// comparisons only ever extend, O(n) in all.
+inline std::vector<size_t> lcp_array(const std::vector<int>& s,
+                                     const std::vector<size_t>& sa) {
+  const size_t n = s.size();
+  std::vector<size_t> position(n);
+  for (size_t i = 0; i < n; ++i) {
+    position[sa[i]] = i;
+  }
+  std::vector<size_t> lcp(n, 0);
+  size_t common = 0;
+  for (size_t i = 0; i < n; ++i) {
+    if (position[i] == 0) {
+      common = 0;
+      continue;
+    }
+    const size_t j = sa[position[i] - 1];
+    while (std::max(i, j) + common < n && s[i + common] == s[j + common]) {
+      ++common;
+    }
+    lcp[position[i]] = common;
+    if (common > 0) {
+      --common;
+    }
+  }
+  return lcp;
+}
+
+inline std::vector<size_t> lcp_array(std::string_view s,
+                                     const std::vector<size_t>& sa) {
+  return lcp_array(symbols(s), sa);
+}
+
+// Each suffix contributes its prefixes, less the ones it shares with the
+// suffix sorted before it, which were counted there
+inline uint64_t distinct_substrings(std::string_view s) {
+  const auto sa = suffix_array(s);
+  const auto lcp = lcp_array(s, sa);
+  uint64_t count = 0;
+  for (size_t i = 0; i < s.size(); ++i) {
+    count += (s.size() - sa[i]) - lcp[i];
+  }
+  return count;
+}
+
+// The longest string that's a substring of both a and b; of the longest,
+// the one whose suffix in a sorts first. Sort the suffixes of a, a
+// separator, then b: the longest common substring is the longest common
+// prefix of some suffix of a and some suffix of b, and the best such pair
+// is next to each other in sorted order. The separator comes before every
+// character and appears once, so no common prefix runs across it.
+// O(n log^2 n) in the total length.
+inline std::string longest_common_substring(std::string_view a,
+                                            std::string_view b) {
+  std::vector<int> s;
+  s.reserve(a.size() + b.size() + 1);
+  for (char c : a) {
+    s.push_back(static_cast<unsigned char>(c) + 1);
+  }
+  s.push_back(0);
+  for (char c : b) {
+    s.push_back(static_cast<unsigned char>(c) + 1);
+  }
+  const auto sa = suffix_array(s);
+  const auto lcp = lcp_array(s, sa);
+  size_t best = 0;
+  size_t start = 0;
+  for (size_t i = 1; i < s.size(); ++i) {
+    const bool prev_in_a = sa[i - 1] < a.size();
+    const bool in_a = sa[i] < a.size();
+    if (prev_in_a != in_a && lcp[i] > best) {
+      best = lcp[i];
+      start = std::min(sa[i - 1], sa[i]);
+    }
+  }
+  return std::string(a.substr(start, best));
+}
+
+// The suffix array of several texts at once: every suffix of every text,
+// as the text's index and the suffix's start, in sorted order, with equal
+// suffixes in the order of their texts. Each text's own suffix array is
+// sorted already, so a k-way merge of them, comparing suffixes across
+// texts, gives the whole thing. O(N log^2 N) for the arrays, then O(N
+// log k) comparisons of up to the longest text each, for a total length N.
+inline std::vector<std::pair<size_t, size_t>> generalized_suffix_array(
+    const std::vector<std::string>& texts) {
+  using Suffix = std::pair<size_t, size_t>;
+  std::vector<std::vector<Suffix>> arrays;
+  for (size_t t = 0; t < texts.size(); ++t) {
+    std::vector<Suffix> suffixes;
+    for (size_t start : suffix_array(texts[t])) {
+      suffixes.push_back({t, start});
+    }
+    arrays.push_back(std::move(suffixes));
+  }
+  const auto less = [&](const Suffix& a, const Suffix& b) {
+    return std::string_view(texts[a.first]).substr(a.second) <
+           std::string_view(texts[b.first]).substr(b.second);
+  };
+  return merge_sorted(arrays, Duplicates::kKeep, less);
+}
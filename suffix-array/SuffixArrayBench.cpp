@@ -0,0 +1,58 @@
+#include <cstddef>
+#include <string>
+
+#include <benchmark/benchmark.h>
+
+#include "../rng/Rng.h"
+#include "SuffixArray.h"
+
+// Prefix doubling against sorting the suffixes themselves, on random text
+// over four letters and on a run of one letter, where the naive sort's
+// comparisons each go on for the whole length. Takes the length as the
+// argument.
+
+static std::string text(size_t n, bool uniform) {
+  Rng gen(216);
+  std::string s(n, 'a');
+  if (!uniform) {
+    for (auto& c : s) {
+      c = "acgt"[uniform_below(gen, 4)];
+    }
+  }
+  return s;
+}
+
+static void BM_Doubling(benchmark::State& state) {
+  const std::string s = text(static_cast<size_t>(state.range(0)), false);
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(suffix_array(s));
+  }
+}
+
+static void BM_Naive(benchmark::State& state) {
+  const std::string s = text(static_cast<size_t>(state.range(0)), false);
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(suffix_array_naive(s));
+  }
+}
+
+static void BM_DoublingOneLetter(benchmark::State& state) {
+  const std::string s = text(static_cast<size_t>(state.range(0)), true);
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(suffix_array(s));
+  }
+}
+
+static void BM_NaiveOneLetter(benchmark::State& state) {
+  const std::string s = text(static_cast<size_t>(state.range(0)), true);
+  for (auto _ : state) {
+    benchmark::DoNotOptimize(suffix_array_naive(s));
+  }
+}
+
+BENCHMARK(BM_Doubling)->RangeMultiplier(4)->Range(1 << 8, 1 << 16);
+BENCHMARK(BM_Naive)->RangeMultiplier(4)->Range(1 << 8, 1 << 16);
+BENCHMARK(BM_DoublingOneLetter)->RangeMultiplier(4)->Range(1 << 8, 1 << 12);
+BENCHMARK(BM_NaiveOneLetter)->RangeMultiplier(4)->Range(1 << 8, 1 << 12);
+
+BENCHMARK_MAIN();
@@ -0,0 +1,115 @@
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <set>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "SuffixArray.h"
+#include "gtest/gtest.h"
+
+// By listing them all
+uint64_t distinct_substrings_naive(const std::string& s) {
+  std::set<std::string> seen;
+  for (size_t i = 0; i < s.size(); ++i) {
+    for (size_t len = 1; i + len <= s.size(); ++len) {
+      seen.insert(s.substr(i, len));
+    }
+  }
+  return seen.size();
+}
+
+size_t longest_common_substring_naive(const std::string& a,
+                                      const std::string& b) {
+  size_t best = 0;
+  for (size_t i = 0; i < a.size(); ++i) {
+    for (size_t j = 0; j < b.size(); ++j) {
+      size_t len = 0;
+      while (i + len < a.size() && j + len < b.size() &&
+             a[i + len] == b[j + len]) {
+        ++len;
+      }
+      best = std::max(best, len);
+    }
+  }
+  return best;
+}
+
+TEST(SuffixArray, Banana) {
+  // a, ana, anana, banana, na, nana
+  const auto sa = suffix_array("banana");
+  EXPECT_EQ(sa, (std::vector<size_t>{5, 3, 1, 0, 4, 2}));
+  EXPECT_EQ(lcp_array("banana", sa), (std::vector<size_t>{0, 1, 3, 0, 0, 2}));
+  EXPECT_EQ(distinct_substrings("banana"), 15u);
+  EXPECT_TRUE(suffix_array("").empty());
+  EXPECT_EQ(distinct_substrings(""), 0u);
+  EXPECT_EQ(distinct_substrings("aaaa"), 4u);
+
+  EXPECT_EQ(longest_common_substring("xabcdey", "zzbcdezz"), "bcde");
+  EXPECT_EQ(longest_common_substring("abc", "xyz"), "");
+  EXPECT_EQ(longest_common_substring("", "abc"), "");
+}
+
+TEST(SuffixArray, MatchesNaive) {
+  Rng gen(216);
+  for (int trial = 0; trial < 300; ++trial) {
+    const int alphabet = 1 + static_cast<int>(uniform_below(gen, 4));
+    const std::string s = random_text(uniform_below(gen, 60), alphabet, gen);
+    const auto sa = suffix_array(s);
+    ASSERT_EQ(sa, suffix_array_naive(s)) << s;
+
+    const auto lcp = lcp_array(s, sa);
+    for (size_t i = 1; i < s.size(); ++i) {
+      const std::string prev = s.substr(sa[i - 1]);
+      const std::string next = s.substr(sa[i]);
+      size_t common = 0;
+      while (common < next.size() && prev[common] == next[common]) {
+        ++common;
+      }
+      ASSERT_EQ(lcp[i], common);
+    }
+    ASSERT_EQ(distinct_substrings(s), distinct_substrings_naive(s));
+
+    const std::string t = random_text(uniform_below(gen, 60), alphabet, gen);
+    const std::string common = longest_common_substring(s, t);
+    ASSERT_EQ(common.size(), longest_common_substring_naive(s, t));
+    ASSERT_NE(s.find(common), std::string::npos);
+    ASSERT_NE(t.find(common), std::string::npos);
+  }
+}
+
+// Against sorting every suffix of every text, the text breaking ties
+TEST(SuffixArray, Generalized) {
+  using Suffixes = std::vector<std::pair<size_t, size_t>>;
+  EXPECT_EQ(generalized_suffix_array({"ab", "b", ""}),
+            (Suffixes{{0, 0}, {0, 1}, {1, 0}}));
+  EXPECT_TRUE(generalized_suffix_array({}).empty());
+
+  Rng gen(228);
+  for (int trial = 0; trial < 100; ++trial) {
+    std::vector<std::string> texts(uniform_below(gen, 6));
+    for (auto& text : texts) {
+      text = random_text(uniform_below(gen, 20), 2, gen);
+    }
+    Suffixes expected;
+    for (size_t t = 0; t < texts.size(); ++t) {
+      for (size_t start = 0; start < texts[t].size(); ++start) {
+        expected.push_back({t, start});
+      }
+    }
+    std::stable_sort(expected.begin(), expected.end(),
+                     [&](const auto& a, const auto& b) {
+                       return texts[a.first].substr(a.second) <
+                              texts[b.first].substr(b.second);
+                     });
+    ASSERT_EQ(generalized_suffix_array(texts), expected);
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,134 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <map>
+#include <string>
+#include <string_view>
+#include <vector>
+
+// Suffix automaton: the smallest DFA that accepts exactly the suffixes of a
+// string, and, reading from the start state without requiring an accepting
+// state at the end, recognizes exactly its substrings. It has fewer than 2n
+// states and 3n transitions, and is built one character at a time.
+//
+// A state is a class of substrings that end at the same set of positions
+// in the text (their endpos set). Those substrings are suffixes of each
+// other with lengths forming a range (len(link(v)), len(v)], where the
+// suffix link goes to the class of the longest suffix that ends in more
+// places. Appending a character c adds a state for the whole text, then
+// walks the suffix links from the old last state adding transitions on c,
+// until it finds a state that already has one; if that transition skips
+// lengths it's split off as a clone so that each class stays a range.
+class SuffixAutomaton {
+ public:
+  SuffixAutomaton() { states_.push_back({0, -1, {}}); }
+
+  explicit SuffixAutomaton(std::string_view text) : SuffixAutomaton() {
+    for (char c : text) {
+      extend(c);
+    }
+  }
+
+  // Amortized O(log sigma) per character, for the map lookups
+  void extend(char c) {
+    const int current = static_cast<int>(states_.size());
+    states_.push_back({states_[last_].len + 1, -1, {}});
+    int p = last_;
+    while (p != -1 && !states_[p].next.count(c)) {
+      states_[p].next[c] = current;
+      p = states_[p].link;
+    }
+    if (p == -1) {
+      states_[current].link = 0;
+    } else {
+      const int q = states_[p].next[c];
+      if (states_[p].len + 1 == states_[q].len) {
+        states_[current].link = q;
+      } else {
+        // q's class has longer strings that don't end here; split off the
+        // short ones, which now end here too
+        const int clone = static_cast<int>(states_.size());
+        states_.push_back({states_[p].len + 1, states_[q].link,
+                           states_[q].next});
+        while (p != -1 && states_[p].next[c] == q) {
+          states_[p].next[c] = clone;
+          p = states_[p].link;
+        }
+        states_[q].link = clone;
+        states_[current].link = clone;
+      }
+    }
+    last_ = current;
+  }
+
+  size_t states() const { return states_.size(); }
+
+  bool contains(std::string_view pattern) const {
+    int v = 0;
+    for (char c : pattern) {
+      const auto it = states_[v].next.find(c);
+      if (it == states_[v].next.end()) {
+        return false;
+      }
+      v = it->second;
+    }
+    return true;
+  }
+
+  // Each state but the start holds len(v) - len(link(v)) distinct
+  // substrings, and every nonempty substring is in exactly one
+  uint64_t distinct_substrings() const {
+    uint64_t count = 0;
+    for (size_t v = 1; v < states_.size(); ++v) {
+      count += static_cast<uint64_t>(states_[v].len -
+                                     states_[states_[v].link].len);
+    }
+    return count;
+  }
+
+  // The longest substring of the text that's also a substring of `other`;
+  // of the longest, the one that ends first in `other`. Read `other`
+  // through the automaton, keeping the longest suffix of what's been read
+  // that's a substring of the text: on a missing transition, fall back
+  // along suffix links, which shortens the match as little as possible.
+  // O(|other| log sigma).
+  std::string longest_common_substring(std::string_view other) const {
+    int v = 0;
+    int length = 0;
+    int best = 0;
+    size_t best_end = 0;
+    for (size_t i = 0; i < other.size(); ++i) {
+      const char c = other[i];
+      while (v != 0 && !states_[v].next.count(c)) {
+        v = states_[v].link;
+        length = states_[v].len;
+      }
+      const auto it = states_[v].next.find(c);
+      if (it != states_[v].next.end()) {
+        v = it->second;
+        ++length;
+      }
+      if (length > best) {
+        best = length;
+        best_end = i + 1;
+      }
+    }
+    return std::string(other.substr(best_end - best, best));
+  }
+
+ private:
+  struct State {
+    int len;   // the longest substring in the class
+    int link;  // -1 for the start state
+    std::map<char, int> next;
+  };
+
+  std::vector<State> states_;
+  int last_ = 0;  // the state of the whole text
+};
+
+inline std::string longest_common_substring_by_automaton(std::string_view a,
+                                                         std::string_view b) {
+  return SuffixAutomaton(a).longest_common_substring(b);
+}
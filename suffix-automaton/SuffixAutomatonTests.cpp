@@ -0,0 +1,66 @@
+#include <cstddef>
+#include <string>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "../suffix-array/SuffixArray.h"
+#include "SuffixAutomaton.h"
+#include "gtest/gtest.h"
+
+TEST(SuffixAutomaton, Small) {
+  const SuffixAutomaton banana("banana");
+  EXPECT_EQ(banana.distinct_substrings(), 15u);
+  EXPECT_TRUE(banana.contains(""));
+  EXPECT_TRUE(banana.contains("nan"));
+  EXPECT_TRUE(banana.contains("banana"));
+  EXPECT_FALSE(banana.contains("nab"));
+  EXPECT_FALSE(banana.contains("bananas"));
+
+  EXPECT_EQ(SuffixAutomaton("").distinct_substrings(), 0u);
+  EXPECT_EQ(SuffixAutomaton("aaaa").distinct_substrings(), 4u);
+  EXPECT_EQ(longest_common_substring_by_automaton("xabcdey", "zzbcdezz"),
+            "bcde");
+  EXPECT_EQ(longest_common_substring_by_automaton("abc", "xyz"), "");
+}
+
+// Built one character at a time, the automaton answers for every prefix
+TEST(SuffixAutomaton, Online) {
+  const std::string text = "abracadabra";
+  SuffixAutomaton automaton;
+  for (size_t i = 0; i < text.size(); ++i) {
+    automaton.extend(text[i]);
+    const std::string prefix = text.substr(0, i + 1);
+    ASSERT_EQ(automaton.distinct_substrings(), distinct_substrings(prefix));
+    ASSERT_LT(automaton.states(), 2 * prefix.size() + 1);
+  }
+}
+
+TEST(SuffixAutomaton, MatchesSuffixArray) {
+  Rng gen(216);
+  for (int trial = 0; trial < 300; ++trial) {
+    const int alphabet = 1 + static_cast<int>(uniform_below(gen, 4));
+    const std::string a = random_text(uniform_below(gen, 200), alphabet, gen);
+    const std::string b = random_text(uniform_below(gen, 200), alphabet, gen);
+    const SuffixAutomaton automaton(a);
+    ASSERT_EQ(automaton.distinct_substrings(), distinct_substrings(a)) << a;
+
+    const std::string common = automaton.longest_common_substring(b);
+    ASSERT_EQ(common.size(), longest_common_substring(a, b).size());
+    ASSERT_TRUE(automaton.contains(common));
+    ASSERT_NE(b.find(common), std::string::npos);
+
+    // Substrings of a, and strings that likely aren't
+    for (int query = 0; query < 20; ++query) {
+      const size_t start = uniform_below(gen, a.size() + 1);
+      const size_t len = uniform_below(gen, a.size() - start + 1);
+      ASSERT_TRUE(automaton.contains(a.substr(start, len)));
+      const std::string other = random_text(uniform_below(gen, 8), 4, gen);
+      ASSERT_EQ(automaton.contains(other), a.find(other) != std::string::npos);
+    }
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
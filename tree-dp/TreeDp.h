@@ -0,0 +1,162 @@
+#pragma once
+
+#include <algorithm>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <unordered_map>
+#include <utility>
+#include <vector>
+
+#include "../binary-tree/TreeNode.h"
+#include "../graph/Graph.h"
+#include "../graph/Tree.h"
+
+// Dynamic programming on trees: maximum-weight independent set.
+//
+// An independent set has no two adjacent vertices. In a general graph the
+// best one is NP-hard to find, but on a tree every vertex splits the problem
+// into independent subproblems, one per child. Solve the subtrees bottom up,
+// remembering two answers for each vertex v:
+//
+//   take[v] = weight[v] + sum over children c of skip[c]
+//   skip[v] = sum over children c of max(take[c], skip[c])
+//
+// The answer is max(take[root], skip[root]). Recovering the set walks back
+// down: a vertex is in it if its parent isn't and taking it is better.
+
+struct IndependentSet {
+  int64_t weight{0};
+  std::vector<int> vertices;  // Ascending
+};
+
+inline IndependentSet max_weight_independent_set(
+    const AdjacencyList& tree, const std::vector<int64_t>& weight) {
+  assert(tree.size() == weight.size() && "one weight per vertex");
+  IndependentSet result;
+  if (tree.empty()) {
+    return result;
+  }
+
+  RootedTree rooted(tree, 0);
+  const size_t n = tree.size();
+  std::vector<int64_t> take(weight);
+  std::vector<int64_t> skip(n, 0);
+  // Reverse BFS order visits children before parents
+  for (size_t i = n; i-- > 1;) {
+    const int v = rooted.order[i];
+    const int p = rooted.parent[v];
+    take[p] += skip[v];
+    skip[p] += std::max(take[v], skip[v]);
+  }
+
+  std::vector<bool> chosen(n, false);
+  for (int v : rooted.order) {
+    const int p = rooted.parent[v];
+    chosen[v] = (p == -1 || !chosen[p]) && take[v] > skip[v];
+  }
+  const int root = rooted.root;
+  result.weight = std::max(take[root], skip[root]);
+  for (size_t v = 0; v < n; ++v) {
+    if (chosen[v]) {
+      result.vertices.push_back(static_cast<int>(v));
+    }
+  }
+  return result;
+}
+
+// O(2^n * n): every subset, so only for small trees
+inline int64_t max_weight_independent_set_naive(
+    const AdjacencyList& tree, const std::vector<int64_t>& weight) {
+  const size_t n = tree.size();
+  int64_t best = 0;
+  for (uint32_t mask = 0; mask < (uint32_t{1} << n); ++mask) {
+    int64_t total = 0;
+    bool independent = true;
+    for (size_t u = 0; u < n && independent; ++u) {
+      if (!(mask >> u & 1)) {
+        continue;
+      }
+      total += weight[u];
+      for (int v : tree[u]) {
+        independent = independent && !(mask >> v & 1);
+      }
+    }
+    if (independent) {
+      best = std::max(best, total);
+    }
+  }
+  return best;
+}
+
+// House Robber III: the nodes of a binary tree are houses holding val each,
+// and robbing a node and its parent sets off the alarm. The same recurrence,
+// with the tree given by TreeNode pointers.
+
+template <typename T>
+struct Robbery {
+  T value{};
+  std::vector<const TreeNode<T>*> nodes;  // In preorder
+};
+
+// The best total with the node robbed and with it left alone
+template <typename T>
+std::pair<T, T> rob_subtree(
+    const TreeNode<T>* node,
+    std::unordered_map<const TreeNode<T>*, std::pair<T, T>>& memo) {
+  if (node == nullptr) {
+    return {T{}, T{}};
+  }
+  auto [left_take, left_skip] = rob_subtree(node->left.get(), memo);
+  auto [right_take, right_skip] = rob_subtree(node->right.get(), memo);
+  std::pair<T, T> result = {
+      node->val + left_skip + right_skip,
+      std::max(left_take, left_skip) + std::max(right_take, right_skip)};
+  memo[node] = result;
+  return result;
+}
+
+template <typename T>
+void collect_robbed(
+    const TreeNode<T>* node, bool parent_robbed,
+    const std::unordered_map<const TreeNode<T>*, std::pair<T, T>>& memo,
+    std::vector<const TreeNode<T>*>& out) {
+  if (node == nullptr) {
+    return;
+  }
+  const auto& [take, skip] = memo.at(node);
+  const bool robbed = !parent_robbed && take > skip;
+  if (robbed) {
+    out.push_back(node);
+  }
+  collect_robbed(node->left.get(), robbed, memo, out);
+  collect_robbed(node->right.get(), robbed, memo, out);
+}
+
+template <typename T>
+Robbery<T> rob(const TreeNode<T>* root) {
+  std::unordered_map<const TreeNode<T>*, std::pair<T, T>> memo;
+  auto [take, skip] = rob_subtree(root, memo);
+  Robbery<T> result;
+  result.value = std::max(take, skip);
+  collect_robbed(root, false, memo, result.nodes);
+  return result;
+}
+
+// The recurrence without memoization: rob the root and go on from the
+// grandchildren, or skip it and go on from the children. Grandchildren get
+// solved over and over, so this is exponential on deep trees.
+template <typename T>
+T rob_naive(const TreeNode<T>* node) {
+  if (node == nullptr) {
+    return T{};
+  }
+  T with_root = node->val;
+  for (const auto* child : {node->left.get(), node->right.get()}) {
+    if (child != nullptr) {
+      with_root += rob_naive(child->left.get()) + rob_naive(child->right.get());
+    }
+  }
+  T without_root = rob_naive(node->left.get()) + rob_naive(node->right.get());
+  return std::max(with_root, without_root);
+}
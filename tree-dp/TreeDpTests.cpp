@@ -0,0 +1,156 @@
+#include <algorithm>
+#include <cstdint>
+#include <vector>
+
+#include "../generators/Generators.h"
+#include "../rng/Rng.h"
+#include "TreeDp.h"
+#include "gtest/gtest.h"
+
+bool is_independent(const AdjacencyList& tree, const std::vector<int>& set) {
+  std::vector<bool> in(tree.size(), false);
+  for (int v : set) {
+    in[v] = true;
+  }
+  for (int u : set) {
+    for (int v : tree[u]) {
+      if (in[v]) {
+        return false;
+      }
+    }
+  }
+  return true;
+}
+
+int64_t total_weight(const std::vector<int64_t>& weight,
+                     const std::vector<int>& set) {
+  int64_t total = 0;
+  for (int v : set) {
+    total += weight[v];
+  }
+  return total;
+}
+
+TEST(IndependentSet, Path) {
+  // 1 - 5 - 1 - 1 - 5: take both fives
+  auto path = undirected_from_edges(5, {{0, 1}, {1, 2}, {2, 3}, {3, 4}});
+  auto result = max_weight_independent_set(path, {1, 5, 1, 1, 5});
+
+  EXPECT_EQ(result.weight, 10);
+  EXPECT_EQ(result.vertices, (std::vector<int>{1, 4}));
+}
+
+TEST(IndependentSet, StarPrefersLeavesOrCenter) {
+  auto star = undirected_from_edges(4, {{0, 1}, {0, 2}, {0, 3}});
+
+  EXPECT_EQ(max_weight_independent_set(star, {10, 3, 3, 3}).vertices,
+            std::vector<int>{0});
+  EXPECT_EQ(max_weight_independent_set(star, {8, 3, 3, 3}).vertices,
+            (std::vector<int>{1, 2, 3}));
+}
+
+TEST(IndependentSet, NegativeWeightsAreLeftOut) {
+  auto path = undirected_from_edges(3, {{0, 1}, {1, 2}});
+  auto result = max_weight_independent_set(path, {-1, -2, 4});
+
+  EXPECT_EQ(result.weight, 4);
+  EXPECT_EQ(result.vertices, std::vector<int>{2});
+}
+
+TEST(IndependentSet, TinyTrees) {
+  EXPECT_EQ(max_weight_independent_set({}, {}).weight, 0);
+  EXPECT_EQ(max_weight_independent_set(AdjacencyList(1), {7}).vertices,
+            std::vector<int>{0});
+}
+
+TEST(IndependentSet, MatchesBruteForce) {
+  Rng gen(134);
+  for (int trial = 0; trial < 300; ++trial) {
+    const int n = uniform_int(gen, 1, 14);
+    auto tree = random_labeled_tree(n, gen);
+    std::vector<int64_t> weight(n);
+    for (auto& w : weight) {
+      w = uniform_int<int64_t>(gen, -5, 20);
+    }
+    auto result = max_weight_independent_set(tree, weight);
+
+    ASSERT_EQ(result.weight, max_weight_independent_set_naive(tree, weight));
+    ASSERT_TRUE(is_independent(tree, result.vertices));
+    ASSERT_EQ(total_weight(weight, result.vertices), result.weight);
+  }
+}
+
+TEST(IndependentSet, DeepPath) {
+  const int n = 100000;
+  std::vector<Edge> edges;
+  for (int i = 1; i < n; ++i) {
+    edges.emplace_back(i - 1, i);
+  }
+  auto result = max_weight_independent_set(undirected_from_edges(n, edges),
+                                           std::vector<int64_t>(n, 1));
+
+  EXPECT_EQ(result.weight, n / 2);
+}
+
+/*
+ * The LeetCode example, worth 3 + 3 + 1 = 7:
+ *
+ *     3
+ *    / \
+ *   2   3
+ *    \   \
+ *     3   1
+ */
+TEST(Rob, Example) {
+  auto tree = make_node(3, make_node(2, nullptr, make_node(3)),
+                        make_node(3, nullptr, make_node(1)));
+  auto result = rob(tree.get());
+
+  EXPECT_EQ(result.value, 7);
+  ASSERT_EQ(result.nodes.size(), 3u);
+  EXPECT_EQ(result.nodes[0], tree.get());
+  EXPECT_EQ(result.nodes[1], tree->left->right.get());
+  EXPECT_EQ(result.nodes[2], tree->right->right.get());
+}
+
+TEST(Rob, SkipsTheRootWhenItsChildrenAreWorthMore) {
+  auto tree = make_node(3, make_node(4, make_node(1), make_node(2)),
+                        make_node(5, nullptr, make_node(1)));
+  auto result = rob(tree.get());
+
+  EXPECT_EQ(result.value, 9);
+  ASSERT_EQ(result.nodes.size(), 2u);
+  EXPECT_EQ(result.nodes[0], tree->left.get());
+  EXPECT_EQ(result.nodes[1], tree->right.get());
+}
+
+TEST(Rob, EmptyTree) {
+  auto result = rob<int>(nullptr);
+
+  EXPECT_EQ(result.value, 0);
+  EXPECT_TRUE(result.nodes.empty());
+}
+
+TEST(Rob, MatchesNaiveRecursion) {
+  Rng gen(135);
+  for (int trial = 0; trial < 300; ++trial) {
+    auto tree = random_binary_tree(uniform_int(gen, 0, 25), gen);
+    auto result = rob(tree.get());
+
+    ASSERT_EQ(result.value, rob_naive(tree.get()));
+    int total = 0;
+    for (const auto* node : result.nodes) {
+      total += node->val;
+      for (const auto* child : {node->left.get(), node->right.get()}) {
+        ASSERT_EQ(std::count(result.nodes.begin(), result.nodes.end(), child),
+                  0);
+      }
+    }
+    ASSERT_EQ(total, result.value);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
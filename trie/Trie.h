@@ -28,6 +28,11 @@ private:
 public:
     Trie() : root(std::make_shared<TrieNode>()) {}
 
+    // For walking the trie a letter at a time
+    const TrieNode* rootNode() const {
+        return root.get();
+    }
+
     void insert(const std::string& word) {
         auto node = root;
         for (const auto& ch : word) {
@@ -0,0 +1,146 @@
+#include <algorithm>
+#include <cmath>
+#include <numbers>
+#include <random>
+#include <vector>
+
+#include "Tsp.h"
+#include "gtest/gtest.h"
+
+std::vector<Point> random_points(std::mt19937& gen, size_t n) {
+  std::uniform_real_distribution<double> coordinate(0.0, 100.0);
+  std::vector<Point> points(n);
+  for (auto& p : points) {
+    p = {coordinate(gen), coordinate(gen)};
+  }
+  return points;
+}
+
+bool is_permutation_from_zero(const std::vector<int>& order, size_t n) {
+  if (order.size() != n || (n > 0 && order[0] != 0)) {
+    return false;
+  }
+  std::vector<int> sorted = order;
+  std::sort(sorted.begin(), sorted.end());
+  for (size_t i = 0; i < n; ++i) {
+    if (sorted[i] != static_cast<int>(i)) {
+      return false;
+    }
+  }
+  return true;
+}
+
+TEST(Tsp, HeldKarpExample) {
+  // The classic four-city instance with an optimal tour of 80
+  DistanceMatrix dist = {
+      {0, 10, 15, 20},
+      {10, 0, 35, 25},
+      {15, 35, 0, 30},
+      {20, 25, 30, 0},
+  };
+  auto tour = held_karp(dist);
+
+  EXPECT_DOUBLE_EQ(tour.cost, 80);
+  EXPECT_DOUBLE_EQ(tour_cost(dist, tour.order), 80);
+  EXPECT_TRUE(is_permutation_from_zero(tour.order, 4));
+}
+
+TEST(Tsp, PointsOnACircle) {
+  // The optimal tour goes around the circle, and 2-opt untangles any other
+  const int n = 12;
+  std::vector<Point> points;
+  std::vector<int> shuffled = {0, 7, 2, 9, 4, 11, 6, 1, 8, 3, 10, 5};
+  for (int i : shuffled) {
+    double angle = 2 * std::numbers::pi * i / n;
+    points.push_back({std::cos(angle), std::sin(angle)});
+  }
+  auto dist = euclidean_distances(points);
+  double perimeter = n * 2 * std::sin(std::numbers::pi / n);
+
+  EXPECT_NEAR(held_karp(dist).cost, perimeter, 1e-9);
+  EXPECT_NEAR(nearest_neighbor_two_opt(dist).cost, perimeter, 1e-9);
+}
+
+TEST(Tsp, TinyInstances) {
+  EXPECT_EQ(held_karp({}).cost, 0.0);
+  EXPECT_TRUE(held_karp({}).order.empty());
+  EXPECT_EQ(held_karp({{0}}).order, std::vector<int>{0});
+  EXPECT_EQ(held_karp({{0}}).cost, 0.0);
+
+  DistanceMatrix two = {{0, 3}, {4, 0}};
+  EXPECT_DOUBLE_EQ(held_karp(two).cost, 7);
+  EXPECT_EQ(held_karp(two).order, (std::vector<int>{0, 1}));
+  EXPECT_DOUBLE_EQ(nearest_neighbor_two_opt(two).cost, 7);
+}
+
+TEST(Tsp, AsymmetricDistances) {
+  // Going around one way is much cheaper than the other
+  DistanceMatrix dist = {
+      {0, 1, 9, 9},
+      {9, 0, 1, 9},
+      {9, 9, 0, 1},
+      {1, 9, 9, 0},
+  };
+  auto tour = held_karp(dist);
+
+  EXPECT_DOUBLE_EQ(tour.cost, 4);
+  EXPECT_EQ(tour.order, (std::vector<int>{0, 1, 2, 3}));
+}
+
+TEST(Tsp, HeldKarpMatchesBruteForce) {
+  std::mt19937 gen(136);
+  std::uniform_int_distribution<int> weight(1, 50);
+  for (int trial = 0; trial < 100; ++trial) {
+    size_t n = std::uniform_int_distribution<size_t>(1, 8)(gen);
+    // Arbitrary, asymmetric integer weights
+    DistanceMatrix dist(n, std::vector<double>(n, 0));
+    for (size_t i = 0; i < n; ++i) {
+      for (size_t j = 0; j < n; ++j) {
+        if (i != j) {
+          dist[i][j] = weight(gen);
+        }
+      }
+    }
+    auto tour = held_karp(dist);
+
+    ASSERT_DOUBLE_EQ(tour.cost, tsp_cost_naive(dist));
+    ASSERT_TRUE(is_permutation_from_zero(tour.order, n));
+    ASSERT_DOUBLE_EQ(tour_cost(dist, tour.order), tour.cost);
+  }
+}
+
+TEST(Tsp, HeuristicIsCloseToOptimal) {
+  std::mt19937 gen(137);
+  double total_ratio = 0.0;
+  const int trials = 50;
+  for (int trial = 0; trial < trials; ++trial) {
+    auto dist = euclidean_distances(random_points(gen, 12));
+    auto exact = held_karp(dist);
+    auto heuristic = nearest_neighbor_two_opt(dist);
+
+    ASSERT_TRUE(is_permutation_from_zero(heuristic.order, 12));
+    ASSERT_NEAR(tour_cost(dist, heuristic.order), heuristic.cost, 1e-9);
+    ASSERT_GE(heuristic.cost, exact.cost - 1e-9);
+    ASSERT_LE(heuristic.cost, 1.3 * exact.cost);
+    total_ratio += heuristic.cost / exact.cost;
+  }
+  EXPECT_LT(total_ratio / trials, 1.05);
+}
+
+TEST(Tsp, TwoOptImprovesNearestNeighbor) {
+  std::mt19937 gen(138);
+  for (int trial = 0; trial < 20; ++trial) {
+    auto dist = euclidean_distances(random_points(gen, 200));
+    auto order = nearest_neighbor_tour(dist);
+    double greedy = tour_cost(dist, order);
+    two_opt(dist, order);
+
+    ASSERT_TRUE(is_permutation_from_zero(order, 200));
+    ASSERT_LE(tour_cost(dist, order), greedy);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
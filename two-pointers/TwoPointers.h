@@ -0,0 +1,155 @@
+#pragma once
+
+#include <algorithm>
+#include <array>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <set>
+#include <utility>
+#include <vector>
+
+// Two pointers: walk two indices through an array, each only ever moving in
+// one direction, so the whole scan is O(n). What makes it correct is an
+// argument that every pair a pointer skips over can never be the answer.
+
+// Indices i < j with sorted[i] + sorted[j] == target. Start at both ends: if
+// the sum is too small, sorted[i] is too small even with the largest partner
+// left, so i can't be in any answer and moves right; if too big, j moves
+// left. O(n).
+template <typename T>
+std::optional<std::pair<size_t, size_t>> pair_sum(const std::vector<T>& sorted,
+                                                  const T& target) {
+  assert(std::is_sorted(sorted.begin(), sorted.end()));
+  if (sorted.size() < 2) {
+    return std::nullopt;
+  }
+  size_t i = 0;
+  size_t j = sorted.size() - 1;
+  while (i < j) {
+    const T sum = sorted[i] + sorted[j];
+    if (sum == target) {
+      return std::pair{i, j};
+    }
+    if (sum < target) {
+      ++i;
+    } else {
+      --j;
+    }
+  }
+  return std::nullopt;
+}
+
+// Every distinct triple of values from different positions that sums to
+// target, each in increasing order, the list sorted. Sort, fix the smallest
+// value, and find the other two with pair_sum's scan on the rest, skipping
+// repeated values so that no triple is reported twice. O(n^2).
+template <typename T>
+std::vector<std::array<T, 3>> three_sum(std::vector<T> nums, const T& target) {
+  std::sort(nums.begin(), nums.end());
+  std::vector<std::array<T, 3>> triples;
+  const size_t n = nums.size();
+  for (size_t a = 0; a + 2 < n; ++a) {
+    if (a > 0 && nums[a] == nums[a - 1]) {
+      continue;
+    }
+    size_t i = a + 1;
+    size_t j = n - 1;
+    while (i < j) {
+      const T sum = nums[a] + nums[i] + nums[j];
+      if (sum < target) {
+        ++i;
+      } else if (target < sum) {
+        --j;
+      } else {
+        triples.push_back({nums[a], nums[i], nums[j]});
+        // Past every copy of both values, to the next distinct pair
+        ++i;
+        --j;
+        while (i < j && nums[i] == nums[i - 1]) {
+          ++i;
+        }
+        while (i < j && nums[j] == nums[j + 1]) {
+          --j;
+        }
+      }
+    }
+  }
+  return triples;
+}
+
+// O(n^3)
+template <typename T>
+std::vector<std::array<T, 3>> three_sum_naive(const std::vector<T>& nums,
+                                              const T& target) {
+  std::set<std::array<T, 3>> triples;
+  for (size_t a = 0; a < nums.size(); ++a) {
+    for (size_t b = a + 1; b < nums.size(); ++b) {
+      for (size_t c = b + 1; c < nums.size(); ++c) {
+        if (nums[a] + nums[b] + nums[c] == target) {
+          std::array<T, 3> triple = {nums[a], nums[b], nums[c]};
+          std::sort(triple.begin(), triple.end());
+          triples.insert(triple);
+        }
+      }
+    }
+  }
+  return {triples.begin(), triples.end()};
+}
+
+// Container with most water: lines of the given heights stand at positions
+// 0, 1, ...; which two hold the most water between them? The area is the
+// distance times the shorter line. Start with the widest pair. The shorter
+// of the two can't do better with any nearer partner, since the width only
+// shrinks and the height is capped by it, so drop it and move inwards. O(n).
+inline int64_t max_water(const std::vector<int64_t>& heights) {
+  if (heights.size() < 2) {
+    return 0;
+  }
+  int64_t best = 0;
+  size_t i = 0;
+  size_t j = heights.size() - 1;
+  while (i < j) {
+    const int64_t width = static_cast<int64_t>(j - i);
+    best = std::max(best, width * std::min(heights[i], heights[j]));
+    if (heights[i] < heights[j]) {
+      ++i;
+    } else {
+      --j;
+    }
+  }
+  return best;
+}
+
+// O(n^2)
+inline int64_t max_water_naive(const std::vector<int64_t>& heights) {
+  int64_t best = 0;
+  for (size_t i = 0; i < heights.size(); ++i) {
+    for (size_t j = i + 1; j < heights.size(); ++j) {
+      best = std::max(best, static_cast<int64_t>(j - i) *
+                                std::min(heights[i], heights[j]));
+    }
+  }
+  return best;
+}
+
+// Removes repeated values from a sorted vector in place, keeping the first
+// of each run, and returns the new size. A slow pointer marks the end of the
+// deduplicated prefix, and a fast one scans for the next new value, which
+// only ever moves forward into the prefix. O(n), like std::unique.
+template <typename T>
+size_t remove_duplicates(std::vector<T>& sorted) {
+  assert(std::is_sorted(sorted.begin(), sorted.end()));
+  size_t kept = 0;
+  for (size_t fast = 0; fast < sorted.size(); ++fast) {
+    if (kept == 0 || !(sorted[fast] == sorted[kept - 1])) {
+      if (kept != fast) {
+        sorted[kept] = std::move(sorted[fast]);
+      }
+      ++kept;
+    }
+  }
+  sorted.resize(kept);
+  return kept;
+}
@@ -0,0 +1,108 @@
+#include <algorithm>
+#include <array>
+#include <cstdint>
+#include <random>
+#include <string>
+#include <vector>
+
+#include "TwoPointers.h"
+#include "gtest/gtest.h"
+
+std::vector<int> random_values(std::mt19937& gen, size_t n, int lo, int hi) {
+  std::uniform_int_distribution<int> value(lo, hi);
+  std::vector<int> values(n);
+  for (auto& v : values) {
+    v = value(gen);
+  }
+  return values;
+}
+
+TEST(TwoPointers, PairSum) {
+  std::vector<int> sorted = {1, 2, 4, 7, 11, 15};
+  auto found = pair_sum(sorted, 15);
+  ASSERT_TRUE(found);
+  EXPECT_EQ(*found, (std::pair<size_t, size_t>{2, 4}));
+  EXPECT_FALSE(pair_sum(sorted, 100));
+  EXPECT_FALSE(pair_sum(std::vector<int>{5}, 10));  // Needs two positions
+  EXPECT_TRUE(pair_sum(std::vector<int>{5, 5}, 10));
+}
+
+TEST(TwoPointers, PairSumMatchesBruteForce) {
+  std::mt19937 gen(156);
+  for (int trial = 0; trial < 500; ++trial) {
+    auto values = random_values(gen, gen() % 15, -10, 10);
+    std::sort(values.begin(), values.end());
+    const int target = std::uniform_int_distribution<int>(-20, 20)(gen);
+
+    bool exists = false;
+    for (size_t i = 0; i < values.size(); ++i) {
+      for (size_t j = i + 1; j < values.size(); ++j) {
+        exists = exists || values[i] + values[j] == target;
+      }
+    }
+    auto found = pair_sum(values, target);
+    ASSERT_EQ(found.has_value(), exists);
+    if (found) {
+      ASSERT_LT(found->first, found->second);
+      ASSERT_EQ(values[found->first] + values[found->second], target);
+    }
+  }
+}
+
+TEST(TwoPointers, ThreeSum) {
+  EXPECT_EQ(three_sum(std::vector<int>{-1, 0, 1, 2, -1, -4}, 0),
+            (std::vector<std::array<int, 3>>{{-1, -1, 2}, {-1, 0, 1}}));
+  EXPECT_EQ(three_sum(std::vector<int>{0, 0, 0, 0}, 0),
+            (std::vector<std::array<int, 3>>{{0, 0, 0}}));
+  EXPECT_TRUE(three_sum(std::vector<int>{1, 2}, 3).empty());
+
+  std::mt19937 gen(157);
+  for (int trial = 0; trial < 300; ++trial) {
+    auto values = random_values(gen, gen() % 20, -8, 8);
+    const int target = std::uniform_int_distribution<int>(-5, 5)(gen);
+    ASSERT_EQ(three_sum(values, target), three_sum_naive(values, target));
+  }
+}
+
+TEST(TwoPointers, MaxWater) {
+  EXPECT_EQ(max_water({1, 8, 6, 2, 5, 4, 8, 3, 7}), 49);
+  EXPECT_EQ(max_water({1, 1}), 1);
+  EXPECT_EQ(max_water({5}), 0);
+
+  std::mt19937 gen(158);
+  std::uniform_int_distribution<int64_t> height(0, 30);
+  for (int trial = 0; trial < 300; ++trial) {
+    std::vector<int64_t> heights(gen() % 30);
+    for (auto& h : heights) {
+      h = height(gen);
+    }
+    ASSERT_EQ(max_water(heights), max_water_naive(heights));
+  }
+}
+
+TEST(TwoPointers, RemoveDuplicates) {
+  std::vector<int> values = {0, 0, 1, 1, 1, 2, 2, 3, 3, 4};
+  EXPECT_EQ(remove_duplicates(values), 5u);
+  EXPECT_EQ(values, (std::vector<int>{0, 1, 2, 3, 4}));
+
+  std::vector<std::string> words = {"a", "a", "b"};
+  EXPECT_EQ(remove_duplicates(words), 2u);
+  EXPECT_EQ(words, (std::vector<std::string>{"a", "b"}));
+
+  std::mt19937 gen(159);
+  for (int trial = 0; trial < 300; ++trial) {
+    auto values = random_values(gen, gen() % 30, 0, 10);
+    std::sort(values.begin(), values.end());
+    auto expected = values;
+    expected.erase(std::unique(expected.begin(), expected.end()),
+                   expected.end());
+
+    ASSERT_EQ(remove_duplicates(values), expected.size());
+    ASSERT_EQ(values, expected);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
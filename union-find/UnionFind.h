@@ -0,0 +1,126 @@
+#pragma once
+
+#include <cassert>
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+// Disjoint sets over elements 0..n-1: each set is a tree of parent links,
+// identified by its root. Union by size keeps the trees O(log n) deep, and
+// path halving flattens them as find() walks up, so any sequence of m
+// operations takes O(m α(n)), where the inverse Ackermann function α(n) is
+// at most 4 for any n that fits in memory.
+class UnionFind {
+ public:
+  explicit UnionFind(size_t n) : parent_(n), size_(n, 1), components_(n) {
+    for (size_t i = 0; i < n; ++i) {
+      parent_[i] = i;
+    }
+  }
+
+  // The root of x's set: the same for all elements of a set
+  size_t find(size_t x) {
+    assert(x < parent_.size());
+    while (parent_[x] != x) {
+      parent_[x] = parent_[parent_[x]];  // Skip to the grandparent
+      x = parent_[x];
+    }
+    return x;
+  }
+
+  // Merges the sets of a and b. False if they were already the same set.
+  bool unite(size_t a, size_t b) {
+    a = find(a);
+    b = find(b);
+    if (a == b) {
+      return false;
+    }
+    if (size_[a] < size_[b]) {
+      std::swap(a, b);
+    }
+    parent_[b] = a;  // The smaller tree goes under the bigger one
+    size_[a] += size_[b];
+    --components_;
+    return true;
+  }
+
+  bool connected(size_t a, size_t b) { return find(a) == find(b); }
+
+  // Number of elements in x's set
+  size_t size_of(size_t x) { return size_[find(x)]; }
+
+  // Number of disjoint sets
+  size_t components() const { return components_; }
+
+  size_t size() const { return parent_.size(); }
+
+ private:
+  std::vector<size_t> parent_;
+  std::vector<size_t> size_;  // Meaningful for roots only
+  size_t components_;
+};
+
+// Union-find that can undo: rollback(snapshot()) puts the sets back the
+// way they were when the snapshot was taken, undoing unions newest first.
+// Undoing rules out path compression, which rewrites links all over the
+// tree, so find() is O(log n) from union by size alone; each union pushes
+// the root it hung under the other, and undoing it just unhooks that root.
+class RollbackUnionFind {
+ public:
+  explicit RollbackUnionFind(size_t n)
+      : parent_(n), size_(n, 1), components_(n) {
+    for (size_t i = 0; i < n; ++i) {
+      parent_[i] = i;
+    }
+  }
+
+  size_t find(size_t x) const {
+    assert(x < parent_.size());
+    while (parent_[x] != x) {
+      x = parent_[x];
+    }
+    return x;
+  }
+
+  bool unite(size_t a, size_t b) {
+    a = find(a);
+    b = find(b);
+    if (a == b) {
+      return false;
+    }
+    if (size_[a] < size_[b]) {
+      std::swap(a, b);
+    }
+    parent_[b] = a;
+    size_[a] += size_[b];
+    --components_;
+    history_.push_back(b);
+    return true;
+  }
+
+  bool connected(size_t a, size_t b) const { return find(a) == find(b); }
+
+  size_t components() const { return components_; }
+
+  size_t size() const { return parent_.size(); }
+
+  // How many unions there are to undo
+  size_t snapshot() const { return history_.size(); }
+
+  void rollback(size_t snapshot) {
+    assert(snapshot <= history_.size() && "snapshot from the future");
+    while (history_.size() > snapshot) {
+      const size_t b = history_.back();
+      history_.pop_back();
+      size_[parent_[b]] -= size_[b];
+      parent_[b] = b;
+      ++components_;
+    }
+  }
+
+ private:
+  std::vector<size_t> parent_;
+  std::vector<size_t> size_;
+  size_t components_;
+  std::vector<size_t> history_;  // Roots hung under another, oldest first
+};
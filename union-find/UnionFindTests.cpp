@@ -0,0 +1,104 @@
+#include <cstddef>
+#include <cstdint>
+#include <random>
+#include <utility>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "UnionFind.h"
+#include "gtest/gtest.h"
+
+TEST(UnionFind, Basics) {
+  UnionFind sets(6);
+  EXPECT_EQ(sets.components(), 6u);
+  EXPECT_FALSE(sets.connected(0, 1));
+
+  EXPECT_TRUE(sets.unite(0, 1));
+  EXPECT_TRUE(sets.unite(2, 3));
+  EXPECT_TRUE(sets.unite(1, 3));
+  EXPECT_FALSE(sets.unite(0, 2));  // Already together
+
+  EXPECT_TRUE(sets.connected(0, 3));
+  EXPECT_FALSE(sets.connected(0, 4));
+  EXPECT_EQ(sets.size_of(2), 4u);
+  EXPECT_EQ(sets.size_of(5), 1u);
+  EXPECT_EQ(sets.components(), 3u);
+}
+
+TEST(UnionFind, MatchesLabels) {
+  // Reference: every element carries a set label, relabeled on each merge
+  std::mt19937 gen(154);
+  const size_t n = 200;
+  UnionFind sets(n);
+  std::vector<size_t> label(n);
+  for (size_t i = 0; i < n; ++i) {
+    label[i] = i;
+  }
+  size_t components = n;
+  std::uniform_int_distribution<size_t> element(0, n - 1);
+  for (int step = 0; step < 1000; ++step) {
+    const size_t a = element(gen);
+    const size_t b = element(gen);
+    const bool separate = label[a] != label[b];
+    ASSERT_EQ(sets.connected(a, b), !separate);
+    ASSERT_EQ(sets.unite(a, b), separate);
+    if (separate) {
+      const size_t old = label[b];
+      for (auto& l : label) {
+        if (l == old) {
+          l = label[a];
+        }
+      }
+      --components;
+    }
+    ASSERT_EQ(sets.components(), components);
+
+    size_t same = 0;
+    for (size_t i = 0; i < n; ++i) {
+      same += label[i] == label[a];
+    }
+    ASSERT_EQ(sets.size_of(a), same);
+  }
+}
+
+// A stack of label snapshots, pushed and popped along with the union-find's
+TEST(UnionFind, Rollback) {
+  Rng gen(191);
+  const size_t n = 50;
+  RollbackUnionFind sets(n);
+  std::vector<size_t> label(n);
+  for (size_t i = 0; i < n; ++i) {
+    label[i] = i;
+  }
+  std::vector<std::pair<size_t, std::vector<size_t>>> saved;
+  for (int step = 0; step < 2000; ++step) {
+    const uint64_t choice = uniform_below(gen, 4);
+    if (choice == 0) {
+      saved.push_back({sets.snapshot(), label});
+    } else if (choice == 1 && !saved.empty()) {
+      sets.rollback(saved.back().first);
+      label = saved.back().second;
+      saved.pop_back();
+    } else {
+      const size_t a = uniform_below(gen, n);
+      const size_t b = uniform_below(gen, n);
+      ASSERT_EQ(sets.unite(a, b), label[a] != label[b]);
+      const size_t old = label[b];
+      for (auto& l : label) {
+        l = l == old ? label[a] : l;
+      }
+    }
+    size_t components = 0;
+    for (size_t i = 0; i < n; ++i) {
+      components += label[i] == i;
+      ASSERT_EQ(sets.connected(i, label[i]), true);
+      ASSERT_EQ(sets.find(i), sets.find(label[i]));
+    }
+    ASSERT_EQ(sets.components(), components);
+  }
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
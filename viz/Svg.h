@@ -0,0 +1,115 @@
+#pragma once
+
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Just enough SVG to draw the etudes: shapes are appended in order, so
+// later ones are drawn on top, and y grows downwards as in the format.
+
+inline std::string escape_xml(const std::string& text) {
+  std::string out;
+  for (char c : text) {
+    switch (c) {
+      case '&': out += "&amp;"; break;
+      case '<': out += "&lt;"; break;
+      case '>': out += "&gt;"; break;
+      case '"': out += "&quot;"; break;
+      default: out += c;
+    }
+  }
+  return out;
+}
+
+class Svg {
+ public:
+  Svg(double width, double height) : width_(width), height_(height) {}
+
+  void line(double x1, double y1, double x2, double y2,
+            const std::string& stroke = "black", double stroke_width = 1) {
+    std::ostringstream out;
+    out << "<line x1=\"" << x1 << "\" y1=\"" << y1 << "\" x2=\"" << x2
+            << "\" y2=\"" << y2 << "\" stroke=\"" << stroke
+            << "\" stroke-width=\"" << stroke_width << "\"/>";
+    shapes_.push_back(out.str());
+  }
+
+  void rect(double x, double y, double width, double height,
+            const std::string& fill, const std::string& stroke = "none") {
+    std::ostringstream out;
+    out << "<rect x=\"" << x << "\" y=\"" << y << "\" width=\"" << width
+            << "\" height=\"" << height << "\" fill=\"" << fill
+            << "\" stroke=\"" << stroke << "\"/>";
+    shapes_.push_back(out.str());
+  }
+
+  void circle(double cx, double cy, double r, const std::string& fill,
+              const std::string& stroke = "black") {
+    std::ostringstream out;
+    out << "<circle cx=\"" << cx << "\" cy=\"" << cy << "\" r=\"" << r
+            << "\" fill=\"" << fill << "\" stroke=\"" << stroke << "\"/>";
+    shapes_.push_back(out.str());
+  }
+
+  // An open path through the points, or a closed one if `closed`
+  void polyline(const std::vector<std::pair<double, double>>& points,
+                const std::string& stroke, const std::string& fill = "none",
+                bool closed = false, double stroke_width = 1) {
+    std::ostringstream out;
+    out << '<' << (closed ? "polygon" : "polyline") << " points=\"";
+    for (size_t i = 0; i < points.size(); ++i) {
+      out << (i == 0 ? "" : " ") << points[i].first << ','
+          << points[i].second;
+    }
+    out << "\" fill=\"" << fill << "\" stroke=\"" << stroke
+        << "\" stroke-width=\"" << stroke_width << "\"/>";
+    shapes_.push_back(out.str());
+  }
+
+  // Centered on x, with y the baseline
+  void text(double x, double y, const std::string& text, double size = 12,
+            const std::string& fill = "black") {
+    std::ostringstream out;
+    out << "<text x=\"" << x << "\" y=\"" << y << "\" font-size=\""
+            << size << "\" text-anchor=\"middle\" fill=\"" << fill
+            << "\" font-family=\"sans-serif\">" << escape_xml(text)
+            << "</text>";
+    shapes_.push_back(out.str());
+  }
+
+  // Adds other's shapes, on top of these
+  void append(const Svg& other) {
+    shapes_.insert(shapes_.end(), other.shapes_.begin(), other.shapes_.end());
+  }
+
+  std::string str() const {
+    std::ostringstream out;
+    out << "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"" << width_
+        << "\" height=\"" << height_ << "\" viewBox=\"0 0 " << width_ << ' '
+        << height_ << "\">\n";
+    for (const auto& s : shapes_) {
+      out << "  " << s << '\n';
+    }
+    out << "</svg>\n";
+    return out.str();
+  }
+
+  size_t size() const { return shapes_.size(); }
+
+ private:
+  double width_;
+  double height_;
+  std::vector<std::string> shapes_;
+};
+
+inline void write_file(const std::filesystem::path& path,
+                       const std::string& contents) {
+  std::ofstream out(path, std::ios::binary);
+  if (!(out << contents)) {
+    throw std::runtime_error("can't write " + path.string());
+  }
+}
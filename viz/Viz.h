@@ -0,0 +1,280 @@
+#pragma once
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <functional>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "../binary-tree/TreeNode.h"
+#include "../geometry/ConvexHull.h"
+#include "../geometry/Skyline.h"
+#include "../parse/Parse.h"
+#include "../sorting/Sorting.h"
+#include "Svg.h"
+
+// Pictures of what the etudes compute, as SVG: a tree with its balance
+// factors, a skyline over its buildings, a convex hull around its points,
+// and a sort as a column of bar charts, one per step. Coordinates in the
+// etudes are in units; the pictures scale them up, and flip y so that up
+// is up.
+
+// Trees: each node goes in its own column, in inorder, and its own row, by
+// depth, so edges never cross. Next to each node is its balance factor,
+// left height minus right height; nodes that would break the AVL property,
+// at more than 1 either way, are shaded.
+
+constexpr double kTreeColumn = 40;
+constexpr double kTreeRow = 60;
+constexpr double kTreeRadius = 15;
+
+using Spot = std::pair<double, double>;
+
+struct TreeLayout {
+  std::vector<std::pair<Spot, Spot>> edges;
+  Svg nodes{0, 0};  // Drawn after the edges, on top of them
+  size_t columns = 0;
+  int rows = 0;
+};
+
+// Lays out the subtree at node, setting spot to where the node went.
+// Returns the subtree's height.
+template <typename T>
+int layout_tree(const TreeNode<T>* node, int depth, TreeLayout& layout,
+                Spot& spot) {
+  if (node == nullptr) {
+    return 0;
+  }
+  Spot left_spot, right_spot;
+  const int left = layout_tree(node->left.get(), depth + 1, layout, left_spot);
+  spot = {kTreeColumn * static_cast<double>(++layout.columns),
+          kTreeRow * (depth + 1)};
+  layout.rows = std::max(layout.rows, depth + 1);
+  const int right =
+      layout_tree(node->right.get(), depth + 1, layout, right_spot);
+  if (node->left) {
+    layout.edges.emplace_back(spot, left_spot);
+  }
+  if (node->right) {
+    layout.edges.emplace_back(spot, right_spot);
+  }
+
+  const int factor = left - right;
+  std::ostringstream value;
+  value << node->val;
+  const auto [x, y] = spot;
+  layout.nodes.circle(x, y, kTreeRadius,
+                      std::abs(factor) > 1 ? "#f4b6b6" : "white");
+  layout.nodes.text(x, y + 4, value.str());
+  layout.nodes.text(x + kTreeRadius + 4, y - kTreeRadius,
+                    std::to_string(factor), 10, "#555");
+  return 1 + std::max(left, right);
+}
+
+template <typename T>
+Svg tree_svg(const TreeNode<T>* root) {
+  TreeLayout layout;
+  Spot spot;
+  layout_tree(root, 0, layout, spot);
+  Svg svg(kTreeColumn * static_cast<double>(layout.columns + 1),
+          kTreeRow * (layout.rows + 0.5));
+  for (const auto& [from, to] : layout.edges) {
+    svg.line(from.first, from.second, to.first, to.second);
+  }
+  svg.append(layout.nodes);
+  return svg;
+}
+
+// Skylines and hulls share a viewport: world coordinates, scaled up, with y
+// flipped and a margin all around.
+
+constexpr double kUnit = 20;
+constexpr double kMargin = 20;
+
+class Viewport {
+ public:
+  Viewport(int64_t min_x, int64_t max_x, int64_t min_y, int64_t max_y)
+      : min_x_(min_x), max_y_(max_y), width_(max_x - min_x),
+        height_(max_y - min_y) {}
+
+  double x(int64_t x) const {
+    return kMargin + kUnit * static_cast<double>(x - min_x_);
+  }
+  double y(int64_t y) const {
+    return kMargin + kUnit * static_cast<double>(max_y_ - y);
+  }
+  Spot at(int64_t x, int64_t y) const { return {this->x(x), this->y(y)}; }
+
+  Svg svg() const {
+    return Svg(2 * kMargin + kUnit * static_cast<double>(width_),
+               2 * kMargin + kUnit * static_cast<double>(height_));
+  }
+
+ private:
+  int64_t min_x_;
+  int64_t max_y_;
+  int64_t width_;
+  int64_t height_;
+};
+
+// The buildings, shaded, with the outline over them and a dot at each key
+// point
+inline Svg skyline_svg(const std::vector<Building>& buildings,
+                       const std::vector<KeyPoint>& outline) {
+  int64_t min_x = 0, max_x = 1, max_y = 1;
+  if (!buildings.empty()) {
+    min_x = max_x = buildings[0].left;
+  }
+  for (const auto& b : buildings) {
+    min_x = std::min(min_x, b.left);
+    max_x = std::max(max_x, b.right);
+    max_y = std::max(max_y, b.height);
+  }
+  const Viewport view(min_x, max_x, 0, max_y);
+  Svg svg = view.svg();
+  svg.line(view.x(min_x), view.y(0), view.x(max_x), view.y(0), "#999");
+  for (const auto& b : buildings) {
+    svg.rect(view.x(b.left), view.y(b.height),
+             view.x(b.right) - view.x(b.left),
+             view.y(0) - view.y(b.height), "#c9d7ea80", "#7a90b0");
+  }
+  std::vector<Spot> steps;
+  int64_t height = 0;
+  for (const auto& point : outline) {
+    steps.push_back(view.at(point.x, height));
+    steps.push_back(view.at(point.x, point.height));
+    height = point.height;
+  }
+  svg.polyline(steps, "#c0392b", "none", false, 3);
+  for (const auto& point : outline) {
+    svg.circle(view.x(point.x), view.y(point.height), 4, "#c0392b", "none");
+  }
+  return svg;
+}
+
+// The hull, shaded, over every point, with its vertices larger
+inline Svg hull_svg(const std::vector<LatticePoint>& points,
+                    const std::vector<LatticePoint>& hull) {
+  int64_t min_x = 0, max_x = 1, min_y = 0, max_y = 1;
+  if (!points.empty()) {
+    min_x = max_x = points[0].x;
+    min_y = max_y = points[0].y;
+  }
+  for (const auto& p : points) {
+    min_x = std::min(min_x, p.x);
+    max_x = std::max(max_x, p.x);
+    min_y = std::min(min_y, p.y);
+    max_y = std::max(max_y, p.y);
+  }
+  const Viewport view(min_x, max_x, min_y, max_y);
+  Svg svg = view.svg();
+  std::vector<Spot> corners;
+  for (const auto& p : hull) {
+    corners.push_back(view.at(p.x, p.y));
+  }
+  svg.polyline(corners, "#4a7f2c", "#e3efd9", true, 2);
+  for (const auto& p : points) {
+    svg.circle(view.x(p.x), view.y(p.y), 3, "black", "none");
+  }
+  for (const auto& p : hull) {
+    svg.circle(view.x(p.x), view.y(p.y), 5, "#4a7f2c", "none");
+  }
+  return svg;
+}
+
+// Sorts: every snapshot of the array is a row of bars, numbered on the
+// left, top to bottom. All rows share one scale, with 0 at the bottom of a
+// row unless some value is negative.
+
+constexpr double kBarWidth = 12;
+constexpr double kBarGap = 2;
+constexpr double kBarRow = 50;
+
+inline Svg bars_svg(const std::vector<std::vector<int64_t>>& snapshots) {
+  int64_t low = 0, high = 0;
+  size_t longest = 0;
+  for (const auto& snapshot : snapshots) {
+    for (int64_t v : snapshot) {
+      low = std::min(low, v);
+      high = std::max(high, v);
+    }
+    longest = std::max(longest, snapshot.size());
+  }
+  const double span = static_cast<double>(std::max<int64_t>(high - low, 1));
+  const double bar_scale = (kBarRow - 10) / span;
+
+  Svg svg(2 * kMargin + kMargin + (kBarWidth + kBarGap) *
+                                      static_cast<double>(longest),
+          kMargin + kBarRow * static_cast<double>(snapshots.size()));
+  for (size_t row = 0; row < snapshots.size(); ++row) {
+    const double top = kMargin / 2 + kBarRow * static_cast<double>(row);
+    const double zero = top + 5 + bar_scale * static_cast<double>(high);
+    svg.text(kMargin, top + kBarRow / 2, std::to_string(row), 10, "#555");
+    for (size_t i = 0; i < snapshots[row].size(); ++i) {
+      const double x = 2 * kMargin + (kBarWidth + kBarGap) *
+                                         static_cast<double>(i);
+      const double length =
+          bar_scale * static_cast<double>(snapshots[row][i]);
+      svg.rect(x, std::min(zero, zero - length), kBarWidth, std::abs(length),
+               "#5b8bd0");
+    }
+  }
+  return svg;
+}
+
+// Something to draw with bars_svg: the array before merge sort and after
+// every merge
+inline std::vector<std::vector<int64_t>> merge_sort_snapshots(
+    std::vector<int64_t> values) {
+  std::vector<std::vector<int64_t>> snapshots = {values};
+  merge_sort(values, std::less<>(),
+             [&](const Step&, const std::vector<int64_t>& after) {
+               snapshots.push_back(after);
+             });
+  return snapshots;
+}
+
+// What the command line draws, as "tree", "skyline", "hull", or "sort",
+// from text in the formats of parse/: a level-order tree, "left right
+// height" for each building, "x y" for each point, and values to sort.
+// Throws std::invalid_argument, or ParseError, for anything else.
+inline std::string render_svg(const std::string& kind,
+                              const std::string& input) {
+  if (kind == "tree") {
+    return tree_svg(parse_tree<int64_t>(input).get()).str();
+  }
+  if (kind == "sort") {
+    return bars_svg(merge_sort_snapshots(parse_values<int64_t>(input)))
+        .str();
+  }
+  if (kind == "skyline") {
+    const auto numbers = parse_values<int64_t>(input);
+    if (numbers.size() % 3 != 0) {
+      throw ParseError("skyline: expected left, right, and height for each "
+                       "building");
+    }
+    std::vector<Building> buildings;
+    for (size_t i = 0; i < numbers.size(); i += 3) {
+      buildings.push_back({numbers[i], numbers[i + 1], numbers[i + 2]});
+    }
+    return skyline_svg(buildings, skyline(buildings)).str();
+  }
+  if (kind == "hull") {
+    const auto numbers = parse_values<int64_t>(input);
+    if (numbers.size() % 2 != 0) {
+      throw ParseError("hull: expected x and y for each point");
+    }
+    std::vector<LatticePoint> points;
+    for (size_t i = 0; i < numbers.size(); i += 2) {
+      points.push_back({numbers[i], numbers[i + 1]});
+    }
+    return hull_svg(points, convex_hull(points)).str();
+  }
+  throw std::invalid_argument("no picture of " + kind +
+                              "; try tree, skyline, hull, or sort");
+}
@@ -0,0 +1,89 @@
+#include <cstddef>
+#include <cstdint>
+#include <filesystem>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../cli/Fixtures.h"
+#include "Svg.h"
+#include "Viz.h"
+#include "gtest/gtest.h"
+
+static size_t count(const std::string& text, const std::string& part) {
+  size_t n = 0;
+  for (size_t at = text.find(part); at != std::string::npos;
+       at = text.find(part, at + 1)) {
+    ++n;
+  }
+  return n;
+}
+
+TEST(Svg, Shapes) {
+  Svg svg(100, 50);
+  svg.rect(0, 0, 10, 20, "red");
+  svg.text(5, 5, "a < b & c");
+  svg.polyline({{0, 0}, {1, 2}}, "blue");
+  EXPECT_EQ(svg.size(), 3u);
+  const std::string text = svg.str();
+  EXPECT_EQ(text.rfind("<svg xmlns=\"http://www.w3.org/2000/svg\" "
+                       "width=\"100\" height=\"50\"",
+                       0),
+            0u);
+  EXPECT_NE(text.find(">a &lt; b &amp; c</text>"), std::string::npos);
+  EXPECT_NE(text.find("points=\"0,0 1,2\""), std::string::npos);
+  EXPECT_EQ(text.substr(text.size() - 7), "</svg>\n");
+}
+
+TEST(Viz, Tree) {
+  // 1 leans left by 2, so it's shaded; 2 leans left by 1
+  const std::string svg = tree_svg(parse_tree<int>("[1,2,null,3]").get()).str();
+  EXPECT_EQ(count(svg, "<circle"), 3u);
+  EXPECT_EQ(count(svg, "<line"), 2u);
+  EXPECT_EQ(count(svg, "#f4b6b6"), 1u);
+  EXPECT_NE(svg.find(">2</text>"), std::string::npos);
+  EXPECT_EQ(count(tree_svg<int>(nullptr).str(), "<circle"), 0u);
+}
+
+TEST(Viz, SkylineAndHull) {
+  const std::vector<Building> buildings = {{2, 9, 10}, {3, 7, 15}};
+  const std::string skyline_picture =
+      skyline_svg(buildings, skyline(buildings)).str();
+  EXPECT_EQ(count(skyline_picture, "<rect"), 2u);
+  EXPECT_EQ(count(skyline_picture, "<polyline"), 1u);
+  EXPECT_EQ(count(skyline_picture, "<circle"), 4u);  // The key points
+
+  const std::vector<LatticePoint> points = {
+      {0, 0}, {2, 0}, {1, 1}, {2, 2}, {0, 2}};
+  const std::string hull_picture = hull_svg(points, convex_hull(points)).str();
+  EXPECT_EQ(count(hull_picture, "<polygon"), 1u);
+  EXPECT_EQ(count(hull_picture, "<circle"), 5u + 4u);
+}
+
+TEST(Viz, Sort) {
+  const auto snapshots = merge_sort_snapshots({3, 1, 2});
+  EXPECT_EQ(snapshots, (std::vector<std::vector<int64_t>>{
+                           {3, 1, 2}, {1, 3, 2}, {1, 2, 3}}));
+  EXPECT_EQ(count(bars_svg(snapshots).str(), "<rect"), 9u);
+  EXPECT_EQ(count(bars_svg({{-2, 2}}).str(), "<rect"), 2u);
+}
+
+TEST(Viz, Render) {
+  EXPECT_EQ(count(render_svg("tree", "[1,2,3]"), "<circle"), 3u);
+  EXPECT_EQ(count(render_svg("skyline", "0 2 3\n1 4 1\n"), "<rect"), 2u);
+  EXPECT_EQ(count(render_svg("hull", "0 0\n1 0\n0 1\n"), "<polygon"), 1u);
+  EXPECT_EQ(count(render_svg("sort", "2 1"), "<rect"), 4u);
+  EXPECT_THROW(render_svg("skyline", "0 2"), ParseError);
+  EXPECT_THROW(render_svg("hull", "0 x"), ParseError);
+  EXPECT_THROW(render_svg("heap", "1 2 3"), std::invalid_argument);
+
+  const auto path = std::filesystem::temp_directory_path() / "viz_tests.svg";
+  write_file(path, render_svg("sort", "1"));
+  EXPECT_EQ(read_file(path), render_svg("sort", "1"));
+  std::filesystem::remove(path);
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
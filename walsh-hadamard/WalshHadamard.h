@@ -0,0 +1,176 @@
+#pragma once
+
+#include <bit>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "../bits/Bits.h"
+
+// Convolutions over the bits of the index. For arrays a and b of length
+// 2^n, the xor convolution is c[k] = sum of a[i] * b[j] over i ^ j = k, and
+// likewise for and and or. Done directly that's O(4^n); each has a
+// transform, like the Fourier transform for ordinary convolution, that
+// turns it into pointwise multiplication, and the transforms take
+// O(n 2^n).
+//
+// All three transforms work one bit at a time: for each bit, pair up the
+// indices that differ only in that bit, and combine each pair (x, y),
+// where x is at the index with the bit clear:
+//
+// - or: y += x. Afterwards a[k] is the sum over the subsets of k, and the
+//   subsets of k are exactly the i and j with i | j a subset of k.
+// - and: x += y, the sum over the supersets of k.
+// - xor: (x, y) -> (x + y, x - y), the Walsh-Hadamard transform: a[k] is
+//   the sum of a[i], negated when i & k has an odd number of bits.
+//
+// Integer entries, so the results are exact as long as they fit in an
+// int64_t; the inverse xor transform divides by 2^n, exactly.
+
+// Sum over subsets (the zeta transform), or with inverse, the Moebius
+// transform that undoes it
+inline void or_transform(std::vector<int64_t>& a, bool inverse = false) {
+  assert(is_power_of_two(a.size()) && "length must be a power of two");
+  for (size_t bit = 1; bit < a.size(); bit <<= 1) {
+    for (size_t i = 0; i < a.size(); ++i) {
+      if (i & bit) {
+        a[i] += inverse ? -a[i ^ bit] : a[i ^ bit];
+      }
+    }
+  }
+}
+
+// Sum over supersets, or its inverse
+inline void and_transform(std::vector<int64_t>& a, bool inverse = false) {
+  assert(is_power_of_two(a.size()) && "length must be a power of two");
+  for (size_t bit = 1; bit < a.size(); bit <<= 1) {
+    for (size_t i = 0; i < a.size(); ++i) {
+      if (!(i & bit)) {
+        a[i] += inverse ? -a[i | bit] : a[i | bit];
+      }
+    }
+  }
+}
+
+// The Walsh-Hadamard transform is its own inverse, up to a factor of 2^n
+inline void xor_transform(std::vector<int64_t>& a, bool inverse = false) {
+  assert(is_power_of_two(a.size()) && "length must be a power of two");
+  for (size_t bit = 1; bit < a.size(); bit <<= 1) {
+    for (size_t i = 0; i < a.size(); ++i) {
+      if (!(i & bit)) {
+        const int64_t x = a[i];
+        const int64_t y = a[i | bit];
+        a[i] = x + y;
+        a[i | bit] = x - y;
+      }
+    }
+  }
+  if (inverse) {
+    for (auto& x : a) {
+      x /= static_cast<int64_t>(a.size());
+    }
+  }
+}
+
+// Transform both, multiply pointwise, and transform back
+template <typename Transform>
+std::vector<int64_t> convolve_by(std::vector<int64_t> a, std::vector<int64_t> b,
+                                 Transform transform) {
+  assert(a.size() == b.size() && "arrays must be the same length");
+  transform(a, false);
+  transform(b, false);
+  for (size_t i = 0; i < a.size(); ++i) {
+    a[i] *= b[i];
+  }
+  transform(a, true);
+  return a;
+}
+
+inline std::vector<int64_t> or_convolution(const std::vector<int64_t>& a,
+                                           const std::vector<int64_t>& b) {
+  return convolve_by(a, b, or_transform);
+}
+
+inline std::vector<int64_t> and_convolution(const std::vector<int64_t>& a,
+                                            const std::vector<int64_t>& b) {
+  return convolve_by(a, b, and_transform);
+}
+
+inline std::vector<int64_t> xor_convolution(const std::vector<int64_t>& a,
+                                            const std::vector<int64_t>& b) {
+  return convolve_by(a, b, xor_transform);
+}
+
+// The subset convolution: c[k] = sum of a[i] * b[j] over the i and j that
+// split k into two disjoint parts, i | j = k and i & j = 0. The or
+// convolution also counts overlapping pairs; they're exactly the ones with
+// popcount(i) + popcount(j) > popcount(k), so keep the arrays ranked by
+// popcount, do the or convolution one rank at a time, and only keep the
+// rank popcount(k) part of the result at index k. O(n^2 2^n).
+inline std::vector<int64_t> subset_convolution(const std::vector<int64_t>& a,
+                                               const std::vector<int64_t>& b) {
+  assert(a.size() == b.size() && "arrays must be the same length");
+  assert(is_power_of_two(a.size()) && "length must be a power of two");
+  const size_t size = a.size();
+  const int n = std::countr_zero(size);
+
+  // by_rank[r][i] is v[i] if i has r bits, else 0, then transformed
+  auto ranked = [&](const std::vector<int64_t>& v) {
+    std::vector<std::vector<int64_t>> by_rank(
+        n + 1, std::vector<int64_t>(size, 0));
+    for (size_t i = 0; i < size; ++i) {
+      by_rank[popcount(i)][i] = v[i];
+    }
+    for (auto& row : by_rank) {
+      or_transform(row);
+    }
+    return by_rank;
+  };
+  const auto ranked_a = ranked(a);
+  const auto ranked_b = ranked(b);
+
+  std::vector<int64_t> c(size, 0);
+  for (int r = 0; r <= n; ++r) {
+    std::vector<int64_t> product(size, 0);
+    for (int s = 0; s <= r; ++s) {
+      for (size_t i = 0; i < size; ++i) {
+        product[i] += ranked_a[s][i] * ranked_b[r - s][i];
+      }
+    }
+    or_transform(product, true);
+    for (size_t i = 0; i < size; ++i) {
+      if (popcount(i) == r) {
+        c[i] = product[i];
+      }
+    }
+  }
+  return c;
+}
+
+// O(4^n), for the tests: op is |, &, or ^ on the indices
+template <typename Op>
+std::vector<int64_t> bitwise_convolution_naive(const std::vector<int64_t>& a,
+                                               const std::vector<int64_t>& b,
+                                               Op op) {
+  std::vector<int64_t> c(a.size(), 0);
+  for (size_t i = 0; i < a.size(); ++i) {
+    for (size_t j = 0; j < b.size(); ++j) {
+      c[op(i, j)] += a[i] * b[j];
+    }
+  }
+  return c;
+}
+
+inline std::vector<int64_t> subset_convolution_naive(
+    const std::vector<int64_t>& a, const std::vector<int64_t>& b) {
+  std::vector<int64_t> c(a.size(), 0);
+  for (size_t i = 0; i < a.size(); ++i) {
+    for (size_t j = 0; j < b.size(); ++j) {
+      if ((i & j) == 0) {
+        c[i | j] += a[i] * b[j];
+      }
+    }
+  }
+  return c;
+}
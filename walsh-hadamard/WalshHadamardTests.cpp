@@ -0,0 +1,85 @@
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "WalshHadamard.h"
+#include "gtest/gtest.h"
+
+std::vector<int64_t> random_array(size_t size, Rng& gen) {
+  std::vector<int64_t> a(size);
+  for (auto& x : a) {
+    x = uniform_int<int64_t>(gen, -1000, 1000);
+  }
+  return a;
+}
+
+TEST(WalshHadamard, Transforms) {
+  // Sums over subsets and supersets of the indices 0b00 through 0b11
+  std::vector<int64_t> a = {1, 2, 3, 4};
+  or_transform(a);
+  EXPECT_EQ(a, (std::vector<int64_t>{1, 3, 4, 10}));
+  or_transform(a, true);
+  EXPECT_EQ(a, (std::vector<int64_t>{1, 2, 3, 4}));
+  and_transform(a);
+  EXPECT_EQ(a, (std::vector<int64_t>{10, 6, 7, 4}));
+  and_transform(a, true);
+  xor_transform(a);
+  EXPECT_EQ(a, (std::vector<int64_t>{10, -2, -4, 0}));
+  xor_transform(a, true);
+  EXPECT_EQ(a, (std::vector<int64_t>{1, 2, 3, 4}));
+
+  // Length 1: every convolution is multiplication
+  EXPECT_EQ(xor_convolution({3}, {-5}), (std::vector<int64_t>{-15}));
+  EXPECT_EQ(subset_convolution({3}, {-5}), (std::vector<int64_t>{-15}));
+}
+
+TEST(WalshHadamard, InversesUndo) {
+  Rng gen(210);
+  for (int n = 0; n <= 10; ++n) {
+    const auto a = random_array(size_t{1} << n, gen);
+    auto b = a;
+    or_transform(b);
+    or_transform(b, true);
+    ASSERT_EQ(b, a);
+    and_transform(b);
+    and_transform(b, true);
+    ASSERT_EQ(b, a);
+    xor_transform(b);
+    xor_transform(b, true);
+    ASSERT_EQ(b, a);
+  }
+}
+
+TEST(WalshHadamard, MatchNaive) {
+  Rng gen(210);
+  for (int trial = 0; trial < 100; ++trial) {
+    const size_t size = size_t{1} << uniform_below(gen, 8);
+    const auto a = random_array(size, gen);
+    const auto b = random_array(size, gen);
+    ASSERT_EQ(or_convolution(a, b),
+              bitwise_convolution_naive(a, b, std::bit_or<size_t>()));
+    ASSERT_EQ(and_convolution(a, b),
+              bitwise_convolution_naive(a, b, std::bit_and<size_t>()));
+    ASSERT_EQ(xor_convolution(a, b),
+              bitwise_convolution_naive(a, b, std::bit_xor<size_t>()));
+    ASSERT_EQ(subset_convolution(a, b), subset_convolution_naive(a, b));
+  }
+}
+
+// Counting: with a the indicator of some sets, the subset convolution of
+// a with itself counts the ordered ways to split each set into two
+// disjoint members
+TEST(WalshHadamard, CountsSplits) {
+  std::vector<int64_t> all(16, 1);
+  const auto splits = subset_convolution(all, all);
+  for (size_t k = 0; k < 16; ++k) {
+    EXPECT_EQ(splits[k], int64_t{1} << popcount(k)) << k;
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
@@ -0,0 +1,33 @@
+// The demos, exported to JavaScript. Only builds with Emscripten:
+//
+//   emcc -std=c++20 -O2 -lembind -o demos.js Bindings.cpp
+//
+// and then, in the page:
+//
+//   const demos = await Module();
+//   JSON.parse(demos.kadane("-2 1 -3 4 -1 2 1 -5 4")).sum  // 6
+
+#ifdef __EMSCRIPTEN__
+
+#include <emscripten/bind.h>
+
+#include <string>
+
+#include "Demos.h"
+
+EMSCRIPTEN_BINDINGS(etudes) {
+  emscripten::function("kadane", +[](const std::string& input) {
+    return run_demo(kadane_demo, input);
+  });
+  emscripten::function("lis", +[](const std::string& input) {
+    return run_demo(lis_demo, input);
+  });
+  emscripten::function("permutations", +[](const std::string& input) {
+    return run_demo(permutations_demo, input);
+  });
+  emscripten::function("balanceFactors", +[](const std::string& input) {
+    return run_demo(balance_factors_demo, input);
+  });
+}
+
+#endif  // __EMSCRIPTEN__
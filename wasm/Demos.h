@@ -0,0 +1,80 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "../binary-tree/BalanceFactors.h"
+#include "../binary-tree/Traversal.h"
+#include "../combinatorics/Permutation.h"
+#include "../parse/Parse.h"
+#include "../patience/Patience.h"
+#include "../sequences/Sequences.h"
+#include "Json.h"
+
+// The etudes a browser page can call, through the bindings in Bindings.cpp.
+// Each one takes its input as text, in the formats of parse/, and returns
+// a JSON object with the answer and whatever a visualization needs to show
+// how it was found. Bad input comes back as {"error": "..."} rather than as
+// an exception, which JavaScript can't catch usefully from here.
+
+// Kadane's maximum consecutive subsequence, with where it starts and ends
+// (end exclusive), and the best suffix sum after each element, which is the
+// quantity the induction carries along
+inline std::string kadane_demo(const std::string& input) {
+  const auto nums = parse_values<int64_t>(input);
+  const Subarray best = max_subarray(nums);
+  std::vector<int64_t> suffixes;
+  int64_t suffix = 0;
+  for (int64_t x : nums) {
+    suffix = suffix + x > 0 ? suffix + x : 0;
+    suffixes.push_back(suffix);
+  }
+  return json_object({{"sum", to_json(best.sum)},
+                      {"begin", to_json(best.begin)},
+                      {"end", to_json(best.end)},
+                      {"suffixes", to_json(suffixes)}});
+}
+
+// A longest increasing subsequence, and the patience piles after the deal
+inline std::string lis_demo(const std::string& input) {
+  const auto values = parse_values<int64_t>(input);
+  return json_object(
+      {{"lis", to_json(longest_increasing_subsequence(values))},
+       {"piles", to_json(patience_piles(values))}});
+}
+
+// All permutations of 0..n-1 in lexicographic order, for n <= 8
+inline std::string permutations_demo(const std::string& input) {
+  const auto n = parse_values<int>(input);
+  if (n.size() != 1 || n[0] < 0 || n[0] > 8) {
+    throw ParseError("expected n between 0 and 8");
+  }
+  std::vector<std::vector<int>> all;
+  for (uint64_t rank = 0; rank < factorial(n[0]); ++rank) {
+    all.push_back(permutation_unrank(n[0], rank));
+  }
+  return json_object({{"permutations", to_json(all)}});
+}
+
+// The tree from a LeetCode array, with each node's balance factor in
+// postorder
+inline std::string balance_factors_demo(const std::string& input) {
+  const auto root = parse_tree<int64_t>(input);
+  return json_object(
+      {{"postorder", to_json(postorder(root.get()))},
+       {"factors", to_json(compute_balance_factors(root.get()))},
+       {"balanced", to_json(is_height_balanced(root.get()))}});
+}
+
+// Calls demo(input), turning a parse error into {"error": ...}
+template <typename Demo>
+std::string run_demo(Demo demo, const std::string& input) {
+  try {
+    return demo(input);
+  } catch (const std::invalid_argument& e) {
+    return json_object({{"error", to_json(std::string(e.what()))}});
+  }
+}
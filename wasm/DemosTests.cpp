@@ -0,0 +1,53 @@
+#include <string>
+#include <vector>
+
+#include "Demos.h"
+#include "Json.h"
+#include "gtest/gtest.h"
+
+TEST(Demos, Json) {
+  EXPECT_EQ(to_json(true), "true");
+  EXPECT_EQ(to_json(-42), "-42");
+  EXPECT_EQ(to_json(std::string("say \"hi\"\n\\")), R"("say \"hi\"\n\\")");
+  EXPECT_EQ(to_json(std::string("\t")), R"("\u0009")");
+  EXPECT_EQ(to_json(std::vector<std::vector<int>>{{1, 2}, {}}), "[[1,2],[]]");
+  EXPECT_EQ(json_object({{"a", "1"}, {"b", "[]"}}), R"({"a":1,"b":[]})");
+  EXPECT_EQ(json_object({}), "{}");
+}
+
+TEST(Demos, Kadane) {
+  EXPECT_EQ(run_demo(kadane_demo, "-2 1 -3 4 -1 2 1 -5 4"),
+            R"({"sum":6,"begin":3,"end":7,"suffixes":[0,1,0,4,3,5,6,1,5]})");
+  EXPECT_EQ(run_demo(kadane_demo, "-1 -2"),
+            R"({"sum":0,"begin":0,"end":0,"suffixes":[0,0]})");
+  EXPECT_EQ(run_demo(kadane_demo, ""),
+            R"({"sum":0,"begin":0,"end":0,"suffixes":[]})");
+}
+
+TEST(Demos, Lis) {
+  EXPECT_EQ(run_demo(lis_demo, "4 1 3"),
+            R"({"lis":[1,3],"piles":[[4,1],[3]]})");
+}
+
+TEST(Demos, Permutations) {
+  EXPECT_EQ(run_demo(permutations_demo, "3"),
+            R"({"permutations":[[0,1,2],[0,2,1],[1,0,2],[1,2,0],[2,0,1],)"
+            R"([2,1,0]]})");
+  EXPECT_EQ(run_demo(permutations_demo, "0"), R"({"permutations":[[]]})");
+  EXPECT_EQ(run_demo(permutations_demo, "9"),
+            R"({"error":"expected n between 0 and 8"})");
+}
+
+TEST(Demos, BalanceFactors) {
+  EXPECT_EQ(run_demo(balance_factors_demo, "[1,2,null,3]"),
+            R"({"postorder":[3,2,1],"factors":[0,1,2],"balanced":false})");
+  EXPECT_EQ(run_demo(balance_factors_demo, "[]"),
+            R"({"postorder":[],"factors":[],"balanced":true})");
+  EXPECT_EQ(run_demo(balance_factors_demo, "[1,x]"),
+            R"({"error":"tree: bad value 'x' at index 1"})");
+}
+
+int main(int argc, char* argv[]) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}
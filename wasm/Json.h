@@ -0,0 +1,64 @@
+#pragma once
+
+#include <concepts>
+#include <cstdio>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Just enough JSON output for the demos: numbers, booleans, strings,
+// arrays, and objects, each written as a string of JSON. Objects keep
+// their keys in the order given.
+
+inline std::string to_json(bool b) { return b ? "true" : "false"; }
+
+template <std::integral T>
+std::string to_json(T x) {
+  return std::to_string(x);
+}
+
+inline std::string to_json(const std::string& s) {
+  std::string json = "\"";
+  for (char c : s) {
+    switch (c) {
+      case '"':
+        json += "\\\"";
+        break;
+      case '\\':
+        json += "\\\\";
+        break;
+      case '\n':
+        json += "\\n";
+        break;
+      default:
+        if (static_cast<unsigned char>(c) < 0x20) {
+          char escaped[8];
+          std::snprintf(escaped, sizeof escaped, "\\u%04x", c);
+          json += escaped;
+        } else {
+          json += c;
+        }
+    }
+  }
+  return json + "\"";
+}
+
+template <typename T>
+std::string to_json(const std::vector<T>& values) {
+  std::string json = "[";
+  for (size_t i = 0; i < values.size(); ++i) {
+    json += (i > 0 ? "," : "") + to_json(values[i]);
+  }
+  return json + "]";
+}
+
+// Fields are (key, already-written JSON) pairs
+inline std::string json_object(
+    const std::vector<std::pair<std::string, std::string>>& fields) {
+  std::string json = "{";
+  for (size_t i = 0; i < fields.size(); ++i) {
+    json += (i > 0 ? "," : "") + to_json(fields[i].first) + ":" +
+            fields[i].second;
+  }
+  return json + "}";
+}
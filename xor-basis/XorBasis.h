@@ -0,0 +1,100 @@
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+// A linear basis of 64-bit words under xor: the words are vectors over
+// GF(2) (gf2/ has the general elimination), and the basis spans every xor
+// of a subset of the words inserted so far. It keeps at most one word per
+// leading bit, so at most 64 and each operation is O(64).
+//
+// Because the basis words have distinct leading bits, reducing a word by
+// them from the top bit down is a greedy walk that answers everything:
+// a word is in the span if it reduces to 0, and the largest xor with a
+// word comes from taking each basis word that raises the result.
+class XorBasis {
+ public:
+  // Adds x to the span. True if x wasn't in it already, and so the rank
+  // went up.
+  bool insert(uint64_t x) {
+    for (int bit = 63; bit >= 0 && x != 0; --bit) {
+      if (!((x >> bit) & 1)) {
+        continue;
+      }
+      if (basis_[bit] == 0) {
+        basis_[bit] = x;
+        ++rank_;
+        return true;
+      }
+      x ^= basis_[bit];
+    }
+    return false;
+  }
+
+  // Whether x is the xor of some subset of the inserted words. 0 always is,
+  // as the empty subset.
+  bool contains(uint64_t x) const {
+    for (int bit = 63; bit >= 0 && x != 0; --bit) {
+      if ((x >> bit) & 1) {
+        x ^= basis_[bit];
+      }
+    }
+    return x == 0;
+  }
+
+  // The largest x ^ s over every s in the span; with x = 0, the largest xor
+  // of a subset. Each step fixes one more bit of the result from the top,
+  // and the basis word leading at that bit is the only one that can still
+  // change it.
+  uint64_t max_xor(uint64_t x = 0) const {
+    for (int bit = 63; bit >= 0; --bit) {
+      if ((x ^ basis_[bit]) > x) {
+        x ^= basis_[bit];
+      }
+    }
+    return x;
+  }
+
+  // The number of basis words: the span has 2^rank() words
+  size_t rank() const { return rank_; }
+
+  // The basis words, from the highest leading bit down
+  std::vector<uint64_t> words() const {
+    std::vector<uint64_t> all;
+    for (int bit = 63; bit >= 0; --bit) {
+      if (basis_[bit] != 0) {
+        all.push_back(basis_[bit]);
+      }
+    }
+    return all;
+  }
+
+ private:
+  // basis_[b] leads with bit b, or is 0
+  std::array<uint64_t, 64> basis_ = {};
+  size_t rank_ = 0;
+};
+
+// The largest xor of a subset of nums, 0 for none. O(64 n).
+inline uint64_t max_subset_xor(const std::vector<uint64_t>& nums) {
+  XorBasis basis;
+  for (uint64_t x : nums) {
+    basis.insert(x);
+  }
+  return basis.max_xor();
+}
+
+// Every xor of a subset of nums, with repeats. O(2^n), for the tests.
+inline std::vector<uint64_t> subset_xors_naive(
+    const std::vector<uint64_t>& nums) {
+  std::vector<uint64_t> xors = {0};
+  for (uint64_t x : nums) {
+    const size_t n = xors.size();
+    for (size_t i = 0; i < n; ++i) {
+      xors.push_back(xors[i] ^ x);
+    }
+  }
+  return xors;
+}
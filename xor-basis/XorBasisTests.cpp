@@ -0,0 +1,96 @@
+#include <algorithm>
+#include <bit>
+#include <cstddef>
+#include <cstdint>
+#include <set>
+#include <vector>
+
+#include "../rng/Rng.h"
+#include "XorBasis.h"
+#include "gtest/gtest.h"
+
+TEST(XorBasis, Small) {
+  EXPECT_EQ(max_subset_xor({}), 0u);
+  EXPECT_EQ(max_subset_xor({8, 1, 2, 12, 7, 6}), 15u);
+  EXPECT_EQ(max_subset_xor({2, 4, 5}), 7u);
+
+  XorBasis basis;
+  EXPECT_TRUE(basis.insert(0b1100));
+  EXPECT_TRUE(basis.insert(0b0110));
+  EXPECT_FALSE(basis.insert(0b1010));  // the xor of the first two
+  EXPECT_FALSE(basis.insert(0));
+  EXPECT_EQ(basis.rank(), 2u);
+  EXPECT_TRUE(basis.contains(0));
+  EXPECT_TRUE(basis.contains(0b1010));
+  EXPECT_FALSE(basis.contains(0b0001));
+  EXPECT_EQ(basis.max_xor(), 0b1100u);
+  EXPECT_EQ(basis.max_xor(0b0001), 0b1101u);
+  EXPECT_EQ(basis.max_xor(0b0100), 0b1110u);
+}
+
+// Words with only a few bits set, so that many of them depend on the
+// others, or full words, which almost never do
+TEST(XorBasis, MatchesEverySubset) {
+  Rng gen(209);
+  for (int trial = 0; trial < 500; ++trial) {
+    const size_t n = uniform_below(gen, 13);
+    const uint64_t mask =
+        bernoulli(gen, 0.5) ? (uint64_t{1} << uniform_int(gen, 1, 12)) - 1
+                            : ~uint64_t{0};
+    std::vector<uint64_t> nums(n);
+    for (auto& x : nums) {
+      x = gen() & mask;
+    }
+
+    XorBasis basis;
+    for (uint64_t x : nums) {
+      basis.insert(x);
+    }
+    const auto xors = subset_xors_naive(nums);
+    const std::set<uint64_t> span(xors.begin(), xors.end());
+    ASSERT_EQ(size_t{1} << basis.rank(), span.size());
+    ASSERT_EQ(max_subset_xor(nums), *span.rbegin());
+
+    for (int query = 0; query < 20; ++query) {
+      // Half the queries from the span, half anything
+      const uint64_t x = query % 2 == 0 ? xors[uniform_below(gen, xors.size())]
+                                        : gen() & mask;
+      ASSERT_EQ(basis.contains(x), span.count(x) == 1);
+      uint64_t best = 0;
+      for (uint64_t s : span) {
+        best = std::max(best, x ^ s);
+      }
+      ASSERT_EQ(basis.max_xor(x), best);
+    }
+  }
+}
+
+TEST(XorBasis, BasisWords) {
+  Rng gen(209);
+  XorBasis basis;
+  std::vector<uint64_t> inserted;
+  for (int i = 0; i < 200; ++i) {
+    const uint64_t x = gen() >> uniform_below(gen, 64);
+    inserted.push_back(x);
+    basis.insert(x);
+  }
+  // The words have distinct leading bits, so they're independent, and
+  // they span everything inserted
+  const auto words = basis.words();
+  ASSERT_EQ(words.size(), basis.rank());
+  for (size_t i = 1; i < words.size(); ++i) {
+    ASSERT_LT(std::bit_width(words[i]), std::bit_width(words[i - 1]));
+  }
+  XorBasis rebuilt;
+  for (uint64_t w : words) {
+    ASSERT_TRUE(rebuilt.insert(w));
+  }
+  for (uint64_t x : inserted) {
+    ASSERT_TRUE(rebuilt.contains(x));
+  }
+}
+
+int main(int argc, char** argv) {
+  testing::InitGoogleTest(&argc, argv);
+  return RUN_ALL_TESTS();
+}